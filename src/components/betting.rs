@@ -1,13 +1,14 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use super::CamelColor;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LegBetTile {
     pub camel: CamelColor,
     pub value: u8, // 5, 3, or 2
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct LegBettingTiles {
     pub stacks: Vec<Vec<LegBetTile>>, // One stack per camel color
 }
@@ -56,13 +57,13 @@ impl Default for LegBettingTiles {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RaceBet {
     pub camel: CamelColor,
     pub player_id: u8,
 }
 
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
 pub struct RaceBets {
     pub winner_bets: Vec<RaceBet>,
     pub loser_bets: Vec<RaceBet>,
@@ -77,3 +78,55 @@ impl RaceBets {
         self.loser_bets.push(RaceBet { camel, player_id });
     }
 }
+
+/// Stores leg bets for each player
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct PlayerLegBetsStore {
+    pub bets: Vec<Vec<LegBetTile>>, // bets[player_id] = list of tiles
+}
+
+impl PlayerLegBetsStore {
+    pub fn new(player_count: usize) -> Self {
+        Self {
+            bets: vec![Vec::new(); player_count],
+        }
+    }
+
+    pub fn add_bet(&mut self, player_id: usize, tile: LegBetTile) {
+        if player_id < self.bets.len() {
+            self.bets[player_id].push(tile);
+        }
+    }
+
+    pub fn clear_all(&mut self) {
+        for bets in &mut self.bets {
+            bets.clear();
+        }
+    }
+}
+
+/// Stores pyramid token counts for each player
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct PlayerPyramidTokens {
+    pub counts: Vec<u8>, // counts[player_id] = number of pyramid tokens
+}
+
+impl PlayerPyramidTokens {
+    pub fn new(player_count: usize) -> Self {
+        Self {
+            counts: vec![0; player_count],
+        }
+    }
+
+    pub fn add_token(&mut self, player_id: usize) {
+        if player_id < self.counts.len() {
+            self.counts[player_id] += 1;
+        }
+    }
+
+    pub fn clear_all(&mut self) {
+        for count in &mut self.counts {
+            *count = 0;
+        }
+    }
+}
@@ -1,12 +1,22 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub const TRACK_LENGTH: u8 = 16;
 
+/// Distance between neighbouring spaces along a row of the track (world units)
+pub const SPACE_SPACING: f32 = 80.0;
+/// Distance between the bottom and top rows of the track
+const TRACK_ROW_HEIGHT: f32 = 200.0;
+/// X position of the first space of the bottom row (and the last of the top row)
+const TRACK_START_X: f32 = -280.0;
+
 #[derive(Component)]
-#[allow(dead_code)]
 pub struct BoardSpace {
     pub index: u8,
+    /// Tint over the painted space once placement highlighting is over (none on the
+    /// first track)
+    pub base_color: Color,
 }
 
 #[derive(Component, Clone, Copy)]
@@ -24,7 +34,7 @@ pub struct SpectatorTileSprite {
 }
 
 /// Resource to track all placed spectator tiles on the board
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
 pub struct PlacedSpectatorTiles {
     /// Map of space_index -> (owner_id, is_oasis)
     pub tiles: HashMap<u8, (u8, bool)>,
@@ -62,43 +72,73 @@ impl PlacedSpectatorTiles {
     }
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct GameBoard {
-    pub space_positions: Vec<Vec2>, // World positions for each track space
+    /// World positions for each track space, one layout per track
+    pub tracks: Vec<Vec<Vec2>>,
+    /// Track the camels are currently racing on
+    pub active_track: usize,
 }
 
 impl GameBoard {
     pub fn new() -> Self {
+        Self::with_tracks(1)
+    }
+
+    /// A board of several tracks raced one after another (Relay Race variant).
+    /// Every track shares the oval layout so the scenery around it stays put.
+    pub fn with_tracks(count: usize) -> Self {
+        Self {
+            tracks: vec![Self::oval_layout(); count.max(1)],
+            active_track: 0,
+        }
+    }
+
+    fn oval_layout() -> Vec<Vec2> {
         // Create an oval track layout
         // Spaces 0-7 on bottom row (left to right)
         // Spaces 8-15 on top row (right to left)
         let mut positions = Vec::with_capacity(TRACK_LENGTH as usize);
 
-        let spacing = 80.0;
-        let row_height = 200.0;
-        let start_x = -280.0;
-
         // Bottom row (0-7): left to right
         for i in 0..8 {
-            positions.push(Vec2::new(start_x + (i as f32 * spacing), -row_height / 2.0));
+            positions.push(Vec2::new(TRACK_START_X + (i as f32 * SPACE_SPACING), -TRACK_ROW_HEIGHT / 2.0));
         }
 
         // Top row (8-15): right to left
         for i in 0..8 {
-            positions.push(Vec2::new(start_x + ((7 - i) as f32 * spacing), row_height / 2.0));
+            positions.push(Vec2::new(TRACK_START_X + ((7 - i) as f32 * SPACE_SPACING), TRACK_ROW_HEIGHT / 2.0));
         }
 
-        Self {
-            space_positions: positions,
-        }
+        positions
     }
 
+    /// World position of a space on the active track
     pub fn get_position(&self, space_index: u8) -> Vec2 {
-        self.space_positions
-            .get(space_index as usize)
+        self.tracks
+            .get(self.active_track)
+            .and_then(|track| track.get(space_index as usize))
             .copied()
             .unwrap_or(Vec2::ZERO)
     }
+
+    pub fn track_count(&self) -> usize {
+        self.tracks.len()
+    }
+
+    /// Whether a camel crossing the finish of the active track ends the race
+    pub fn on_last_track(&self) -> bool {
+        self.active_track + 1 >= self.tracks.len()
+    }
+
+    /// Move the race on to the next track; returns false if already on the last one
+    pub fn advance_track(&mut self) -> bool {
+        if self.on_last_track() {
+            return false;
+        }
+        self.active_track += 1;
+        true
+    }
 }
 
 impl Default for GameBoard {
@@ -1,7 +1,20 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// How camel colors are drawn, from the player's settings
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CamelPalette {
+    /// Draw every camel, on the board and in the UI, in colors that stay apart with
+    /// red-green color blindness (Okabe-Ito), instead of the usual ones
+    pub colorblind: bool,
+    /// Mark every camel, die and bet tile with its color's letter and pattern, so the
+    /// colors can be told apart without seeing them
+    pub markers: bool,
+}
 
 /// Racing camel colors (Second Edition)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CamelColor {
     Blue,
     Green,
@@ -21,7 +34,46 @@ impl CamelColor {
         ]
     }
 
-    pub fn to_bevy_color(&self) -> Color {
+    /// The camel's color in the colorblind palette, if it's in use
+    pub fn colorblind_rgb(self, palette: CamelPalette) -> Option<[u8; 3]> {
+        if !palette.colorblind {
+            return None;
+        }
+        Some(match self {
+            CamelColor::Blue => [0, 114, 178],
+            CamelColor::Green => [0, 158, 115],
+            CamelColor::Red => [213, 94, 0],
+            CamelColor::Yellow => [240, 228, 66],
+            CamelColor::Purple => [204, 121, 167],
+        })
+    }
+
+    /// Letter marking the color
+    pub fn letter(self) -> char {
+        match self {
+            CamelColor::Blue => 'B',
+            CamelColor::Green => 'G',
+            CamelColor::Red => 'R',
+            CamelColor::Yellow => 'Y',
+            CamelColor::Purple => 'P',
+        }
+    }
+
+    /// Hatching marking the color
+    pub fn pattern(self) -> ColorPattern {
+        match self {
+            CamelColor::Blue => ColorPattern::HorizontalStripes,
+            CamelColor::Green => ColorPattern::VerticalStripes,
+            CamelColor::Red => ColorPattern::Dots,
+            CamelColor::Yellow => ColorPattern::Checks,
+            CamelColor::Purple => ColorPattern::Diamonds,
+        }
+    }
+
+    pub fn to_bevy_color(self, palette: CamelPalette) -> Color {
+        if let Some([r, g, b]) = self.colorblind_rgb(palette) {
+            return Color::srgb_u8(r, g, b);
+        }
         match self {
             CamelColor::Blue => Color::srgb(0.2, 0.4, 0.9),
             CamelColor::Green => Color::srgb(0.2, 0.8, 0.3),
@@ -32,7 +84,85 @@ impl CamelColor {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Hatching drawn over a camel color, one per color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorPattern {
+    HorizontalStripes,
+    VerticalStripes,
+    Dots,
+    Checks,
+    Diamonds,
+}
+
+/// One mark of a pattern, in a unit square centered on the origin (y up)
+#[derive(Debug, Clone, Copy)]
+pub struct PatternMark {
+    pub center: Vec2,
+    pub size: Vec2,
+    /// Turned 45 degrees
+    pub diamond: bool,
+}
+
+impl ColorPattern {
+    /// The marks making up the pattern. Scale them to the area being marked.
+    pub fn marks(self) -> Vec<PatternMark> {
+        let mark = |x: f32, y: f32, w: f32, h: f32| PatternMark { center: Vec2::new(x, y), size: Vec2::new(w, h), diamond: false };
+        let thirds = [-1.0 / 3.0, 0.0, 1.0 / 3.0];
+        match self {
+            ColorPattern::HorizontalStripes => thirds.iter().map(|&y| mark(0.0, y, 1.0, 0.12)).collect(),
+            ColorPattern::VerticalStripes => thirds.iter().map(|&x| mark(x, 0.0, 0.12, 1.0)).collect(),
+            ColorPattern::Dots => thirds
+                .iter()
+                .flat_map(|&x| thirds.iter().map(move |&y| mark(x, y, 0.14, 0.14)))
+                .collect(),
+            ColorPattern::Checks => (0..4)
+                .flat_map(|col| (0..4).map(move |row| (col, row)))
+                .filter(|(col, row)| (col + row) % 2 == 0)
+                .map(|(col, row)| mark(-0.375 + col as f32 * 0.25, -0.375 + row as f32 * 0.25, 0.25, 0.25))
+                .collect(),
+            ColorPattern::Diamonds => [(-0.25, -0.25), (-0.25, 0.25), (0.25, -0.25), (0.25, 0.25)]
+                .iter()
+                .map(|&(x, y)| PatternMark { diamond: true, ..mark(x, y, 0.22, 0.22) })
+                .collect(),
+        }
+    }
+}
+
+/// Custom display names for racing camels ("Old Bessie" instead of "Yellow").
+/// Purely cosmetic: rules always go by the camel's color.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct CamelNames {
+    names: HashMap<CamelColor, String>,
+}
+
+impl CamelNames {
+    /// Build from (color, name) pairs, ignoring blank names
+    pub fn new(names: impl IntoIterator<Item = (CamelColor, String)>) -> Self {
+        Self {
+            names: names
+                .into_iter()
+                .map(|(color, name)| (color, name.trim().to_string()))
+                .filter(|(_, name)| !name.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Name to show for a camel, falling back to its color
+    pub fn name(&self, color: CamelColor) -> String {
+        self.names
+            .get(&color)
+            .cloned()
+            .unwrap_or_else(|| format!("{:?}", color))
+    }
+
+    /// Custom names only, for sending to other players
+    #[allow(dead_code)]
+    pub fn custom_names(&self) -> impl Iterator<Item = (CamelColor, &str)> {
+        self.names.iter().map(|(color, name)| (*color, name.as_str()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CrazyCamelColor {
     Black,
     White,
@@ -43,7 +173,7 @@ impl CrazyCamelColor {
         [CrazyCamelColor::Black, CrazyCamelColor::White]
     }
 
-    pub fn to_bevy_color(&self) -> Color {
+    pub fn to_bevy_color(self) -> Color {
         match self {
             CrazyCamelColor::Black => Color::srgb(0.15, 0.15, 0.15),
             CrazyCamelColor::White => Color::srgb(0.95, 0.95, 0.95),
@@ -61,7 +191,10 @@ pub struct CrazyCamel {
     pub color: CrazyCamelColor,
 }
 
-#[derive(Component)]
+/// Query filter for every camel on the board, racing or crazy
+pub type AnyCamel = Or<(With<Camel>, With<CrazyCamel>)>;
+
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct BoardPosition {
     pub space_index: u8,
     pub stack_position: u8, // 0 = bottom of stack
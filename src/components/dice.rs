@@ -1,8 +1,10 @@
 use bevy::prelude::*;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use super::{CamelColor, CrazyCamelColor};
+use crate::game::core;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RegularDie {
     pub color: CamelColor,
     pub value: Option<u8>, // 1, 2, or 3 when rolled
@@ -13,20 +15,13 @@ impl RegularDie {
         Self { color, value: None }
     }
 
-    pub fn roll(&mut self) -> u8 {
-        let mut rng = rand::thread_rng();
-        let value = rng.gen_range(1..=3);
-        self.value = Some(value);
-        value
-    }
-
     pub fn reset(&mut self) {
         self.value = None;
     }
 }
 
 /// Represents either a regular camel die or the crazy camel die
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub enum PyramidDie {
     Regular(RegularDie),
@@ -36,13 +31,13 @@ pub enum PyramidDie {
 }
 
 /// Result of rolling a die from the pyramid
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum DieRollResult {
     Regular { color: CamelColor, value: u8 },
     Crazy { color: CrazyCamelColor, value: u8 },
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct Pyramid {
     pub dice: Vec<PyramidDie>,
     pub rolled_dice: Vec<PyramidDie>,
@@ -64,41 +59,91 @@ impl Pyramid {
         }
     }
 
-    pub fn roll_random_die(&mut self) -> Option<DieRollResult> {
+    /// Roll a die using the given generator.
+    /// Dice are drawn in a fixed order so a seeded generator always gives the same rolls.
+    pub fn roll_die_with(&mut self, rng: &mut impl Rng) -> Option<DieRollResult> {
+        let result = self.draw_die_with(rng)?;
+        self.apply_roll(&result);
+        Some(result)
+    }
+
+    /// Pick a die and roll it without taking it out of the pyramid, for the roll to be
+    /// applied as a `GameAction`. Draws in the same fixed order as `roll_die_with`.
+    pub fn draw_die_with(&self, rng: &mut impl Rng) -> Option<DieRollResult> {
         if self.dice.is_empty() {
             return None;
         }
 
-        let mut rng = rand::thread_rng();
-        let index = rng.gen_range(0..self.dice.len());
-        let die = self.dice.remove(index);
+        let mut dice: Vec<&PyramidDie> = self.dice.iter().collect();
+        dice.sort_by_key(|die| match die {
+            PyramidDie::Regular(regular) => regular.color as usize,
+            PyramidDie::Crazy { .. } => usize::MAX,
+        });
+        let die = dice[rng.gen_range(0..dice.len())];
 
         let result = match die {
-            PyramidDie::Regular(mut regular_die) => {
-                let value = regular_die.roll();
-                let color = regular_die.color;
-                self.rolled_dice.push(PyramidDie::Regular(regular_die));
-                DieRollResult::Regular { color, value }
-            }
+            PyramidDie::Regular(regular_die) => DieRollResult::Regular {
+                color: regular_die.color,
+                value: core::roll_die_face(rng),
+            },
             PyramidDie::Crazy { .. } => {
                 // Roll value 1-3 and randomly pick white or black crazy camel
-                let value = rng.gen_range(1..=3);
-                let crazy_color = if rng.gen_bool(0.5) {
-                    CrazyCamelColor::White
-                } else {
-                    CrazyCamelColor::Black
-                };
-                self.rolled_dice.push(PyramidDie::Crazy { rolled: Some((crazy_color, value)) });
-                DieRollResult::Crazy { color: crazy_color, value }
+                let (color, value) = core::roll_crazy_die(rng);
+                DieRollResult::Crazy { color, value }
             }
         };
 
         Some(result)
     }
 
+    /// Take a die rolled elsewhere (by an online host) out of the pyramid with its result.
+    /// Returns false if that die isn't in the pyramid.
+    pub fn apply_roll(&mut self, result: &DieRollResult) -> bool {
+        let index = self.dice.iter().position(|die| match (die, result) {
+            (PyramidDie::Regular(regular), DieRollResult::Regular { color, .. }) => regular.color == *color,
+            (PyramidDie::Crazy { .. }, DieRollResult::Crazy { .. }) => true,
+            _ => false,
+        });
+        let Some(index) = index else { return false };
+        self.dice.remove(index);
+
+        let rolled = match *result {
+            DieRollResult::Regular { color, value } => PyramidDie::Regular(RegularDie { color, value: Some(value) }),
+            DieRollResult::Crazy { color, value } => PyramidDie::Crazy { rolled: Some((color, value)) },
+        };
+        self.rolled_dice.push(rolled);
+        true
+    }
+
     pub fn all_dice_rolled(&self) -> bool {
         // Leg ends after 5 dice are rolled (any combination of regular and crazy)
-        self.rolled_dice.len() >= 5
+        core::leg_over(self.rolled_dice.len())
+    }
+
+    /// Rolls until the leg ends (a leg is 5 rolls, even with dice left in the pyramid)
+    pub fn rolls_left_in_leg(&self) -> usize {
+        core::rolls_left_in_leg(self.rolled_dice.len())
+    }
+
+    /// Whether the camel's die is still in the pyramid this leg
+    pub fn has_die(&self, color: CamelColor) -> bool {
+        self.dice
+            .iter()
+            .any(|die| matches!(die, PyramidDie::Regular(regular) if regular.color == color))
+    }
+
+    /// Whether the crazy camels' gray die is still in the pyramid this leg
+    pub fn has_crazy_die(&self) -> bool {
+        self.dice.iter().any(|die| matches!(die, PyramidDie::Crazy { .. }))
+    }
+
+    /// Chance that a die still in the pyramid comes out before the leg ends. Every die
+    /// left is equally likely to be one of the rolls still to come.
+    pub fn chance_to_roll(&self) -> f32 {
+        if self.dice.is_empty() {
+            return 0.0;
+        }
+        (self.rolls_left_in_leg() as f32 / self.dice.len() as f32).min(1.0)
     }
 
     pub fn remaining_dice_count(&self) -> usize {
@@ -1,15 +1,20 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use super::CamelColor;
+use super::{CamelColor, LegBetTile};
 use crate::ui::characters::CharacterId;
 
-#[derive(Resource)]
+/// A seat as `Players::new()` takes it: name, whether the AI plays it, character,
+/// palette color and the custom color picked instead, if any
+pub type SeatConfig = (String, bool, CharacterId, usize, Option<[u8; 3]>);
+
+#[derive(Resource, Clone, Serialize, Deserialize)]
 pub struct Players {
     pub players: Vec<PlayerData>,
     pub current_player_index: usize,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PlayerData {
     pub id: u8,
     pub name: String,
@@ -19,6 +24,11 @@ pub struct PlayerData {
     pub is_ai: bool,
     pub character_id: CharacterId,
     pub color_index: usize,
+    /// Color picked with the color picker, used instead of the `color_index` slot
+    #[serde(default)]
+    pub custom_color: Option<[u8; 3]>,
+    /// Player gave up; the AI plays out their seat and they rank last
+    pub conceded: bool,
 }
 
 impl PlayerData {
@@ -32,19 +42,22 @@ impl PlayerData {
             is_ai,
             character_id: CharacterId::from_index(id as usize),
             color_index: id as usize,
+            custom_color: None,
+            conceded: false,
         }
     }
 }
 
 impl Players {
-    pub fn new(player_configs: Vec<(String, bool, CharacterId, usize)>) -> Self {
+    pub fn new(player_configs: Vec<SeatConfig>) -> Self {
         let players = player_configs
             .into_iter()
             .enumerate()
-            .map(|(i, (name, is_ai, character_id, color_index))| {
+            .map(|(i, (name, is_ai, character_id, color_index, custom_color))| {
                 let mut player = PlayerData::new(i as u8, name, is_ai);
                 player.character_id = character_id;
                 player.color_index = color_index;
+                player.custom_color = custom_color;
                 player
             })
             .collect();
@@ -76,8 +89,54 @@ impl Default for Players {
     fn default() -> Self {
         // Default 2-player game for testing
         Self::new(vec![
-            ("Player 1".to_string(), false, CharacterId::from_index(0), 0),
-            ("Player 2 (AI)".to_string(), true, CharacterId::from_index(1), 1),
+            ("Player 1".to_string(), false, CharacterId::from_index(0), 0, None),
+            ("Player 2 (AI)".to_string(), true, CharacterId::from_index(1), 1, None),
         ])
     }
 }
+
+/// Neutral pseudo-player for the 2-player variant.
+/// After every die roll the Sheikh takes the top leg bet tile of the rolled camel,
+/// so two players can't share the best tiles between themselves.
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct SheikhBettor {
+    pub enabled: bool,
+    pub leg_bets: Vec<LegBetTile>,
+    pub money: i32,
+}
+
+impl SheikhBettor {
+    pub const NAME: &'static str = "The Sheikh";
+
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            leg_bets: Vec::new(),
+            money: 3, // Same starting money as a player
+        }
+    }
+}
+
+/// The current game turn state
+#[derive(Resource, Clone, Serialize, Deserialize)]
+pub struct TurnState {
+    pub current_player: usize,
+    pub action_taken: bool,
+    pub leg_number: u32,
+    pub awaiting_action: bool,
+    pub leg_has_started: bool, // Set to true after first action in a leg
+    pub turn_delay_timer: f32, // Timer to delay before advancing turn (for animations/pacing)
+}
+
+impl Default for TurnState {
+    fn default() -> Self {
+        Self {
+            current_player: 0,
+            action_taken: false,
+            leg_number: 1,
+            awaiting_action: true,
+            leg_has_started: false,
+            turn_delay_timer: 0.0,
+        }
+    }
+}
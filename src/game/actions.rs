@@ -0,0 +1,271 @@
+// Game actions
+// Everything that changes the table is a `GameAction`, applied by one reducer: `apply`.
+// The action handlers put what local players and the AI do through it, and online
+// clients and replays feed the host's logged actions through the same handlers, so a
+// roll, a bet or a leg's scoring is applied the same way however it arrived. The reducer
+// only sees plain game data: no entities, no animation and no randomness (a roll carries
+// its result), so the same actions on the same table always leave the same table.
+// Where the camels are is left to the movement systems, which play the roll by the
+// core rules once its dice animation is over.
+
+use serde::{Deserialize, Serialize};
+
+use crate::components::dice::DieRollResult;
+use crate::components::{
+    CamelColor, LegBetTile, LegBettingTiles, PlacedSpectatorTiles, PlayerLegBetsStore, PlayerPyramidTokens, Players,
+    Pyramid, RaceBet, RaceBets, SheikhBettor, TRACK_LENGTH,
+};
+use crate::game::core::{apply_payout, leg_tile_payout, race_bet_payouts, PYRAMID_TOKEN_PAYOUT};
+
+/// Something that happens in a game. Player actions also travel between online players:
+/// clients send their own actions to the host; the host logs every action it applies,
+/// with the die it rolled, and clients replay that log to play exactly the same game.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum GameAction {
+    /// The host clicked Start Game after the setup rolls
+    StartRace,
+    /// Only the host's log carries the result
+    RollPyramid { result: Option<DieRollResult> },
+    TakeLegBet { color: CamelColor },
+    PlaceSpectatorTile { space_index: u8, is_oasis: bool },
+    PlaceRaceBet { color: CamelColor, is_winner_bet: bool },
+    /// Score the leg and clear the table for the next one. Every player applies it as
+    /// the leg's scoring is dismissed, from the same rolls, so it's never logged.
+    EndLeg { first: Option<CamelColor>, second: Option<CamelColor> },
+    /// Pay out the race bets once a camel crosses the finish (never logged either)
+    EndGame { winner: Option<CamelColor>, loser: Option<CamelColor> },
+    /// The player gives up their seat to the AI
+    Concede { player_index: usize },
+    /// The host handed a seat whose player dropped out to the AI
+    ConvertToAi { player_index: usize },
+    /// The host gave a seat back to its player, who came back online
+    ReturnSeat { player_index: usize },
+    /// The player ran out of time, so the host rolls the pyramid for them next
+    TurnTimedOut { player_index: usize },
+    /// A client fell out of step and wants the host's full game state (never logged)
+    RequestState,
+}
+
+impl GameAction {
+    /// Actions that use up the current player's turn
+    pub fn is_turn_action(&self) -> bool {
+        matches!(
+            self,
+            GameAction::RollPyramid { .. }
+                | GameAction::TakeLegBet { .. }
+                | GameAction::PlaceSpectatorTile { .. }
+                | GameAction::PlaceRaceBet { .. }
+        )
+    }
+}
+
+/// The table actions are applied to: everything the rules keep track of besides where
+/// the camels are. Turn actions are taken by the current player.
+pub struct GameTable<'a> {
+    pub players: &'a mut Players,
+    pub pyramid: &'a mut Pyramid,
+    pub leg_tiles: &'a mut LegBettingTiles,
+    pub race_bets: &'a mut RaceBets,
+    pub placed_tiles: &'a mut PlacedSpectatorTiles,
+    pub leg_bets: &'a mut PlayerLegBetsStore,
+    pub pyramid_tokens: &'a mut PlayerPyramidTokens,
+    pub sheikh: &'a mut SheikhBettor,
+    /// Pyramid tickets a roll earns (2 during a Gold Rush)
+    pub tickets_per_roll: u8,
+}
+
+/// What an action did, for the handler that applied it to show and log
+#[derive(Clone, Debug)]
+pub enum Applied {
+    /// In 2-player games the Sheikh took the rolled camel's top leg tile
+    Rolled { sheikh_tile: Option<LegBetTile> },
+    LegBetTaken { tile: LegBetTile },
+    RaceBetPlaced,
+    SpectatorTilePlaced,
+    LegEnded,
+    /// Race bet payouts in the order they were scored, winner bets first
+    GameEnded { payouts: Vec<RacePayout> },
+    /// The seat changed hands between its player and the AI
+    SeatChanged,
+    /// Starting the race, running out of time and asking for the state leave the table
+    /// as it is
+    Unchanged,
+}
+
+/// One race bet's payout
+#[derive(Clone, Debug)]
+pub struct RacePayout {
+    pub player_index: usize,
+    pub bet: RaceBet,
+    pub is_winner_bet: bool,
+    pub payout: i32,
+    /// The player's money once it was paid
+    pub money: i32,
+}
+
+/// Why an action was turned down. The table is left as it was.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rejected {
+    /// A roll needs its result: the die is the host's (or the handler's) to roll
+    NoRollResult,
+    /// The rolled die already came out of the pyramid this leg
+    DieNotInPyramid,
+    NoLegTileLeft,
+    RaceCardNotInHand,
+    NoSpectatorTileInHand,
+    /// The start space, past the finish, or a space with a spectator tile on it
+    SpaceUnavailable,
+    /// No such seat, or it's already with the player (or the AI) the action hands it to
+    SeatUnchanged,
+}
+
+/// Apply an action to the table. Rules about the camels (no spectator tile next to
+/// one) and the leg's desert event (no tiles in a sandstorm) are the handler's to check.
+pub fn apply(table: &mut GameTable, action: &GameAction) -> Result<Applied, Rejected> {
+    match action {
+        GameAction::RollPyramid { result } => roll(table, result.as_ref().ok_or(Rejected::NoRollResult)?),
+        GameAction::TakeLegBet { color } => take_leg_bet(table, *color),
+        GameAction::PlaceSpectatorTile { space_index, is_oasis } => {
+            place_spectator_tile(table, *space_index, *is_oasis)
+        }
+        GameAction::PlaceRaceBet { color, is_winner_bet } => place_race_bet(table, *color, *is_winner_bet),
+        GameAction::EndLeg { first, second } => {
+            end_leg(table, *first, *second);
+            Ok(Applied::LegEnded)
+        }
+        GameAction::EndGame { winner, loser } => Ok(Applied::GameEnded { payouts: end_game(table, *winner, *loser) }),
+        GameAction::Concede { player_index } => {
+            let player = table.players.players.get_mut(*player_index).ok_or(Rejected::SeatUnchanged)?;
+            if player.conceded {
+                return Err(Rejected::SeatUnchanged);
+            }
+            player.conceded = true;
+            player.is_ai = true;
+            Ok(Applied::SeatChanged)
+        }
+        GameAction::ConvertToAi { player_index } => {
+            let player = table.players.players.get_mut(*player_index).ok_or(Rejected::SeatUnchanged)?;
+            if player.is_ai {
+                return Err(Rejected::SeatUnchanged);
+            }
+            player.is_ai = true;
+            Ok(Applied::SeatChanged)
+        }
+        // Conceded seats stay with the AI
+        GameAction::ReturnSeat { player_index } => {
+            let player = table.players.players.get_mut(*player_index).ok_or(Rejected::SeatUnchanged)?;
+            if !player.is_ai || player.conceded {
+                return Err(Rejected::SeatUnchanged);
+            }
+            player.is_ai = false;
+            Ok(Applied::SeatChanged)
+        }
+        GameAction::StartRace | GameAction::TurnTimedOut { .. } | GameAction::RequestState => Ok(Applied::Unchanged),
+    }
+}
+
+/// Take the rolled die out of the pyramid and pay the roller their tickets. Only racing
+/// camel dice earn pyramid tokens, and in 2-player games the Sheikh takes the rolled
+/// camel's top leg tile.
+fn roll(table: &mut GameTable, result: &DieRollResult) -> Result<Applied, Rejected> {
+    if !table.pyramid.apply_roll(result) {
+        return Err(Rejected::DieNotInPyramid);
+    }
+
+    let player_index = table.players.current_player_index;
+    let tickets = table.tickets_per_roll;
+    table.players.current_player_mut().money += tickets as i32 * PYRAMID_TOKEN_PAYOUT;
+
+    let mut sheikh_tile = None;
+    if let DieRollResult::Regular { color, .. } = *result {
+        for _ in 0..tickets {
+            table.pyramid_tokens.add_token(player_index);
+        }
+        if table.sheikh.enabled {
+            sheikh_tile = table.leg_tiles.take_tile(color);
+            table.sheikh.leg_bets.extend(sheikh_tile.clone());
+        }
+    }
+    Ok(Applied::Rolled { sheikh_tile })
+}
+
+fn take_leg_bet(table: &mut GameTable, color: CamelColor) -> Result<Applied, Rejected> {
+    let tile = table.leg_tiles.take_tile(color).ok_or(Rejected::NoLegTileLeft)?;
+    table.leg_bets.add_bet(table.players.current_player_index, tile.clone());
+    Ok(Applied::LegBetTaken { tile })
+}
+
+/// Place (or move) the current player's spectator tile
+fn place_spectator_tile(table: &mut GameTable, space_index: u8, is_oasis: bool) -> Result<Applied, Rejected> {
+    let player = table.players.current_player_mut();
+    if !player.has_spectator_tile {
+        return Err(Rejected::NoSpectatorTileInHand);
+    }
+    if space_index == 0 || space_index >= TRACK_LENGTH || table.placed_tiles.is_space_occupied(space_index) {
+        return Err(Rejected::SpaceUnavailable);
+    }
+
+    table.placed_tiles.remove_player_tile(player.id);
+    table.placed_tiles.place_tile(space_index, player.id, is_oasis);
+    player.has_spectator_tile = false;
+    Ok(Applied::SpectatorTilePlaced)
+}
+
+fn place_race_bet(table: &mut GameTable, color: CamelColor, is_winner_bet: bool) -> Result<Applied, Rejected> {
+    let player = table.players.current_player_mut();
+    if !player.available_race_cards.remove(&color) {
+        return Err(Rejected::RaceCardNotInHand);
+    }
+    if is_winner_bet {
+        table.race_bets.place_winner_bet(color, player.id);
+    } else {
+        table.race_bets.place_loser_bet(color, player.id);
+    }
+    Ok(Applied::RaceBetPlaced)
+}
+
+/// Pay out the leg bet tiles (the Sheikh's into its own purse), then put the dice, leg
+/// tiles and spectator tiles back for the next leg
+fn end_leg(table: &mut GameTable, first: Option<CamelColor>, second: Option<CamelColor>) {
+    for (player, tiles) in table.players.players.iter_mut().zip(&table.leg_bets.bets) {
+        for tile in tiles {
+            apply_payout(&mut player.money, leg_tile_payout(tile, first, second));
+        }
+    }
+    let sheikh_total: i32 = table.sheikh.leg_bets.iter().map(|tile| leg_tile_payout(tile, first, second)).sum();
+    apply_payout(&mut table.sheikh.money, sheikh_total);
+    table.sheikh.leg_bets.clear();
+
+    table.pyramid.reset();
+    table.leg_tiles.reset();
+    table.leg_bets.clear_all();
+    table.pyramid_tokens.clear_all();
+    table.placed_tiles.clear();
+    for player in table.players.players.iter_mut() {
+        player.has_spectator_tile = true;
+    }
+}
+
+/// Pay out the winner bets, then the loser bets
+fn end_game(table: &mut GameTable, winner: Option<CamelColor>, loser: Option<CamelColor>) -> Vec<RacePayout> {
+    let mut payouts = Vec::new();
+    for (bets, result, is_winner_bet) in [
+        (&table.race_bets.winner_bets, winner, true),
+        (&table.race_bets.loser_bets, loser, false),
+    ] {
+        for (bet, payout) in bets.iter().zip(race_bet_payouts(bets, result)) {
+            let players = &mut table.players.players;
+            let Some(player_index) = players.iter().position(|p| p.id == bet.player_id) else { continue };
+            let player = &mut players[player_index];
+            apply_payout(&mut player.money, payout);
+            payouts.push(RacePayout {
+                player_index,
+                bet: bet.clone(),
+                is_winner_bet,
+                payout,
+                money: player.money,
+            });
+        }
+    }
+    payouts
+}
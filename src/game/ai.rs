@@ -1,79 +1,298 @@
 // AI player logic for Camel Up
 // Phase 7 implementation
 
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
 use rand::Rng;
 
 use crate::components::*;
-use crate::systems::movement::get_leading_camel;
+use crate::game::ai_weights::AiWeights;
+use crate::game::desert_events::ActiveEvent;
+use crate::game::log::{GameEvent, GameLog};
+use crate::game::probability::{color_index, RaceBoard};
+use crate::game::rng::GameRng;
+use crate::game::simulation::{simulate, SimulationResult, DEFAULT_SAMPLES};
+use crate::network::state::NetworkState;
+use crate::systems::autoplay::AutoplayConfig;
 use crate::systems::turn::{
-    TurnState, RollPyramidAction, TakeLegBetAction, PlaceRaceBetAction, PlaceSpectatorTileAction,
+    PlayerActions, RollPyramidAction, TakeLegBetAction, PlaceRaceBetAction, PlaceSpectatorTileAction,
 };
 use crate::ui::hud::UiState;
 
 /// AI difficulty levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub enum AiDifficulty {
-    /// Picks random valid actions
-    Random,
-    /// Simple heuristics - bet on leaders, roll when unsure
+    /// Greedy heuristics (bet on the leader, roll when unsure) with frequent random picks
+    Easy,
+    /// Uses the probability engine, but blunts its values with noise
     #[default]
-    Basic,
-    /// Probability estimation and strategic play
-    Smart,
+    Medium,
+    /// Exact leg odds plus a Monte Carlo race simulation, picks the best expected value
+    Hard,
+}
+
+impl AiDifficulty {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AiDifficulty::Easy => "Easy",
+            AiDifficulty::Medium => "Medium",
+            AiDifficulty::Hard => "Hard",
+        }
+    }
+
+    /// Next difficulty when cycling through them in setup
+    pub fn next(&self) -> Self {
+        match self {
+            AiDifficulty::Easy => AiDifficulty::Medium,
+            AiDifficulty::Medium => AiDifficulty::Hard,
+            AiDifficulty::Hard => AiDifficulty::Easy,
+        }
+    }
+
+    /// Whether this difficulty needs the background race simulation
+    pub fn uses_odds(&self) -> bool {
+        !matches!(self, AiDifficulty::Easy)
+    }
 }
 
 /// Configuration for AI players
 #[derive(Resource)]
 pub struct AiConfig {
-    pub difficulty: AiDifficulty,
+    /// Difficulty of each AI player, by player id
+    pub player_difficulties: HashMap<u8, AiDifficulty>,
     /// Delay in seconds before AI takes action (so player can see what's happening)
     pub think_delay: f32,
 }
 
+impl AiConfig {
+    pub fn difficulty_for(&self, player_id: u8) -> AiDifficulty {
+        self.player_difficulties.get(&player_id).copied().unwrap_or_default()
+    }
+}
+
 impl Default for AiConfig {
     fn default() -> Self {
         Self {
-            difficulty: AiDifficulty::Basic,
+            player_difficulties: HashMap::new(),
             think_delay: 0.5, // 0.5 second delay
         }
     }
 }
 
-/// Tracks when the AI started "thinking" for the current turn
+/// Tracks when the AI started "thinking" for the current turn, and the background
+/// task choosing its action. On wasm the task pool runs tasks on the main thread
+/// between frames, so a long evaluation is spread out instead of stalling one frame.
 #[derive(Resource, Default)]
 pub struct AiThinkTimer {
     pub started: bool,
     pub elapsed: f32,
+    decision: Option<Task<(AiAction, String)>>,
+}
+
+impl AiThinkTimer {
+    /// Stop thinking, dropping (and so cancelling) any decision in progress
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether a decision has been started for this turn
+    fn is_deciding(&self) -> bool {
+        self.decision.is_some()
+    }
+
+    /// Start choosing an action in the background
+    fn start_deciding(&mut self, choose: impl FnOnce() -> (AiAction, String) + Send + 'static) {
+        self.decision = Some(AsyncComputeTaskPool::get().spawn(async move { choose() }));
+    }
+
+    /// The chosen action and its reason, once the background task has finished
+    fn poll_decision(&mut self) -> Option<(AiAction, String)> {
+        let task = self.decision.as_mut()?;
+        let result = block_on(future::poll_once(task))?;
+        self.decision = None;
+        Some(result)
+    }
+}
+
+/// How much better than the alternatives a race bet has to be before it's worth
+/// playing now rather than waiting. A race card spent now can't be used later with
+/// better information, so waiting pays off while several legs remain and the field
+/// can still change, but a card still in hand when the race ends is worthless, so
+/// the margin shrinks to nothing as the finish gets close.
+fn race_bet_patience(odds: &SimulationResult, weights: &AiWeights) -> f32 {
+    let waiting = (odds.legs_remaining * weights.race_bet_patience_per_leg).min(weights.max_race_bet_patience);
+    waiting * (1.0 - odds.finishes_this_leg)
+}
+
+/// Who wants each camel to do well: positive when the deciding player has money on it,
+/// negative when human opponents do. Lets spectator tiles set back the camels humans
+/// bet on (mirage) and carry the AI's own forward (oasis).
+#[derive(Clone, Debug, Default)]
+pub struct CamelStakes {
+    by_camel: [f32; 5],
+}
+
+impl CamelStakes {
+    /// Stakes for `player_index` from this leg's tiles and the race bets on the table.
+    /// Other AI players' bets are ignored: the AIs don't gang up on each other.
+    pub fn new(
+        player_index: usize,
+        players: &[PlayerData],
+        leg_bets: &[Vec<LegBetTile>],
+        race_bets: &RaceBets,
+        weights: &AiWeights,
+    ) -> Self {
+        let side = |owner: usize| {
+            if owner == player_index {
+                1.0
+            } else if players.get(owner).is_some_and(|p| !p.is_ai) {
+                -1.0
+            } else {
+                0.0
+            }
+        };
+
+        let mut stakes = Self::default();
+        for (owner, tiles) in leg_bets.iter().enumerate() {
+            for tile in tiles {
+                stakes.by_camel[color_index(tile.camel)] += side(owner) * tile.value as f32;
+            }
+        }
+        for (bets, direction) in [(&race_bets.winner_bets, 1.0), (&race_bets.loser_bets, -1.0)] {
+            for bet in bets {
+                let owner = players.iter().position(|p| p.id == bet.player_id).unwrap_or(usize::MAX);
+                stakes.by_camel[color_index(bet.camel)] += side(owner) * direction * weights.race_bet_stake;
+            }
+        }
+        stakes
+    }
+
+    /// Extra value of a tile on `space` from where it pushes the camels that land on it,
+    /// with the camel that matters most
+    fn tile_swing(
+        &self,
+        odds: &SimulationResult,
+        weights: &AiWeights,
+        space: u8,
+        is_oasis: bool,
+    ) -> (f32, Option<CamelColor>) {
+        let direction = if is_oasis { 1.0 } else { -1.0 };
+        let mut total = 0.0;
+        let mut biggest: Option<(CamelColor, f32)> = None;
+        for color in CamelColor::all() {
+            let i = color_index(color);
+            let landings = odds.leg_landings_by_camel[i].get(space as usize).copied().unwrap_or(0.0);
+            let swing = self.by_camel[i] * landings * direction * weights.tile_swing_per_landing;
+            total += swing;
+            if biggest.is_none_or(|(_, best)| swing > best) {
+                biggest = Some((color, swing));
+            }
+        }
+        (total, biggest.filter(|(_, swing)| *swing > 0.05).map(|(color, _)| color))
+    }
+}
+
+/// Leg and race odds for the current board, calculated in the background for the AI.
+/// Odds only change when the board does, so results are reused until then.
+#[derive(Resource, Default)]
+pub struct AiSimulation {
+    task: Option<Task<SimulationResult>>,
+    result: Option<SimulationResult>,
+    /// Board the simulation belongs to (leg, dice rolled, spectator tiles placed)
+    board_key: Option<(u32, usize, usize)>,
+}
+
+impl AiSimulation {
+    /// Start simulating a new board, or return the odds once the simulation is done.
+    /// The sampled futures are drawn from the game's rng, so the odds are reproducible.
+    fn poll(
+        &mut self,
+        board_key: (u32, usize, usize),
+        board: impl FnOnce() -> RaceBoard,
+        game_rng: &mut GameRng,
+    ) -> Option<&SimulationResult> {
+        if self.board_key != Some(board_key) {
+            let board = board();
+            let mut rng = game_rng.fork();
+            self.task = Some(
+                AsyncComputeTaskPool::get()
+                    .spawn(async move { simulate(&board, DEFAULT_SAMPLES, &mut rng) }),
+            );
+            self.result = None;
+            self.board_key = Some(board_key);
+        }
+
+        if let Some(task) = self.task.as_mut() {
+            if let Some(result) = block_on(future::poll_once(task)) {
+                self.result = Some(result);
+                self.task = None;
+            }
+        }
+        self.result.as_ref()
+    }
 }
 
 /// Available actions the AI can choose from
 #[derive(Debug, Clone)]
-enum AiAction {
+pub enum AiAction {
     RollPyramid,
     TakeLegBet(CamelColor),
     PlaceRaceBet { color: CamelColor, is_winner: bool },
     PlaceSpectatorTile { space: u8, is_oasis: bool },
 }
 
+/// The table as the AI sees it when choosing what to do
+#[derive(SystemParam)]
+pub struct AiTableView<'w, 's> {
+    players: Res<'w, Players>,
+    turn_state: Res<'w, TurnState>,
+    pyramid: Res<'w, Pyramid>,
+    placed_tiles: Res<'w, PlacedSpectatorTiles>,
+    leg_tiles: Res<'w, LegBettingTiles>,
+    race_bets: Res<'w, RaceBets>,
+    player_leg_bets: Option<Res<'w, PlayerLegBetsStore>>,
+    active_event: Res<'w, ActiveEvent>,
+    camels: Query<'w, 's, (&'static Camel, &'static BoardPosition)>,
+    crazy_camels: Query<'w, 's, (&'static CrazyCamel, &'static BoardPosition)>,
+}
+
+impl AiTableView<'_, '_> {
+    fn race_board(&self) -> RaceBoard {
+        RaceBoard::from_world(&self.camels, &self.crazy_camels, &self.pyramid, &self.placed_tiles)
+    }
+}
+
+/// The AI's settings and what it carries between frames while it thinks: the think
+/// timer, the decision being worked out and the race simulation behind its odds
+#[derive(SystemParam)]
+pub struct AiThinking<'w> {
+    config: Res<'w, AiConfig>,
+    weights: Res<'w, AiWeights>,
+    autoplay: Res<'w, AutoplayConfig>,
+    timer: ResMut<'w, AiThinkTimer>,
+    simulation: ResMut<'w, AiSimulation>,
+    game_rng: ResMut<'w, GameRng>,
+}
+
 /// Main AI decision system - runs when it's an AI player's turn
 pub fn ai_decision_system(
-    players: Res<Players>,
-    turn_state: Res<TurnState>,
-    ai_config: Res<AiConfig>,
-    mut ai_timer: ResMut<AiThinkTimer>,
+    table: AiTableView,
+    mut thinking: AiThinking,
     time: Res<Time>,
-    camels: Query<(&Camel, &BoardPosition)>,
-    crazy_camels: Query<(&CrazyCamel, &BoardPosition)>,
-    leg_tiles: Res<LegBettingTiles>,
-    pyramid: Res<Pyramid>,
-    placed_tiles: Res<PlacedSpectatorTiles>,
     ui_state: Res<UiState>,
-    mut roll_action: MessageWriter<RollPyramidAction>,
-    mut leg_bet_action: MessageWriter<TakeLegBetAction>,
-    mut race_bet_action: MessageWriter<PlaceRaceBetAction>,
-    mut spectator_action: MessageWriter<PlaceSpectatorTileAction>,
+    mut game_log: ResMut<GameLog>,
+    mut actions: PlayerActions,
+    network_state: Res<NetworkState>,
 ) {
+    // Online, AI seats are played by the host; clients replay its choices. A replay
+    // already has them.
+    if network_state.follows_log() {
+        return;
+    }
+
     // Don't act during initial roll animations
     if !ui_state.initial_rolls_complete {
         return;
@@ -81,87 +300,112 @@ pub fn ai_decision_system(
 
     // Don't act while leg scoring modal is showing
     if ui_state.show_leg_scoring {
-        ai_timer.started = false;
-        ai_timer.elapsed = 0.0;
+        thinking.timer.reset();
         return;
     }
 
     // Only act if it's an AI player's turn and no action taken yet
-    if turn_state.action_taken {
+    if table.turn_state.action_taken {
         // Reset timer when action is taken
-        ai_timer.started = false;
-        ai_timer.elapsed = 0.0;
+        thinking.timer.reset();
         return;
     }
 
-    let current = players.current_player();
+    let current = table.players.current_player();
     if !current.is_ai {
         // Reset timer for human players
-        ai_timer.started = false;
-        ai_timer.elapsed = 0.0;
+        thinking.timer.reset();
         return;
     }
 
+    // Medium and Hard AIs simulate the race in the background while "thinking"
+    let difficulty = thinking.config.difficulty_for(current.id);
+    let odds = if difficulty.uses_odds() {
+        let board_key = (table.turn_state.leg_number, table.pyramid.rolled_dice.len(), table.placed_tiles.tiles.len());
+        thinking
+            .simulation
+            .poll(board_key, || table.race_board(), &mut thinking.game_rng)
+            .cloned()
+    } else {
+        None
+    };
+
     // Start or update the think timer
-    if !ai_timer.started {
-        ai_timer.started = true;
-        ai_timer.elapsed = 0.0;
+    if !thinking.timer.started {
+        thinking.timer.started = true;
+        thinking.timer.elapsed = 0.0;
         return; // Wait until next frame to start counting
     }
 
-    ai_timer.elapsed += time.delta_secs();
+    thinking.timer.elapsed += time.delta_secs();
 
-    // Wait for the think delay before taking action
-    if ai_timer.elapsed < ai_config.think_delay {
-        return;
-    }
+    // Hand the choice to a background task once the odds it needs are ready.
+    // It works on a snapshot of the board, so nothing here has to wait for it.
+    if !thinking.timer.is_deciding() {
+        let board = table.race_board();
+        let mut available_actions = collect_available_actions(current, &board, &table.leg_tiles);
 
-    // Collect available actions
-    let available_actions = collect_available_actions(
-        current,
-        &camels,
-        &crazy_camels,
-        &leg_tiles,
-        &pyramid,
-        &placed_tiles,
-    );
+        // Desert Events: no spectator tiles during a sandstorm
+        if table.active_event.spectator_tiles_banned() {
+            available_actions.retain(|action| !matches!(action, AiAction::PlaceSpectatorTile { .. }));
+        }
+
+        if available_actions.is_empty() {
+            // Fallback: always can roll pyramid (unless all dice rolled, but then leg ends)
+            actions.roll.write(RollPyramidAction { result: None });
+            return;
+        }
+
+        // Keep thinking until the simulation has finished
+        if difficulty.uses_odds() && odds.is_none() {
+            return;
+        }
 
-    if available_actions.is_empty() {
-        // Fallback: always can roll pyramid (unless all dice rolled, but then leg ends)
-        roll_action.write(RollPyramidAction);
+        let leg_bets = table.player_leg_bets.as_ref().map_or(&[][..], |store| &store.bets[..]);
+        let weights = thinking.weights.clone();
+        let stakes = CamelStakes::new(table.players.current_player_index, &table.players.players, leg_bets, &table.race_bets, &weights);
+        let names = game_log.camel_names.clone();
+        let leg_tiles = table.leg_tiles.clone();
+        let race_bets = table.race_bets.clone();
+        let mut rng = thinking.game_rng.fork();
+
+        thinking.timer.start_deciding(move || {
+            let table = AiTable {
+                board: &board,
+                leg_tiles: &leg_tiles,
+                race_bets: &race_bets,
+                stakes: &stakes,
+                names: &names,
+            };
+            choose_action(difficulty, &available_actions, odds.as_ref(), table, &weights, &mut rng)
+        });
+    }
+
+    // Wait for the think delay before taking action
+    if thinking.timer.elapsed < thinking.autoplay.think_delay(thinking.config.think_delay) {
         return;
     }
 
-    // Choose action based on difficulty
-    let chosen_action = match ai_config.difficulty {
-        AiDifficulty::Random => choose_random_action(&available_actions),
-        AiDifficulty::Basic => choose_basic_action(&available_actions, &camels, &leg_tiles, &pyramid),
-        AiDifficulty::Smart => choose_smart_action(&available_actions, &camels, &leg_tiles, &pyramid, current),
-    };
+    let Some((chosen_action, reason)) = thinking.timer.poll_decision() else { return };
+
+    // Explain the choice in the log before the action itself is recorded
+    let player = current.name.clone();
+    game_log.record(table.turn_state.leg_number, GameEvent::AiReasoning { player, reason });
 
     // Execute the chosen action
-    execute_action(
-        chosen_action,
-        &mut roll_action,
-        &mut leg_bet_action,
-        &mut race_bet_action,
-        &mut spectator_action,
-    );
+    execute_action(chosen_action, &mut actions);
 }
 
-/// Collect all valid actions the AI can take
-fn collect_available_actions(
+/// Collect all valid actions the player can take on this board
+pub fn collect_available_actions(
     player: &PlayerData,
-    camels: &Query<(&Camel, &BoardPosition)>,
-    crazy_camels: &Query<(&CrazyCamel, &BoardPosition)>,
+    board: &RaceBoard,
     leg_tiles: &LegBettingTiles,
-    pyramid: &Pyramid,
-    placed_tiles: &PlacedSpectatorTiles,
 ) -> Vec<AiAction> {
     let mut actions = Vec::new();
 
     // Can always roll pyramid if dice remain
-    if !pyramid.all_dice_rolled() {
+    if board.dice_left_to_roll > 0 {
         actions.push(AiAction::RollPyramid);
     }
 
@@ -180,8 +424,7 @@ fn collect_available_actions(
 
     // Check spectator tile placement
     if player.has_spectator_tile {
-        let valid_spaces = get_valid_spectator_spaces(camels, crazy_camels, placed_tiles);
-        for space in valid_spaces {
+        for space in board.valid_spectator_spaces() {
             actions.push(AiAction::PlaceSpectatorTile { space, is_oasis: true });
             actions.push(AiAction::PlaceSpectatorTile { space, is_oasis: false });
         }
@@ -190,53 +433,65 @@ fn collect_available_actions(
     actions
 }
 
-/// Get valid spaces where a spectator tile can be placed
-fn get_valid_spectator_spaces(
-    camels: &Query<(&Camel, &BoardPosition)>,
-    crazy_camels: &Query<(&CrazyCamel, &BoardPosition)>,
-    placed_tiles: &PlacedSpectatorTiles,
-) -> Vec<u8> {
-    let mut valid = Vec::new();
-
-    // Check spaces 1-15 (can't place on 0)
-    for space in 1..TRACK_LENGTH {
-        // Can't place if there's already a tile
-        if placed_tiles.is_space_occupied(space) {
-            continue;
-        }
+/// The table as an AI weighs its choice: the race, the bets still on offer, its own
+/// stakes in each camel and the camels' names for the reasons it gives
+#[derive(Clone, Copy)]
+pub struct AiTable<'a> {
+    pub board: &'a RaceBoard,
+    pub leg_tiles: &'a LegBettingTiles,
+    pub race_bets: &'a RaceBets,
+    pub stakes: &'a CamelStakes,
+    pub names: &'a CamelNames,
+}
 
-        // Can't place if there's a camel on the space (including crazy camels)
-        let has_camel = camels.iter().any(|(_, pos)| pos.space_index == space);
-        let has_crazy_camel = crazy_camels.iter().any(|(_, pos)| pos.space_index == space);
-        if has_camel || has_crazy_camel {
-            continue;
+/// Choose an action the way an AI of this difficulty would.
+/// `odds` are needed for Medium and Hard; without them every AI plays like Easy.
+/// Any randomness (Easy's whims, Medium's noise) is drawn from `rng`.
+/// Returns the action with the reason it was picked.
+pub fn choose_action(
+    difficulty: AiDifficulty,
+    actions: &[AiAction],
+    odds: Option<&SimulationResult>,
+    table: AiTable,
+    weights: &AiWeights,
+    rng: &mut impl Rng,
+) -> (AiAction, String) {
+    let AiTable { board, leg_tiles, names, .. } = table;
+    match (difficulty, odds) {
+        (AiDifficulty::Medium | AiDifficulty::Hard, Some(odds)) => {
+            let noise = if difficulty == AiDifficulty::Medium { weights.medium_value_noise } else { 0.0 };
+            choose_smart_action(actions, odds, table, weights, noise, rng)
+        }
+        _ => {
+            if rng.gen_bool(weights.easy_random_chance) {
+                let action = choose_random_action(actions, rng);
+                let reason = format!("{} on a whim", describe_action(&action, names));
+                (action, reason)
+            } else {
+                let leader = board.rankings().first().copied();
+                choose_basic_action(actions, leader, leg_tiles, board.remaining_dice.len(), weights, names, rng)
+            }
         }
-
-        valid.push(space);
     }
-
-    valid
 }
 
-/// Random AI: Pick any action randomly
-fn choose_random_action(actions: &[AiAction]) -> AiAction {
-    let mut rng = rand::thread_rng();
+/// Pick any action randomly
+fn choose_random_action(actions: &[AiAction], rng: &mut impl Rng) -> AiAction {
     let index = rng.gen_range(0..actions.len());
     actions[index].clone()
 }
 
-/// Basic AI: Simple heuristics
+/// Greedy heuristics: bet on the current leader, otherwise roll.
+/// Returns the action with the reason it was picked.
 fn choose_basic_action(
     actions: &[AiAction],
-    camels: &Query<(&Camel, &BoardPosition)>,
+    leader: Option<CamelColor>,
     leg_tiles: &LegBettingTiles,
-    pyramid: &Pyramid,
-) -> AiAction {
-    let mut rng = rand::thread_rng();
-
-    // Get leading camel
-    let leader = get_leading_camel(camels);
-
+    dice_remaining: usize,
+    weights: &AiWeights,
+    names: &CamelNames,
+    rng: &mut impl Rng,
+) -> (AiAction, String) {
     // Priority 1: If leader has 5-value tile, take it
     if let Some(leader_color) = leader {
         if let Some(tile) = leg_tiles.top_tile(leader_color) {
@@ -244,7 +499,9 @@ fn choose_basic_action(
                 for action in actions {
                     if let AiAction::TakeLegBet(color) = action {
                         if *color == leader_color {
-                            return action.clone();
+                            let name = names.name(leader_color);
+                            let reason = format!("{} because {} leads and its $5 tile was still there", describe_action(action, names), name);
+                            return (action.clone(), reason);
                         }
                     }
                 }
@@ -252,13 +509,14 @@ fn choose_basic_action(
         }
     }
 
-    // Priority 2: 50% chance to take leg bet on leader if available
+    // Priority 2: Sometimes take leg bet on leader if available
     if let Some(leader_color) = leader {
-        if rng.gen_bool(0.5) {
+        if rng.gen_bool(weights.easy_leader_bet_chance) {
             for action in actions {
                 if let AiAction::TakeLegBet(color) = action {
                     if *color == leader_color {
-                        return action.clone();
+                        let reason = format!("{} because {} leads", describe_action(action, names), names.name(leader_color));
+                        return (action.clone(), reason);
                     }
                 }
             }
@@ -266,14 +524,18 @@ fn choose_basic_action(
     }
 
     // Priority 3: If most dice rolled (leg ending soon), consider race bets
-    let dice_remaining = pyramid.remaining_dice_count();
-    if dice_remaining <= 2 {
+    if dice_remaining <= weights.easy_race_bet_dice_left {
         // Consider placing race bets
         if let Some(leader_color) = leader {
             for action in actions {
                 if let AiAction::PlaceRaceBet { color, is_winner: true } = action {
-                    if *color == leader_color && rng.gen_bool(0.3) {
-                        return action.clone();
+                    if *color == leader_color && rng.gen_bool(weights.easy_race_bet_chance) {
+                        let reason = format!(
+                            "{} because {} leads with the leg nearly over",
+                            describe_action(action, names),
+                            names.name(leader_color)
+                        );
+                        return (action.clone(), reason);
                     }
                 }
             }
@@ -283,234 +545,200 @@ fn choose_basic_action(
     // Default: Roll pyramid die (guaranteed +1 coin)
     for action in actions {
         if matches!(action, AiAction::RollPyramid) {
-            return action.clone();
+            return (action.clone(), "Rolled the pyramid for a sure $1".to_string());
         }
     }
 
     // Fallback: random action
-    choose_random_action(actions)
+    let action = choose_random_action(actions, rng);
+    let reason = format!("{} with nothing better to do", describe_action(&action, names));
+    (action, reason)
 }
 
-/// Smart AI: Probability-based decision making
+/// Pick the action with the highest expected value under the simulated odds.
+/// Each value is shifted by up to `noise` dollars either way to make weaker players.
+/// Returns the action with an explanation built from the odds behind it.
 fn choose_smart_action(
     actions: &[AiAction],
-    camels: &Query<(&Camel, &BoardPosition)>,
-    leg_tiles: &LegBettingTiles,
-    pyramid: &Pyramid,
-    player: &PlayerData,
-) -> AiAction {
-    let mut rng = rand::thread_rng();
-
-    // Get camel rankings
-    let rankings = get_camel_rankings(camels);
-    let leader = rankings.first().map(|(c, _, _)| *c);
-    let last = rankings.last().map(|(c, _, _)| *c);
-
-    // Get unrolled dice colors (camels that can still move)
-    let unrolled_colors: Vec<CamelColor> = pyramid.dice.iter()
-        .filter_map(|d| match d {
-            crate::components::dice::PyramidDie::Regular(die) => Some(die.color),
-            crate::components::dice::PyramidDie::Crazy { .. } => None,
+    odds: &SimulationResult,
+    table: AiTable,
+    weights: &AiWeights,
+    noise: f32,
+    rng: &mut impl Rng,
+) -> (AiAction, String) {
+    let AiTable { leg_tiles, race_bets, stakes, names, .. } = table;
+    let mut scored: Vec<(&AiAction, f32)> = actions
+        .iter()
+        .map(|action| {
+            let mut value = expected_value(action, odds, leg_tiles, race_bets, stakes, weights);
+            if noise > 0.0 {
+                value += rng.gen_range(-noise..=noise);
+            }
+            (action, value)
         })
         .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
 
-    // Calculate expected values for leg bets
-    let mut best_leg_bet: Option<(AiAction, f32)> = None;
-
-    for action in actions {
-        if let AiAction::TakeLegBet(color) = action {
-            if let Some(tile) = leg_tiles.top_tile(*color) {
-                let ev = calculate_leg_bet_ev(*color, tile.value, &rankings, &unrolled_colors);
-                if ev > 0.5 {
-                    // Only consider positive expected value bets
-                    if best_leg_bet.is_none() || ev > best_leg_bet.as_ref().unwrap().1 {
-                        best_leg_bet = Some((action.clone(), ev));
-                    }
-                }
-            }
-        }
+    let Some(&(best, _)) = scored.first() else {
+        return (AiAction::RollPyramid, "Rolled the pyramid for a sure $1".to_string());
+    };
+    let mut reason = format!(
+        "{} because {}",
+        describe_action(best, names),
+        explain_odds(best, odds, leg_tiles, race_bets, stakes, weights, names)
+    );
+    if let Some(&(runner_up, _)) = scored.get(1) {
+        reason.push_str(&format!(
+            " (next best: {}, {})",
+            describe_action(runner_up, names).to_lowercase(),
+            explain_odds(runner_up, odds, leg_tiles, race_bets, stakes, weights, names)
+        ));
     }
+    (best.clone(), reason)
+}
 
-    // If we have a good leg bet (EV > 1.5), take it
-    if let Some((action, ev)) = &best_leg_bet {
-        if *ev > 1.5 {
-            return action.clone();
+/// What the action does, as the start of a log sentence
+fn describe_action(action: &AiAction, names: &CamelNames) -> String {
+    match action {
+        AiAction::RollPyramid => "Rolled the pyramid".to_string(),
+        AiAction::TakeLegBet(color) => format!("Took the {} leg bet", names.name(*color)),
+        AiAction::PlaceRaceBet { color, is_winner } => {
+            let outcome = if *is_winner { "win" } else { "lose" };
+            format!("Bet on {} to {} the race", names.name(*color), outcome)
+        }
+        AiAction::PlaceSpectatorTile { space, is_oasis } => {
+            let tile_type = if *is_oasis { "an oasis" } else { "a mirage" };
+            format!("Placed {} on space {}", tile_type, space + 1)
         }
     }
+}
 
-    // Consider race bets in later stages
-    let dice_rolled = 5 - pyramid.remaining_dice_count();
-    let game_progress = dice_rolled as f32 / 5.0;
-
-    // Only consider race bets if we've seen some dice and have strong leader
-    if game_progress > 0.4 && !player.available_race_cards.is_empty() {
-        if let Some(leader_color) = leader {
-            // Check if leader is far ahead
-            if let Some((_, leader_space, _)) = rankings.first() {
-                if let Some((_, second_space, _)) = rankings.get(1) {
-                    let lead = leader_space - second_space;
-                    if lead >= 2 && rng.gen_bool(0.4) {
-                        // Strong lead - consider winner bet
-                        for action in actions {
-                            if let AiAction::PlaceRaceBet { color, is_winner: true } = action {
-                                if *color == leader_color {
-                                    return action.clone();
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+/// The odds and expected money behind an action
+fn explain_odds(
+    action: &AiAction,
+    odds: &SimulationResult,
+    leg_tiles: &LegBettingTiles,
+    race_bets: &RaceBets,
+    stakes: &CamelStakes,
+    weights: &AiWeights,
+    names: &CamelNames,
+) -> String {
+    match action {
+        AiAction::RollPyramid => "it pays a sure $1".to_string(),
+        AiAction::TakeLegBet(color) => {
+            let value = leg_tiles.top_tile(*color).map_or(0.0, |tile| odds.leg_bet_value(*color, tile.value));
+            format!(
+                "{} is {:.0}% to win the leg (worth {})",
+                names.name(*color),
+                odds.leg_first(*color) * 100.0,
+                format_money(value)
+            )
         }
-
-        // Consider loser bets on last place camel
-        if let Some(last_color) = last {
-            if let Some((_, last_space, _)) = rankings.last() {
-                if let Some((_, second_last_space, _)) = rankings.get(rankings.len().saturating_sub(2)) {
-                    let behind = second_last_space - last_space;
-                    if behind >= 2 && rng.gen_bool(0.3) {
-                        for action in actions {
-                            if let AiAction::PlaceRaceBet { color, is_winner: false } = action {
-                                if *color == last_color {
-                                    return action.clone();
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        AiAction::PlaceRaceBet { color, is_winner } => {
+            let (p, bets_placed, outcome) = if *is_winner {
+                (odds.race_winner(*color), race_bets.winner_bets.len(), "win")
+            } else {
+                (odds.race_loser(*color), race_bets.loser_bets.len(), "lose")
+            };
+            let timing = if odds.finishes_this_leg >= 0.5 {
+                "the race should end this leg".to_string()
+            } else {
+                format!("about {:.1} legs to go", odds.legs_remaining)
+            };
+            format!(
+                "{} is {:.0}% to {} the race (worth {}, {})",
+                names.name(*color),
+                p * 100.0,
+                outcome,
+                format_money(odds.race_bet_value(*color, *is_winner, bets_placed)),
+                timing
+            )
         }
-    }
-
-    // Take good leg bet if available
-    if let Some((action, _)) = best_leg_bet {
-        return action;
-    }
-
-    // Consider strategic spectator tile placement
-    if player.has_spectator_tile {
-        if let Some(_leader_color) = leader {
-            if let Some((_, leader_space, _)) = rankings.first() {
-                // Place oasis 2-3 spaces ahead of leader
-                let target_space = leader_space + 2;
-                if target_space < TRACK_LENGTH {
-                    for action in actions {
-                        if let AiAction::PlaceSpectatorTile { space, is_oasis: true } = action {
-                            if *space == target_space || *space == target_space + 1 {
-                                if rng.gen_bool(0.3) {
-                                    return action.clone();
-                                }
-                            }
-                        }
-                    }
-                }
+        AiAction::PlaceSpectatorTile { space, is_oasis } => {
+            let landings = odds.leg_landings.get(*space as usize).copied().unwrap_or(0.0);
+            let mut reason = format!("camels should land there {:.1} times this leg", landings);
+            if let (_, Some(color)) = stakes.tile_swing(odds, weights, *space, *is_oasis) {
+                let push = if *is_oasis {
+                    format!(", carrying {} forward", names.name(color))
+                } else {
+                    format!(", setting back {}", names.name(color))
+                };
+                reason.push_str(&push);
             }
+            reason
         }
     }
-
-    // Default: Roll pyramid
-    for action in actions {
-        if matches!(action, AiAction::RollPyramid) {
-            return action.clone();
-        }
-    }
-
-    choose_random_action(actions)
 }
 
-/// Get camel rankings sorted by position (first = leader)
-fn get_camel_rankings(camels: &Query<(&Camel, &BoardPosition)>) -> Vec<(CamelColor, u8, u8)> {
-    let mut rankings: Vec<(CamelColor, u8, u8)> = camels
-        .iter()
-        .map(|(c, p)| (c.color, p.space_index, p.stack_position))
-        .collect();
-
-    // Sort by space descending, then stack position descending
-    rankings.sort_by(|a, b| {
-        b.1.cmp(&a.1).then(b.2.cmp(&a.2))
-    });
-
-    rankings
+/// Signed dollar amount, e.g. "+$1.8"
+fn format_money(value: f32) -> String {
+    let sign = if value < 0.0 { "-" } else { "+" };
+    format!("{}${:.1}", sign, value.abs())
 }
 
-/// Calculate expected value of a leg bet
-fn calculate_leg_bet_ev(
-    color: CamelColor,
-    tile_value: u8,
-    rankings: &[(CamelColor, u8, u8)],
-    unrolled_colors: &[CamelColor],
+/// Expected money from taking an action now
+fn expected_value(
+    action: &AiAction,
+    odds: &SimulationResult,
+    leg_tiles: &LegBettingTiles,
+    race_bets: &RaceBets,
+    stakes: &CamelStakes,
+    weights: &AiWeights,
 ) -> f32 {
-    // Find current position
-    let position = rankings.iter().position(|(c, _, _)| *c == color);
-    let Some(pos) = position else { return -1.0 };
-
-    // Check if this camel can still move
-    let can_move = unrolled_colors.contains(&color);
-
-    // Simple probability estimation
-    let p_first: f32;
-    let p_second: f32;
-
-    match pos {
-        0 => {
-            // Currently first
-            p_first = if can_move { 0.7 } else { 0.5 };
-            p_second = if can_move { 0.2 } else { 0.3 };
-        }
-        1 => {
-            // Currently second
-            p_first = if can_move { 0.35 } else { 0.25 };
-            p_second = if can_move { 0.35 } else { 0.35 };
+    match action {
+        // Guaranteed pyramid ticket
+        AiAction::RollPyramid => 1.0,
+        // A leg bet loses $1 unless its camel comes first or second
+        AiAction::TakeLegBet(color) => {
+            let Some(tile) = leg_tiles.top_tile(*color) else { return f32::MIN };
+            let losing = 1.0 - odds.leg_first(*color) - odds.leg_second(*color);
+            odds.leg_bet_value(*color, tile.value) - weights.risk_aversion * losing
         }
-        2 => {
-            // Currently third
-            p_first = if can_move { 0.15 } else { 0.1 };
-            p_second = if can_move { 0.25 } else { 0.2 };
+        AiAction::PlaceRaceBet { color, is_winner } => {
+            let (p, bets_placed) = if *is_winner {
+                (odds.race_winner(*color), race_bets.winner_bets.len())
+            } else {
+                (odds.race_loser(*color), race_bets.loser_bets.len())
+            };
+            odds.race_bet_value(*color, *is_winner, bets_placed)
+                - race_bet_patience(odds, weights)
+                - weights.risk_aversion * (1.0 - p)
         }
-        _ => {
-            // Fourth or fifth
-            p_first = if can_move { 0.08 } else { 0.05 };
-            p_second = if can_move { 0.15 } else { 0.1 };
+        // Spectator tiles pay $1 every time a camel lands on them, and push that camel
+        AiAction::PlaceSpectatorTile { space, is_oasis } => {
+            let landings = odds.leg_landings.get(*space as usize).copied().unwrap_or(0.0);
+            landings + stakes.tile_swing(odds, weights, *space, *is_oasis).0
         }
     }
-
-    let p_other = 1.0 - p_first - p_second;
-
-    // EV = P(1st) * tile_value + P(2nd) * 1 - P(other) * 1
-    p_first * tile_value as f32 + p_second * 1.0 - p_other * 1.0
 }
 
 /// Execute the chosen AI action
-fn execute_action(
-    action: AiAction,
-    roll_action: &mut MessageWriter<RollPyramidAction>,
-    leg_bet_action: &mut MessageWriter<TakeLegBetAction>,
-    race_bet_action: &mut MessageWriter<PlaceRaceBetAction>,
-    spectator_action: &mut MessageWriter<PlaceSpectatorTileAction>,
-) {
+fn execute_action(action: AiAction, actions: &mut PlayerActions) {
     match action {
         AiAction::RollPyramid => {
             info!("AI chose to roll pyramid");
-            roll_action.write(RollPyramidAction);
+            actions.roll.write(RollPyramidAction { result: None });
         }
         AiAction::TakeLegBet(color) => {
             info!("AI chose to take {:?} leg bet", color);
-            leg_bet_action.write(TakeLegBetAction { color });
+            actions.leg_bet.write(TakeLegBetAction { color, from_host: false });
         }
         AiAction::PlaceRaceBet { color, is_winner } => {
             let bet_type = if is_winner { "winner" } else { "loser" };
             info!("AI chose to bet on {:?} as {}", color, bet_type);
-            race_bet_action.write(PlaceRaceBetAction {
+            actions.race_bet.write(PlaceRaceBetAction {
                 color,
                 is_winner_bet: is_winner,
+                from_host: false,
             });
         }
         AiAction::PlaceSpectatorTile { space, is_oasis } => {
             let tile_type = if is_oasis { "oasis" } else { "mirage" };
             info!("AI chose to place {} on space {}", tile_type, space + 1);
-            spectator_action.write(PlaceSpectatorTileAction {
+            actions.spectator_tile.write(PlaceSpectatorTileAction {
                 space_index: space,
                 is_oasis,
+                from_host: false,
             });
         }
     }
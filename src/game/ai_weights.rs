@@ -0,0 +1,121 @@
+// Tunable AI heuristics
+// The numbers the AI weighs its choices with (how often Easy plays at random, how long
+// race bets are held back, what a spectator tile's push is worth...) are read from
+// `assets/ai_weights.ron` at startup, so tuning them doesn't need a recompile. With the
+// `dev` feature the asset server watches the file and changes apply straight away.
+
+use bevy::asset::io::Reader;
+use bevy::asset::{ron, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Path of the weights file, relative to `assets/`
+pub const AI_WEIGHTS_PATH: &str = "ai_weights.ron";
+
+/// Weights behind the AI's decisions. Missing fields in the file keep their defaults.
+#[derive(Asset, TypePath, Resource, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct AiWeights {
+    /// Chance an Easy AI ignores its heuristics and picks any valid action
+    pub easy_random_chance: f64,
+    /// Chance an Easy AI takes the leader's leg bet when it isn't the $5 tile
+    pub easy_leader_bet_chance: f64,
+    /// Dice left in the pyramid at or below which an Easy AI considers a race bet
+    pub easy_race_bet_dice_left: usize,
+    /// Chance an Easy AI backs the leader to win the race once it does
+    pub easy_race_bet_chance: f64,
+    /// Random swing (in $) applied to each action's value by a Medium AI
+    pub medium_value_noise: f32,
+    /// Dollars knocked off a bet's value for each 100% chance of it losing money.
+    /// 0 weighs bets on expected value alone; higher values favor safe pyramid rolls.
+    pub risk_aversion: f32,
+    /// Margin race bets must beat other actions by for every leg still to come...
+    pub race_bet_patience_per_leg: f32,
+    /// ...up to this much, however long the race still has to run
+    pub max_race_bet_patience: f32,
+    /// Dollars a race bet counts for when weighing whose camel a tile should push
+    pub race_bet_stake: f32,
+    /// How much a spectator tile's push changes a camel's fortunes, per expected
+    /// landing, for each dollar riding on that camel
+    pub tile_swing_per_landing: f32,
+}
+
+impl Default for AiWeights {
+    fn default() -> Self {
+        Self {
+            easy_random_chance: 0.3,
+            easy_leader_bet_chance: 0.5,
+            easy_race_bet_dice_left: 2,
+            easy_race_bet_chance: 0.3,
+            medium_value_noise: 1.5,
+            risk_aversion: 0.0,
+            race_bet_patience_per_leg: 0.6,
+            max_race_bet_patience: 2.5,
+            race_bet_stake: 1.0,
+            tile_swing_per_landing: 0.15,
+        }
+    }
+}
+
+#[cfg(feature = "ai_benchmark")]
+impl AiWeights {
+    /// Read weights straight from a file, for the headless benchmark (which has no
+    /// asset server)
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path, e))?;
+        ron::from_str(&text).map_err(|e| format!("Could not parse {}: {}", path, e))
+    }
+}
+
+/// Reads `AiWeights` from RON
+#[derive(Default)]
+pub struct AiWeightsLoader;
+
+impl AssetLoader for AiWeightsLoader {
+    type Asset = AiWeights;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<AiWeights, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+/// Keeps the weights file loaded (and watched, with the `dev` feature)
+#[derive(Resource)]
+pub struct AiWeightsHandle(Handle<AiWeights>);
+
+/// System to start loading the weights file
+pub fn load_ai_weights(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(AiWeightsHandle(asset_server.load(AI_WEIGHTS_PATH)));
+}
+
+/// System to put the weights into use whenever the file is loaded or changes.
+/// Until then (or if the file is missing) the AI plays with the defaults.
+pub fn apply_ai_weights(
+    mut events: MessageReader<AssetEvent<AiWeights>>,
+    assets: Res<Assets<AiWeights>>,
+    handle: Option<Res<AiWeightsHandle>>,
+    mut weights: ResMut<AiWeights>,
+) {
+    let Some(handle) = handle else { return };
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(&handle.0) || event.is_modified(&handle.0) {
+            if let Some(loaded) = assets.get(&handle.0) {
+                info!("AI weights loaded from {}: {:?}", AI_WEIGHTS_PATH, loaded);
+                *weights = loaded.clone();
+            }
+        }
+    }
+}
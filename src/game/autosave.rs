@@ -0,0 +1,124 @@
+// Saving the local game after every turn, so a closed tab or a crash doesn't lose it
+// The save is the snapshot an online host publishes (`SerializableGameState`) plus the
+// variants the game was set up with. "Continue Last Game" restores it the way a
+// rejoining guest catches up: set up the same table, let the setup rolls finish, then
+// put the snapshot in place.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::ai::{AiConfig, AiDifficulty};
+use crate::game::storage;
+use crate::network::messages::{SerializableGameState, PROTOCOL_VERSION};
+use crate::network::state::{NetworkState, ReceivedGameState};
+use crate::network::sync::{ready_for_turn_action, GameStateSource};
+use crate::systems::autoplay::AutoplayConfig;
+use crate::ui::characters::CharacterId;
+use crate::ui::hud::UiState;
+use crate::ui::player_setup::{PlayerConfig, PlayerSetupConfig};
+
+/// Name the save is kept under in `storage`
+const SAVE_NAME: &str = "save";
+
+/// A local game as it stood after its latest turn
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub sheikh_variant: bool,
+    pub desert_events: bool,
+    pub relay_race: bool,
+    /// Difficulty of each seat's AI, in seat order
+    pub ai_difficulties: Vec<AiDifficulty>,
+    pub state: SerializableGameState,
+}
+
+/// The saved game the main menu offers to continue
+#[derive(Resource, Default)]
+pub struct Autosave {
+    pub saved: Option<SavedGame>,
+}
+
+impl Autosave {
+    /// Forget the saved game: a new one started, or it finished
+    pub fn clear(&mut self) {
+        self.saved = None;
+        storage::remove(SAVE_NAME);
+    }
+
+    /// Set up the saved game to be played again: seats and variants now, the rest of
+    /// the snapshot once the new table's setup rolls are done
+    pub fn restore(&self, config: &mut PlayerSetupConfig, received_state: &mut ReceivedGameState) -> bool {
+        let Some(ref saved) = self.saved else { return false };
+        let Ok(state_json) = serde_json::to_string(&saved.state) else { return false };
+
+        config.sheikh_variant = saved.sheikh_variant;
+        config.desert_events = saved.desert_events;
+        config.relay_race = saved.relay_race;
+        config.randomize_start_order = false;
+        config.players = saved
+            .state
+            .players
+            .iter()
+            .enumerate()
+            .map(|(i, player)| PlayerConfig {
+                name: player.name.clone(),
+                is_ai: player.is_ai,
+                character_id: CharacterId::from_index(player.character_id as usize),
+                color_index: player.color_index,
+                custom_color: player.custom_color,
+                name_edited: true,
+                ai_difficulty: saved.ai_difficulties.get(i).copied().unwrap_or_default(),
+            })
+            .collect();
+
+        received_state.state_json = Some(state_json);
+        received_state.needs_processing = true;
+        received_state.restoring = true;
+        true
+    }
+}
+
+/// System to pick up the game saved last time the app ran. Saves from a build on
+/// another protocol version may not restore the same game, so they're dropped.
+pub fn load_autosave(mut autosave: ResMut<Autosave>) {
+    autosave.saved = storage::read(SAVE_NAME)
+        .and_then(|json| serde_json::from_str::<SavedGame>(&json).ok())
+        .filter(|saved| saved.state.protocol == PROTOCOL_VERSION);
+}
+
+/// System to save a local game each time a turn is over and the next player is up
+pub fn autosave_game(
+    mut autosave: ResMut<Autosave>,
+    snapshot: GameStateSource,
+    ui_state: Res<UiState>,
+    network_state: Res<NetworkState>,
+    (config, ai_config, autoplay): (Res<PlayerSetupConfig>, Res<AiConfig>, Res<AutoplayConfig>),
+    mut was_waiting: Local<bool>,
+) {
+    let waiting = ready_for_turn_action(&ui_state, &snapshot.turn_state, &snapshot.pyramid);
+    let turn_over = waiting && !*was_waiting;
+    *was_waiting = waiting;
+    // Online games have their own way back in, and nobody needs to continue a watched
+    // one (a replay, or the AI playing itself)
+    if !turn_over || !network_state.is_local() || autoplay.active {
+        return;
+    }
+
+    let saved = SavedGame {
+        sheikh_variant: config.sheikh_variant,
+        desert_events: config.desert_events,
+        relay_race: config.relay_race,
+        ai_difficulties: snapshot.players.players.iter().map(|p| ai_config.difficulty_for(p.id)).collect(),
+        state: snapshot.state(0, &[]),
+    };
+    if let Ok(json) = serde_json::to_string(&saved) {
+        storage::write(SAVE_NAME, &json);
+        autosave.saved = Some(saved);
+    }
+}
+
+/// System to forget a finished game, so the menu stops offering to continue it
+pub fn forget_finished_game(mut autosave: ResMut<Autosave>, network_state: Res<NetworkState>) {
+    if network_state.is_local() {
+        autosave.clear();
+    }
+}
@@ -0,0 +1,525 @@
+// Headless AI self-play benchmark
+// Plays thousands of AI-vs-AI games on the plain game model (no ECS, no rendering)
+// and reports how each difficulty fares: win rate, average money and which actions
+// it picks. Run it before and after tuning the AI:
+//
+//     cargo run --release --features ai_benchmark -- --benchmark --games 2000 --seats hard,medium,easy
+//
+// Every seat plays with the weights in assets/ai_weights.ron, or `--weights FILE` to try
+// another set.
+//
+// `--headless` plays the games on the same simplified model but prints every game's
+// result as JSON instead, for CI and balancing scripts:
+//
+//     cargo run --release --features ai_benchmark -- --headless --games 20 --seed 42 --seats hard,easy
+//
+// Game N is played from seed + N, so any single game can be played again on its own.
+//
+// Uses the same action choice, movement and payout rules as the real game. Desert
+// Events and the 2-player Sheikh variant are left out.
+
+use std::time::Instant;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+
+use crate::components::{
+    CamelColor, CamelNames, CrazyCamelColor, DieRollResult, LegBetTile, LegBettingTiles, PlayerData,
+    Pyramid, RaceBets, TRACK_LENGTH,
+};
+use crate::game::ai::{choose_action, collect_available_actions, AiAction, AiDifficulty, AiTable, CamelStakes};
+use crate::game::ai_weights::{AiWeights, AI_WEIGHTS_PATH};
+use crate::game::probability::{Piece, RaceBoard};
+use crate::game::core::{
+    apply_payout, leg_tile_payout, race_bet_payouts, PYRAMID_TOKEN_PAYOUT, SPECTATOR_TILE_PAYOUT,
+};
+use crate::game::simulation::{simulate, SimulationResult, DEFAULT_SAMPLES};
+
+/// Safety cap on turns per game (real games end long before this)
+const MAX_TURNS: u32 = 1000;
+
+const DIFFICULTIES: [AiDifficulty; 3] = [AiDifficulty::Easy, AiDifficulty::Medium, AiDifficulty::Hard];
+
+const ACTION_KINDS: [&str; 4] = ["Roll", "Leg bet", "Race bet", "Spectator"];
+
+/// What to play
+pub struct BenchmarkConfig {
+    pub games: usize,
+    /// Difficulty of each seat, in turn order
+    pub seats: Vec<AiDifficulty>,
+    /// Race simulation samples per Medium/Hard decision
+    pub samples: usize,
+    /// AI weights every seat plays with
+    pub weights: AiWeights,
+    /// Seed of the first game (random if not given)
+    pub seed: Option<u64>,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            games: 1000,
+            seats: vec![AiDifficulty::Easy, AiDifficulty::Medium, AiDifficulty::Hard],
+            samples: DEFAULT_SAMPLES,
+            weights: AiWeights::default(),
+            seed: None,
+        }
+    }
+}
+
+impl BenchmarkConfig {
+    /// Read `--games N`, `--seats easy,medium,hard`, `--samples N`, `--seed N` and
+    /// `--weights FILE` from the command line. Weights come from the game's own weights file by default.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut weights_path = format!("assets/{}", AI_WEIGHTS_PATH);
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+            match arg.as_str() {
+                "--games" => config.games = value()?.parse().map_err(|_| "--games needs a number")?,
+                "--samples" => config.samples = value()?.parse().map_err(|_| "--samples needs a number")?,
+                "--seed" => config.seed = Some(value()?.parse().map_err(|_| "--seed needs a number")?),
+                "--weights" => weights_path = value()?.clone(),
+                "--seats" => {
+                    config.seats = value()?
+                        .split(',')
+                        .map(|seat| {
+                            DIFFICULTIES
+                                .into_iter()
+                                .find(|d| d.label().eq_ignore_ascii_case(seat.trim()))
+                                .ok_or_else(|| format!("Unknown difficulty '{}'", seat))
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                _ => {}
+            }
+        }
+        if !(2..=8).contains(&config.seats.len()) {
+            return Err("--seats needs 2 to 8 difficulties".to_string());
+        }
+        config.weights = AiWeights::from_file(&weights_path)?;
+        Ok(config)
+    }
+
+    /// Generator for one game: seeded from `seed` + the game's index when there's a
+    /// seed, so the game can be replayed
+    fn game_rng(&self, game_index: usize) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed.wrapping_add(game_index as u64)),
+            None => StdRng::from_entropy(),
+        }
+    }
+}
+
+/// Totals for every seat played at one difficulty
+#[derive(Clone, Default)]
+struct DifficultyStats {
+    seats: usize,
+    /// Shared wins count as a fraction
+    wins: f32,
+    money: i64,
+    actions: [usize; 4],
+}
+
+/// Totals for a whole benchmark run
+#[derive(Clone, Default)]
+struct BenchmarkStats {
+    games: usize,
+    legs: u64,
+    turns: u64,
+    by_difficulty: [DifficultyStats; 3],
+}
+
+impl BenchmarkStats {
+    fn merge(&mut self, other: &BenchmarkStats) {
+        self.games += other.games;
+        self.legs += other.legs;
+        self.turns += other.turns;
+        for (mine, theirs) in self.by_difficulty.iter_mut().zip(&other.by_difficulty) {
+            mine.seats += theirs.seats;
+            mine.wins += theirs.wins;
+            mine.money += theirs.money;
+            for (a, b) in mine.actions.iter_mut().zip(theirs.actions) {
+                *a += b;
+            }
+        }
+    }
+}
+
+fn difficulty_index(difficulty: AiDifficulty) -> usize {
+    DIFFICULTIES.iter().position(|&d| d == difficulty).unwrap_or(0)
+}
+
+fn action_kind(action: &AiAction) -> usize {
+    match action {
+        AiAction::RollPyramid => 0,
+        AiAction::TakeLegBet(_) => 1,
+        AiAction::PlaceRaceBet { .. } => 2,
+        AiAction::PlaceSpectatorTile { .. } => 3,
+    }
+}
+
+/// One game, played without the ECS
+struct HeadlessGame {
+    board: RaceBoard,
+    pyramid: Pyramid,
+    leg_tiles: LegBettingTiles,
+    race_bets: RaceBets,
+    players: Vec<PlayerData>,
+    difficulties: Vec<AiDifficulty>,
+    /// Leg bet tiles taken this leg, per player
+    leg_bets: Vec<Vec<LegBetTile>>,
+    /// Owner of each spectator tile on the board, as (space, player index)
+    tile_owners: Vec<(u8, usize)>,
+    /// Actions each player took, counted like `ACTION_KINDS`
+    actions: Vec<[usize; 4]>,
+    current: usize,
+    legs: u32,
+    turns: u32,
+    /// Odds with the board they belong to (leg, dice rolled, spectator tiles placed)
+    odds: Option<((u32, usize, usize), SimulationResult)>,
+    names: CamelNames,
+}
+
+impl HeadlessGame {
+    /// Set up the board with the usual opening rolls; `first` takes the first turn
+    fn new(difficulties: &[AiDifficulty], first: usize, rng: &mut impl Rng) -> Self {
+        let mut stacks = vec![Vec::new(); TRACK_LENGTH as usize];
+
+        // Racing camels start on spaces 1-3, crazy camels on 14-16, rolled in random order
+        let mut racers = CamelColor::all();
+        racers.shuffle(rng);
+        for color in racers {
+            stacks[rng.gen_range(1..=3) - 1].push(Piece::Racer(color));
+        }
+        for color in [CrazyCamelColor::White, CrazyCamelColor::Black] {
+            stacks[TRACK_LENGTH as usize - rng.gen_range(1..=3)].push(Piece::Crazy(color));
+        }
+
+        let pyramid = Pyramid::new();
+        let mut board = RaceBoard {
+            stacks,
+            spectator_tiles: Vec::new(),
+            remaining_dice: Vec::new(),
+            dice_left_to_roll: 0,
+        };
+        board.set_pyramid(&pyramid);
+
+        Self {
+            board,
+            pyramid,
+            leg_tiles: LegBettingTiles::new(),
+            race_bets: RaceBets::default(),
+            players: difficulties
+                .iter()
+                .enumerate()
+                .map(|(i, d)| PlayerData::new(i as u8, format!("{} {}", d.label(), i + 1), true))
+                .collect(),
+            difficulties: difficulties.to_vec(),
+            leg_bets: vec![Vec::new(); difficulties.len()],
+            tile_owners: Vec::new(),
+            actions: vec![[0; 4]; difficulties.len()],
+            current: first,
+            legs: 1,
+            turns: 0,
+            odds: None,
+            names: CamelNames::default(),
+        }
+    }
+
+    /// Recalculate the odds if the board changed since they were last needed
+    fn update_odds(&mut self, samples: usize, rng: &mut impl Rng) {
+        let key = (self.legs, self.pyramid.rolled_dice.len(), self.board.spectator_tiles.len());
+        if self.odds.as_ref().is_none_or(|(odds_key, _)| *odds_key != key) {
+            self.odds = Some((key, simulate(&self.board, samples, rng)));
+        }
+    }
+
+    /// Play one turn for the current player. Returns true once the race is over.
+    fn play_turn(&mut self, config: &BenchmarkConfig, stats: &mut BenchmarkStats, rng: &mut impl Rng) -> bool {
+        let difficulty = self.difficulties[self.current];
+        if difficulty.uses_odds() {
+            self.update_odds(config.samples, rng);
+        }
+
+        let actions = collect_available_actions(&self.players[self.current], &self.board, &self.leg_tiles);
+        let action = if actions.is_empty() {
+            AiAction::RollPyramid
+        } else {
+            let odds = self.odds.as_ref().map(|(_, odds)| odds).filter(|_| difficulty.uses_odds());
+            let weights = &config.weights;
+            let stakes = CamelStakes::new(self.current, &self.players, &self.leg_bets, &self.race_bets, weights);
+            let table = AiTable {
+                board: &self.board,
+                leg_tiles: &self.leg_tiles,
+                race_bets: &self.race_bets,
+                stakes: &stakes,
+                names: &self.names,
+            };
+            choose_action(difficulty, &actions, odds, table, weights, rng).0
+        };
+        stats.by_difficulty[difficulty_index(difficulty)].actions[action_kind(&action)] += 1;
+        self.actions[self.current][action_kind(&action)] += 1;
+        self.apply(action, rng);
+        self.turns += 1;
+
+        if self.board.finished() || self.turns >= MAX_TURNS {
+            self.score_leg();
+            self.score_race();
+            return true;
+        }
+        if self.pyramid.all_dice_rolled() {
+            self.score_leg();
+            self.start_new_leg();
+        }
+        self.current = (self.current + 1) % self.players.len();
+        false
+    }
+
+    fn apply(&mut self, action: AiAction, rng: &mut impl Rng) {
+        let player = &mut self.players[self.current];
+        match action {
+            AiAction::RollPyramid => {
+                let Some(result) = self.pyramid.roll_die_with(rng) else { return };
+                player.money += PYRAMID_TOKEN_PAYOUT;
+                match result {
+                    DieRollResult::Regular { color, value } => {
+                        // Spectator tiles pay their owner when a camel lands on them
+                        if let Some(landed) = self.board.move_racer(color, value) {
+                            if let Some(&(_, owner)) = self.tile_owners.iter().find(|(space, _)| *space == landed) {
+                                self.players[owner].money += SPECTATOR_TILE_PAYOUT;
+                            }
+                        }
+                    }
+                    DieRollResult::Crazy { color, value } => self.board.move_crazy(color, value),
+                }
+                self.board.set_pyramid(&self.pyramid);
+            }
+            AiAction::TakeLegBet(color) => {
+                if let Some(tile) = self.leg_tiles.take_tile(color) {
+                    self.leg_bets[self.current].push(tile);
+                }
+            }
+            AiAction::PlaceRaceBet { color, is_winner } => {
+                player.available_race_cards.remove(&color);
+                if is_winner {
+                    self.race_bets.place_winner_bet(color, player.id);
+                } else {
+                    self.race_bets.place_loser_bet(color, player.id);
+                }
+            }
+            AiAction::PlaceSpectatorTile { space, is_oasis } => {
+                player.has_spectator_tile = false;
+                self.board.spectator_tiles.push((space, is_oasis));
+                self.tile_owners.push((space, self.current));
+            }
+        }
+    }
+
+    fn score_leg(&mut self) {
+        let rankings = self.board.rankings();
+        let (first, second) = (rankings.first().copied(), rankings.get(1).copied());
+        for (player, bets) in self.players.iter_mut().zip(&self.leg_bets) {
+            for tile in bets {
+                apply_payout(&mut player.money, leg_tile_payout(tile, first, second));
+            }
+        }
+    }
+
+    fn score_race(&mut self) {
+        let rankings = self.board.rankings();
+        for (bets, result) in [
+            (&self.race_bets.winner_bets, rankings.first().copied()),
+            (&self.race_bets.loser_bets, rankings.last().copied()),
+        ] {
+            for (bet, payout) in bets.iter().zip(race_bet_payouts(bets, result)) {
+                if let Some(player) = self.players.iter_mut().find(|p| p.id == bet.player_id) {
+                    apply_payout(&mut player.money, payout);
+                }
+            }
+        }
+    }
+
+    fn start_new_leg(&mut self) {
+        self.pyramid.reset();
+        self.leg_tiles.reset();
+        self.leg_bets.iter_mut().for_each(Vec::clear);
+        self.tile_owners.clear();
+        self.board.start_new_leg();
+        self.board.set_pyramid(&self.pyramid);
+        for player in &mut self.players {
+            player.has_spectator_tile = true;
+        }
+        self.legs += 1;
+    }
+
+    /// Add this finished game's results to the totals
+    fn record(&self, stats: &mut BenchmarkStats) {
+        stats.games += 1;
+        stats.legs += self.legs as u64;
+        stats.turns += self.turns as u64;
+
+        let best = self.players.iter().map(|p| p.money).max().unwrap_or(0);
+        let winners = self.players.iter().filter(|p| p.money == best).count() as f32;
+        for (player, &difficulty) in self.players.iter().zip(&self.difficulties) {
+            let seat = &mut stats.by_difficulty[difficulty_index(difficulty)];
+            seat.seats += 1;
+            seat.money += player.money as i64;
+            if player.money == best {
+                seat.wins += 1.0 / winners;
+            }
+        }
+    }
+}
+
+/// Play `games` games, rotating who goes first so no seat keeps the first move
+fn play_games(config: &BenchmarkConfig, games: impl Iterator<Item = usize>) -> BenchmarkStats {
+    let mut stats = BenchmarkStats::default();
+    for game_index in games {
+        play_game(config, game_index, &mut stats).record(&mut stats);
+    }
+    stats
+}
+
+/// Play one game to the end, with its actions counted in `stats`
+fn play_game(config: &BenchmarkConfig, game_index: usize, stats: &mut BenchmarkStats) -> HeadlessGame {
+    let mut rng = config.game_rng(game_index);
+    let mut game = HeadlessGame::new(&config.seats, game_index % config.seats.len(), &mut rng);
+    while !game.play_turn(config, stats, &mut rng) {}
+    game
+}
+
+/// Play every game, spread over all cores, and print the report
+pub fn run_benchmark(config: &BenchmarkConfig) {
+    let seats: Vec<&str> = config.seats.iter().map(|d| d.label()).collect();
+    println!(
+        "AI benchmark: {} games, seats {}, {} samples per decision",
+        config.games,
+        seats.join(", "),
+        config.samples
+    );
+
+    let started = Instant::now();
+    let workers = std::thread::available_parallelism().map_or(1, |n| n.get()).min(config.games.max(1));
+    let mut stats = BenchmarkStats::default();
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|worker| scope.spawn(move || play_games(config, (worker..config.games).step_by(workers))))
+            .collect();
+        for handle in handles {
+            if let Ok(worker_stats) = handle.join() {
+                stats.merge(&worker_stats);
+            }
+        }
+    });
+
+    print_report(&stats, started.elapsed().as_secs_f32());
+}
+
+fn print_report(stats: &BenchmarkStats, seconds: f32) {
+    let games = stats.games.max(1) as f32;
+    println!(
+        "Played {} games in {:.1}s: {:.1} legs and {:.1} turns per game\n",
+        stats.games,
+        seconds,
+        stats.legs as f32 / games,
+        stats.turns as f32 / games
+    );
+
+    println!(
+        "{:<8} {:>6} {:>9} {:>10}   {:>7} {:>8} {:>9} {:>10}",
+        "AI", "Seats", "Win rate", "Avg money", ACTION_KINDS[0], ACTION_KINDS[1], ACTION_KINDS[2], ACTION_KINDS[3]
+    );
+    for (difficulty, seat) in DIFFICULTIES.iter().zip(&stats.by_difficulty) {
+        if seat.seats == 0 {
+            continue;
+        }
+        let actions = seat.actions.iter().sum::<usize>().max(1) as f32;
+        let share = |kind: usize| format!("{:.1}%", seat.actions[kind] as f32 / actions * 100.0);
+        let avg_money = format!("${:.1}", seat.money as f32 / seat.seats as f32);
+        println!(
+            "{:<8} {:>6} {:>8.1}% {:>10}   {:>7} {:>8} {:>9} {:>10}",
+            difficulty.label(),
+            seat.seats,
+            seat.wins / seat.seats as f32 * 100.0,
+            avg_money,
+            share(0),
+            share(1),
+            share(2),
+            share(3)
+        );
+    }
+}
+
+/// One seat's result in a headless game
+#[derive(Serialize)]
+struct SeatResult {
+    difficulty: &'static str,
+    money: i32,
+    /// Actions taken, counted like `ACTION_KINDS`: roll, leg bet, race bet, spectator tile
+    actions: [usize; 4],
+}
+
+/// One headless game's result, as printed
+#[derive(Serialize)]
+struct GameResult {
+    seed: u64,
+    /// Seat that took the first turn
+    first_seat: usize,
+    legs: u32,
+    turns: u32,
+    winning_camel: Option<CamelColor>,
+    losing_camel: Option<CamelColor>,
+    /// In seat order
+    seats: Vec<SeatResult>,
+    /// Seats that finished with the most money
+    winners: Vec<usize>,
+}
+
+impl GameResult {
+    fn new(seed: u64, first_seat: usize, game: &HeadlessGame) -> Self {
+        let rankings = game.board.rankings();
+        let best = game.players.iter().map(|p| p.money).max().unwrap_or(0);
+        Self {
+            seed,
+            first_seat,
+            legs: game.legs,
+            turns: game.turns,
+            winning_camel: rankings.first().copied(),
+            losing_camel: rankings.last().copied(),
+            seats: game
+                .players
+                .iter()
+                .zip(&game.difficulties)
+                .zip(&game.actions)
+                .map(|((player, difficulty), actions)| SeatResult {
+                    difficulty: difficulty.label(),
+                    money: player.money,
+                    actions: *actions,
+                })
+                .collect(),
+            winners: (0..game.players.len()).filter(|&seat| game.players[seat].money == best).collect(),
+        }
+    }
+}
+
+/// Play the configured games one by one on the benchmark's simplified model (no
+/// Desert Events, no Sheikh), then print their results as JSON. Without `--seed` a
+/// random one is picked; the results list it.
+pub fn run_headless(mut config: BenchmarkConfig) {
+    let seed = *config.seed.get_or_insert_with(rand::random);
+    let results: Vec<GameResult> = (0..config.games)
+        .map(|game_index| {
+            let game = play_game(&config, game_index, &mut BenchmarkStats::default());
+            GameResult::new(seed.wrapping_add(game_index as u64), game_index % config.seats.len(), &game)
+        })
+        .collect();
+
+    match serde_json::to_string_pretty(&results) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("Couldn't write the results: {}", e),
+    }
+}
@@ -0,0 +1,457 @@
+// Core rules
+// Camel Up's rules as plain data and functions, with nothing from the ECS: how a roll
+// moves a stack and what a spectator tile does to it, race order, the pyramid's dice and
+// when a leg is over, and what every bet pays. The movement systems, the turn systems,
+// the scoring screens, the AI's simulations and the headless benchmark all play by these,
+// so a rule only has to be written once.
+
+use rand::Rng;
+
+use crate::components::{CamelColor, CrazyCamelColor, LegBetTile, RaceBet, TRACK_LENGTH};
+
+/// Lowest face on a camel die
+pub const MIN_DIE_VALUE: u8 = 1;
+
+/// Highest face on a camel die
+pub const MAX_DIE_VALUE: u8 = 3;
+
+/// Rolls in a leg: the leg ends with one die still in the pyramid
+pub const DICE_PER_LEG: usize = 5;
+
+/// What a pyramid ticket pays, as soon as its die is rolled
+pub const PYRAMID_TOKEN_PAYOUT: i32 = 1;
+
+/// What a spectator tile's owner earns each time a camel lands on it
+pub const SPECTATOR_TILE_PAYOUT: i32 = 1;
+
+/// Payouts for correct race bets, in the order they were placed
+pub const RACE_BET_PAYOUTS: [i32; 5] = [8, 5, 3, 2, 1];
+
+// ============================================================================
+// Pyramid dice
+// ============================================================================
+
+/// Roll a camel die
+pub fn roll_die_face(rng: &mut impl Rng) -> u8 {
+    rng.gen_range(MIN_DIE_VALUE..=MAX_DIE_VALUE)
+}
+
+/// Roll the gray crazy die: a face for the white or the black crazy camel
+pub fn roll_crazy_die(rng: &mut impl Rng) -> (CrazyCamelColor, u8) {
+    let value = roll_die_face(rng);
+    let color = if rng.gen_bool(0.5) {
+        CrazyCamelColor::White
+    } else {
+        CrazyCamelColor::Black
+    };
+    (color, value)
+}
+
+/// Rolls still to come in a leg once `rolled` dice have come out of the pyramid
+pub fn rolls_left_in_leg(rolled: usize) -> usize {
+    DICE_PER_LEG.saturating_sub(rolled)
+}
+
+/// Whether a leg is over once `rolled` dice have come out of the pyramid
+pub fn leg_over(rolled: usize) -> bool {
+    rolled >= DICE_PER_LEG
+}
+
+// ============================================================================
+// Movement
+// ============================================================================
+
+/// Where a stack ends up after a roll and any spectator tile on the space it reached
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Landing {
+    /// Space the stack ends on (can be past the last space)
+    pub space: u8,
+    pub crossed_finish: bool,
+    /// A mirage puts the moving stack underneath the camels already there
+    pub underneath: bool,
+}
+
+/// Resolve a spectator tile, given as (owner_id, is_oasis), on the space a roll reached.
+/// An oasis carries the stack one space further, landing on top; a mirage sends it one
+/// space back, underneath. Tiles past the finish don't count.
+pub fn resolve_landing(rolled_space: u8, tile: Option<(u8, bool)>) -> Landing {
+    let mut landing = Landing {
+        space: rolled_space,
+        crossed_finish: rolled_space >= TRACK_LENGTH,
+        underneath: false,
+    };
+    if landing.crossed_finish {
+        return landing;
+    }
+    match tile {
+        Some((_, true)) => {
+            landing.space += 1;
+            landing.crossed_finish = landing.space >= TRACK_LENGTH;
+        }
+        Some((_, false)) => {
+            landing.space = landing.space.saturating_sub(1);
+            landing.underneath = true;
+        }
+        None => {}
+    }
+    landing
+}
+
+/// Where a racing camel's roll takes it, worked out before anything moves.
+/// `P` is whatever identifies a piece on the board: an entity, a color...
+pub struct CamelMovePlan<P> {
+    /// Space the rolling camel starts on
+    pub start_space: u8,
+    /// Space the roll reaches before any spectator tile
+    pub rolled_space: u8,
+    /// Space the stack ends on (the last space if it crossed the finish)
+    pub final_space: u8,
+    pub crossed_finish: bool,
+    /// Spectator tile on the rolled space: (owner_id, is_oasis)
+    pub tile: Option<(u8, bool)>,
+    /// The rolling camel and everything riding on it, bottom first, with their old
+    /// stack positions
+    pub moving: Vec<(P, u8)>,
+    /// Height of the stack already on the final space
+    pub landing_height: u8,
+}
+
+impl<P> CamelMovePlan<P> {
+    /// A mirage puts the moving stack underneath the camels already there
+    pub fn lands_underneath(&self) -> bool {
+        matches!(self.tile, Some((_, false)))
+    }
+
+    /// Stack position each moving camel ends at, bottom first
+    pub fn landing_stack_position(&self, index: usize) -> u8 {
+        if self.lands_underneath() {
+            index as u8
+        } else {
+            self.landing_height + index as u8
+        }
+    }
+}
+
+impl<P: PartialEq> CamelMovePlan<P> {
+    /// Where the piece at (`space`, `stack`) is once the move is made: the moving stack on
+    /// the final space and, after a mirage, the camels already there lifted on top of it.
+    /// Everything else stays put.
+    pub fn destination(&self, piece: &P, space: u8, stack: u8) -> (u8, u8) {
+        if let Some(index) = self.moving.iter().position(|(moving, _)| moving == piece) {
+            (self.final_space, self.landing_stack_position(index))
+        } else if self.lands_underneath() && space == self.final_space {
+            (space, stack + self.moving.len() as u8)
+        } else {
+            (space, stack)
+        }
+    }
+}
+
+/// The moving piece and everything stacked on it, bottom first, with their stack positions
+fn stack_from<P: Copy + PartialEq>(pieces: &[(P, u8, u8)], mover: P, space: u8, stack: u8) -> Vec<(P, u8)> {
+    let mut moving: Vec<(P, u8)> = pieces
+        .iter()
+        .filter(|&&(piece, s, height)| piece == mover || (s == space && height > stack))
+        .map(|&(piece, _, height)| (piece, height))
+        .collect();
+    moving.sort_by_key(|(_, height)| *height);
+    moving
+}
+
+/// Height of the stack on a space, leaving out the pieces that are moving
+fn stack_height<P: PartialEq>(pieces: &[(P, u8, u8)], space: u8, moving: &[(P, u8)]) -> u8 {
+    pieces
+        .iter()
+        .filter(|(piece, s, _)| *s == space && !moving.iter().any(|(m, _)| m == piece))
+        .map(|(_, _, height)| height + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Work out a racing camel's move without making it: `pieces` is every camel on the
+/// board, racing and crazy, as (piece, space, stack position), and `tile_at` gives the
+/// spectator tile on a space as (owner_id, is_oasis).
+pub fn plan_camel_move<P: Copy + PartialEq>(
+    pieces: &[(P, u8, u8)],
+    mover: P,
+    spaces: u8,
+    tile_at: impl Fn(u8) -> Option<(u8, bool)>,
+) -> Option<CamelMovePlan<P>> {
+    let &(_, start_space, start_stack) = pieces.iter().find(|(piece, _, _)| *piece == mover)?;
+
+    let rolled_space = start_space + spaces;
+    let tile = if rolled_space >= TRACK_LENGTH { None } else { tile_at(rolled_space) };
+    let landing = resolve_landing(rolled_space, tile);
+
+    let moving = stack_from(pieces, mover, start_space, start_stack);
+    let final_space = landing.space.min(TRACK_LENGTH - 1);
+    let landing_height = stack_height(pieces, final_space, &moving);
+
+    Some(CamelMovePlan {
+        start_space,
+        rolled_space,
+        final_space,
+        crossed_finish: landing.crossed_finish,
+        tile,
+        moving,
+        landing_height,
+    })
+}
+
+/// Where a crazy camel's roll takes it: backwards, carrying whatever rides on it, landing
+/// on top. Spectator tiles don't affect crazy camels.
+pub struct CrazyMovePlan<P> {
+    pub start_space: u8,
+    pub final_space: u8,
+    /// The crazy camel and everything riding on it, bottom first, with their old stack
+    /// positions
+    pub moving: Vec<(P, u8)>,
+    /// Height of the stack already on the final space
+    pub landing_height: u8,
+}
+
+/// Work out a crazy camel's move without making it, `pieces` as for `plan_camel_move`
+pub fn plan_crazy_move<P: Copy + PartialEq>(pieces: &[(P, u8, u8)], mover: P, spaces: u8) -> Option<CrazyMovePlan<P>> {
+    let &(_, start_space, start_stack) = pieces.iter().find(|(piece, _, _)| *piece == mover)?;
+    let final_space = start_space.saturating_sub(spaces);
+    let moving = stack_from(pieces, mover, start_space, start_stack);
+    let landing_height = stack_height(pieces, final_space, &moving);
+    Some(CrazyMovePlan { start_space, final_space, moving, landing_height })
+}
+
+impl<P: PartialEq> CrazyMovePlan<P> {
+    /// Where the piece at (`space`, `stack`) is once the move is made, as for
+    /// `CamelMovePlan::destination`
+    pub fn destination(&self, piece: &P, space: u8, stack: u8) -> (u8, u8) {
+        match self.moving.iter().position(|(moving, _)| moving == piece) {
+            Some(index) => (self.final_space, self.landing_height + index as u8),
+            None => (space, stack),
+        }
+    }
+}
+
+/// Pieces in race order, leader first: furthest along, then highest in its stack
+pub fn race_order<P: Copy>(pieces: impl IntoIterator<Item = (P, u8, u8)>) -> Vec<P> {
+    let mut pieces: Vec<(P, u8, u8)> = pieces.into_iter().collect();
+    pieces.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)));
+    pieces.into_iter().map(|(piece, _, _)| piece).collect()
+}
+
+// ============================================================================
+// Scoring
+// ============================================================================
+
+/// Money a leg bet tile pays: its value if the camel won the leg, $1 for second, -$1 otherwise
+pub fn leg_tile_payout(tile: &LegBetTile, first: Option<CamelColor>, second: Option<CamelColor>) -> i32 {
+    if Some(tile.camel) == first {
+        tile.value as i32
+    } else if Some(tile.camel) == second {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Payout of each race bet, in the order they were placed. Correct bets take
+/// $8, $5, $3, $2 and then $1 each; wrong bets cost $1.
+pub fn race_bet_payouts(bets: &[RaceBet], result: Option<CamelColor>) -> Vec<i32> {
+    let mut correct = 0;
+    bets.iter()
+        .map(|bet| {
+            if Some(bet.camel) == result {
+                let payout = RACE_BET_PAYOUTS.get(correct).copied().unwrap_or(1);
+                correct += 1;
+                payout
+            } else {
+                -1
+            }
+        })
+        .collect()
+}
+
+/// Add a payout to a purse; losses never take it below zero
+pub fn apply_payout(money: &mut i32, payout: i32) {
+    *money = (*money + payout).max(0);
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Pieces on the test boards: 0 to 4 are racing camels, 5 and 6 crazy camels
+    const PIECES: usize = 7;
+    const RACERS: std::ops::Range<usize> = 0..5;
+    const CRAZIES: std::ops::Range<usize> = 5..7;
+
+    /// A board as the planners take it, (piece, space, stack position) for every piece,
+    /// with the pieces stacked in a random order
+    fn board() -> impl Strategy<Value = Vec<(usize, u8, u8)>> {
+        let order: Vec<usize> = (0..PIECES).collect();
+        (proptest::collection::vec(0..TRACK_LENGTH, PIECES), Just(order).prop_shuffle()).prop_map(|(spaces, order)| {
+            let mut heights = [0u8; TRACK_LENGTH as usize];
+            let mut board: Vec<(usize, u8, u8)> = order
+                .into_iter()
+                .map(|piece| {
+                    let space = spaces[piece];
+                    heights[space as usize] += 1;
+                    (piece, space, heights[space as usize] - 1)
+                })
+                .collect();
+            board.sort_by_key(|&(piece, _, _)| piece);
+            board
+        })
+    }
+
+    /// Spectator tiles as (space, owner_id, is_oasis)
+    fn tiles() -> impl Strategy<Value = Vec<(u8, u8, bool)>> {
+        proptest::collection::vec((1..TRACK_LENGTH, 0..8u8, any::<bool>()), 0..4)
+    }
+
+    /// The spectator tile on a space, as (owner_id, is_oasis)
+    fn tile_at(tiles: &[(u8, u8, bool)], space: u8) -> Option<(u8, bool)> {
+        tiles.iter().find(|tile| tile.0 == space).map(|&(_, owner, oasis)| (owner, oasis))
+    }
+
+    /// Plan a racing camel's move and carry it out the way `move_camel_system` does
+    fn racing_move(
+        board: &[(usize, u8, u8)],
+        mover: usize,
+        spaces: u8,
+        tiles: &[(u8, u8, bool)],
+    ) -> (CamelMovePlan<usize>, Vec<(usize, u8, u8)>) {
+        let plan = plan_camel_move(board, mover, spaces, |space| tile_at(tiles, space)).expect("the mover is on the board");
+        let after = board
+            .iter()
+            .map(|&(piece, space, stack)| {
+                let (space, stack) = plan.destination(&piece, space, stack);
+                (piece, space, stack)
+            })
+            .collect();
+        (plan, after)
+    }
+
+    /// A space's pieces, bottom first
+    fn stack_on(board: &[(usize, u8, u8)], space: u8) -> Vec<usize> {
+        let mut stack: Vec<(usize, u8)> = board
+            .iter()
+            .filter(|&&(_, s, _)| s == space)
+            .map(|&(piece, _, height)| (piece, height))
+            .collect();
+        stack.sort_by_key(|&(_, height)| height);
+        stack.into_iter().map(|(piece, _)| piece).collect()
+    }
+
+    /// The mover and everything riding on it, bottom first
+    fn riders(board: &[(usize, u8, u8)], mover: usize) -> Vec<usize> {
+        stack_on(board, board[mover].1).into_iter().skip_while(|&piece| piece != mover).collect()
+    }
+
+    /// Every piece is still on the board exactly once, and every stack is numbered 0, 1,
+    /// 2... with no gaps or doubles
+    fn check_conserved(after: &[(usize, u8, u8)]) -> Result<(), TestCaseError> {
+        for piece in 0..PIECES {
+            prop_assert_eq!(after.iter().filter(|&&(p, _, _)| p == piece).count(), 1);
+        }
+        for space in 0..TRACK_LENGTH {
+            let mut heights: Vec<u8> = after.iter().filter(|&&(_, s, _)| s == space).map(|p| p.2).collect();
+            heights.sort_unstable();
+            prop_assert!(
+                heights.iter().enumerate().all(|(i, &height)| height as usize == i),
+                "space {} is numbered {:?}",
+                space,
+                heights
+            );
+        }
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn racing_moves_conserve_the_camels(
+            board in board(),
+            mover in RACERS,
+            spaces in 1..=MAX_DIE_VALUE + 1,
+            tiles in tiles(),
+        ) {
+            let (_, after) = racing_move(&board, mover, spaces, &tiles);
+            check_conserved(&after)?;
+        }
+
+        #[test]
+        fn racing_moves_keep_the_stack_in_order(
+            board in board(),
+            mover in RACERS,
+            spaces in 1..=MAX_DIE_VALUE + 1,
+            tiles in tiles(),
+        ) {
+            let riders = riders(&board, mover);
+            let (plan, after) = racing_move(&board, mover, spaces, &tiles);
+            prop_assert_eq!(plan.moving.iter().map(|&(piece, _)| piece).collect::<Vec<_>>(), riders.clone());
+
+            // On top of the stack there, or underneath it after a mirage
+            let landed = stack_on(&after, plan.final_space);
+            if plan.lands_underneath() {
+                prop_assert!(landed.starts_with(&riders), "{:?} under {:?}", riders, landed);
+            } else {
+                prop_assert!(landed.ends_with(&riders), "{:?} on {:?}", riders, landed);
+            }
+        }
+
+        #[test]
+        fn spectator_tiles_carry_the_stack_on_or_back(
+            board in board(),
+            mover in RACERS,
+            spaces in 1..=MAX_DIE_VALUE,
+            tiles in tiles(),
+        ) {
+            let rolled = board[mover].1 + spaces;
+            prop_assume!(rolled < TRACK_LENGTH);
+            let riders = riders(&board, mover);
+            let (plan, after) = racing_move(&board, mover, spaces, &tiles);
+            let landed = stack_on(&after, plan.final_space);
+            match tile_at(&tiles, rolled) {
+                // An oasis: one space further, on top
+                Some((_, true)) => {
+                    prop_assert_eq!(plan.final_space, (rolled + 1).min(TRACK_LENGTH - 1));
+                    prop_assert_eq!(plan.crossed_finish, rolled + 1 >= TRACK_LENGTH);
+                    prop_assert!(landed.ends_with(&riders), "{:?} on {:?}", riders, landed);
+                }
+                // A mirage: one space back, underneath
+                Some((_, false)) => {
+                    prop_assert_eq!(plan.final_space, rolled - 1);
+                    prop_assert!(landed.starts_with(&riders), "{:?} under {:?}", riders, landed);
+                }
+                None => {
+                    prop_assert_eq!(plan.final_space, rolled);
+                    prop_assert!(landed.ends_with(&riders), "{:?} on {:?}", riders, landed);
+                }
+            }
+        }
+
+        #[test]
+        fn crazy_camels_move_backwards(
+            board in board(),
+            mover in CRAZIES,
+            spaces in 1..=MAX_DIE_VALUE,
+        ) {
+            let start = board[mover].1;
+            let riders = riders(&board, mover);
+            let plan = plan_crazy_move(&board, mover, spaces).expect("the mover is on the board");
+            prop_assert_eq!(plan.final_space, start.saturating_sub(spaces));
+            prop_assert_eq!(plan.moving.iter().map(|&(piece, _)| piece).collect::<Vec<_>>(), riders.clone());
+
+            let after: Vec<(usize, u8, u8)> = board
+                .iter()
+                .map(|&(piece, space, stack)| {
+                    let (space, stack) = plan.destination(&piece, space, stack);
+                    (piece, space, stack)
+                })
+                .collect();
+            check_conserved(&after)?;
+            let landed = stack_on(&after, plan.final_space);
+            prop_assert!(landed.ends_with(&riders), "{:?} on {:?}", riders, landed);
+        }
+    }
+}
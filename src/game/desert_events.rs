@@ -0,0 +1,89 @@
+// Desert Events variant
+// At the start of each leg a random event changes the rules for that leg.
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+/// A rule modifier that lasts for one leg
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DesertEvent {
+    /// Racing camels move one extra space on every roll
+    Tailwind,
+    /// Spectator tiles can't be placed this leg
+    Sandstorm,
+    /// Each roll earns two pyramid tickets
+    GoldRush,
+}
+
+impl DesertEvent {
+    pub fn all() -> [DesertEvent; 3] {
+        [DesertEvent::Tailwind, DesertEvent::Sandstorm, DesertEvent::GoldRush]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DesertEvent::Tailwind => "Tailwind",
+            DesertEvent::Sandstorm => "Sandstorm",
+            DesertEvent::GoldRush => "Gold Rush",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            DesertEvent::Tailwind => "Racing camels move 1 extra space on every roll",
+            DesertEvent::Sandstorm => "No spectator tiles can be placed this leg",
+            DesertEvent::GoldRush => "Rolling earns 2 pyramid tickets ($2) instead of 1",
+        }
+    }
+}
+
+/// The event in effect for the current leg (Desert Events variant only)
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct ActiveEvent {
+    pub enabled: bool,
+    pub event: Option<DesertEvent>,
+    /// Leg the current event was drawn for (0 = none drawn yet)
+    pub drawn_for_leg: u32,
+    /// The game's seed: online players and replays draw the same events from it
+    pub seed: u64,
+}
+
+impl ActiveEvent {
+    pub fn new(enabled: bool, seed: u64) -> Self {
+        Self {
+            enabled,
+            seed,
+            ..default()
+        }
+    }
+
+    /// Draw the event for the given leg, the same one every time for the same seed and leg
+    pub fn draw(&mut self, leg: u32) -> DesertEvent {
+        let events = DesertEvent::all();
+        let index = ChaCha8Rng::seed_from_u64(self.seed ^ leg as u64).gen_range(0..events.len());
+        let event = events[index];
+        self.event = Some(event);
+        self.drawn_for_leg = leg;
+        event
+    }
+
+    pub fn is(&self, event: DesertEvent) -> bool {
+        self.event == Some(event)
+    }
+
+    /// Extra spaces racing camels move on a roll
+    pub fn extra_move(&self) -> u8 {
+        if self.is(DesertEvent::Tailwind) { 1 } else { 0 }
+    }
+
+    pub fn spectator_tiles_banned(&self) -> bool {
+        self.is(DesertEvent::Sandstorm)
+    }
+
+    /// Pyramid tickets earned for rolling (each worth $1)
+    pub fn tickets_per_roll(&self) -> u8 {
+        if self.is(DesertEvent::GoldRush) { 2 } else { 1 }
+    }
+}
@@ -0,0 +1,237 @@
+// Remappable keyboard shortcuts
+// Every shortcut the game listens for is looked up here instead of being hard-coded.
+// The main menu's Settings lets the player click a binding and press a new key; the
+// bindings are saved in the settings file alongside the other preferences.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Something the player can do from the keyboard
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyAction {
+    /// Stop watching autoplay, or stop the stress test, and go back to the main menu
+    StopWatching,
+    /// Start the developer stress test from the main menu
+    StressTest,
+    /// Show or hide the developer state machine overlay
+    StateOverlay,
+    /// Show or hide the developer network overlay
+    NetworkOverlay,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 4] = [
+        KeyAction::StopWatching,
+        KeyAction::StressTest,
+        KeyAction::StateOverlay,
+        KeyAction::NetworkOverlay,
+    ];
+
+    /// What the settings screen calls it
+    pub fn label(self) -> &'static str {
+        match self {
+            KeyAction::StopWatching => "Stop autoplay",
+            KeyAction::StressTest => "Stress test",
+            KeyAction::StateOverlay => "State overlay",
+            KeyAction::NetworkOverlay => "Network overlay",
+        }
+    }
+
+    /// Whether this build listens for it at all: developer shortcuts need the developer
+    /// tools, and the overlays are only in builds with their feature
+    pub fn is_available(self, developer_tools: bool) -> bool {
+        match self {
+            KeyAction::StopWatching => true,
+            KeyAction::StressTest => developer_tools,
+            KeyAction::StateOverlay => cfg!(feature = "state_overlay"),
+            KeyAction::NetworkOverlay => cfg!(feature = "network_overlay"),
+        }
+    }
+}
+
+/// Keys that can be bound, with the names they're shown and saved under
+const NAMED_KEYS: [(KeyCode, &str); 63] = [
+    (KeyCode::KeyA, "A"),
+    (KeyCode::KeyB, "B"),
+    (KeyCode::KeyC, "C"),
+    (KeyCode::KeyD, "D"),
+    (KeyCode::KeyE, "E"),
+    (KeyCode::KeyF, "F"),
+    (KeyCode::KeyG, "G"),
+    (KeyCode::KeyH, "H"),
+    (KeyCode::KeyI, "I"),
+    (KeyCode::KeyJ, "J"),
+    (KeyCode::KeyK, "K"),
+    (KeyCode::KeyL, "L"),
+    (KeyCode::KeyM, "M"),
+    (KeyCode::KeyN, "N"),
+    (KeyCode::KeyO, "O"),
+    (KeyCode::KeyP, "P"),
+    (KeyCode::KeyQ, "Q"),
+    (KeyCode::KeyR, "R"),
+    (KeyCode::KeyS, "S"),
+    (KeyCode::KeyT, "T"),
+    (KeyCode::KeyU, "U"),
+    (KeyCode::KeyV, "V"),
+    (KeyCode::KeyW, "W"),
+    (KeyCode::KeyX, "X"),
+    (KeyCode::KeyY, "Y"),
+    (KeyCode::KeyZ, "Z"),
+    (KeyCode::Digit0, "0"),
+    (KeyCode::Digit1, "1"),
+    (KeyCode::Digit2, "2"),
+    (KeyCode::Digit3, "3"),
+    (KeyCode::Digit4, "4"),
+    (KeyCode::Digit5, "5"),
+    (KeyCode::Digit6, "6"),
+    (KeyCode::Digit7, "7"),
+    (KeyCode::Digit8, "8"),
+    (KeyCode::Digit9, "9"),
+    (KeyCode::F1, "F1"),
+    (KeyCode::F2, "F2"),
+    (KeyCode::F3, "F3"),
+    (KeyCode::F4, "F4"),
+    (KeyCode::F5, "F5"),
+    (KeyCode::F6, "F6"),
+    (KeyCode::F7, "F7"),
+    (KeyCode::F8, "F8"),
+    (KeyCode::F9, "F9"),
+    (KeyCode::F10, "F10"),
+    (KeyCode::F11, "F11"),
+    (KeyCode::F12, "F12"),
+    (KeyCode::Escape, "Escape"),
+    (KeyCode::Space, "Space"),
+    (KeyCode::Enter, "Enter"),
+    (KeyCode::Tab, "Tab"),
+    (KeyCode::Backspace, "Backspace"),
+    (KeyCode::Delete, "Delete"),
+    (KeyCode::Insert, "Insert"),
+    (KeyCode::Home, "Home"),
+    (KeyCode::End, "End"),
+    (KeyCode::PageUp, "Page Up"),
+    (KeyCode::PageDown, "Page Down"),
+    (KeyCode::ArrowUp, "Up"),
+    (KeyCode::ArrowDown, "Down"),
+    (KeyCode::ArrowLeft, "Left"),
+    (KeyCode::ArrowRight, "Right"),
+];
+
+/// Name of a bindable key, or None if it can't be bound
+pub fn key_name(key: KeyCode) -> Option<&'static str> {
+    NAMED_KEYS.iter().find(|(code, _)| *code == key).map(|(_, name)| *name)
+}
+
+fn key_from_name(name: &str) -> Option<KeyCode> {
+    NAMED_KEYS.iter().find(|(_, n)| *n == name).map(|(code, _)| *code)
+}
+
+/// Keys are saved by name, so the settings file stays readable
+mod saved_key {
+    use bevy::prelude::KeyCode;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(key: &KeyCode, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(super::key_name(*key).unwrap_or("Escape"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<KeyCode, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        super::key_from_name(&name).ok_or_else(|| D::Error::custom(format!("unknown key {}", name)))
+    }
+}
+
+/// Which key does what
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    #[serde(with = "saved_key")]
+    pub stop_watching: KeyCode,
+    #[serde(with = "saved_key")]
+    pub stress_test: KeyCode,
+    #[serde(with = "saved_key")]
+    pub state_overlay: KeyCode,
+    #[serde(with = "saved_key")]
+    pub network_overlay: KeyCode,
+    /// Binding waiting for the player to press its new key
+    #[serde(skip)]
+    pub capturing: Option<KeyAction>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            stop_watching: KeyCode::Escape,
+            stress_test: KeyCode::F9,
+            state_overlay: KeyCode::F10,
+            network_overlay: KeyCode::F3,
+            capturing: None,
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Key bound to an action
+    pub fn key(&self, action: KeyAction) -> KeyCode {
+        match action {
+            KeyAction::StopWatching => self.stop_watching,
+            KeyAction::StressTest => self.stress_test,
+            KeyAction::StateOverlay => self.state_overlay,
+            KeyAction::NetworkOverlay => self.network_overlay,
+        }
+    }
+
+    fn key_mut(&mut self, action: KeyAction) -> &mut KeyCode {
+        match action {
+            KeyAction::StopWatching => &mut self.stop_watching,
+            KeyAction::StressTest => &mut self.stress_test,
+            KeyAction::StateOverlay => &mut self.state_overlay,
+            KeyAction::NetworkOverlay => &mut self.network_overlay,
+        }
+    }
+
+    /// Name of the key bound to an action, for hints like "Press Escape to stop"
+    pub fn key_name(&self, action: KeyAction) -> &'static str {
+        key_name(self.key(action)).unwrap_or("?")
+    }
+
+    /// Bind a key to an action. An action that already had the key gets the action's old one.
+    pub fn bind(&mut self, action: KeyAction, key: KeyCode) {
+        let previous = self.key(action);
+        if let Some(other) = KeyAction::ALL.into_iter().find(|a| *a != action && self.key(*a) == key) {
+            *self.key_mut(other) = previous;
+        }
+        *self.key_mut(action) = key;
+    }
+
+    /// Whether the action's key was pressed this frame. Nothing fires while a binding
+    /// is waiting for its new key.
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>, action: KeyAction) -> bool {
+        self.capturing.is_none() && keys.just_pressed(self.key(action))
+    }
+}
+
+/// The keyboard through the bindings, for systems that listen for a shortcut
+#[derive(SystemParam)]
+pub struct Shortcuts<'w> {
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    bindings: Res<'w, KeyBindings>,
+}
+
+impl Shortcuts<'_> {
+    /// Whether the action's key was pressed this frame (see `KeyBindings::just_pressed`)
+    pub fn just_pressed(&self, action: KeyAction) -> bool {
+        self.bindings.just_pressed(&self.keys, action)
+    }
+}
+
+/// System to give the binding being edited the next bindable key pressed
+pub fn capture_key_binding(mut keys: ResMut<ButtonInput<KeyCode>>, mut bindings: ResMut<KeyBindings>) {
+    let Some(action) = bindings.capturing else { return };
+    let Some(key) = keys.get_just_pressed().copied().find(|key| key_name(*key).is_some()) else { return };
+
+    // The press only rebinds: it shouldn't also do what it's now bound to
+    keys.clear_just_pressed(key);
+    bindings.bind(action, key);
+    bindings.capturing = None;
+}
@@ -0,0 +1,92 @@
+// Local leaderboard of the best results
+// The ten best final scores made on this device, with who made them, how many players
+// were in the game and when. The final results screen adds the scores of the people
+// playing here; the main menu shows the list.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::storage;
+
+/// Name the leaderboard is kept under in `storage`
+const LEADERBOARD_NAME: &str = "leaderboard";
+
+/// How many results the leaderboard keeps
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// One final score on the leaderboard
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub name: String,
+    pub money: i32,
+    /// How many players were in the game
+    pub player_count: usize,
+    /// When the game finished, as YYYY-MM-DD
+    pub date: String,
+}
+
+/// The best final scores, best first
+#[derive(Resource, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct Leaderboard {
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// Add a final score, keeping the best ones. Earlier results stay ahead of equal later ones.
+    pub fn record(&mut self, name: &str, money: i32, player_count: usize) {
+        let rank = self.entries.iter().position(|entry| money > entry.money).unwrap_or(self.entries.len());
+        if rank >= LEADERBOARD_SIZE {
+            return;
+        }
+        self.entries.insert(rank, LeaderboardEntry {
+            name: name.to_string(),
+            money,
+            player_count,
+            date: format_date(now_ms()),
+        });
+        self.entries.truncate(LEADERBOARD_SIZE);
+    }
+
+    /// Keep the leaderboard for next time
+    pub fn save(&self) {
+        if let Ok(json) = serde_json::to_string(self) {
+            storage::write(LEADERBOARD_NAME, &json);
+        }
+    }
+}
+
+/// Wall clock time in milliseconds
+#[cfg(target_arch = "wasm32")]
+fn now_ms() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// The UTC date of a Unix timestamp, as YYYY-MM-DD
+fn format_date(timestamp_ms: u64) -> String {
+    // Civil date from days since the epoch (Howard Hinnant's algorithm)
+    let days = (timestamp_ms / 86_400_000) as i64 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// System to pick up the leaderboard kept from earlier games
+pub fn load_leaderboard(mut leaderboard: ResMut<Leaderboard>) {
+    if let Some(saved) = storage::read(LEADERBOARD_NAME).and_then(|json| serde_json::from_str::<Leaderboard>(&json).ok()) {
+        *leaderboard = saved;
+    }
+}
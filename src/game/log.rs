@@ -0,0 +1,394 @@
+// Central game event log
+// Every gameplay system records what happened here; UI panels read from it.
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::components::{CamelColor, CamelNames, CrazyCamelColor, Players};
+use crate::game::desert_events::DesertEvent;
+
+/// Something that happened during a game
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum GameEvent {
+    GameStarted { player_names: Vec<String> },
+    DieRolled { player: String, color: CamelColor, value: u8 },
+    CrazyDieRolled { player: String, color: CrazyCamelColor, value: u8 },
+    LegBetTaken { player: String, color: CamelColor, value: u8 },
+    /// Race bet colors stay secret until the end of the game
+    RaceBetPlaced { player: String, is_winner_bet: bool },
+    SpectatorTilePlaced { player: String, space_index: u8, is_oasis: bool },
+    SpectatorTilePayout { owner: String, space_index: u8 },
+    LegScored { first: Option<CamelColor>, second: Option<CamelColor> },
+    DesertEventDrawn { event: DesertEvent },
+    CamelFinished { color: CamelColor },
+    /// Relay Race: a track was completed and the camels move on to the next one
+    RelayHandoff { track: u8, order: Vec<CamelColor> },
+    RaceBetResolved { player: String, color: CamelColor, is_winner_bet: bool, payout: i32 },
+    PlayerConceded { player: String },
+    /// The AI took over a seat its human left, without conceding it
+    SeatTakenOver { player: String },
+    /// A player who dropped out of an online game came back to their seat
+    SeatReturned { player: String },
+    /// The room's turn timer ran out on a player
+    TurnTimedOut { player: String },
+    /// Why an AI player chose its action, recorded just before the action
+    AiReasoning { player: String, reason: String },
+}
+
+impl GameEvent {
+    /// Describe the event as a plain English sentence
+    pub fn describe(&self, names: &CamelNames) -> String {
+        match self {
+            GameEvent::GameStarted { player_names } => {
+                format!("A new race begins with {}.", player_names.join(", "))
+            }
+            GameEvent::DieRolled { player, color, value } => {
+                format!("{} rolled the {:?} die: {} moves {} {}.", player, color, names.name(*color), value, spaces(*value))
+            }
+            GameEvent::CrazyDieRolled { player, color, value } => {
+                format!("{} rolled the crazy die: the {:?} crazy camel moves {} {} backwards.", player, color, value, spaces(*value))
+            }
+            GameEvent::LegBetTaken { player, color, value } => {
+                format!("{} took the {} leg bet tile worth {}.", player, names.name(*color), value)
+            }
+            GameEvent::RaceBetPlaced { player, is_winner_bet } => {
+                let bet_type = if *is_winner_bet { "winner" } else { "loser" };
+                format!("{} placed a secret bet on the overall {}.", player, bet_type)
+            }
+            GameEvent::SpectatorTilePlaced { player, space_index, is_oasis } => {
+                let tile_type = if *is_oasis { "an oasis" } else { "a mirage" };
+                format!("{} placed {} on space {}.", player, tile_type, space_index + 1)
+            }
+            GameEvent::SpectatorTilePayout { owner, space_index } => {
+                format!("A camel landed on space {}: {} earns $1.", space_index + 1, owner)
+            }
+            GameEvent::LegScored { first, second } => match (first, second) {
+                (Some(first), Some(second)) => {
+                    format!("The leg is over: {} leads, {} is second.", names.name(*first), names.name(*second))
+                }
+                _ => "The leg is over.".to_string(),
+            },
+            GameEvent::DesertEventDrawn { event } => {
+                format!("Desert event: {}! {}.", event.name(), event.description())
+            }
+            GameEvent::CamelFinished { color } => {
+                format!("{} crosses the finish line!", names.name(*color))
+            }
+            GameEvent::RelayHandoff { track, order } => {
+                let order: Vec<String> = order.iter().map(|color| names.name(*color)).collect();
+                format!(
+                    "Track {} is done! The camels line up for track {} in finishing order: {}.",
+                    track,
+                    track + 1,
+                    order.join(", ")
+                )
+            }
+            GameEvent::RaceBetResolved { player, color, is_winner_bet, payout } => {
+                let bet_type = if *is_winner_bet { "winner" } else { "loser" };
+                if *payout > 0 {
+                    format!("{}'s {} bet on {} was right: +${}.", player, bet_type, names.name(*color), payout)
+                } else {
+                    format!("{}'s {} bet on {} was wrong: -$1.", player, bet_type, names.name(*color))
+                }
+            }
+            GameEvent::PlayerConceded { player } => {
+                format!("{} conceded: the AI plays out their seat.", player)
+            }
+            GameEvent::SeatTakenOver { player } => {
+                format!("The AI takes over {}'s seat.", player)
+            }
+            GameEvent::SeatReturned { player } => {
+                format!("{} is back and takes over from the AI.", player)
+            }
+            GameEvent::TurnTimedOut { player } => {
+                format!("{} ran out of time: the pyramid is rolled for them.", player)
+            }
+            GameEvent::AiReasoning { player, reason } => {
+                format!("{} (AI): {}.", player, reason)
+            }
+        }
+    }
+}
+
+impl GameLogEntry {
+    /// Short line for the turn history panel, like "Leg 2 — Alice rolled Green 2".
+    /// None for entries too chatty for it (the AI's reasoning).
+    pub fn summary(&self, names: &CamelNames) -> Option<String> {
+        let text = match &self.event {
+            GameEvent::GameStarted { player_names } => format!("Race starts: {}", player_names.join(", ")),
+            GameEvent::DieRolled { player, color, value } => {
+                format!("{} rolled {} {}", player, names.name(*color), value)
+            }
+            GameEvent::CrazyDieRolled { player, color, value } => {
+                format!("{} rolled crazy {:?} {}", player, color, value)
+            }
+            GameEvent::LegBetTaken { player, color, value } => {
+                format!("{} bet {} ${}", player, names.name(*color), value)
+            }
+            GameEvent::RaceBetPlaced { player, is_winner_bet } => {
+                format!("{} placed a secret {} bet", player, if *is_winner_bet { "winner" } else { "loser" })
+            }
+            GameEvent::SpectatorTilePlaced { player, space_index, is_oasis } => {
+                let tile_type = if *is_oasis { "an oasis" } else { "a mirage" };
+                format!("{} placed {} on space {}", player, tile_type, space_index + 1)
+            }
+            GameEvent::SpectatorTilePayout { owner, space_index } => {
+                format!("{} +$1 from space {}", owner, space_index + 1)
+            }
+            GameEvent::LegScored { first, second } => {
+                let mut text = "Leg scoring:".to_string();
+                if let (Some(first), Some(second)) = (first, second) {
+                    text += &format!(" {} 1st, {} 2nd", names.name(*first), names.name(*second));
+                }
+                let payouts: Vec<String> = self
+                    .money
+                    .iter()
+                    .map(|change| format!("{} {:+}", change.player, change.delta))
+                    .collect();
+                if !payouts.is_empty() {
+                    text += &format!(" ({})", payouts.join(", "));
+                }
+                text
+            }
+            GameEvent::DesertEventDrawn { event } => format!("Desert event: {}", event.name()),
+            GameEvent::CamelFinished { color } => format!("{} crosses the finish line", names.name(*color)),
+            GameEvent::RelayHandoff { track, .. } => format!("Track {} done", track),
+            GameEvent::RaceBetResolved { player, color, is_winner_bet, payout } => {
+                let bet_type = if *is_winner_bet { "winner" } else { "loser" };
+                let result = if *payout > 0 { format!("+${}", payout) } else { "-$1".to_string() };
+                format!("{}'s {} bet on {}: {}", player, bet_type, names.name(*color), result)
+            }
+            GameEvent::PlayerConceded { player } => format!("{} conceded", player),
+            GameEvent::SeatTakenOver { player } => format!("AI takes over {}", player),
+            GameEvent::SeatReturned { player } => format!("{} is back", player),
+            GameEvent::TurnTimedOut { player } => format!("{} ran out of time", player),
+            GameEvent::AiReasoning { .. } => return None,
+        };
+        Some(format!("Leg {} — {}", self.leg, text))
+    }
+}
+
+fn spaces(value: u8) -> &'static str {
+    if value == 1 { "space" } else { "spaces" }
+}
+
+/// A rule check an action passed before it was applied
+#[derive(Clone, Debug, Serialize)]
+pub struct RuleCheck {
+    pub rule: &'static str,
+    pub detail: String,
+}
+
+impl RuleCheck {
+    pub fn new(rule: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            rule,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// A player's money changing, and what they had afterwards
+#[derive(Clone, Debug, Serialize)]
+pub struct MoneyChange {
+    pub player: String,
+    pub delta: i32,
+    pub money: i32,
+}
+
+/// Every player's money at one point of the game, by seat
+#[derive(Clone, Debug)]
+pub struct MoneySample {
+    pub label: String,
+    pub money: Vec<i32>,
+}
+
+/// Every player's money at the start and after each scoring, for the chart on the
+/// final results
+#[derive(Resource, Default)]
+pub struct MoneyTimeline {
+    pub samples: Vec<MoneySample>,
+}
+
+impl MoneyTimeline {
+    pub fn sample(&mut self, label: impl Into<String>, players: &Players) {
+        self.samples.push(MoneySample {
+            label: label.into(),
+            money: players.players.iter().map(|p| p.money).collect(),
+        });
+    }
+
+    /// Seat with the most money at each sample, or None while the lead is shared
+    pub fn leaders(&self) -> Vec<Option<usize>> {
+        self.samples
+            .iter()
+            .map(|sample| {
+                let top = sample.money.iter().max()?;
+                let mut leaders = sample.money.iter().enumerate().filter(|(_, money)| *money == top);
+                match (leaders.next(), leaders.next()) {
+                    (Some((seat, _)), None) => Some(seat),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+}
+
+/// A logged event with the leg and turn it happened in
+#[derive(Clone, Debug, Serialize)]
+pub struct GameLogEntry {
+    pub leg: u32,
+    pub turn: u32,
+    pub event: GameEvent,
+    /// Why the action was allowed (empty for events that aren't player actions)
+    pub checks: Vec<RuleCheck>,
+    /// Money that changed hands before the next event was logged
+    pub money: Vec<MoneyChange>,
+}
+
+/// Resource holding every event of the current game in order
+#[derive(Resource, Default)]
+pub struct GameLog {
+    pub entries: Vec<GameLogEntry>,
+    /// Camel names of this game, used when describing events
+    pub camel_names: CamelNames,
+    /// Turns played so far, counting the one in progress
+    pub turn: u32,
+    /// Each seat's money when last checked, to tell what changed since
+    last_money: Vec<i32>,
+}
+
+impl GameLog {
+    pub fn new(camel_names: CamelNames) -> Self {
+        Self {
+            entries: Vec::new(),
+            camel_names,
+            turn: 1,
+            last_money: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, leg: u32, event: GameEvent) {
+        self.record_action(leg, event, Vec::new());
+    }
+
+    /// Record an action along with the rule checks that allowed it
+    pub fn record_action(&mut self, leg: u32, event: GameEvent, checks: Vec<RuleCheck>) {
+        info!("[log] {}", event.describe(&self.camel_names));
+        for check in &checks {
+            debug!("[rules] {}: {}", check.rule, check.detail);
+        }
+        self.entries.push(GameLogEntry { leg, turn: self.turn, event, checks, money: Vec::new() });
+    }
+
+    /// Note every change in the players' money since the last check, against the
+    /// latest event
+    pub fn record_money(&mut self, players: &Players) {
+        if self.last_money.len() != players.players.len() {
+            self.last_money = players.players.iter().map(|p| p.money).collect();
+            return;
+        }
+        for (index, player) in players.players.iter().enumerate() {
+            self.record_player_money(index, &player.name, player.money);
+        }
+    }
+
+    /// Note one player's money against the latest entry, for changes made together but
+    /// logged one at a time
+    pub fn record_player_money(&mut self, index: usize, player: &str, money: i32) {
+        let (Some(entry), Some(last)) = (self.entries.last_mut(), self.last_money.get_mut(index)) else { return };
+        if money != *last {
+            entry.money.push(MoneyChange {
+                player: player.to_string(),
+                delta: money - *last,
+                money,
+            });
+            *last = money;
+        }
+    }
+
+    /// A player's money after each change this game, from what they started with to
+    /// `money_now`
+    pub fn money_history(&self, player: &str, money_now: i32) -> Vec<i32> {
+        let mut changes = self.entries.iter().flat_map(|entry| &entry.money).filter(|change| change.player == player);
+        let Some(first) = changes.next() else { return vec![money_now] };
+        let mut history = vec![first.money - first.delta, first.money];
+        history.extend(changes.map(|change| change.money));
+        if history.last() != Some(&money_now) {
+            history.push(money_now);
+        }
+        history
+    }
+
+    /// The whole game as a JSON document, for strategy analysis and bug reports
+    pub fn to_json(&self, players: &Players) -> String {
+        let export = LogExport {
+            camels: CamelColor::all()
+                .into_iter()
+                .map(|color| ExportedCamel { color, name: self.camel_names.name(color) })
+                .collect(),
+            players: players
+                .players
+                .iter()
+                .map(|p| ExportedPlayer { name: &p.name, is_ai: p.is_ai, money: p.money, conceded: p.conceded })
+                .collect(),
+            entries: self
+                .entries
+                .iter()
+                .map(|entry| ExportedEntry { text: entry.event.describe(&self.camel_names), entry })
+                .collect(),
+        };
+        serde_json::to_string_pretty(&export).unwrap_or_default()
+    }
+
+    /// Full narrative of the game as plain text, one sentence per line
+    pub fn to_text(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| format!("Leg {}: {}", entry.leg, entry.event.describe(&self.camel_names)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Layout of the exported log
+#[derive(Serialize)]
+struct LogExport<'a> {
+    camels: Vec<ExportedCamel>,
+    /// Seats in turn order, with how they finished
+    players: Vec<ExportedPlayer<'a>>,
+    entries: Vec<ExportedEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct ExportedCamel {
+    color: CamelColor,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct ExportedPlayer<'a> {
+    name: &'a str,
+    is_ai: bool,
+    money: i32,
+    conceded: bool,
+}
+
+/// A log entry along with the sentence the log panel shows for it
+#[derive(Serialize)]
+struct ExportedEntry<'a> {
+    text: String,
+    #[serde(flatten)]
+    entry: &'a GameLogEntry,
+}
+
+/// System to note every change in the players' money in the log, so the exported log
+/// shows what each action was worth
+pub fn track_money_changes(players: Option<Res<Players>>, mut game_log: ResMut<GameLog>) {
+    if let Some(players) = players {
+        if players.is_changed() {
+            game_log.record_money(&players);
+        }
+    }
+}
@@ -1,4 +1,22 @@
 pub mod state;
 pub mod rules;
-pub mod scoring;
+pub mod core;
+pub mod snapshot;
+pub mod actions;
+pub mod rng;
 pub mod ai;
+pub mod ai_weights;
+pub mod probability;
+pub mod simulation;
+pub mod log;
+pub mod desert_events;
+pub mod relay;
+pub mod autosave;
+pub mod storage;
+pub mod replay;
+pub mod stats;
+pub mod leaderboard;
+pub mod settings;
+pub mod key_bindings;
+#[cfg(feature = "ai_benchmark")]
+pub mod benchmark;
@@ -0,0 +1,384 @@
+// Race probability and reachability helpers
+// Pure calculations over a snapshot of the board so they can be shared by
+// the AI and analytical overlays without touching the ECS world.
+
+use bevy::ecs::query::QueryFilter;
+use bevy::prelude::*;
+
+use crate::components::dice::PyramidDie;
+use crate::components::{
+    BoardPosition, Camel, CamelColor, CrazyCamel, CrazyCamelColor, PlacedSpectatorTiles, Pyramid,
+    TRACK_LENGTH,
+};
+use crate::game::core::{
+    resolve_landing, rolls_left_in_leg, DICE_PER_LEG, MAX_DIE_VALUE, MIN_DIE_VALUE,
+};
+
+/// Position of a single racing camel within a snapshot
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CamelSlot {
+    pub color: CamelColor,
+    pub space: u8,
+    pub stack: u8, // 0 = bottom of stack
+}
+
+/// Copy of the race state used for probability calculations
+#[derive(Clone, Debug, Default)]
+pub struct RaceSnapshot {
+    pub camels: Vec<CamelSlot>,
+    /// Racing camels whose die is still in the pyramid
+    pub unrolled: Vec<CamelColor>,
+    /// How many more dice will be rolled before the leg ends
+    pub dice_left_to_roll: usize,
+}
+
+impl RaceSnapshot {
+    /// Build a snapshot from the live camel positions and pyramid
+    pub fn from_world<F: QueryFilter>(
+        camels: &Query<(&Camel, &BoardPosition), F>,
+        pyramid: &Pyramid,
+    ) -> Self {
+        let camels = camels
+            .iter()
+            .map(|(camel, pos)| CamelSlot {
+                color: camel.color,
+                space: pos.space_index,
+                stack: pos.stack_position,
+            })
+            .collect();
+
+        let unrolled = pyramid
+            .dice
+            .iter()
+            .filter_map(|die| match die {
+                PyramidDie::Regular(regular) => Some(regular.color),
+                PyramidDie::Crazy { .. } => None,
+            })
+            .collect();
+
+        Self {
+            camels,
+            unrolled,
+            dice_left_to_roll: rolls_left_in_leg(pyramid.rolled_dice.len()),
+        }
+    }
+
+    pub fn slot(&self, color: CamelColor) -> Option<CamelSlot> {
+        self.camels.iter().find(|c| c.color == color).copied()
+    }
+
+    /// Camels sorted by race position (first = leader)
+    pub fn rankings(&self) -> Vec<CamelSlot> {
+        let mut rankings = self.camels.clone();
+        rankings.sort_by(|a, b| b.space.cmp(&a.space).then(b.stack.cmp(&a.stack)));
+        rankings
+    }
+
+    pub fn leader(&self) -> Option<CamelColor> {
+        self.rankings().first().map(|c| c.color)
+    }
+}
+
+/// Range of spaces a camel can still cover during the current leg
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReachableRange {
+    /// Fewest spaces it moves if it moves at all
+    pub min: u8,
+    /// Most spaces it can move if every die that carries it rolls high
+    pub max: u8,
+}
+
+impl ReachableRange {
+    pub fn can_move(&self) -> bool {
+        self.max > 0
+    }
+}
+
+/// Calculate how far a racing camel can still travel this leg.
+/// A camel moves when its own die is rolled or when a camel beneath it in its
+/// stack moves. Spectator tiles and crazy camels are not considered.
+pub fn reachable_range(snapshot: &RaceSnapshot, color: CamelColor) -> Option<ReachableRange> {
+    let slot = snapshot.slot(color)?;
+
+    // Unrolled dice belonging to this camel or any camel carrying it
+    let dice = snapshot
+        .camels
+        .iter()
+        .filter(|c| c.space == slot.space && c.stack <= slot.stack)
+        .filter(|c| snapshot.unrolled.contains(&c.color))
+        .count();
+    let carriers = dice.min(snapshot.dice_left_to_roll);
+    if carriers == 0 {
+        return Some(ReachableRange { min: 0, max: 0 });
+    }
+
+    // The pyramid holds one die more than the leg rolls, so all but one of those dice are
+    // sure to be rolled; a carried camel can't land closer than one smallest roll per die
+    let sure = dice.saturating_sub(1).clamp(1, carriers);
+    Some(ReachableRange {
+        min: sure as u8 * MIN_DIE_VALUE,
+        max: carriers as u8 * MAX_DIE_VALUE,
+    })
+}
+
+/// Camel stacks per space, bottom to top. Spaces past the finish are added as needed.
+pub type TrackStacks = Vec<Vec<CamelColor>>;
+
+/// Move a camel (and every camel stacked on top of it) forward, landing on top
+pub fn move_camel(stacks: &mut TrackStacks, color: CamelColor, spaces: u8) {
+    let Some((space, height)) = stacks.iter().enumerate().find_map(|(space, stack)| {
+        stack.iter().position(|&c| c == color).map(|height| (space, height))
+    }) else {
+        return;
+    };
+
+    let moving = stacks[space].split_off(height);
+    let target = space + spaces as usize;
+    if target >= stacks.len() {
+        stacks.resize(target + 1, Vec::new());
+    }
+    stacks[target].extend(moving);
+}
+
+/// Camels in race order (first = leader) for a set of stacks
+pub fn stack_rankings(stacks: &TrackStacks) -> Vec<CamelColor> {
+    stacks
+        .iter()
+        .rev()
+        .flat_map(|stack| stack.iter().rev().copied())
+        .collect()
+}
+
+/// Anything that occupies a spot in a camel stack
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Piece {
+    Racer(CamelColor),
+    Crazy(CrazyCamelColor),
+}
+
+/// A die that can still come out of the pyramid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimDie {
+    Regular(CamelColor),
+    /// The shared gray die: moves the white or black crazy camel backwards
+    Crazy,
+}
+
+/// Full copy of the board for simulating rolls: racing and crazy camels,
+/// spectator tiles and the dice left in the pyramid
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaceBoard {
+    /// Pieces per space, bottom to top. Spaces past the finish are added as needed.
+    pub stacks: Vec<Vec<Piece>>,
+    /// Spectator tiles as (space, is_oasis)
+    pub spectator_tiles: Vec<(u8, bool)>,
+    pub remaining_dice: Vec<SimDie>,
+    pub dice_left_to_roll: usize,
+}
+
+impl RaceBoard {
+    /// Build a board from the live camel positions, spectator tiles and pyramid
+    pub fn from_world<F1: QueryFilter, F2: QueryFilter>(
+        camels: &Query<(&Camel, &BoardPosition), F1>,
+        crazy_camels: &Query<(&CrazyCamel, &BoardPosition), F2>,
+        pyramid: &Pyramid,
+        placed_tiles: &PlacedSpectatorTiles,
+    ) -> Self {
+        let mut placed: Vec<(u8, u8, Piece)> = camels
+            .iter()
+            .map(|(camel, pos)| (pos.space_index, pos.stack_position, Piece::Racer(camel.color)))
+            .chain(crazy_camels.iter().map(|(camel, pos)| {
+                (pos.space_index, pos.stack_position, Piece::Crazy(camel.color))
+            }))
+            .collect();
+        placed.sort_by_key(|(space, stack, _)| (*space, *stack));
+
+        let mut stacks = vec![Vec::new(); TRACK_LENGTH as usize];
+        for (space, _, piece) in placed {
+            if space as usize >= stacks.len() {
+                stacks.resize(space as usize + 1, Vec::new());
+            }
+            stacks[space as usize].push(piece);
+        }
+
+        let mut board = Self {
+            stacks,
+            spectator_tiles: placed_tiles
+                .tiles
+                .iter()
+                .map(|(&space, &(_, is_oasis))| (space, is_oasis))
+                .collect(),
+            remaining_dice: Vec::new(),
+            dice_left_to_roll: 0,
+        };
+        board.set_pyramid(pyramid);
+        board
+    }
+
+    /// Copy the dice still in the pyramid, and how many more will be rolled this leg
+    pub fn set_pyramid(&mut self, pyramid: &Pyramid) {
+        self.remaining_dice = pyramid
+            .dice
+            .iter()
+            .map(|die| match die {
+                PyramidDie::Regular(regular) => SimDie::Regular(regular.color),
+                PyramidDie::Crazy { .. } => SimDie::Crazy,
+            })
+            .collect();
+        self.dice_left_to_roll = rolls_left_in_leg(pyramid.rolled_dice.len());
+    }
+
+    fn find(&self, piece: Piece) -> Option<(usize, usize)> {
+        self.stacks.iter().enumerate().find_map(|(space, stack)| {
+            stack.iter().position(|&p| p == piece).map(|height| (space, height))
+        })
+    }
+
+    /// Move a racing camel and everything on top of it forward, applying spectator tiles.
+    /// Returns the space it first landed on (before any tile effect).
+    pub fn move_racer(&mut self, color: CamelColor, spaces: u8) -> Option<u8> {
+        let (space, height) = self.find(Piece::Racer(color))?;
+        let moving = self.stacks[space].split_off(height);
+
+        let landed = space as u8 + spaces;
+        let tile = self
+            .spectator_tiles
+            .iter()
+            .find(|(tile, _)| *tile == landed)
+            .map(|&(_, is_oasis)| (0, is_oasis));
+        let landing = resolve_landing(landed, tile);
+        let target = landing.space as usize;
+
+        if target >= self.stacks.len() {
+            self.stacks.resize(target + 1, Vec::new());
+        }
+        if landing.underneath {
+            let above = std::mem::replace(&mut self.stacks[target], moving);
+            self.stacks[target].extend(above);
+        } else {
+            self.stacks[target].extend(moving);
+        }
+        Some(landed)
+    }
+
+    /// Move a crazy camel and everything on top of it backwards, landing on top
+    pub fn move_crazy(&mut self, color: CrazyCamelColor, spaces: u8) {
+        let Some((space, height)) = self.find(Piece::Crazy(color)) else { return };
+        let moving = self.stacks[space].split_off(height);
+        let target = space.saturating_sub(spaces as usize);
+        self.stacks[target].extend(moving);
+    }
+
+    /// Whether a racing camel has crossed the finish line
+    pub fn finished(&self) -> bool {
+        self.stacks
+            .iter()
+            .skip(TRACK_LENGTH as usize)
+            .any(|stack| stack.iter().any(|p| matches!(p, Piece::Racer(_))))
+    }
+
+    /// Racing camels in race order (first = leader)
+    pub fn rankings(&self) -> Vec<CamelColor> {
+        self.stacks
+            .iter()
+            .rev()
+            .flat_map(|stack| stack.iter().rev())
+            .filter_map(|piece| match piece {
+                Piece::Racer(color) => Some(*color),
+                Piece::Crazy(_) => None,
+            })
+            .collect()
+    }
+
+    /// Spaces a spectator tile can go on: not the start space, and no camel or tile there
+    pub fn valid_spectator_spaces(&self) -> Vec<u8> {
+        (1..TRACK_LENGTH)
+            .filter(|&space| {
+                self.stacks.get(space as usize).is_none_or(|stack| stack.is_empty())
+                    && !self.spectator_tiles.iter().any(|(tile, _)| *tile == space)
+            })
+            .collect()
+    }
+
+    /// Put every die back in the pyramid and clear spectator tiles for a new leg
+    pub fn start_new_leg(&mut self) {
+        self.remaining_dice = CamelColor::all().into_iter().map(SimDie::Regular).collect();
+        self.remaining_dice.push(SimDie::Crazy);
+        self.dice_left_to_roll = DICE_PER_LEG;
+        self.spectator_tiles.clear();
+    }
+}
+
+/// Index of a camel color in `CamelColor::all()`, for per-camel arrays
+pub fn color_index(color: CamelColor) -> usize {
+    CamelColor::all().iter().position(|&c| c == color).unwrap_or(0)
+}
+
+/// Exact odds for the rest of the current leg, indexed like `CamelColor::all()`
+#[derive(Clone, Debug, Default)]
+pub struct LegOdds {
+    pub first: [f32; 5],
+    pub second: [f32; 5],
+    /// Expected number of racing camel landings per space for the rest of the leg
+    pub landings: [f32; TRACK_LENGTH as usize],
+    /// The same landings split by which camel's die moved it, indexed like `first`
+    pub landings_by_camel: [[f32; TRACK_LENGTH as usize]; 5],
+}
+
+/// Calculate exact leg odds by enumerating every order the remaining dice can come
+/// out of the pyramid and every face they can show, crazy camels and spectator tiles
+/// included. At most 6 dice x 5 rolls remain, so this stays in the low hundreds of
+/// thousands of outcomes.
+pub fn exact_leg_odds(board: &RaceBoard) -> LegOdds {
+    let mut odds = LegOdds::default();
+    enumerate_leg(board, 1.0, &mut odds);
+    odds
+}
+
+/// Walk every outcome of the next roll, weighting each branch by its probability
+fn enumerate_leg(board: &RaceBoard, weight: f32, odds: &mut LegOdds) {
+    if board.dice_left_to_roll == 0 || board.remaining_dice.is_empty() || board.finished() {
+        let rankings = board.rankings();
+        if let Some(&first) = rankings.first() {
+            odds.first[color_index(first)] += weight;
+        }
+        if let Some(&second) = rankings.get(1) {
+            odds.second[color_index(second)] += weight;
+        }
+        return;
+    }
+
+    let die_weight = weight / board.remaining_dice.len() as f32;
+    for index in 0..board.remaining_dice.len() {
+        let mut after_draw = board.clone();
+        let die = after_draw.remaining_dice.remove(index);
+        after_draw.dice_left_to_roll -= 1;
+
+        match die {
+            SimDie::Regular(color) => {
+                let face_weight = die_weight / MAX_DIE_VALUE as f32;
+                for value in 1..=MAX_DIE_VALUE {
+                    let mut next = after_draw.clone();
+                    if let Some(landed) = next.move_racer(color, value) {
+                        if let Some(count) = odds.landings.get_mut(landed as usize) {
+                            *count += face_weight;
+                            odds.landings_by_camel[color_index(color)][landed as usize] += face_weight;
+                        }
+                    }
+                    enumerate_leg(&next, face_weight, odds);
+                }
+            }
+            SimDie::Crazy => {
+                // Six faces: 1-3 for each of the white and black crazy camels
+                let face_weight = die_weight / (2.0 * MAX_DIE_VALUE as f32);
+                for color in [CrazyCamelColor::White, CrazyCamelColor::Black] {
+                    for value in 1..=MAX_DIE_VALUE {
+                        let mut next = after_draw.clone();
+                        next.move_crazy(color, value);
+                        enumerate_leg(&next, face_weight, odds);
+                    }
+                }
+            }
+        }
+    }
+}
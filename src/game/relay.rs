@@ -0,0 +1,190 @@
+// Relay Race variant (experimental)
+// The race is run over two tracks. When a camel crosses the finish of track one, the
+// order the camels finished in seeds where they start on track two, leader furthest
+// ahead. The leg carries on across the handoff, and only the finish of the last track
+// ends the game and decides the race bets.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::board::BoardSpace;
+use crate::components::*;
+use crate::game::log::{GameEvent, GameLog};
+use crate::systems::animation::{MovementAnimation, MultiStepMovementAnimation};
+use crate::systems::movement::MovementCompleteEvent;
+
+/// Number of tracks raced in a relay game
+pub const RELAY_TRACKS: usize = 2;
+
+/// Start space on the next track for each finishing place (first place first)
+const RELAY_START_SPACES: [u8; 5] = [2, 1, 1, 0, 0];
+
+/// Seconds to let the finishing hop play out before the camels walk to the next track
+const HANDOFF_DELAY: f32 = 1.2;
+/// Seconds the walk to the new start spaces takes
+const HANDOFF_WALK_DURATION: f32 = 0.8;
+
+/// Shade laid over the later tracks' painted spaces, darker so the new board reads as a
+/// new board
+const NEXT_TRACK_SPACE_COLOR: Color = Color::srgba(0.55, 0.35, 0.2, 0.35);
+
+/// State of the Relay Race variant
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+pub struct RelayRace {
+    pub enabled: bool,
+    /// Order the camels finished each completed track in, first place first
+    pub finish_orders: Vec<Vec<CamelColor>>,
+    /// Counts down while the camels wait to walk to the next track
+    handoff_timer: f32,
+}
+
+impl RelayRace {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            ..default()
+        }
+    }
+
+    /// Tracks the board needs for this game
+    pub fn track_count(&self) -> usize {
+        if self.enabled { RELAY_TRACKS } else { 1 }
+    }
+
+    /// Whether the camels are moving between tracks right now
+    pub fn handing_off(&self) -> bool {
+        self.handoff_timer > 0.0
+    }
+}
+
+/// Start space of a finishing place on the next track
+fn start_space(place: usize) -> u8 {
+    RELAY_START_SPACES.get(place).copied().unwrap_or(0)
+}
+
+/// (space, stack position) on the next track for each camel in finishing order.
+/// Camels sharing a start space keep their order: the better finisher rides on top.
+pub fn relay_start_positions(order: &[CamelColor]) -> Vec<(CamelColor, u8, u8)> {
+    order
+        .iter()
+        .enumerate()
+        .map(|(place, &color)| {
+            let space = start_space(place);
+            let below = (place + 1..order.len()).filter(|&p| start_space(p) == space).count();
+            (color, space, below as u8)
+        })
+        .collect()
+}
+
+/// The board the race is handed across: its tracks and spaces, and the spectator tiles
+/// cleared off the finished track
+#[derive(SystemParam)]
+pub struct RelayTrack<'w, 's> {
+    commands: Commands<'w, 's>,
+    board: ResMut<'w, GameBoard>,
+    board_spaces: Query<'w, 's, (&'static mut BoardSpace, &'static mut Sprite)>,
+    spectator_tiles: Query<'w, 's, Entity, With<SpectatorTile>>,
+}
+
+/// System to move the race on to the next track when a camel finishes one that isn't
+/// the last. Runs before the game end check, which skips finishes handed off here.
+pub fn relay_handoff_system(
+    time: Res<Time>,
+    mut events: MessageReader<MovementCompleteEvent>,
+    relay: Option<ResMut<RelayRace>>,
+    track: RelayTrack,
+    mut camels: Query<(Entity, &Camel, &mut BoardPosition, &Transform), Without<CrazyCamel>>,
+    mut crazy_camels: Query<(Entity, &CrazyCamel, &mut BoardPosition, &Transform), Without<Camel>>,
+    (mut placed_tiles, mut players, mut game_log, turn_state): (
+        ResMut<PlacedSpectatorTiles>,
+        ResMut<Players>,
+        ResMut<GameLog>,
+        Res<TurnState>,
+    ),
+) {
+    let Some(mut relay) = relay else { return };
+    if !relay.enabled {
+        return;
+    }
+    let RelayTrack { mut commands, mut board, mut board_spaces, spectator_tiles } = track;
+
+    for event in events.read() {
+        if !event.crossed_finish || relay.handing_off() || !board.advance_track() {
+            continue;
+        }
+
+        // Finishing order: furthest along first, top of a stack ahead of those below
+        let mut standings: Vec<(CamelColor, u8, u8)> = camels
+            .iter()
+            .map(|(_, camel, pos, _)| (camel.color, pos.space_index, pos.stack_position))
+            .collect();
+        standings.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)));
+        let order: Vec<CamelColor> = standings.into_iter().map(|(color, _, _)| color).collect();
+
+        info!("Relay handoff: track {} finished in order {:?}", board.active_track, order);
+        game_log.record(turn_state.leg_number, GameEvent::RelayHandoff {
+            track: board.active_track as u8,
+            order: order.clone(),
+        });
+
+        for (color, space, stack) in relay_start_positions(&order) {
+            if let Some((_, _, mut pos, _)) = camels.iter_mut().find(|(_, camel, _, _)| camel.color == color) {
+                pos.space_index = space;
+                pos.stack_position = stack;
+            }
+        }
+
+        // Crazy camels start again from the far end, in the order they were running
+        let mut crazy: Vec<_> = crazy_camels.iter_mut().collect();
+        crazy.sort_by_key(|(_, _, pos, _)| std::cmp::Reverse((pos.space_index, pos.stack_position)));
+        for (i, (_, _, pos, _)) in crazy.iter_mut().enumerate() {
+            pos.space_index = TRACK_LENGTH - 1 - i as u8;
+            pos.stack_position = 0;
+        }
+
+        // Spectator tiles belong to the track they were placed on
+        placed_tiles.clear();
+        for player in players.players.iter_mut() {
+            player.has_spectator_tile = true;
+        }
+        for entity in spectator_tiles.iter() {
+            commands.entity(entity).despawn();
+        }
+
+        relay.finish_orders.push(order);
+        relay.handoff_timer = HANDOFF_DELAY;
+    }
+
+    if !relay.handing_off() {
+        return;
+    }
+    relay.handoff_timer -= time.delta_secs();
+    if relay.handing_off() {
+        return;
+    }
+    relay.handoff_timer = 0.0;
+
+    // Walk every camel to wherever the handoff (and any roll since) left it
+    let walks = camels
+        .iter()
+        .map(|(entity, _, pos, transform)| (entity, pos, transform))
+        .chain(crazy_camels.iter().map(|(entity, _, pos, transform)| (entity, pos, transform)));
+    for (entity, pos, transform) in walks {
+        let base = board.get_position(pos.space_index);
+        let end = Vec3::new(
+            base.x,
+            base.y + pos.stack_position as f32 * 25.0,
+            10.0 + pos.stack_position as f32,
+        );
+        commands
+            .entity(entity)
+            .remove::<MultiStepMovementAnimation>()
+            .insert(MovementAnimation::new(transform.translation, end, HANDOFF_WALK_DURATION));
+    }
+
+    for (mut space, mut sprite) in board_spaces.iter_mut() {
+        space.base_color = NEXT_TRACK_SPACE_COLOR;
+        sprite.color = NEXT_TRACK_SPACE_COLOR;
+    }
+}
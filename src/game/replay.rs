@@ -0,0 +1,321 @@
+// Game replays
+// Every action a local or hosted game applies is recorded along with the seed it was
+// set up from. That is all online clients need to play the host's game, so a replay
+// is watched the same way: the recorded seed sets up the table and the actions are
+// fed to the client's log replay one at a time, through the usual movement and
+// animations. Playback can be paused, stepped an action at a time, and sped up.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use serde::{Deserialize, Serialize};
+
+use crate::game::actions::GameAction;
+use crate::game::ai::AiDifficulty;
+use crate::game::state::GameState;
+use crate::game::storage;
+use crate::network::messages::{SequencedAction, PROTOCOL_VERSION};
+use crate::network::state::{NetworkMode, NetworkState, OutgoingNetworkActions, ReceivedGameState};
+use crate::systems::autoplay::AutoplayConfig;
+use crate::ui::characters::CharacterId;
+use crate::ui::player_setup::{PlayerConfig, PlayerSetupConfig};
+use crate::ui::theme::{desert_button, desert_button_enabled, DesertButtonStyle};
+
+/// Name the last finished game's replay is kept under in `storage`
+const REPLAY_NAME: &str = "replay";
+
+/// Playback speeds to cycle through
+const SPEEDS: [f32; 4] = [0.5, 1.0, 2.0, 4.0];
+
+/// Game seconds between turns while watching, so each one can be followed
+const TURN_PAUSE_SECS: f32 = 0.8;
+
+/// A player's seat as the game began
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReplaySeat {
+    pub name: String,
+    pub is_ai: bool,
+    pub character_id: u8,
+    pub color_index: usize,
+    #[serde(default)]
+    pub custom_color: Option<[u8; 3]>,
+    pub ai_difficulty: AiDifficulty,
+}
+
+/// Everything needed to play a game again: how it was set up, and what happened
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Replay {
+    /// `PROTOCOL_VERSION` of the build that recorded it, which must match to play it back
+    pub protocol: u32,
+    pub seed: u64,
+    pub sheikh_variant: bool,
+    pub desert_events: bool,
+    pub relay_race: bool,
+    pub camel_names: [String; 5],
+    /// Seats in turn order
+    pub seats: Vec<ReplaySeat>,
+    pub actions: Vec<GameAction>,
+}
+
+/// The game being recorded, set up fresh with every game
+#[derive(Resource)]
+pub struct ReplayRecording {
+    pub replay: Replay,
+    /// The actions don't tell the whole story: the game was continued from a save, or
+    /// is itself a replay
+    pub partial: bool,
+}
+
+impl ReplayRecording {
+    pub fn new(seed: u64, config: &PlayerSetupConfig, ordered_players: &[PlayerConfig]) -> Self {
+        Self {
+            replay: Replay {
+                protocol: PROTOCOL_VERSION,
+                seed,
+                sheikh_variant: config.sheikh_variant,
+                desert_events: config.desert_events,
+                relay_race: config.relay_race,
+                camel_names: config.camel_names.clone(),
+                seats: ordered_players
+                    .iter()
+                    .map(|p| ReplaySeat {
+                        name: p.name.clone(),
+                        is_ai: p.is_ai,
+                        character_id: p.character_id.index() as u8,
+                        color_index: p.color_index,
+                        custom_color: p.custom_color,
+                        ai_difficulty: p.ai_difficulty,
+                    })
+                    .collect(),
+                actions: Vec::new(),
+            },
+            partial: false,
+        }
+    }
+}
+
+/// The last finished game's replay, and the state of its playback while it's watched
+#[derive(Resource)]
+pub struct ReplayViewer {
+    pub last: Option<Replay>,
+    /// The replay being watched
+    pub watching: Option<Replay>,
+    /// Actions handed to the game so far
+    pub fed: usize,
+    pub paused: bool,
+    /// Hand over one more action while paused
+    pub step: bool,
+    pub speed: f32,
+    /// Game seconds left before the next turn action is handed over
+    wait: f32,
+}
+
+impl Default for ReplayViewer {
+    fn default() -> Self {
+        Self {
+            last: None,
+            watching: None,
+            fed: 0,
+            paused: false,
+            step: false,
+            speed: 1.0,
+            wait: 0.0,
+        }
+    }
+}
+
+impl ReplayViewer {
+    /// Set up the last game's table and start watching it
+    pub fn start(&mut self, config: &mut PlayerSetupConfig, network_state: &mut NetworkState) -> bool {
+        let Some(replay) = self.last.clone() else { return false };
+
+        config.sheikh_variant = replay.sheikh_variant;
+        config.desert_events = replay.desert_events;
+        config.relay_race = replay.relay_race;
+        config.camel_names = replay.camel_names.clone();
+        config.randomize_start_order = false;
+        config.players = replay
+            .seats
+            .iter()
+            .map(|seat| PlayerConfig {
+                name: seat.name.clone(),
+                is_ai: seat.is_ai,
+                character_id: CharacterId::from_index(seat.character_id as usize),
+                color_index: seat.color_index,
+                custom_color: seat.custom_color,
+                name_edited: true,
+                ai_difficulty: seat.ai_difficulty,
+            })
+            .collect();
+
+        network_state.mode = NetworkMode::Replay;
+        network_state.game_seed = Some(replay.seed);
+        network_state.actions_logged = 0;
+        network_state.pending_replay.clear();
+        network_state.resyncing = false;
+
+        *self = ReplayViewer { last: self.last.take(), watching: Some(replay), ..default() };
+        info!("Watching a replay of {} actions", self.watching.as_ref().map_or(0, |r| r.actions.len()));
+        true
+    }
+
+    /// Actions in the replay being watched
+    pub fn total(&self) -> usize {
+        self.watching.as_ref().map_or(0, |replay| replay.actions.len())
+    }
+}
+
+/// System to pick up the last game's replay from the last time the app ran, if this
+/// build can still play it
+pub fn load_last_replay(mut viewer: ResMut<ReplayViewer>) {
+    viewer.last = storage::read(REPLAY_NAME)
+        .and_then(|json| serde_json::from_str::<Replay>(&json).ok())
+        .filter(|replay| replay.protocol == PROTOCOL_VERSION);
+}
+
+/// System to collect the actions the game applied into its recording
+pub fn record_actions(
+    recording: Option<ResMut<ReplayRecording>>,
+    mut outgoing_actions: ResMut<OutgoingNetworkActions>,
+    network_state: Res<NetworkState>,
+    received_state: Res<ReceivedGameState>,
+) {
+    let Some(mut recording) = recording else { return };
+    if recording.is_added() {
+        // Anything left over belongs to the last game
+        outgoing_actions.recorded.clear();
+    }
+    if received_state.restoring || network_state.is_replay() {
+        recording.partial = true;
+    }
+    recording.replay.actions.append(&mut outgoing_actions.recorded);
+}
+
+/// System to keep the finished game's replay, to watch from the main menu. Games the
+/// AI played by itself aren't worth replacing it with.
+pub fn save_replay(
+    recording: Option<Res<ReplayRecording>>,
+    mut viewer: ResMut<ReplayViewer>,
+    autoplay: Res<AutoplayConfig>,
+) {
+    let Some(recording) = recording else { return };
+    if recording.partial || recording.replay.actions.is_empty() || autoplay.active {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string(&recording.replay) {
+        storage::write(REPLAY_NAME, &json);
+        viewer.last = Some(recording.replay.clone());
+    }
+}
+
+/// System to hand the replay's actions to the game, one at a time, for the log replay
+/// to apply once the game is ready for each
+pub fn feed_replay(
+    mut viewer: ResMut<ReplayViewer>,
+    mut network_state: ResMut<NetworkState>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    time: Res<Time>,
+) {
+    if !network_state.is_replay() {
+        return;
+    }
+    if virtual_time.relative_speed() != viewer.speed {
+        virtual_time.set_relative_speed(viewer.speed);
+    }
+
+    viewer.wait = (viewer.wait - time.delta_secs()).max(0.0);
+    if !network_state.pending_replay.is_empty() || (viewer.paused && !viewer.step) {
+        return;
+    }
+    let Some(action) = viewer.watching.as_ref().and_then(|replay| replay.actions.get(viewer.fed)).cloned() else {
+        return;
+    };
+    // Pause between turns, unless stepping through them
+    if action.is_turn_action() && viewer.wait > 0.0 && !viewer.step {
+        return;
+    }
+    if action.is_turn_action() {
+        viewer.wait = TURN_PAUSE_SECS;
+    }
+
+    viewer.fed += 1;
+    viewer.step = false;
+    network_state.pending_replay.push(SequencedAction {
+        sequence: viewer.fed as u32,
+        action,
+        fairness: None,
+        checksum: None,
+        protocol: PROTOCOL_VERSION,
+    });
+}
+
+/// System to stop watching when back at the main menu
+pub fn end_replay(
+    mut viewer: ResMut<ReplayViewer>,
+    mut network_state: ResMut<NetworkState>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if !network_state.is_replay() {
+        return;
+    }
+    network_state.mode = NetworkMode::Local;
+    network_state.game_seed = None;
+    network_state.actions_logged = 0;
+    network_state.pending_replay.clear();
+    virtual_time.set_relative_speed(1.0);
+    viewer.watching = None;
+    info!("Stopped watching the replay");
+}
+
+/// Playback controls shown while watching a replay
+pub fn replay_controls_ui(
+    mut contexts: EguiContexts,
+    mut viewer: ResMut<ReplayViewer>,
+    network_state: Res<NetworkState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !network_state.is_replay() {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let style = DesertButtonStyle::small();
+    egui::Area::new(egui::Id::new("replay_controls"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -12.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(egui::Color32::from_rgba_unmultiplied(30, 25, 20, 220))
+                .corner_radius(egui::CornerRadius::same(6))
+                .inner_margin(egui::Margin::symmetric(10, 6))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(format!("Replay {} / {}", viewer.fed, viewer.total()))
+                                .size(13.0)
+                                .color(egui::Color32::WHITE),
+                        );
+                        ui.add_space(8.0);
+
+                        let play_label = if viewer.paused { "Play" } else { "Pause" };
+                        if desert_button(ui, play_label, &style).clicked() {
+                            viewer.paused = !viewer.paused;
+                        }
+                        let can_step = viewer.paused && viewer.fed < viewer.total();
+                        if desert_button_enabled(ui, "Step", &style, can_step).clicked() && can_step {
+                            viewer.step = true;
+                        }
+
+                        let speed_label = format!("{}x", viewer.speed);
+                        if desert_button(ui, &speed_label, &style).on_hover_text("Playback speed").clicked() {
+                            let next = SPEEDS.iter().position(|&s| s == viewer.speed).map_or(1, |i| (i + 1) % SPEEDS.len());
+                            viewer.speed = SPEEDS[next];
+                        }
+
+                        ui.add_space(8.0);
+                        if desert_button(ui, "Exit", &style).clicked() {
+                            next_state.set(GameState::MainMenu);
+                        }
+                    });
+                });
+        });
+}
@@ -0,0 +1,91 @@
+// Game randomness
+// Everything random about a game (the setup rolls, the local pyramid, the AI's picks
+// and sudden death) is drawn from one `GameRng`, seeded as the game starts. The same
+// seed, players and moves replay the same game, so a bug report only needs the seed.
+// Online rolls come from the host's per-leg fairness seed instead, and cosmetic
+// randomness (animations, menu camels, names) stays unseeded.
+// Seeds are shared (bug reports, online rooms), so both the generator (ChaCha8) and the
+// hash for text seeds (FNV-1a) have fixed definitions: every build and platform turns
+// the same seed into the same game.
+
+use std::ops::{Deref, DerefMut};
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The game's seeded random number generator
+#[derive(Resource)]
+pub struct GameRng {
+    rng: ChaCha8Rng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self { rng: ChaCha8Rng::seed_from_u64(seed) }
+    }
+
+    /// A generator of its own for work done off the main thread (the AI deciding in
+    /// the background), drawn from this one so it's just as reproducible
+    pub fn fork(&mut self) -> ChaCha8Rng {
+        ChaCha8Rng::seed_from_u64(self.rng.gen())
+    }
+}
+
+impl Deref for GameRng {
+    type Target = ChaCha8Rng;
+
+    fn deref(&self) -> &ChaCha8Rng {
+        &self.rng
+    }
+}
+
+impl DerefMut for GameRng {
+    fn deref_mut(&mut self) -> &mut ChaCha8Rng {
+        &mut self.rng
+    }
+}
+
+/// Seed typed in by the player: a number is used as it is, anything else (a word, a
+/// date) is hashed, and blank means pick one at random
+pub fn parse_seed(text: &str) -> Option<u64> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    text.parse().ok().or_else(|| {
+        let mut hasher = StableHasher::new();
+        hasher.write(b"camel-up-game-seed");
+        hasher.write(text.as_bytes());
+        Some(hasher.finish())
+    })
+}
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher`, whose algorithm may change between Rust
+/// releases, the same bytes always hash to the same value, on every build.
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,2 +1,80 @@
 // Game rules and validation logic
-// Will be implemented in Phase 3-4
+// Reminders for "use it or lose it" resources: things a player only gets value from
+// before the leg (or race) ends. Each reminder is a rule checked against the board;
+// to hook a new one in, add a variant and a line in `Reminder::applies`.
+
+use bevy::prelude::*;
+
+use crate::components::{PlayerData, Players, Pyramid};
+use crate::game::desert_events::ActiveEvent;
+
+/// Something the current player is about to miss out on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reminder {
+    /// Spectator tile still in hand with one roll left in the leg
+    SpectatorTileLastChance,
+}
+
+impl Reminder {
+    pub const ALL: [Reminder; 1] = [Reminder::SpectatorTileLastChance];
+
+    /// Short text for the hint chip
+    pub fn message(&self) -> &'static str {
+        match self {
+            Reminder::SpectatorTileLastChance => "Last chance to place this leg",
+        }
+    }
+
+    /// Shorter text for the cramped mobile cards
+    pub fn short_message(&self) -> &'static str {
+        match self {
+            Reminder::SpectatorTileLastChance => "Last chance",
+        }
+    }
+
+    /// Whether the rule fires for this player on the current board
+    fn applies(&self, player: &PlayerData, pyramid: &Pyramid, active_event: &ActiveEvent) -> bool {
+        match self {
+            // A tile only earns while camels still move this leg
+            Reminder::SpectatorTileLastChance => {
+                player.has_spectator_tile
+                    && pyramid.rolls_left_in_leg() == 1
+                    && !active_event.spectator_tiles_banned()
+            }
+        }
+    }
+}
+
+/// Reminders that apply to the current player right now
+#[derive(Resource, Default)]
+pub struct ActiveReminders {
+    pub reminders: Vec<Reminder>,
+}
+
+impl ActiveReminders {
+    pub fn has(&self, reminder: Reminder) -> bool {
+        self.reminders.contains(&reminder)
+    }
+}
+
+/// System to re-check every reminder rule for the current player
+pub fn update_reminders(
+    players: Res<Players>,
+    pyramid: Res<Pyramid>,
+    active_event: Res<ActiveEvent>,
+    mut active: ResMut<ActiveReminders>,
+) {
+    let current = players.current_player();
+    let reminders: Vec<Reminder> = if current.is_ai {
+        Vec::new()
+    } else {
+        Reminder::ALL
+            .into_iter()
+            .filter(|reminder| reminder.applies(current, &pyramid, &active_event))
+            .collect()
+    };
+
+    if active.reminders != reminders {
+        active.reminders = reminders;
+    }
+}
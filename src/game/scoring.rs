@@ -1,2 +0,0 @@
-// Leg and end-game scoring logic
-// Will be implemented in Phase 5
@@ -0,0 +1,198 @@
+// Player preferences kept between runs
+// Animation speed, the colorblind palette and color markers, UI scale, large text, the dyslexia-friendly
+// font, high contrast, the left-handed layout, the volumes and mute, the name and avatar the first seat
+// starts with, the spoken commentary settings (volume included) and the key bindings are loaded at
+// startup and saved again whenever one changes.
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::CamelPalette;
+use crate::game::key_bindings::KeyBindings;
+use crate::game::replay::ReplayViewer;
+use crate::game::storage;
+use crate::network::state::NetworkState;
+use crate::systems::autoplay::AutoplayConfig;
+use crate::systems::speech::SpeechSettings;
+use crate::ui::characters::{AvatarSpec, CharacterId};
+use crate::ui::player_setup::PlayerSetupConfig;
+
+/// Name the settings are kept under in `storage`
+const SETTINGS_NAME: &str = "settings";
+
+/// Slowest and fastest animation speeds offered (1.0 = normal)
+pub const MIN_ANIMATION_SPEED: f32 = 0.5;
+pub const MAX_ANIMATION_SPEED: f32 = 2.0;
+
+/// Smallest and largest UI scales offered, on top of the scale that fits the window
+pub const MIN_UI_SCALE: f32 = 0.75;
+pub const MAX_UI_SCALE: f32 = 1.75;
+
+/// Preferences that aren't part of any one game
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// How fast local games play out: camel moves, dice, pauses between turns
+    pub animation_speed: f32,
+    /// Draw the camels in colors that stay apart with red-green color blindness
+    pub colorblind: bool,
+    /// Mark camels, dice and bet tiles with their color's letter and pattern
+    pub color_markers: bool,
+    /// Multiplies the UI scale that fits the window
+    pub ui_scale: f32,
+    /// Draw all text larger, on top of the UI scale
+    pub large_text: bool,
+    /// Draw all text in a plain sans-serif that's easier to read with dyslexia
+    pub dyslexia_font: bool,
+    /// Draw the UI with near-black backgrounds, thick borders and bright text
+    pub high_contrast: bool,
+    /// Put the actions on the right and the players on the left, and mirror the phone
+    /// action sheet, for left-handed thumb reach
+    pub left_handed: bool,
+    /// Loudness of everything, from silent (0.0) to full (1.0)
+    pub master_volume: f32,
+    /// Loudness of the background music, on top of the master volume
+    pub music_volume: f32,
+    /// Loudness of the sound effects, on top of the master volume
+    pub sfx_volume: f32,
+    /// Silence everything, keeping the volumes for when it's turned back on
+    pub muted: bool,
+    /// Name the first seat starts with on the main menu
+    pub player_name: Option<String>,
+    /// Avatar the first seat starts with on the main menu
+    pub player_character: Option<u8>,
+    /// Avatar built in the avatar editor, used instead of `player_character` when set
+    pub player_avatar: Option<AvatarSpec>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            animation_speed: 1.0,
+            colorblind: false,
+            color_markers: false,
+            ui_scale: 1.0,
+            large_text: false,
+            dyslexia_font: false,
+            high_contrast: false,
+            left_handed: false,
+            master_volume: 1.0,
+            music_volume: 0.5,
+            sfx_volume: 1.0,
+            muted: false,
+            player_name: None,
+            player_character: None,
+            player_avatar: None,
+        }
+    }
+}
+
+impl Settings {
+    /// How the camels' colors are drawn
+    pub fn camel_palette(&self) -> CamelPalette {
+        CamelPalette {
+            colorblind: self.colorblind,
+            markers: self.color_markers,
+        }
+    }
+
+    /// Seat the player at the first seat with their name and avatar, if they've set them
+    pub fn apply_to_first_seat(&self, config: &mut PlayerSetupConfig) {
+        let Some(first) = config.players.iter().position(|p| !p.is_ai) else { return };
+
+        if let Some(ref name) = self.player_name {
+            config.players[first].name = name.trim().to_string();
+            config.players[first].name_edited = true;
+        }
+        let character = self
+            .player_avatar
+            .map(CharacterId::Custom)
+            .or(self.player_character.map(|index| CharacterId::from_index(index as usize)));
+        if let Some(character) = character {
+            // Whoever had the avatar gets the first seat's old one
+            let previous = config.players[first].character_id;
+            if let Some(other) = config.players.iter_mut().find(|p| p.character_id == character) {
+                other.character_id = previous;
+            }
+            config.players[first].character_id = character;
+        }
+    }
+}
+
+/// The settings file: our settings, the spoken commentary's and the key bindings
+#[derive(Serialize, Deserialize)]
+struct SavedSettings {
+    settings: Settings,
+    speech: SpeechSettings,
+    #[serde(default)]
+    key_bindings: KeyBindings,
+}
+
+/// System to load the settings saved last time, and put them into effect
+pub fn load_settings(
+    mut settings: ResMut<Settings>,
+    mut speech: ResMut<SpeechSettings>,
+    mut key_bindings: ResMut<KeyBindings>,
+    mut config: ResMut<PlayerSetupConfig>,
+    mut camel_palette: ResMut<CamelPalette>,
+) {
+    let Some(saved) = storage::read(SETTINGS_NAME).and_then(|json| serde_json::from_str::<SavedSettings>(&json).ok())
+    else {
+        return;
+    };
+    saved.settings.apply_to_first_seat(&mut config);
+    *camel_palette = saved.settings.camel_palette();
+    *settings = saved.settings;
+    *speech = saved.speech;
+    *key_bindings = saved.key_bindings;
+}
+
+/// System to save the settings whenever they change
+pub fn save_settings(
+    settings: Res<Settings>,
+    speech: Res<SpeechSettings>,
+    key_bindings: Res<KeyBindings>,
+    mut camel_palette: ResMut<CamelPalette>,
+) {
+    if settings.is_added() || !(settings.is_changed() || speech.is_changed() || key_bindings.is_changed()) {
+        return;
+    }
+    camel_palette.set_if_neq(settings.camel_palette());
+    let saved = SavedSettings {
+        settings: settings.clone(),
+        speech: speech.clone(),
+        key_bindings: key_bindings.clone(),
+    };
+    if let Ok(json) = serde_json::to_string(&saved) {
+        storage::write(SETTINGS_NAME, &json);
+    }
+}
+
+/// System to play everything at the master volume, or not at all when muted. Sounds pick
+/// up the global volume as they start; the music, already playing, follows it itself.
+pub fn apply_master_volume(settings: Res<Settings>, mut global_volume: ResMut<GlobalVolume>) {
+    if !settings.is_changed() {
+        return;
+    }
+    let master = if settings.muted { 0.0 } else { settings.master_volume };
+    *global_volume = GlobalVolume::new(Volume::Linear(master));
+}
+
+/// System to play local games at the chosen animation speed. Online games stay at normal
+/// speed so turn timers run alike for everyone; autoplay and replays set their own.
+pub fn apply_animation_speed(
+    settings: Res<Settings>,
+    network_state: Res<NetworkState>,
+    autoplay: Res<AutoplayConfig>,
+    replay: Res<ReplayViewer>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if autoplay.active || replay.watching.is_some() {
+        return;
+    }
+    let speed = if network_state.is_online() { 1.0 } else { settings.animation_speed };
+    if virtual_time.relative_speed() != speed {
+        virtual_time.set_relative_speed(speed);
+    }
+}
@@ -0,0 +1,142 @@
+// Monte Carlo race simulation
+// Leg odds come from the exact enumeration in `probability`; samples random
+// sequences of dice to estimate how the whole race is likely to end.
+// Runs off the main thread for the AI.
+
+use rand::Rng;
+
+use crate::components::{CamelColor, TRACK_LENGTH};
+use crate::game::core::{roll_crazy_die, roll_die_face, RACE_BET_PAYOUTS};
+use crate::game::probability::{color_index, exact_leg_odds, RaceBoard, SimDie};
+
+/// Number of sampled futures per AI decision
+pub const DEFAULT_SAMPLES: usize = 4000;
+
+/// Safety cap on simulated legs per race (real races end long before this)
+const MAX_SIMULATED_LEGS: usize = 30;
+
+/// Estimated odds for each camel, indexed like `CamelColor::all()`
+#[derive(Clone, Debug, Default)]
+pub struct SimulationResult {
+    pub leg_first: [f32; 5],
+    pub leg_second: [f32; 5],
+    pub race_winner: [f32; 5],
+    pub race_loser: [f32; 5],
+    /// Expected number of racing camel landings per space for the rest of the leg
+    pub leg_landings: [f32; TRACK_LENGTH as usize],
+    /// Landings per space split by the camel whose die moved it, indexed like `leg_first`
+    pub leg_landings_by_camel: [[f32; TRACK_LENGTH as usize]; 5],
+    /// Expected number of full legs still to come after this one
+    pub legs_remaining: f32,
+    /// Chance the race finishes during the current leg
+    pub finishes_this_leg: f32,
+}
+
+impl SimulationResult {
+    pub fn leg_first(&self, color: CamelColor) -> f32 {
+        self.leg_first[color_index(color)]
+    }
+
+    pub fn leg_second(&self, color: CamelColor) -> f32 {
+        self.leg_second[color_index(color)]
+    }
+
+    pub fn race_winner(&self, color: CamelColor) -> f32 {
+        self.race_winner[color_index(color)]
+    }
+
+    pub fn race_loser(&self, color: CamelColor) -> f32 {
+        self.race_loser[color_index(color)]
+    }
+
+    /// Expected money from taking this camel's leg bet tile worth `tile_value`
+    pub fn leg_bet_value(&self, color: CamelColor, tile_value: u8) -> f32 {
+        let p_first = self.leg_first(color);
+        let p_second = self.leg_second(color);
+        p_first * tile_value as f32 + p_second - (1.0 - p_first - p_second)
+    }
+
+    /// Expected money from a race bet on this camel, with `bets_placed` bets of the
+    /// same kind already on the table
+    pub fn race_bet_value(&self, color: CamelColor, is_winner: bool, bets_placed: usize) -> f32 {
+        let p = if is_winner { self.race_winner(color) } else { self.race_loser(color) };
+        // Earlier correct bets on this camel take the bigger payouts
+        let correct_ahead = (bets_placed as f32 * p).floor() as usize;
+        let payout = RACE_BET_PAYOUTS.get(correct_ahead).copied().unwrap_or(1) as f32;
+        p * payout - (1.0 - p)
+    }
+}
+
+/// Roll one random die from the pyramid and apply it to the board
+fn roll_random(board: &mut RaceBoard, rng: &mut impl Rng) {
+    let index = rng.gen_range(0..board.remaining_dice.len());
+    let die = board.remaining_dice.remove(index);
+    board.dice_left_to_roll -= 1;
+
+    match die {
+        SimDie::Regular(color) => {
+            board.move_racer(color, roll_die_face(rng));
+        }
+        SimDie::Crazy => {
+            let (color, value) = roll_crazy_die(rng);
+            board.move_crazy(color, value);
+        }
+    }
+}
+
+/// Play out the rest of the leg
+fn play_leg(board: &mut RaceBoard, rng: &mut impl Rng) {
+    while board.dice_left_to_roll > 0 && !board.remaining_dice.is_empty() && !board.finished() {
+        roll_random(board, rng);
+    }
+}
+
+/// Calculate exact leg odds and estimate race odds by sampling random futures from `rng`
+pub fn simulate(board: &RaceBoard, samples: usize, rng: &mut impl Rng) -> SimulationResult {
+    let leg = exact_leg_odds(board);
+    let mut result = SimulationResult {
+        leg_first: leg.first,
+        leg_second: leg.second,
+        leg_landings: leg.landings,
+        leg_landings_by_camel: leg.landings_by_camel,
+        ..Default::default()
+    };
+    if samples == 0 {
+        return result;
+    }
+
+    for _ in 0..samples {
+        let mut sim = board.clone();
+
+        let mut legs = 0;
+        while !sim.finished() && legs < MAX_SIMULATED_LEGS {
+            if legs > 0 {
+                sim.start_new_leg();
+            }
+            play_leg(&mut sim, rng);
+            legs += 1;
+        }
+        // A board that is already finished plays no legs at all
+        result.legs_remaining += legs.saturating_sub(1) as f32;
+        if legs == 1 && sim.finished() {
+            result.finishes_this_leg += 1.0;
+        }
+
+        let race_order = sim.rankings();
+        if let Some(&winner) = race_order.first() {
+            result.race_winner[color_index(winner)] += 1.0;
+        }
+        if let Some(&loser) = race_order.last() {
+            result.race_loser[color_index(loser)] += 1.0;
+        }
+    }
+
+    let n = samples as f32;
+    for odds in [&mut result.race_winner, &mut result.race_loser] {
+        odds.iter_mut().for_each(|p| *p /= n);
+    }
+    result.legs_remaining /= n;
+    result.finishes_this_leg /= n;
+
+    result
+}
@@ -0,0 +1,137 @@
+// Game snapshots
+// The whole table as one serializable value: the players and their bets, the pyramid,
+// the turn, the variants' state and where every camel stands. Taken from a `World` and
+// put back into one, it's the common ground for saves, undo, replays and network sync.
+// The network's `SerializableGameState` stays as it is, since it's the wire format
+// every build in a room has to agree on.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::*;
+use crate::game::desert_events::ActiveEvent;
+use crate::game::relay::RelayRace;
+use crate::systems::animation::{MovementAnimation, MultiStepMovementAnimation};
+
+/// The table at one moment, as plain data
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub players: Players,
+    pub turn_state: TurnState,
+    pub pyramid: Pyramid,
+    pub leg_betting_tiles: LegBettingTiles,
+    pub race_bets: RaceBets,
+    pub placed_spectator_tiles: PlacedSpectatorTiles,
+    pub player_leg_bets: PlayerLegBetsStore,
+    pub player_pyramid_tokens: PlayerPyramidTokens,
+    pub sheikh: SheikhBettor,
+    pub active_event: ActiveEvent,
+    pub relay: RelayRace,
+    /// Tracks on the board (more than one in a Relay Race) and the one being raced on
+    pub track_count: usize,
+    pub active_track: usize,
+    pub camels: Vec<(CamelColor, BoardPosition)>,
+    pub crazy_camels: Vec<(CrazyCamelColor, BoardPosition)>,
+}
+
+impl GameSnapshot {
+    /// The table as it stands, or `None` when no game is set up
+    pub fn capture(world: &mut World) -> Option<Self> {
+        let board = world.get_resource::<GameBoard>()?;
+        let (track_count, active_track) = (board.track_count(), board.active_track);
+
+        // In color order, so the same table always gives the same snapshot
+        let mut camels: Vec<_> = world
+            .query::<(&Camel, &BoardPosition)>()
+            .iter(world)
+            .map(|(camel, pos)| (camel.color, *pos))
+            .collect();
+        camels.sort_by_key(|&(color, _)| color as usize);
+        let mut crazy_camels: Vec<_> = world
+            .query_filtered::<(&CrazyCamel, &BoardPosition), Without<Camel>>()
+            .iter(world)
+            .map(|(camel, pos)| (camel.color, *pos))
+            .collect();
+        crazy_camels.sort_by_key(|&(color, _)| color as usize);
+
+        Some(Self {
+            players: world.get_resource::<Players>()?.clone(),
+            turn_state: world.get_resource::<TurnState>()?.clone(),
+            pyramid: world.get_resource::<Pyramid>()?.clone(),
+            leg_betting_tiles: world.get_resource::<LegBettingTiles>()?.clone(),
+            race_bets: world.get_resource::<RaceBets>()?.clone(),
+            placed_spectator_tiles: world.get_resource::<PlacedSpectatorTiles>()?.clone(),
+            player_leg_bets: world.get_resource::<PlayerLegBetsStore>()?.clone(),
+            player_pyramid_tokens: world.get_resource::<PlayerPyramidTokens>()?.clone(),
+            // The variants are off unless set up
+            sheikh: world.get_resource::<SheikhBettor>().cloned().unwrap_or_default(),
+            active_event: world.get_resource::<ActiveEvent>().cloned().unwrap_or_default(),
+            relay: world.get_resource::<RelayRace>().cloned().unwrap_or_default(),
+            track_count,
+            active_track,
+            camels,
+            crazy_camels,
+        })
+    }
+
+    /// Put the table back as it was captured. Camels already on the board are stopped
+    /// and snapped to their spaces; the board's sprites follow on their own.
+    pub fn restore(&self, world: &mut World) {
+        world.insert_resource(self.players.clone());
+        world.insert_resource(self.turn_state.clone());
+        world.insert_resource(self.pyramid.clone());
+        world.insert_resource(self.leg_betting_tiles.clone());
+        world.insert_resource(self.race_bets.clone());
+        world.insert_resource(self.placed_spectator_tiles.clone());
+        world.insert_resource(self.player_leg_bets.clone());
+        world.insert_resource(self.player_pyramid_tokens.clone());
+        world.insert_resource(self.sheikh.clone());
+        world.insert_resource(self.active_event.clone());
+        world.insert_resource(self.relay.clone());
+
+        let board = {
+            let mut board = world.get_resource_or_insert_with(GameBoard::new);
+            if board.track_count() != self.track_count {
+                *board = GameBoard::with_tracks(self.track_count);
+            }
+            board.active_track = self.active_track.min(self.track_count.saturating_sub(1));
+            board.clone()
+        };
+
+        let mut placed = Vec::new();
+        let mut camels = world.query::<(Entity, &Camel, &mut BoardPosition, &mut Transform)>();
+        for (entity, camel, mut pos, mut transform) in camels.iter_mut(world) {
+            if let Some((_, saved)) = self.camels.iter().find(|(color, _)| *color == camel.color) {
+                *pos = *saved;
+                transform.translation = resting_place(&board, saved);
+                placed.push(entity);
+            }
+        }
+        let mut crazy_camels =
+            world.query_filtered::<(Entity, &CrazyCamel, &mut BoardPosition, &mut Transform), Without<Camel>>();
+        for (entity, camel, mut pos, mut transform) in crazy_camels.iter_mut(world) {
+            if let Some((_, saved)) = self.crazy_camels.iter().find(|(color, _)| *color == camel.color) {
+                *pos = *saved;
+                transform.translation = resting_place(&board, saved);
+                placed.push(entity);
+            }
+        }
+
+        // A move still playing out would carry the camel off its restored space
+        for entity in placed {
+            world
+                .entity_mut(entity)
+                .remove::<(MovementAnimation, MultiStepMovementAnimation)>();
+        }
+    }
+}
+
+/// Where a camel stands at rest: on its space, raised by the camels beneath it
+fn resting_place(board: &GameBoard, pos: &BoardPosition) -> Vec3 {
+    let base = board.get_position(pos.space_index);
+    Vec3::new(
+        base.x,
+        base.y + pos.stack_position as f32 * 25.0,
+        10.0 + pos.stack_position as f32,
+    )
+}
@@ -9,3 +9,31 @@ pub enum GameState {
     Playing,
     GameEnd,
 }
+
+/// Stages of a frame at the table, run in this order so each stage sees what the one
+/// before it did this frame instead of the next
+#[derive(SystemSet, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GameSet {
+    /// Clicks on the board, the AI, autoplay, replays and the network turned into actions
+    Input,
+    /// Actions applied to the table: bets placed, dice rolled
+    Resolve,
+    /// Camels moved for the dice rolled
+    Movement,
+    /// Turns advanced, legs and the race scored, the log and saves brought up to date
+    Scoring,
+    /// The board, camera and HUD catching up with the table
+    Animation,
+}
+
+/// A game left with "Save & Quit": its entities and resources are kept so it can be
+/// resumed from the main menu instead of being cleaned up
+#[derive(Resource, Default)]
+pub struct SuspendedGame {
+    pub active: bool,
+}
+
+/// Run condition: no game is waiting to be resumed
+pub fn no_suspended_game(suspended: Res<SuspendedGame>) -> bool {
+    !suspended.active
+}
@@ -0,0 +1,155 @@
+// Player statistics kept across games
+// Each human player's games are tallied under their name, their profile, when a game
+// reaches its final results: how many they played and won, the money they finished
+// with, the actions they took and the most they made in a single leg. The tallies are
+// kept with the autosave and the last replay, and shown on the main menu's Stats page.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::{PlayerData, Players};
+use crate::game::log::{GameEvent, GameLog};
+use crate::game::storage;
+use crate::network::state::NetworkState;
+use crate::systems::autoplay::AutoplayConfig;
+use crate::ui::scoring::{GameEndPhase, GameEndState};
+
+/// Name the statistics are kept under in `storage`
+const STATS_NAME: &str = "stats";
+
+/// How often a profile took each kind of action
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ActionCounts {
+    pub rolls: u32,
+    pub leg_bets: u32,
+    pub race_bets: u32,
+    pub spectator_tiles: u32,
+}
+
+impl ActionCounts {
+    /// The action taken most often, if any were taken
+    pub fn favorite(&self) -> Option<&'static str> {
+        [
+            ("Rolling the pyramid", self.rolls),
+            ("Leg bets", self.leg_bets),
+            ("Race bets", self.race_bets),
+            ("Spectator tiles", self.spectator_tiles),
+        ]
+        .into_iter()
+        .filter(|&(_, count)| count > 0)
+        .max_by_key(|&(_, count)| count)
+        .map(|(action, _)| action)
+    }
+}
+
+/// One profile's tallies over every game it finished
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProfileStats {
+    pub games_played: u32,
+    pub wins: u32,
+    /// Money at the end of every game, added up
+    pub total_money: i32,
+    pub actions: ActionCounts,
+    /// The most money made in a single leg
+    pub best_leg: i32,
+}
+
+impl ProfileStats {
+    pub fn average_money(&self) -> f32 {
+        if self.games_played == 0 {
+            0.0
+        } else {
+            self.total_money as f32 / self.games_played as f32
+        }
+    }
+}
+
+/// Statistics of every profile that has played on this device, by player name
+#[derive(Resource, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct PlayerStats {
+    pub profiles: BTreeMap<String, ProfileStats>,
+}
+
+impl PlayerStats {
+    /// Add a finished game to a profile's tallies
+    fn record_game(&mut self, name: &str, money: i32, won: bool, game_log: &GameLog) {
+        let stats = self.profiles.entry(name.to_string()).or_default();
+        stats.games_played += 1;
+        stats.wins += won as u32;
+        stats.total_money += money;
+
+        // Money made in each leg. Race bets pay out after the race, not in a leg.
+        let mut leg_money: BTreeMap<u32, i32> = BTreeMap::new();
+        for entry in &game_log.entries {
+            match entry.event {
+                GameEvent::DieRolled { ref player, .. } | GameEvent::CrazyDieRolled { ref player, .. } if player == name => {
+                    stats.actions.rolls += 1
+                }
+                GameEvent::LegBetTaken { ref player, .. } if player == name => stats.actions.leg_bets += 1,
+                GameEvent::RaceBetPlaced { ref player, .. } if player == name => stats.actions.race_bets += 1,
+                GameEvent::SpectatorTilePlaced { ref player, .. } if player == name => {
+                    stats.actions.spectator_tiles += 1
+                }
+                GameEvent::RaceBetResolved { .. } => continue,
+                _ => {}
+            }
+            for change in entry.money.iter().filter(|change| change.player == name) {
+                *leg_money.entry(entry.leg).or_default() += change.delta;
+            }
+        }
+        if let Some(&best) = leg_money.values().max() {
+            stats.best_leg = stats.best_leg.max(best);
+        }
+    }
+}
+
+/// Whether a seat is played by someone at this device: any human seat in a local
+/// game, only our own seat online
+pub fn plays_here(network_state: &NetworkState, seat: usize, player: &PlayerData) -> bool {
+    if network_state.is_online() {
+        network_state.local_player_index == Some(seat)
+    } else {
+        !player.is_ai
+    }
+}
+
+/// System to pick up the statistics kept from earlier games
+pub fn load_player_stats(mut stats: ResMut<PlayerStats>) {
+    if let Some(saved) = storage::read(STATS_NAME).and_then(|json| serde_json::from_str::<PlayerStats>(&json).ok()) {
+        *stats = saved;
+    }
+}
+
+/// System to tally a game once its final results are up, for the people playing on
+/// this device. Games nobody played (the AI by itself, or a replay) aren't counted.
+pub fn record_player_stats(
+    mut stats: ResMut<PlayerStats>,
+    game_end_state: Option<Res<GameEndState>>,
+    players: Option<Res<Players>>,
+    game_log: Res<GameLog>,
+    network_state: Res<NetworkState>,
+    autoplay: Res<AutoplayConfig>,
+    mut recorded: Local<bool>,
+) {
+    let (Some(state), Some(players)) = (game_end_state, players) else { return };
+    if state.phase != GameEndPhase::FinalResults {
+        *recorded = false;
+        return;
+    }
+    if std::mem::replace(&mut *recorded, true) || autoplay.active || network_state.is_replay() {
+        return;
+    }
+
+    let winner = state.winner(&players);
+    for (seat, player) in players.players.iter().enumerate() {
+        if plays_here(&network_state, seat, player) {
+            stats.record_game(&player.name, player.money, winner == Some(player.id), &game_log);
+        }
+    }
+
+    if let Ok(json) = serde_json::to_string(&*stats) {
+        storage::write(STATS_NAME, &json);
+    }
+}
@@ -0,0 +1,99 @@
+// Small JSON documents kept between runs: the settings, the autosave, the last game's
+// replay, the players' stats, achievements and leaderboard. Browser builds keep them in
+// localStorage, desktop builds in files in the player's home directory. Files the player
+// asks for, like an exported game log, are downloaded in the browser and saved wherever
+// they pick on desktop.
+
+/// Keep a document under `name`, replacing the last one
+#[cfg(target_arch = "wasm32")]
+pub fn write(name: &str, json: &str) {
+    crate::network::js_bindings::store_item(&format!("camelUp.{}", name), json);
+}
+
+/// The document kept under `name`, if any
+#[cfg(target_arch = "wasm32")]
+pub fn read(name: &str) -> Option<String> {
+    crate::network::js_bindings::load_item(&format!("camelUp.{}", name))
+}
+
+/// Forget the document kept under `name`
+#[cfg(target_arch = "wasm32")]
+pub fn remove(name: &str) {
+    crate::network::js_bindings::remove_item(&format!("camelUp.{}", name));
+}
+
+/// Hand the player a file to keep: the browser downloads it
+#[cfg(target_arch = "wasm32")]
+pub fn export(file_name: &str, contents: &str) {
+    crate::network::js_bindings::download_file(file_name, contents);
+}
+
+/// Directory the documents are kept in instead of the home directory, once `set_dir` is called
+#[cfg(not(target_arch = "wasm32"))]
+static DIR: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+
+/// Keep the documents in `dir` instead of the player's home directory: an app embedding
+/// the game can keep them with its own, and the tests keep them out of the way of a real
+/// player's. Only the first call counts.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_dir(dir: std::path::PathBuf) {
+    let _ = DIR.set(dir);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn path(name: &str) -> Option<std::path::PathBuf> {
+    let file_name = format!(".camel-up-{}.json", name);
+    if let Some(dir) = DIR.get() {
+        return Some(dir.join(file_name));
+    }
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| std::path::PathBuf::from(home).join(file_name))
+}
+
+/// Write next to `path` first and rename over it, so a crash or a full disk mid-write
+/// leaves the old document in place instead of half of the new one
+#[cfg(not(target_arch = "wasm32"))]
+fn write_atomically(path: &std::path::Path, contents: &str) {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = std::path::PathBuf::from(temp);
+    let result = std::fs::write(&temp, contents).and_then(|()| std::fs::rename(&temp, path));
+    if let Err(e) = result {
+        bevy::log::warn!("Could not write {}: {}", path.display(), e);
+        let _ = std::fs::remove_file(&temp);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn write(name: &str, json: &str) {
+    if let Some(path) = path(name) {
+        write_atomically(&path, json);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn read(name: &str) -> Option<String> {
+    std::fs::read_to_string(path(name)?).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn remove(name: &str) {
+    if let Some(path) = path(name) {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Hand the player a file to keep: a save dialog asks where, on the async compute pool
+/// so the game keeps drawing
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export(file_name: &str, contents: &str) {
+    let dialog = rfd::AsyncFileDialog::new().set_file_name(file_name);
+    let contents = contents.to_string();
+    bevy::tasks::AsyncComputeTaskPool::get()
+        .spawn(async move {
+            let Some(file) = dialog.save_file().await else { return };
+            write_atomically(file.path(), &contents);
+        })
+        .detach();
+}
@@ -0,0 +1,130 @@
+//! Camel Up as a Bevy plugin
+//!
+//! The standalone game (`src/main.rs`) is a thin wrapper around [`GamePlugin`]. Other Bevy
+//! apps, like a board game collection shell, can embed the game the same way: add
+//! `DefaultPlugins` (with whatever window they like) and then the plugin.
+//!
+//! ```ignore
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(camel_up::GamePlugin::builder().exit_on_quit(false).build())
+//!     .run();
+//! ```
+
+use bevy::prelude::*;
+
+mod components;
+mod game;
+mod network;
+mod plugins;
+mod systems;
+mod ui;
+
+#[cfg(test)]
+mod tests;
+
+use network::NetworkPlugin;
+use systems::stress_test::start_stress_test;
+
+pub use game::snapshot::GameSnapshot;
+pub use game::state::GameState;
+pub use plugins::camera::{CameraState, GameCamera};
+pub use plugins::{AnimationPlugin, CameraPlugin, GameLogicPlugin, HudPlugin, MenuPlugin};
+#[cfg(feature = "ai_benchmark")]
+pub use game::benchmark::{run_benchmark, run_headless, BenchmarkConfig};
+#[cfg(not(target_arch = "wasm32"))]
+pub use game::storage::set_dir as set_storage_dir;
+#[cfg(not(target_arch = "wasm32"))]
+pub use network::relay_server::{run_relay_server, DEFAULT_RELAY_ADDRESS};
+
+/// How the game behaves inside the app that hosts it
+#[derive(Resource, Clone)]
+pub struct GameConfig {
+    /// Quit buttons close the app. Embedding apps usually turn this off and listen
+    /// for [`QuitRequested`] to return to their own menu instead.
+    pub exit_on_quit: bool,
+    /// Developer shortcuts, like the stress test on the main menu (F9 unless rebound)
+    pub developer_tools: bool,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            exit_on_quit: true,
+            developer_tools: true,
+        }
+    }
+}
+
+/// Builder for [`GamePlugin`]
+#[derive(Default)]
+pub struct GamePluginBuilder {
+    config: GameConfig,
+}
+
+impl GamePluginBuilder {
+    pub fn exit_on_quit(mut self, exit: bool) -> Self {
+        self.config.exit_on_quit = exit;
+        self
+    }
+
+    pub fn developer_tools(mut self, enabled: bool) -> Self {
+        self.config.developer_tools = enabled;
+        self
+    }
+
+    pub fn build(self) -> GamePlugin {
+        GamePlugin { config: self.config }
+    }
+}
+
+/// Message sent when a player presses Quit
+#[derive(Message)]
+pub struct QuitRequested;
+
+/// The whole game: states, resources, systems and UI. Needs `DefaultPlugins`;
+/// adds `EguiPlugin` itself unless the host app already has it.
+///
+/// Made of the domain plugins ([`GameLogicPlugin`], [`AnimationPlugin`], [`CameraPlugin`],
+/// [`HudPlugin`] and [`MenuPlugin`]) plus networking; an app that only wants part of the
+/// game can add those itself instead.
+#[derive(Default)]
+pub struct GamePlugin {
+    config: GameConfig,
+}
+
+impl GamePlugin {
+    pub fn builder() -> GamePluginBuilder {
+        GamePluginBuilder::default()
+    }
+}
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone()).add_plugins((
+            NetworkPlugin,
+            GameLogicPlugin,
+            AnimationPlugin,
+            CameraPlugin,
+            HudPlugin,
+            MenuPlugin,
+        ));
+
+        // Quit buttons close the app unless the host app handles `QuitRequested` itself
+        if self.config.exit_on_quit {
+            app.add_systems(Update, exit_on_quit_request);
+        }
+
+        // Developer stress test (F9 on the main menu, unless rebound)
+        if self.config.developer_tools {
+            app.add_systems(Update, start_stress_test.run_if(in_state(GameState::MainMenu)));
+        }
+    }
+}
+
+/// System to close the app when a player presses Quit (standalone game)
+fn exit_on_quit_request(mut requests: MessageReader<QuitRequested>, mut exit: MessageWriter<AppExit>) {
+    if requests.read().last().is_some() {
+        exit.write(AppExit::Success);
+    }
+}
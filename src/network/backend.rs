@@ -0,0 +1,482 @@
+//! Transport that online games run over
+//!
+//! The lobby, room and sync code talk to a `NetworkBackend` instead of a particular
+//! service. The browser build uses Firebase (see `firebase.rs`); desktop builds go
+//! through a WebSocket relay server (see `websocket.rs`). Messages travel as the JSON
+//! of the types in `messages.rs`, whatever carries them.
+
+use std::ops::Deref;
+
+use bevy::prelude::*;
+
+use super::error::{NetworkError, NetworkOperation, NetworkResults};
+
+/// A service that hosts rooms and relays actions (and the odd game state) between players.
+/// Slow operations run in the background and report back through `NetworkResults`;
+/// incoming updates are buffered until the matching `poll_*` call picks them up.
+pub trait NetworkBackend: Send + Sync + 'static {
+    /// Connect and sign in (reports `NetworkOperation::Authenticate`)
+    fn authenticate(&self, results: NetworkResults);
+
+    /// Id of the local player, once signed in
+    fn local_player_id(&self) -> Option<String>;
+
+    /// Create a room hosted by the local player (reports `NetworkOperation::CreateRoom`)
+    fn create_room(&self, room_code: &str, name: &str, character_id: u8, color_index: usize, results: NetworkResults);
+
+    /// Join someone else's room (reports `NetworkOperation::JoinRoom`)
+    fn join_room(&self, room_code: &str, name: &str, character_id: u8, color_index: usize, results: NetworkResults);
+
+    /// Take the seat `player_id` had in a started game again, after losing the page or
+    /// connection, showing the seat's `rejoin_token` if the service handed one out
+    /// (reports `NetworkOperation::JoinRoom`)
+    fn rejoin_room(&self, room_code: &str, player_id: &str, rejoin_token: Option<&str>, results: NetworkResults);
+
+    /// Secret the service gave our seat in the room we're in, which taking the seat
+    /// back from another connection needs. `None` when the sign in is proof enough.
+    fn rejoin_token(&self) -> Option<String>;
+
+    fn leave_room(&self, room_code: &str);
+
+    /// Change the local player's character, color (a palette slot, or the picked
+    /// `custom_color`) and optionally name
+    fn update_appearance(
+        &self,
+        room_code: &str,
+        character_id: u8,
+        color_index: usize,
+        custom_color: Option<[u8; 3]>,
+        name: Option<String>,
+    );
+
+    /// Tell the room whether the local player is ready to start
+    fn set_ready(&self, room_code: &str, is_ready: bool);
+
+    /// Host setting: shuffle everyone but the host when the game starts
+    fn set_randomize_order(&self, room_code: &str, randomize: bool);
+
+    /// Room's current randomize order setting
+    fn randomize_order(&self) -> bool;
+
+    /// Host: take a player out of the waiting room and keep them out
+    fn kick_player(&self, room_code: &str, player_id: &str);
+
+    /// Host: stop (or start again) accepting new players
+    fn set_locked(&self, room_code: &str, locked: bool);
+
+    /// Whether the room we're in has stopped accepting new players
+    fn is_locked(&self) -> bool;
+
+    /// Whether the host took us out of the room we're in
+    fn was_kicked(&self) -> bool;
+
+    /// Host: share the table set up in the waiting room (`SerializableRoomSettings` JSON)
+    fn set_room_settings(&self, room_code: &str, settings_json: String);
+
+    /// Room's current table settings JSON, once the host has shared them
+    fn room_settings(&self) -> Option<String>;
+
+    /// Clock that players' heartbeats (`OnlinePlayerInfo::last_seen`) are stamped with,
+    /// if the backend uses heartbeats
+    fn server_time_ms(&self) -> Option<f64>;
+
+    /// Close the room to new players and start playing with the given setup JSON
+    /// (reports `NetworkOperation::StartGame`)
+    fn start_game(&self, room_code: &str, setup_json: String, results: NetworkResults);
+
+    /// Whether the host has started the game in the room we're in
+    fn has_game_started(&self) -> bool;
+
+    /// Setup JSON the host started the game with, once it has
+    fn game_setup(&self) -> Option<String>;
+
+    /// Host: take a finished game's room back to its waiting room for a rematch, keeping
+    /// its players (everyone but the host has to ready up again)
+    fn reset_room(&self, room_code: &str);
+
+    /// Start receiving the room's player list and settings
+    fn subscribe_to_room(&self, room_code: &str);
+
+    /// Start receiving the host's action log and game state (clients)
+    fn subscribe_to_game_state(&self, room_code: &str);
+
+    /// Start receiving actions submitted by clients (host)
+    fn subscribe_to_actions(&self, room_code: &str);
+
+    fn unsubscribe_all(&self);
+
+    /// Stop receiving the game's actions and state, but keep listening to the room
+    fn unsubscribe_from_game(&self);
+
+    /// Publish the game state (host, reports `NetworkOperation::SyncState`)
+    fn write_game_state(&self, room_code: &str, state_json: String, results: NetworkResults);
+
+    /// Add an applied action to the room's action log (host, reports `NetworkOperation::SyncState`)
+    fn broadcast_action(&self, room_code: &str, action_json: String, results: NetworkResults);
+
+    /// Send an action to the host (clients, reports `NetworkOperation::SubmitAction`)
+    fn submit_action(&self, room_code: &str, action_json: String, results: NetworkResults);
+
+    /// Latest game state JSON received since the last poll
+    fn poll_game_state(&self) -> Option<String>;
+
+    /// JSON array of the actions received since the last poll
+    fn poll_actions(&self) -> Option<String>;
+
+    /// JSON array of the host's logged actions received since the last poll
+    fn poll_host_actions(&self) -> Option<String>;
+
+    /// JSON array of the room's players, if it changed since the last poll
+    fn poll_players(&self) -> Option<String>;
+
+    /// Show everyone else in the room an emote (`Emote` JSON)
+    fn send_emote(&self, room_code: &str, emote_json: String);
+
+    /// JSON array of the other players' emotes (`EmoteMessage`) received since the last poll
+    fn poll_emotes(&self) -> Option<String>;
+
+    /// Wall clock time in milliseconds, for stamping actions
+    fn timestamp_ms(&self) -> u64;
+}
+
+/// The backend in use. Apps embedding the game can insert their own before adding
+/// the plugin; otherwise the platform's default is used.
+#[derive(Resource)]
+pub struct Backend(Box<dyn NetworkBackend>);
+
+#[allow(dead_code)]
+impl Backend {
+    pub fn new(backend: impl NetworkBackend) -> Self {
+        Self(Box::new(backend))
+    }
+}
+
+impl Default for Backend {
+    #[cfg(target_arch = "wasm32")]
+    fn default() -> Self {
+        Self::new(super::firebase::FirebaseBackend)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn default() -> Self {
+        Self::new(super::websocket::WebSocketBackend::from_env())
+    }
+}
+
+impl Deref for Backend {
+    type Target = dyn NetworkBackend;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+/// Backend for builds without online play: anything that needs the server fails
+/// with `NetworkError::Unsupported`, and nothing ever arrives
+#[allow(dead_code)]
+pub struct UnsupportedBackend;
+
+impl NetworkBackend for UnsupportedBackend {
+    fn authenticate(&self, results: NetworkResults) {
+        results.send(NetworkOperation::Authenticate, Err(NetworkError::Unsupported));
+    }
+
+    fn local_player_id(&self) -> Option<String> {
+        None
+    }
+
+    fn create_room(&self, _room_code: &str, _name: &str, _character_id: u8, _color_index: usize, results: NetworkResults) {
+        results.send(NetworkOperation::CreateRoom, Err(NetworkError::Unsupported));
+    }
+
+    fn join_room(&self, _room_code: &str, _name: &str, _character_id: u8, _color_index: usize, results: NetworkResults) {
+        results.send(NetworkOperation::JoinRoom, Err(NetworkError::Unsupported));
+    }
+
+    fn rejoin_room(&self, _room_code: &str, _player_id: &str, _rejoin_token: Option<&str>, results: NetworkResults) {
+        results.send(NetworkOperation::JoinRoom, Err(NetworkError::Unsupported));
+    }
+
+    fn rejoin_token(&self) -> Option<String> {
+        None
+    }
+
+    fn leave_room(&self, _room_code: &str) {}
+
+    fn update_appearance(
+        &self,
+        _room_code: &str,
+        _character_id: u8,
+        _color_index: usize,
+        _custom_color: Option<[u8; 3]>,
+        _name: Option<String>,
+    ) {
+    }
+
+    fn set_ready(&self, _room_code: &str, _is_ready: bool) {}
+
+    fn set_randomize_order(&self, _room_code: &str, _randomize: bool) {}
+
+    fn randomize_order(&self) -> bool {
+        false
+    }
+
+    fn kick_player(&self, _room_code: &str, _player_id: &str) {}
+
+    fn set_locked(&self, _room_code: &str, _locked: bool) {}
+
+    fn is_locked(&self) -> bool {
+        false
+    }
+
+    fn was_kicked(&self) -> bool {
+        false
+    }
+
+    fn set_room_settings(&self, _room_code: &str, _settings_json: String) {}
+
+    fn room_settings(&self) -> Option<String> {
+        None
+    }
+
+    fn server_time_ms(&self) -> Option<f64> {
+        None
+    }
+
+    fn start_game(&self, _room_code: &str, _setup_json: String, results: NetworkResults) {
+        results.send(NetworkOperation::StartGame, Err(NetworkError::Unsupported));
+    }
+
+    fn has_game_started(&self) -> bool {
+        false
+    }
+
+    fn game_setup(&self) -> Option<String> {
+        None
+    }
+
+    fn reset_room(&self, _room_code: &str) {}
+
+    fn subscribe_to_room(&self, _room_code: &str) {}
+
+    fn subscribe_to_game_state(&self, _room_code: &str) {}
+
+    fn subscribe_to_actions(&self, _room_code: &str) {}
+
+    fn unsubscribe_all(&self) {}
+
+    fn unsubscribe_from_game(&self) {}
+
+    fn write_game_state(&self, _room_code: &str, _state_json: String, results: NetworkResults) {
+        results.send(NetworkOperation::SyncState, Err(NetworkError::Unsupported));
+    }
+
+    fn broadcast_action(&self, _room_code: &str, _action_json: String, results: NetworkResults) {
+        results.send(NetworkOperation::SyncState, Err(NetworkError::Unsupported));
+    }
+
+    fn submit_action(&self, _room_code: &str, _action_json: String, results: NetworkResults) {
+        results.send(NetworkOperation::SubmitAction, Err(NetworkError::Unsupported));
+    }
+
+    fn poll_game_state(&self) -> Option<String> {
+        None
+    }
+
+    fn poll_actions(&self) -> Option<String> {
+        None
+    }
+
+    fn poll_host_actions(&self) -> Option<String> {
+        None
+    }
+
+    fn poll_players(&self) -> Option<String> {
+        None
+    }
+
+    fn send_emote(&self, _room_code: &str, _emote_json: String) {}
+
+    fn poll_emotes(&self) -> Option<String> {
+        None
+    }
+
+    fn timestamp_ms(&self) -> u64 {
+        0
+    }
+}
+
+/// Backend for the tests: keeps every write sent to the room, and hands each poll
+/// whatever the test left for it
+#[cfg(test)]
+pub mod mock {
+    use std::sync::{Arc, Mutex, MutexGuard};
+
+    use super::NetworkBackend;
+    use crate::network::error::{NetworkError, NetworkOperation, NetworkResults};
+
+    /// What a `MockBackend` has been sent, and what it has waiting for the game
+    #[derive(Default)]
+    pub struct MockRoom {
+        /// Writes in the order they were sent, with the operation each reports as
+        pub writes: Vec<(NetworkOperation, String)>,
+        /// What writes report instead of success, while set
+        pub write_error: Option<NetworkError>,
+        pub game_state: Option<String>,
+        pub actions: Option<String>,
+        pub host_actions: Option<String>,
+        pub players: Option<String>,
+    }
+
+    /// A room that answers straight away. Clones share the room, so a test keeps one to
+    /// look into while the game talks to another.
+    #[derive(Clone, Default)]
+    pub struct MockBackend(Arc<Mutex<MockRoom>>);
+
+    impl MockBackend {
+        pub fn room(&self) -> MutexGuard<'_, MockRoom> {
+            self.0.lock().unwrap()
+        }
+
+        /// The JSON of the writes sent so far
+        pub fn written(&self) -> Vec<String> {
+            self.room().writes.iter().map(|(_, json)| json.clone()).collect()
+        }
+
+        fn write(&self, operation: NetworkOperation, json: String, results: NetworkResults) {
+            let mut room = self.room();
+            room.writes.push((operation, json));
+            results.send(operation, room.write_error.clone().map_or(Ok(()), Err));
+        }
+    }
+
+    impl NetworkBackend for MockBackend {
+        fn authenticate(&self, results: NetworkResults) {
+            results.send(NetworkOperation::Authenticate, Ok(()));
+        }
+
+        fn local_player_id(&self) -> Option<String> {
+            Some("mock-player".to_string())
+        }
+
+        fn create_room(&self, _room_code: &str, _name: &str, _character_id: u8, _color_index: usize, results: NetworkResults) {
+            results.send(NetworkOperation::CreateRoom, Ok(()));
+        }
+
+        fn join_room(&self, _room_code: &str, _name: &str, _character_id: u8, _color_index: usize, results: NetworkResults) {
+            results.send(NetworkOperation::JoinRoom, Ok(()));
+        }
+
+        fn rejoin_room(&self, _room_code: &str, _player_id: &str, _rejoin_token: Option<&str>, results: NetworkResults) {
+            results.send(NetworkOperation::JoinRoom, Ok(()));
+        }
+
+        fn rejoin_token(&self) -> Option<String> {
+            None
+        }
+
+        fn leave_room(&self, _room_code: &str) {}
+
+        fn update_appearance(
+            &self,
+            _room_code: &str,
+            _character_id: u8,
+            _color_index: usize,
+            _custom_color: Option<[u8; 3]>,
+            _name: Option<String>,
+        ) {
+        }
+
+        fn set_ready(&self, _room_code: &str, _is_ready: bool) {}
+
+        fn set_randomize_order(&self, _room_code: &str, _randomize: bool) {}
+
+        fn randomize_order(&self) -> bool {
+            false
+        }
+
+        fn kick_player(&self, _room_code: &str, _player_id: &str) {}
+
+        fn set_locked(&self, _room_code: &str, _locked: bool) {}
+
+        fn is_locked(&self) -> bool {
+            false
+        }
+
+        fn was_kicked(&self) -> bool {
+            false
+        }
+
+        fn set_room_settings(&self, _room_code: &str, _settings_json: String) {}
+
+        fn room_settings(&self) -> Option<String> {
+            None
+        }
+
+        fn server_time_ms(&self) -> Option<f64> {
+            None
+        }
+
+        fn start_game(&self, _room_code: &str, _setup_json: String, results: NetworkResults) {
+            results.send(NetworkOperation::StartGame, Ok(()));
+        }
+
+        fn has_game_started(&self) -> bool {
+            true
+        }
+
+        fn game_setup(&self) -> Option<String> {
+            None
+        }
+
+        fn reset_room(&self, _room_code: &str) {}
+
+        fn subscribe_to_room(&self, _room_code: &str) {}
+
+        fn subscribe_to_game_state(&self, _room_code: &str) {}
+
+        fn subscribe_to_actions(&self, _room_code: &str) {}
+
+        fn unsubscribe_all(&self) {}
+
+        fn unsubscribe_from_game(&self) {}
+
+        fn write_game_state(&self, _room_code: &str, state_json: String, results: NetworkResults) {
+            self.write(NetworkOperation::SyncState, state_json, results);
+        }
+
+        fn broadcast_action(&self, _room_code: &str, action_json: String, results: NetworkResults) {
+            self.write(NetworkOperation::SyncState, action_json, results);
+        }
+
+        fn submit_action(&self, _room_code: &str, action_json: String, results: NetworkResults) {
+            self.write(NetworkOperation::SubmitAction, action_json, results);
+        }
+
+        fn poll_game_state(&self) -> Option<String> {
+            self.room().game_state.take()
+        }
+
+        fn poll_actions(&self) -> Option<String> {
+            self.room().actions.take()
+        }
+
+        fn poll_host_actions(&self) -> Option<String> {
+            self.room().host_actions.take()
+        }
+
+        fn poll_players(&self) -> Option<String> {
+            self.room().players.take()
+        }
+
+        fn send_emote(&self, _room_code: &str, _emote_json: String) {}
+
+        fn poll_emotes(&self) -> Option<String> {
+            None
+        }
+
+        fn timestamp_ms(&self) -> u64 {
+            0
+        }
+    }
+}
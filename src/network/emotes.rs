@@ -0,0 +1,89 @@
+//! Quick reactions players send each other during online games
+//!
+//! Emotes skip the host's action log: they don't change the game, so they go straight
+//! to everyone else in the room and are shown as soon as they arrive.
+
+use bevy::prelude::*;
+
+use super::backend::Backend;
+use super::messages::{Emote, EmoteMessage};
+use super::state::NetworkState;
+
+/// Seconds an emote bubble stays up
+pub const EMOTE_SECS: f64 = 3.0;
+
+/// Seconds between emotes from the local player, so nobody floods the table
+pub const EMOTE_COOLDOWN_SECS: f64 = 1.0;
+
+/// An emote being shown above a player's avatar
+#[derive(Clone, Debug)]
+pub struct ShownEmote {
+    pub seat: usize,
+    pub emote: Emote,
+    /// `Time::elapsed_secs_f64` when it arrived
+    pub shown_at: f64,
+}
+
+/// Emotes waiting to be sent, and the ones on screen
+#[derive(Resource, Default)]
+pub struct Emotes {
+    /// Chosen by the local player since the last frame
+    pub outgoing: Vec<Emote>,
+    pub shown: Vec<ShownEmote>,
+    /// When the local player last sent one
+    pub last_sent_at: Option<f64>,
+}
+
+impl Emotes {
+    /// Send an emote from the local player, unless they've just sent one
+    pub fn send(&mut self, emote: Emote, now: f64) {
+        if self.cooling_down(now) {
+            return;
+        }
+        self.outgoing.push(emote);
+        self.last_sent_at = Some(now);
+    }
+
+    /// Whether the local player has to wait before sending another emote
+    pub fn cooling_down(&self, now: f64) -> bool {
+        self.last_sent_at.is_some_and(|sent| now - sent < EMOTE_COOLDOWN_SECS)
+    }
+
+    /// The newest emote shown for a seat, if it's still up
+    pub fn latest(&self, seat: usize) -> Option<&ShownEmote> {
+        self.shown.iter().rev().find(|shown| shown.seat == seat)
+    }
+}
+
+/// System to send the local player's emotes and pick up everyone else's
+pub fn exchange_emotes(
+    network_state: Res<NetworkState>,
+    backend: Res<Backend>,
+    mut emotes: ResMut<Emotes>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs_f64();
+    emotes.shown.retain(|shown| now - shown.shown_at < EMOTE_SECS);
+
+    if !network_state.is_online() {
+        emotes.outgoing.clear();
+        return;
+    }
+    let Some(ref room_code) = network_state.room_code else { return };
+
+    // Our own emotes go up here straight away; the backend only sends them to the others
+    for emote in std::mem::take(&mut emotes.outgoing) {
+        backend.send_emote(room_code, serde_json::to_string(&emote).unwrap_or_default());
+        if let Some(seat) = network_state.local_player_index {
+            emotes.shown.push(ShownEmote { seat, emote, shown_at: now });
+        }
+    }
+
+    let Some(json) = backend.poll_emotes() else { return };
+    let Ok(messages) = serde_json::from_str::<Vec<EmoteMessage>>(&json) else { return };
+    for message in messages {
+        if let Some(seat) = network_state.seat_of(&message.player_id) {
+            emotes.shown.push(ShownEmote { seat, emote: message.emote, shown_at: now });
+        }
+    }
+}
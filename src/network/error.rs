@@ -0,0 +1,165 @@
+//! Typed errors for network operations
+//!
+//! Firebase calls run asynchronously and report back through `NetworkResults`,
+//! so the lobby, waiting room and in-game sync can show what actually went wrong.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+
+use super::state::NetworkState;
+
+/// Why a network operation failed
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum NetworkError {
+    RoomFull,
+    RoomNotFound,
+    GameAlreadyStarted,
+    /// The host stopped accepting new players
+    RoomLocked,
+    /// The host took us out of the room
+    Kicked,
+    /// The host left, which closed the room
+    HostLeft,
+    /// The room's host is on a build that speaks a different protocol version
+    VersionMismatch,
+    PermissionDenied,
+    QuotaExceeded,
+    NotAuthenticated,
+    Disconnected,
+    /// This build can't play online
+    Unsupported,
+    Other(String),
+}
+
+impl NetworkError {
+    /// Classify an error message or Firebase error code
+    #[allow(dead_code)]
+    pub fn from_message(message: &str) -> Self {
+        let lower = message.to_lowercase();
+        if lower.contains("room is full") || lower.contains("room full") {
+            NetworkError::RoomFull
+        } else if lower.contains("room not found") || lower.contains("does not exist") {
+            NetworkError::RoomNotFound
+        } else if lower.contains("already started") {
+            NetworkError::GameAlreadyStarted
+        } else if lower.contains("room is locked") {
+            NetworkError::RoomLocked
+        } else if lower.contains("removed from room") {
+            NetworkError::Kicked
+        } else if lower.contains("version mismatch") {
+            NetworkError::VersionMismatch
+        } else if lower.contains("permission") {
+            NetworkError::PermissionDenied
+        } else if lower.contains("quota") || lower.contains("resource-exhausted") || lower.contains("too many") {
+            NetworkError::QuotaExceeded
+        } else if lower.contains("not authenticated") || lower.contains("auth/") {
+            NetworkError::NotAuthenticated
+        } else if lower.contains("network") || lower.contains("offline") || lower.contains("unavailable") {
+            NetworkError::Disconnected
+        } else {
+            NetworkError::Other(message.to_string())
+        }
+    }
+
+    /// What the player should do about it
+    pub fn user_message(&self) -> String {
+        match self {
+            NetworkError::RoomFull => "That room is full. Ask the host for a new room or create your own.".to_string(),
+            NetworkError::RoomNotFound => "Room not found. Check the 4-letter code and try again.".to_string(),
+            NetworkError::GameAlreadyStarted => "That game has already started. Ask the host to create a new room.".to_string(),
+            NetworkError::RoomLocked => "The host has locked that room. Ask them to unlock it, or create your own.".to_string(),
+            NetworkError::Kicked => "The host removed you from the room.".to_string(),
+            NetworkError::HostLeft => "The host left, so the room has closed.".to_string(),
+            NetworkError::VersionMismatch => "That room is running a different version of the game. Make sure you and the host both have the latest version (reload the page), then try again.".to_string(),
+            NetworkError::PermissionDenied => "Permission denied. Only the host can do that, or the room has closed.".to_string(),
+            NetworkError::QuotaExceeded => "The game server is busy right now. Please wait a minute and try again.".to_string(),
+            NetworkError::NotAuthenticated => "Couldn't sign in to the game server. Reload the page to try again.".to_string(),
+            NetworkError::Disconnected => "Connection lost. Check your internet connection.".to_string(),
+            NetworkError::Unsupported => "Online play isn't available in this version of the game.".to_string(),
+            NetworkError::Other(message) => format!("Network error: {}", message),
+        }
+    }
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.user_message())
+    }
+}
+
+/// The kind of network operation a result belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum NetworkOperation {
+    Authenticate,
+    CreateRoom,
+    JoinRoom,
+    StartGame,
+    SyncState,
+    SubmitAction,
+}
+
+impl NetworkOperation {
+    /// Failures that mean the player never made it into (or has lost) the room
+    pub fn is_room_setup(&self) -> bool {
+        matches!(self, NetworkOperation::CreateRoom | NetworkOperation::JoinRoom | NetworkOperation::StartGame)
+    }
+}
+
+/// A failed network operation
+#[derive(Clone, Debug)]
+pub struct NetworkFailure {
+    pub operation: NetworkOperation,
+    pub error: NetworkError,
+}
+
+/// Completed operation reported by an async callback
+#[derive(Clone, Debug)]
+pub struct NetworkResult {
+    pub operation: NetworkOperation,
+    pub result: Result<(), NetworkError>,
+}
+
+/// Channel that async Firebase callbacks report their results into
+#[derive(Resource, Clone, Default)]
+pub struct NetworkResults {
+    queue: Arc<Mutex<Vec<NetworkResult>>>,
+}
+
+#[allow(dead_code)]
+impl NetworkResults {
+    /// Report the outcome of an operation (callable from any callback)
+    pub fn send(&self, operation: NetworkOperation, result: Result<(), NetworkError>) {
+        if let Ok(mut queue) = self.queue.lock() {
+            queue.push(NetworkResult { operation, result });
+        }
+    }
+
+    pub fn drain(&self) -> Vec<NetworkResult> {
+        self.queue.lock().map(|mut queue| queue.drain(..).collect()).unwrap_or_default()
+    }
+}
+
+/// System to apply reported results to the network state
+pub fn collect_network_results(
+    results: Res<NetworkResults>,
+    mut network_state: ResMut<NetworkState>,
+) {
+    for NetworkResult { operation, result } in results.drain() {
+        match result {
+            Ok(()) => {
+                // A later success clears an earlier failure of the same kind
+                if network_state.connection_error.as_ref().is_some_and(|f| f.operation == operation) {
+                    network_state.connection_error = None;
+                }
+            }
+            Err(error) => {
+                warn!("Network {:?} failed: {:?}", operation, error);
+                network_state.connection_error = Some(NetworkFailure { operation, error });
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Commit-reveal proof that online dice rolls were fair
+//!
+//! At the start of each leg the host picks a secret seed and broadcasts only its hash.
+//! Every roll of the leg is drawn from that seed, and once the leg ends the seed is
+//! revealed so clients can check the hash and replay the rolls themselves. The hash is
+//! SHA-256, so the host can't find another seed behind the same commitment, and the rolls
+//! come from ChaCha8, so every build replays a seed the same way.
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use sha2::{Digest, Sha256};
+
+use crate::components::dice::{DieRollResult, PyramidDie};
+use crate::components::Pyramid;
+use crate::components::TurnState;
+use super::state::NetworkState;
+
+/// Fairness commitment for the current leg
+#[derive(Resource, Default)]
+pub struct LegFairness {
+    pub leg: u32,
+    /// Hash of the leg's seed, published before any dice are rolled
+    pub commitment: Option<String>,
+    /// Seed once it is known: the host knows it all along, clients after the leg ends
+    pub revealed_seed: Option<u64>,
+    /// Result of checking the revealed seed against the commitment and the rolls
+    pub verified: Option<bool>,
+    /// Roll generator derived from the seed (host only)
+    rng: Option<ChaCha8Rng>,
+}
+
+impl LegFairness {
+    /// Pick a new secret seed and commit to it (host only)
+    pub fn commit(&mut self, leg: u32) {
+        let seed: u64 = rand::thread_rng().gen();
+        *self = Self {
+            leg,
+            commitment: Some(commitment_for(leg, seed)),
+            revealed_seed: Some(seed),
+            verified: None,
+            rng: Some(ChaCha8Rng::seed_from_u64(seed)),
+        };
+    }
+
+    /// Record the commitment published by the host (clients only)
+    pub fn observe_commitment(&mut self, leg: u32, commitment: String) {
+        *self = Self {
+            leg,
+            commitment: Some(commitment),
+            ..default()
+        };
+    }
+
+    /// Generator the host must use for every roll of the committed leg
+    pub fn rng_mut(&mut self) -> Option<&mut ChaCha8Rng> {
+        self.rng.as_mut()
+    }
+
+    /// Check that the seed matches the commitment and reproduces every roll of the leg
+    pub fn verify(&mut self, rolls: &[DieRollResult]) -> bool {
+        let (Some(seed), Some(commitment)) = (self.revealed_seed, self.commitment.as_ref()) else {
+            return false;
+        };
+
+        let fair = commitment_for(self.leg, seed) == *commitment && replay_matches(seed, rolls);
+        self.verified = Some(fair);
+        fair
+    }
+}
+
+/// Hash published for a leg's seed (SHA-256, as hex)
+pub fn commitment_for(leg: u32, seed: u64) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"camel-up-leg-seed");
+    hasher.update(leg.to_le_bytes());
+    hasher.update(seed.to_le_bytes());
+    hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Seeds travel as hex strings since JSON numbers lose precision past 2^53
+pub fn encode_seed(seed: u64) -> String {
+    format!("{:016x}", seed)
+}
+
+pub fn decode_seed(seed: &str) -> Option<u64> {
+    u64::from_str_radix(seed, 16).ok()
+}
+
+/// Re-roll a fresh pyramid from the seed and compare against the observed rolls
+fn replay_matches(seed: u64, rolls: &[DieRollResult]) -> bool {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut pyramid = Pyramid::new();
+
+    rolls.iter().all(|observed| {
+        match (pyramid.roll_die_with(&mut rng), observed) {
+            (
+                Some(DieRollResult::Regular { color, value }),
+                DieRollResult::Regular { color: observed_color, value: observed_value },
+            ) => color == *observed_color && value == *observed_value,
+            (
+                Some(DieRollResult::Crazy { color, value }),
+                DieRollResult::Crazy { color: observed_color, value: observed_value },
+            ) => color == *observed_color && value == *observed_value,
+            _ => false,
+        }
+    })
+}
+
+/// Rolls of the current leg in the order they happened
+pub fn leg_rolls(pyramid: &Pyramid) -> Vec<DieRollResult> {
+    pyramid
+        .rolled_dice
+        .iter()
+        .filter_map(|die| match die {
+            PyramidDie::Regular(regular) => regular
+                .value
+                .map(|value| DieRollResult::Regular { color: regular.color, value }),
+            PyramidDie::Crazy { rolled } => {
+                rolled.map(|(color, value)| DieRollResult::Crazy { color, value })
+            }
+        })
+        .collect()
+}
+
+/// System to commit to a new seed at the start of each leg (host only)
+pub fn commit_leg_seed(
+    network_state: Res<NetworkState>,
+    turn_state: Res<TurnState>,
+    mut fairness: ResMut<LegFairness>,
+) {
+    if network_state.is_host() && fairness.leg != turn_state.leg_number {
+        fairness.commit(turn_state.leg_number);
+        info!("Committed to dice seed for leg {}", turn_state.leg_number);
+    }
+}
+
+/// System to verify the leg's rolls once the seed is revealed at the end of the leg
+pub fn verify_leg_rolls(
+    network_state: Res<NetworkState>,
+    pyramid: Res<Pyramid>,
+    mut fairness: ResMut<LegFairness>,
+) {
+    if !network_state.is_online()
+        || !pyramid.all_dice_rolled()
+        || fairness.revealed_seed.is_none()
+        || fairness.verified.is_some()
+    {
+        return;
+    }
+
+    if fairness.verify(&leg_rolls(&pyramid)) {
+        info!("Leg {} dice verified fair", fairness.leg);
+    } else {
+        warn!("Leg {} dice do not match the committed seed", fairness.leg);
+    }
+}
@@ -0,0 +1,178 @@
+//! Firebase Realtime Database backend (browser builds)
+//!
+//! Forwards every call to the JavaScript bridge in firebase_bridge.js.
+
+use super::backend::NetworkBackend;
+use super::error::{NetworkError, NetworkOperation, NetworkResults};
+use super::js_bindings::{self, async_ops};
+
+/// Online play through Firebase, with anonymous sign in
+pub struct FirebaseBackend;
+
+impl NetworkBackend for FirebaseBackend {
+    fn authenticate(&self, results: NetworkResults) {
+        async_ops::init_and_authenticate(results);
+    }
+
+    fn local_player_id(&self) -> Option<String> {
+        js_bindings::get_current_user_id()
+    }
+
+    fn create_room(&self, room_code: &str, name: &str, character_id: u8, color_index: usize, results: NetworkResults) {
+        async_ops::create_room_async(room_code.to_string(), name.to_string(), character_id, color_index, results);
+    }
+
+    fn join_room(&self, room_code: &str, name: &str, character_id: u8, color_index: usize, results: NetworkResults) {
+        async_ops::join_room_async(room_code.to_string(), name.to_string(), character_id, color_index, results);
+    }
+
+    fn rejoin_room(&self, room_code: &str, player_id: &str, _rejoin_token: Option<&str>, results: NetworkResults) {
+        // The seat belongs to whoever was signed in when we joined
+        if self.local_player_id().as_deref() != Some(player_id) {
+            results.send(
+                NetworkOperation::JoinRoom,
+                Err(NetworkError::Other("that game was joined from another sign in".to_string())),
+            );
+            return;
+        }
+        async_ops::rejoin_room_async(room_code.to_string(), results);
+    }
+
+    // The database rules check the sign in itself
+    fn rejoin_token(&self) -> Option<String> {
+        None
+    }
+
+    fn leave_room(&self, room_code: &str) {
+        async_ops::leave_room_async(room_code.to_string());
+    }
+
+    fn update_appearance(
+        &self,
+        room_code: &str,
+        character_id: u8,
+        color_index: usize,
+        custom_color: Option<[u8; 3]>,
+        name: Option<String>,
+    ) {
+        async_ops::update_appearance_async(room_code.to_string(), character_id, color_index, custom_color, name);
+    }
+
+    fn set_ready(&self, room_code: &str, is_ready: bool) {
+        async_ops::set_ready_async(room_code.to_string(), is_ready);
+    }
+
+    fn set_randomize_order(&self, room_code: &str, randomize: bool) {
+        async_ops::set_randomize_order_async(room_code.to_string(), randomize);
+    }
+
+    fn randomize_order(&self) -> bool {
+        js_bindings::get_randomize_order()
+    }
+
+    fn kick_player(&self, room_code: &str, player_id: &str) {
+        async_ops::kick_player_async(room_code.to_string(), player_id.to_string());
+    }
+
+    fn set_locked(&self, room_code: &str, locked: bool) {
+        async_ops::set_room_locked_async(room_code.to_string(), locked);
+    }
+
+    fn is_locked(&self) -> bool {
+        js_bindings::is_room_locked()
+    }
+
+    fn was_kicked(&self) -> bool {
+        js_bindings::was_kicked()
+    }
+
+    fn set_room_settings(&self, room_code: &str, settings_json: String) {
+        async_ops::set_room_settings_async(room_code.to_string(), settings_json);
+    }
+
+    fn room_settings(&self) -> Option<String> {
+        js_bindings::get_room_settings()
+    }
+
+    fn server_time_ms(&self) -> Option<f64> {
+        Some(js_bindings::get_server_time())
+    }
+
+    fn start_game(&self, room_code: &str, setup_json: String, results: NetworkResults) {
+        async_ops::start_game_async(room_code.to_string(), setup_json, results);
+    }
+
+    fn has_game_started(&self) -> bool {
+        js_bindings::has_game_started()
+    }
+
+    fn game_setup(&self) -> Option<String> {
+        js_bindings::get_game_setup()
+    }
+
+    fn reset_room(&self, room_code: &str) {
+        async_ops::reset_room_async(room_code.to_string());
+    }
+
+    fn subscribe_to_room(&self, room_code: &str) {
+        js_bindings::subscribe_to_players(room_code);
+        js_bindings::subscribe_to_metadata(room_code);
+        js_bindings::subscribe_to_emotes(room_code);
+    }
+
+    fn subscribe_to_game_state(&self, room_code: &str) {
+        js_bindings::subscribe_to_game_state(room_code);
+    }
+
+    fn subscribe_to_actions(&self, room_code: &str) {
+        js_bindings::subscribe_to_actions(room_code);
+    }
+
+    fn unsubscribe_all(&self) {
+        js_bindings::unsubscribe_all();
+    }
+
+    fn unsubscribe_from_game(&self) {
+        js_bindings::unsubscribe_from_game();
+    }
+
+    fn write_game_state(&self, room_code: &str, state_json: String, results: NetworkResults) {
+        async_ops::write_state_async(room_code.to_string(), state_json, results);
+    }
+
+    fn broadcast_action(&self, room_code: &str, action_json: String, results: NetworkResults) {
+        async_ops::broadcast_action_async(room_code.to_string(), action_json, results);
+    }
+
+    fn submit_action(&self, room_code: &str, action_json: String, results: NetworkResults) {
+        async_ops::submit_action_async(room_code.to_string(), action_json, results);
+    }
+
+    fn poll_game_state(&self) -> Option<String> {
+        js_bindings::poll_game_state()
+    }
+
+    fn poll_actions(&self) -> Option<String> {
+        js_bindings::poll_actions()
+    }
+
+    fn poll_host_actions(&self) -> Option<String> {
+        js_bindings::poll_host_actions()
+    }
+
+    fn poll_players(&self) -> Option<String> {
+        js_bindings::poll_players()
+    }
+
+    fn send_emote(&self, room_code: &str, emote_json: String) {
+        async_ops::send_emote_async(room_code.to_string(), emote_json);
+    }
+
+    fn poll_emotes(&self) -> Option<String> {
+        js_bindings::poll_emotes()
+    }
+
+    fn timestamp_ms(&self) -> u64 {
+        js_sys::Date::now() as u64
+    }
+}
@@ -30,6 +30,7 @@ extern "C" {
         host_name: &str,
         character_id: u8,
         color_index: usize,
+        protocol_version: u32,
     ) -> Result<JsValue, JsValue>;
 
     #[wasm_bindgen(js_name = roomExists, catch)]
@@ -41,8 +42,12 @@ extern "C" {
         player_name: &str,
         character_id: u8,
         color_index: usize,
+        protocol_version: u32,
     ) -> Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(js_name = rejoinRoom, catch)]
+    pub async fn rejoin_room(room_code: &str, protocol_version: u32) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_name = leaveRoom, catch)]
     pub async fn leave_room(room_code: &str) -> Result<JsValue, JsValue>;
 
@@ -54,11 +59,15 @@ extern "C" {
         room_code: &str,
         character_id: u8,
         color_index: usize,
+        custom_color: Option<Vec<u8>>,
         name: Option<String>,
     ) -> Result<JsValue, JsValue>;
 
     #[wasm_bindgen(js_name = startGame, catch)]
-    pub async fn start_game(room_code: &str) -> Result<JsValue, JsValue>;
+    pub async fn start_game(room_code: &str, setup_json: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = resetRoom, catch)]
+    pub async fn reset_room(room_code: &str) -> Result<JsValue, JsValue>;
 
     // Game state sync
     #[wasm_bindgen(js_name = writeGameState, catch)]
@@ -67,6 +76,9 @@ extern "C" {
     #[wasm_bindgen(js_name = submitAction, catch)]
     pub async fn submit_action(room_code: &str, action_json: &str) -> Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(js_name = broadcastAction, catch)]
+    pub async fn broadcast_action(room_code: &str, action_json: &str) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_name = markActionProcessed, catch)]
     pub async fn mark_action_processed(
         room_code: &str,
@@ -86,6 +98,9 @@ extern "C" {
     #[wasm_bindgen(js_name = subscribeToMetadata)]
     pub fn subscribe_to_metadata(room_code: &str);
 
+    #[wasm_bindgen(js_name = subscribeToEmotes)]
+    pub fn subscribe_to_emotes(room_code: &str);
+
     // Polling (for receiving Firebase updates)
     #[wasm_bindgen(js_name = pollGameState)]
     pub fn poll_game_state() -> Option<String>;
@@ -93,18 +108,121 @@ extern "C" {
     #[wasm_bindgen(js_name = pollActions)]
     pub fn poll_actions() -> Option<String>;
 
+    #[wasm_bindgen(js_name = pollHostActions)]
+    pub fn poll_host_actions() -> Option<String>;
+
     #[wasm_bindgen(js_name = pollPlayers)]
     pub fn poll_players() -> Option<String>;
 
+    #[wasm_bindgen(js_name = pollEmotes)]
+    pub fn poll_emotes() -> Option<String>;
+
+    #[wasm_bindgen(js_name = sendEmote, catch)]
+    pub async fn send_emote(room_code: &str, emote_json: &str) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(js_name = hasGameStarted)]
     pub fn has_game_started() -> bool;
 
+    #[wasm_bindgen(js_name = getGameSetup)]
+    pub fn get_game_setup() -> Option<String>;
+
     #[wasm_bindgen(js_name = getRandomizeOrder)]
     pub fn get_randomize_order() -> bool;
 
     #[wasm_bindgen(js_name = setRandomizeOrder, catch)]
     pub async fn set_randomize_order(room_code: &str, randomize: bool) -> Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(js_name = getServerTime)]
+    pub fn get_server_time() -> f64;
+
+    #[wasm_bindgen(js_name = getRoomSettings)]
+    pub fn get_room_settings() -> Option<String>;
+
+    #[wasm_bindgen(js_name = setRoomSettings, catch)]
+    pub async fn set_room_settings(room_code: &str, settings_json: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = isRoomLocked)]
+    pub fn is_room_locked() -> bool;
+
+    #[wasm_bindgen(js_name = setRoomLocked, catch)]
+    pub async fn set_room_locked(room_code: &str, locked: bool) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = kickPlayer, catch)]
+    pub async fn kick_player(room_code: &str, player_id: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_name = wasKicked)]
+    pub fn was_kicked() -> bool;
+
+    // Device capabilities
+    #[wasm_bindgen(js_name = getHardwareConcurrency)]
+    pub fn get_hardware_concurrency() -> u32;
+
+    // Invite links
+    #[wasm_bindgen(js_name = getInviteLink)]
+    pub fn get_invite_link(room_code: &str) -> String;
+
+    #[wasm_bindgen(js_name = takeInviteRoomCode)]
+    pub fn take_invite_room_code() -> Option<String>;
+
+    #[wasm_bindgen(js_name = copyToClipboard)]
+    pub fn copy_to_clipboard(text: &str);
+
+    #[wasm_bindgen(js_name = canShare)]
+    pub fn can_share() -> bool;
+
+    #[wasm_bindgen(js_name = shareInvite)]
+    pub fn share_invite(room_code: &str, link: &str);
+
+    // Confirm before closing the tab mid-game
+    #[wasm_bindgen(js_name = setLeaveGuard)]
+    pub fn set_leave_guard(enabled: bool);
+
+    // Spoken commentary
+    // Session kept in localStorage across page reloads
+    #[wasm_bindgen(js_name = saveSession)]
+    pub fn save_session(session_json: &str);
+
+    #[wasm_bindgen(js_name = loadSession)]
+    pub fn load_session() -> Option<String>;
+
+    #[wasm_bindgen(js_name = clearSession)]
+    pub fn clear_session();
+
+    // Documents kept in localStorage between visits (the autosave, the last replay, stats)
+    #[wasm_bindgen(js_name = storeItem)]
+    pub fn store_item(key: &str, json: &str);
+
+    #[wasm_bindgen(js_name = loadItem)]
+    pub fn load_item(key: &str) -> Option<String>;
+
+    #[wasm_bindgen(js_name = removeItem)]
+    pub fn remove_item(key: &str);
+
+    #[wasm_bindgen(js_name = downloadFile)]
+    pub fn download_file(file_name: &str, contents: &str);
+
+    #[wasm_bindgen(js_name = isSpeechSupported)]
+    pub fn is_speech_supported() -> bool;
+
+    #[wasm_bindgen(js_name = getSpeechVoices)]
+    pub fn get_speech_voices() -> String;
+
+    #[wasm_bindgen(js_name = speakText)]
+    pub fn speak_text(text: &str, rate: f32, volume: f32, voice_name: &str);
+
+    #[wasm_bindgen(js_name = stopSpeech)]
+    pub fn stop_speech();
+
+    #[wasm_bindgen(js_name = announce)]
+    pub fn announce(text: &str);
+
+    // "Your turn" alert: flash the tab title while the tab is in the background
+    #[wasm_bindgen(js_name = flashTitle)]
+    pub fn flash_title(message: &str);
+
+    #[wasm_bindgen(js_name = stopTitleFlash)]
+    pub fn stop_title_flash();
+
     // Error handling
     #[wasm_bindgen(js_name = getFirebaseError)]
     pub fn get_firebase_error() -> Option<String>;
@@ -113,6 +231,9 @@ extern "C" {
     #[wasm_bindgen(js_name = unsubscribeAll)]
     pub fn unsubscribe_all();
 
+    #[wasm_bindgen(js_name = unsubscribeFromGame)]
+    pub fn unsubscribe_from_game();
+
     #[wasm_bindgen(js_name = deleteRoom, catch)]
     pub async fn delete_room(room_code: &str) -> Result<JsValue, JsValue>;
 }
@@ -120,31 +241,60 @@ extern "C" {
 /// Wrapper for async Firebase operations
 pub mod async_ops {
     use super::*;
+    use crate::network::error::{NetworkError, NetworkOperation, NetworkResults};
+    use crate::network::messages::PROTOCOL_VERSION;
     use wasm_bindgen_futures::spawn_local;
 
+    /// Turn a rejected JS promise into a typed error, using the Firebase error code when present
+    fn js_error(e: &JsValue, fallback: &str) -> NetworkError {
+        if let Some(message) = e.as_string() {
+            return NetworkError::from_message(&message);
+        }
+        let field = |name: &str| {
+            js_sys::Reflect::get(e, &JsValue::from_str(name))
+                .ok()
+                .and_then(|v| v.as_string())
+        };
+        match (field("code"), field("message")) {
+            (Some(code), Some(message)) => NetworkError::from_message(&format!("{}: {}", code, message)),
+            (Some(text), None) | (None, Some(text)) => NetworkError::from_message(&text),
+            (None, None) => NetworkError::Other(fallback.to_string()),
+        }
+    }
+
+    /// Error recorded by the bridge when a call returned false
+    fn bridge_error(fallback: &str) -> NetworkError {
+        get_firebase_error()
+            .map(|message| NetworkError::from_message(&message))
+            .unwrap_or_else(|| NetworkError::Other(fallback.to_string()))
+    }
+
+    /// Await a bridge call that resolves to true on success
+    async fn check(
+        call: impl std::future::Future<Output = Result<JsValue, JsValue>>,
+        fallback: &str,
+    ) -> Result<(), NetworkError> {
+        match call.await {
+            Ok(result) if result.as_bool().unwrap_or(false) => Ok(()),
+            Ok(_) => Err(bridge_error(fallback)),
+            Err(e) => Err(js_error(&e, fallback)),
+        }
+    }
+
     /// Initialize Firebase and sign in anonymously
-    pub fn init_and_authenticate(on_complete: impl FnOnce(Result<String, String>) + 'static) {
+    pub fn init_and_authenticate(results: NetworkResults) {
         spawn_local(async move {
             if !initialize_firebase() {
-                on_complete(Err("Failed to initialize Firebase".to_string()));
+                results.send(NetworkOperation::Authenticate, Err(bridge_error("Failed to initialize Firebase")));
                 return;
             }
 
-            match sign_in_anonymously().await {
-                Ok(uid) => {
-                    if let Some(uid_str) = uid.as_string() {
-                        on_complete(Ok(uid_str));
-                    } else {
-                        on_complete(Err("Failed to get user ID".to_string()));
-                    }
-                }
-                Err(e) => {
-                    let msg = e
-                        .as_string()
-                        .unwrap_or_else(|| "Unknown authentication error".to_string());
-                    on_complete(Err(msg));
-                }
-            }
+            let result = match sign_in_anonymously().await {
+                Ok(uid) if uid.as_string().is_some() => Ok(()),
+                Ok(_) => Err(NetworkError::NotAuthenticated),
+                Err(e) => Err(js_error(&e, "Unknown authentication error")),
+            };
+            results.send(NetworkOperation::Authenticate, result);
         });
     }
 
@@ -154,26 +304,15 @@ pub mod async_ops {
         host_name: String,
         character_id: u8,
         color_index: usize,
-        on_complete: impl FnOnce(Result<(), String>) + 'static,
+        results: NetworkResults,
     ) {
         spawn_local(async move {
-            match create_room(&room_code, &host_name, character_id, color_index).await {
-                Ok(result) => {
-                    if result.as_bool().unwrap_or(false) {
-                        on_complete(Ok(()));
-                    } else {
-                        on_complete(Err(
-                            get_firebase_error().unwrap_or_else(|| "Failed to create room".into())
-                        ));
-                    }
-                }
-                Err(e) => {
-                    on_complete(Err(
-                        e.as_string()
-                            .unwrap_or_else(|| "Room creation error".to_string())
-                    ));
-                }
-            }
+            let result = check(
+                create_room(&room_code, &host_name, character_id, color_index, PROTOCOL_VERSION),
+                "Failed to create room",
+            )
+            .await;
+            results.send(NetworkOperation::CreateRoom, result);
         });
     }
 
@@ -183,71 +322,57 @@ pub mod async_ops {
         player_name: String,
         character_id: u8,
         color_index: usize,
-        on_complete: impl FnOnce(Result<(), String>) + 'static,
+        results: NetworkResults,
     ) {
         spawn_local(async move {
-            match join_room(&room_code, &player_name, character_id, color_index).await {
-                Ok(result) => {
-                    if result.as_bool().unwrap_or(false) {
-                        on_complete(Ok(()));
-                    } else {
-                        on_complete(Err(
-                            get_firebase_error().unwrap_or_else(|| "Failed to join room".into())
-                        ));
-                    }
-                }
-                Err(e) => {
-                    on_complete(Err(
-                        e.as_string()
-                            .unwrap_or_else(|| "Join room error".to_string())
-                    ));
-                }
-            }
+            let result = check(
+                join_room(&room_code, &player_name, character_id, color_index, PROTOCOL_VERSION),
+                "Failed to join room",
+            )
+            .await;
+            results.send(NetworkOperation::JoinRoom, result);
+        });
+    }
+
+    /// Take our seat again in a started game
+    pub fn rejoin_room_async(room_code: String, results: NetworkResults) {
+        spawn_local(async move {
+            let result = check(rejoin_room(&room_code, PROTOCOL_VERSION), "Failed to rejoin room").await;
+            results.send(NetworkOperation::JoinRoom, result);
         });
     }
 
     /// Write game state to Firebase
-    pub fn write_state_async(room_code: String, state_json: String) {
+    pub fn write_state_async(room_code: String, state_json: String, results: NetworkResults) {
         spawn_local(async move {
-            if let Err(e) = write_game_state(&room_code, &state_json).await {
-                bevy::log::warn!(
-                    "Failed to write game state: {}",
-                    e.as_string().unwrap_or_default()
-                );
-            }
+            let result = check(write_game_state(&room_code, &state_json), "Failed to write game state").await;
+            results.send(NetworkOperation::SyncState, result);
         });
     }
 
     /// Submit an action to Firebase
-    pub fn submit_action_async(room_code: String, action_json: String) {
+    pub fn submit_action_async(room_code: String, action_json: String, results: NetworkResults) {
         spawn_local(async move {
-            if let Err(e) = submit_action(&room_code, &action_json).await {
-                bevy::log::warn!(
-                    "Failed to submit action: {}",
-                    e.as_string().unwrap_or_default()
-                );
-            }
+            let result = check(submit_action(&room_code, &action_json), "Failed to submit action").await;
+            results.send(NetworkOperation::SubmitAction, result);
         });
     }
 
-    /// Start the game
-    pub fn start_game_async(room_code: String, on_complete: impl FnOnce(Result<(), String>) + 'static) {
+    /// Add an action to the room's action log
+    pub fn broadcast_action_async(room_code: String, action_json: String, results: NetworkResults) {
         spawn_local(async move {
-            match start_game(&room_code).await {
-                Ok(result) => {
-                    if result.as_bool().unwrap_or(false) {
-                        on_complete(Ok(()));
-                    } else {
-                        on_complete(Err("Failed to start game".to_string()));
-                    }
-                }
-                Err(e) => {
-                    on_complete(Err(e.as_string().unwrap_or_else(|| "Start game error".to_string())));
-                }
-            }
+            let result = check(broadcast_action(&room_code, &action_json), "Failed to log action").await;
+            results.send(NetworkOperation::SyncState, result);
         });
     }
 
+    /// Start the game
+    pub fn start_game_async(room_code: String, setup_json: String, results: NetworkResults) {
+        spawn_local(async move {
+            let result = check(start_game(&room_code, &setup_json), "Failed to start game").await;
+            results.send(NetworkOperation::StartGame, result);
+        });
+    }
     /// Set player ready status
     pub fn set_ready_async(room_code: String, is_ready: bool) {
         spawn_local(async move {
@@ -256,9 +381,16 @@ pub mod async_ops {
     }
 
     /// Update player appearance (character, color, and optionally name)
-    pub fn update_appearance_async(room_code: String, character_id: u8, color_index: usize, name: Option<String>) {
+    pub fn update_appearance_async(
+        room_code: String,
+        character_id: u8,
+        color_index: usize,
+        custom_color: Option<[u8; 3]>,
+        name: Option<String>,
+    ) {
         spawn_local(async move {
-            let _ = update_player_appearance(&room_code, character_id, color_index, name).await;
+            let custom_color = custom_color.map(|rgb| rgb.to_vec());
+            let _ = update_player_appearance(&room_code, character_id, color_index, custom_color, name).await;
         });
     }
 
@@ -275,4 +407,39 @@ pub mod async_ops {
             let _ = set_randomize_order(&room_code, randomize).await;
         });
     }
+
+    /// Share the room's table settings (host only)
+    pub fn set_room_settings_async(room_code: String, settings_json: String) {
+        spawn_local(async move {
+            let _ = set_room_settings(&room_code, &settings_json).await;
+        });
+    }
+
+    /// Lock or unlock the room (host only)
+    pub fn set_room_locked_async(room_code: String, locked: bool) {
+        spawn_local(async move {
+            let _ = set_room_locked(&room_code, locked).await;
+        });
+    }
+
+    /// Remove a player from the room (host only)
+    pub fn kick_player_async(room_code: String, player_id: String) {
+        spawn_local(async move {
+            let _ = kick_player(&room_code, &player_id).await;
+        });
+    }
+
+    /// Show everyone else in the room an emote
+    pub fn send_emote_async(room_code: String, emote_json: String) {
+        spawn_local(async move {
+            let _ = send_emote(&room_code, &emote_json).await;
+        });
+    }
+
+    /// Take the room back to its waiting room for a rematch (host only)
+    pub fn reset_room_async(room_code: String) {
+        spawn_local(async move {
+            let _ = reset_room(&room_code).await;
+        });
+    }
 }
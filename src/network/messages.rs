@@ -1,20 +1,125 @@
 use serde::{Deserialize, Serialize};
 
-/// Actions that can be sent over the network
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub enum NetworkAction {
-    RollPyramid,
-    TakeLegBet { color: String },
-    PlaceSpectatorTile { space_index: u8, is_oasis: bool },
-    PlaceRaceBet { color: String, is_winner_bet: bool },
-}
+use crate::game::actions::GameAction;
+use super::state::OnlinePlayerInfo;
+
+/// Version of the messages below. Bump it whenever a change would make an older build
+/// misread a newer one's messages, or play out the same actions differently. Builds
+/// only play together on the same version; messages from builds that didn't send one
+/// read as version 0.
+pub const PROTOCOL_VERSION: u32 = 1;
 
-/// A network action with metadata
+/// An action a client sends to the host
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct NetworkActionMessage {
     pub player_id: String,
-    pub action: NetworkAction,
+    pub action: GameAction,
     pub timestamp: u64,
+    #[serde(default)]
+    pub protocol: u32,
+}
+
+/// Quick reaction a player sends to the table during an online game
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Emote {
+    Clap,
+    Camel,
+    Scream,
+    Money,
+}
+
+impl Emote {
+    pub const ALL: [Emote; 4] = [Emote::Clap, Emote::Camel, Emote::Scream, Emote::Money];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Emote::Clap => "👏",
+            Emote::Camel => "🐪",
+            Emote::Scream => "😱",
+            Emote::Money => "💰",
+        }
+    }
+}
+
+/// An emote on its way to everyone else in the room
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EmoteMessage {
+    pub player_id: String,
+    pub emote: Emote,
+}
+
+/// An action the host applied, numbered from 1 so clients apply each one once and in order
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SequencedAction {
+    pub sequence: u32,
+    pub action: GameAction,
+    /// Dice seed commitment for the leg, with the seed once its last die is rolled
+    #[serde(default)]
+    pub fairness: Option<SerializableFairness>,
+    /// Checksum of the game just before a turn action, so clients can check they're in step
+    #[serde(default)]
+    pub checksum: Option<String>,
+    #[serde(default)]
+    pub protocol: u32,
+}
+
+/// What the host settles when starting an online game, so everyone sets up the same one
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SerializableGameSetup {
+    /// Shared seed for the turn order, starting positions and desert events (hex, like leg seeds)
+    pub seed: String,
+    pub sheikh_variant: bool,
+    pub desert_events: bool,
+    pub relay_race: bool,
+    /// Custom camel names, indexed like `CamelColor::all()`
+    pub camel_names: Vec<String>,
+    /// AI players seated after the room's players
+    #[serde(default)]
+    pub ai_seats: u8,
+    /// Seconds a player gets for their turn, 0 for no limit
+    #[serde(default)]
+    pub turn_timer_secs: u16,
+    /// Seconds a player may be gone before the AI takes over their seat
+    #[serde(default)]
+    pub takeover_grace_secs: u16,
+    #[serde(default)]
+    pub protocol: u32,
+}
+
+/// Table the host sets up in the waiting room, kept with the room so everyone sees
+/// it before the game starts
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SerializableRoomSettings {
+    /// Seats at the table, which the room stops filling at
+    pub max_players: u8,
+    /// Seat AI players in the seats nobody joined for
+    pub fill_with_ai: bool,
+    /// Seconds a player gets for their turn, 0 for no limit
+    pub turn_timer_secs: u16,
+    /// Seconds a player may be gone before the AI takes over their seat
+    #[serde(default = "default_takeover_grace_secs")]
+    pub takeover_grace_secs: u16,
+    pub sheikh_variant: bool,
+    pub desert_events: bool,
+    pub relay_race: bool,
+}
+
+fn default_takeover_grace_secs() -> u16 {
+    30
+}
+
+impl Default for SerializableRoomSettings {
+    fn default() -> Self {
+        Self {
+            max_players: 8,
+            fill_with_ai: false,
+            turn_timer_secs: 0,
+            takeover_grace_secs: default_takeover_grace_secs(),
+            sheikh_variant: true,
+            desert_events: false,
+            relay_race: false,
+        }
+    }
 }
 
 /// Serializable version of camel position
@@ -37,6 +142,10 @@ pub struct SerializablePlayer {
     pub is_ai: bool,
     pub character_id: u8,
     pub color_index: usize,
+    #[serde(default)]
+    pub custom_color: Option<[u8; 3]>,
+    #[serde(default)]
+    pub conceded: bool,
 }
 
 /// Serializable turn state
@@ -62,6 +171,14 @@ pub struct SerializableDieResult {
     pub is_crazy: bool,
 }
 
+/// Commit-reveal data for the leg's dice seed (seed only sent once the leg is over)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SerializableFairness {
+    pub leg: u32,
+    pub commitment: String,
+    pub revealed_seed: Option<String>,
+}
+
 /// Serializable leg betting tiles state
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SerializableLegBettingTiles {
@@ -90,9 +207,11 @@ pub struct SerializableLegBet {
     pub value: u8,
 }
 
-/// Complete game state for network sync
+/// Complete game state, published by the host at the start of each leg so clients
+/// that missed part of the action log can catch up
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SerializableGameState {
+    /// Number of logged actions the state includes
     pub version: u32,
     pub turn_state: SerializableTurnState,
     pub players: Vec<SerializablePlayer>,
@@ -105,23 +224,117 @@ pub struct SerializableGameState {
     pub placed_spectator_tiles: Vec<SerializableSpectatorTile>,
     pub player_leg_bets: Vec<Vec<SerializableLegBet>>,
     pub player_pyramid_tokens: Vec<u8>,
+    /// Dice seed commitment for the current leg
+    #[serde(default)]
+    pub fairness: Option<SerializableFairness>,
+    /// Custom camel names chosen by the host, as (color, name)
+    #[serde(default)]
+    pub camel_names: Vec<(String, String)>,
+    #[serde(default)]
+    pub protocol: u32,
+}
+
+/// Operations the relay server confirms with `RelayEvent::Done`
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum RelayOperation {
+    CreateRoom,
+    JoinRoom,
+    StartGame,
+    SyncState,
+    SubmitAction,
 }
 
-/// Room metadata
+/// Message from a game to the WebSocket relay server. A connection is in at most
+/// one room, so only creating and joining name it.
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct RoomMetadata {
-    pub host_id: String,
-    pub created_at: u64,
-    pub game_started: bool,
-    pub max_players: u8,
+#[serde(tag = "type")]
+pub enum RelayRequest {
+    /// `protocol` is the creator's `PROTOCOL_VERSION`, which players joining must match
+    CreateRoom {
+        room_code: String,
+        name: String,
+        character_id: u8,
+        color_index: usize,
+        #[serde(default)]
+        protocol: u32,
+    },
+    JoinRoom {
+        room_code: String,
+        name: String,
+        character_id: u8,
+        color_index: usize,
+        #[serde(default)]
+        protocol: u32,
+    },
+    /// Take back the seat `player_id` had in a started game, after losing the connection.
+    /// `rejoin_token` is the one the relay gave the seat when it joined.
+    RejoinRoom {
+        room_code: String,
+        player_id: String,
+        #[serde(default)]
+        rejoin_token: String,
+        #[serde(default)]
+        protocol: u32,
+    },
+    LeaveRoom,
+    UpdateAppearance {
+        character_id: u8,
+        color_index: usize,
+        #[serde(default)]
+        custom_color: Option<[u8; 3]>,
+        name: Option<String>,
+    },
+    SetReady { is_ready: bool },
+    SetRandomizeOrder { randomize: bool },
+    /// Host only: take a player out of the waiting room
+    KickPlayer { player_id: String },
+    /// Host only: stop (or start again) accepting new players
+    SetLocked { locked: bool },
+    /// Host only
+    SetRoomSettings { settings: SerializableRoomSettings },
+    StartGame { setup: SerializableGameSetup },
+    /// Host only: back to the waiting room for a rematch, keeping the players
+    ResetRoom,
+    /// Host only: sent on to everyone else in the room
+    WriteGameState { state: Box<SerializableGameState> },
+    /// Host only: sent on to everyone else in the room
+    BroadcastAction { action: SequencedAction },
+    /// Sent on to the host, stamped with the sender's id
+    SubmitAction { action: NetworkActionMessage },
+    /// Sent on to everyone else in the room
+    SendEmote { emote: Emote },
 }
 
-/// Player info stored in Firebase
+/// Message from the WebSocket relay server to a game
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct FirebasePlayerInfo {
-    pub name: String,
-    pub character_id: u8,
-    pub color_index: usize,
-    pub is_ready: bool,
-    pub is_connected: bool,
+#[serde(tag = "type")]
+pub enum RelayEvent {
+    /// First message on a new connection: the id the server knows us by
+    Welcome { player_id: String },
+    /// Secret for taking our seat back if the connection drops, sent on creating,
+    /// joining or rejoining a room
+    RejoinToken { token: String },
+    /// Outcome of a request, with the reason if it failed
+    Done { operation: RelayOperation, error: Option<String> },
+    /// Everyone in the room, in the order they joined
+    Players { players: Vec<OnlinePlayerInfo> },
+    Settings {
+        game_started: bool,
+        randomize_order: bool,
+        #[serde(default)]
+        locked: bool,
+        #[serde(default)]
+        room_settings: Option<SerializableRoomSettings>,
+        setup: Option<SerializableGameSetup>,
+    },
+    /// The host took us out of the room
+    Kicked,
+    /// The host left, taking the room with them
+    RoomClosed,
+    GameState { state: Box<SerializableGameState> },
+    Action { action: NetworkActionMessage },
+    /// An entry of the host's action log
+    HostAction { action: SequencedAction },
+    /// Another player's emote
+    Emote { emote: EmoteMessage },
 }
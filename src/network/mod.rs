@@ -1,15 +1,27 @@
 pub mod state;
 pub mod messages;
 pub mod room;
+pub mod fairness;
+pub mod error;
+pub mod backend;
+pub mod sync;
+pub mod session;
+pub mod emotes;
 
 #[cfg(target_arch = "wasm32")]
 pub mod js_bindings;
 
 #[cfg(target_arch = "wasm32")]
-pub mod sync;
+pub mod firebase;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod websocket;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod relay_server;
 
 use bevy::prelude::*;
-use state::{NetworkState, NetworkMode, RoomPlayers, PendingNetworkActions, ReceivedGameState};
+use state::{NetworkState, NetworkMode, RoomPlayers, PendingNetworkActions, OutgoingNetworkActions, ReceivedGameState};
 
 /// Plugin that handles all multiplayer networking functionality
 pub struct NetworkPlugin;
@@ -19,17 +31,43 @@ impl Plugin for NetworkPlugin {
         app.init_resource::<NetworkState>()
             .init_resource::<RoomPlayers>()
             .init_resource::<PendingNetworkActions>()
-            .init_resource::<ReceivedGameState>();
-
-        // Add WASM-specific systems
-        #[cfg(target_arch = "wasm32")]
-        {
-            app.add_systems(Update, (
-                sync::poll_firebase_updates,
-                sync::process_received_game_state,
-                sync::broadcast_game_state_system,
-            ).run_if(resource_exists::<crate::components::Players>));
-        }
+            .init_resource::<OutgoingNetworkActions>()
+            .init_resource::<ReceivedGameState>()
+            .init_resource::<fairness::LegFairness>()
+            .init_resource::<emotes::Emotes>()
+            .init_resource::<error::NetworkResults>()
+            .init_resource::<backend::Backend>();
+
+        // Apply results reported by async network callbacks
+        app.add_systems(Update, error::collect_network_results);
+
+        // Commit-reveal of each leg's dice seed (host commits, everyone verifies)
+        app.add_systems(Update, (
+            fairness::commit_leg_seed,
+            fairness::verify_leg_rolls,
+        ).run_if(resource_exists::<crate::components::TurnState>)
+         .run_if(resource_exists::<crate::components::Pyramid>));
+
+        // Exchange actions (and the odd full game state) with the other players, ahead of
+        // the table resolving them
+        app.add_systems(Update, (
+            sync::poll_network_updates,
+            sync::process_received_game_state,
+            sync::apply_network_actions,
+            sync::replay_host_actions,
+            sync::track_settled_checksum,
+            sync::run_turn_timer,
+            sync::send_outgoing_actions,
+            sync::flush_write_queue,
+            sync::take_over_disconnected_seats,
+            sync::broadcast_game_state_system,
+        ).in_set(crate::game::state::GameSet::Input)
+         .run_if(resource_exists::<crate::components::Players>));
+
+        // Quick reactions, outside the host's action log
+        app.add_systems(Update, emotes::exchange_emotes.run_if(resource_exists::<crate::components::Players>));
+
+        app.add_systems(OnEnter(crate::game::state::GameState::GameEnd), session::forget_finished_game);
     }
 }
 
@@ -44,6 +82,7 @@ pub fn is_online(network_state: &NetworkState) -> bool {
 pub fn can_local_player_act(network_state: &NetworkState, current_player_index: usize, players: &crate::components::Players) -> bool {
     match network_state.mode {
         NetworkMode::Local => true,
+        NetworkMode::Replay => false,
         NetworkMode::OnlineHost | NetworkMode::OnlineClient => {
             // Check if the current player is the local player
             if let Some(local_player_index) = network_state.local_player_index {
@@ -0,0 +1,529 @@
+//! WebSocket relay server for desktop multiplayer
+//!
+//! `camel-up --relay [ADDRESS]` runs this instead of the game. It keeps rooms in
+//! memory and passes messages between the players in them, playing the part the
+//! Firebase database plays for the browser build. It never looks inside a game: the
+//! host's game is the authority, the server only checks who may do what.
+//!
+//! The rooms live in a `LocalRelay`, which games in the same process can also play
+//! through without a socket (see `WebSocketBackend::loopback`).
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use bevy::log::{error, info, warn};
+use futures_util::{SinkExt, StreamExt};
+use rand::Rng;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio_tungstenite::tungstenite::Message;
+
+use super::messages::{
+    EmoteMessage, RelayEvent, RelayOperation, RelayRequest, SerializableGameSetup, SerializableGameState,
+    SerializableRoomSettings,
+};
+use super::state::OnlinePlayerInfo;
+
+/// Address the relay listens on when none is given
+pub const DEFAULT_RELAY_ADDRESS: &str = "0.0.0.0:7878";
+
+/// Players a room takes unless the host sets fewer, like the browser build
+const MAX_PLAYERS: usize = 8;
+
+/// A room and the connections of everyone in it
+struct Room {
+    host_id: String,
+    /// In the order they joined
+    players: Vec<OnlinePlayerInfo>,
+    game_started: bool,
+    randomize_order: bool,
+    /// No new players while set
+    locked: bool,
+    /// Protocol version of the host's build, which players joining must match
+    protocol: u32,
+    settings: Option<SerializableRoomSettings>,
+    setup: Option<SerializableGameSetup>,
+    /// The host's latest game state, for players taking their seat back
+    game_state: Option<Box<SerializableGameState>>,
+    /// Each seat's rejoin token, which a new connection needs to take the seat back
+    rejoin_tokens: HashMap<String, String>,
+}
+
+#[derive(Default)]
+struct Relay {
+    rooms: HashMap<String, Room>,
+    connections: HashMap<String, UnboundedSender<RelayEvent>>,
+    /// Room each connection is in
+    room_of: HashMap<String, String>,
+}
+
+impl Relay {
+    fn send_to(&self, player_id: &str, event: RelayEvent) {
+        if let Some(tx) = self.connections.get(player_id) {
+            let _ = tx.send(event);
+        }
+    }
+
+    /// Send an event to everyone connected to a room
+    fn broadcast(&self, room: &Room, event: &RelayEvent) {
+        for player in &room.players {
+            self.send_to(&player.id, event.clone());
+        }
+    }
+
+    fn broadcast_players(&self, room: &Room) {
+        self.broadcast(room, &RelayEvent::Players { players: room.players.clone() });
+    }
+
+    fn broadcast_settings(&self, room: &Room) {
+        self.broadcast(room, &settings(room));
+    }
+
+    /// Send an event to everyone in a room but the host
+    fn broadcast_to_guests(&self, room: &Room, event: &RelayEvent) {
+        for player in room.players.iter().filter(|p| p.id != room.host_id) {
+            self.send_to(&player.id, event.clone());
+        }
+    }
+
+    /// Handle one request, returning the error for the requester if it failed
+    fn handle(&mut self, player_id: &str, request: RelayRequest) -> Result<(), String> {
+        match request {
+            RelayRequest::CreateRoom { room_code, name, character_id, color_index, protocol } => {
+                if self.rooms.contains_key(&room_code) {
+                    return Err("Room code already in use".to_string());
+                }
+                self.leave(player_id);
+                let mut room = Room {
+                    host_id: player_id.to_string(),
+                    players: vec![OnlinePlayerInfo {
+                        id: player_id.to_string(),
+                        name,
+                        character_id,
+                        color_index,
+                        custom_color: None,
+                        is_ready: true,
+                        is_connected: true,
+                        is_host: true,
+                        last_seen: None,
+                    }],
+                    game_started: false,
+                    randomize_order: false,
+                    locked: false,
+                    protocol,
+                    settings: None,
+                    setup: None,
+                    game_state: None,
+                    rejoin_tokens: HashMap::new(),
+                };
+                info!("Room {} created by {}", room_code, player_id);
+                self.send_to(player_id, RelayEvent::RejoinToken { token: seat_token(&mut room, player_id) });
+                self.broadcast_settings(&room);
+                self.broadcast_players(&room);
+                self.rooms.insert(room_code.clone(), room);
+                self.room_of.insert(player_id.to_string(), room_code);
+            }
+            RelayRequest::JoinRoom { room_code, name, character_id, color_index, protocol } => {
+                let Some(room) = self.rooms.get(&room_code) else {
+                    return Err("Room not found".to_string());
+                };
+                if room.protocol != protocol {
+                    return Err(format!("Version mismatch: the room plays version {}, you have {}", room.protocol, protocol));
+                }
+                if room.game_started {
+                    return Err("Game already started".to_string());
+                }
+                if room.locked {
+                    return Err("Room is locked".to_string());
+                }
+                let max_players = room.settings.as_ref().map_or(MAX_PLAYERS, |s| usize::from(s.max_players));
+                if room.players.len() >= max_players.min(MAX_PLAYERS) {
+                    return Err("Room is full".to_string());
+                }
+                self.leave(player_id);
+                let Some(room) = self.rooms.get_mut(&room_code) else {
+                    return Err("Room not found".to_string());
+                };
+                room.players.push(OnlinePlayerInfo {
+                    id: player_id.to_string(),
+                    name,
+                    character_id,
+                    color_index,
+                    custom_color: None,
+                    is_ready: false,
+                    is_connected: true,
+                    is_host: false,
+                    last_seen: None,
+                });
+                let token = seat_token(room, player_id);
+                info!("{} joined room {}", player_id, room_code);
+                self.room_of.insert(player_id.to_string(), room_code.clone());
+                let room = &self.rooms[&room_code];
+                self.send_to(player_id, RelayEvent::RejoinToken { token });
+                self.send_to(player_id, settings(room));
+                self.broadcast_players(room);
+            }
+            RelayRequest::RejoinRoom { room_code, player_id: seat_id, rejoin_token, protocol } => {
+                let Some(room) = self.rooms.get(&room_code) else {
+                    return Err("Room not found".to_string());
+                };
+                if room.protocol != protocol {
+                    return Err(format!("Version mismatch: the room plays version {}, you have {}", room.protocol, protocol));
+                }
+                // Only a seat whose connection dropped can be taken back
+                if !room.players.iter().any(|p| p.id == seat_id && !p.is_connected) {
+                    return Err("Permission denied: that seat isn't free".to_string());
+                }
+                // ...and only by whoever the seat's token was given to
+                if room.rejoin_tokens.get(&seat_id) != Some(&rejoin_token) {
+                    return Err("Permission denied: that isn't your seat".to_string());
+                }
+                self.leave(player_id);
+                if let Some(tx) = self.connections.remove(player_id) {
+                    self.connections.insert(seat_id.clone(), tx);
+                }
+                let Some(room) = self.rooms.get_mut(&room_code) else {
+                    return Err("Room not found".to_string());
+                };
+                if let Some(seat) = room.players.iter_mut().find(|p| p.id == seat_id) {
+                    seat.is_connected = true;
+                }
+                info!("{} rejoined room {}", seat_id, room_code);
+                let room = &self.rooms[&room_code];
+                self.send_to(&seat_id, RelayEvent::Welcome { player_id: seat_id.clone() });
+                self.send_to(&seat_id, RelayEvent::RejoinToken { token: rejoin_token });
+                self.send_to(&seat_id, settings(room));
+                if let Some(ref state) = room.game_state {
+                    self.send_to(&seat_id, RelayEvent::GameState { state: state.clone() });
+                }
+                self.broadcast_players(room);
+            }
+            RelayRequest::LeaveRoom => self.leave(player_id),
+            RelayRequest::UpdateAppearance { character_id, color_index, custom_color, name } => {
+                let room = self.room_mut(player_id)?;
+                if let Some(player) = room.players.iter_mut().find(|p| p.id == player_id) {
+                    player.character_id = character_id;
+                    player.color_index = color_index;
+                    player.custom_color = custom_color;
+                    if let Some(name) = name {
+                        player.name = name;
+                    }
+                }
+                let room = self.room(player_id)?;
+                self.broadcast_players(room);
+            }
+            RelayRequest::SetReady { is_ready } => {
+                let room = self.room_mut(player_id)?;
+                if let Some(player) = room.players.iter_mut().find(|p| p.id == player_id) {
+                    player.is_ready = is_ready;
+                }
+                let room = self.room(player_id)?;
+                self.broadcast_players(room);
+            }
+            RelayRequest::SetRandomizeOrder { randomize } => {
+                let room = self.hosted_room_mut(player_id)?;
+                room.randomize_order = randomize;
+                let room = self.room(player_id)?;
+                self.broadcast_settings(room);
+            }
+            RelayRequest::KickPlayer { player_id: kicked_id } => {
+                let room = self.hosted_room(player_id)?;
+                if kicked_id == room.host_id || !room.players.iter().any(|p| p.id == kicked_id) {
+                    return Err("No such player to remove".to_string());
+                }
+                if room.game_started {
+                    return Err("Game already started".to_string());
+                }
+                info!("{} removed from room {}", kicked_id, self.room_of[player_id]);
+                self.send_to(&kicked_id, RelayEvent::Kicked);
+                self.leave(&kicked_id);
+            }
+            RelayRequest::SetLocked { locked } => {
+                let room = self.hosted_room_mut(player_id)?;
+                room.locked = locked;
+                let room = self.room(player_id)?;
+                self.broadcast_settings(room);
+            }
+            RelayRequest::SetRoomSettings { settings } => {
+                let room = self.hosted_room_mut(player_id)?;
+                room.settings = Some(settings);
+                let room = self.room(player_id)?;
+                self.broadcast_settings(room);
+            }
+            RelayRequest::StartGame { setup } => {
+                let room = self.hosted_room_mut(player_id)?;
+                room.game_started = true;
+                room.setup = Some(setup);
+                let room = self.room(player_id)?;
+                info!("Game started in room {}", self.room_of[player_id]);
+                self.broadcast_settings(room);
+            }
+            RelayRequest::ResetRoom => {
+                let room = self.hosted_room_mut(player_id)?;
+                room.game_started = false;
+                room.setup = None;
+                room.game_state = None;
+                // Players who dropped out of the game don't come back to the waiting room
+                let gone: Vec<String> =
+                    room.players.iter().filter(|p| !p.is_connected).map(|p| p.id.clone()).collect();
+                room.players.retain(|p| p.is_connected);
+                room.rejoin_tokens.retain(|id, _| !gone.contains(id));
+                for player in room.players.iter_mut().filter(|p| !p.is_host) {
+                    player.is_ready = false;
+                }
+                for id in &gone {
+                    self.room_of.remove(id);
+                }
+                let room = self.room(player_id)?;
+                info!("Room {} reset for a rematch", self.room_of[player_id]);
+                self.broadcast_settings(room);
+                self.broadcast_players(room);
+            }
+            RelayRequest::WriteGameState { state } => {
+                let room = self.hosted_room_mut(player_id)?;
+                room.game_state = Some(state.clone());
+                let room = self.room(player_id)?;
+                self.broadcast_to_guests(room, &RelayEvent::GameState { state });
+            }
+            RelayRequest::BroadcastAction { action } => {
+                let room = self.hosted_room(player_id)?;
+                self.broadcast_to_guests(room, &RelayEvent::HostAction { action });
+            }
+            RelayRequest::SubmitAction { mut action } => {
+                let room = self.room(player_id)?;
+                // Actions always come from whoever sent them
+                action.player_id = player_id.to_string();
+                self.send_to(&room.host_id, RelayEvent::Action { action });
+            }
+            RelayRequest::SendEmote { emote } => {
+                let room = self.room(player_id)?;
+                let event = RelayEvent::Emote { emote: EmoteMessage { player_id: player_id.to_string(), emote } };
+                for player in room.players.iter().filter(|p| p.id != player_id) {
+                    self.send_to(&player.id, event.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn room(&self, player_id: &str) -> Result<&Room, String> {
+        self.room_of
+            .get(player_id)
+            .and_then(|code| self.rooms.get(code))
+            .ok_or_else(|| "Room not found".to_string())
+    }
+
+    fn room_mut(&mut self, player_id: &str) -> Result<&mut Room, String> {
+        self.room_of
+            .get(player_id)
+            .and_then(|code| self.rooms.get_mut(code))
+            .ok_or_else(|| "Room not found".to_string())
+    }
+
+    /// The player's room, if they are its host
+    fn hosted_room(&self, player_id: &str) -> Result<&Room, String> {
+        let room = self.room(player_id)?;
+        if room.host_id != player_id {
+            return Err("Permission denied: only the host can do that".to_string());
+        }
+        Ok(room)
+    }
+
+    fn hosted_room_mut(&mut self, player_id: &str) -> Result<&mut Room, String> {
+        let room = self.room_mut(player_id)?;
+        if room.host_id != player_id {
+            return Err("Permission denied: only the host can do that".to_string());
+        }
+        Ok(room)
+    }
+
+    /// Take a player out of their room. Rooms close once nobody is left, or when the
+    /// host leaves, since the host's game is the one everyone plays.
+    fn leave(&mut self, player_id: &str) {
+        let Some(code) = self.room_of.remove(player_id) else { return };
+        let Some(room) = self.rooms.get_mut(&code) else { return };
+        room.players.retain(|p| p.id != player_id);
+        room.rejoin_tokens.remove(player_id);
+        if room.players.is_empty() || room.host_id == player_id {
+            self.close_room(&code);
+        } else {
+            let room = &self.rooms[&code];
+            self.broadcast_players(room);
+        }
+    }
+
+    /// A connection dropped: keep the seat but flag it, so the host can hand it to the AI
+    fn disconnect(&mut self, player_id: &str) {
+        self.connections.remove(player_id);
+        let Some(code) = self.room_of.get(player_id).cloned() else { return };
+        let Some(room) = self.rooms.get_mut(&code) else { return };
+        if !room.game_started || room.host_id == player_id {
+            self.leave(player_id);
+            return;
+        }
+        if let Some(player) = room.players.iter_mut().find(|p| p.id == player_id) {
+            player.is_connected = false;
+        }
+        if room.players.iter().all(|p| !p.is_connected) {
+            self.close_room(&code);
+        } else {
+            let room = &self.rooms[&code];
+            self.broadcast_players(room);
+        }
+    }
+
+    /// Remove a room, telling anyone still in it
+    fn close_room(&mut self, code: &str) {
+        let Some(room) = self.rooms.remove(code) else { return };
+        info!("Room {} closed", code);
+        for player in &room.players {
+            self.send_to(&player.id, RelayEvent::RoomClosed);
+        }
+        self.room_of.retain(|_, room_code| room_code != code);
+    }
+}
+
+/// The relay's rooms, shared by every connection to it
+#[derive(Clone, Default)]
+pub struct LocalRelay(Arc<Mutex<Relay>>);
+
+impl LocalRelay {
+    /// Add a connection, welcomed under a new player id. Returns the id and the
+    /// events the relay sends it.
+    pub(crate) fn connect(&self) -> (String, UnboundedReceiver<RelayEvent>) {
+        let player_id = new_player_id();
+        let (tx, rx) = mpsc::unbounded_channel();
+        let _ = tx.send(RelayEvent::Welcome { player_id: player_id.clone() });
+        if let Ok(mut relay) = self.0.lock() {
+            relay.connections.insert(player_id.clone(), tx);
+        }
+        (player_id, rx)
+    }
+
+    /// Handle a request from the connection known as `player_id`, answering with
+    /// `Done` for the operations that report back. Taking a seat back in a game gives
+    /// the connection the seat's id.
+    pub(crate) fn request(&self, player_id: &mut String, request: RelayRequest) {
+        let operation = match request {
+            RelayRequest::CreateRoom { .. } => Some(RelayOperation::CreateRoom),
+            RelayRequest::JoinRoom { .. } | RelayRequest::RejoinRoom { .. } => Some(RelayOperation::JoinRoom),
+            RelayRequest::StartGame { .. } => Some(RelayOperation::StartGame),
+            RelayRequest::WriteGameState { .. } | RelayRequest::BroadcastAction { .. } => {
+                Some(RelayOperation::SyncState)
+            }
+            RelayRequest::SubmitAction { .. } => Some(RelayOperation::SubmitAction),
+            _ => None,
+        };
+        let seat_id = match request {
+            RelayRequest::RejoinRoom { ref player_id, .. } => Some(player_id.clone()),
+            _ => None,
+        };
+        let Ok(mut relay) = self.0.lock() else { return };
+        let result = relay.handle(player_id, request);
+        if let (Some(seat_id), Ok(())) = (seat_id, &result) {
+            *player_id = seat_id;
+        }
+        if let Some(operation) = operation {
+            relay.send_to(player_id, RelayEvent::Done { operation, error: result.err() });
+        }
+    }
+
+    /// The connection known as `player_id` is gone
+    pub(crate) fn disconnect(&self, player_id: &str) {
+        if let Ok(mut relay) = self.0.lock() {
+            relay.disconnect(player_id);
+        }
+    }
+}
+
+/// A room's settings, as sent to its players
+fn settings(room: &Room) -> RelayEvent {
+    RelayEvent::Settings {
+        game_started: room.game_started,
+        randomize_order: room.randomize_order,
+        locked: room.locked,
+        room_settings: room.settings.clone(),
+        setup: room.setup.clone(),
+    }
+}
+
+/// Give a player's seat in `room` a new rejoin token, returning it
+fn seat_token(room: &mut Room, player_id: &str) -> String {
+    let token = random_hex(32);
+    room.rejoin_tokens.insert(player_id.to_string(), token.clone());
+    token
+}
+
+/// Run the relay server until the process is stopped
+pub fn run_relay_server(address: &str) {
+    // No Bevy app runs here to set up logging
+    let _ = bevy::log::tracing_subscriber::fmt().try_init();
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            error!("Could not start the relay server: {}", e);
+            return;
+        }
+    };
+    runtime.block_on(async {
+        let listener = match TcpListener::bind(address).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!("Could not listen on {}: {}", address, e);
+                return;
+            }
+        };
+        info!("Camel Up relay server listening on ws://{}", address);
+
+        let relay = LocalRelay::default();
+        while let Ok((stream, peer)) = listener.accept().await {
+            let relay = relay.clone();
+            tokio::spawn(async move {
+                if let Err(e) = serve_connection(stream, relay).await {
+                    warn!("Connection from {} ended: {}", peer, e);
+                }
+            });
+        }
+    });
+}
+
+/// Run one player's connection: greet them, then handle their requests until they go
+async fn serve_connection(stream: TcpStream, relay: LocalRelay) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+    let socket = tokio_tungstenite::accept_async(stream).await?;
+    let (mut writer, mut reader) = socket.split();
+    let (mut player_id, mut rx) = relay.connect();
+
+    let writer_task = tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            let Ok(json) = serde_json::to_string(&event) else { continue };
+            if writer.send(Message::text(json)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    // Until the player leaves, closes the game or the connection drops
+    while let Some(Ok(message)) = reader.next().await {
+        let Message::Text(text) = message else { continue };
+        match serde_json::from_str::<RelayRequest>(&text) {
+            Ok(request) => relay.request(&mut player_id, request),
+            Err(e) => warn!("Ignoring unreadable request from {}: {}", player_id, e),
+        }
+    }
+
+    relay.disconnect(&player_id);
+    writer_task.abort();
+    Ok(())
+}
+
+/// Random id for a new connection
+fn new_player_id() -> String {
+    random_hex(16)
+}
+
+/// `len` random hex digits
+fn random_hex(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| format!("{:x}", rng.gen_range(0..16u8))).collect()
+}
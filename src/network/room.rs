@@ -1,5 +1,10 @@
 use rand::Rng;
 
+use super::backend::NetworkBackend;
+use super::error::NetworkResults;
+use super::session;
+use super::state::{NetworkMode, NetworkState};
+
 /// Generate a random 4-character room code
 pub fn generate_room_code() -> String {
     let mut rng = rand::thread_rng();
@@ -14,3 +19,87 @@ pub fn generate_room_code() -> String {
 pub fn is_valid_room_code(code: &str) -> bool {
     code.len() == 4 && code.chars().all(|c| c.is_ascii_alphanumeric())
 }
+
+/// Create a room and enter it as its host. Failure arrives later through `results`.
+pub fn host_room(
+    backend: &dyn NetworkBackend,
+    network_state: &mut NetworkState,
+    room_code: String,
+    name: &str,
+    character_id: u8,
+    color_index: usize,
+    results: &NetworkResults,
+) {
+    backend.create_room(&room_code, name, character_id, color_index, results.clone());
+    network_state.mode = NetworkMode::OnlineHost;
+    network_state.room_code = Some(room_code);
+    network_state.is_connected = true;
+}
+
+/// Join someone else's room. Failure arrives later through `results`.
+pub fn join_room(
+    backend: &dyn NetworkBackend,
+    network_state: &mut NetworkState,
+    room_code: String,
+    name: &str,
+    character_id: u8,
+    color_index: usize,
+    results: &NetworkResults,
+) {
+    backend.join_room(&room_code, name, character_id, color_index, results.clone());
+    network_state.mode = NetworkMode::OnlineClient;
+    network_state.room_code = Some(room_code);
+    network_state.is_connected = true;
+}
+
+/// Take our seat (`player_id`'s, proven with its `rejoin_token`) again in a game under
+/// way, and listen to it. Failure arrives later through `results`.
+pub fn rejoin_room(
+    backend: &dyn NetworkBackend,
+    network_state: &mut NetworkState,
+    room_code: String,
+    player_id: &str,
+    rejoin_token: Option<&str>,
+    results: &NetworkResults,
+) {
+    backend.rejoin_room(&room_code, player_id, rejoin_token, results.clone());
+    backend.subscribe_to_room(&room_code);
+    backend.subscribe_to_game_state(&room_code);
+    network_state.mode = NetworkMode::OnlineClient;
+    network_state.room_code = Some(room_code);
+    network_state.is_connected = true;
+}
+
+/// Leave the current room and stop listening to it
+pub fn leave_room(backend: &dyn NetworkBackend, network_state: &mut NetworkState) {
+    if let Some(ref room_code) = network_state.room_code {
+        backend.leave_room(room_code);
+    }
+    backend.unsubscribe_all();
+    session::clear_session();
+    network_state.reset();
+}
+
+/// Back to the room's waiting room after a finished game, for a rematch: stop
+/// listening to the old game, but stay in the room as who we were
+pub fn return_to_room(backend: &dyn NetworkBackend, network_state: &mut NetworkState) {
+    backend.unsubscribe_from_game();
+    let mode = network_state.mode;
+    let room_code = network_state.room_code.take();
+    let local_player_id = network_state.local_player_id.take();
+    network_state.reset();
+    network_state.mode = mode;
+    network_state.room_code = room_code;
+    network_state.local_player_id = local_player_id;
+    network_state.is_connected = true;
+}
+
+/// Stop listening to a room we never made it into, keeping who we are and why it failed
+pub fn abandon_room(backend: &dyn NetworkBackend, network_state: &mut NetworkState) {
+    backend.unsubscribe_all();
+    let failure = network_state.connection_error.take();
+    let local_player_id = network_state.local_player_id.take();
+    network_state.reset();
+    network_state.local_player_id = local_player_id;
+    network_state.connection_error = failure;
+}
@@ -0,0 +1,66 @@
+//! Remembering the online game we're playing, so a reloaded page (or a restarted
+//! desktop game) can rejoin it
+//!
+//! Only guests remember their game: the host's game is the authority and lives in
+//! its page, so there is nothing for a reloaded host to go back to.
+
+use serde::{Deserialize, Serialize};
+
+/// Name the session is kept under in `storage` on desktop
+#[cfg(not(target_arch = "wasm32"))]
+const SESSION_NAME: &str = "session";
+
+/// Room and player to rejoin after the page reloads
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SavedSession {
+    pub room_code: String,
+    /// Player id the seat was taken under
+    pub player_id: String,
+    /// Secret the service gave the seat, for services that ask for one to take it back
+    #[serde(default)]
+    pub rejoin_token: Option<String>,
+}
+
+/// Remember the game we're playing in (browser builds keep it in localStorage)
+#[cfg(target_arch = "wasm32")]
+pub fn save_session(session: &SavedSession) {
+    if let Ok(json) = serde_json::to_string(session) {
+        super::js_bindings::save_session(&json);
+    }
+}
+
+/// The game we were playing when the page closed, if any
+#[cfg(target_arch = "wasm32")]
+pub fn saved_session() -> Option<SavedSession> {
+    super::js_bindings::load_session().and_then(|json| serde_json::from_str(&json).ok())
+}
+
+/// Forget the game: it's over, or we left it
+#[cfg(target_arch = "wasm32")]
+pub fn clear_session() {
+    super::js_bindings::clear_session();
+}
+
+/// Remember the game we're playing in (desktop builds keep it in a file, for a game
+/// restarted after a crash or a lost connection)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_session(session: &SavedSession) {
+    if let Ok(json) = serde_json::to_string(session) {
+        crate::game::storage::write(SESSION_NAME, &json);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn saved_session() -> Option<SavedSession> {
+    crate::game::storage::read(SESSION_NAME).and_then(|json| serde_json::from_str(&json).ok())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn clear_session() {
+    crate::game::storage::remove(SESSION_NAME);
+}
+
+/// System to forget a finished game, so the menu stops offering to rejoin it
+pub fn forget_finished_game() {
+    clear_session();
+}
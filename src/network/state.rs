@@ -9,6 +9,7 @@ pub enum NetworkMode {
     Local,          // Single device, no network
     OnlineHost,     // Hosting a multiplayer game
     OnlineClient,   // Joined someone else's game
+    Replay,         // Watching a recorded game play back
 }
 
 /// Global network state resource
@@ -20,8 +21,64 @@ pub struct NetworkState {
     pub local_player_id: Option<String>,   // Firebase auth UID
     pub local_player_index: Option<usize>, // Index in Players list
     pub is_connected: bool,
-    pub connection_error: Option<String>,
+    pub connection_error: Option<super::error::NetworkFailure>,
     pub game_state_version: u32,           // Tracks state sync version
+    /// Firebase auth UID of the player in each seat, in turn order
+    pub seat_player_ids: Vec<String>,
+    /// Seed the host started the game with, shared by everyone in the room
+    pub game_seed: Option<u64>,
+    /// Actions in the host's log so far: logged, on the host; applied, on a client
+    pub actions_logged: u32,
+    /// Logged actions a client has received but not applied yet, in order
+    pub pending_replay: Vec<super::messages::SequencedAction>,
+    /// Host: checksum of the game the last time it waited for a turn action, with the
+    /// number of actions logged by then
+    pub settled_checksum: Option<(u32, String)>,
+    /// Host: a client asked for the full game state
+    pub state_requested: bool,
+    /// Client: the game here no longer matches the host's, and its state is on the way
+    pub resyncing: bool,
+    /// Host: seats the AI took over when their player dropped out, handed back if they return
+    pub seats_taken_over: Vec<usize>,
+    /// Seconds a player gets for their turn, 0 for no limit
+    pub turn_timer_secs: u16,
+    /// Host: seconds a seat's player may be gone before the AI takes over
+    pub takeover_grace_secs: u16,
+    /// Seats whose player has gone missing, with when they were first missed
+    /// (`Time::elapsed_secs_f64`)
+    pub absent_since: Vec<(usize, f64)>,
+    /// Time left for the turn being waited on, while the turn timer runs
+    pub turn_time_left: Option<f32>,
+    /// Actions (and the odd game state) on their way to the room
+    pub write_queue: super::sync::WriteQueue,
+    /// Sizes and times of the latest messages each way
+    pub traffic: NetworkTraffic,
+}
+
+/// The latest messages sent to and received from the room, for the network overlay.
+/// Times are `Time::elapsed_secs_f64`, sizes are bytes of JSON.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct NetworkTraffic {
+    pub last_sent_at: Option<f64>,
+    pub last_sent_bytes: usize,
+    pub total_sent_bytes: usize,
+    pub last_received_at: Option<f64>,
+    pub last_received_bytes: usize,
+    pub total_received_bytes: usize,
+}
+
+impl NetworkTraffic {
+    pub fn sent(&mut self, now: f64, bytes: usize) {
+        self.last_sent_at = Some(now);
+        self.last_sent_bytes = bytes;
+        self.total_sent_bytes += bytes;
+    }
+
+    pub fn received(&mut self, now: f64, bytes: usize) {
+        self.last_received_at = Some(now);
+        self.last_received_bytes = bytes;
+        self.total_received_bytes += bytes;
+    }
 }
 
 #[allow(dead_code)]
@@ -38,6 +95,30 @@ impl NetworkState {
         matches!(self.mode, NetworkMode::OnlineHost | NetworkMode::OnlineClient)
     }
 
+    pub fn is_local(&self) -> bool {
+        matches!(self.mode, NetworkMode::Local)
+    }
+
+    pub fn is_replay(&self) -> bool {
+        matches!(self.mode, NetworkMode::Replay)
+    }
+
+    /// Whether the game plays out from a log, the host's or a replay's, rather than
+    /// from choices made here
+    pub fn follows_log(&self) -> bool {
+        matches!(self.mode, NetworkMode::OnlineClient | NetworkMode::Replay)
+    }
+
+    /// Seat index of a room player, once the game has started
+    pub fn seat_of(&self, player_id: &str) -> Option<usize> {
+        self.seat_player_ids.iter().position(|id| id == player_id)
+    }
+
+    /// Whether a seat's player has gone missing from the room
+    pub fn is_absent(&self, seat: usize) -> bool {
+        self.absent_since.iter().any(|&(absent, _)| absent == seat)
+    }
+
     pub fn reset(&mut self) {
         *self = Self::default();
     }
@@ -50,9 +131,30 @@ pub struct OnlinePlayerInfo {
     pub name: String,
     pub character_id: u8,
     pub color_index: usize,
+    /// Color picked with the color picker, used instead of the `color_index` slot
+    #[serde(default)]
+    pub custom_color: Option<[u8; 3]>,
     pub is_ready: bool,
     pub is_connected: bool,
     pub is_host: bool,
+    /// Last heartbeat, in the backend's server time (ms), for backends that send them
+    #[serde(default)]
+    pub last_seen: Option<f64>,
+}
+
+/// Heartbeats older than this mean the player's game has stopped (a frozen or
+/// backgrounded tab that never closed)
+pub const HEARTBEAT_STALE_MS: f64 = 15_000.0;
+
+impl OnlinePlayerInfo {
+    /// Whether the player is still with us: connected, and (where heartbeats are sent)
+    /// heard from recently
+    pub fn is_present(&self, server_time_ms: Option<f64>) -> bool {
+        match (server_time_ms, self.last_seen) {
+            (Some(now), Some(last_seen)) => self.is_connected && now - last_seen < HEARTBEAT_STALE_MS,
+            _ => self.is_connected,
+        }
+    }
 }
 
 /// Tracks all players in the current room
@@ -68,11 +170,20 @@ pub struct PendingNetworkActions {
     pub actions: Vec<super::messages::NetworkActionMessage>,
 }
 
+/// Actions to send: clients submit their own to the host, the host logs the ones it applied
+#[derive(Resource, Default)]
+pub struct OutgoingNetworkActions {
+    pub actions: Vec<crate::game::actions::GameAction>,
+    /// Every action applied in a local or hosted game, picked up for its replay
+    pub recorded: Vec<crate::game::actions::GameAction>,
+}
+
 /// Latest game state received from network (used by clients)
 #[derive(Resource, Default)]
 #[allow(dead_code)]
 pub struct ReceivedGameState {
     pub state_json: Option<String>,
-    pub version: u32,
     pub needs_processing: bool,
+    /// The state is a saved local game being continued, not one from the host
+    pub restoring: bool,
 }
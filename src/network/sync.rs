@@ -1,29 +1,205 @@
-//! State synchronization between game instances via Firebase
+//! Keeping online games in step over the network backend
+//!
+//! Players don't exchange game state: clients send their actions to the host, the
+//! host applies them and logs every action it applies (with the die it rolled), and
+//! clients replay that log. Since everyone sets up from the same seed, replaying the
+//! same actions plays out the same game. The host also publishes the full state at
+//! the start of each leg, for clients that missed part of the log.
+//!
+//! Each logged turn action carries a checksum of the host's game just before it.
+//! A client whose own checksum differs stops replaying, asks the host for its full
+//! state and picks up from there.
+//!
+//! Writes to the room go through a queue, one at a time and in order. A write the
+//! backend fails is retried with backoff, so moves made while the connection is down
+//! arrive late instead of never.
 
+use std::collections::VecDeque;
+
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use crate::components::dice::{DieRollResult, PyramidDie};
 use crate::components::{
-    BoardPosition, Camel, CamelColor, CrazyCamel, CrazyCamelColor, Players,
-    LegBettingTiles, RaceBets, PlacedSpectatorTiles, Pyramid, GameBoard,
+    BoardPosition, Camel, CamelColor, CamelNames, CrazyCamel, CrazyCamelColor, Players,
+    LegBettingTiles, RaceBets, PlacedSpectatorTiles, Pyramid, TurnState, PlayerLegBetsStore, PlayerPyramidTokens,
+};
+use crate::game::actions::GameAction;
+use crate::game::log::GameLog;
+use crate::game::rng::StableHasher;
+use crate::systems::turn::{
+    PlayerActions, SeatActions, RollPyramidAction, TakeLegBetAction, PlaceSpectatorTileAction, PlaceRaceBetAction,
+    ConcedeAction, ConvertToAiAction, ReturnSeatAction, TurnTimedOutAction,
 };
-use crate::systems::turn::{TurnState, PlayerLegBetsStore, PlayerPyramidTokens};
-use super::state::{NetworkState, ReceivedGameState, PendingNetworkActions};
+use crate::ui::hud::UiState;
+use super::error::{NetworkError, NetworkFailure, NetworkOperation, NetworkResult, NetworkResults};
+use super::fairness::{self, LegFairness};
+use super::state::{
+    NetworkState, OnlinePlayerInfo, ReceivedGameState, PendingNetworkActions, OutgoingNetworkActions, RoomPlayers,
+};
+use super::backend::Backend;
 use super::messages::*;
-use super::js_bindings;
 
-/// System to poll Firebase for updates
-pub fn poll_firebase_updates(
-    network_state: Res<NetworkState>,
+/// Seconds a write may wait for the backend before players are told the connection
+/// looks lost (the browser's Firebase client holds writes while offline)
+const WRITE_STALLED_SECS: f64 = 5.0;
+
+/// A write to the room, waiting its turn
+#[derive(Clone, Debug)]
+enum QueuedWrite {
+    /// Host: an entry of the action log
+    LogAction(String),
+    /// Host: the full game state
+    GameState(String),
+    /// Client: an action for the host
+    SubmitAction(String),
+}
+
+/// Writes to the room, sent one at a time so the host's log stays in order, and
+/// retried until the backend takes them
+#[derive(Default)]
+pub struct WriteQueue {
+    writes: VecDeque<QueuedWrite>,
+    /// Outcome of the write in flight, kept apart from other operations' results
+    results: NetworkResults,
+    /// When the write at the front was sent, while waiting to hear how it went
+    /// (`Time::elapsed_secs_f64`)
+    in_flight_since: Option<f64>,
+    /// Failed attempts at the write at the front
+    attempts: u32,
+    /// When to try the write at the front again, after a failure
+    retry_at: f64,
+}
+
+impl WriteQueue {
+    /// Writes not yet taken by the backend
+    pub fn len(&self) -> usize {
+        self.writes.len()
+    }
+
+    /// Whether writes are held up: the last attempt failed, or the backend has been
+    /// sitting on one for a while
+    pub fn is_stalled(&self, now: f64) -> bool {
+        self.attempts > 0 || self.in_flight_since.is_some_and(|sent| now - sent > WRITE_STALLED_SECS)
+    }
+}
+
+/// Seconds to wait before another attempt at a failed write: doubling from half a
+/// second, up to 8
+fn retry_delay(attempts: u32) -> f64 {
+    (0.5 * 2f64.powi(attempts.saturating_sub(1).min(4) as i32)).min(8.0)
+}
+
+/// System to send queued writes to the room, one at a time, retrying failed ones with
+/// backoff. Only failures retrying can't fix reach `connection_error`.
+pub fn flush_write_queue(
+    backend: Res<Backend>,
+    mut network_state: ResMut<NetworkState>,
+    time: Res<Time>,
+) {
+    if !network_state.is_online() {
+        return;
+    }
+    let Some(room_code) = network_state.room_code.clone() else {
+        return;
+    };
+    let now = time.elapsed_secs_f64();
+
+    let mut failure = None;
+    let queue = &mut network_state.write_queue;
+    for NetworkResult { operation, result } in queue.results.drain() {
+        if queue.in_flight_since.take().is_none() {
+            // Not about a write (the connection dropping while nothing was in flight)
+            if let Err(error) = result {
+                failure = Some(NetworkFailure { operation, error });
+            }
+            continue;
+        }
+        match result {
+            Ok(()) => {
+                queue.writes.pop_front();
+                queue.attempts = 0;
+            }
+            Err(error @ (NetworkError::PermissionDenied | NetworkError::Unsupported)) => {
+                warn!("Giving up on a {:?} write: {:?}", operation, error);
+                queue.writes.pop_front();
+                queue.attempts = 0;
+                failure = Some(NetworkFailure { operation, error });
+            }
+            Err(error) => {
+                queue.attempts += 1;
+                let delay = retry_delay(queue.attempts);
+                queue.retry_at = now + delay;
+                warn!("{:?} write failed ({:?}), retrying in {:.1}s", operation, error, delay);
+            }
+        }
+    }
+    if failure.is_some() {
+        network_state.connection_error = failure;
+    }
+
+    let queue = &mut network_state.write_queue;
+    if queue.in_flight_since.is_some() || now < queue.retry_at {
+        return;
+    }
+    let Some(write) = queue.writes.front().cloned() else {
+        return;
+    };
+    queue.in_flight_since = Some(now);
+    let results = queue.results.clone();
+    let bytes = match &write {
+        QueuedWrite::LogAction(json) | QueuedWrite::GameState(json) | QueuedWrite::SubmitAction(json) => json.len(),
+    };
+    network_state.traffic.sent(now, bytes);
+    match write {
+        QueuedWrite::LogAction(json) => backend.broadcast_action(&room_code, json, results),
+        QueuedWrite::GameState(json) => backend.write_game_state(&room_code, json, results),
+        QueuedWrite::SubmitAction(json) => backend.submit_action(&room_code, json, results),
+    }
+}
+
+/// System to poll the backend for updates
+pub fn poll_network_updates(
+    backend: Res<Backend>,
+    mut network_state: ResMut<NetworkState>,
     mut received_state: ResMut<ReceivedGameState>,
     mut pending_actions: ResMut<PendingNetworkActions>,
+    time: Res<Time>,
 ) {
     if !network_state.is_online() {
         return;
     }
+    let now = time.elapsed_secs_f64();
 
-    // Poll for game state updates (clients receive these)
+    // Poll for the host's action log and game state (clients receive these)
     if network_state.is_client() {
-        if let Some(state_json) = js_bindings::poll_game_state() {
+        if let Some(log_json) = backend.poll_host_actions() {
+            network_state.traffic.received(now, log_json.len());
+            match serde_json::from_str::<Vec<SequencedAction>>(&log_json) {
+                Ok(actions) => {
+                    // Actions from a build on another version could play out differently here
+                    if actions.iter().any(|a| a.protocol != PROTOCOL_VERSION) {
+                        warn!("Ignoring host actions on another protocol version, we use {}", PROTOCOL_VERSION);
+                        network_state.connection_error = Some(NetworkFailure {
+                            operation: NetworkOperation::SyncState,
+                            error: NetworkError::VersionMismatch,
+                        });
+                    }
+                    let applied = network_state.actions_logged;
+                    let pending = &mut network_state.pending_replay;
+                    for action in actions.into_iter().filter(|a| a.protocol == PROTOCOL_VERSION) {
+                        if action.sequence > applied && !pending.iter().any(|a| a.sequence == action.sequence) {
+                            pending.push(action);
+                        }
+                    }
+                    pending.sort_by_key(|a| a.sequence);
+                }
+                Err(e) => warn!("Failed to parse host actions: {}", e),
+            }
+        }
+
+        if let Some(state_json) = backend.poll_game_state() {
             info!("Client received game state update ({} bytes)", state_json.len());
+            network_state.traffic.received(now, state_json.len());
             received_state.state_json = Some(state_json);
             received_state.needs_processing = true;
         }
@@ -31,11 +207,16 @@ pub fn poll_firebase_updates(
 
     // Poll for action updates (host receives these)
     if network_state.is_host() {
-        if let Some(actions_json) = js_bindings::poll_actions() {
+        if let Some(actions_json) = backend.poll_actions() {
+            network_state.traffic.received(now, actions_json.len());
             if let Ok(actions) = serde_json::from_str::<Vec<serde_json::Value>>(&actions_json) {
                 for action_value in actions {
-                    if let Ok(action) = serde_json::from_value::<NetworkActionMessage>(action_value) {
-                        pending_actions.actions.push(action);
+                    match serde_json::from_value::<NetworkActionMessage>(action_value) {
+                        Ok(action) if action.protocol != PROTOCOL_VERSION => {
+                            warn!("Ignoring action from {} on protocol version {}", action.player_id, action.protocol);
+                        }
+                        Ok(action) => pending_actions.actions.push(action),
+                        Err(_) => {}
                     }
                 }
             }
@@ -43,22 +224,41 @@ pub fn poll_firebase_updates(
     }
 }
 
-/// System to apply received game state (clients only)
+/// The parts of the table a received game state is put back onto. Any of them may be
+/// missing while the game is still being set up.
+#[derive(SystemParam)]
+pub struct ReceivedTable<'w> {
+    players: Option<ResMut<'w, Players>>,
+    turn_state: Option<ResMut<'w, TurnState>>,
+    pyramid: Option<ResMut<'w, Pyramid>>,
+    player_leg_bets: Option<ResMut<'w, PlayerLegBetsStore>>,
+    player_pyramid_tokens: Option<ResMut<'w, PlayerPyramidTokens>>,
+    leg_fairness: Option<ResMut<'w, LegFairness>>,
+    camel_names: Option<ResMut<'w, CamelNames>>,
+    game_log: Option<ResMut<'w, GameLog>>,
+    leg_betting_tiles: Option<ResMut<'w, LegBettingTiles>>,
+    race_bets: Option<ResMut<'w, RaceBets>>,
+    placed_tiles: Option<ResMut<'w, PlacedSpectatorTiles>>,
+}
+
+/// System to catch up from the host's published game state when part of its action
+/// log never arrived, or after falling out of step or rejoining (clients only). Also
+/// puts back a saved local game being continued.
 pub fn process_received_game_state(
-    network_state: Res<NetworkState>,
+    mut network_state: ResMut<NetworkState>,
     mut received_state: ResMut<ReceivedGameState>,
-    mut players: Option<ResMut<Players>>,
-    mut turn_state: Option<ResMut<TurnState>>,
-    mut pyramid: Option<ResMut<Pyramid>>,
-    mut leg_betting_tiles: Option<ResMut<LegBettingTiles>>,
-    mut race_bets: Option<ResMut<RaceBets>>,
-    mut placed_tiles: Option<ResMut<PlacedSpectatorTiles>>,
-    mut player_leg_bets: Option<ResMut<PlayerLegBetsStore>>,
-    mut player_pyramid_tokens: Option<ResMut<PlayerPyramidTokens>>,
+    table: ReceivedTable,
     mut camels: Query<(&Camel, &mut BoardPosition, &mut Transform)>,
     mut crazy_camels: Query<(&CrazyCamel, &mut BoardPosition, &mut Transform), Without<Camel>>,
+    mut ui_state: ResMut<UiState>,
 ) {
-    if !network_state.is_client() || !received_state.needs_processing {
+    let restoring = received_state.restoring;
+    if !(network_state.is_client() || restoring) || !received_state.needs_processing {
+        return;
+    }
+    // A rejoined or continued game sets up from scratch: let its setup rolls finish,
+    // or they'd move the camels again after we place them
+    if (network_state.resyncing || restoring) && !ui_state.camel_rolls_complete {
         return;
     }
 
@@ -76,17 +276,49 @@ pub fn process_received_game_state(
             return;
         }
     };
+    if state.protocol != PROTOCOL_VERSION {
+        warn!("Ignoring game state on protocol version {}, we use {}", state.protocol, PROTOCOL_VERSION);
+        received_state.needs_processing = false;
+        return;
+    }
 
-    // Only process if version is newer
-    if state.version <= received_state.version {
+    // Replaying the log gets us there by itself unless the next action we need is
+    // missing, or we fell out of step and asked for this state
+    let next = network_state.actions_logged + 1;
+    let needed = if network_state.resyncing || restoring {
+        state.version >= network_state.actions_logged
+    } else {
+        state.version > network_state.actions_logged
+            && network_state.pending_replay.first().is_none_or(|a| a.sequence != next)
+    };
+    if !needed {
         received_state.needs_processing = false;
         return;
     }
 
-    info!("Applying game state version {} with {} camels, {} crazy camels",
+    info!("Catching up to game state after action {} with {} camels, {} crazy camels",
         state.version, state.camels.len(), state.crazy_camels.len());
-    received_state.version = state.version;
+    network_state.actions_logged = state.version;
+    network_state.pending_replay.retain(|a| a.sequence > state.version);
+    network_state.resyncing = false;
     received_state.needs_processing = false;
+    received_state.restoring = false;
+    // The host only publishes once the race is under way
+    ui_state.initial_rolls_complete = true;
+
+    let ReceivedTable {
+        mut players,
+        mut turn_state,
+        mut pyramid,
+        mut player_leg_bets,
+        mut player_pyramid_tokens,
+        mut leg_fairness,
+        mut camel_names,
+        mut game_log,
+        mut leg_betting_tiles,
+        mut race_bets,
+        mut placed_tiles,
+    } = table;
 
     // Apply turn state
     if let Some(ref mut ts) = turn_state {
@@ -104,6 +336,8 @@ pub fn process_received_game_state(
             if let Some(player) = p.players.get_mut(i) {
                 player.money = sp.money;
                 player.has_spectator_tile = sp.has_spectator_tile;
+                player.is_ai = sp.is_ai;
+                player.conceded = sp.conceded;
                 player.available_race_cards = sp.available_race_cards
                     .iter()
                     .filter_map(|c| parse_camel_color(c))
@@ -153,26 +387,40 @@ pub fn process_received_game_state(
         }
     }
 
-    // Apply pyramid state
+    // Apply pyramid state: put every die back, then take out the rolled ones
     if let Some(ref mut pyr) = pyramid {
-        pyr.rolled_dice.clear();
+        pyr.reset();
         for die in &state.pyramid.rolled_dice {
-            if die.is_crazy {
-                if let Some(color) = parse_crazy_camel_color(&die.color) {
-                    pyr.rolled_dice.push(crate::components::dice::PyramidDie::Crazy {
-                        rolled: Some((color, die.value)),
-                    });
-                }
+            let result = if die.is_crazy {
+                parse_crazy_camel_color(&die.color).map(|color| DieRollResult::Crazy { color, value: die.value })
             } else {
-                if let Some(color) = parse_camel_color(&die.color) {
-                    let mut regular_die = crate::components::dice::RegularDie::new(color);
-                    regular_die.value = Some(die.value);
-                    pyr.rolled_dice.push(crate::components::dice::PyramidDie::Regular(regular_die));
-                }
+                parse_camel_color(&die.color).map(|color| DieRollResult::Regular { color, value: die.value })
+            };
+            if let Some(result) = result {
+                pyr.apply_roll(&result);
             }
         }
     }
 
+    // Track the host's seed commitment; the seed arrives once the leg is over
+    if let (Some(f), Some(ref mut leg_fairness)) = (&state.fairness, &mut leg_fairness) {
+        observe_fairness(leg_fairness, f);
+    }
+
+    // Use the host's camel names everywhere names are shown
+    let host_names = CamelNames::new(
+        state
+            .camel_names
+            .iter()
+            .filter_map(|(color, name)| parse_camel_color(color).map(|color| (color, name.clone()))),
+    );
+    if let Some(ref mut camel_names) = camel_names {
+        **camel_names = host_names.clone();
+    }
+    if let Some(ref mut game_log) = game_log {
+        game_log.camel_names = host_names;
+    }
+
     // Apply player leg bets
     if let Some(ref mut plb) = player_leg_bets {
         for (i, bets) in state.player_leg_bets.iter().enumerate() {
@@ -190,6 +438,32 @@ pub fn process_received_game_state(
         }
     }
 
+    // Apply the bets on the table
+    if let Some(ref mut tiles) = leg_betting_tiles {
+        for (i, color) in CamelColor::all().iter().enumerate() {
+            let values = state.leg_betting_tiles.tiles.iter().find(|(c, _)| *c == format!("{:?}", color));
+            if let (Some(stack), Some((_, values))) = (tiles.stacks.get_mut(i), values) {
+                *stack = values.iter().map(|&value| crate::components::LegBetTile { camel: *color, value }).collect();
+            }
+        }
+    }
+    if let Some(ref mut race_bets) = race_bets {
+        let parse = |bets: &[SerializableRaceBet]| -> Vec<crate::components::RaceBet> {
+            bets.iter()
+                .filter_map(|b| parse_camel_color(&b.camel_color).map(|camel| crate::components::RaceBet { camel, player_id: b.player_id }))
+                .collect()
+        };
+        race_bets.winner_bets = parse(&state.winner_bets);
+        race_bets.loser_bets = parse(&state.loser_bets);
+    }
+    if let Some(ref mut placed_tiles) = placed_tiles {
+        placed_tiles.tiles = state
+            .placed_spectator_tiles
+            .iter()
+            .map(|t| (t.space_index, (t.owner_id, t.is_oasis)))
+            .collect();
+    }
+
     // Apply player pyramid tokens
     if let Some(ref mut ppt) = player_pyramid_tokens {
         for (i, &count) in state.player_pyramid_tokens.iter().enumerate() {
@@ -200,128 +474,548 @@ pub fn process_received_game_state(
     }
 }
 
-/// System to broadcast game state to Firebase (host only)
+/// Everything a `SerializableGameState` is made from, for systems that take a snapshot
+/// of the game
+#[derive(SystemParam)]
+pub struct GameStateSource<'w, 's> {
+    pub players: Res<'w, Players>,
+    pub turn_state: Res<'w, TurnState>,
+    pub pyramid: Res<'w, Pyramid>,
+    leg_betting_tiles: Res<'w, LegBettingTiles>,
+    race_bets: Res<'w, RaceBets>,
+    placed_tiles: Res<'w, PlacedSpectatorTiles>,
+    player_leg_bets: Res<'w, PlayerLegBetsStore>,
+    player_pyramid_tokens: Res<'w, PlayerPyramidTokens>,
+    leg_fairness: Res<'w, LegFairness>,
+    camel_names: Res<'w, CamelNames>,
+    camels: Query<'w, 's, (&'static Camel, &'static BoardPosition)>,
+    crazy_camels: Query<'w, 's, (&'static CrazyCamel, &'static BoardPosition), Without<Camel>>,
+}
+
+impl GameStateSource<'_, '_> {
+    /// The game as it stands after `version` logged actions, with the player id in each seat
+    pub fn state(&self, version: u32, seat_player_ids: &[String]) -> SerializableGameState {
+        SerializableGameState {
+            version,
+            turn_state: SerializableTurnState {
+                current_player: self.turn_state.current_player,
+                action_taken: self.turn_state.action_taken,
+                leg_number: self.turn_state.leg_number,
+                awaiting_action: self.turn_state.awaiting_action,
+                leg_has_started: self.turn_state.leg_has_started,
+            },
+            players: self.players.players.iter().enumerate().map(|(i, p)| {
+                SerializablePlayer {
+                    id: p.id,
+                    network_id: seat_player_ids.get(i).cloned().unwrap_or_default(),
+                    name: p.name.clone(),
+                    money: p.money,
+                    has_spectator_tile: p.has_spectator_tile,
+                    available_race_cards: p.available_race_cards.iter().map(|c| format!("{:?}", c)).collect(),
+                    is_ai: p.is_ai,
+                    character_id: p.character_id.index() as u8,
+                    color_index: p.color_index,
+                    custom_color: p.custom_color,
+                    conceded: p.conceded,
+                }
+            }).collect(),
+            camels: self.camels.iter().map(|(c, p)| {
+                SerializableCamelPosition {
+                    color: format!("{:?}", c.color),
+                    space_index: p.space_index,
+                    stack_position: p.stack_position,
+                }
+            }).collect(),
+            crazy_camels: self.crazy_camels.iter().map(|(c, p)| {
+                SerializableCamelPosition {
+                    color: format!("{:?}", c.color),
+                    space_index: p.space_index,
+                    stack_position: p.stack_position,
+                }
+            }).collect(),
+            pyramid: SerializablePyramid {
+                rolled_dice: self.pyramid.rolled_dice.iter().filter_map(|r| {
+                    match r {
+                        crate::components::dice::PyramidDie::Regular(die) => {
+                            die.value.map(|value| SerializableDieResult {
+                                color: format!("{:?}", die.color),
+                                value,
+                                is_crazy: false,
+                            })
+                        }
+                        crate::components::dice::PyramidDie::Crazy { rolled } => {
+                            rolled.map(|(color, value)| SerializableDieResult {
+                                color: format!("{:?}", color),
+                                value,
+                                is_crazy: true,
+                            })
+                        }
+                    }
+                }).collect(),
+            },
+            leg_betting_tiles: SerializableLegBettingTiles {
+                tiles: CamelColor::all().iter().enumerate().map(|(i, color)| {
+                    let available: Vec<u8> = self.leg_betting_tiles.stacks.get(i)
+                        .map(|stack| stack.iter().map(|t| t.value).collect())
+                        .unwrap_or_default();
+                    (format!("{:?}", color), available)
+                }).collect(),
+            },
+            winner_bets: self.race_bets.winner_bets.iter().map(|b| {
+                SerializableRaceBet {
+                    camel_color: format!("{:?}", b.camel),
+                    player_id: b.player_id,
+                }
+            }).collect(),
+            loser_bets: self.race_bets.loser_bets.iter().map(|b| {
+                SerializableRaceBet {
+                    camel_color: format!("{:?}", b.camel),
+                    player_id: b.player_id,
+                }
+            }).collect(),
+            placed_spectator_tiles: self.placed_tiles.tiles.iter().map(|(&space, &(owner, is_oasis))| {
+                SerializableSpectatorTile {
+                    space_index: space,
+                    owner_id: owner,
+                    is_oasis,
+                }
+            }).collect(),
+            player_leg_bets: self.player_leg_bets.bets.iter().map(|bets| {
+                bets.iter().map(|b| SerializableLegBet {
+                    camel_color: format!("{:?}", b.camel),
+                    value: b.value,
+                }).collect()
+            }).collect(),
+            player_pyramid_tokens: self.player_pyramid_tokens.counts.clone(),
+            fairness: public_fairness(&self.leg_fairness, &self.pyramid),
+            camel_names: self
+                .camel_names
+                .custom_names()
+                .map(|(color, name)| (format!("{:?}", color), name.to_string()))
+                .collect(),
+            protocol: PROTOCOL_VERSION,
+        }
+    }
+}
+
+/// System to publish the full game state at the start of each leg, and between turns
+/// when a client asks for it (host only). Clients normally replay the action log and
+/// only need it after missing part of it or falling out of step.
 pub fn broadcast_game_state_system(
-    network_state: Res<NetworkState>,
-    players: Res<Players>,
+    mut network_state: ResMut<NetworkState>,
+    snapshot: GameStateSource,
+    ui_state: Res<UiState>,
+    mut published_leg: Local<u32>,
+) {
+    if !network_state.is_host() {
+        return;
+    }
+    let turn_state = &snapshot.turn_state;
+    let new_leg = turn_state.leg_number != *published_leg
+        && ui_state.initial_rolls_complete
+        && !ui_state.show_leg_scoring;
+    let requested = network_state.state_requested && ready_for_turn_action(&ui_state, turn_state, &snapshot.pyramid);
+    if !new_leg && !requested {
+        return;
+    }
+
+    let state = snapshot.state(network_state.actions_logged, &network_state.seat_player_ids);
+
+    // Serialize and send
+    if let Ok(json) = serde_json::to_string(&state) {
+        network_state.write_queue.writes.push_back(QueuedWrite::GameState(json));
+        *published_leg = turn_state.leg_number;
+        network_state.state_requested = false;
+    }
+}
+
+/// System to note the checksum of the game whenever it waits for a turn action (host
+/// only), for the next logged action to carry
+pub fn track_settled_checksum(
+    mut network_state: ResMut<NetworkState>,
+    ui_state: Res<UiState>,
+    turn_state: Res<TurnState>,
+    checked: ChecksumSource,
+) {
+    if !network_state.is_host() || !ready_for_turn_action(&ui_state, &turn_state, &checked.pyramid) {
+        return;
+    }
+    let checksum = checked.checksum();
+    network_state.settled_checksum = Some((network_state.actions_logged, checksum));
+}
+
+/// System to run the room's turn timer. Everyone counts down a player's turn to show
+/// it; when it runs out the host notes it in the log and rolls the pyramid for them,
+/// so a room never stalls on someone who walked away.
+pub fn run_turn_timer(
+    mut network_state: ResMut<NetworkState>,
+    time: Res<Time>,
+    ui_state: Res<UiState>,
     turn_state: Res<TurnState>,
     pyramid: Res<Pyramid>,
-    leg_betting_tiles: Res<LegBettingTiles>,
-    race_bets: Res<RaceBets>,
-    placed_tiles: Res<PlacedSpectatorTiles>,
-    player_leg_bets: Res<PlayerLegBetsStore>,
-    player_pyramid_tokens: Res<PlayerPyramidTokens>,
-    camels: Query<(&Camel, &BoardPosition)>,
-    crazy_camels: Query<(&CrazyCamel, &BoardPosition), Without<Camel>>,
-    mut last_version: Local<u32>,
+    players: Res<Players>,
+    (mut timed_out_actions, mut roll_actions): (MessageWriter<TurnTimedOutAction>, MessageWriter<RollPyramidAction>),
 ) {
-    if !network_state.is_host() {
+    if !network_state.is_online() || network_state.turn_timer_secs == 0 {
+        return;
+    }
+    // AI seats take their turn without a timer
+    let waiting = ready_for_turn_action(&ui_state, &turn_state, &pyramid) && !players.current_player().is_ai;
+    if !waiting || network_state.resyncing {
+        network_state.turn_time_left = None;
         return;
     }
 
-    let Some(ref room_code) = network_state.room_code else {
+    let limit = f32::from(network_state.turn_timer_secs);
+    let left = network_state.turn_time_left.unwrap_or(limit) - time.delta_secs();
+    if left > 0.0 || !network_state.is_host() {
+        network_state.turn_time_left = Some(left.max(0.0));
         return;
-    };
+    }
+
+    info!("Player {} ran out of time, rolling for them", players.current_player_index);
+    timed_out_actions.write(TurnTimedOutAction { player_index: players.current_player_index });
+    roll_actions.write(RollPyramidAction { result: None });
+    network_state.turn_time_left = None;
+}
+
+/// System to turn actions submitted by clients into local game actions (host only).
+/// The host resolves every action itself, so dice are only ever rolled here. Players
+/// may only act for their own seat, and only take turn actions on their turn.
+pub fn apply_network_actions(
+    mut network_state: ResMut<NetworkState>,
+    players: Res<Players>,
+    mut pending_actions: ResMut<PendingNetworkActions>,
+    mut turn_actions: PlayerActions,
+    mut concede_actions: MessageWriter<ConcedeAction>,
+) {
+    if !network_state.is_host() {
+        return;
+    }
+
+    for message in pending_actions.actions.drain(..) {
+        let seat = network_state.seat_of(&message.player_id);
+        let allowed = match message.action {
+            GameAction::Concede { player_index } => seat == Some(player_index),
+            GameAction::RequestState => seat.is_some(),
+            ref action if action.is_turn_action() => seat == Some(players.current_player_index),
+            // Starting the race and moving seats between players and the AI are up to the host
+            _ => false,
+        };
+        if !allowed {
+            warn!("Ignoring {:?} from {} (seat {:?})", message.action, message.player_id, seat);
+            continue;
+        }
 
-    // Create serializable state
-    let version = *last_version + 1;
-
-    let state = SerializableGameState {
-        version,
-        turn_state: SerializableTurnState {
-            current_player: turn_state.current_player,
-            action_taken: turn_state.action_taken,
-            leg_number: turn_state.leg_number,
-            awaiting_action: turn_state.awaiting_action,
-            leg_has_started: turn_state.leg_has_started,
-        },
-        players: players.players.iter().enumerate().map(|(i, p)| {
-            SerializablePlayer {
-                id: p.id,
-                network_id: network_state.local_player_id.clone().unwrap_or_default(), // TODO: Map properly
-                name: p.name.clone(),
-                money: p.money,
-                has_spectator_tile: p.has_spectator_tile,
-                available_race_cards: p.available_race_cards.iter().map(|c| format!("{:?}", c)).collect(),
-                is_ai: p.is_ai,
-                character_id: p.character_id as u8,
-                color_index: p.color_index,
+        info!("Host applying action from {}: {:?}", message.player_id, message.action);
+        match message.action {
+            // Whatever result a client sends, the dice are the host's to roll
+            GameAction::RollPyramid { .. } => {
+                turn_actions.roll.write(RollPyramidAction { result: None });
             }
-        }).collect(),
-        camels: camels.iter().map(|(c, p)| {
-            SerializableCamelPosition {
-                color: format!("{:?}", c.color),
-                space_index: p.space_index,
-                stack_position: p.stack_position,
+            GameAction::TakeLegBet { color } => {
+                turn_actions.leg_bet.write(TakeLegBetAction { color, from_host: false });
             }
-        }).collect(),
-        crazy_camels: crazy_camels.iter().map(|(c, p)| {
-            SerializableCamelPosition {
-                color: format!("{:?}", c.color),
-                space_index: p.space_index,
-                stack_position: p.stack_position,
+            GameAction::PlaceSpectatorTile { space_index, is_oasis } => {
+                turn_actions.spectator_tile.write(PlaceSpectatorTileAction { space_index, is_oasis, from_host: false });
             }
-        }).collect(),
-        pyramid: SerializablePyramid {
-            rolled_dice: pyramid.rolled_dice.iter().filter_map(|r| {
-                match r {
-                    crate::components::dice::PyramidDie::Regular(die) => {
-                        die.value.map(|value| SerializableDieResult {
-                            color: format!("{:?}", die.color),
-                            value,
-                            is_crazy: false,
-                        })
-                    }
-                    crate::components::dice::PyramidDie::Crazy { rolled } => {
-                        rolled.map(|(color, value)| SerializableDieResult {
-                            color: format!("{:?}", color),
-                            value,
-                            is_crazy: true,
-                        })
-                    }
-                }
-            }).collect(),
-        },
-        leg_betting_tiles: SerializableLegBettingTiles {
-            tiles: CamelColor::all().iter().enumerate().map(|(i, color)| {
-                let available: Vec<u8> = leg_betting_tiles.stacks.get(i)
-                    .map(|stack| stack.iter().map(|t| t.value).collect())
-                    .unwrap_or_default();
-                (format!("{:?}", color), available)
-            }).collect(),
-        },
-        winner_bets: race_bets.winner_bets.iter().map(|b| {
-            SerializableRaceBet {
-                camel_color: format!("{:?}", b.camel),
-                player_id: b.player_id,
+            GameAction::PlaceRaceBet { color, is_winner_bet } => {
+                turn_actions.race_bet.write(PlaceRaceBetAction { color, is_winner_bet, from_host: false });
             }
-        }).collect(),
-        loser_bets: race_bets.loser_bets.iter().map(|b| {
-            SerializableRaceBet {
-                camel_color: format!("{:?}", b.camel),
-                player_id: b.player_id,
+            GameAction::Concede { player_index } => {
+                concede_actions.write(ConcedeAction { player_index });
             }
-        }).collect(),
-        placed_spectator_tiles: placed_tiles.tiles.iter().map(|(&space, &(owner, is_oasis))| {
-            SerializableSpectatorTile {
-                space_index: space,
-                owner_id: owner,
-                is_oasis,
+            GameAction::RequestState => {
+                network_state.state_requested = true;
             }
-        }).collect(),
-        player_leg_bets: player_leg_bets.bets.iter().map(|bets| {
-            bets.iter().map(|b| SerializableLegBet {
-                camel_color: format!("{:?}", b.camel),
-                value: b.value,
-            }).collect()
-        }).collect(),
-        player_pyramid_tokens: player_pyramid_tokens.counts.clone(),
+            GameAction::StartRace
+            | GameAction::EndLeg { .. }
+            | GameAction::EndGame { .. }
+            | GameAction::ConvertToAi { .. }
+            | GameAction::ReturnSeat { .. }
+            | GameAction::TurnTimedOut { .. } => {}
+        }
+    }
+}
+
+/// System to replay the host's action log (clients only), or a recorded game's actions
+/// (see `game::replay`). Each action waits until the game here has caught up to where
+/// the host applied it: the previous turn is over, and any leg scoring has been dismissed. A turn action whose checksum doesn't match
+/// the game here stops the replay until the host's full state arrives.
+pub fn replay_host_actions(
+    mut network_state: ResMut<NetworkState>,
+    mut ui_state: ResMut<UiState>,
+    turn_state: Res<TurnState>,
+    mut leg_fairness: ResMut<LegFairness>,
+    (mut turn_actions, mut seat_actions): (PlayerActions, SeatActions),
+    mut outgoing_actions: ResMut<OutgoingNetworkActions>,
+    checked: ChecksumSource,
+) {
+    if !network_state.follows_log() || network_state.resyncing {
+        return;
+    }
+    let Some(next) = network_state.pending_replay.first() else { return };
+    if next.sequence != network_state.actions_logged + 1 {
+        // Still waiting for an earlier action (or the host's state) to arrive
+        return;
+    }
+
+    let ready = match next.action {
+        GameAction::StartRace => ui_state.camel_rolls_complete,
+        ref action if action.is_turn_action() => ready_for_turn_action(&ui_state, &turn_state, &checked.pyramid),
+        _ => true,
     };
+    if !ready {
+        return;
+    }
 
-    // Serialize and send
-    if let Ok(json) = serde_json::to_string(&state) {
-        js_bindings::async_ops::write_state_async(room_code.clone(), json);
-        *last_version = version;
+    if let Some(ref expected) = next.checksum {
+        let checksum = checked.checksum();
+        if checksum != *expected {
+            warn!("Out of step with the host before action {} (checksum {}, host has {}), resyncing",
+                next.sequence, checksum, expected);
+            network_state.resyncing = true;
+            outgoing_actions.actions.push(GameAction::RequestState);
+            return;
+        }
+    }
+
+    let next = network_state.pending_replay.remove(0);
+    network_state.actions_logged = next.sequence;
+    if let Some(ref f) = next.fairness {
+        observe_fairness(&mut leg_fairness, f);
+    }
+
+    match next.action {
+        GameAction::StartRace => {
+            ui_state.initial_rolls_complete = true;
+        }
+        GameAction::RollPyramid { result } => {
+            if result.is_some() {
+                turn_actions.roll.write(RollPyramidAction { result });
+            } else {
+                warn!("Host logged roll {} without its result", next.sequence);
+            }
+        }
+        GameAction::TakeLegBet { color } => {
+            turn_actions.leg_bet.write(TakeLegBetAction { color, from_host: true });
+        }
+        GameAction::PlaceSpectatorTile { space_index, is_oasis } => {
+            turn_actions.spectator_tile.write(PlaceSpectatorTileAction { space_index, is_oasis, from_host: true });
+        }
+        GameAction::PlaceRaceBet { color, is_winner_bet } => {
+            turn_actions.race_bet.write(PlaceRaceBetAction { color, is_winner_bet, from_host: true });
+        }
+        GameAction::Concede { player_index } => {
+            seat_actions.concede.write(ConcedeAction { player_index });
+        }
+        GameAction::ConvertToAi { player_index } => {
+            seat_actions.convert_to_ai.write(ConvertToAiAction { player_index });
+        }
+        GameAction::ReturnSeat { player_index } => {
+            seat_actions.return_seat.write(ReturnSeatAction { player_index });
+        }
+        GameAction::TurnTimedOut { player_index } => {
+            seat_actions.timed_out.write(TurnTimedOutAction { player_index });
+        }
+        GameAction::EndLeg { .. } | GameAction::EndGame { .. } | GameAction::RequestState => {}
+    }
+}
+
+/// System to keep track of who is still in the room, for everyone to see, and to hand
+/// the seats of players who have been gone longer than the room's grace period to the
+/// AI (host only), so the game never stalls waiting on someone who is gone. Players
+/// who come back get their seat back.
+pub fn take_over_disconnected_seats(
+    backend: Res<Backend>,
+    mut network_state: ResMut<NetworkState>,
+    mut room_players: ResMut<RoomPlayers>,
+    players: Res<Players>,
+    time: Res<Time>,
+    mut convert_actions: MessageWriter<ConvertToAiAction>,
+    mut return_seat_actions: MessageWriter<ReturnSeatAction>,
+) {
+    if !network_state.is_online() {
+        return;
+    }
+
+    if let Some(players_json) = backend.poll_players() {
+        if let Ok(room) = serde_json::from_str::<Vec<OnlinePlayerInfo>>(&players_json) {
+            room_players.players = room;
+        }
+    }
+
+    // Heartbeats go stale without any update arriving, so this is checked every frame
+    let server_time = backend.server_time_ms();
+    let now = time.elapsed_secs_f64();
+    let grace = f64::from(network_state.takeover_grace_secs);
+    for player_index in 0..network_state.seat_player_ids.len() {
+        let id = &network_state.seat_player_ids[player_index];
+        let Some(player) = players.players.get(player_index) else { continue };
+        // AI seats have nobody to miss, and conceded seats stay with the AI
+        if id.is_empty() || player.conceded {
+            continue;
+        }
+
+        // Leaving the room removes the entry; closing or freezing the tab flags it
+        let present = Some(player_index) == network_state.local_player_index
+            || room_players.players.iter().any(|p| &p.id == id && p.is_present(server_time));
+        let absent_since = network_state
+            .absent_since
+            .iter()
+            .find(|&&(seat, _)| seat == player_index)
+            .map(|&(_, since)| since);
+        match (present, absent_since) {
+            (true, Some(_)) => network_state.absent_since.retain(|&(seat, _)| seat != player_index),
+            (false, None) => network_state.absent_since.push((player_index, now)),
+            _ => {}
+        }
+
+        if !network_state.is_host() {
+            continue;
+        }
+        let taken_over = network_state.seats_taken_over.contains(&player_index);
+        let gone_too_long = absent_since.is_some_and(|since| now - since >= grace);
+        if !present && gone_too_long && !player.is_ai {
+            info!("Seat {} lost its player, handing it to the AI", player_index);
+            convert_actions.write(ConvertToAiAction { player_index });
+            network_state.seats_taken_over.push(player_index);
+        } else if present && taken_over {
+            info!("Seat {} got its player back", player_index);
+            return_seat_actions.write(ReturnSeatAction { player_index });
+            network_state.seats_taken_over.retain(|&seat| seat != player_index);
+        }
+    }
+}
+
+/// System to send queued actions: clients submit theirs to the host, the host numbers
+/// the ones it applied and logs them for the clients
+pub fn send_outgoing_actions(
+    backend: Res<Backend>,
+    mut network_state: ResMut<NetworkState>,
+    mut outgoing_actions: ResMut<OutgoingNetworkActions>,
+    leg_fairness: Res<LegFairness>,
+    pyramid: Res<Pyramid>,
+) {
+    if !network_state.is_online() || outgoing_actions.actions.is_empty() {
+        return;
+    }
+
+    if network_state.is_host() {
+        for action in outgoing_actions.actions.drain(..) {
+            // Only if the game hasn't moved on since the checksum was taken
+            let checksum = match network_state.settled_checksum {
+                Some((logged, ref checksum)) if action.is_turn_action() && logged == network_state.actions_logged => {
+                    Some(checksum.clone())
+                }
+                _ => None,
+            };
+            network_state.actions_logged += 1;
+            let entry = SequencedAction {
+                sequence: network_state.actions_logged,
+                action,
+                fairness: public_fairness(&leg_fairness, &pyramid),
+                checksum,
+                protocol: PROTOCOL_VERSION,
+            };
+            if let Ok(json) = serde_json::to_string(&entry) {
+                network_state.write_queue.writes.push_back(QueuedWrite::LogAction(json));
+            }
+        }
+        return;
+    }
+
+    let player_id = network_state.local_player_id.clone().unwrap_or_default();
+    for action in outgoing_actions.actions.drain(..) {
+        let message = NetworkActionMessage {
+            player_id: player_id.clone(),
+            action,
+            timestamp: backend.timestamp_ms(),
+            protocol: PROTOCOL_VERSION,
+        };
+        if let Ok(json) = serde_json::to_string(&message) {
+            network_state.write_queue.writes.push_back(QueuedWrite::SubmitAction(json));
+        }
+    }
+}
+
+/// Whether the game is between turns, waiting for the current player to act
+pub fn ready_for_turn_action(ui_state: &UiState, turn_state: &TurnState, pyramid: &Pyramid) -> bool {
+    ui_state.initial_rolls_complete
+        && !turn_state.action_taken
+        && !ui_state.show_leg_scoring
+        && ui_state.leg_scoring_delay <= 0.0
+        && !pyramid.all_dice_rolled()
+}
+
+/// What every player's game must agree on: camel positions, money and the dice rolled
+/// from the pyramid
+#[derive(SystemParam)]
+pub struct ChecksumSource<'w, 's> {
+    players: Res<'w, Players>,
+    pyramid: Res<'w, Pyramid>,
+    camels: Query<'w, 's, (&'static Camel, &'static BoardPosition)>,
+    crazy_camels: Query<'w, 's, (&'static CrazyCamel, &'static BoardPosition), Without<Camel>>,
+}
+
+impl ChecksumSource<'_, '_> {
+    /// Checksum of the game as it is here. Only fixed size values go into it, byte by
+    /// byte, and FNV-1a never changes, so browser and desktop builds get the same result.
+    fn checksum(&self) -> String {
+        let mut positions: Vec<(u8, u8, u8)> = self
+            .camels
+            .iter()
+            .map(|(camel, pos)| (camel.color as u8, pos.space_index, pos.stack_position))
+            .chain(self.crazy_camels.iter().map(|(camel, pos)| (10 + camel.color as u8, pos.space_index, pos.stack_position)))
+            .collect();
+        positions.sort();
+
+        let mut rolled: Vec<(u8, u8)> = self
+            .pyramid
+            .rolled_dice
+            .iter()
+            .map(|die| match die {
+                PyramidDie::Regular(regular) => (regular.color as u8, regular.value.unwrap_or(0)),
+                PyramidDie::Crazy { rolled } => rolled.map_or((10, 0), |(color, value)| (10 + color as u8, value)),
+            })
+            .collect();
+        rolled.sort();
+
+        let mut hasher = StableHasher::new();
+        hasher.write(b"camel-up-state");
+        for (camel, space, stack) in &positions {
+            hasher.write(&[*camel, *space, *stack]);
+        }
+        for player in &self.players.players {
+            hasher.write(&player.money.to_le_bytes());
+        }
+        for (die, value) in &rolled {
+            hasher.write(&[*die, *value]);
+        }
+        format!("{:016x}", hasher.finish())
+    }
+}
+
+/// The leg's seed commitment as clients may see it: the seed stays secret until
+/// every die of the leg has been rolled
+fn public_fairness(leg_fairness: &LegFairness, pyramid: &Pyramid) -> Option<SerializableFairness> {
+    leg_fairness.commitment.as_ref().map(|commitment| SerializableFairness {
+        leg: leg_fairness.leg,
+        commitment: commitment.clone(),
+        revealed_seed: leg_fairness
+            .revealed_seed
+            .filter(|_| pyramid.all_dice_rolled())
+            .map(fairness::encode_seed),
+    })
+}
+
+/// Track the host's seed commitment, and the seed once it is revealed (clients only)
+fn observe_fairness(leg_fairness: &mut LegFairness, f: &SerializableFairness) {
+    if f.leg != leg_fairness.leg || leg_fairness.commitment.is_none() {
+        leg_fairness.observe_commitment(f.leg, f.commitment.clone());
+    }
+    if leg_fairness.revealed_seed.is_none() {
+        leg_fairness.revealed_seed = f.revealed_seed.as_deref().and_then(fairness::decode_seed);
     }
 }
 
@@ -345,3 +1039,186 @@ fn parse_crazy_camel_color(s: &str) -> Option<CrazyCamelColor> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::network::backend::mock::MockBackend;
+    use crate::network::state::NetworkMode;
+    use crate::ui::characters::CharacterId;
+
+    /// Room ABCD as `mode` sees it, talking to a mock backend the test keeps a handle on
+    fn room(mode: NetworkMode) -> (World, MockBackend) {
+        let backend = MockBackend::default();
+        let mut world = World::new();
+        world.insert_resource(Backend::new(backend.clone()));
+        world.insert_resource(NetworkState { mode, room_code: Some("ABCD".to_string()), ..default() });
+        world.insert_resource(Time::<()>::default());
+        world.init_resource::<ReceivedGameState>();
+        world.init_resource::<PendingNetworkActions>();
+        (world, backend)
+    }
+
+    fn queue(world: &mut World, write: QueuedWrite) {
+        world.resource_mut::<NetworkState>().write_queue.writes.push_back(write);
+    }
+
+    fn flush(world: &mut World) {
+        world.run_system_once(flush_write_queue).unwrap();
+    }
+
+    /// Entry `sequence` of the host's log, rolling the pyramid
+    fn logged(sequence: u32, protocol: u32) -> SequencedAction {
+        SequencedAction {
+            sequence,
+            action: GameAction::RollPyramid { result: None },
+            fairness: None,
+            checksum: None,
+            protocol,
+        }
+    }
+
+    #[test]
+    fn writes_reach_the_room_one_at_a_time_and_in_order() {
+        let (mut world, backend) = room(NetworkMode::OnlineHost);
+        queue(&mut world, QueuedWrite::LogAction("1".to_string()));
+        queue(&mut world, QueuedWrite::LogAction("2".to_string()));
+        queue(&mut world, QueuedWrite::GameState("3".to_string()));
+
+        flush(&mut world);
+        assert_eq!(backend.written(), ["1"]);
+        flush(&mut world);
+        flush(&mut world);
+        assert_eq!(backend.written(), ["1", "2", "3"]);
+
+        flush(&mut world);
+        let network_state = world.resource::<NetworkState>();
+        assert_eq!(network_state.write_queue.len(), 0);
+        assert!(network_state.connection_error.is_none());
+    }
+
+    #[test]
+    fn a_failed_write_is_sent_again_after_backing_off() {
+        let (mut world, backend) = room(NetworkMode::OnlineClient);
+        backend.room().write_error = Some(NetworkError::Disconnected);
+        queue(&mut world, QueuedWrite::SubmitAction("roll".to_string()));
+        flush(&mut world);
+        flush(&mut world);
+
+        // Nothing goes out again until the backoff is over
+        assert_eq!(backend.written(), ["roll"]);
+        let network_state = world.resource::<NetworkState>();
+        assert!(network_state.write_queue.is_stalled(0.0));
+        assert!(network_state.connection_error.is_none());
+
+        backend.room().write_error = None;
+        world.resource_mut::<Time>().advance_by(Duration::from_secs_f64(retry_delay(1) + 0.1));
+        flush(&mut world);
+        assert_eq!(backend.written(), ["roll", "roll"]);
+        assert_eq!(backend.room().writes[1].0, NetworkOperation::SubmitAction);
+
+        flush(&mut world);
+        let network_state = world.resource::<NetworkState>();
+        assert_eq!(network_state.write_queue.len(), 0);
+        assert!(!network_state.write_queue.is_stalled(1.0));
+    }
+
+    #[test]
+    fn a_write_the_room_refuses_is_dropped_and_reported() {
+        let (mut world, backend) = room(NetworkMode::OnlineHost);
+        backend.room().write_error = Some(NetworkError::PermissionDenied);
+        queue(&mut world, QueuedWrite::LogAction("1".to_string()));
+        queue(&mut world, QueuedWrite::LogAction("2".to_string()));
+        flush(&mut world);
+        flush(&mut world);
+
+        // Retrying can't fix it, so the next write goes out straight away
+        assert_eq!(backend.written(), ["1", "2"]);
+        let failure = world.resource::<NetworkState>().connection_error.clone().unwrap();
+        assert_eq!(failure.operation, NetworkOperation::SyncState);
+        assert_eq!(failure.error, NetworkError::PermissionDenied);
+    }
+
+    #[test]
+    fn clients_line_up_the_hosts_log_once_and_in_order() {
+        let (mut world, backend) = room(NetworkMode::OnlineClient);
+        world.resource_mut::<NetworkState>().actions_logged = 1;
+        let log = |entries: &[SequencedAction]| Some(serde_json::to_string(entries).unwrap());
+
+        // Entries can arrive out of order, and the ones already applied are skipped
+        backend.room().host_actions =
+            log(&[logged(3, PROTOCOL_VERSION), logged(1, PROTOCOL_VERSION), logged(2, PROTOCOL_VERSION)]);
+        backend.room().game_state = Some("{}".to_string());
+        world.run_system_once(poll_network_updates).unwrap();
+        let sequences = |world: &World| -> Vec<u32> {
+            world.resource::<NetworkState>().pending_replay.iter().map(|a| a.sequence).collect()
+        };
+        assert_eq!(sequences(&world), [2, 3]);
+        assert!(world.resource::<ReceivedGameState>().needs_processing);
+
+        // An entry heard twice is only replayed once
+        backend.room().host_actions = log(&[logged(2, PROTOCOL_VERSION), logged(4, PROTOCOL_VERSION)]);
+        world.run_system_once(poll_network_updates).unwrap();
+        assert_eq!(sequences(&world), [2, 3, 4]);
+
+        // A host on another protocol version could play out differently here
+        backend.room().host_actions = log(&[logged(5, PROTOCOL_VERSION + 1)]);
+        world.run_system_once(poll_network_updates).unwrap();
+        assert_eq!(sequences(&world), [2, 3, 4]);
+        let failure = world.resource::<NetworkState>().connection_error.clone().unwrap();
+        assert_eq!(failure.error, NetworkError::VersionMismatch);
+    }
+
+    #[test]
+    fn the_host_only_takes_actions_a_player_may_take() {
+        let (mut world, backend) = room(NetworkMode::OnlineHost);
+        world.resource_mut::<NetworkState>().seat_player_ids = vec!["host".to_string(), "guest".to_string()];
+        world.insert_resource(Players::new(vec![
+            ("Host".to_string(), false, CharacterId::from_index(0), 0, None),
+            ("Guest".to_string(), false, CharacterId::from_index(1), 1, None),
+        ]));
+        world.init_resource::<Messages<RollPyramidAction>>();
+        world.init_resource::<Messages<TakeLegBetAction>>();
+        world.init_resource::<Messages<PlaceSpectatorTileAction>>();
+        world.init_resource::<Messages<PlaceRaceBetAction>>();
+        world.init_resource::<Messages<ConcedeAction>>();
+
+        let from = |player_id: &str, action: GameAction| NetworkActionMessage {
+            player_id: player_id.to_string(),
+            action,
+            timestamp: 0,
+            protocol: PROTOCOL_VERSION,
+        };
+        backend.room().actions = Some(
+            serde_json::to_string(&[
+                // Not the guest's turn, nor the guest's seat to concede
+                from("guest", GameAction::RollPyramid { result: None }),
+                from("guest", GameAction::Concede { player_index: 0 }),
+                // Starting the race is the host's to do, and strangers have no say
+                from("guest", GameAction::StartRace),
+                from("stranger", GameAction::RequestState),
+                // What they may do
+                from("host", GameAction::TakeLegBet { color: CamelColor::Blue }),
+                from("guest", GameAction::Concede { player_index: 1 }),
+                from("guest", GameAction::RequestState),
+            ])
+            .unwrap(),
+        );
+        world.run_system_once(poll_network_updates).unwrap();
+        world.run_system_once(apply_network_actions).unwrap();
+
+        assert!(world.resource::<Messages<RollPyramidAction>>().is_empty());
+        let leg_bets: Vec<CamelColor> =
+            world.resource_mut::<Messages<TakeLegBetAction>>().drain().map(|bet| bet.color).collect();
+        assert_eq!(leg_bets, [CamelColor::Blue]);
+        let concessions: Vec<usize> =
+            world.resource_mut::<Messages<ConcedeAction>>().drain().map(|c| c.player_index).collect();
+        assert_eq!(concessions, [1]);
+        assert!(world.resource::<NetworkState>().state_requested);
+    }
+}
@@ -0,0 +1,626 @@
+//! WebSocket relay backend (desktop builds)
+//!
+//! Talks to a relay server (`camel-up --relay`, see `relay_server.rs`) that keeps rooms
+//! and passes actions and game state between the players in them. The connection runs
+//! on a small tokio runtime of its own; whatever arrives is buffered until polled.
+//! The tests play through a relay in the same process instead, without a socket.
+
+use std::sync::{Arc, Mutex};
+
+use bevy::prelude::*;
+use futures_util::{SinkExt, StreamExt};
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio_tungstenite::tungstenite::Message;
+
+use super::backend::NetworkBackend;
+use super::error::{NetworkError, NetworkOperation, NetworkResults};
+use super::messages::{
+    EmoteMessage, NetworkActionMessage, RelayEvent, RelayOperation, RelayRequest, SequencedAction, PROTOCOL_VERSION,
+};
+#[cfg(test)]
+use super::relay_server::LocalRelay;
+
+/// Relay server used when `CAMEL_UP_RELAY_URL` isn't set
+pub const DEFAULT_RELAY_URL: &str = "ws://127.0.0.1:7878";
+
+/// What the relay has sent since the last poll
+#[derive(Default)]
+struct Inbox {
+    player_id: Option<String>,
+    /// The relay's secret for our seat in the room we're in
+    rejoin_token: Option<String>,
+    /// Where to report request outcomes (the results channel of the last call)
+    results: Option<NetworkResults>,
+    game_state: Option<String>,
+    actions: Vec<NetworkActionMessage>,
+    host_actions: Vec<SequencedAction>,
+    players: Option<String>,
+    emotes: Vec<EmoteMessage>,
+    game_started: bool,
+    randomize_order: bool,
+    locked: bool,
+    /// The host took us out of the room
+    kicked: bool,
+    room_settings: Option<String>,
+    setup: Option<String>,
+}
+
+/// Where the relay runs
+enum RelayServer {
+    /// Reached over a WebSocket
+    Remote(String),
+    /// In this process
+    #[cfg(test)]
+    Local(LocalRelay),
+}
+
+/// Online play through a WebSocket relay server
+pub struct WebSocketBackend {
+    server: RelayServer,
+    /// Why online play is off, if the runtime couldn't start
+    runtime: Result<Runtime, NetworkError>,
+    inbox: Arc<Mutex<Inbox>>,
+    /// Requests waiting to be written to the socket, while connected
+    outgoing: Arc<Mutex<Option<UnboundedSender<RelayRequest>>>>,
+}
+
+impl WebSocketBackend {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::with_server(RelayServer::Remote(url.into()))
+    }
+
+    /// Play through a relay in this process instead of over a socket
+    #[cfg(test)]
+    pub fn loopback(relay: LocalRelay) -> Self {
+        Self::with_server(RelayServer::Local(relay))
+    }
+
+    fn with_server(server: RelayServer) -> Self {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("camel-up-network")
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                warn!("Could not start the network runtime: {}", e);
+                NetworkError::Other(format!("couldn't start networking ({})", e))
+            });
+        Self {
+            server,
+            runtime,
+            inbox: Arc::default(),
+            outgoing: Arc::default(),
+        }
+    }
+
+    /// Relay server from `CAMEL_UP_RELAY_URL`, or a local one
+    pub fn from_env() -> Self {
+        Self::new(std::env::var("CAMEL_UP_RELAY_URL").unwrap_or_else(|_| DEFAULT_RELAY_URL.to_string()))
+    }
+
+    /// Queue a request for the relay. Returns false (after reporting `operation` as
+    /// failed, if given) when there is no connection to send it on.
+    fn send(&self, request: RelayRequest, operation: Option<NetworkOperation>, results: Option<NetworkResults>) -> bool {
+        if let Some(ref results) = results {
+            if let Ok(mut inbox) = self.inbox.lock() {
+                inbox.results = Some(results.clone());
+            }
+        }
+
+        let sent = self
+            .outgoing
+            .lock()
+            .ok()
+            .and_then(|outgoing| outgoing.as_ref().map(|tx| tx.send(request).is_ok()))
+            .unwrap_or(false);
+        if !sent {
+            if let (Some(operation), Some(results)) = (operation, results) {
+                results.send(operation, Err(NetworkError::Disconnected));
+            }
+        }
+        sent
+    }
+
+    fn with_inbox<T>(&self, f: impl FnOnce(&mut Inbox) -> T) -> Option<T> {
+        self.inbox.lock().ok().map(|mut inbox| f(&mut inbox))
+    }
+}
+
+impl NetworkBackend for WebSocketBackend {
+    fn authenticate(&self, results: NetworkResults) {
+        // Already connected: nothing to do
+        let connected = self.outgoing.lock().is_ok_and(|outgoing| outgoing.as_ref().is_some_and(|tx| !tx.is_closed()));
+        if connected && self.local_player_id().is_some() {
+            results.send(NetworkOperation::Authenticate, Ok(()));
+            return;
+        }
+
+        let runtime = match self.runtime {
+            Ok(ref runtime) => runtime,
+            Err(ref error) => {
+                results.send(NetworkOperation::Authenticate, Err(error.clone()));
+                return;
+            }
+        };
+
+        self.with_inbox(|inbox| inbox.results = Some(results.clone()));
+        let inbox = self.inbox.clone();
+        let outgoing = self.outgoing.clone();
+        match self.server {
+            RelayServer::Remote(ref url) => {
+                let url = url.clone();
+                runtime.spawn(async move {
+                    let socket = match tokio_tungstenite::connect_async(url.as_str()).await {
+                        Ok((socket, _)) => socket,
+                        Err(e) => {
+                            warn!("Could not connect to relay server {}: {}", url, e);
+                            results.send(
+                                NetworkOperation::Authenticate,
+                                Err(NetworkError::Other(format!("couldn't reach the game server at {}", url))),
+                            );
+                            return;
+                        }
+                    };
+                    info!("Connected to relay server {}", url);
+
+                    let (mut writer, mut reader) = socket.split();
+                    let (tx, mut rx) = mpsc::unbounded_channel::<RelayRequest>();
+                    if let Ok(mut outgoing) = outgoing.lock() {
+                        *outgoing = Some(tx);
+                    }
+
+                    tokio::spawn(async move {
+                        while let Some(request) = rx.recv().await {
+                            let Ok(json) = serde_json::to_string(&request) else { continue };
+                            if writer.send(Message::text(json)).await.is_err() {
+                                break;
+                            }
+                        }
+                    });
+
+                    while let Some(Ok(message)) = reader.next().await {
+                        let Message::Text(text) = message else { continue };
+                        match serde_json::from_str::<RelayEvent>(&text) {
+                            Ok(event) => receive(&inbox, event),
+                            Err(e) => warn!("Ignoring unreadable relay message: {}", e),
+                        }
+                    }
+
+                    // Connection closed: forget it, and say so if we were in a game
+                    info!("Disconnected from relay server {}", url);
+                    if let Ok(mut outgoing) = outgoing.lock() {
+                        *outgoing = None;
+                    }
+                    if let Ok(mut inbox) = inbox.lock() {
+                        inbox.player_id = None;
+                        if let Some(ref results) = inbox.results {
+                            results.send(NetworkOperation::SyncState, Err(NetworkError::Disconnected));
+                        }
+                    }
+                });
+            }
+            #[cfg(test)]
+            RelayServer::Local(ref relay) => {
+                let (mut player_id, mut events) = relay.connect();
+                let (tx, mut rx) = mpsc::unbounded_channel::<RelayRequest>();
+                if let Ok(mut outgoing) = outgoing.lock() {
+                    *outgoing = Some(tx);
+                }
+                runtime.spawn(async move {
+                    while let Some(event) = events.recv().await {
+                        receive(&inbox, event);
+                    }
+                });
+                let relay = relay.clone();
+                runtime.spawn(async move {
+                    while let Some(request) = rx.recv().await {
+                        relay.request(&mut player_id, request);
+                    }
+                    relay.disconnect(&player_id);
+                });
+            }
+        }
+    }
+
+    fn local_player_id(&self) -> Option<String> {
+        self.with_inbox(|inbox| inbox.player_id.clone()).flatten()
+    }
+
+    fn create_room(&self, room_code: &str, name: &str, character_id: u8, color_index: usize, results: NetworkResults) {
+        let request = RelayRequest::CreateRoom {
+            room_code: room_code.to_string(),
+            name: name.to_string(),
+            character_id,
+            color_index,
+            protocol: PROTOCOL_VERSION,
+        };
+        self.send(request, Some(NetworkOperation::CreateRoom), Some(results));
+    }
+
+    fn join_room(&self, room_code: &str, name: &str, character_id: u8, color_index: usize, results: NetworkResults) {
+        let request = RelayRequest::JoinRoom {
+            room_code: room_code.to_string(),
+            name: name.to_string(),
+            character_id,
+            color_index,
+            protocol: PROTOCOL_VERSION,
+        };
+        self.send(request, Some(NetworkOperation::JoinRoom), Some(results));
+    }
+
+    fn rejoin_room(&self, room_code: &str, player_id: &str, rejoin_token: Option<&str>, results: NetworkResults) {
+        let request = RelayRequest::RejoinRoom {
+            room_code: room_code.to_string(),
+            player_id: player_id.to_string(),
+            rejoin_token: rejoin_token.unwrap_or_default().to_string(),
+            protocol: PROTOCOL_VERSION,
+        };
+        self.send(request, Some(NetworkOperation::JoinRoom), Some(results));
+    }
+
+    fn rejoin_token(&self) -> Option<String> {
+        self.with_inbox(|inbox| inbox.rejoin_token.clone()).flatten()
+    }
+
+    fn leave_room(&self, _room_code: &str) {
+        self.send(RelayRequest::LeaveRoom, None, None);
+    }
+
+    fn update_appearance(
+        &self,
+        _room_code: &str,
+        character_id: u8,
+        color_index: usize,
+        custom_color: Option<[u8; 3]>,
+        name: Option<String>,
+    ) {
+        self.send(RelayRequest::UpdateAppearance { character_id, color_index, custom_color, name }, None, None);
+    }
+
+    fn set_ready(&self, _room_code: &str, is_ready: bool) {
+        self.send(RelayRequest::SetReady { is_ready }, None, None);
+    }
+
+    fn set_randomize_order(&self, _room_code: &str, randomize: bool) {
+        self.send(RelayRequest::SetRandomizeOrder { randomize }, None, None);
+    }
+
+    fn randomize_order(&self) -> bool {
+        self.with_inbox(|inbox| inbox.randomize_order).unwrap_or(false)
+    }
+
+    fn kick_player(&self, _room_code: &str, player_id: &str) {
+        self.send(RelayRequest::KickPlayer { player_id: player_id.to_string() }, None, None);
+    }
+
+    fn set_locked(&self, _room_code: &str, locked: bool) {
+        self.send(RelayRequest::SetLocked { locked }, None, None);
+    }
+
+    fn is_locked(&self) -> bool {
+        self.with_inbox(|inbox| inbox.locked).unwrap_or(false)
+    }
+
+    fn was_kicked(&self) -> bool {
+        self.with_inbox(|inbox| inbox.kicked).unwrap_or(false)
+    }
+
+    fn set_room_settings(&self, _room_code: &str, settings_json: String) {
+        match serde_json::from_str(&settings_json) {
+            Ok(settings) => {
+                self.send(RelayRequest::SetRoomSettings { settings }, None, None);
+            }
+            Err(e) => warn!("Could not read room settings: {}", e),
+        }
+    }
+
+    fn room_settings(&self) -> Option<String> {
+        self.with_inbox(|inbox| inbox.room_settings.clone()).flatten()
+    }
+
+    // The relay flags a player the moment their connection drops, so there are no
+    // heartbeats to check
+    fn server_time_ms(&self) -> Option<f64> {
+        None
+    }
+
+    fn start_game(&self, _room_code: &str, setup_json: String, results: NetworkResults) {
+        match serde_json::from_str(&setup_json) {
+            Ok(setup) => {
+                self.send(RelayRequest::StartGame { setup }, Some(NetworkOperation::StartGame), Some(results));
+            }
+            Err(e) => results.send(NetworkOperation::StartGame, Err(NetworkError::Other(e.to_string()))),
+        }
+    }
+
+    fn has_game_started(&self) -> bool {
+        self.with_inbox(|inbox| inbox.game_started).unwrap_or(false)
+    }
+
+    fn game_setup(&self) -> Option<String> {
+        self.with_inbox(|inbox| inbox.setup.clone()).flatten()
+    }
+
+    fn reset_room(&self, _room_code: &str) {
+        self.send(RelayRequest::ResetRoom, None, None);
+    }
+
+    // The relay sends everything about the room we're in, so there is nothing to
+    // subscribe to: updates wait in the inbox until polled.
+    fn subscribe_to_room(&self, _room_code: &str) {}
+
+    fn subscribe_to_game_state(&self, _room_code: &str) {}
+
+    fn subscribe_to_actions(&self, _room_code: &str) {}
+
+    fn unsubscribe_all(&self) {
+        self.with_inbox(|inbox| {
+            inbox.game_state = None;
+            inbox.actions.clear();
+            inbox.host_actions.clear();
+            inbox.players = None;
+            inbox.emotes.clear();
+            inbox.game_started = false;
+            inbox.randomize_order = false;
+            inbox.locked = false;
+            inbox.kicked = false;
+            inbox.room_settings = None;
+            inbox.setup = None;
+            inbox.rejoin_token = None;
+        });
+    }
+
+    fn unsubscribe_from_game(&self) {
+        self.with_inbox(|inbox| {
+            inbox.game_state = None;
+            inbox.actions.clear();
+            inbox.host_actions.clear();
+            inbox.emotes.clear();
+        });
+    }
+
+    fn write_game_state(&self, _room_code: &str, state_json: String, results: NetworkResults) {
+        match serde_json::from_str(&state_json) {
+            Ok(state) => {
+                self.send(RelayRequest::WriteGameState { state }, Some(NetworkOperation::SyncState), Some(results));
+            }
+            Err(e) => results.send(NetworkOperation::SyncState, Err(NetworkError::Other(e.to_string()))),
+        }
+    }
+
+    fn broadcast_action(&self, _room_code: &str, action_json: String, results: NetworkResults) {
+        match serde_json::from_str(&action_json) {
+            Ok(action) => {
+                self.send(RelayRequest::BroadcastAction { action }, Some(NetworkOperation::SyncState), Some(results));
+            }
+            Err(e) => results.send(NetworkOperation::SyncState, Err(NetworkError::Other(e.to_string()))),
+        }
+    }
+
+    fn submit_action(&self, _room_code: &str, action_json: String, results: NetworkResults) {
+        match serde_json::from_str(&action_json) {
+            Ok(action) => {
+                self.send(RelayRequest::SubmitAction { action }, Some(NetworkOperation::SubmitAction), Some(results));
+            }
+            Err(e) => results.send(NetworkOperation::SubmitAction, Err(NetworkError::Other(e.to_string()))),
+        }
+    }
+
+    fn poll_game_state(&self) -> Option<String> {
+        self.with_inbox(|inbox| inbox.game_state.take()).flatten()
+    }
+
+    fn poll_actions(&self) -> Option<String> {
+        let actions = self.with_inbox(|inbox| std::mem::take(&mut inbox.actions))?;
+        if actions.is_empty() {
+            return None;
+        }
+        serde_json::to_string(&actions).ok()
+    }
+
+    fn poll_host_actions(&self) -> Option<String> {
+        let actions = self.with_inbox(|inbox| std::mem::take(&mut inbox.host_actions))?;
+        if actions.is_empty() {
+            return None;
+        }
+        serde_json::to_string(&actions).ok()
+    }
+
+    fn poll_players(&self) -> Option<String> {
+        self.with_inbox(|inbox| inbox.players.take()).flatten()
+    }
+
+    fn send_emote(&self, _room_code: &str, emote_json: String) {
+        if let Ok(emote) = serde_json::from_str(&emote_json) {
+            self.send(RelayRequest::SendEmote { emote }, None, None);
+        }
+    }
+
+    fn poll_emotes(&self) -> Option<String> {
+        let emotes = self.with_inbox(|inbox| std::mem::take(&mut inbox.emotes))?;
+        if emotes.is_empty() {
+            return None;
+        }
+        serde_json::to_string(&emotes).ok()
+    }
+
+    fn timestamp_ms(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0)
+    }
+}
+
+/// Store an event from the relay until the game polls for it
+fn receive(inbox: &Mutex<Inbox>, event: RelayEvent) {
+    let Ok(mut inbox) = inbox.lock() else { return };
+    match event {
+        RelayEvent::Welcome { player_id } => {
+            info!("Relay server knows us as {}", player_id);
+            inbox.player_id = Some(player_id);
+            if let Some(ref results) = inbox.results {
+                results.send(NetworkOperation::Authenticate, Ok(()));
+            }
+        }
+        RelayEvent::RejoinToken { token } => {
+            inbox.rejoin_token = Some(token);
+        }
+        RelayEvent::Done { operation, error } => {
+            let operation = match operation {
+                RelayOperation::CreateRoom => NetworkOperation::CreateRoom,
+                RelayOperation::JoinRoom => NetworkOperation::JoinRoom,
+                RelayOperation::StartGame => NetworkOperation::StartGame,
+                RelayOperation::SyncState => NetworkOperation::SyncState,
+                RelayOperation::SubmitAction => NetworkOperation::SubmitAction,
+            };
+            if let Some(ref results) = inbox.results {
+                results.send(operation, error.map_or(Ok(()), |message| Err(NetworkError::from_message(&message))));
+            }
+        }
+        RelayEvent::Players { players } => {
+            inbox.players = serde_json::to_string(&players).ok();
+        }
+        RelayEvent::Settings { game_started, randomize_order, locked, room_settings, setup } => {
+            inbox.game_started = game_started;
+            inbox.randomize_order = randomize_order;
+            inbox.locked = locked;
+            inbox.room_settings = room_settings.and_then(|settings| serde_json::to_string(&settings).ok());
+            inbox.setup = setup.and_then(|setup| serde_json::to_string(&setup).ok());
+        }
+        RelayEvent::Kicked => {
+            inbox.kicked = true;
+        }
+        RelayEvent::RoomClosed => {
+            // Waiting players go back to the lobby; in a game, the banner says why it stopped
+            let operation = if inbox.game_started { NetworkOperation::SyncState } else { NetworkOperation::JoinRoom };
+            if let Some(ref results) = inbox.results {
+                results.send(operation, Err(NetworkError::HostLeft));
+            }
+        }
+        RelayEvent::GameState { state } => {
+            inbox.game_state = serde_json::to_string(&state).ok();
+        }
+        RelayEvent::Action { action } => {
+            inbox.actions.push(action);
+        }
+        RelayEvent::HostAction { action } => {
+            inbox.host_actions.push(action);
+        }
+        RelayEvent::Emote { emote } => {
+            inbox.emotes.push(emote);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::network::messages::SerializableGameSetup;
+    use crate::network::state::OnlinePlayerInfo;
+
+    /// Keep checking until `done` holds, failing if the relay takes more than a couple
+    /// of seconds to get there
+    fn wait_for(mut done: impl FnMut() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while !done() {
+            assert!(Instant::now() < deadline, "timed out waiting on the relay");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    /// A player signed in to `relay`, and the results channel their calls report to
+    fn sign_in(relay: &LocalRelay) -> (WebSocketBackend, NetworkResults) {
+        let backend = WebSocketBackend::loopback(relay.clone());
+        let results = NetworkResults::default();
+        backend.authenticate(results.clone());
+        wait_for(|| backend.local_player_id().is_some());
+        (backend, results)
+    }
+
+    /// How the next `operation` reported to `results` turned out
+    fn outcome(results: &NetworkResults, operation: NetworkOperation) -> Result<(), NetworkError> {
+        let mut outcome = None;
+        wait_for(|| {
+            outcome = results.drain().into_iter().find(|r| r.operation == operation).map(|r| r.result);
+            outcome.is_some()
+        });
+        outcome.unwrap()
+    }
+
+    /// Room ABCD, hosted by one player and joined by another
+    fn room(relay: &LocalRelay) -> [(WebSocketBackend, NetworkResults); 2] {
+        let (host, host_results) = sign_in(relay);
+        host.create_room("ABCD", "Host", 0, 0, host_results.clone());
+        assert_eq!(outcome(&host_results, NetworkOperation::CreateRoom), Ok(()));
+
+        let (guest, guest_results) = sign_in(relay);
+        guest.join_room("ABCD", "Guest", 1, 1, guest_results.clone());
+        assert_eq!(outcome(&guest_results, NetworkOperation::JoinRoom), Ok(()));
+        [(host, host_results), (guest, guest_results)]
+    }
+
+    #[test]
+    fn the_host_sees_guests_join_and_guests_hear_when_the_host_leaves() {
+        let relay = LocalRelay::default();
+        let [(host, _), (guest, guest_results)] = room(&relay);
+
+        let mut players = Vec::new();
+        wait_for(|| {
+            if let Some(json) = host.poll_players() {
+                players = serde_json::from_str::<Vec<OnlinePlayerInfo>>(&json).unwrap();
+            }
+            players.len() == 2
+        });
+        assert_eq!(players[1].id, guest.local_player_id().unwrap());
+
+        host.leave_room("ABCD");
+        assert_eq!(outcome(&guest_results, NetworkOperation::JoinRoom), Err(NetworkError::HostLeft));
+    }
+
+    #[test]
+    fn a_dropped_guest_takes_their_seat_back() {
+        let relay = LocalRelay::default();
+        let [(host, host_results), (guest, _)] = room(&relay);
+        let setup = SerializableGameSetup {
+            seed: "1".to_string(),
+            sheikh_variant: false,
+            desert_events: false,
+            relay_race: false,
+            camel_names: Vec::new(),
+            ai_seats: 0,
+            turn_timer_secs: 0,
+            takeover_grace_secs: 0,
+            protocol: PROTOCOL_VERSION,
+        };
+        host.start_game("ABCD", serde_json::to_string(&setup).unwrap(), host_results.clone());
+        assert_eq!(outcome(&host_results, NetworkOperation::StartGame), Ok(()));
+
+        // The guest's connection drops, and they come back on a new one
+        let seat = guest.local_player_id().unwrap();
+        let token = guest.rejoin_token().unwrap();
+        relay.disconnect(&seat);
+
+        // Knowing the seat's id isn't enough to take it
+        let (other, other_results) = sign_in(&relay);
+        other.rejoin_room("ABCD", &seat, None, other_results.clone());
+        assert_eq!(outcome(&other_results, NetworkOperation::JoinRoom), Err(NetworkError::PermissionDenied));
+        other.rejoin_room("ABCD", &seat, Some("not the token"), other_results.clone());
+        assert_eq!(outcome(&other_results, NetworkOperation::JoinRoom), Err(NetworkError::PermissionDenied));
+
+        let (returning, returning_results) = sign_in(&relay);
+        assert_ne!(returning.local_player_id(), Some(seat.clone()));
+        returning.rejoin_room("ABCD", &seat, Some(&token), returning_results.clone());
+        assert_eq!(outcome(&returning_results, NetworkOperation::JoinRoom), Ok(()));
+        assert_eq!(returning.local_player_id(), Some(seat.clone()));
+        assert!(returning.has_game_started());
+        assert_eq!(returning.rejoin_token(), Some(token.clone()));
+
+        // Nobody else can take a seat that's in use, token or not
+        other.rejoin_room("ABCD", &seat, Some(&token), other_results.clone());
+        assert_eq!(outcome(&other_results, NetworkOperation::JoinRoom), Err(NetworkError::PermissionDenied));
+    }
+}
@@ -0,0 +1,75 @@
+// Animation plugin
+// Everything that moves on the board without being a rule: camels hopping and
+// sliding, the pyramid, dice, particles and fireworks, and the overlays drawn over
+// the track (leader crown, race line projection, landing ghosts).
+
+use bevy::prelude::*;
+
+use crate::game::settings::apply_animation_speed;
+use crate::game::state::{GameSet, GameState};
+use crate::systems::animation::{
+    animate_movement_system, animate_multi_step_movement_system, animate_pyramid_hover,
+    animate_pyramid_setup_pulse, animate_pyramid_shake, crown_drop_system, dice_result_popup_system,
+    dice_roll_animation_system, explosion_particle_system, fade_out_system, firework_system,
+    highlight_ring_system, money_delta_popup_system, particle_system, spawn_money_delta_popups,
+    tent_flash_system,
+};
+use crate::systems::movement_preview::update_movement_preview;
+use crate::systems::quality::{detect_quality_tier, hide_drop_shadows, QualityTier};
+use crate::systems::render::{update_leader_crown, update_leader_projection, LeaderProjection};
+use crate::ui::scoring::CelebrationState;
+
+/// Board animations, effects and overlays, at the quality the device can afford
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<QualityTier>()
+            .init_resource::<CelebrationState>()
+            .init_resource::<LeaderProjection>();
+
+        // Adaptive quality for low-end devices (picked once at startup)
+        app.add_systems(Startup, detect_quality_tier)
+            .add_systems(Update, (hide_drop_shadows, apply_animation_speed));
+
+        // Overlays drawn over the track, once the table has moved on this frame
+        app.add_systems(
+            Update,
+            (
+                // Leader race line projection overlay
+                update_leader_projection,
+                // Crown riding on the leading camel
+                update_leader_crown,
+                // Landing ghosts for the hovered camel
+                update_movement_preview,
+            )
+                .in_set(GameSet::Animation)
+                .run_if(in_state(GameState::Playing)),
+        );
+
+        // Animation systems (run in all states for smooth animations)
+        app.add_systems(
+            Update,
+            (
+                animate_movement_system,
+                animate_multi_step_movement_system,
+                animate_pyramid_shake,
+                animate_pyramid_hover,
+                animate_pyramid_setup_pulse,
+                fade_out_system,
+                dice_result_popup_system,
+                // Hands the camel move over to `GameSet::Movement` once the die settles
+                dice_roll_animation_system,
+                particle_system,
+                firework_system,
+                explosion_particle_system,
+                crown_drop_system,
+                highlight_ring_system,
+                tent_flash_system,
+                spawn_money_delta_popups.run_if(in_state(GameState::Playing)),
+                money_delta_popup_system,
+            )
+                .in_set(GameSet::Animation),
+        );
+    }
+}
@@ -0,0 +1,237 @@
+// Camera plugin
+// The camera that draws the board: fitted to the space the HUD leaves for the board,
+// eased in as the setup rolls finish, pinch-zoomed and panned on touch screens, and
+// shaken or punched in by the camera effects.
+
+use bevy::prelude::*;
+
+use crate::components::{BoardPosition, Camel, GameBoard};
+use crate::game::state::{GameSet, GameState};
+use crate::systems::animation::{animate_camera_zoom, CameraZoomAnimation};
+use crate::systems::camera_control::board_touch_camera;
+use crate::systems::camera_effects::{apply_camera_effects, CameraEffects};
+use crate::ui::hud::UiState;
+
+/// The board camera, its fit to the window and its effects
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraState>()
+            .init_resource::<CameraEffects>()
+            .add_systems(Startup, setup_camera)
+            // Runs every frame to handle window resizing, after the table has moved on
+            .add_systems(
+                Update,
+                (
+                    scale_camera_to_fit,
+                    // Pinch-zoom and drag-pan of the board on touch screens, then shake and zoom punches
+                    (board_touch_camera, apply_camera_effects).chain().after(scale_camera_to_fit),
+                    animate_camera_zoom,
+                )
+                    .in_set(GameSet::Animation),
+            );
+    }
+}
+
+/// Resource to track camera state for zoom transitions
+#[derive(Resource)]
+pub struct CameraState {
+    /// Tracks the previous value of initial_rolls_complete to detect transitions
+    last_initial_rolls_complete: bool,
+    /// Whether the view was last widened to a relay track's start spaces
+    last_relay_start: bool,
+    /// How far the player has pinched in on top of the fitted view (1.0 = fitted)
+    pub user_zoom: f32,
+    /// Where the player has dragged the camera to, in world units from the fitted view
+    pub user_pan: Vec2,
+    /// Brief extra zoom from a camera effect (1.0 = none), see `camera_effects`
+    pub punch_zoom: f32,
+}
+
+impl Default for CameraState {
+    fn default() -> Self {
+        Self {
+            last_initial_rolls_complete: false,
+            last_relay_start: false,
+            user_zoom: 1.0,
+            user_pan: Vec2::ZERO,
+            punch_zoom: 1.0,
+        }
+    }
+}
+
+impl CameraState {
+    /// Put the camera back to the fitted view
+    pub fn reset_user_view(&mut self) {
+        self.user_zoom = 1.0;
+        self.user_pan = Vec2::ZERO;
+    }
+
+    /// Zoom on top of the fitted scale: the player's pinch and any zoom punch
+    pub fn zoom(&self) -> f32 {
+        self.user_zoom * self.punch_zoom
+    }
+}
+
+/// Marker for the camera that draws the board, so the game never grabs a host app's cameras
+#[derive(Component)]
+pub struct GameCamera;
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera2d, GameCamera));
+}
+
+// Game board design dimensions
+const GAME_WORLD_HEIGHT: f32 = 400.0; // Board height + stack space + margins
+
+// Board layout constants
+const BOARD_START_X: f32 = -280.0; // X position of space 0
+const BOARD_SPACING: f32 = 80.0; // Distance between spaces
+const BOARD_MARGIN: f32 = 102.0; // Margin on each side (reduced for mobile breathing room)
+
+/// Calculate the X range of the board that should be visible based on game state
+/// Returns (min_x, max_x) in world coordinates
+fn calculate_visible_board_range(
+    ui_state: &UiState,
+    camels: &Query<&BoardPosition, With<Camel>>,
+    current_game_state: &GameState,
+) -> (f32, f32) {
+    // Default: show spaces 1-16 (main track, not space 0)
+    let default_min = BOARD_START_X + BOARD_SPACING; // -200 (space 1)
+    let default_max = BOARD_START_X + 7.0 * BOARD_SPACING; // +280 (space 7/8)
+
+    // Check if any camel is at space 0 (initial setup)
+    let has_camel_at_start = camels.iter().any(|p| p.space_index == 0);
+
+    // Check if game has ended (a camel crossed the finish line)
+    // Note: We use GameState::GameEnd instead of checking space_index >= 16
+    // because space_index is clamped to 15 in the movement system
+    let has_camel_past_finish = matches!(current_game_state, GameState::GameEnd);
+
+    // Also check if we're still in initial setup
+    let in_initial_setup = !ui_state.initial_rolls_complete;
+
+    // Determine min_x based on state
+    let min_x = if has_camel_past_finish {
+        -400.0 // Include winner position at -360 with margin
+    } else if has_camel_at_start || in_initial_setup {
+        -500.0 // Include staging position at -450 with margin
+    } else {
+        default_min // -200, skip space 0
+    };
+
+    (min_x, default_max)
+}
+
+/// System to scale the camera's orthographic projection to fit the game board
+/// Uses the measured game_board_rect from egui CentralPanel for accurate sizing
+/// Dynamically adjusts visible range based on game state
+/// Triggers smooth zoom animation when initial rolls complete
+fn scale_camera_to_fit(
+    mut camera_query: Query<
+        (Entity, &mut Projection, Option<&CameraZoomAnimation>),
+        With<GameCamera>,
+    >,
+    ui_state: Res<UiState>,
+    camels: Query<&BoardPosition, With<Camel>>,
+    mut camera_state: ResMut<CameraState>,
+    mut commands: Commands,
+    current_game_state: Res<State<GameState>>,
+    board: Option<Res<GameBoard>>,
+) {
+    let Ok((entity, mut projection, animation)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let Some(rect) = ui_state.game_board_rect else {
+        return;
+    };
+
+    let effective_width = rect.width();
+    let effective_height = rect.height();
+
+    if effective_width <= 0.0 || effective_height <= 0.0 {
+        return;
+    }
+
+    // Relay Race: after a handoff, keep the new track's first space in view
+    // until every camel has left it
+    let relay_start = board.as_ref().is_some_and(|board| board.active_track > 0)
+        && camels.iter().any(|p| p.space_index == 0);
+
+    // 1. Calculate the authoritative target based on state
+    let (world_min_x, world_max_x) = if ui_state.initial_rolls_complete {
+        let min_x = if relay_start { BOARD_START_X } else { BOARD_START_X + BOARD_SPACING };
+        (min_x, BOARD_START_X + 7.0 * BOARD_SPACING)
+    } else {
+        calculate_visible_board_range(ui_state.as_ref(), &camels, current_game_state.get())
+    };
+
+    let world_width = (world_max_x - world_min_x) + 2.0 * BOARD_MARGIN;
+    let scale_x = world_width / effective_width;
+    let scale_y = GAME_WORLD_HEIGHT / effective_height;
+    let target_scale = scale_x.max(scale_y).max(1.0);
+
+    // 2. Handle Transition
+    if ui_state.initial_rolls_complete && !camera_state.last_initial_rolls_complete {
+        if let Projection::Orthographic(ref ortho) = *projection {
+            // The scale before the player's pinch zoom and any punch, which stay on top of the animation
+            let current_scale = ortho.scale * camera_state.zoom();
+
+            if (current_scale - target_scale).abs() > 0.01 {
+                bevy::log::info!(
+                    "Starting zoom animation: {:.3} -> {:.3}",
+                    current_scale,
+                    target_scale
+                );
+                commands.entity(entity).insert(CameraZoomAnimation::new(
+                    current_scale,
+                    target_scale,
+                    0.2,
+                ));
+            }
+        }
+        camera_state.last_initial_rolls_complete = true;
+
+        // --- CRITICAL FIX ---
+        // Return immediately! Do not fall through to the steady-state logic below.
+        // The 'CameraZoomAnimation' component won't exist on the entity until
+        // the next frame (Commands are deferred), so 'animation.is_some()'
+        // below would be false, causing an instant snap if we didn't return here.
+        return;
+    }
+
+    // Reset tracking when returning to initial setup
+    if !ui_state.initial_rolls_complete && camera_state.last_initial_rolls_complete {
+        camera_state.last_initial_rolls_complete = false;
+    }
+
+    // Ease between tracks instead of snapping when a relay handoff changes the view
+    if relay_start != camera_state.last_relay_start {
+        camera_state.last_relay_start = relay_start;
+        if let Projection::Orthographic(ref ortho) = *projection {
+            let current_scale = ortho.scale * camera_state.zoom();
+            if (current_scale - target_scale).abs() > 0.01 {
+                commands.entity(entity).insert(CameraZoomAnimation::new(
+                    current_scale,
+                    target_scale,
+                    0.4,
+                ));
+            }
+        }
+        return;
+    }
+
+    // 3. Steady State Application
+    // If animation is running, do nothing (animation system handles it)
+    if animation.is_some() {
+        return;
+    }
+
+    // Otherwise, apply target immediately, zoomed in as far as the player has pinched
+    // (and any zoom punch)
+    if let Projection::Orthographic(ref mut ortho) = *projection {
+        ortho.scale = target_scale / camera_state.zoom();
+    }
+}
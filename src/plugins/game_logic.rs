@@ -0,0 +1,309 @@
+// Game logic plugin
+// The table itself: game states, the rules, turns, the AI, scoring, and everything the
+// game keeps between runs (settings, autosave, replays, stats and achievements).
+
+use bevy::prelude::*;
+
+use crate::components::{self, CamelNames, CamelPalette, SheikhBettor};
+use crate::game::ai::{ai_decision_system, AiConfig, AiSimulation, AiThinkTimer};
+use crate::game::ai_weights::{apply_ai_weights, load_ai_weights, AiWeights, AiWeightsLoader};
+use crate::game::autosave::{autosave_game, forget_finished_game, load_autosave, Autosave};
+use crate::game::desert_events::ActiveEvent;
+use crate::game::key_bindings::KeyBindings;
+use crate::game::leaderboard::{load_leaderboard, Leaderboard};
+use crate::game::log::{track_money_changes, GameLog, MoneyTimeline};
+use crate::game::relay::{relay_handoff_system, RelayRace};
+use crate::game::replay::{end_replay, feed_replay, load_last_replay, record_actions, save_replay, ReplayViewer};
+use crate::game::rules::{update_reminders, ActiveReminders};
+use crate::game::settings::{load_settings, save_settings, Settings};
+use crate::game::state::{no_suspended_game, GameSet, GameState, SuspendedGame};
+use crate::game::stats::{load_player_stats, record_player_stats, PlayerStats};
+use crate::network;
+use crate::systems::achievements::{load_achievements, track_achievements, Achievements};
+use crate::systems::autoplay::{autoplay_system, end_autoplay, AutoplayConfig};
+use crate::systems::leg::{calculate_final_scores, start_next_leg_system, StartNextLegAction};
+use crate::systems::leg_recap::{leg_recap_system, reset_leg_recap, LegRecap};
+use crate::systems::movement::{
+    move_camel_system, move_crazy_camel_system, MoveCamelEvent, MoveCrazyCamelEvent,
+    MovementCompleteEvent,
+};
+use crate::systems::quality::QualityTier;
+use crate::systems::setup::{
+    cleanup_game, hide_setup_instructions_system, initial_roll_animation_system, setup_game, SetupAssets,
+};
+use crate::systems::speech::SpeechSettings;
+use crate::systems::stress_test::{end_stress_test, stress_test_system, StressTest};
+use crate::systems::turn::{
+    advance_turn_system, check_game_end_system, check_leg_end_system, game_end_delay_system,
+    handle_concede_action, handle_convert_to_ai_action, handle_leg_bet_action, handle_pyramid_click,
+    handle_pyramid_hover, handle_pyramid_roll_action, handle_race_bet_action, handle_return_seat_action,
+    handle_board_space_clicks, handle_spectator_tile_action, handle_spectator_tile_clicks,
+    handle_turn_timed_out_action, tint_board_spaces_for_placement, update_spectator_tile_sprites,
+    ConcedeAction, ConvertToAiAction, CrazyCamelRollResult, PlaceRaceBetAction,
+    PlaceSpectatorTileAction, PyramidRollResult, ReturnSeatAction, RollPyramidAction, TakeLegBetAction,
+    TurnTimedOutAction,
+};
+use crate::ui::hud::UiState;
+use crate::ui::player_setup::PlayerSetupConfig;
+use crate::ui::scoring::setup_game_end_state;
+use crate::QuitRequested;
+
+/// States, rules, turns, AI and scoring. Needs [`AnimationPlugin`](super::AnimationPlugin)
+/// and [`CameraPlugin`](super::CameraPlugin): camels only finish moving once animated.
+pub struct GameLogicPlugin;
+
+impl Plugin for GameLogicPlugin {
+    fn build(&self, app: &mut App) {
+        // Game states
+        app.init_state::<GameState>()
+            // Resources
+            // (`UiState` is the table's shared state - selection, setup progress, the
+            // last roll - so it lives here even though the HUD draws most of it)
+            .init_resource::<UiState>()
+            .init_resource::<PlayerSetupConfig>()
+            .init_resource::<AiConfig>()
+            .init_resource::<AiWeights>()
+            .init_resource::<AiThinkTimer>()
+            .init_resource::<AiSimulation>()
+            .init_resource::<StressTest>()
+            .init_resource::<AutoplayConfig>()
+            .init_resource::<SuspendedGame>()
+            .init_resource::<Autosave>()
+            .init_resource::<PlayerStats>()
+            .init_resource::<Leaderboard>()
+            .init_resource::<Achievements>()
+            .init_resource::<ReplayViewer>()
+            .init_resource::<LegRecap>()
+            .init_resource::<GameLog>()
+            .init_resource::<MoneyTimeline>()
+            .init_resource::<CamelNames>()
+            .init_resource::<SheikhBettor>()
+            .init_resource::<ActiveEvent>()
+            .init_resource::<RelayRace>()
+            .init_resource::<ActiveReminders>()
+            .init_resource::<SpeechSettings>()
+            .init_resource::<Settings>()
+            .init_resource::<CamelPalette>()
+            .init_resource::<KeyBindings>()
+            // Messages
+            .add_message::<MoveCamelEvent>()
+            .add_message::<MoveCrazyCamelEvent>()
+            .add_message::<MovementCompleteEvent>()
+            .add_message::<TakeLegBetAction>()
+            .add_message::<PlaceSpectatorTileAction>()
+            .add_message::<RollPyramidAction>()
+            .add_message::<PlaceRaceBetAction>()
+            .add_message::<ConcedeAction>()
+            .add_message::<ConvertToAiAction>()
+            .add_message::<ReturnSeatAction>()
+            .add_message::<TurnTimedOutAction>()
+            .add_message::<StartNextLegAction>()
+            .add_message::<QuitRequested>()
+            .add_message::<PyramidRollResult>()
+            .add_message::<CrazyCamelRollResult>();
+
+        // AI weights, read from assets so they can be tuned without recompiling
+        app.init_asset::<AiWeights>()
+            .register_asset_loader(AiWeightsLoader)
+            .add_systems(Startup, load_ai_weights)
+            .add_systems(Update, apply_ai_weights);
+
+        // Local games are saved after every turn, to continue from the main menu
+        app.add_systems(Startup, load_autosave)
+            .add_systems(
+                Update,
+                autosave_game
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(resource_exists::<components::Players>),
+            )
+            .add_systems(OnEnter(GameState::GameEnd), forget_finished_game);
+
+        // Every game is recorded, and the last one can be watched again
+        app.add_systems(Startup, load_last_replay)
+            .add_systems(
+                Update,
+                (
+                    record_actions.in_set(GameSet::Scoring),
+                    feed_replay.in_set(GameSet::Input).run_if(in_state(GameState::Playing)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::GameEnd), save_replay)
+            .add_systems(OnEnter(GameState::MainMenu), end_replay);
+
+        // Players' results are tallied across games, for the main menu's Stats page
+        app.add_systems(Startup, load_player_stats)
+            .add_systems(
+                Update,
+                record_player_stats.in_set(GameSet::Scoring).run_if(in_state(GameState::GameEnd)),
+            );
+
+        // The best final scores, added on the final results screen
+        app.add_systems(Startup, load_leaderboard);
+
+        // Achievements unlock as the game log grows
+        app.add_systems(Startup, load_achievements).add_systems(
+            Update,
+            track_achievements
+                .in_set(GameSet::Scoring)
+                .after(track_money_changes)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::GameEnd))),
+        );
+
+        // Player preferences, kept between runs
+        app.add_systems(Startup, load_settings)
+            .add_systems(Update, save_settings);
+
+        // Game setup when entering Playing state
+        app.add_systems(OnEnter(GameState::Playing), setup_game_with_resources)
+            // A rematch comes back to the waiting room from the last game's results
+            .add_systems(OnEnter(GameState::WaitingRoom), cleanup_game.run_if(no_suspended_game))
+            // Game logic systems, one stage of the turn after another. Resolving actions,
+            // scoring and the AI's thinking time run on the fixed timestep, so delays and
+            // timers don't depend on the frame rate. Input, camel moves, the leg recap's
+            // camera sweep and the animations run every frame, so motion stays smooth.
+            // (`FixedUpdate` runs before `Update` each frame, so whatever moves always
+            // starts from the latest table.)
+            .configure_sets(
+                Update,
+                (
+                    GameSet::Input,
+                    GameSet::Resolve,
+                    GameSet::Movement,
+                    GameSet::Scoring,
+                    GameSet::Animation,
+                )
+                    .chain(),
+            )
+            .configure_sets(
+                FixedUpdate,
+                (
+                    GameSet::Input,
+                    GameSet::Resolve,
+                    GameSet::Movement,
+                    GameSet::Scoring,
+                    GameSet::Animation,
+                )
+                    .chain(),
+            )
+            // Clicks on the board, and the stress test's rolls
+            .add_systems(
+                Update,
+                (
+                    handle_pyramid_click,
+                    handle_pyramid_hover,
+                    handle_spectator_tile_clicks,
+                    // Before placement clears the selection, so placing a tile doesn't also inspect
+                    handle_board_space_clicks.before(handle_spectator_tile_clicks),
+                    stress_test_system,
+                )
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // AI decision system - runs when it's an AI player's turn
+            .add_systems(
+                FixedUpdate,
+                ai_decision_system
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // "Watch AI Game" autoplay
+            .add_systems(
+                Update,
+                autoplay_system
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::GameEnd))),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    handle_leg_bet_action,
+                    handle_pyramid_roll_action,
+                    handle_race_bet_action,
+                    handle_concede_action,
+                    handle_convert_to_ai_action,
+                    handle_return_seat_action,
+                    // Noted in the log before the roll made for the player
+                    handle_turn_timed_out_action.before(handle_pyramid_roll_action),
+                    handle_spectator_tile_action,
+                )
+                    .in_set(GameSet::Resolve)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (move_camel_system, move_crazy_camel_system, initial_roll_animation_system)
+                    .in_set(GameSet::Movement)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    advance_turn_system,
+                    check_leg_end_system,
+                    (relay_handoff_system, check_game_end_system).chain(),
+                    game_end_delay_system,
+                    // Use-it-or-lose-it reminders for the current player
+                    update_reminders,
+                )
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Recap between the last roll of a leg and its scoring (it sweeps the camera)
+            .add_systems(
+                Update,
+                leg_recap_system
+                    .in_set(GameSet::Animation)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnExit(GameState::Playing), reset_leg_recap)
+            // The leg scored and the table cleared once the scoring modal is dismissed
+            .add_systems(
+                Update,
+                start_next_leg_system
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Money changes are logged against the action that caused them
+            .add_systems(
+                Update,
+                track_money_changes
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::GameEnd))),
+            )
+            // Board highlights for the spectator tile being placed, and the setup hints
+            .add_systems(
+                Update,
+                (
+                    update_spectator_tile_sprites,
+                    tint_board_spaces_for_placement,
+                    hide_setup_instructions_system,
+                )
+                    .in_set(GameSet::Animation)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Game end scoring
+            .add_systems(
+                OnEnter(GameState::GameEnd),
+                (calculate_final_scores, setup_game_end_state),
+            )
+            // Cleanup when returning to main menu (unless the game was saved to resume)
+            .add_systems(
+                OnEnter(GameState::MainMenu),
+                (cleanup_game.run_if(no_suspended_game), end_stress_test, end_autoplay),
+            );
+    }
+}
+
+/// Setup game with additional resources for turn tracking
+fn setup_game_with_resources(
+    commands: Commands,
+    config: Res<PlayerSetupConfig>,
+    existing_camels: Query<Entity, With<components::Camel>>,
+    assets: SetupAssets,
+    quality: Res<QualityTier>,
+    network_state: Res<network::state::NetworkState>,
+    camel_palette: Res<CamelPalette>,
+) {
+    setup_game(commands, config, existing_camels, assets, quality, network_state, camel_palette);
+}
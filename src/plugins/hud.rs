@@ -0,0 +1,241 @@
+// HUD plugin
+// What the player sees and hears around the board: egui and its fonts, theme and
+// scale, the in-game panels and dialogs, the results screen, spoken commentary,
+// music and sound effects. A table run without anyone watching can leave it out.
+
+use bevy::audio::AddAudioSource;
+use bevy::prelude::*;
+use bevy_egui::{EguiPlugin, EguiPrimaryContextPass};
+
+use crate::game::replay::replay_controls_ui;
+use crate::game::settings::{apply_master_volume, Settings};
+use crate::game::state::{GameSet, GameState};
+use crate::systems::crowd::{crowd_ambience, CrowdAudio};
+use crate::systems::music::{crossfade_music, switch_music, Soundtrack};
+use crate::systems::quality::apply_ui_quality;
+use crate::systems::sound_effects::{play_celebration_fanfare, SoundEffectAudio};
+use crate::systems::speech::{announce_game_log, speak_game_log, Announcement, Speaker};
+use crate::systems::turn_alert::{alert_local_turn, reset_turn_alert, TurnAlert};
+use crate::ui;
+use crate::ui::achievements::achievement_toast_ui;
+use crate::ui::game_log::{game_log_window_ui, turn_history_panel_ui};
+use crate::ui::hud::{
+    desert_event_banner_ui, game_hud_ui, leg_scoring_modal_ui, money_delta_popups_ui, network_error_banner_ui,
+    resync_toast_ui, turn_timer_ui,
+    probability_panel_ui, relay_track_banner_ui, write_retry_banner_ui,
+    update_camel_position_animations, update_dice_popup_timer, update_ui_on_crazy_roll,
+    update_ui_on_roll, CamelPositionAnimations,
+};
+use crate::ui::odds::{leg_odds_panel_ui, update_race_odds, RaceOdds};
+use crate::ui::quit_dialog::{arm_leave_guard, disarm_leave_guard, quit_dialog_ui};
+use crate::ui::rules::RulesState;
+use crate::ui::scoring::game_end_ui;
+use crate::ui::space_inspector::space_inspector_ui;
+use crate::ui::theme::{apply_contrast_theme, configure_fonts, FontsConfigured};
+use crate::ui::turn_banner::{spawn_turn_banner, turn_banner_ui};
+
+/// In-game UI, results screen, speech and audio. Adds `EguiPlugin` itself unless the
+/// host app already has it.
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin::default());
+        }
+
+        app.init_resource::<CamelPositionAnimations>()
+            .init_resource::<RaceOdds>()
+            .init_resource::<RulesState>()
+            .init_resource::<FontsConfigured>()
+            .init_resource::<Speaker>()
+            .init_resource::<Announcement>();
+
+        // UI scaling - runs every frame to handle window resizing
+        // Font configuration also runs in Update but only configures once
+        app.add_systems(
+            Update,
+            (scale_ui_to_fit, configure_fonts, apply_contrast_theme, apply_ui_quality),
+        );
+
+        // Read the game log aloud when spoken commentary is on, and announce it to screen readers
+        app.add_systems(Update, (speak_game_log, announce_game_log));
+
+        // Background music, crossfaded between the menus, play and the game end, the crowd
+        // around the track, and sound effects (only when the host app plays audio)
+        if app.is_plugin_added::<bevy::audio::AudioPlugin>() {
+            app.add_audio_source::<Soundtrack>()
+                .add_audio_source::<SoundEffectAudio>()
+                .add_audio_source::<CrowdAudio>()
+                .add_systems(Update, (apply_master_volume, switch_music, crossfade_music).chain())
+                .add_systems(Update, (crowd_ambience.after(apply_master_volume), play_celebration_fanfare));
+        }
+
+        // Chime and flash the tab title when an online game's turn comes to the local player
+        app.init_resource::<TurnAlert>()
+            .add_systems(Update, alert_local_turn.run_if(in_state(GameState::Playing)))
+            .add_systems(OnExit(GameState::Playing), reset_turn_alert);
+
+        // Developer state machine overlay (F10 unless rebound, built with --features state_overlay)
+        #[cfg(feature = "state_overlay")]
+        app.init_resource::<ui::state_overlay::StateOverlay>()
+            .add_systems(Update, ui::state_overlay::toggle_state_overlay)
+            .add_systems(EguiPrimaryContextPass, ui::state_overlay::state_overlay_ui);
+
+        // Developer network overlay (F3 unless rebound, built with --features network_overlay)
+        #[cfg(feature = "network_overlay")]
+        app.init_resource::<ui::network_overlay::NetworkOverlay>()
+            .add_systems(Update, ui::network_overlay::toggle_network_overlay)
+            .add_systems(EguiPrimaryContextPass, ui::network_overlay::network_overlay_ui);
+
+        // Replay controls and achievement toasts, over the board
+        app.add_systems(EguiPrimaryContextPass, replay_controls_ui.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                EguiPrimaryContextPass,
+                achievement_toast_ui.run_if(in_state(GameState::Playing).or(in_state(GameState::GameEnd))),
+            );
+
+        // Whose turn it is, over the middle of the board
+        app.add_systems(OnEnter(GameState::Playing), spawn_turn_banner)
+            // UI systems (egui context pass)
+            .add_systems(
+                EguiPrimaryContextPass,
+                game_hud_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                leg_scoring_modal_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                desert_event_banner_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                relay_track_banner_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                network_error_banner_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                resync_toast_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                write_retry_banner_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                turn_timer_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                game_end_ui.run_if(in_state(GameState::GameEnd)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                leg_odds_panel_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                quit_dialog_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                probability_panel_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                game_log_window_ui
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::GameEnd))),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                turn_history_panel_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                space_inspector_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                turn_banner_ui.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                money_delta_popups_ui.run_if(in_state(GameState::Playing)),
+            )
+            // Dice popups, the standings' slide as camels overtake, and the leg and race
+            // odds for the odds panels, once the table has moved on this frame
+            .add_systems(
+                Update,
+                (
+                    update_ui_on_roll,
+                    update_ui_on_crazy_roll,
+                    update_dice_popup_timer,
+                    update_camel_position_animations,
+                    update_race_odds,
+                )
+                    .in_set(GameSet::Animation)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Confirm before closing the browser tab mid-game
+            .add_systems(OnEnter(GameState::Playing), arm_leave_guard)
+            .add_systems(OnExit(GameState::Playing), disarm_leave_guard);
+    }
+}
+
+// Design resolution - the game is designed for these sizes
+const DESIGN_WIDTH: f32 = 1280.0;
+const DESIGN_HEIGHT: f32 = 720.0;
+const MOBILE_DESIGN_WIDTH: f32 = 400.0;
+const MOBILE_DESIGN_HEIGHT: f32 = 600.0;
+
+// Layout thresholds
+const MIN_SIDE_PANEL_WIDTH: f32 = 600.0; // Minimum width to use side panels
+const SIDE_PANEL_ASPECT_RATIO: f32 = 1.2; // Minimum aspect ratio for side panels
+
+/// System to scale the entire UI (egui) based on window size
+/// Uses aspect ratio to determine layout (side panels vs top/bottom)
+/// Scales UI based on both width and height constraints
+fn scale_ui_to_fit(
+    mut egui_contexts: Query<&mut bevy_egui::EguiContextSettings>,
+    windows: Query<&Window>,
+    mut ui_state: ResMut<ui::hud::UiState>,
+    settings: Res<Settings>,
+) {
+    let Ok(window) = windows.single() else { return };
+
+    let window_width = window.width();
+    let window_height = window.height();
+
+    if window_width <= 0.0 || window_height <= 0.0 {
+        return;
+    }
+
+    // Determine layout based on aspect ratio and minimum width
+    let aspect_ratio = window_width / window_height;
+    let use_side_panels =
+        aspect_ratio > SIDE_PANEL_ASPECT_RATIO && window_width >= MIN_SIDE_PANEL_WIDTH;
+    ui_state.use_side_panels = use_side_panels;
+
+    // Calculate UI scale with height constraint
+    let scale = if use_side_panels {
+        // Landscape: scale based on smaller dimension ratio
+        let scale_x = window_width / DESIGN_WIDTH;
+        let scale_y = window_height / DESIGN_HEIGHT;
+        (scale_x.min(scale_y) * 0.95).clamp(0.5, 1.5)
+    } else {
+        // Portrait: scale based on width, but cap by height
+        let width_scale = window_width / MOBILE_DESIGN_WIDTH;
+        let height_scale = window_height / MOBILE_DESIGN_HEIGHT;
+        width_scale.min(height_scale).max(1.0)
+    };
+
+    // Set egui's scale factor for all contexts, adjusted by the player's UI scale
+    for mut egui_settings in egui_contexts.iter_mut() {
+        egui_settings.scale_factor = scale * settings.ui_scale;
+    }
+}
@@ -0,0 +1,51 @@
+// Menu plugin
+// The screens around a game: the main menu and its pages (settings, stats,
+// leaderboard, trophies), and the online lobby and waiting room.
+
+use bevy::prelude::*;
+use bevy_egui::EguiPrimaryContextPass;
+
+use crate::game::key_bindings::capture_key_binding;
+use crate::game::state::GameState;
+use crate::ui::achievements::TrophyGalleryState;
+use crate::ui::leaderboard::LeaderboardPageState;
+use crate::ui::lobby::{cleanup_lobby, lobby_ui, waiting_room_ui, LobbyState};
+use crate::ui::main_menu::main_menu_ui;
+use crate::ui::stats::StatsPageState;
+use crate::GameConfig;
+
+/// Main menu, lobby and waiting room. Drawn with the egui setup, fonts and speech of
+/// [`HudPlugin`](super::HudPlugin).
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameConfig>()
+            .init_resource::<LobbyState>()
+            .init_resource::<StatsPageState>()
+            .init_resource::<LeaderboardPageState>()
+            .init_resource::<TrophyGalleryState>();
+
+        // Joining straight from an invite link opened in the browser
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(Startup, crate::ui::lobby::open_invite_link);
+
+        // Rebinding keys on the settings page
+        app.add_systems(Update, capture_key_binding);
+
+        app.add_systems(
+            EguiPrimaryContextPass,
+            main_menu_ui.run_if(in_state(GameState::MainMenu)),
+        )
+        .add_systems(
+            EguiPrimaryContextPass,
+            lobby_ui.run_if(in_state(GameState::Lobby)),
+        )
+        .add_systems(
+            EguiPrimaryContextPass,
+            waiting_room_ui.run_if(in_state(GameState::WaitingRoom)),
+        )
+        .add_systems(OnExit(GameState::Lobby), cleanup_lobby)
+        .add_systems(OnExit(GameState::WaitingRoom), cleanup_lobby);
+    }
+}
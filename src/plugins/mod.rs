@@ -0,0 +1,17 @@
+// Domain plugins
+// `GamePlugin` is assembled from these, each registering the resources, messages and
+// systems of one part of the game. The table itself (`GameLogicPlugin`) can't run
+// without its animations and camera, but the HUD and the menus are optional, so an app
+// can leave out what it doesn't draw.
+
+pub mod game_logic;
+pub mod animation;
+pub mod camera;
+pub mod hud;
+pub mod menu;
+
+pub use animation::AnimationPlugin;
+pub use camera::CameraPlugin;
+pub use game_logic::GameLogicPlugin;
+pub use hud::HudPlugin;
+pub use menu::MenuPlugin;
@@ -0,0 +1,373 @@
+// Achievements
+// Milestones players unlock by how they play: winning with nothing but leg bets,
+// calling the loser first, going broke... The game log is followed as it grows, a
+// tally is kept for each seat played at this device, and achievements are checked
+// as the tallies change and again once the final results are up. Unlocks are kept
+// between runs with the stats, pop up as toasts and fill the main menu's trophy
+// gallery.
+
+use std::collections::{BTreeMap, HashMap};
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::{Players, SheikhBettor};
+use crate::game::ai::{AiConfig, AiDifficulty};
+use crate::game::desert_events::ActiveEvent;
+use crate::game::log::{GameEvent, GameLog, GameLogEntry};
+use crate::game::relay::RelayRace;
+use crate::game::stats::plays_here;
+use crate::game::storage;
+use crate::network::state::NetworkState;
+use crate::systems::autoplay::AutoplayConfig;
+use crate::ui::scoring::{GameEndPhase, GameEndState};
+
+/// Name the unlocked achievements are kept under in `storage`
+const ACHIEVEMENTS_NAME: &str = "achievements";
+
+/// Seconds an unlock toast stays up
+pub const TOAST_SECS: f64 = 4.0;
+
+/// Payout of the first correct winner or loser bet
+const FIRST_RACE_BET_PAYOUT: i32 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Achievement {
+    FirstWin,
+    LegBetsOnly,
+    CalledTheLoser,
+    CalledTheWinner,
+    BothCalls,
+    Broke,
+    HighRoller,
+    Landslide,
+    PhotoFinish,
+    Payday,
+    PyramidScheme,
+    HandsOff,
+    Madness,
+    TouristTrap,
+    Oops,
+    StormChaser,
+    AnchorLeg,
+    OutfoxedTheSheikh,
+    Globetrotter,
+    GiantSlayer,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 20] = [
+        Achievement::FirstWin,
+        Achievement::LegBetsOnly,
+        Achievement::CalledTheLoser,
+        Achievement::CalledTheWinner,
+        Achievement::BothCalls,
+        Achievement::Broke,
+        Achievement::HighRoller,
+        Achievement::Landslide,
+        Achievement::PhotoFinish,
+        Achievement::Payday,
+        Achievement::PyramidScheme,
+        Achievement::HandsOff,
+        Achievement::Madness,
+        Achievement::TouristTrap,
+        Achievement::Oops,
+        Achievement::StormChaser,
+        Achievement::AnchorLeg,
+        Achievement::OutfoxedTheSheikh,
+        Achievement::Globetrotter,
+        Achievement::GiantSlayer,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Achievement::FirstWin => "First Victory",
+            Achievement::LegBetsOnly => "Leg Work",
+            Achievement::CalledTheLoser => "Called It",
+            Achievement::CalledTheWinner => "Prophet",
+            Achievement::BothCalls => "Fortune Teller",
+            Achievement::Broke => "Flat Broke",
+            Achievement::HighRoller => "High Roller",
+            Achievement::Landslide => "Landslide",
+            Achievement::PhotoFinish => "Photo Finish",
+            Achievement::Payday => "Payday",
+            Achievement::PyramidScheme => "Pyramid Scheme",
+            Achievement::HandsOff => "Hands Off",
+            Achievement::Madness => "Madness",
+            Achievement::TouristTrap => "Tourist Trap",
+            Achievement::Oops => "Oops",
+            Achievement::StormChaser => "Storm Chaser",
+            Achievement::AnchorLeg => "Anchor Leg",
+            Achievement::OutfoxedTheSheikh => "Outfoxed the Sheikh",
+            Achievement::Globetrotter => "Globetrotter",
+            Achievement::GiantSlayer => "Giant Slayer",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Achievement::FirstWin => "Win a game",
+            Achievement::LegBetsOnly => "Win with only leg bets: no race bets and no spectator tile",
+            Achievement::CalledTheLoser => "Be the first to bet on the overall loser",
+            Achievement::CalledTheWinner => "Be the first to bet on the overall winner",
+            Achievement::BothCalls => "Bet right on both the overall winner and loser in one game",
+            Achievement::Broke => "Finish a game with $0",
+            Achievement::HighRoller => "Finish a game with $40 or more",
+            Achievement::Landslide => "Win by $10 or more",
+            Achievement::PhotoFinish => "Win on a tiebreaker",
+            Achievement::Payday => "Make $10 or more in a single leg",
+            Achievement::PyramidScheme => "Roll the pyramid 10 times in one game",
+            Achievement::HandsOff => "Finish a game without rolling the pyramid",
+            Achievement::Madness => "Roll the crazy camel die",
+            Achievement::TouristTrap => "Get paid by your spectator tile 5 times in one game",
+            Achievement::Oops => "Get 3 race bets wrong in one game",
+            Achievement::StormChaser => "Win a game with Desert Events",
+            Achievement::AnchorLeg => "Win a Relay Race",
+            Achievement::OutfoxedTheSheikh => "Win a 2-player game against the Sheikh",
+            Achievement::Globetrotter => "Win an online game",
+            Achievement::GiantSlayer => "Win against a Hard AI",
+        }
+    }
+}
+
+/// Who unlocked an achievement first
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Unlock {
+    pub player: String,
+}
+
+/// An unlock being shown as a toast
+#[derive(Clone, Debug)]
+pub struct AchievementToast {
+    pub achievement: Achievement,
+    pub player: String,
+    /// `Time::elapsed_secs_f64` when it was unlocked
+    pub shown_at: f64,
+}
+
+/// Achievements unlocked on this device, and the toasts announcing new ones
+#[derive(Resource, Default)]
+pub struct Achievements {
+    pub unlocked: BTreeMap<Achievement, Unlock>,
+    pub toasts: Vec<AchievementToast>,
+}
+
+impl Achievements {
+    /// Unlock an achievement for a player, unless it already is. Returns whether it's new.
+    fn unlock(&mut self, achievement: Achievement, player: &str, now: f64) -> bool {
+        if self.unlocked.contains_key(&achievement) {
+            return false;
+        }
+        info!("{} unlocked the \"{}\" achievement", player, achievement.name());
+        self.unlocked.insert(achievement, Unlock { player: player.to_string() });
+        self.toasts.push(AchievementToast { achievement, player: player.to_string(), shown_at: now });
+        true
+    }
+}
+
+/// One seat's doings in the game so far
+#[derive(Default)]
+struct SeatTally {
+    rolls: u32,
+    crazy_rolls: u32,
+    race_bets: u32,
+    spectator_tiles: u32,
+    spectator_payouts: u32,
+    wrong_race_bets: u32,
+    correct_winner_bet: bool,
+    correct_loser_bet: bool,
+    first_winner_bet: bool,
+    first_loser_bet: bool,
+    /// Money made in each leg, not counting race bets paid out after the race
+    leg_money: BTreeMap<u32, i32>,
+}
+
+impl SeatTally {
+    fn count(&mut self, name: &str, entry: &GameLogEntry) {
+        match entry.event {
+            GameEvent::DieRolled { ref player, .. } if player == name => self.rolls += 1,
+            GameEvent::CrazyDieRolled { ref player, .. } if player == name => {
+                self.rolls += 1;
+                self.crazy_rolls += 1;
+            }
+            GameEvent::RaceBetPlaced { ref player, .. } if player == name => self.race_bets += 1,
+            GameEvent::SpectatorTilePlaced { ref player, .. } if player == name => self.spectator_tiles += 1,
+            GameEvent::SpectatorTilePayout { ref owner, .. } if owner == name => self.spectator_payouts += 1,
+            GameEvent::RaceBetResolved { ref player, is_winner_bet, payout, .. } => {
+                if player == name {
+                    match (payout > 0, is_winner_bet) {
+                        (false, _) => self.wrong_race_bets += 1,
+                        (true, true) => self.correct_winner_bet = true,
+                        (true, false) => self.correct_loser_bet = true,
+                    }
+                    if payout == FIRST_RACE_BET_PAYOUT {
+                        if is_winner_bet {
+                            self.first_winner_bet = true;
+                        } else {
+                            self.first_loser_bet = true;
+                        }
+                    }
+                }
+                return;
+            }
+            _ => {}
+        }
+        for change in entry.money.iter().filter(|change| change.player == name) {
+            *self.leg_money.entry(entry.leg).or_default() += change.delta;
+        }
+    }
+
+    /// Achievements earned as soon as they happen
+    fn earned_during_game(&self) -> Vec<Achievement> {
+        [
+            (Achievement::Payday, self.leg_money.values().any(|&money| money >= 10)),
+            (Achievement::PyramidScheme, self.rolls >= 10),
+            (Achievement::Madness, self.crazy_rolls > 0),
+            (Achievement::TouristTrap, self.spectator_payouts >= 5),
+        ]
+        .into_iter()
+        .filter_map(|(achievement, earned)| earned.then_some(achievement))
+        .collect()
+    }
+}
+
+/// The game being followed: how far into its log, and each local seat's tally by name
+#[derive(Default)]
+pub struct AchievementTracker {
+    seen: usize,
+    seats: HashMap<String, SeatTally>,
+    finished: bool,
+}
+
+/// System to pick up the achievements unlocked in earlier runs
+pub fn load_achievements(mut achievements: ResMut<Achievements>) {
+    if let Some(unlocked) = storage::read(ACHIEVEMENTS_NAME)
+        .and_then(|json| serde_json::from_str::<BTreeMap<Achievement, Unlock>>(&json).ok())
+    {
+        achievements.unlocked = unlocked;
+    }
+}
+
+/// The game achievements are earned in: its log, its table and who is playing it
+#[derive(SystemParam)]
+pub struct TrackedGame<'w> {
+    game_log: Res<'w, GameLog>,
+    players: Option<Res<'w, Players>>,
+    game_end_state: Option<Res<'w, GameEndState>>,
+    network_state: Res<'w, NetworkState>,
+    autoplay: Res<'w, AutoplayConfig>,
+    ai_config: Res<'w, AiConfig>,
+    sheikh: Option<Res<'w, SheikhBettor>>,
+    desert_events: Option<Res<'w, ActiveEvent>>,
+    relay: Option<Res<'w, RelayRace>>,
+}
+
+/// System to follow the game log and unlock achievements for the seats played at this
+/// device. Games nobody played (the AI by itself, or a replay) don't count.
+pub fn track_achievements(
+    mut achievements: ResMut<Achievements>,
+    mut tracker: Local<AchievementTracker>,
+    game: TrackedGame,
+    time: Res<Time>,
+) {
+    let TrackedGame {
+        game_log,
+        players,
+        game_end_state,
+        network_state,
+        autoplay,
+        ai_config,
+        sheikh,
+        desert_events,
+        relay,
+    } = game;
+    let now = time.elapsed_secs_f64();
+    achievements.toasts.retain(|toast| now - toast.shown_at < TOAST_SECS);
+
+    let Some(players) = players else { return };
+    // A fresh log means a new game started
+    if game_log.entries.len() < tracker.seen {
+        *tracker = AchievementTracker::default();
+    }
+    if autoplay.active || network_state.is_replay() || tracker.finished {
+        return;
+    }
+
+    let final_results = game_end_state.as_ref().is_some_and(|state| state.phase == GameEndPhase::FinalResults);
+    // Money keeps landing on the latest entry until the next one is logged, so it's
+    // only counted once the game has moved past it (or is over)
+    let ready = if final_results { game_log.entries.len() } else { game_log.entries.len().saturating_sub(1) };
+    if ready <= tracker.seen {
+        return;
+    }
+
+    let local_names: Vec<String> = players
+        .players
+        .iter()
+        .enumerate()
+        .filter(|(seat, player)| plays_here(&network_state, *seat, player))
+        .map(|(_, player)| player.name.clone())
+        .collect();
+
+    let mut unlocked_any = false;
+    let seen = tracker.seen;
+    for entry in &game_log.entries[seen..ready] {
+        if matches!(entry.event, GameEvent::GameStarted { .. }) {
+            tracker.seats.clear();
+        }
+        for name in &local_names {
+            tracker.seats.entry(name.clone()).or_default().count(name, entry);
+        }
+    }
+    tracker.seen = ready;
+    for name in &local_names {
+        let Some(tally) = tracker.seats.get(name) else { continue };
+        for achievement in tally.earned_during_game() {
+            unlocked_any |= achievements.unlock(achievement, name, now);
+        }
+    }
+
+    if let (true, Some(state)) = (final_results, game_end_state) {
+        tracker.finished = true;
+        let winner = state.winner(&players);
+        let hard_ai = players.players.iter().any(|p| p.is_ai && ai_config.difficulty_for(p.id) == AiDifficulty::Hard);
+        let sheikh = sheikh.is_some_and(|sheikh| sheikh.enabled);
+        let desert_events = desert_events.is_some_and(|events| events.enabled);
+        let relay = relay.is_some_and(|relay| relay.enabled);
+
+        for player in players.players.iter().filter(|p| local_names.contains(&p.name)) {
+            let Some(tally) = tracker.seats.get(&player.name) else { continue };
+            let won = winner == Some(player.id);
+            let runner_up = players.players.iter().filter(|p| p.id != player.id).map(|p| p.money).max().unwrap_or(0);
+
+            let earned = [
+                (Achievement::FirstWin, won),
+                (Achievement::LegBetsOnly, won && tally.race_bets == 0 && tally.spectator_tiles == 0),
+                (Achievement::CalledTheLoser, tally.first_loser_bet),
+                (Achievement::CalledTheWinner, tally.first_winner_bet),
+                (Achievement::BothCalls, tally.correct_winner_bet && tally.correct_loser_bet),
+                (Achievement::Broke, player.money == 0),
+                (Achievement::HighRoller, player.money >= 40),
+                (Achievement::Landslide, won && player.money - runner_up >= 10),
+                (Achievement::PhotoFinish, won && player.money == runner_up),
+                (Achievement::HandsOff, tally.rolls == 0),
+                (Achievement::Oops, tally.wrong_race_bets >= 3),
+                (Achievement::StormChaser, won && desert_events),
+                (Achievement::AnchorLeg, won && relay),
+                (Achievement::OutfoxedTheSheikh, won && sheikh),
+                (Achievement::Globetrotter, won && network_state.is_online()),
+                (Achievement::GiantSlayer, won && hard_ai),
+            ];
+            for (achievement, _) in earned.into_iter().filter(|(_, earned)| *earned) {
+                unlocked_any |= achievements.unlock(achievement, &player.name, now);
+            }
+        }
+    }
+
+    if unlocked_any {
+        if let Ok(json) = serde_json::to_string(&achievements.unlocked) {
+            storage::write(ACHIEVEMENTS_NAME, &json);
+        }
+    }
+}
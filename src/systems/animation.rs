@@ -4,6 +4,7 @@ use std::f32::consts::TAU;
 use crate::components::{CamelColor, CrazyCamelColor, CrazyCamel};
 use crate::components::board::{PyramidRollButton, PyramidShakeAnimation, PyramidHovered, PyramidHoverBorder};
 use crate::systems::movement::{MoveCamelEvent, MoveCrazyCamelEvent};
+use crate::systems::quality::DropShadow;
 
 /// Component for entities that are animating their position
 #[derive(Component)]
@@ -124,6 +125,9 @@ pub fn dice_result_popup_system(
     }
 }
 
+/// Query filter for pieces still on their way, in one step or hop by hop
+pub type Moving = Or<(With<MovementAnimation>, With<MultiStepMovementAnimation>)>;
+
 /// Multi-step movement animation for hop-by-hop camel movement
 #[derive(Component)]
 pub struct MultiStepMovementAnimation {
@@ -300,11 +304,21 @@ pub struct PendingCrazyCamelMove {
     pub spaces: u8,
 }
 
+/// A rolling die, and the camel it will move once it settles
+type RollingDie<'a> = (
+    Entity,
+    &'a mut Transform,
+    &'a mut Sprite,
+    &'a mut DiceRollAnimation,
+    Option<&'a PendingCamelMove>,
+    Option<&'a PendingCrazyCamelMove>,
+);
+
 /// System to animate dice roll through phases
 pub fn dice_roll_animation_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut Transform, &mut Sprite, &mut DiceRollAnimation, Option<&PendingCamelMove>, Option<&PendingCrazyCamelMove>), With<DiceSprite>>,
+    mut query: Query<RollingDie, With<DiceSprite>>,
     _children_query: Query<&Children>,
     _text_query: Query<&mut Transform, (With<DiceValueText>, Without<DiceSprite>)>,
     mut move_camel: MessageWriter<MoveCamelEvent>,
@@ -798,6 +812,7 @@ pub fn spawn_crown(commands: &mut Commands, position: Vec3, drop_height: Option<
 
         // Shadow: base
         parent.spawn((
+            DropShadow,
             Sprite {
                 color: shadow_color,
                 custom_size: Some(Vec2::new(base_width, base_height)),
@@ -808,6 +823,7 @@ pub fn spawn_crown(commands: &mut Commands, position: Vec3, drop_height: Option<
         // Shadow: points
         for i in [-1, 0, 1] {
             parent.spawn((
+                DropShadow,
                 Sprite {
                     color: shadow_color,
                     custom_size: Some(Vec2::new(point_width, point_height)),
@@ -968,10 +984,13 @@ pub fn animate_pyramid_shake(
     }
 }
 
+/// The pyramid button, whether the pointer is over it or it is shaking, and its border
+type HoverablePyramid<'a> = (&'a mut Transform, Option<&'a PyramidHovered>, Option<&'a PyramidShakeAnimation>, &'a Children);
+
 /// System to animate the pyramid hover effect (scale up when hovered)
 pub fn animate_pyramid_hover(
     time: Res<Time>,
-    mut pyramid_query: Query<(&mut Transform, Option<&PyramidHovered>, Option<&PyramidShakeAnimation>, &Children), With<PyramidRollButton>>,
+    mut pyramid_query: Query<HoverablePyramid, With<PyramidRollButton>>,
     mut border_query: Query<&mut Visibility, With<PyramidHoverBorder>>,
 ) {
     const HOVER_SCALE: f32 = 1.12;
@@ -1072,7 +1091,8 @@ impl CameraZoomAnimation {
 /// System to animate camera zoom transitions with easing
 pub fn animate_camera_zoom(
     time: Res<Time>,
-    mut query: Query<(Entity, &mut Projection, &mut CameraZoomAnimation), With<Camera2d>>,
+    camera_state: Res<crate::CameraState>,
+    mut query: Query<(Entity, &mut Projection, &mut CameraZoomAnimation), With<crate::GameCamera>>,
     mut commands: Commands,
 ) {
     for (entity, mut projection, mut anim) in query.iter_mut() {
@@ -1084,8 +1104,9 @@ pub fn animate_camera_zoom(
 
         let scale = anim.start_scale + (anim.target_scale - anim.start_scale) * eased;
 
+        // The player's pinch zoom and any zoom punch stay on top of the animated scale
         if let Projection::Orthographic(ref mut ortho) = *projection {
-            ortho.scale = scale;
+            ortho.scale = scale / camera_state.zoom();
         }
 
         if progress >= 1.0 {
@@ -1093,3 +1114,131 @@ pub fn animate_camera_zoom(
         }
     }
 }
+
+/// Floating "+$1" / "-$1" beside a player's avatar when their money changes.
+/// The avatars are drawn by egui, so the HUD draws the text; this only times it.
+#[derive(Component)]
+pub struct MoneyDeltaPopup {
+    pub player_index: usize,
+    pub delta: i32,
+    pub elapsed: f32,
+}
+
+impl MoneyDeltaPopup {
+    /// How long the text drifts up before it's gone (seconds)
+    pub const DURATION: f32 = 1.4;
+
+    /// 0.0 when it appears, 1.0 when it's faded out
+    pub fn progress(&self) -> f32 {
+        (self.elapsed / Self::DURATION).clamp(0.0, 1.0)
+    }
+}
+
+/// System to spawn a money popup for every player whose money changed since last frame
+/// (pyramid tokens, spectator tile payouts, leg scoring)
+pub fn spawn_money_delta_popups(
+    mut commands: Commands,
+    players: Option<Res<crate::components::Players>>,
+    mut last_money: Local<Vec<i32>>,
+) {
+    let Some(players) = players else { return };
+
+    // A new game starts everyone from scratch rather than from the last game's money
+    if players.is_added() || last_money.len() != players.players.len() {
+        *last_money = players.players.iter().map(|p| p.money).collect();
+        return;
+    }
+    if !players.is_changed() {
+        return;
+    }
+
+    for (player_index, (player, last)) in players.players.iter().zip(last_money.iter_mut()).enumerate() {
+        if player.money != *last {
+            commands.spawn((
+                DespawnOnExit(crate::game::state::GameState::Playing),
+                MoneyDeltaPopup { player_index, delta: player.money - *last, elapsed: 0.0 },
+            ));
+            *last = player.money;
+        }
+    }
+}
+
+/// System to age money popups and remove them once they've faded
+pub fn money_delta_popup_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut MoneyDeltaPopup)>,
+) {
+    for (entity, mut popup) in query.iter_mut() {
+        popup.elapsed += time.delta_secs();
+        if popup.elapsed >= MoneyDeltaPopup::DURATION {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Pulsing ring around a camel, despawned when it runs out (leg recap podium)
+#[derive(Component)]
+pub struct HighlightRing {
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl HighlightRing {
+    pub fn new(duration: f32) -> Self {
+        Self { elapsed: 0.0, duration }
+    }
+}
+
+/// System to pop highlight rings in, pulse them and remove them when done
+pub fn highlight_ring_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut HighlightRing)>,
+) {
+    for (entity, mut transform, mut ring) in query.iter_mut() {
+        ring.elapsed += time.delta_secs();
+        if ring.elapsed >= ring.duration {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Grow in over the first 0.2s, shrink away over the last 0.2s, breathe in between
+        let grow = (ring.elapsed / 0.2).min(1.0).min((ring.duration - ring.elapsed) / 0.2);
+        let pulse = 1.0 + 0.08 * (ring.elapsed * 6.0).sin();
+        transform.scale = Vec3::splat(grow * pulse);
+    }
+}
+
+/// A glow over a dice tent that flashes once after `delay` seconds (leg recap roll order)
+#[derive(Component)]
+pub struct TentFlash {
+    pub delay: f32,
+    pub elapsed: f32,
+}
+
+impl TentFlash {
+    /// How long one flash lasts (seconds)
+    pub const DURATION: f32 = 0.4;
+
+    pub fn after(delay: f32) -> Self {
+        Self { delay, elapsed: 0.0 }
+    }
+}
+
+/// System to flash tent glows in and out, removing each once it's flashed
+pub fn tent_flash_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Sprite, &mut TentFlash)>,
+) {
+    for (entity, mut sprite, mut flash) in query.iter_mut() {
+        flash.elapsed += time.delta_secs();
+        let t = (flash.elapsed - flash.delay) / TentFlash::DURATION;
+        if t >= 1.0 {
+            commands.entity(entity).despawn();
+        } else {
+            sprite.color = sprite.color.with_alpha(0.7 * (t.max(0.0) * std::f32::consts::PI).sin());
+        }
+    }
+}
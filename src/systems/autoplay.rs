@@ -0,0 +1,144 @@
+// AI-vs-AI autoplay ("Watch AI Game")
+// Every seat is handed to the AI, time runs faster and the leg scoring and game end
+// modals click themselves after a short pause, so a whole game plays out hands-free.
+// Useful for demos and for watching how the AIs fare against each other. Games keep
+// starting back to back until Escape returns to the main menu.
+
+use bevy::prelude::*;
+
+use crate::game::key_bindings::{KeyAction, KeyBindings};
+use crate::game::state::GameState;
+use crate::systems::setup::InitialSetupRolls;
+use crate::ui::hud::UiState;
+use crate::ui::player_setup::{PlayerConfig, PlayerSetupConfig};
+
+/// How much faster the game clock runs while watching
+const AUTOPLAY_SPEED: f32 = 2.5;
+
+/// Game seconds a modal stays up before autoplay clicks through it
+/// (game time runs at `AUTOPLAY_SPEED`, so this is 1.6 real seconds)
+const MODAL_DWELL: f32 = 4.0;
+
+/// Game seconds the final results stay up before the next game starts
+const FINAL_RESULTS_DWELL: f32 = 12.0;
+
+/// AI think delay multiplier while watching
+const THINK_DELAY_SCALE: f32 = 0.5;
+
+/// State of autoplay, consulted by the modal UIs and the AI think timer
+#[derive(Resource, Default)]
+pub struct AutoplayConfig {
+    pub active: bool,
+    /// Seconds the leg scoring modal has been showing
+    modal_timer: f32,
+    /// Set when a game finished and the next one should start
+    next_game: bool,
+    /// Seats as the player set them up, restored when autoplay ends
+    saved_players: Option<Vec<PlayerConfig>>,
+}
+
+impl AutoplayConfig {
+    /// Whether a modal that has been showing for `shown_for` seconds should be clicked
+    pub fn should_click(&self, shown_for: f32) -> bool {
+        self.active && shown_for >= MODAL_DWELL
+    }
+
+    /// Whether the leg scoring modal should be clicked through
+    pub fn leg_scoring_ready(&self) -> bool {
+        self.should_click(self.modal_timer)
+    }
+
+    /// Whether the final results have been up long enough to start the next game
+    pub fn final_results_ready(&self, shown_for: f32) -> bool {
+        self.active && shown_for >= FINAL_RESULTS_DWELL
+    }
+
+    /// Queue up another game after this one
+    pub fn start_next_game(&mut self) {
+        self.next_game = true;
+    }
+
+    /// AI think delay, shortened while watching
+    pub fn think_delay(&self, base: f32) -> f32 {
+        if self.active { base * THINK_DELAY_SCALE } else { base }
+    }
+
+    /// Hand every seat to the AI and start watching
+    pub fn start(&mut self, config: &mut PlayerSetupConfig) {
+        self.saved_players = Some(config.players.clone());
+        for i in 0..config.players.len() {
+            config.set_player_is_ai(i, true);
+        }
+        self.active = true;
+        self.modal_timer = 0.0;
+        self.next_game = false;
+        info!("Autoplay started: {} AI players", config.players.len());
+    }
+}
+
+/// System to drive autoplay: skip setup clicks, time the modals and run the clock fast
+pub fn autoplay_system(
+    time: Res<Time>,
+    (keys, key_bindings): (Res<ButtonInput<KeyCode>>, Res<KeyBindings>),
+    mut autoplay: ResMut<AutoplayConfig>,
+    mut ui_state: ResMut<UiState>,
+    initial_rolls: Option<ResMut<InitialSetupRolls>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !autoplay.active {
+        return;
+    }
+
+    if key_bindings.just_pressed(&keys, KeyAction::StopWatching) {
+        next_state.set(GameState::MainMenu);
+        return;
+    }
+
+    if virtual_time.relative_speed() != AUTOPLAY_SPEED {
+        virtual_time.set_relative_speed(AUTOPLAY_SPEED);
+    }
+
+    // Click through the setup rolls and the Start Game button
+    if let Some(mut rolls) = initial_rolls {
+        if rolls.waiting_for_click {
+            rolls.waiting_for_click = false;
+        }
+    }
+    if ui_state.camel_rolls_complete && !ui_state.initial_rolls_complete {
+        ui_state.initial_rolls_complete = true;
+    }
+
+    if ui_state.show_leg_scoring {
+        autoplay.modal_timer += time.delta_secs();
+    } else {
+        autoplay.modal_timer = 0.0;
+    }
+}
+
+/// System to start the next game, or stop autoplay and give the seats back
+pub fn end_autoplay(
+    mut autoplay: ResMut<AutoplayConfig>,
+    mut config: ResMut<PlayerSetupConfig>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !autoplay.active {
+        return;
+    }
+
+    if autoplay.next_game {
+        autoplay.next_game = false;
+        autoplay.modal_timer = 0.0;
+        info!("Autoplay: starting the next game");
+        next_state.set(GameState::Playing);
+        return;
+    }
+
+    if let Some(players) = autoplay.saved_players.take() {
+        config.players = players;
+    }
+    virtual_time.set_relative_speed(1.0);
+    *autoplay = AutoplayConfig::default();
+    info!("Autoplay stopped");
+}
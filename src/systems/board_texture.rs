@@ -0,0 +1,161 @@
+// Board texture
+// The track never changes during a game, so it's painted once at setup into a single
+// background texture: every space with its drop shadow, border, sand and highlight,
+// the space numbers and the checkered finish line. What does change - placement tints,
+// the relay's later tracks, spectator tiles - is drawn by sprites on top.
+
+use bevy::prelude::*;
+
+use crate::components::{GameBoard, TRACK_LENGTH};
+use crate::game::state::GameState;
+use crate::systems::canvas::Canvas;
+
+/// Size of a board space in world units
+pub const SPACE_SIZE: Vec2 = Vec2::new(70.0, 50.0);
+
+/// Sand color of the board spaces
+const SAND_SPACE_COLOR: Color = Color::srgb(0.85, 0.75, 0.55);
+
+/// Texture pixels per world unit, so the board stays crisp when the camera zooms in
+const PIXELS_PER_UNIT: f32 = 2.0;
+
+/// Room around the spaces' centers for the spaces themselves, their numbers and the
+/// finish flag
+const BOARD_MARGIN: Vec2 = Vec2::new(70.0, 60.0);
+
+/// Space numbers are drawn in a 3x5 blocky font, one row of bits per line (top first)
+const DIGIT_GLYPHS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b001, 0b001, 0b001],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+/// Size of one block of a digit (world units)
+const DIGIT_CELL: f32 = 2.4;
+
+/// Spawn the painted track as one background sprite, with or without drop shadows
+pub fn spawn_board_background(
+    commands: &mut Commands,
+    images: &mut Assets<Image>,
+    board: &GameBoard,
+    shadows: bool,
+) {
+    let spaces: Vec<Vec2> = (0..TRACK_LENGTH).map(|i| board.get_position(i)).collect();
+    let (min, max) = spaces
+        .iter()
+        .fold((Vec2::MAX, Vec2::MIN), |(min, max), pos| (min.min(*pos), max.max(*pos)));
+    let area = Rect::from_corners(min - BOARD_MARGIN, max + BOARD_MARGIN);
+
+    let mut canvas = Canvas::new(area, PIXELS_PER_UNIT);
+    paint_spaces(&mut canvas, &spaces, shadows);
+    if let Some(finish) = spaces.last() {
+        paint_finish_line(&mut canvas, *finish);
+    }
+    for (index, pos) in spaces.iter().enumerate() {
+        paint_number(&mut canvas, *pos - Vec2::new(0.0, 35.0), index + 1);
+    }
+
+    commands.spawn((
+        DespawnOnExit(GameState::GameEnd),
+        Sprite {
+            image: images.add(canvas.into_image()),
+            custom_size: Some(area.size()),
+            ..default()
+        },
+        Transform::from_translation(area.center().extend(0.0)),
+    ));
+}
+
+/// Paint the spaces layer by layer, so no space's border covers its neighbour's sand
+fn paint_spaces(canvas: &mut Canvas, spaces: &[Vec2], shadows: bool) {
+    // Shadow layer (offset down-right, darker)
+    if shadows {
+        for pos in spaces {
+            canvas.fill_rect(*pos + Vec2::new(3.0, -3.0), SPACE_SIZE, Color::srgba(0.3, 0.25, 0.15, 0.5));
+        }
+    }
+
+    // Border layer (slightly larger, dark brown)
+    for pos in spaces {
+        canvas.fill_rect(*pos, SPACE_SIZE + Vec2::new(4.0, 4.0), Color::srgb(0.4, 0.3, 0.2));
+    }
+
+    // Main space (sand color)
+    for pos in spaces {
+        canvas.fill_rect(*pos, SPACE_SIZE, SAND_SPACE_COLOR);
+    }
+
+    // Inner highlight (top portion, subtle)
+    for pos in spaces {
+        canvas.fill_rect(
+            *pos + Vec2::new(0.0, 8.0),
+            Vec2::new(SPACE_SIZE.x - 8.0, SPACE_SIZE.y * 0.4),
+            Color::srgba(1.0, 0.95, 0.85, 0.3),
+        );
+    }
+}
+
+/// Paint a vertical checkered finish line on the left edge of the final space
+/// This emphasizes that camels must cross this threshold to win
+fn paint_finish_line(canvas: &mut Canvas, pos: Vec2) {
+    let checker_size = 8.0;
+    let rows = 8; // Tall vertical flag
+    let cols = 3; // Narrow width
+
+    // Position on the LEFT edge of the final space (the finish threshold)
+    let start_x = pos.x - 40.0 - (cols as f32 * checker_size) / 2.0 + checker_size / 2.0;
+    let start_y = pos.y - (rows as f32 * checker_size) / 2.0 + checker_size / 2.0;
+
+    // A flagpole on the left side, with a small gold ball on top
+    let pole_height = rows as f32 * checker_size + 20.0;
+    let pole_x = start_x - checker_size / 2.0 - 2.0;
+    canvas.fill_rect(Vec2::new(pole_x, pos.y), Vec2::new(4.0, pole_height), Color::srgb(0.4, 0.3, 0.2));
+    canvas.fill_rect(
+        Vec2::new(pole_x, pos.y + pole_height / 2.0 + 4.0),
+        Vec2::splat(8.0),
+        Color::srgb(0.8, 0.7, 0.2),
+    );
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let is_white = (row + col) % 2 == 0;
+            canvas.fill_rect(
+                Vec2::new(start_x + col as f32 * checker_size, start_y + row as f32 * checker_size),
+                Vec2::splat(checker_size),
+                if is_white { Color::WHITE } else { Color::BLACK },
+            );
+        }
+    }
+}
+
+/// Paint a space number centered on `center`
+fn paint_number(canvas: &mut Canvas, center: Vec2, number: usize) {
+    let digits: Vec<usize> = number
+        .to_string()
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|digit| digit as usize)
+        .collect();
+
+    // Three blocks per digit with one between digits, five blocks tall
+    let width = (digits.len() * 4 - 1) as f32 * DIGIT_CELL;
+    let top_left = center + Vec2::new(-width / 2.0, 2.5 * DIGIT_CELL);
+
+    for (place, digit) in digits.into_iter().enumerate() {
+        for (row, bits) in DIGIT_GLYPHS[digit].iter().enumerate() {
+            for column in 0..3 {
+                if bits & (0b100 >> column) != 0 {
+                    let block = Vec2::new((place * 4 + column) as f32 + 0.5, -(row as f32) - 0.5);
+                    canvas.fill_rect(top_left + block * DIGIT_CELL, Vec2::splat(DIGIT_CELL), Color::WHITE);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,201 @@
+// Camel atlas
+// Every camel is one textured sprite. Its silhouette - body, hump, neck, head and legs,
+// with border, highlights, eye and drop shadow - is painted once per camel color into a
+// single texture when a game is set up, instead of being built from some thirty sprites
+// per camel. Painted at setup, the frames pick up the player's `CamelPalette` (colors and
+// markers) and the quality tier's drop shadows.
+
+use bevy::color::Srgba;
+use bevy::prelude::*;
+
+use crate::components::{CamelColor, CamelPalette, CrazyCamelColor};
+use crate::systems::canvas::Canvas;
+
+/// Area of one frame in world units, around the camel's center
+const FRAME_SIZE: Vec2 = Vec2::new(64.0, 48.0);
+
+/// Texture pixels per world unit, so camels stay crisp when the camera zooms in
+const PIXELS_PER_UNIT: f32 = 2.0;
+
+/// Size of one frame in texture pixels
+const FRAME_PIXELS: UVec2 = UVec2::new(
+    (FRAME_SIZE.x * PIXELS_PER_UNIT) as u32,
+    (FRAME_SIZE.y * PIXELS_PER_UNIT) as u32,
+);
+
+/// Body, hump, neck and head: (center, size) around the camel's center
+const BODY: (Vec2, Vec2) = (Vec2::new(0.0, 0.0), Vec2::new(32.0, 18.0));
+const HUMP: (Vec2, Vec2) = (Vec2::new(-2.0, 10.0), Vec2::new(14.0, 12.0));
+const NECK: (Vec2, Vec2) = (Vec2::new(16.0, 8.0), Vec2::new(8.0, 16.0));
+const HEAD: (Vec2, Vec2) = (Vec2::new(22.0, 16.0), Vec2::new(14.0, 10.0));
+
+/// Back left, back right, front left and front right legs
+const LEGS: [Vec2; 4] = [
+    Vec2::new(-10.0, -14.0),
+    Vec2::new(-4.0, -14.0),
+    Vec2::new(8.0, -14.0),
+    Vec2::new(14.0, -14.0),
+];
+const LEG_SIZE: Vec2 = Vec2::new(5.0, 14.0);
+
+/// Drop shadow, offset down-right
+const SHADOW_OFFSET: Vec2 = Vec2::new(2.0, -2.0);
+const SHADOW_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.3);
+
+/// Darken a color by a given amount (0.0 = no change, 1.0 = black)
+fn darken_color(color: Color, amount: f32) -> Color {
+    let rgba: Srgba = color.into();
+    Color::srgba(
+        (rgba.red * (1.0 - amount)).max(0.0),
+        (rgba.green * (1.0 - amount)).max(0.0),
+        (rgba.blue * (1.0 - amount)).max(0.0),
+        rgba.alpha,
+    )
+}
+
+/// Lighten a color by a given amount (0.0 = no change, 1.0 = white)
+fn lighten_color(color: Color, amount: f32) -> Color {
+    let rgba: Srgba = color.into();
+    Color::srgba(
+        (rgba.red + (1.0 - rgba.red) * amount).min(1.0),
+        (rgba.green + (1.0 - rgba.green) * amount).min(1.0),
+        (rgba.blue + (1.0 - rgba.blue) * amount).min(1.0),
+        rgba.alpha,
+    )
+}
+
+/// Which camel a frame of the atlas draws
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CamelSkin {
+    Racing(CamelColor),
+    Crazy(CrazyCamelColor),
+}
+
+impl CamelSkin {
+    /// Every camel, in frame order
+    fn all() -> impl Iterator<Item = CamelSkin> {
+        CamelColor::all()
+            .into_iter()
+            .map(CamelSkin::Racing)
+            .chain(CrazyCamelColor::all().into_iter().map(CamelSkin::Crazy))
+    }
+
+    /// Index of the camel's frame in the atlas
+    fn frame(self) -> usize {
+        CamelSkin::all().position(|skin| skin == self).unwrap_or(0)
+    }
+
+    fn color(self, palette: CamelPalette) -> Color {
+        match self {
+            CamelSkin::Racing(color) => color.to_bevy_color(palette),
+            CamelSkin::Crazy(color) => color.to_bevy_color(),
+        }
+    }
+
+    /// Racing camels carry their color's pattern on the body
+    fn marker(self) -> Option<CamelColor> {
+        match self {
+            CamelSkin::Racing(color) => Some(color),
+            CamelSkin::Crazy(_) => None,
+        }
+    }
+}
+
+/// The painted camels, one frame per color, for the game being played
+#[derive(Resource, Clone)]
+pub struct CamelAtlas {
+    image: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    palette: CamelPalette,
+}
+
+impl CamelAtlas {
+    /// Paint every camel into a new texture in `palette`, with or without drop shadows
+    pub fn build(
+        images: &mut Assets<Image>,
+        layouts: &mut Assets<TextureAtlasLayout>,
+        palette: CamelPalette,
+        shadows: bool,
+    ) -> Self {
+        // Frames side by side, each camel centered in its own
+        let frames = CamelSkin::all().count() as u32;
+        let strip = Vec2::new(FRAME_SIZE.x * frames as f32, FRAME_SIZE.y);
+        let mut canvas = Canvas::new(Rect::new(0.0, -strip.y / 2.0, strip.x, strip.y / 2.0), PIXELS_PER_UNIT);
+        for skin in CamelSkin::all() {
+            let center = Vec2::new((skin.frame() as f32 + 0.5) * FRAME_SIZE.x, 0.0);
+            let marker = skin.marker().filter(|_| palette.markers);
+            paint_camel(&mut canvas, center, skin.color(palette), marker, shadows);
+        }
+
+        Self {
+            image: images.add(canvas.into_image()),
+            layout: layouts.add(TextureAtlasLayout::from_grid(FRAME_PIXELS, frames, 1, None, None)),
+            palette,
+        }
+    }
+
+    /// The palette the camels were painted in
+    pub fn palette(&self) -> CamelPalette {
+        self.palette
+    }
+
+    /// Sprite drawing the camel, centered on its entity
+    pub fn sprite(&self, skin: CamelSkin) -> Sprite {
+        Sprite {
+            image: self.image.clone(),
+            texture_atlas: Some(TextureAtlas {
+                layout: self.layout.clone(),
+                index: skin.frame(),
+            }),
+            custom_size: Some(FRAME_SIZE),
+            ..default()
+        }
+    }
+}
+
+/// Paint one camel centered on `origin`, back to front as the sprites used to stack
+fn paint_camel(canvas: &mut Canvas, origin: Vec2, base_color: Color, marker: Option<CamelColor>, shadows: bool) {
+    let border_color = darken_color(base_color, 0.4);
+    let highlight_color = lighten_color(base_color, 0.3).with_alpha(0.4);
+
+    // Each part with how much its border grows it
+    let parts = [BODY, HUMP, NECK, HEAD]
+        .into_iter()
+        .map(|(center, size)| (origin + center, size, 3.0))
+        .chain(LEGS.into_iter().map(|center| (origin + center, LEG_SIZE, 2.0)));
+
+    if shadows {
+        for (center, size, _) in parts.clone() {
+            canvas.fill_rect(center + SHADOW_OFFSET, size, SHADOW_COLOR);
+        }
+    }
+    for (center, size, border) in parts.clone() {
+        canvas.fill_rect(center, size + Vec2::splat(border), border_color);
+    }
+    for (center, size, _) in parts {
+        canvas.fill_rect(center, size, base_color);
+    }
+
+    // Highlight on hump and head
+    canvas.fill_rect(origin + HUMP.0 + Vec2::new(0.0, 3.0), Vec2::new(HUMP.1.x - 4.0, 4.0), highlight_color);
+    canvas.fill_rect(origin + HEAD.0 + Vec2::new(0.0, 2.0), Vec2::new(HEAD.1.x - 4.0, 3.0), highlight_color);
+
+    // Hatching of the color's pattern over the body (as `spawn_color_pattern`)
+    if let Some(color) = marker {
+        let ink = Color::srgba(0.0, 0.0, 0.0, 0.35);
+        let (body_center, body_size) = BODY;
+        for mark in color.pattern().marks() {
+            let center = origin + body_center + mark.center * body_size;
+            if mark.diamond {
+                // A square turned 45 degrees reaches half its diagonal from the center
+                let reach = (mark.size * body_size).min_element() * std::f32::consts::FRAC_1_SQRT_2;
+                canvas.fill_diamond(center, reach, ink);
+            } else {
+                canvas.fill_rect(center, mark.size * body_size, ink);
+            }
+        }
+    }
+
+    // Eye (small dark dot on head)
+    canvas.fill_rect(origin + HEAD.0 + Vec2::new(3.0, 1.0), Vec2::splat(3.0), Color::srgb(0.1, 0.1, 0.1));
+}
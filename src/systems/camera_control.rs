@@ -0,0 +1,106 @@
+// Touch camera control for the board
+// On touch screens the board can be pinched to zoom in and dragged to pan around,
+// and a double-tap puts the framing back. The zoom multiplies the scale that
+// `scale_camera_to_fit` and its zoom animations pick, so resizing the window and the
+// automatic zooms keep working underneath whatever the player has set.
+
+use bevy::input::touch::Touches;
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContextSettings};
+
+use crate::ui::hud::UiState;
+use crate::{CameraState, GameCamera};
+
+/// Furthest the player can zoom in, as a multiple of the fitted view
+pub const MAX_USER_ZOOM: f32 = 3.0;
+
+/// How far the camera can pan from the fitted view when fully zoomed in (world units)
+const MAX_PAN: Vec2 = Vec2::new(420.0, 260.0);
+
+/// Two taps closer together than this (seconds) reset the framing
+const DOUBLE_TAP_SECS: f64 = 0.3;
+
+/// A touch that moves less than this (logical pixels) counts as a tap, not a drag
+pub const TAP_SLOP: f32 = 12.0;
+
+/// Fingers on the board and the last tap, between frames
+#[derive(Default)]
+pub struct BoardGestures {
+    /// Touches that started on the board; touches that started on a panel are left to egui
+    touch_ids: HashSet<u64>,
+    /// When the last tap on the board ended
+    last_tap: Option<f64>,
+}
+
+/// System to pinch-zoom, drag-pan and double-tap-reset the board camera
+pub fn board_touch_camera(
+    touches: Res<Touches>,
+    time: Res<Time<Real>>,
+    ui_state: Res<UiState>,
+    egui_settings: Query<&EguiContextSettings>,
+    mut camera_state: ResMut<CameraState>,
+    camera: Query<&Projection, With<GameCamera>>,
+    mut gestures: Local<BoardGestures>,
+) {
+    let Ok(projection) = camera.single() else { return };
+
+    // Touch positions are in window pixels, the board rect in egui points
+    let egui_scale = egui_settings.iter().next().map_or(1.0, |settings| settings.scale_factor);
+    let on_board = |position: Vec2| {
+        ui_state
+            .game_board_rect
+            .is_some_and(|rect| rect.contains(egui::pos2(position.x / egui_scale, position.y / egui_scale)))
+    };
+
+    for touch in touches.iter_just_pressed() {
+        if on_board(touch.position()) {
+            gestures.touch_ids.insert(touch.id());
+        }
+    }
+
+    for touch in touches.iter_just_released() {
+        if !gestures.touch_ids.remove(&touch.id()) || touch.distance().length() > TAP_SLOP {
+            continue;
+        }
+        let now = time.elapsed_secs_f64();
+        if gestures.last_tap.is_some_and(|last| now - last < DOUBLE_TAP_SECS) {
+            camera_state.reset_user_view();
+            gestures.last_tap = None;
+        } else {
+            gestures.last_tap = Some(now);
+        }
+    }
+    for touch in touches.iter_just_canceled() {
+        gestures.touch_ids.remove(&touch.id());
+    }
+
+    // World units per window pixel right now, to move the board along with the fingers
+    let world_per_pixel = match projection {
+        Projection::Orthographic(ortho) => ortho.scale,
+        _ => 1.0,
+    };
+
+    let fingers: Vec<_> = touches.iter().filter(|touch| gestures.touch_ids.contains(&touch.id())).collect();
+    let drag = match fingers.as_slice() {
+        [finger] => finger.delta(),
+        [first, second, ..] => {
+            let distance = first.position().distance(second.position());
+            let previous = first.previous_position().distance(second.previous_position());
+            if previous > 0.0 {
+                camera_state.user_zoom = (camera_state.user_zoom * distance / previous).clamp(1.0, MAX_USER_ZOOM);
+            }
+            (first.delta() + second.delta()) * 0.5
+        }
+        [] => Vec2::ZERO,
+    };
+    if drag != Vec2::ZERO {
+        // Screen y points down, world y up
+        camera_state.user_pan += Vec2::new(-drag.x, drag.y) * world_per_pixel;
+    }
+
+    // Less room to pan the less we're zoomed in: none at all in the fitted view.
+    // `apply_camera_effects` moves the camera there, along with any shake.
+    let limit = MAX_PAN * (1.0 - 1.0 / camera_state.user_zoom);
+    camera_state.user_pan = camera_state.user_pan.clamp(-limit, limit);
+}
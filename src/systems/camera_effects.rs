@@ -0,0 +1,109 @@
+// Camera effects for dramatic moments
+// Trauma-based screen shake, a brief zoom punch, and a focus point that scripted
+// moments like the leg recap steer the camera to. Gameplay systems add trauma (a
+// crazy camel dragging racing camels backwards, the winner crossing the line) and
+// the shake fades as the trauma decays. The punch zooms in on top of the fitted
+// scale the same way the player's pinch zoom does, so it layers under
+// `scale_camera_to_fit` and `animate_camera_zoom` rather than fighting them.
+
+use bevy::prelude::*;
+
+use crate::{CameraState, GameCamera};
+
+/// Furthest the shake moves the camera at full trauma (world units)
+const MAX_SHAKE_OFFSET: f32 = 14.0;
+
+/// Trauma lost per second
+const TRAUMA_DECAY: f32 = 1.4;
+
+/// How long a zoom punch lasts (seconds)
+const PUNCH_DURATION: f32 = 0.35;
+
+/// Trauma for a crazy camel carrying racing camels backwards
+pub const CRAZY_STACK_TRAUMA: f32 = 0.5;
+/// Trauma for the winning camel crossing the finish line
+pub const FINISH_TRAUMA: f32 = 0.7;
+/// Zoom punch for the winning camel crossing the finish line
+pub const FINISH_PUNCH: f32 = 0.12;
+
+/// Shake and zoom punch in progress
+#[derive(Resource, Default)]
+pub struct CameraEffects {
+    /// 0.0 (still) to 1.0 (shaking hard); the shake grows with its square
+    trauma: f32,
+    /// Seconds into the current zoom punch, if one is playing
+    punch_elapsed: Option<f32>,
+    /// How far the current punch zooms in at its peak (0.1 = 10%)
+    punch_strength: f32,
+    /// Point the camera is steered to, in world units from the fitted view
+    focus: Vec2,
+    /// How far in the camera zooms on the focus (0.1 = 10%)
+    focus_zoom: f32,
+}
+
+impl CameraEffects {
+    /// Shake the camera harder, up to full trauma
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+
+    /// Zoom in briefly by `strength` (0.1 = 10%) and back out
+    pub fn punch(&mut self, strength: f32) {
+        self.punch_elapsed = Some(0.0);
+        self.punch_strength = strength;
+    }
+
+    /// Steer the camera to `focus`, zoomed in by `zoom` (0.1 = 10%), until it's set
+    /// back to zero. Scripted moments like the leg recap move it along every frame.
+    pub fn set_focus(&mut self, focus: Vec2, zoom: f32) {
+        self.focus = focus;
+        self.focus_zoom = zoom;
+    }
+}
+
+/// System to advance the shake and punch, and place the camera where the player's pan,
+/// any focus and the shake put it
+pub fn apply_camera_effects(
+    time: Res<Time>,
+    mut effects: ResMut<CameraEffects>,
+    mut camera_state: ResMut<CameraState>,
+    mut camera: Query<&mut Transform, With<GameCamera>>,
+) {
+    let Ok(mut transform) = camera.single_mut() else { return };
+    let dt = time.delta_secs();
+
+    effects.trauma = (effects.trauma - TRAUMA_DECAY * dt).max(0.0);
+    let shake = effects.trauma * effects.trauma;
+
+    // A few out-of-step sine waves stand in for noise so the shake doesn't repeat visibly
+    let t = time.elapsed_secs();
+    let offset = Vec2::new(
+        (t * 47.0).sin() + 0.5 * (t * 83.0).sin(),
+        (t * 53.0).cos() + 0.5 * (t * 71.0).sin(),
+    ) / 1.5
+        * MAX_SHAKE_OFFSET
+        * shake;
+
+    // The punch eases in and back out over its duration
+    let punch_zoom = match effects.punch_elapsed {
+        Some(elapsed) if elapsed < PUNCH_DURATION => {
+            effects.punch_elapsed = Some(elapsed + dt);
+            1.0 + effects.punch_strength * (std::f32::consts::PI * elapsed / PUNCH_DURATION).sin()
+        }
+        Some(_) => {
+            effects.punch_elapsed = None;
+            1.0
+        }
+        None => 1.0,
+    };
+    let punch_zoom = punch_zoom * (1.0 + effects.focus_zoom);
+    if camera_state.punch_zoom != punch_zoom {
+        camera_state.punch_zoom = punch_zoom;
+    }
+
+    let position = camera_state.user_pan + effects.focus + offset;
+    if transform.translation.truncate() != position {
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
@@ -0,0 +1,114 @@
+// Painted textures
+// A small software painter for the textures built at setup (the camel atlas, the board):
+// flat shapes blended one over another, in world units, then handed to the GPU as a
+// single image. Pixels are filled where their centers fall inside a shape.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::color::Srgba;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+/// An area of the world being painted, in sRGB with straight alpha
+pub struct Canvas {
+    area: Rect,
+    pixels_per_unit: f32,
+    width: u32,
+    height: u32,
+    pixels: Vec<Srgba>,
+}
+
+impl Canvas {
+    /// A transparent canvas covering `area` (world units, y up)
+    pub fn new(area: Rect, pixels_per_unit: f32) -> Self {
+        let width = (area.width() * pixels_per_unit).ceil() as u32;
+        let height = (area.height() * pixels_per_unit).ceil() as u32;
+        Self {
+            area,
+            pixels_per_unit,
+            width,
+            height,
+            pixels: vec![Srgba::NONE; (width * height) as usize],
+        }
+    }
+
+    /// Blend a rectangle over the canvas
+    pub fn fill_rect(&mut self, center: Vec2, size: Vec2, color: Color) {
+        self.fill(center - size / 2.0, center + size / 2.0, color, |_| true);
+    }
+
+    /// Blend a square turned 45 degrees, reaching `reach` from its center, over the canvas
+    pub fn fill_diamond(&mut self, center: Vec2, reach: f32, color: Color) {
+        self.fill(center - Vec2::splat(reach), center + Vec2::splat(reach), color, |point| {
+            (point - center).abs().element_sum() <= reach
+        });
+    }
+
+    /// Blend `color` over the pixels between `min` and `max` whose centers are `inside`
+    /// the shape
+    fn fill(&mut self, min: Vec2, max: Vec2, color: Color, inside: impl Fn(Vec2) -> bool) {
+        let color: Srgba = color.into();
+        // Texture rows run top to bottom
+        let columns = self.pixel_span(min.x - self.area.min.x, max.x - self.area.min.x, self.width);
+        let rows = self.pixel_span(self.area.max.y - max.y, self.area.max.y - min.y, self.height);
+
+        for row in rows {
+            for column in columns.clone() {
+                let point = Vec2::new(
+                    self.area.min.x + (column as f32 + 0.5) / self.pixels_per_unit,
+                    self.area.max.y - (row as f32 + 0.5) / self.pixels_per_unit,
+                );
+                if inside(point) {
+                    let index = (row * self.width + column) as usize;
+                    self.pixels[index] = blend_over(color, self.pixels[index]);
+                }
+            }
+        }
+    }
+
+    /// Pixels (out of `pixels`) whose centers lie between `from` and `to` world units
+    /// from the canvas' edge
+    fn pixel_span(&self, from: f32, to: f32, pixels: u32) -> std::ops::Range<u32> {
+        let first = (from * self.pixels_per_unit - 0.5).ceil().max(0.0) as u32;
+        let end = ((to * self.pixels_per_unit - 0.5).floor() + 1.0).clamp(0.0, pixels as f32) as u32;
+        first..end
+    }
+
+    pub fn into_image(self) -> Image {
+        let data = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| {
+                [pixel.red, pixel.green, pixel.blue, pixel.alpha]
+                    .map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8)
+            })
+            .collect();
+        Image::new(
+            Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::RENDER_WORLD,
+        )
+    }
+}
+
+/// `top` drawn over `bottom`
+fn blend_over(top: Srgba, bottom: Srgba) -> Srgba {
+    let alpha = top.alpha + bottom.alpha * (1.0 - top.alpha);
+    if alpha <= 0.0 {
+        return Srgba::NONE;
+    }
+    let mix = |top_channel: f32, bottom_channel: f32| {
+        (top_channel * top.alpha + bottom_channel * bottom.alpha * (1.0 - top.alpha)) / alpha
+    };
+    Srgba::new(
+        mix(top.red, bottom.red),
+        mix(top.green, bottom.green),
+        mix(top.blue, bottom.blue),
+        alpha,
+    )
+}
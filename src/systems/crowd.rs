@@ -0,0 +1,152 @@
+// Crowd ambience
+// A crowd murmurs around the track while a game is on, roars when a camel crosses the
+// finish line, and dies back down over the celebration. Synthesized like the music:
+// noise narrowed to the pitch of voices, rising and falling like people talking over
+// each other. It plays at the sound effect volume.
+
+use bevy::audio::{AudioSinkPlayback, Decodable, Source, Volume};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use crate::game::settings::Settings;
+use crate::game::state::GameState;
+use crate::systems::movement::MovementCompleteEvent;
+
+/// Samples per second the murmur is synthesized at
+const SAMPLE_RATE: u32 = 22_050;
+
+/// Filter strengths that keep the noise between about 200 Hz and 1 kHz, where voices are
+const LOW_PASS: f32 = 0.248;
+const HIGH_PASS: f32 = 0.055;
+
+/// How loud the murmur is when nothing's happening, next to a full roar
+const MURMUR_LEVEL: f32 = 0.25;
+
+/// How long the crowd takes to fade in or out as a game starts or is left (seconds)
+const FADE_DURATION: f32 = 1.5;
+
+/// How long the roar at the finish takes to die back down (seconds)
+const ROAR_DURATION: f32 = 4.0;
+
+/// The crowd as an audio asset
+#[derive(Asset, TypePath)]
+pub struct CrowdAudio;
+
+impl Decodable for CrowdAudio {
+    type DecoderItem = f32;
+    type Decoder = CrowdDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        CrowdDecoder {
+            sample: 0,
+            noise: 0x1234_5678,
+            low: 0.0,
+            lower: 0.0,
+        }
+    }
+}
+
+/// Synthesizes the murmur one sample at a time, forever
+pub struct CrowdDecoder {
+    sample: u64,
+    /// State of the random noise the voices are made from
+    noise: u32,
+    /// The noise with the highs taken off, then with only the lows left
+    low: f32,
+    lower: f32,
+}
+
+impl Iterator for CrowdDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let t = (self.sample % (SAMPLE_RATE as u64 * 3600)) as f32 / SAMPLE_RATE as f32;
+        self.sample += 1;
+
+        self.noise = self.noise.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        let white = ((self.noise >> 16) & 0x7fff) as f32 / 16_384.0 - 1.0;
+        self.low += (white - self.low) * LOW_PASS;
+        self.lower += (self.low - self.lower) * HIGH_PASS;
+        let voices = self.low - self.lower;
+
+        // Talk swelling and dropping at a few unrelated rates
+        let chatter = 0.6
+            + 0.2 * (TAU * 0.7 * t).sin()
+            + 0.1 * (TAU * 1.3 * t + 1.0).sin()
+            + 0.1 * (TAU * 2.9 * t + 2.0).sin();
+        Some((voices * chatter * 2.0).clamp(-1.0, 1.0))
+    }
+}
+
+impl Source for CrowdDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// The playing crowd
+#[derive(Component)]
+pub struct CrowdAmbience {
+    /// How far faded in, from silent (0.0) to full (1.0)
+    level: f32,
+    /// How much of the finish line roar is left, from none (0.0) to all of it (1.0)
+    roar: f32,
+}
+
+/// System to keep the crowd murmuring through play and the game end, roaring when a camel
+/// crosses the finish line. Runs on real time, like the music fades.
+pub fn crowd_ambience(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    time: Res<Time<Real>>,
+    mut movements: MessageReader<MovementCompleteEvent>,
+    (settings, global_volume): (Res<Settings>, Res<GlobalVolume>),
+    mut crowds: Query<(Entity, &mut CrowdAmbience, Option<&mut AudioSink>)>,
+    mut audio: ResMut<Assets<CrowdAudio>>,
+) {
+    let wanted = matches!(state.get(), GameState::Playing | GameState::GameEnd);
+    let finished = movements.read().any(|movement| movement.crossed_finish);
+
+    if wanted && crowds.is_empty() {
+        commands.spawn((
+            AudioPlayer(audio.add(CrowdAudio)),
+            PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+            CrowdAmbience { level: 0.0, roar: 0.0 },
+        ));
+        return;
+    }
+
+    let dt = time.delta_secs();
+    for (entity, mut crowd, sink) in &mut crowds {
+        if finished {
+            crowd.roar = 1.0;
+        }
+        crowd.roar = (crowd.roar - dt / ROAR_DURATION).max(0.0);
+        crowd.level = if wanted {
+            (crowd.level + dt / FADE_DURATION).min(1.0)
+        } else {
+            (crowd.level - dt / FADE_DURATION).max(0.0)
+        };
+        if !wanted && crowd.level <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if let Some(mut sink) = sink {
+            let loudness = crowd.level * (MURMUR_LEVEL + (1.0 - MURMUR_LEVEL) * crowd.roar) * settings.sfx_volume;
+            sink.set_volume(Volume::Linear(loudness) * global_volume.volume);
+        }
+    }
+}
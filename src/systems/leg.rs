@@ -1,71 +1,98 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use crate::components::*;
-use crate::systems::movement::{get_leading_camel, get_last_place_camel};
+use crate::game::log::{GameEvent, GameLog, MoneyTimeline};
+use crate::game::actions::{Applied, GameAction};
+use crate::systems::animation::DiceSprite;
+use crate::systems::movement::{get_leading_camel, get_last_place_camel, get_second_place_camel};
+use crate::systems::setup::PendingInitialMove;
+use crate::systems::turn::TableResources;
+use crate::ui::hud::UiState;
+
+/// The players have seen the leg scored (the modal's Start Next Leg button, or autoplay)
+#[derive(Message)]
+pub struct StartNextLegAction;
+
+/// The leg's rolled dice and spectator tiles, taken off the board once it's scored
+#[derive(SystemParam)]
+pub struct LegPieces<'w, 's> {
+    commands: Commands<'w, 's>,
+    spectator_tiles: Query<'w, 's, Entity, With<SpectatorTile>>,
+    dice_sprites: Query<'w, 's, Entity, With<DiceSprite>>,
+}
+
+/// System to score the leg once the scoring modal is dismissed: pay out the leg bets (the
+/// Sheikh's into its own purse) and clear the table for the next leg: dice back in the
+/// pyramid, leg tiles back on their stacks, and spectator tiles back to their owners
+pub fn start_next_leg_system(
+    mut requests: MessageReader<StartNextLegAction>,
+    mut ui_state: ResMut<UiState>,
+    mut table: TableResources,
+    mut turn_state: ResMut<TurnState>,
+    camels: Query<(&Camel, &BoardPosition), Without<PendingInitialMove>>,
+    leg_pieces: LegPieces,
+    (mut game_log, mut money_timeline): (ResMut<GameLog>, ResMut<MoneyTimeline>),
+) {
+    if requests.read().count() == 0 || !ui_state.show_leg_scoring {
+        return;
+    }
+
+    let first = get_leading_camel(&camels);
+    let second = get_second_place_camel(&camels);
+    game_log.record(turn_state.leg_number, GameEvent::LegScored { first, second });
+
+    // Ending a leg is never turned down
+    let _ = table.apply(&GameAction::EndLeg { first, second });
+    money_timeline.sample(format!("Leg {}", turn_state.leg_number), &table.players);
+
+    turn_state.leg_number += 1;
+    turn_state.action_taken = false;
+    turn_state.awaiting_action = true;
+    turn_state.leg_has_started = false;
+    turn_state.turn_delay_timer = 0.0;
+
+    let LegPieces { mut commands, spectator_tiles, dice_sprites } = leg_pieces;
+    for entity in spectator_tiles.iter().chain(dice_sprites.iter()) {
+        commands.entity(entity).despawn();
+    }
+
+    ui_state.show_leg_scoring = false;
+}
 
 /// System to calculate final game scores
 pub fn calculate_final_scores(
-    mut players: ResMut<Players>,
-    race_bets: Res<RaceBets>,
+    mut table: TableResources,
     camels: Query<(&Camel, &BoardPosition)>,
+    mut game_log: ResMut<GameLog>,
+    turn_state: Option<Res<TurnState>>,
+    mut money_timeline: ResMut<MoneyTimeline>,
 ) {
+    let leg = turn_state.as_ref().map_or(1, |t| t.leg_number);
+
     let winner = get_leading_camel(&camels);
     let loser = get_last_place_camel(&camels);
 
     info!("Game over! Winner: {:?}, Loser: {:?}", winner, loser);
 
-    // Score winner bets
-    let winner_payouts = [8, 5, 3, 2, 1];
-    let mut winner_payout_idx = 0;
-
-    for bet in &race_bets.winner_bets {
-        if Some(bet.camel) == winner {
-            // Correct bet
-            let payout = if winner_payout_idx < winner_payouts.len() {
-                winner_payouts[winner_payout_idx]
-            } else {
-                1
-            };
-            winner_payout_idx += 1;
-
-            if let Some(player) = players.players.iter_mut().find(|p| p.id == bet.player_id) {
-                player.money += payout;
-                info!("{} earned {} for correct winner bet on {:?}", player.name, payout, bet.camel);
-            }
-        } else {
-            // Wrong bet
-            if let Some(player) = players.players.iter_mut().find(|p| p.id == bet.player_id) {
-                player.money = (player.money - 1).max(0);
-                info!("{} lost 1 for wrong winner bet on {:?}", player.name, bet.camel);
-            }
-        }
+    // Winner bets are scored first, then loser bets
+    let Ok(Applied::GameEnded { payouts }) = table.apply(&GameAction::EndGame { winner, loser }) else {
+        return;
+    };
+    for payout in payouts {
+        let player = &table.players.players[payout.player_index];
+        let bet_type = if payout.is_winner_bet { "winner" } else { "loser" };
+        info!("{} got {} for {} bet on {:?}", player.name, payout.payout, bet_type, payout.bet.camel);
+        game_log.record(leg, GameEvent::RaceBetResolved {
+            player: player.name.clone(),
+            color: payout.bet.camel,
+            is_winner_bet: payout.is_winner_bet,
+            payout: payout.payout,
+        });
+        // Note each payout against its own bet, not all of them against the last
+        game_log.record_player_money(payout.player_index, &player.name, payout.money);
     }
 
-    // Score loser bets
-    let loser_payouts = [8, 5, 3, 2, 1];
-    let mut loser_payout_idx = 0;
-
-    for bet in &race_bets.loser_bets {
-        if Some(bet.camel) == loser {
-            // Correct bet
-            let payout = if loser_payout_idx < loser_payouts.len() {
-                loser_payouts[loser_payout_idx]
-            } else {
-                1
-            };
-            loser_payout_idx += 1;
-
-            if let Some(player) = players.players.iter_mut().find(|p| p.id == bet.player_id) {
-                player.money += payout;
-                info!("{} earned {} for correct loser bet on {:?}", player.name, payout, bet.camel);
-            }
-        } else {
-            // Wrong bet
-            if let Some(player) = players.players.iter_mut().find(|p| p.id == bet.player_id) {
-                player.money = (player.money - 1).max(0);
-                info!("{} lost 1 for wrong loser bet on {:?}", player.name, bet.camel);
-            }
-        }
-    }
+    money_timeline.sample("Final", &table.players);
 
     // Winner determination happens in the UI scoring phase after bet animations complete
 }
@@ -0,0 +1,158 @@
+// Leg recap
+// Once the last die of a leg has been rolled, a short recap plays before the scoring
+// modal: the camera sweeps along the track from start to finish, rings mark the camels
+// in first and second, and the dice tents flash in the order their dice were rolled.
+// `check_leg_end_system` starts it and waits for it to finish.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+use crate::components::*;
+use crate::systems::animation::{HighlightRing, TentFlash};
+use crate::systems::camera_effects::CameraEffects;
+use crate::ui::hud::UiState;
+
+/// How long the whole recap lasts (seconds)
+const RECAP_DURATION: f32 = 3.0;
+
+/// How long the camera takes to sweep from the start to the finish (seconds)
+const SWEEP_DURATION: f32 = 2.4;
+
+/// How far the camera zooms in halfway through the sweep (0.35 = 35%)
+const SWEEP_ZOOM: f32 = 0.35;
+
+/// When the first tent flashes, and the gap before each next one (seconds)
+const TENT_FLASH_START: f32 = 0.3;
+const TENT_FLASH_INTERVAL: f32 = 0.35;
+
+/// Ring colors for the camels in first and second
+const FIRST_RING_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+const SECOND_RING_COLOR: Color = Color::srgb(0.8, 0.8, 0.88);
+
+/// Where the leg recap is up to
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum LegRecapPhase {
+    /// No recap: the leg is still being played, or it's being scored
+    #[default]
+    Idle,
+    /// The leg just ended; the rings and tent flashes go up next frame
+    Starting,
+    /// The camera is sweeping the track
+    Playing { elapsed: f32 },
+}
+
+/// The recap played between the last roll of a leg and its scoring
+#[derive(Resource, Default)]
+pub struct LegRecap {
+    pub phase: LegRecapPhase,
+}
+
+impl LegRecap {
+    pub fn start(&mut self) {
+        self.phase = LegRecapPhase::Starting;
+    }
+
+    pub fn is_idle(&self) -> bool {
+        self.phase == LegRecapPhase::Idle
+    }
+}
+
+/// Point `progress` (0.0 to 1.0) of the way along the active track, from the first
+/// space to the last
+fn track_point(board: &GameBoard, progress: f32) -> Vec2 {
+    let along = progress.clamp(0.0, 1.0) * (TRACK_LENGTH - 1) as f32;
+    let space = (along.floor() as u8).min(TRACK_LENGTH - 2);
+    board
+        .get_position(space)
+        .lerp(board.get_position(space + 1), along - space as f32)
+}
+
+/// What the recap looks at on the table: the track, the camels on it, and the tents of
+/// the dice rolled this leg
+#[derive(SystemParam)]
+pub struct RecapTable<'w, 's> {
+    board: Res<'w, GameBoard>,
+    pyramid: Res<'w, Pyramid>,
+    camels: Query<'w, 's, (Entity, &'static BoardPosition), With<Camel>>,
+    tents: Query<'w, 's, (Entity, &'static DiceTent)>,
+}
+
+/// System to play the leg recap and open the scoring modal when it's done
+pub fn leg_recap_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut recap: ResMut<LegRecap>,
+    mut ui_state: ResMut<UiState>,
+    mut camera_effects: ResMut<CameraEffects>,
+    table: RecapTable,
+    (mut meshes, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<ColorMaterial>>),
+) {
+    let RecapTable { board, pyramid, camels, tents } = table;
+    match recap.phase {
+        LegRecapPhase::Idle => {}
+        LegRecapPhase::Starting => {
+            // Rings under the leg's first and second place camels
+            let mut standings: Vec<(Entity, u8, u8)> = camels
+                .iter()
+                .map(|(entity, pos)| (entity, pos.space_index, pos.stack_position))
+                .collect();
+            standings.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)));
+
+            let ring = meshes.add(Annulus::new(24.0, 28.0));
+            for ((camel, _, _), color) in standings.iter().zip([FIRST_RING_COLOR, SECOND_RING_COLOR]) {
+                let material = materials.add(ColorMaterial::from_color(color));
+                commands.entity(*camel).with_children(|parent| {
+                    parent.spawn((
+                        Mesh2d(ring.clone()),
+                        MeshMaterial2d(material),
+                        // Behind the camel's own layers
+                        Transform::from_xyz(0.0, 0.0, -0.6).with_scale(Vec3::ZERO),
+                        HighlightRing::new(RECAP_DURATION),
+                    ));
+                });
+            }
+
+            // Tents fill up in roll order, so their index is the order to flash them in
+            for (tent_entity, tent) in tents.iter() {
+                if tent.index >= pyramid.rolled_dice.len() {
+                    continue;
+                }
+                commands.entity(tent_entity).with_children(|parent| {
+                    parent.spawn((
+                        Sprite {
+                            color: Color::srgba(1.0, 0.95, 0.7, 0.0),
+                            custom_size: Some(Vec2::new(64.0, 84.0)),
+                            ..default()
+                        },
+                        Transform::from_xyz(0.0, -3.0, 1.0),
+                        TentFlash::after(TENT_FLASH_START + tent.index as f32 * TENT_FLASH_INTERVAL),
+                    ));
+                });
+            }
+
+            recap.phase = LegRecapPhase::Playing { elapsed: 0.0 };
+        }
+        LegRecapPhase::Playing { elapsed } => {
+            let elapsed = elapsed + time.delta_secs();
+            if elapsed >= RECAP_DURATION {
+                camera_effects.set_focus(Vec2::ZERO, 0.0);
+                recap.phase = LegRecapPhase::Idle;
+                ui_state.show_leg_scoring = true;
+                return;
+            }
+
+            // Ease out from the fitted view and back into it over the sweep
+            let progress = (elapsed / SWEEP_DURATION).min(1.0);
+            let weight = (PI * progress).sin();
+            camera_effects.set_focus(track_point(&board, progress) * weight, SWEEP_ZOOM * weight);
+            recap.phase = LegRecapPhase::Playing { elapsed };
+        }
+    }
+}
+
+/// System to drop an unfinished recap when the game is left, so the next one starts clean
+pub fn reset_leg_recap(mut recap: ResMut<LegRecap>, mut camera_effects: ResMut<CameraEffects>) {
+    recap.phase = LegRecapPhase::Idle;
+    camera_effects.set_focus(Vec2::ZERO, 0.0);
+}
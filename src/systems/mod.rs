@@ -1,7 +1,23 @@
 pub mod setup;
 pub mod input;
 pub mod movement;
+pub mod movement_preview;
+pub mod canvas;
+pub mod board_texture;
+pub mod camel_atlas;
 pub mod turn;
 pub mod leg;
+pub mod leg_recap;
 pub mod render;
 pub mod animation;
+pub mod stress_test;
+pub mod autoplay;
+pub mod quality;
+pub mod speech;
+pub mod music;
+pub mod crowd;
+pub mod sound_effects;
+pub mod turn_alert;
+pub mod achievements;
+pub mod camera_control;
+pub mod camera_effects;
@@ -1,7 +1,11 @@
 use bevy::prelude::*;
 use bevy::ecs::query::QueryFilter;
+use bevy::ecs::system::SystemParam;
 use crate::components::*;
+use crate::game::core::{plan_camel_move, plan_crazy_move, race_order, SPECTATOR_TILE_PAYOUT};
+use crate::game::log::{GameEvent, GameLog};
 use crate::systems::animation::{MovementAnimation, MultiStepMovementAnimation};
+use crate::systems::camera_effects::{CameraEffects, CRAZY_STACK_TRAUMA};
 
 /// Message fired when a camel needs to move
 #[derive(Message)]
@@ -76,6 +80,42 @@ fn generate_waypoints(
     waypoints
 }
 
+/// Every camel on the board as (entity, space, stack position), for `plan_camel_move`.
+/// `move_camel_system` moves camels by the plan, and the hover preview shows it.
+pub fn board_pieces<'a>(positions: impl Iterator<Item = (Entity, &'a BoardPosition)>) -> Vec<(Entity, u8, u8)> {
+    positions.map(|(entity, pos)| (entity, pos.space_index, pos.stack_position)).collect()
+}
+
+/// The spectator tiles a racing camel can land on, and the players they pay out to
+#[derive(SystemParam)]
+pub struct SpectatorTilePayouts<'w> {
+    placed_tiles: Option<Res<'w, PlacedSpectatorTiles>>,
+    players: Option<ResMut<'w, Players>>,
+    game_log: ResMut<'w, GameLog>,
+    turn_state: Option<Res<'w, TurnState>>,
+}
+
+impl SpectatorTilePayouts<'_> {
+    /// The (owner, is_oasis) of the tile on `space`, if there is one
+    fn tile_at(&self, space: u8) -> Option<(u8, bool)> {
+        self.placed_tiles.as_deref().and_then(|tiles| tiles.get_tile(space))
+    }
+
+    /// Pay the owner of the tile on `space_index` for a camel landing on it
+    fn pay(&mut self, owner_id: u8, space_index: u8) {
+        let Some(players) = self.players.as_mut() else { return };
+        if let Some(owner) = players.players.iter_mut().find(|p| p.id == owner_id) {
+            owner.money += SPECTATOR_TILE_PAYOUT;
+            info!("{} earned ${} from spectator tile!", owner.name, SPECTATOR_TILE_PAYOUT);
+            let leg = self.turn_state.as_ref().map_or(1, |t| t.leg_number);
+            self.game_log.record(leg, GameEvent::SpectatorTilePayout {
+                owner: owner.name.clone(),
+                space_index,
+            });
+        }
+    }
+}
+
 /// System to handle regular camel movement
 pub fn move_camel_system(
     mut commands: Commands,
@@ -84,94 +124,44 @@ pub fn move_camel_system(
     mut crazy_camels: Query<(Entity, &CrazyCamel, &mut BoardPosition, &mut Transform), Without<Camel>>,
     board: Res<GameBoard>,
     mut movement_complete: MessageWriter<MovementCompleteEvent>,
-    placed_tiles: Option<Res<PlacedSpectatorTiles>>,
-    mut players: Option<ResMut<Players>>,
+    mut payouts: SpectatorTilePayouts,
 ) {
     for event in events.read() {
         // Find the camel that needs to move
-        let mut moving_camel_entity = None;
-        let mut start_space = 0u8;
-        let mut start_stack_pos = 0u8;
-
-        for (entity, camel, pos, _) in camels.iter() {
-            if camel.color == event.color {
-                moving_camel_entity = Some(entity);
-                start_space = pos.space_index;
-                start_stack_pos = pos.stack_position;
-                break;
-            }
-        }
-
-        let Some(moving_entity) = moving_camel_entity else { continue };
-
-        // Calculate initial target space
-        let mut target_space = start_space + event.spaces;
-        let mut crossed_finish = target_space >= TRACK_LENGTH;
-        let mut land_underneath = false;  // For mirage tiles
-
-        // Check for spectator tile at target space
-        if !crossed_finish {
-            if let Some(ref tiles) = placed_tiles {
-                if let Some((owner_id, is_oasis)) = tiles.get_tile(target_space) {
-                    // Pay the owner 1 coin
-                    if let Some(ref mut players) = players {
-                        if let Some(owner) = players.players.iter_mut().find(|p| p.id == owner_id) {
-                            owner.money += 1;
-                            info!("{} earned $1 from spectator tile!", owner.name);
-                        }
-                    }
-
-                    if is_oasis {
-                        // Oasis: move 1 more space forward, land on top
-                        target_space += 1;
-                        crossed_finish = target_space >= TRACK_LENGTH;
-                        info!("Oasis! Camel moves 1 extra space forward");
-                    } else {
-                        // Mirage: move 1 space backward, land underneath
-                        target_space = target_space.saturating_sub(1);
-                        land_underneath = true;
-                        info!("Mirage! Camel moves 1 space backward and lands underneath");
-                    }
-                }
-            }
-        }
-
-        // Collect all camels that need to move (the moving camel and all camels on top of it)
-        let mut camels_to_move: Vec<Entity> = Vec::new();
-
-        // Add the moving camel
-        camels_to_move.push(moving_entity);
-
-        // Find all camels stacked on top of the moving camel (same space, higher stack position)
-        for (entity, _, pos, _) in camels.iter() {
-            if pos.space_index == start_space && pos.stack_position > start_stack_pos {
-                camels_to_move.push(entity);
+        let Some(moving_entity) = camels
+            .iter()
+            .find(|(_, camel, _, _)| camel.color == event.color)
+            .map(|(entity, _, _, _)| entity)
+        else {
+            continue;
+        };
+
+        let pieces = board_pieces(
+            camels
+                .iter()
+                .map(|(entity, _, pos, _)| (entity, pos))
+                .chain(crazy_camels.iter().map(|(entity, _, pos, _)| (entity, pos))),
+        );
+        let Some(plan) = plan_camel_move(&pieces, moving_entity, event.spaces, |space| payouts.tile_at(space)) else {
+            continue;
+        };
+        let start_space = plan.start_space;
+        let crossed_finish = plan.crossed_finish;
+        let land_underneath = plan.lands_underneath();
+
+        if let Some((owner_id, is_oasis)) = plan.tile {
+            payouts.pay(owner_id, plan.rolled_space);
+
+            if is_oasis {
+                info!("Oasis! Camel moves 1 extra space forward");
+            } else {
+                info!("Mirage! Camel moves 1 space backward and lands underneath");
             }
         }
 
-        // Also check crazy camels on top
-        for (entity, _, pos, _) in crazy_camels.iter() {
-            if pos.space_index == start_space && pos.stack_position > start_stack_pos {
-                camels_to_move.push(entity);
-            }
-        }
-
-        // Sort by stack position so we maintain relative order
-        let mut camel_stack_positions: Vec<(Entity, u8)> = camels_to_move
-            .iter()
-            .filter_map(|&e| {
-                if let Ok((_, _, pos, _)) = camels.get(e) {
-                    Some((e, pos.stack_position))
-                } else if let Ok((_, _, pos, _)) = crazy_camels.get(e) {
-                    Some((e, pos.stack_position))
-                } else {
-                    None
-                }
-            })
-            .collect();
-        camel_stack_positions.sort_by_key(|(_, pos)| *pos);
-
-        let final_space = target_space.min(TRACK_LENGTH - 1);
+        let camel_stack_positions = &plan.moving;
+        let camels_to_move: Vec<Entity> = camel_stack_positions.iter().map(|(entity, _)| *entity).collect();
+        let final_space = plan.final_space;
 
         // Build stack heights array for intermediate hop positions
         // Camels should hop onto top of existing stacks at each space
@@ -192,7 +182,6 @@ pub fn move_camel_system(
 
         if land_underneath {
             // Landing underneath: shift existing camels up, place moving camels at bottom
-            let num_moving = camel_stack_positions.len() as u8;
 
             // First, collect entities at destination that need to be shifted (not the ones moving)
             let entities_to_shift: Vec<Entity> = camels
@@ -214,7 +203,7 @@ pub fn move_camel_system(
             // Shift existing camels up with animation (simple vertical shift)
             for entity in entities_to_shift {
                 if let Ok((_, _, mut pos, transform)) = camels.get_mut(entity) {
-                    pos.stack_position += num_moving;
+                    (pos.space_index, pos.stack_position) = plan.destination(&entity, pos.space_index, pos.stack_position);
                     let stack_offset = pos.stack_position as f32 * 25.0;
                     let base_pos = board.get_position(final_space);
                     let start = transform.translation;
@@ -225,7 +214,7 @@ pub fn move_camel_system(
 
             for entity in crazy_entities_to_shift {
                 if let Ok((_, _, mut pos, transform)) = crazy_camels.get_mut(entity) {
-                    pos.stack_position += num_moving;
+                    (pos.space_index, pos.stack_position) = plan.destination(&entity, pos.space_index, pos.stack_position);
                     let stack_offset = pos.stack_position as f32 * 25.0;
                     let base_pos = board.get_position(final_space);
                     let start = transform.translation;
@@ -236,10 +225,10 @@ pub fn move_camel_system(
 
             // Place moving camels at bottom with multi-step animation
             // Calculate the space before spectator tile effect for proper waypoint generation
-            let pre_spectator_space = start_space + event.spaces;
+            let pre_spectator_space = plan.rolled_space;
 
             for (i, (entity, _)) in camel_stack_positions.iter().enumerate() {
-                let new_stack_pos = i as u8;
+                let new_stack_pos = plan.landing_stack_position(i);
                 let z_index = 10.0 + new_stack_pos as f32;
 
                 // Generate waypoints from start to pre-desert-tile space
@@ -264,36 +253,22 @@ pub fn move_camel_system(
                 }
 
                 if let Ok((_, _, mut pos, _)) = camels.get_mut(*entity) {
-                    pos.space_index = final_space;
-                    pos.stack_position = new_stack_pos;
+                    (pos.space_index, pos.stack_position) = plan.destination(entity, pos.space_index, pos.stack_position);
                     commands.entity(*entity).insert(MultiStepMovementAnimation::new(waypoints.clone(), CAMEL_HOP_DURATION));
                 } else if let Ok((_, _, mut pos, _)) = crazy_camels.get_mut(*entity) {
-                    pos.space_index = final_space;
-                    pos.stack_position = new_stack_pos;
+                    (pos.space_index, pos.stack_position) = plan.destination(entity, pos.space_index, pos.stack_position);
                     commands.entity(*entity).insert(MultiStepMovementAnimation::new(waypoints.clone(), CAMEL_HOP_DURATION));
                 }
             }
         } else {
-            // Normal landing on top
-            // Count how many camels are already at the target space (excluding moving ones)
-            let mut target_stack_height = 0u8;
-            for (entity, _, pos, _) in camels.iter() {
-                if pos.space_index == final_space && !camels_to_move.contains(&entity) {
-                    target_stack_height = target_stack_height.max(pos.stack_position + 1);
-                }
-            }
-            for (entity, _, pos, _) in crazy_camels.iter() {
-                if pos.space_index == final_space && !camels_to_move.contains(&entity) {
-                    target_stack_height = target_stack_height.max(pos.stack_position + 1);
-                }
-            }
+            // Normal landing on top of the camels already there
 
             // Move all the camels with multi-step animation
             // Calculate the space before spectator tile effect for proper waypoint generation
-            let pre_spectator_space = start_space + event.spaces;
+            let pre_spectator_space = plan.rolled_space;
 
             for (i, (entity, _old_stack_pos)) in camel_stack_positions.iter().enumerate() {
-                let new_stack_pos = target_stack_height + i as u8;
+                let new_stack_pos = plan.landing_stack_position(i);
                 let z_index = 10.0 + new_stack_pos as f32;
 
                 // Generate waypoints from start to pre-desert-tile space
@@ -323,12 +298,10 @@ pub fn move_camel_system(
                 }
 
                 if let Ok((_, _, mut pos, _)) = camels.get_mut(*entity) {
-                    pos.space_index = final_space;
-                    pos.stack_position = new_stack_pos;
+                    (pos.space_index, pos.stack_position) = plan.destination(entity, pos.space_index, pos.stack_position);
                     commands.entity(*entity).insert(MultiStepMovementAnimation::new(waypoints.clone(), CAMEL_HOP_DURATION));
                 } else if let Ok((_, _, mut pos, _)) = crazy_camels.get_mut(*entity) {
-                    pos.space_index = final_space;
-                    pos.stack_position = new_stack_pos;
+                    (pos.space_index, pos.stack_position) = plan.destination(entity, pos.space_index, pos.stack_position);
                     commands.entity(*entity).insert(MultiStepMovementAnimation::new(waypoints.clone(), CAMEL_HOP_DURATION));
                 }
             }
@@ -345,58 +318,40 @@ pub fn move_crazy_camel_system(
     mut camels: Query<(Entity, &Camel, &mut BoardPosition, &mut Transform)>,
     mut crazy_camels: Query<(Entity, &CrazyCamel, &mut BoardPosition, &mut Transform), Without<Camel>>,
     board: Res<GameBoard>,
+    mut camera_effects: ResMut<CameraEffects>,
 ) {
     for event in events.read() {
         // Find the crazy camel that needs to move
-        let mut moving_entity = None;
-        let mut start_space = 0u8;
-        let mut start_stack_pos = 0u8;
-
-        for (entity, camel, pos, _) in crazy_camels.iter() {
-            if camel.color == event.color {
-                moving_entity = Some(entity);
-                start_space = pos.space_index;
-                start_stack_pos = pos.stack_position;
-                break;
-            }
-        }
-
-        let Some(moving_ent) = moving_entity else { continue };
-
-        // Crazy camels move backwards
-        let target_space = start_space.saturating_sub(event.spaces);
-
-        // Collect all camels/crazy camels on top
-        let mut entities_to_move: Vec<(Entity, u8, bool)> = Vec::new(); // (entity, stack_pos, is_crazy)
-
-        entities_to_move.push((moving_ent, start_stack_pos, true));
-
-        for (entity, _, pos, _) in camels.iter() {
-            if pos.space_index == start_space && pos.stack_position > start_stack_pos {
-                entities_to_move.push((entity, pos.stack_position, false));
-            }
-        }
-
-        for (entity, _, pos, _) in crazy_camels.iter() {
-            if entity != moving_ent && pos.space_index == start_space && pos.stack_position > start_stack_pos {
-                entities_to_move.push((entity, pos.stack_position, true));
-            }
-        }
-
-        entities_to_move.sort_by_key(|(_, pos, _)| *pos);
+        let Some(moving_ent) = crazy_camels
+            .iter()
+            .find(|(_, camel, _, _)| camel.color == event.color)
+            .map(|(entity, _, _, _)| entity)
+        else {
+            continue;
+        };
+
+        // Crazy camels move backwards, carrying everything on top and landing ON TOP
+        let pieces = board_pieces(
+            camels
+                .iter()
+                .map(|(entity, _, pos, _)| (entity, pos))
+                .chain(crazy_camels.iter().map(|(entity, _, pos, _)| (entity, pos))),
+        );
+        let Some(plan) = plan_crazy_move(&pieces, moving_ent, event.spaces) else { continue };
+        let start_space = plan.start_space;
+        let target_space = plan.final_space;
+        let target_stack_height = plan.landing_height;
+
+        // (entity, stack_pos, is_crazy), bottom first
+        let entities_to_move: Vec<(Entity, u8, bool)> = plan
+            .moving
+            .iter()
+            .map(|&(entity, stack)| (entity, stack, crazy_camels.get(entity).is_ok()))
+            .collect();
 
-        // Crazy camels land ON TOP of existing camels when they move
-        // Count how many camels are already at the target space (excluding moving ones)
-        let mut target_stack_height = 0u8;
-        for (entity, _, pos, _) in camels.iter() {
-            if pos.space_index == target_space && !entities_to_move.iter().any(|(e, _, _)| *e == entity) {
-                target_stack_height = target_stack_height.max(pos.stack_position + 1);
-            }
-        }
-        for (entity, _, pos, _) in crazy_camels.iter() {
-            if pos.space_index == target_space && !entities_to_move.iter().any(|(e, _, _)| *e == entity) {
-                target_stack_height = target_stack_height.max(pos.stack_position + 1);
-            }
+        // Racing camels dragged backwards: shake the camera
+        if entities_to_move.iter().any(|(_, _, is_crazy)| !is_crazy) {
+            camera_effects.add_trauma(CRAZY_STACK_TRAUMA);
         }
 
         // Build stack heights array for intermediate hop positions
@@ -432,74 +387,39 @@ pub fn move_crazy_camel_system(
 
             if *is_crazy {
                 if let Ok((_, _, mut pos, _)) = crazy_camels.get_mut(*entity) {
-                    pos.space_index = target_space;
-                    pos.stack_position = new_stack_pos;
+                    (pos.space_index, pos.stack_position) = plan.destination(entity, pos.space_index, pos.stack_position);
                     commands.entity(*entity).insert(MultiStepMovementAnimation::new(waypoints.clone(), CAMEL_HOP_DURATION));
                 }
             } else if let Ok((_, _, mut pos, _)) = camels.get_mut(*entity) {
-                pos.space_index = target_space;
-                pos.stack_position = new_stack_pos;
+                (pos.space_index, pos.stack_position) = plan.destination(entity, pos.space_index, pos.stack_position);
                 commands.entity(*entity).insert(MultiStepMovementAnimation::new(waypoints.clone(), CAMEL_HOP_DURATION));
             }
         }
     }
 }
 
+/// Racing camels in race order, leader first
+fn camel_rankings<F: QueryFilter>(camels: &Query<(&Camel, &BoardPosition), F>) -> Vec<CamelColor> {
+    race_order(camels.iter().map(|(camel, pos)| (camel.color, pos.space_index, pos.stack_position)))
+}
+
 /// Get the leading camel (first place)
 pub fn get_leading_camel<F: QueryFilter>(
     camels: &Query<(&Camel, &BoardPosition), F>,
 ) -> Option<CamelColor> {
-    let mut best: Option<(CamelColor, u8, u8)> = None; // (color, space, stack_pos)
-
-    for (camel, pos) in camels.iter() {
-        match best {
-            None => best = Some((camel.color, pos.space_index, pos.stack_position)),
-            Some((_, best_space, best_stack)) => {
-                if pos.space_index > best_space ||
-                   (pos.space_index == best_space && pos.stack_position > best_stack) {
-                    best = Some((camel.color, pos.space_index, pos.stack_position));
-                }
-            }
-        }
-    }
-
-    best.map(|(color, _, _)| color)
+    camel_rankings(camels).first().copied()
 }
 
 /// Get the second place camel
 pub fn get_second_place_camel<F: QueryFilter>(
     camels: &Query<(&Camel, &BoardPosition), F>,
 ) -> Option<CamelColor> {
-    let mut rankings: Vec<(CamelColor, u8, u8)> = camels
-        .iter()
-        .map(|(c, p)| (c.color, p.space_index, p.stack_position))
-        .collect();
-
-    // Sort by space descending, then stack position descending
-    rankings.sort_by(|a, b| {
-        b.1.cmp(&a.1).then(b.2.cmp(&a.2))
-    });
-
-    rankings.get(1).map(|(color, _, _)| *color)
+    camel_rankings(camels).get(1).copied()
 }
 
 /// Get the last place camel (for end-game betting)
 pub fn get_last_place_camel<F: QueryFilter>(
     camels: &Query<(&Camel, &BoardPosition), F>,
 ) -> Option<CamelColor> {
-    let mut worst: Option<(CamelColor, u8, u8)> = None;
-
-    for (camel, pos) in camels.iter() {
-        match worst {
-            None => worst = Some((camel.color, pos.space_index, pos.stack_position)),
-            Some((_, worst_space, worst_stack)) => {
-                if pos.space_index < worst_space ||
-                   (pos.space_index == worst_space && pos.stack_position < worst_stack) {
-                    worst = Some((camel.color, pos.space_index, pos.stack_position));
-                }
-            }
-        }
-    }
-
-    worst.map(|(color, _, _)| color)
+    camel_rankings(camels).last().copied()
 }
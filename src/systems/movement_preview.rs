@@ -0,0 +1,210 @@
+// Movement preview
+// Hovering a camel, on the board or in the positions panel, shows translucent ghosts
+// where it would land for a roll of 1, 2 or 3, carrying whatever rides on it and
+// following any spectator tile on the way. The landings come from the same
+// `plan_camel_move` that `move_camel_system` moves camels by, run as a dry run.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContextSettings};
+
+use crate::components::*;
+use crate::game::core::{plan_camel_move, MAX_DIE_VALUE};
+use crate::game::state::GameState;
+use crate::systems::animation::Moving;
+use crate::systems::movement::board_pieces;
+use crate::systems::camel_atlas::{CamelAtlas, CamelSkin};
+use crate::ui::hud::UiState;
+
+/// How opaque the ghosts are
+const GHOST_ALPHA: f32 = 0.35;
+
+/// Ghosts draw above every real camel
+const GHOST_Z: f32 = 30.0;
+
+/// Half the size of a camel's clickable area on the board (world units)
+const CAMEL_HALF_SIZE: Vec2 = Vec2::new(25.0, 18.0);
+
+/// Marker for a ghost camel or roll label spawned by the preview
+#[derive(Component)]
+pub struct MovementPreviewGhost;
+
+/// What the ghosts on screen were drawn for, so they're only rebuilt when it changes
+#[derive(Default, PartialEq)]
+pub struct ShownPreview {
+    camel: Option<CamelColor>,
+    pieces: Vec<(Entity, u8, u8)>,
+    tiles: Vec<(u8, u8, bool)>,
+}
+
+/// Where the cursor points on the board, when it isn't over the UI
+#[derive(SystemParam)]
+pub struct BoardCursor<'w, 's> {
+    windows: Query<'w, 's, &'static Window>,
+    camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<crate::GameCamera>>,
+    egui_settings: Query<'w, 's, &'static EguiContextSettings>,
+}
+
+impl BoardCursor<'_, '_> {
+    /// The cursor's world position, if it is over the board area the HUD leaves free
+    fn world_position(&self, ui_state: &UiState) -> Option<Vec2> {
+        let cursor = self.windows.single().ok()?.cursor_position()?;
+        let egui_scale = self.egui_settings.iter().next().map_or(1.0, |settings| settings.scale_factor);
+        let on_board = ui_state
+            .game_board_rect
+            .is_some_and(|rect| rect.contains(egui::pos2(cursor.x / egui_scale, cursor.y / egui_scale)));
+        if !on_board {
+            return None;
+        }
+        let (camera, camera_transform) = self.camera.single().ok()?;
+        camera.viewport_to_world_2d(camera_transform, cursor).ok()
+    }
+}
+
+/// The camels a preview is drawn for, and whether any are still on the move
+#[derive(SystemParam)]
+pub struct PreviewCamels<'w, 's> {
+    camels: Query<'w, 's, (Entity, &'static Camel, &'static BoardPosition, &'static GlobalTransform)>,
+    crazy_camels: Query<'w, 's, (Entity, &'static CrazyCamel, &'static BoardPosition)>,
+    moving: Query<'w, 's, (), Moving>,
+}
+
+impl PreviewCamels<'_, '_> {
+    /// How `entity` is drawn, if it is a camel
+    fn skin(&self, entity: Entity) -> Option<CamelSkin> {
+        if let Ok((_, camel, _, _)) = self.camels.get(entity) {
+            Some(CamelSkin::Racing(camel.color))
+        } else if let Ok((_, camel, _)) = self.crazy_camels.get(entity) {
+            Some(CamelSkin::Crazy(camel.color))
+        } else {
+            None
+        }
+    }
+}
+
+/// System to show the landing ghosts for the hovered camel
+pub fn update_movement_preview(
+    mut commands: Commands,
+    ui_state: Res<UiState>,
+    cursor: BoardCursor,
+    preview_camels: PreviewCamels,
+    (board, placed_tiles, atlas): (Res<GameBoard>, Option<Res<PlacedSpectatorTiles>>, Option<Res<CamelAtlas>>),
+    ghosts: Query<Entity, With<MovementPreviewGhost>>,
+    mut shown: Local<ShownPreview>,
+) {
+    let PreviewCamels { camels, crazy_camels, moving } = &preview_camels;
+
+    // Panel hover first, then a camel under the cursor on the board (topmost wins).
+    // Nothing while camels are on the move: the board is about to change.
+    let hovered = if !ui_state.initial_rolls_complete || !moving.is_empty() {
+        None
+    } else {
+        ui_state.preview_camel.or_else(|| {
+            let world_pos = cursor.world_position(&ui_state)?;
+            camels
+                .iter()
+                .filter(|(_, _, _, transform)| {
+                    let offset = (world_pos - transform.translation().truncate()).abs();
+                    offset.x <= CAMEL_HALF_SIZE.x && offset.y <= CAMEL_HALF_SIZE.y
+                })
+                .max_by_key(|(_, _, pos, _)| pos.stack_position)
+                .map(|(_, camel, _, _)| camel.color)
+        })
+    };
+
+    let pieces = board_pieces(
+        camels
+            .iter()
+            .map(|(entity, _, pos, _)| (entity, pos))
+            .chain(crazy_camels.iter().map(|(entity, _, pos)| (entity, pos))),
+    );
+    let mut tiles: Vec<(u8, u8, bool)> = placed_tiles
+        .as_deref()
+        .map(|tiles| tiles.tiles.iter().map(|(&space, &(owner, is_oasis))| (space, owner, is_oasis)).collect())
+        .unwrap_or_default();
+    tiles.sort();
+
+    let wanted = ShownPreview { camel: hovered, pieces, tiles };
+    if *shown == wanted {
+        return;
+    }
+
+    for ghost in ghosts.iter() {
+        commands.entity(ghost).despawn();
+    }
+
+    if let Some(color) = wanted.camel {
+        let mover = camels.iter().find(|(_, camel, _, _)| camel.color == color).map(|(entity, _, _, _)| entity);
+        if let (Some(mover), Some(atlas)) = (mover, atlas.as_deref()) {
+            let pieces = &wanted.pieces;
+            spawn_ghosts(&mut commands, atlas, &board, placed_tiles.as_deref(), pieces, mover, &preview_camels);
+        }
+    }
+
+    *shown = wanted;
+}
+
+/// Spawn a ghost of the moving stack for each roll, with the roll on the hovered camel
+fn spawn_ghosts(
+    commands: &mut Commands,
+    atlas: &CamelAtlas,
+    board: &GameBoard,
+    placed_tiles: Option<&PlacedSpectatorTiles>,
+    pieces: &[(Entity, u8, u8)],
+    mover: Entity,
+    camels: &PreviewCamels,
+) {
+    // Rolls that land the hovered camel in the same spot share one label
+    let mut labels: Vec<(Vec2, String)> = Vec::new();
+
+    for roll in 1..=MAX_DIE_VALUE {
+        let Some(plan) = plan_camel_move(pieces, mover, roll, |space| placed_tiles.and_then(|tiles| tiles.get_tile(space)))
+        else {
+            continue;
+        };
+        let base = board.get_position(plan.final_space);
+        let finish = if plan.crossed_finish { " finish" } else { "" };
+
+        // The hovered camel is at the bottom of the moving stack
+        let landing = base + Vec2::new(0.0, plan.landing_stack_position(0) as f32 * 25.0);
+        if let Some((_, text)) = labels.iter_mut().find(|(at, _)| at.distance(landing) < 1.0) {
+            text.push_str(&format!("/{}{}", roll, finish));
+            continue;
+        }
+        labels.push((landing, format!("{}{}", roll, finish)));
+
+        for (i, (entity, _)) in plan.moving.iter().enumerate() {
+            let position = base + Vec2::new(0.0, plan.landing_stack_position(i) as f32 * 25.0);
+
+            // Facing as in the movement animation: racing camels toward the finish,
+            // crazy camels away from it
+            let Some(skin) = camels.skin(*entity) else { continue };
+            let face_left = (position.y > 0.0) != matches!(skin, CamelSkin::Crazy(_));
+
+            commands.spawn((
+                DespawnOnExit(GameState::Playing),
+                MovementPreviewGhost,
+                Sprite {
+                    color: Color::WHITE.with_alpha(GHOST_ALPHA),
+                    ..atlas.sprite(skin)
+                },
+                Transform::from_translation(position.extend(GHOST_Z + i as f32))
+                    .with_scale(Vec3::new(if face_left { -1.0 } else { 1.0 }, 1.0, 1.0)),
+            ));
+        }
+    }
+
+    for (position, text) in labels {
+        commands.spawn((
+            DespawnOnExit(GameState::Playing),
+            MovementPreviewGhost,
+            Text2d::new(text),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Transform::from_translation((position + Vec2::new(0.0, 28.0)).extend(GHOST_Z + 10.0)),
+        ));
+    }
+}
@@ -0,0 +1,271 @@
+// Background music
+// A looping desert soundtrack with one track for the menus, one for play and one for the
+// game end celebration, crossfaded as the game moves between states. The tracks are
+// synthesized as they play rather than loaded from files: a drone, a plucked melody in
+// the Hijaz mode and a hand drum, so the web build has nothing extra to download.
+
+use bevy::audio::{AudioSinkPlayback, Decodable, Source, Volume};
+use bevy::prelude::*;
+use std::f64::consts::TAU;
+use std::time::Duration;
+
+use crate::game::settings::Settings;
+use crate::game::state::GameState;
+
+/// Samples per second the tracks are synthesized at
+const SAMPLE_RATE: u32 = 22_050;
+
+/// How long one track takes to fade into the next (seconds)
+const CROSSFADE_DURATION: f32 = 2.0;
+
+/// Semitones above the tonic of each degree of the Hijaz mode, over two octaves
+const HIJAZ: [f32; 15] = [0.0, 1.0, 4.0, 5.0, 7.0, 8.0, 10.0, 12.0, 13.0, 16.0, 17.0, 19.0, 20.0, 22.0, 24.0];
+
+/// A rest in a melody
+const R: i8 = -1;
+
+/// Which part of the soundtrack plays
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MusicTrack {
+    Menu,
+    Gameplay,
+    Celebration,
+}
+
+impl MusicTrack {
+    pub fn for_state(state: GameState) -> Self {
+        match state {
+            GameState::MainMenu | GameState::Lobby | GameState::WaitingRoom => Self::Menu,
+            GameState::Playing => Self::Gameplay,
+            GameState::GameEnd => Self::Celebration,
+        }
+    }
+
+    fn score(self) -> &'static Score {
+        match self {
+            Self::Menu => &MENU_SCORE,
+            Self::Gameplay => &GAMEPLAY_SCORE,
+            Self::Celebration => &CELEBRATION_SCORE,
+        }
+    }
+}
+
+/// How a track goes: one melody note and one drum stroke per eighth note, each looping
+struct Score {
+    /// Frequency of the mode's first degree (Hz)
+    tonic: f64,
+    /// Beats per minute
+    tempo: f64,
+    /// Degrees of `HIJAZ`, or `R` to rest
+    melody: &'static [i8],
+    /// 'D' for the low "dum", 't' for the sharp "tek", anything else to rest
+    drums: &'static [u8],
+    /// How loud the drone, melody and drum are in the mix
+    levels: [f32; 3],
+}
+
+/// Slow and open: a long drone with a sparse melody and a heartbeat drum
+const MENU_SCORE: Score = Score {
+    tonic: 146.83,
+    tempo: 76.0,
+    melody: &[
+        7, R, R, 8, 7, R, 5, R, 4, R, R, R, R, R, R, R, //
+        4, 5, 7, R, 8, R, 7, R, 5, 4, R, 1, 0, R, R, R,
+    ],
+    drums: b"D.......D...t...",
+    levels: [0.35, 0.45, 0.25],
+};
+
+/// The maqsum rhythm under a quiet melody that stays out of the way of the game
+const GAMEPLAY_SCORE: Score = Score {
+    tonic: 146.83,
+    tempo: 104.0,
+    melody: &[
+        0, R, 4, R, 5, R, 7, R, 8, 7, R, 5, 4, R, R, R, //
+        7, R, 8, R, 10, R, 8, 7, 5, R, 4, R, 5, R, R, R, //
+        11, R, R, 10, 8, R, 7, R, 8, R, 7, 5, 4, R, R, R, //
+        5, 4, 1, R, 0, R, R, R, R, R, R, R, R, R, R, R,
+    ],
+    drums: b"Dt.tD.t.",
+    levels: [0.2, 0.3, 0.4],
+};
+
+/// Fast runs up the mode over a busy drum, an octave up
+const CELEBRATION_SCORE: Score = Score {
+    tonic: 293.66,
+    tempo: 132.0,
+    melody: &[
+        0, 4, 5, 7, 8, 10, 12, R, 12, 10, 12, R, 14, R, 12, R, //
+        7, 8, 10, 12, 10, 8, 7, R, 5, 7, 8, 7, 5, 4, 0, R,
+    ],
+    drums: b"D.tDt.tt",
+    levels: [0.2, 0.45, 0.45],
+};
+
+/// A soundtrack track as an audio asset
+#[derive(Asset, TypePath)]
+pub struct Soundtrack {
+    track: MusicTrack,
+}
+
+impl Decodable for Soundtrack {
+    type DecoderItem = f32;
+    type Decoder = SoundtrackDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        SoundtrackDecoder {
+            score: self.track.score(),
+            sample: 0,
+            noise: 0x2545_f491,
+        }
+    }
+}
+
+/// Synthesizes a track one sample at a time, forever
+pub struct SoundtrackDecoder {
+    score: &'static Score,
+    sample: u64,
+    /// State of the random noise the "tek" is made from
+    noise: u32,
+}
+
+impl SoundtrackDecoder {
+    fn next_noise(&mut self) -> f32 {
+        self.noise = self.noise.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        ((self.noise >> 16) & 0x7fff) as f32 / 16_384.0 - 1.0
+    }
+}
+
+impl Iterator for SoundtrackDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let score = self.score;
+        let t = self.sample as f64 / SAMPLE_RATE as f64;
+        self.sample += 1;
+
+        // Time since the current eighth note started
+        let step_length = 30.0 / score.tempo;
+        let step = (t / step_length) as usize;
+        let since = t - step as f64 * step_length;
+
+        // Drone: the tonic an octave down with its fifth, slowly breathing
+        let drone_frequency = score.tonic / 2.0;
+        let breath = 0.8 + 0.2 * (TAU * 0.25 * t).sin();
+        let drone = breath
+            * ((TAU * drone_frequency * t).sin()
+                + 0.5 * (TAU * drone_frequency * 1.5 * t).sin()
+                + 0.25 * (TAU * drone_frequency * 2.0 * t).sin());
+
+        // Melody: a plucked string, bright at first and dying away
+        let degree = score.melody[step % score.melody.len()];
+        let melody = if degree == R {
+            0.0
+        } else {
+            let frequency = score.tonic * 2f64.powf(HIJAZ[degree as usize] as f64 / 12.0);
+            let phase = TAU * frequency * since;
+            let envelope = (since * 300.0).min(1.0) * (-since * 5.0).exp();
+            envelope * (phase.sin() + 0.4 * (2.0 * phase).sin() + 0.2 * (3.0 * phase).sin())
+        };
+
+        // Drum: the "dum" is a thump falling in pitch, the "tek" a short burst of noise
+        let drum = match score.drums[step % score.drums.len()] {
+            b'D' => {
+                let phase = TAU * (60.0 * since + 50.0 * (1.0 - (-since * 30.0).exp()) / 30.0);
+                phase.sin() * (-since * 14.0).exp()
+            }
+            b't' => self.next_noise() as f64 * 0.5 * (-since * 70.0).exp(),
+            _ => 0.0,
+        };
+
+        let [drone_level, melody_level, drum_level] = score.levels;
+        let mix = drone as f32 * drone_level + melody as f32 * melody_level + drum as f32 * drum_level;
+        // Soft clip so loud moments round off instead of crackling
+        Some(mix.tanh() * 0.8)
+    }
+}
+
+impl Source for SoundtrackDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// A playing track, fading in, or fading out to make way for the next
+#[derive(Component)]
+pub struct MusicPlayer {
+    track: MusicTrack,
+    /// How far faded in, from silent (0.0) to full (1.0)
+    level: f32,
+    fading_out: bool,
+}
+
+/// System to start the track for the current state, fading out whatever played before
+pub fn switch_music(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    mut players: Query<&mut MusicPlayer>,
+    mut soundtracks: ResMut<Assets<Soundtrack>>,
+) {
+    let track = MusicTrack::for_state(*state.get());
+
+    // A track still fading out comes back rather than starting over
+    let mut playing = false;
+    for mut player in &mut players {
+        let wanted = player.track == track && !playing;
+        playing |= wanted;
+        if player.fading_out == wanted {
+            player.fading_out = !wanted;
+        }
+    }
+
+    if !playing {
+        commands.spawn((
+            AudioPlayer(soundtracks.add(Soundtrack { track })),
+            PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+            MusicPlayer {
+                track,
+                level: 0.0,
+                fading_out: false,
+            },
+        ));
+    }
+}
+
+/// System to fade tracks in and out at the music and master volumes, dropping the ones
+/// faded out.
+/// Runs on real time, so the animation speed doesn't hurry the fades.
+pub fn crossfade_music(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    (settings, global_volume): (Res<Settings>, Res<GlobalVolume>),
+    mut players: Query<(Entity, &mut MusicPlayer, Option<&mut AudioSink>)>,
+) {
+    let step = time.delta_secs() / CROSSFADE_DURATION;
+    for (entity, mut player, sink) in &mut players {
+        player.level = if player.fading_out {
+            (player.level - step).max(0.0)
+        } else {
+            (player.level + step).min(1.0)
+        };
+        if player.fading_out && player.level <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if let Some(mut sink) = sink {
+            sink.set_volume(Volume::Linear(player.level * settings.music_volume) * global_volume.volume);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+// Adaptive rendering quality
+// Low-end devices (mostly phones running the wasm build) get lower resolution text and
+// meshes and no drop shadows. The tier is picked once at startup from the browser's
+// reported core count and a quick CPU micro-benchmark.
+
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+/// Workload for the startup benchmark (iterations of a small float loop)
+const BENCHMARK_ITERATIONS: u32 = 2_000_000;
+
+/// Benchmark time above which the device is treated as low-end
+const LOW_END_BENCHMARK_MS: f32 = 25.0;
+
+/// Devices reporting this many logical cores or fewer are treated as low-end
+const LOW_END_MAX_CORES: u32 = 2;
+
+/// egui memory key for the low quality flag read by `quality_shadow`
+pub const LOW_QUALITY_EGUI_ID: &str = "low_quality_tier";
+
+/// Rendering quality, read by spawn and drawing code
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum QualityTier {
+    #[default]
+    High,
+    Low,
+}
+
+impl QualityTier {
+    pub fn shadows(&self) -> bool {
+        *self == QualityTier::High
+    }
+
+    /// Multiplier for world text font sizes (the text is scaled back up to the same size)
+    pub fn text_resolution(&self) -> f32 {
+        match self {
+            QualityTier::High => 1.0,
+            QualityTier::Low => 0.5,
+        }
+    }
+
+    /// Segments used for circle meshes
+    pub fn circle_resolution(&self) -> u32 {
+        match self {
+            QualityTier::High => 32,
+            QualityTier::Low => 12,
+        }
+    }
+}
+
+/// Marker for drop shadow sprites, hidden on low-end devices
+#[derive(Component)]
+pub struct DropShadow;
+
+/// Logical cores reported by the browser (0 if unknown)
+#[cfg(target_arch = "wasm32")]
+fn hardware_concurrency() -> u32 {
+    crate::network::js_bindings::get_hardware_concurrency()
+}
+
+/// Native builds don't check (see `detect_quality_tier`)
+#[cfg(not(target_arch = "wasm32"))]
+fn hardware_concurrency() -> u32 {
+    0
+}
+
+/// Time a fixed float workload, in milliseconds
+fn run_benchmark() -> f32 {
+    let start = Instant::now();
+    let mut x = 0.5_f32;
+    for i in 0..BENCHMARK_ITERATIONS {
+        x = std::hint::black_box((x * 1.000_1 + i as f32 * 0.000_01).sin());
+    }
+    std::hint::black_box(x);
+    start.elapsed().as_secs_f32() * 1000.0
+}
+
+/// System to pick the quality tier once at startup
+pub fn detect_quality_tier(mut commands: Commands) {
+    // Desktop builds always get full quality
+    if !cfg!(target_arch = "wasm32") {
+        commands.insert_resource(QualityTier::High);
+        return;
+    }
+
+    let cores = hardware_concurrency();
+    let benchmark_ms = run_benchmark();
+
+    let low_end = (cores > 0 && cores <= LOW_END_MAX_CORES) || benchmark_ms > LOW_END_BENCHMARK_MS;
+    let tier = if low_end { QualityTier::Low } else { QualityTier::High };
+    info!(
+        "Quality tier {:?} ({} cores reported, benchmark {:.1} ms)",
+        tier, cores, benchmark_ms
+    );
+    commands.insert_resource(tier);
+}
+
+/// System to hide newly spawned drop shadows on low-end devices
+pub fn hide_drop_shadows(
+    tier: Res<QualityTier>,
+    mut shadows: Query<&mut Visibility, Added<DropShadow>>,
+) {
+    if tier.shadows() {
+        return;
+    }
+    for mut visibility in shadows.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+/// System to turn off egui shadows and anti-aliasing on low-end devices.
+/// Runs every frame but only configures once, like `configure_fonts`.
+pub fn apply_ui_quality(
+    mut contexts: EguiContexts,
+    tier: Res<QualityTier>,
+    mut applied: Local<bool>,
+) {
+    if *applied || tier.shadows() {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    ctx.style_mut(|style| {
+        style.visuals.window_shadow = egui::Shadow::NONE;
+        style.visuals.popup_shadow = egui::Shadow::NONE;
+    });
+    ctx.tessellation_options_mut(|options| options.feathering = false);
+    ctx.data_mut(|data| data.insert_temp(egui::Id::new(LOW_QUALITY_EGUI_ID), true));
+    *applied = true;
+}
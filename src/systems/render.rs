@@ -1,2 +1,175 @@
 // Sprite rendering and animations
-// Will be expanded in later phases
+// Analytical overlays drawn on the game board
+
+use bevy::prelude::*;
+use crate::components::*;
+use crate::game::probability::{reachable_range, RaceSnapshot, ReachableRange};
+use crate::game::state::GameState;
+use crate::systems::animation::{spawn_crown, CrownDropAnimation, CrownMarker};
+use crate::systems::movement::get_leading_camel;
+use crate::systems::setup::PendingInitialMove;
+use crate::ui::hud::UiState;
+
+/// Marker component for the dots of the leader's race line projection
+#[derive(Component)]
+pub struct LeaderProjectionDot;
+
+/// Tracks what the projection was last drawn for so dots are only rebuilt after a roll
+#[derive(Resource, Default)]
+pub struct LeaderProjection {
+    drawn_for: Option<(CamelColor, u8, ReachableRange)>,
+}
+
+/// Distance along the track between projection dots (in spaces)
+const PROJECTION_DOT_STEP: f32 = 0.2;
+/// Offset below the space center so dots sit under the camels
+const PROJECTION_Y_OFFSET: f32 = -18.0;
+/// Above board spaces (0-1), below spectator tile previews (4.5)
+const PROJECTION_Z: f32 = 4.0;
+
+/// System to draw a dotted projection from the leading camel to the finish line.
+/// The stretch of track the leader can still reach this leg is highlighted in its color.
+pub fn update_leader_projection(
+    mut commands: Commands,
+    mut projection: ResMut<LeaderProjection>,
+    ui_state: Res<UiState>,
+    camels: Query<(&Camel, &BoardPosition), Without<PendingInitialMove>>,
+    table: Option<(Res<Pyramid>, Res<GameBoard>)>,
+    dots: Query<Entity, With<LeaderProjectionDot>>,
+    camel_palette: Res<CamelPalette>,
+) {
+    let Some((pyramid, board)) = table else { return };
+
+    let snapshot = RaceSnapshot::from_world(&camels, &pyramid);
+    let target = if ui_state.initial_rolls_complete {
+        snapshot.leader().and_then(|leader| {
+            let slot = snapshot.slot(leader)?;
+            let range = reachable_range(&snapshot, leader)?;
+            Some((leader, slot.space, range))
+        })
+    } else {
+        None
+    };
+
+    // Dots are despawned when leaving Playing, so redraw if they have gone missing (or
+    // the leader's color has changed)
+    if target == projection.drawn_for && (target.is_none() || !dots.is_empty()) && !camel_palette.is_changed() {
+        return;
+    }
+    projection.drawn_for = target;
+
+    for entity in dots.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let Some((leader, start_space, range)) = target else { return };
+
+    let leader_color = leader.to_bevy_color(*camel_palette);
+    let reach_start = (start_space + range.min) as f32;
+    let reach_end = (start_space + range.max) as f32;
+    let finish = TRACK_LENGTH as f32;
+
+    let mut progress = start_space as f32 + PROJECTION_DOT_STEP;
+    while progress <= finish {
+        let in_reach = range.can_move() && progress >= reach_start && progress <= reach_end;
+        let is_endpoint = range.can_move()
+            && ((progress - reach_start).abs() < 0.01 || (progress - reach_end).abs() < 0.01);
+
+        let (color, size) = if is_endpoint {
+            (leader_color.with_alpha(0.9), 7.0)
+        } else if in_reach {
+            (leader_color.with_alpha(0.7), 4.0)
+        } else {
+            (Color::srgba(1.0, 1.0, 1.0, 0.25), 3.0)
+        };
+
+        let pos = track_point(&board, progress);
+        commands.spawn((
+            DespawnOnExit(GameState::Playing),
+            LeaderProjectionDot,
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y + PROJECTION_Y_OFFSET, PROJECTION_Z),
+        ));
+
+        progress += PROJECTION_DOT_STEP;
+    }
+}
+
+/// Marker for the small crown riding on the leading camel
+#[derive(Component)]
+pub struct LeaderCrown;
+
+/// Where the leader's crown sits on the camel's head, relative to the camel (facing right)
+const LEADER_CROWN_OFFSET: Vec3 = Vec3::new(21.0, 25.0, 2.0);
+/// The leader's crown is smaller than the winner's
+const LEADER_CROWN_SCALE: f32 = 0.6;
+/// How far above the camel the crown drops from when the lead changes
+const LEADER_CROWN_DROP: f32 = 40.0;
+const LEADER_CROWN_DROP_SECS: f32 = 0.5;
+
+/// System to keep a small crown on the leading camel, dropping it onto the new leader
+/// whenever the lead changes. It gives way to the winner's crown at the finish.
+pub fn update_leader_crown(
+    mut commands: Commands,
+    ui_state: Res<UiState>,
+    camels: Query<(&Camel, &BoardPosition), Without<PendingInitialMove>>,
+    camel_entities: Query<(Entity, &Camel)>,
+    crowns: Query<(Entity, &ChildOf), With<LeaderCrown>>,
+    winner_crowns: Query<(), (With<CrownMarker>, Without<LeaderCrown>)>,
+) {
+    let leader = if ui_state.initial_rolls_complete && winner_crowns.is_empty() {
+        get_leading_camel(&camels)
+            .and_then(|color| camel_entities.iter().find(|(_, camel)| camel.color == color))
+            .map(|(entity, _)| entity)
+    } else {
+        None
+    };
+
+    let crown = crowns.iter().next();
+    match (crown, leader) {
+        (Some((_, parent)), Some(leader)) if parent.parent() == leader => {}
+        (Some((crown, _)), Some(leader)) => {
+            // Reparent onto the new leader and drop in again
+            commands.entity(crown).insert((
+                ChildOf(leader),
+                CrownDropAnimation::new(LEADER_CROWN_OFFSET, LEADER_CROWN_DROP, LEADER_CROWN_DROP_SECS),
+            ));
+        }
+        (None, Some(leader)) => {
+            let crown = spawn_crown(&mut commands, LEADER_CROWN_OFFSET, None);
+            commands.entity(crown).insert((
+                LeaderCrown,
+                ChildOf(leader),
+                Transform::from_translation(LEADER_CROWN_OFFSET + Vec3::Y * LEADER_CROWN_DROP)
+                    .with_scale(Vec3::splat(LEADER_CROWN_SCALE)),
+                CrownDropAnimation::new(LEADER_CROWN_OFFSET, LEADER_CROWN_DROP, LEADER_CROWN_DROP_SECS),
+            ));
+        }
+        (Some((crown, _)), None) => {
+            commands.entity(crown).despawn();
+        }
+        (None, None) => {}
+    }
+}
+
+/// World position for a fractional distance along the track.
+/// Values past the last space continue beyond the finish line.
+fn track_point(board: &GameBoard, progress: f32) -> Vec2 {
+    let last = TRACK_LENGTH - 1;
+    let from = (progress.floor() as u8).min(last);
+    let t = progress - from as f32;
+
+    let from_pos = board.get_position(from);
+    let to_pos = if from < last {
+        board.get_position(from + 1)
+    } else {
+        // Finish line sits one space-width past the last space (top row runs right to left)
+        from_pos + Vec2::new(-SPACE_SPACING, 0.0)
+    };
+
+    from_pos.lerp(to_pos, t)
+}
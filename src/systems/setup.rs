@@ -1,103 +1,41 @@
 use crate::components::*;
+use crate::game::ai::{AiConfig, AiSimulation};
+use crate::game::desert_events::ActiveEvent;
+use crate::game::relay::RelayRace;
+use crate::game::replay::ReplayRecording;
+use crate::game::rng::GameRng;
+use crate::game::log::{GameEvent, GameLog, MoneyTimeline};
 use crate::game::state::GameState;
-use crate::systems::turn::{PlayerLegBetsStore, PlayerPyramidTokens, TurnState};
+use crate::network::fairness::LegFairness;
+use crate::network::state::NetworkState;
+use crate::systems::board_texture::{spawn_board_background, SPACE_SIZE};
+use crate::systems::camel_atlas::{CamelAtlas, CamelSkin};
+use crate::systems::quality::{DropShadow, QualityTier};
 use crate::ui::player_setup::PlayerSetupConfig;
-use bevy::color::Srgba;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use rand::seq::SliceRandom;
 use rand::Rng;
 
-// ============================================================================
-// Color Manipulation Helpers
-// ============================================================================
-
-/// Darken a color by a given amount (0.0 = no change, 1.0 = black)
-fn darken_color(color: Color, amount: f32) -> Color {
-    let rgba: Srgba = color.into();
-    Color::srgba(
-        (rgba.red * (1.0 - amount)).max(0.0),
-        (rgba.green * (1.0 - amount)).max(0.0),
-        (rgba.blue * (1.0 - amount)).max(0.0),
-        rgba.alpha,
-    )
-}
-
-/// Lighten a color by a given amount (0.0 = no change, 1.0 = white)
-fn lighten_color(color: Color, amount: f32) -> Color {
-    let rgba: Srgba = color.into();
-    Color::srgba(
-        (rgba.red + (1.0 - rgba.red) * amount).min(1.0),
-        (rgba.green + (1.0 - rgba.green) * amount).min(1.0),
-        (rgba.blue + (1.0 - rgba.blue) * amount).min(1.0),
-        rgba.alpha,
-    )
-}
-
 // ============================================================================
 // Board Space Spawning
 // ============================================================================
 
-/// Spawn a polished board space with shadow, border, and highlight layers
+/// Spawn a board space's sprites over the painted track: the tint shown while a
+/// spectator tile is being placed, and the tile itself
 fn spawn_board_space(commands: &mut Commands, pos: Vec2, index: u8) {
-    let space_size = Vec2::new(70.0, 50.0);
-
-    // Shadow layer (offset down-right, darker)
-    commands.spawn((
-        DespawnOnExit(GameState::GameEnd),
-        Sprite {
-            color: Color::srgba(0.3, 0.25, 0.15, 0.5),
-            custom_size: Some(space_size),
-            ..default()
-        },
-        Transform::from_xyz(pos.x + 3.0, pos.y - 3.0, 0.0),
-    ));
-
-    // Border layer (slightly larger, dark brown)
+    // Placement tint (clear until a tile is being placed)
     commands.spawn((
         DespawnOnExit(GameState::GameEnd),
+        BoardSpace { index, base_color: Color::NONE },
         Sprite {
-            color: Color::srgb(0.4, 0.3, 0.2),
-            custom_size: Some(space_size + Vec2::new(4.0, 4.0)),
-            ..default()
-        },
-        Transform::from_xyz(pos.x, pos.y, 0.1),
-    ));
-
-    // Main space (sand color)
-    commands.spawn((
-        DespawnOnExit(GameState::GameEnd),
-        BoardSpace { index },
-        Sprite {
-            color: Color::srgb(0.85, 0.75, 0.55),
-            custom_size: Some(space_size),
+            color: Color::NONE,
+            custom_size: Some(SPACE_SIZE),
             ..default()
         },
         Transform::from_xyz(pos.x, pos.y, 0.2),
     ));
 
-    // Inner highlight (top portion, subtle)
-    commands.spawn((
-        DespawnOnExit(GameState::GameEnd),
-        Sprite {
-            color: Color::srgba(1.0, 0.95, 0.85, 0.3),
-            custom_size: Some(Vec2::new(space_size.x - 8.0, space_size.y * 0.4)),
-            ..default()
-        },
-        Transform::from_xyz(pos.x, pos.y + 8.0, 0.3),
-    ));
-
-    // Space number label (below the space)
-    commands.spawn((
-        DespawnOnExit(GameState::GameEnd),
-        Text2d::new(format!("{}", index + 1)),
-        TextFont {
-            font_size: 16.0,
-            ..default()
-        },
-        TextColor(Color::WHITE),
-        Transform::from_xyz(pos.x, pos.y - 35.0, 1.0),
-    ));
-
     // Spectator tile sprite (initially invisible, updated by update_spectator_tile_sprites system)
     let tile_size = Vec2::new(35.0, 18.0);
     commands
@@ -123,302 +61,67 @@ fn spawn_board_space(commands: &mut Commands, pos: Vec2, index: u8) {
                 Transform::from_xyz(0.0, 0.0, 0.1),
             ));
         });
-
-    // Add finish line marker at space 16 (index 15)
-    if index == 15 {
-        spawn_finish_line(commands, pos);
-    }
-}
-
-/// Spawn a vertical checkered finish line on the left edge of the final space
-/// This emphasizes that camels must cross this threshold to win
-fn spawn_finish_line(commands: &mut Commands, pos: Vec2) {
-    let checker_size = 8.0;
-    let rows = 8; // Tall vertical flag
-    let cols = 3; // Narrow width
-
-    // Position on the LEFT edge of space 15 (the finish threshold)
-    // Camels crossing this line have finished the race
-    let start_x = pos.x - 40.0 - (cols as f32 * checker_size) / 2.0 + checker_size / 2.0;
-    let start_y = pos.y - (rows as f32 * checker_size) / 2.0 + checker_size / 2.0;
-
-    for row in 0..rows {
-        for col in 0..cols {
-            let is_white = (row + col) % 2 == 0;
-            let color = if is_white { Color::WHITE } else { Color::BLACK };
-            commands.spawn((
-                DespawnOnExit(GameState::GameEnd),
-                Sprite {
-                    color,
-                    custom_size: Some(Vec2::splat(checker_size)),
-                    ..default()
-                },
-                Transform::from_xyz(
-                    start_x + col as f32 * checker_size,
-                    start_y + row as f32 * checker_size,
-                    0.5,
-                ),
-            ));
-        }
-    }
-
-    // Add a flagpole on the left side
-    let pole_height = rows as f32 * checker_size + 20.0;
-    let pole_x = start_x - checker_size / 2.0 - 2.0;
-    let pole_y = pos.y;
-
-    commands.spawn((
-        DespawnOnExit(GameState::GameEnd),
-        Sprite {
-            color: Color::srgb(0.4, 0.3, 0.2), // Brown pole
-            custom_size: Some(Vec2::new(4.0, pole_height)),
-            ..default()
-        },
-        Transform::from_xyz(pole_x, pole_y, 0.4),
-    ));
-
-    // Add a small ball on top of the pole
-    commands.spawn((
-        DespawnOnExit(GameState::GameEnd),
-        Sprite {
-            color: Color::srgb(0.8, 0.7, 0.2), // Gold ball
-            custom_size: Some(Vec2::splat(8.0)),
-            ..default()
-        },
-        Transform::from_xyz(pole_x, pole_y + pole_height / 2.0 + 4.0, 0.4),
-    ));
 }
 
 // ============================================================================
 // Camel Spawning
 // ============================================================================
 
-/// Spawn the camel silhouette shape components as children
-/// Creates a stylized camel profile using multiple overlapping shapes:
-/// - Body (large horizontal oval)
-/// - Hump (oval on top of body)
-/// - Neck (tall narrow rectangle)
-/// - Head (small oval)
-/// - Legs (4 thin rectangles)
-fn spawn_camel_shape(
-    parent: &mut ChildSpawnerCommands,
-    base_color: Color,
-    border_color: Color,
-    highlight_color: Color,
-) {
-    // Camel dimensions (overall bounding box roughly 50x35)
-    let body_size = Vec2::new(32.0, 18.0);
-    let hump_size = Vec2::new(14.0, 12.0);
-    let neck_size = Vec2::new(8.0, 16.0);
-    let head_size = Vec2::new(14.0, 10.0);
-    let leg_size = Vec2::new(5.0, 14.0);
-
-    // Positions relative to camel center
-    let body_pos = Vec2::new(0.0, 0.0);
-    let hump_pos = Vec2::new(-2.0, 10.0);
-    let neck_pos = Vec2::new(16.0, 8.0);
-    let head_pos = Vec2::new(22.0, 16.0);
-    let leg_positions = [
-        Vec2::new(-10.0, -14.0), // Back left
-        Vec2::new(-4.0, -14.0),  // Back right
-        Vec2::new(8.0, -14.0),   // Front left
-        Vec2::new(14.0, -14.0),  // Front right
-    ];
-
-    // Shadow layer - all parts offset
-    let shadow_offset = Vec3::new(2.0, -2.0, -0.3);
-    let shadow_color = Color::srgba(0.0, 0.0, 0.0, 0.3);
-
-    // Shadow: body
-    parent.spawn((
-        Sprite {
-            color: shadow_color,
-            custom_size: Some(body_size),
-            ..default()
-        },
-        Transform::from_translation(body_pos.extend(0.0) + shadow_offset),
-    ));
-    // Shadow: hump
-    parent.spawn((
-        Sprite {
-            color: shadow_color,
-            custom_size: Some(hump_size),
-            ..default()
-        },
-        Transform::from_translation(hump_pos.extend(0.0) + shadow_offset),
-    ));
-    // Shadow: neck
-    parent.spawn((
-        Sprite {
-            color: shadow_color,
-            custom_size: Some(neck_size),
-            ..default()
-        },
-        Transform::from_translation(neck_pos.extend(0.0) + shadow_offset),
-    ));
-    // Shadow: head
-    parent.spawn((
-        Sprite {
-            color: shadow_color,
-            custom_size: Some(head_size),
-            ..default()
-        },
-        Transform::from_translation(head_pos.extend(0.0) + shadow_offset),
-    ));
-    // Shadow: legs
-    for leg_pos in &leg_positions {
-        parent.spawn((
-            Sprite {
-                color: shadow_color,
-                custom_size: Some(leg_size),
-                ..default()
-            },
-            Transform::from_translation(leg_pos.extend(0.0) + shadow_offset),
-        ));
-    }
-
-    // Border layer - all parts slightly larger
-    let border_expand = 3.0;
-
-    // Border: body
-    parent.spawn((
-        Sprite {
-            color: border_color,
-            custom_size: Some(body_size + Vec2::splat(border_expand)),
-            ..default()
-        },
-        Transform::from_xyz(body_pos.x, body_pos.y, -0.2),
-    ));
-    // Border: hump
-    parent.spawn((
-        Sprite {
-            color: border_color,
-            custom_size: Some(hump_size + Vec2::splat(border_expand)),
-            ..default()
-        },
-        Transform::from_xyz(hump_pos.x, hump_pos.y, -0.2),
-    ));
-    // Border: neck
-    parent.spawn((
-        Sprite {
-            color: border_color,
-            custom_size: Some(neck_size + Vec2::splat(border_expand)),
-            ..default()
-        },
-        Transform::from_xyz(neck_pos.x, neck_pos.y, -0.2),
-    ));
-    // Border: head
-    parent.spawn((
-        Sprite {
-            color: border_color,
-            custom_size: Some(head_size + Vec2::splat(border_expand)),
-            ..default()
-        },
-        Transform::from_xyz(head_pos.x, head_pos.y, -0.2),
-    ));
-    // Border: legs
-    for leg_pos in &leg_positions {
-        parent.spawn((
-            Sprite {
-                color: border_color,
-                custom_size: Some(leg_size + Vec2::splat(border_expand - 1.0)),
-                ..default()
-            },
-            Transform::from_xyz(leg_pos.x, leg_pos.y, -0.2),
-        ));
-    }
-
-    // Main color layer
-    // Body
-    parent.spawn((
-        Sprite {
-            color: base_color,
-            custom_size: Some(body_size),
-            ..default()
-        },
-        Transform::from_xyz(body_pos.x, body_pos.y, -0.1),
-    ));
-    // Hump
-    parent.spawn((
-        Sprite {
-            color: base_color,
-            custom_size: Some(hump_size),
-            ..default()
-        },
-        Transform::from_xyz(hump_pos.x, hump_pos.y, -0.1),
-    ));
-    // Neck
-    parent.spawn((
-        Sprite {
-            color: base_color,
-            custom_size: Some(neck_size),
-            ..default()
-        },
-        Transform::from_xyz(neck_pos.x, neck_pos.y, -0.1),
-    ));
-    // Head
-    parent.spawn((
-        Sprite {
-            color: base_color,
-            custom_size: Some(head_size),
-            ..default()
-        },
-        Transform::from_xyz(head_pos.x, head_pos.y, -0.1),
-    ));
-    // Legs
-    for leg_pos in &leg_positions {
+/// Spawn the hatching of a camel color's pattern over a `size` area centered on
+/// `center` (for players with the color markers on)
+pub fn spawn_color_pattern(parent: &mut ChildSpawnerCommands, color: CamelColor, center: Vec2, size: Vec2, z: f32) {
+    let ink = Color::srgba(0.0, 0.0, 0.0, 0.35);
+    for mark in color.pattern().marks() {
+        let mut transform = Transform::from_translation((center + mark.center * size).extend(z));
+        let mark_size = if mark.diamond {
+            transform.rotate_z(std::f32::consts::FRAC_PI_4);
+            Vec2::splat((mark.size * size).min_element())
+        } else {
+            mark.size * size
+        };
         parent.spawn((
             Sprite {
-                color: base_color,
-                custom_size: Some(leg_size),
+                color: ink,
+                custom_size: Some(mark_size),
                 ..default()
             },
-            Transform::from_xyz(leg_pos.x, leg_pos.y, -0.1),
+            transform,
         ));
     }
+}
 
-    // Highlight on hump and head
+/// Spawn a camel color's letter on a dark badge `size` across at `center` (for players
+/// with the color markers on)
+pub fn spawn_color_letter(parent: &mut ChildSpawnerCommands, color: CamelColor, center: Vec2, size: f32, z: f32) {
     parent.spawn((
         Sprite {
-            color: highlight_color.with_alpha(0.4),
-            custom_size: Some(Vec2::new(hump_size.x - 4.0, 4.0)),
+            color: Color::srgba(0.0, 0.0, 0.0, 0.65),
+            custom_size: Some(Vec2::splat(size)),
             ..default()
         },
-        Transform::from_xyz(hump_pos.x, hump_pos.y + 3.0, 0.0),
+        Transform::from_translation(center.extend(z)),
     ));
     parent.spawn((
-        Sprite {
-            color: highlight_color.with_alpha(0.4),
-            custom_size: Some(Vec2::new(head_size.x - 4.0, 3.0)),
-            ..default()
-        },
-        Transform::from_xyz(head_pos.x, head_pos.y + 2.0, 0.0),
-    ));
-
-    // Eye (small dark circle on head)
-    parent.spawn((
-        Sprite {
-            color: Color::srgb(0.1, 0.1, 0.1),
-            custom_size: Some(Vec2::new(3.0, 3.0)),
+        Text2d::new(color.letter().to_string()),
+        TextFont {
+            font_size: size * 0.8,
             ..default()
         },
-        Transform::from_xyz(head_pos.x + 3.0, head_pos.y + 1.0, 0.1),
+        TextColor(Color::WHITE),
+        Transform::from_translation(center.extend(z + 0.05)),
     ));
 }
 
 /// Spawn a polished racing camel with camel-shaped silhouette
 fn spawn_racing_camel(
     commands: &mut Commands,
+    atlas: &CamelAtlas,
     color: CamelColor,
     space_index: u8,
     stack_pos: u8,
     spawn_pos: Vec3,
     pending_move: bool,
 ) {
-    let base_color = color.to_bevy_color();
-    let border_color = darken_color(base_color, 0.4);
-    let highlight_color = lighten_color(base_color, 0.3);
-
     // Parent entity with game logic components
     let mut entity_commands = commands.spawn((
         DespawnOnExit(GameState::GameEnd),
@@ -428,6 +131,7 @@ fn spawn_racing_camel(
             stack_position: stack_pos,
         },
         CamelSprite,
+        atlas.sprite(CamelSkin::Racing(color)),
         Transform::from_translation(spawn_pos),
         Visibility::default(),
     ));
@@ -436,24 +140,24 @@ fn spawn_racing_camel(
         entity_commands.insert(PendingInitialMove);
     }
 
-    entity_commands.with_children(|parent| {
-        spawn_camel_shape(parent, base_color, border_color, highlight_color);
-    });
+    // The letter badge is text, so it rides on the painted camel
+    if atlas.palette().markers {
+        entity_commands.with_children(|parent| {
+            spawn_color_letter(parent, color, Vec2::ZERO, 14.0, 0.15);
+        });
+    }
 }
 
 /// Spawn a polished crazy camel with camel-shaped silhouette (facing left on top row)
 fn spawn_crazy_camel(
     commands: &mut Commands,
+    atlas: &CamelAtlas,
     color: CrazyCamelColor,
     space_index: u8,
     stack_pos: u8,
     spawn_pos: Vec3,
     pending_move: bool,
 ) {
-    let base_color = color.to_bevy_color();
-    let border_color = darken_color(base_color, 0.4);
-    let highlight_color = lighten_color(base_color, 0.3);
-
     // Parent entity with game logic components
     // Crazy camels face right (same as racing camels) but move backwards on the track
     let mut entity_commands = commands.spawn((
@@ -464,6 +168,7 @@ fn spawn_crazy_camel(
             stack_position: stack_pos,
         },
         CamelSprite,
+        atlas.sprite(CamelSkin::Crazy(color)),
         Transform::from_translation(spawn_pos),
         Visibility::default(),
     ));
@@ -471,10 +176,6 @@ fn spawn_crazy_camel(
     if pending_move {
         entity_commands.insert(PendingInitialMove);
     }
-
-    entity_commands.with_children(|parent| {
-        spawn_camel_shape(parent, base_color, border_color, highlight_color);
-    });
 }
 
 // ============================================================================
@@ -533,6 +234,7 @@ fn spawn_tent_layers(
 
     // Shadow: base
     parent.spawn((
+        DropShadow,
         TentSprite,
         Sprite {
             color: shadow_color,
@@ -583,6 +285,7 @@ fn spawn_tent_layers(
 
         // Shadow for this step
         parent.spawn((
+            DropShadow,
             TentSprite,
             Sprite {
                 color: shadow_color,
@@ -636,6 +339,7 @@ fn spawn_tent_layers(
 
         // Shadow
         parent.spawn((
+            DropShadow,
             TentSprite,
             Sprite {
                 color: shadow_color,
@@ -670,11 +374,20 @@ const PYRAMID_BASE_Z: f32 = 15.0;
 #[derive(Component)]
 pub struct PyramidSprite;
 
+/// The meshes and materials of the gold coin on the pyramid button
+struct GoldCoinHandles {
+    outer_mesh: Handle<Mesh>,
+    inner_mesh: Handle<Mesh>,
+    outer_material: Handle<ColorMaterial>,
+    inner_material: Handle<ColorMaterial>,
+}
+
 /// Spawn the pyramid roll button as a game board sprite
 fn spawn_pyramid_button(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
+    quality: QualityTier,
 ) {
     // Pyramid gold colors (matching the egui version)
     let pyramid_light = Color::srgb(0.83, 0.66, 0.29); // #D4A84B
@@ -689,10 +402,12 @@ fn spawn_pyramid_button(
     let coin_gold = Color::srgb(0.83, 0.66, 0.29); // #D4A84B
     let coin_dark = Color::srgb(0.63, 0.48, 0.19); // #A07A30
 
-    let coin_outer_mesh = meshes.add(Circle::new(coin_radius + 2.0));
-    let coin_inner_mesh = meshes.add(Circle::new(coin_radius));
-    let coin_outer_material = materials.add(ColorMaterial::from_color(coin_dark));
-    let coin_inner_material = materials.add(ColorMaterial::from_color(coin_gold));
+    let coin = GoldCoinHandles {
+        outer_mesh: meshes.add(Circle::new(coin_radius + 2.0).mesh().resolution(quality.circle_resolution())),
+        inner_mesh: meshes.add(Circle::new(coin_radius).mesh().resolution(quality.circle_resolution())),
+        outer_material: materials.add(ColorMaterial::from_color(coin_dark)),
+        inner_material: materials.add(ColorMaterial::from_color(coin_gold)),
+    };
 
     // Parent pyramid entity with clickable marker
     commands
@@ -710,10 +425,7 @@ fn spawn_pyramid_button(
                 pyramid_dark,
                 outline_color,
                 shadow_color,
-                coin_outer_mesh.clone(),
-                coin_inner_mesh.clone(),
-                coin_outer_material.clone(),
-                coin_inner_material.clone(),
+                coin,
             );
         });
 }
@@ -726,10 +438,7 @@ fn spawn_pyramid_layers(
     dark_color: Color,
     outline_color: Color,
     shadow_color: Color,
-    coin_outer_mesh: Handle<Mesh>,
-    coin_inner_mesh: Handle<Mesh>,
-    coin_outer_material: Handle<ColorMaterial>,
-    coin_inner_material: Handle<ColorMaterial>,
+    coin: GoldCoinHandles,
 ) {
     // The pyramid is built from triangular-ish shapes using rectangles
     // We'll approximate with a stepped pyramid similar to the tents
@@ -772,6 +481,7 @@ fn spawn_pyramid_layers(
 
         // Shadow for this step
         parent.spawn((
+            DropShadow,
             PyramidSprite,
             Sprite {
                 color: shadow_color,
@@ -830,40 +540,26 @@ fn spawn_pyramid_layers(
     ));
 
     // Add gold coin below "Roll" text
-    spawn_gold_coin(
-        parent,
-        Vec3::new(0.0, -26.0, 1.0),
-        coin_outer_mesh,
-        coin_inner_mesh,
-        coin_outer_material,
-        coin_inner_material,
-    );
+    spawn_gold_coin(parent, Vec3::new(0.0, -26.0, 1.0), coin);
 }
 
 /// Spawn a gold coin with circular mesh and "1" inside
-fn spawn_gold_coin(
-    parent: &mut ChildSpawnerCommands,
-    position: Vec3,
-    coin_outer_mesh: Handle<Mesh>,
-    coin_inner_mesh: Handle<Mesh>,
-    coin_outer_material: Handle<ColorMaterial>,
-    coin_inner_material: Handle<ColorMaterial>,
-) {
+fn spawn_gold_coin(parent: &mut ChildSpawnerCommands, position: Vec3, coin: GoldCoinHandles) {
     let text_color = Color::srgb(0.42, 0.29, 0.10); // #6B4A1A
 
     // Coin outer border (darker circle)
     parent.spawn((
         PyramidSprite,
-        Mesh2d(coin_outer_mesh),
-        MeshMaterial2d(coin_outer_material),
+        Mesh2d(coin.outer_mesh),
+        MeshMaterial2d(coin.outer_material),
         Transform::from_translation(position + Vec3::new(0.0, 0.0, 0.0)),
     ));
 
     // Coin main (gold circle)
     parent.spawn((
         PyramidSprite,
-        Mesh2d(coin_inner_mesh),
-        MeshMaterial2d(coin_inner_material),
+        Mesh2d(coin.inner_mesh),
+        MeshMaterial2d(coin.inner_material),
         Transform::from_translation(position + Vec3::new(0.0, 0.0, 0.1)),
     ));
 
@@ -881,7 +577,7 @@ fn spawn_gold_coin(
 }
 
 /// Spawn the setup arrow and text below the pyramid
-fn spawn_setup_instructions(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+fn spawn_setup_instructions(commands: &mut Commands, asset_server: &Res<AssetServer>, quality: QualityTier) {
     let arrow_y = PYRAMID_Y_POSITION - PYRAMID_SIZE / 2.0 - 45.0;
     let text_y = arrow_y - 35.0;
     let gold_color = Color::srgb(0.85, 0.65, 0.13); // Gold color
@@ -935,11 +631,12 @@ fn spawn_setup_instructions(commands: &mut Commands, asset_server: &Res<AssetSer
         Text2d::new("Set up camels"),
         TextFont {
             font: font.clone(),
-            font_size: 48.0,
+            font_size: 48.0 * quality.text_resolution(),
             ..default()
         },
         TextColor(gold_color),
-        Transform::from_xyz(0.0, text_y, PYRAMID_BASE_Z + 1.0),
+        Transform::from_xyz(0.0, text_y, PYRAMID_BASE_Z + 1.0)
+            .with_scale(Vec3::splat(1.0 / quality.text_resolution())),
     ));
 }
 
@@ -947,6 +644,7 @@ fn spawn_setup_instructions(commands: &mut Commands, asset_server: &Res<AssetSer
 fn spawn_start_game_button(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
+    quality: QualityTier,
 ) {
     let button_y = PYRAMID_Y_POSITION - PYRAMID_SIZE / 2.0 - 60.0;
     let gold_color = Color::srgb(0.85, 0.65, 0.13);
@@ -960,11 +658,12 @@ fn spawn_start_game_button(
         Text2d::new("Start Game"),
         TextFont {
             font: font.clone(),
-            font_size: 32.0,
+            font_size: 32.0 * quality.text_resolution(),
             ..default()
         },
         TextColor(gold_color),
-        Transform::from_xyz(0.0, button_y, PYRAMID_BASE_Z + 1.0),
+        Transform::from_xyz(0.0, button_y, PYRAMID_BASE_Z + 1.0)
+            .with_scale(Vec3::splat(1.0 / quality.text_resolution())),
         Visibility::Hidden,
     ));
 }
@@ -977,9 +676,9 @@ pub enum InitialRollCamel {
 }
 
 impl InitialRollCamel {
-    pub fn to_bevy_color(&self) -> Color {
+    pub fn to_bevy_color(self, palette: CamelPalette) -> Color {
         match self {
-            InitialRollCamel::Racing(c) => c.to_bevy_color(),
+            InitialRollCamel::Racing(c) => c.to_bevy_color(palette),
             InitialRollCamel::Crazy(c) => c.to_bevy_color(),
         }
     }
@@ -1075,54 +774,107 @@ fn generate_initial_waypoints_crazy(
     waypoints
 }
 
+/// The asset stores and loader `setup_game` builds the table's meshes, sprites and text from
+#[derive(SystemParam)]
+pub struct SetupAssets<'w> {
+    pub meshes: ResMut<'w, Assets<Mesh>>,
+    pub materials: ResMut<'w, Assets<ColorMaterial>>,
+    pub images: ResMut<'w, Assets<Image>>,
+    pub atlas_layouts: ResMut<'w, Assets<TextureAtlasLayout>>,
+    pub asset_server: Res<'w, AssetServer>,
+}
+
 pub fn setup_game(
     mut commands: Commands,
     config: Res<PlayerSetupConfig>,
     existing_camels: Query<Entity, With<Camel>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    asset_server: Res<AssetServer>,
+    assets: SetupAssets,
+    quality: Res<QualityTier>,
+    network_state: Res<NetworkState>,
+    camel_palette: Res<CamelPalette>,
 ) {
     // Don't setup if game entities already exist (returning from leg scoring)
     if !existing_camels.is_empty() {
         info!("Game already setup, skipping...");
         return;
     }
+    let SetupAssets { mut meshes, mut materials, mut images, mut atlas_layouts, asset_server } = assets;
+
+    // Online players all draw from the host's seed, so they set up the same game, and a
+    // replay from the recorded one. Local games use the seed typed in at setup, or pick
+    // their own, for their replay.
+    let game_seed = network_state
+        .game_seed
+        .filter(|_| !network_state.is_local())
+        .or_else(|| config.chosen_seed())
+        .unwrap_or_else(rand::random);
+    info!("Game seed: {}", game_seed);
+    let mut rng = GameRng::new(game_seed);
 
     // Create players from setup config
-    let players = Players::new(config.to_player_configs());
+    let ordered_players = config.ordered_players(&mut *rng);
+    let players = Players::new(PlayerSetupConfig::to_player_configs(&ordered_players));
     let player_count = players.players.len();
 
+    // Start a fresh event log for this game
+    let camel_names = config.camel_names();
+    let mut game_log = GameLog::new(camel_names.clone());
+    game_log.record(1, GameEvent::GameStarted {
+        player_names: players.players.iter().map(|p| p.name.clone()).collect(),
+    });
+    commands.insert_resource(game_log);
+    commands.insert_resource(camel_names);
+
     // Insert game resources
-    commands.insert_resource(GameBoard::new());
+    let relay = RelayRace::new(config.relay_race);
+    commands.insert_resource(GameBoard::with_tracks(relay.track_count()));
+    commands.insert_resource(relay);
+    let mut money_timeline = MoneyTimeline::default();
+    money_timeline.sample("Start", &players);
+    commands.insert_resource(money_timeline);
     commands.insert_resource(players);
     commands.insert_resource(Pyramid::new());
     commands.insert_resource(LegBettingTiles::new());
     commands.insert_resource(RaceBets::default());
     commands.insert_resource(PlacedSpectatorTiles::default());
+    commands.insert_resource(SheikhBettor::new(config.uses_sheikh()));
+    commands.insert_resource(ActiveEvent::new(config.desert_events, game_seed));
+    commands.insert_resource(ReplayRecording::new(game_seed, &config, &ordered_players));
 
     // Insert turn-related resources
     commands.insert_resource(TurnState::default());
     commands.insert_resource(PlayerLegBetsStore::new(player_count));
     commands.insert_resource(PlayerPyramidTokens::new(player_count));
+    commands.insert_resource(LegFairness::default());
+    commands.insert_resource(AiSimulation::default());
+    commands.insert_resource(AiConfig {
+        player_difficulties: PlayerSetupConfig::ai_difficulties(&ordered_players),
+        ..default()
+    });
 
     // Get board for positioning
     let board = GameBoard::new();
 
-    // Spawn the track spaces with polished layered visuals
+    // Paint the track once, then lay each space's changing sprites over it
+    spawn_board_background(&mut commands, &mut images, &board, quality.shadows());
     for i in 0..TRACK_LENGTH {
         let pos = board.get_position(i);
         spawn_board_space(&mut commands, pos, i);
     }
 
+    // Paint this game's camels, in the palette and markers the player has picked
+    let camel_atlas = CamelAtlas::build(&mut images, &mut atlas_layouts, *camel_palette, quality.shadows());
+    commands.insert_resource(camel_atlas.clone());
+
     // Roll initial positions for racing camels (spaces 1-3, i.e., indices 0-2)
-    let mut rng = rand::thread_rng();
     let mut camel_positions: Vec<(u8, u8)> = Vec::new(); // (space_index, stack_pos)
-    let mut initial_rolls = InitialSetupRolls::default();
-    initial_rolls.waiting_for_click = true; // Require click for first roll
+    let mut initial_rolls = InitialSetupRolls {
+        waiting_for_click: true, // Require click for first roll
+        ..default()
+    };
 
     let mut racing_order: Vec<CamelColor> = CamelColor::all().into();
-    racing_order.shuffle(&mut rng);
+    racing_order.shuffle(&mut *rng);
 
     for (i, color) in racing_order.into_iter().enumerate() {
         // Roll 1-3 for starting position (space index 0-2)
@@ -1164,6 +916,7 @@ pub fn setup_game(
         );
         spawn_racing_camel(
             &mut commands,
+            &camel_atlas,
             color,
             space_index,
             stack_pos,
@@ -1178,7 +931,7 @@ pub fn setup_game(
     let mut crazy_positions: Vec<(u8, u8)> = Vec::new(); // (space_index, stack_pos)
 
     let mut crazy_order: Vec<CrazyCamelColor> = CrazyCamelColor::all().into();
-    crazy_order.shuffle(&mut rng);
+    crazy_order.shuffle(&mut *rng);
 
     for (i, crazy_color) in crazy_order.into_iter().enumerate() {
         // Roll 1-3 for starting position (mapped to space indices 13-15)
@@ -1221,6 +974,7 @@ pub fn setup_game(
         );
         spawn_crazy_camel(
             &mut commands,
+            &camel_atlas,
             crazy_color,
             space_index,
             stack_pos,
@@ -1231,6 +985,8 @@ pub fn setup_game(
 
     // Insert the initial rolls resource for display
     commands.insert_resource(initial_rolls);
+    // The rest of the game draws from where the setup rolls left off
+    commands.insert_resource(rng);
 
     // Spawn dice tents below the track
     let num_tents = 5;
@@ -1244,73 +1000,86 @@ pub fn setup_game(
     }
 
     // Spawn pyramid roll button below the track
-    spawn_pyramid_button(&mut commands, &mut meshes, &mut materials);
+    spawn_pyramid_button(&mut commands, &mut meshes, &mut materials, *quality);
 
     // Spawn setup instructions (arrow and text)
-    spawn_setup_instructions(&mut commands, &asset_server);
+    spawn_setup_instructions(&mut commands, &asset_server, *quality);
 
     // Spawn start game button (initially hidden)
-    spawn_start_game_button(&mut commands, &asset_server);
+    spawn_start_game_button(&mut commands, &asset_server, *quality);
 
     info!("Game setup complete!");
 }
 
+/// The screen state a game leaves behind in resources that outlive it
+#[derive(SystemParam)]
+pub struct GameScreenState<'w> {
+    ui_state: ResMut<'w, crate::ui::hud::UiState>,
+    celebration_state: ResMut<'w, crate::ui::scoring::CelebrationState>,
+    camera_state: ResMut<'w, crate::CameraState>,
+    // Drawn by the HUD, which an app may leave out
+    camel_position_anims: Option<ResMut<'w, crate::ui::hud::CamelPositionAnimations>>,
+    rules_state: Option<ResMut<'w, crate::ui::rules::RulesState>>,
+    ai_think_timer: ResMut<'w, crate::game::ai::AiThinkTimer>,
+}
+
+impl GameScreenState<'_> {
+    fn reset(&mut self) {
+        *self.ui_state = crate::ui::hud::UiState::default();
+        *self.celebration_state = crate::ui::scoring::CelebrationState::default();
+        *self.camera_state = crate::CameraState::default();
+        if let Some(camel_position_anims) = self.camel_position_anims.as_mut() {
+            **camel_position_anims = crate::ui::hud::CamelPositionAnimations::default();
+        }
+        if let Some(rules_state) = self.rules_state.as_mut() {
+            **rules_state = crate::ui::rules::RulesState::default();
+        }
+        *self.ai_think_timer = crate::game::ai::AiThinkTimer::default();
+    }
+}
+
+/// The entities `setup_game` spawns for a game
+#[derive(SystemParam)]
+pub struct GameEntities<'w, 's> {
+    camels: Query<'w, 's, Entity, With<Camel>>,
+    crazy_camels: Query<'w, 's, Entity, With<CrazyCamel>>,
+    camel_sprites: Query<'w, 's, Entity, With<CamelSprite>>,
+    board_spaces: Query<'w, 's, Entity, With<board::BoardSpace>>,
+    dice_tents: Query<'w, 's, Entity, With<DiceTent>>,
+    pyramid: Query<'w, 's, Entity, With<PyramidRollButton>>,
+    setup_arrows: Query<'w, 's, Entity, With<board::SetupArrow>>,
+    setup_text: Query<'w, 's, Entity, With<board::SetupText>>,
+    start_button: Query<'w, 's, Entity, With<board::StartGameButton>>,
+}
+
+impl GameEntities<'_, '_> {
+    fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.camels
+            .iter()
+            .chain(self.crazy_camels.iter())
+            .chain(self.camel_sprites.iter())
+            .chain(self.board_spaces.iter())
+            .chain(self.dice_tents.iter())
+            .chain(self.pyramid.iter())
+            .chain(self.setup_arrows.iter())
+            .chain(self.setup_text.iter())
+            .chain(self.start_button.iter())
+    }
+}
+
 /// Clean up game resources when leaving the Playing state.
 /// Explicitly despawns all game entities to ensure clean restart.
 pub fn cleanup_game(
     mut commands: Commands,
-    mut ui_state: ResMut<crate::ui::hud::UiState>,
-    mut celebration_state: ResMut<crate::ui::scoring::CelebrationState>,
-    mut camera_state: ResMut<crate::CameraState>,
-    mut camel_position_anims: ResMut<crate::ui::hud::CamelPositionAnimations>,
-    mut rules_state: ResMut<crate::ui::rules::RulesState>,
-    mut ai_think_timer: ResMut<crate::game::ai::AiThinkTimer>,
-    // Queries to find all game entities
-    camels: Query<Entity, With<Camel>>,
-    crazy_camels: Query<Entity, With<CrazyCamel>>,
-    camel_sprites: Query<Entity, With<CamelSprite>>,
-    board_spaces: Query<Entity, With<board::BoardSpace>>,
-    dice_tents: Query<Entity, With<DiceTent>>,
-    pyramid: Query<Entity, With<PyramidRollButton>>,
-    setup_arrows: Query<Entity, With<board::SetupArrow>>,
-    setup_text: Query<Entity, With<board::SetupText>>,
-    start_button: Query<Entity, With<board::StartGameButton>>,
+    mut screen_state: GameScreenState,
+    game_entities: GameEntities,
 ) {
     // Reset all UI and game state
-    *ui_state = crate::ui::hud::UiState::default();
-    *celebration_state = crate::ui::scoring::CelebrationState::default();
-    *camera_state = crate::CameraState::default();
-    *camel_position_anims = crate::ui::hud::CamelPositionAnimations::default();
-    *rules_state = crate::ui::rules::RulesState::default();
-    *ai_think_timer = crate::game::ai::AiThinkTimer::default();
+    screen_state.reset();
 
     // Explicitly despawn all game entities to ensure they don't persist
     // This prevents the "existing_camels" check in setup_game from failing
-    for entity in camels.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in crazy_camels.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in camel_sprites.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in board_spaces.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in dice_tents.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in pyramid.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in setup_arrows.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in setup_text.iter() {
-        commands.entity(entity).despawn();
-    }
-    for entity in start_button.iter() {
+    for entity in game_entities.iter() {
         commands.entity(entity).despawn();
     }
 
@@ -1327,26 +1096,20 @@ pub fn cleanup_game(
     commands.remove_resource::<PlayerLegBetsStore>();
     commands.remove_resource::<PlayerPyramidTokens>();
     commands.remove_resource::<InitialSetupRolls>();
+    commands.remove_resource::<GameRng>();
 
     info!("Game cleanup complete - all entities despawned and resources removed!");
 }
 
+/// The arrow and text pointing new players at the pyramid during the setup rolls
+type SetupInstructions = (Or<(With<board::SetupArrow>, With<board::SetupText>)>, Without<board::StartGameButton>);
+
 /// System to manage setup UI visibility (arrow/text -> Start Game button)
 pub fn hide_setup_instructions_system(
     ui_state: Res<crate::ui::hud::UiState>,
     network_state: Res<crate::network::state::NetworkState>,
-    mut arrow_query: Query<
-        &mut Visibility,
-        (With<board::SetupArrow>, Without<board::SetupText>, Without<board::StartGameButton>),
-    >,
-    mut text_query: Query<
-        &mut Visibility,
-        (With<board::SetupText>, Without<board::SetupArrow>, Without<board::StartGameButton>),
-    >,
-    mut button_query: Query<
-        &mut Visibility,
-        (With<board::StartGameButton>, Without<board::SetupArrow>, Without<board::SetupText>),
-    >,
+    mut instructions_query: Query<&mut Visibility, SetupInstructions>,
+    mut button_query: Query<&mut Visibility, With<board::StartGameButton>>,
 ) {
     // In online mode, non-host players should not see setup instructions at all
     // They just watch the host do the init rolls
@@ -1354,10 +1117,7 @@ pub fn hide_setup_instructions_system(
 
     if is_non_host_online {
         // Hide all setup UI for non-host players
-        for mut visibility in arrow_query.iter_mut() {
-            *visibility = Visibility::Hidden;
-        }
-        for mut visibility in text_query.iter_mut() {
+        for mut visibility in instructions_query.iter_mut() {
             *visibility = Visibility::Hidden;
         }
         for mut visibility in button_query.iter_mut() {
@@ -1368,10 +1128,7 @@ pub fn hide_setup_instructions_system(
 
     // When camel rolls are complete, hide arrow/text and show Start Game button
     if ui_state.camel_rolls_complete {
-        for mut visibility in arrow_query.iter_mut() {
-            *visibility = Visibility::Hidden;
-        }
-        for mut visibility in text_query.iter_mut() {
+        for mut visibility in instructions_query.iter_mut() {
             *visibility = Visibility::Hidden;
         }
         // Show button only if game hasn't started yet
@@ -1390,6 +1147,15 @@ pub fn hide_setup_instructions_system(
     }
 }
 
+/// The camels of the setup rolls: those still waiting to walk onto the board, and any
+/// on their way there
+#[derive(SystemParam)]
+pub struct SetupRollCamels<'w, 's> {
+    racing: Query<'w, 's, (Entity, &'static Camel, &'static Transform), With<PendingInitialMove>>,
+    crazy: Query<'w, 's, (Entity, &'static CrazyCamel, &'static Transform), With<PendingInitialMove>>,
+    moving: Query<'w, 's, Entity, (With<CamelSprite>, crate::systems::animation::Moving)>,
+}
+
 /// System to animate initial camel placement rolls
 pub fn initial_roll_animation_system(
     mut commands: Commands,
@@ -1400,21 +1166,8 @@ pub fn initial_roll_animation_system(
         &crate::systems::animation::DiceRollAnimation,
         With<crate::systems::animation::DiceSprite>,
     >,
-    mut racing_camel_query: Query<(Entity, &Camel, &Transform), With<PendingInitialMove>>,
-    mut crazy_camel_query: Query<
-        (Entity, &CrazyCamel, &Transform),
-        (With<PendingInitialMove>, Without<Camel>),
-    >,
-    moving_camels: Query<
-        Entity,
-        (
-            With<CamelSprite>,
-            Or<(
-                With<crate::systems::animation::MovementAnimation>,
-                With<crate::systems::animation::MultiStepMovementAnimation>,
-            )>,
-        ),
-    >,
+    setup_camels: SetupRollCamels,
+    camel_palette: Res<CamelPalette>,
 ) {
     let Some(ref mut rolls) = initial_rolls else {
         // If no InitialSetupRolls resource, consider rolls complete
@@ -1441,7 +1194,7 @@ pub fn initial_roll_animation_system(
     }
 
     // Check dice animation state
-    let dice_in_display_or_later = dice_query.iter().next().map_or(false, |anim| {
+    let dice_in_display_or_later = dice_query.iter().next().is_some_and(|anim| {
         matches!(
             anim.phase,
             crate::systems::animation::DiceRollPhase::Settling
@@ -1451,7 +1204,7 @@ pub fn initial_roll_animation_system(
         )
     });
     let dice_finished = dice_query.is_empty();
-    let camel_still_moving = !moving_camels.is_empty();
+    let camel_still_moving = !setup_camels.moving.is_empty();
 
     // Start camel moving when dice enters settling phase
     // Also trigger if dice has already finished (despawned) but camel hasn't moved yet
@@ -1466,11 +1219,11 @@ pub fn initial_roll_animation_system(
         // Find the camel and start its movement animation
         match camel_type {
             InitialRollCamel::Racing(color) => {
-                for (entity, camel, transform) in racing_camel_query.iter_mut() {
+                for (entity, camel, transform) in setup_camels.racing.iter() {
                     if camel.color == color {
                         let start_pos = transform.translation;
                         let waypoints = generate_initial_waypoints_racing(
-                            &*board,
+                            &board,
                             start_pos,
                             space_index,
                             stack_pos,
@@ -1490,11 +1243,11 @@ pub fn initial_roll_animation_system(
                 }
             }
             InitialRollCamel::Crazy(color) => {
-                for (entity, camel, transform) in crazy_camel_query.iter_mut() {
+                for (entity, camel, transform) in setup_camels.crazy.iter() {
                     if camel.color == color {
                         let start_pos = transform.translation;
                         let waypoints = generate_initial_waypoints_crazy(
-                            &*board,
+                            &board,
                             start_pos,
                             space_index,
                             stack_pos,
@@ -1557,7 +1310,7 @@ pub fn initial_roll_animation_system(
 
         // Spawn animated dice sprite in center of board
         let dice_pos = Vec3::new(0.0, 0.0, 100.0);
-        let dice_color = camel_type.to_bevy_color();
+        let dice_color = camel_type.to_bevy_color(*camel_palette);
 
         // Spawn the dice sprite with animation
         commands
@@ -1572,6 +1325,12 @@ pub fn initial_roll_animation_system(
                 Transform::from_translation(dice_pos),
             ))
             .with_children(|parent| {
+                // Racing camel dice carry the color's pattern and letter (clear of the pips)
+                if let (InitialRollCamel::Racing(color), true) = (camel_type, camel_palette.markers) {
+                    spawn_color_pattern(parent, color, Vec2::ZERO, Vec2::splat(56.0), 0.5);
+                    spawn_color_letter(parent, color, Vec2::new(17.0, 17.0), 16.0, 1.2);
+                }
+
                 // Spawn pips as children
                 let pip_positions = get_pip_positions(value);
                 for pip_pos in pip_positions {
@@ -0,0 +1,177 @@
+// Sound effects
+// Short sounds for things worth hearing about, synthesized as they play like the
+// background music: the "your turn" chime, and the fanfare that opens the fireworks at
+// the end of a game. They play at the sound effect volume; the master volume and mute
+// come in through the global volume.
+
+use bevy::audio::{Decodable, Source, Volume};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use crate::game::settings::Settings;
+use crate::ui::scoring::CelebrationState;
+
+/// Samples per second the effects are synthesized at
+const SAMPLE_RATE: u32 = 22_050;
+
+/// Frequencies of a bell's strongest partials relative to its note, with how loud each
+/// is and how fast it dies away
+const BELL_PARTIALS: [(f32, f32, f32); 3] = [(1.0, 1.0, 4.0), (2.76, 0.5, 6.0), (5.4, 0.25, 9.0)];
+
+/// The fanfare's notes: when each starts and how long it's held (seconds), its frequency
+/// and how loud it is. A D major arpeggio up to a held chord.
+const FANFARE_NOTES: [(f32, f32, f32, f32); 6] = [
+    (0.0, 0.14, 293.66, 1.0),
+    (0.15, 0.14, 369.99, 1.0),
+    (0.3, 0.14, 440.0, 1.0),
+    (0.45, 1.35, 587.33, 1.0),
+    (0.45, 1.35, 293.66, 0.5),
+    (0.45, 1.35, 369.99, 0.5),
+];
+
+/// How long a brass note takes to die away once released (seconds)
+const BRASS_RELEASE: f32 = 0.1;
+
+/// A sound the game can play
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundEffect {
+    /// Two rising bell strokes: it's the local player's turn
+    YourTurn,
+    /// A brass arpeggio and chord as the end of game fireworks start
+    Fanfare,
+}
+
+impl SoundEffect {
+    /// How long the sound lasts (seconds)
+    fn duration(self) -> f32 {
+        match self {
+            Self::YourTurn => 1.2,
+            Self::Fanfare => 2.0,
+        }
+    }
+
+    /// The sound's sample at `t` seconds in
+    fn sample(self, t: f32) -> f32 {
+        match self {
+            Self::YourTurn => 0.4 * (bell(880.0, t) + bell(1318.5, t - 0.15)),
+            Self::Fanfare => {
+                0.15 * FANFARE_NOTES
+                    .iter()
+                    .map(|&(start, length, frequency, level)| level * brass(frequency, t - start, length))
+                    .sum::<f32>()
+            }
+        }
+    }
+}
+
+/// A bell struck at time 0 (silent before it)
+fn bell(frequency: f32, t: f32) -> f32 {
+    if t < 0.0 {
+        return 0.0;
+    }
+    let attack = (t * 400.0).min(1.0);
+    BELL_PARTIALS
+        .iter()
+        .map(|&(ratio, level, decay)| level * (TAU * frequency * ratio * t).sin() * (-t * decay).exp())
+        .sum::<f32>()
+        * attack
+}
+
+/// A brass note started at time 0 and held for `length` seconds: a quick attack that
+/// settles, with the bright harmonics of a horn
+fn brass(frequency: f32, t: f32, length: f32) -> f32 {
+    if t < 0.0 || t > length + BRASS_RELEASE {
+        return 0.0;
+    }
+    let release = if t > length { 1.0 - (t - length) / BRASS_RELEASE } else { 1.0 };
+    let envelope = (t / 0.03).min(1.0) * release * (0.7 + 0.3 * (-t * 6.0).exp());
+    let tone: f32 = (1..=6).map(|n| (TAU * frequency * n as f32 * t).sin() / n as f32).sum();
+    envelope * tone
+}
+
+/// A sound effect as an audio asset
+#[derive(Asset, TypePath)]
+pub struct SoundEffectAudio {
+    effect: SoundEffect,
+}
+
+impl Decodable for SoundEffectAudio {
+    type DecoderItem = f32;
+    type Decoder = SoundEffectDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        SoundEffectDecoder {
+            effect: self.effect,
+            sample: 0,
+            length: (self.effect.duration() * SAMPLE_RATE as f32) as u32,
+        }
+    }
+}
+
+/// Synthesizes a sound effect one sample at a time
+pub struct SoundEffectDecoder {
+    effect: SoundEffect,
+    sample: u32,
+    length: u32,
+}
+
+impl Iterator for SoundEffectDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.sample >= self.length {
+            return None;
+        }
+        let t = self.sample as f32 / SAMPLE_RATE as f32;
+        self.sample += 1;
+        Some(self.effect.sample(t).clamp(-1.0, 1.0))
+    }
+}
+
+impl Source for SoundEffectDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some((self.length - self.sample) as usize)
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(self.effect.duration()))
+    }
+}
+
+/// Play a sound effect once at the sound effect volume. Does nothing when the app
+/// doesn't play audio (`audio` is None).
+pub fn play_sound_effect(
+    commands: &mut Commands,
+    audio: Option<&mut Assets<SoundEffectAudio>>,
+    settings: &Settings,
+    effect: SoundEffect,
+) {
+    let Some(audio) = audio else { return };
+    commands.spawn((
+        AudioPlayer(audio.add(SoundEffectAudio { effect })),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(settings.sfx_volume)),
+    ));
+}
+
+/// System to sound the fanfare as the end of game fireworks start
+pub fn play_celebration_fanfare(
+    mut commands: Commands,
+    celebration: Res<CelebrationState>,
+    settings: Res<Settings>,
+    mut audio: Option<ResMut<Assets<SoundEffectAudio>>>,
+    mut was_active: Local<bool>,
+) {
+    if celebration.active && !*was_active {
+        play_sound_effect(&mut commands, audio.as_deref_mut(), &settings, SoundEffect::Fanfare);
+    }
+    *was_active = celebration.active;
+}
@@ -0,0 +1,259 @@
+// Spoken commentary for players who can't easily read the panels
+// When turned on in the main menu, every line the game log records (dice rolls, bets,
+// leg results...) is read aloud as it happens. The browser build uses the Web Speech
+// API; desktop builds need `--features speech` (the `tts` crate, which on Linux talks
+// to speech-dispatcher).
+// Whether or not commentary is on, the latest lines are also kept as an announcement,
+// which the browser build writes to a hidden ARIA live region for screen readers.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::log::{GameEvent, GameLog};
+
+/// Slowest and fastest speaking rates offered in settings (1.0 = the voice's normal speed)
+pub const MIN_SPEECH_RATE: f32 = 0.5;
+pub const MAX_SPEECH_RATE: f32 = 2.0;
+
+/// Player's spoken commentary settings
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpeechSettings {
+    pub enabled: bool,
+    /// Speaking rate, 1.0 is the voice's normal speed
+    pub rate: f32,
+    /// Loudness from silent (0.0) to full (1.0)
+    pub volume: f32,
+    /// Voice name as reported by the platform (None = system default)
+    pub voice: Option<String>,
+}
+
+impl Default for SpeechSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rate: 1.0,
+            volume: 1.0,
+            voice: None,
+        }
+    }
+}
+
+/// The latest game log lines, for screen readers
+#[derive(Resource, Default)]
+pub struct Announcement {
+    pub text: String,
+}
+
+/// Whether a log entry is worth reading aloud
+/// AI reasoning is long and repeats what the action line says, so it stays on screen only.
+fn is_spoken(event: &GameEvent) -> bool {
+    !matches!(event, GameEvent::AiReasoning { .. })
+}
+
+/// Text-to-speech backend for the current platform
+#[derive(Resource)]
+#[cfg_attr(not(any(feature = "speech", target_arch = "wasm32")), derive(Default))]
+pub struct Speaker {
+    voices: Vec<String>,
+    #[cfg(target_arch = "wasm32")]
+    rate: f32,
+    #[cfg(target_arch = "wasm32")]
+    volume: f32,
+    #[cfg(target_arch = "wasm32")]
+    voice: Option<String>,
+    #[cfg(all(feature = "speech", not(target_arch = "wasm32")))]
+    tts: Option<tts::Tts>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Default for Speaker {
+    fn default() -> Self {
+        Self {
+            voices: Vec::new(),
+            rate: 1.0,
+            volume: 1.0,
+            voice: None,
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Speaker {
+    pub fn available(&self) -> bool {
+        crate::network::js_bindings::is_speech_supported()
+    }
+
+    /// Voice names (browsers load them lazily, so keep asking until some show up)
+    pub fn voices(&mut self) -> &[String] {
+        if self.voices.is_empty() {
+            self.voices = crate::network::js_bindings::get_speech_voices()
+                .lines()
+                .map(str::to_string)
+                .collect();
+        }
+        &self.voices
+    }
+
+    pub fn configure(&mut self, settings: &SpeechSettings) {
+        self.rate = settings.rate;
+        self.volume = settings.volume;
+        self.voice = settings.voice.clone();
+    }
+
+    pub fn speak(&mut self, text: &str) {
+        let voice = self.voice.as_deref().unwrap_or("");
+        crate::network::js_bindings::speak_text(text, self.rate, self.volume, voice);
+    }
+
+    pub fn stop(&mut self) {
+        crate::network::js_bindings::stop_speech();
+    }
+}
+
+#[cfg(all(feature = "speech", not(target_arch = "wasm32")))]
+impl Default for Speaker {
+    fn default() -> Self {
+        let tts = match tts::Tts::default() {
+            Ok(tts) => Some(tts),
+            Err(e) => {
+                warn!("Text-to-speech unavailable: {}", e);
+                None
+            }
+        };
+        let voices = tts
+            .as_ref()
+            .and_then(|tts| tts.voices().ok())
+            .map(|voices| voices.iter().map(|v| v.name()).collect())
+            .unwrap_or_default();
+        Self { voices, tts }
+    }
+}
+
+#[cfg(all(feature = "speech", not(target_arch = "wasm32")))]
+impl Speaker {
+    pub fn available(&self) -> bool {
+        self.tts.is_some()
+    }
+
+    pub fn voices(&mut self) -> &[String] {
+        &self.voices
+    }
+
+    pub fn configure(&mut self, settings: &SpeechSettings) {
+        let Some(tts) = self.tts.as_mut() else { return };
+
+        // Backends use their own rate scales; map our multiplier around the normal rate
+        let (min, normal, max) = (tts.min_rate(), tts.normal_rate(), tts.max_rate());
+        let rate = if settings.rate >= 1.0 {
+            normal + (max - normal) * (settings.rate - 1.0) / (MAX_SPEECH_RATE - 1.0)
+        } else {
+            normal - (normal - min) * (1.0 - settings.rate) / (1.0 - MIN_SPEECH_RATE)
+        };
+        if let Err(e) = tts.set_rate(rate) {
+            warn!("Could not set speech rate: {}", e);
+        }
+
+        let (min, max) = (tts.min_volume(), tts.max_volume());
+        if let Err(e) = tts.set_volume(min + (max - min) * settings.volume) {
+            warn!("Could not set speech volume: {}", e);
+        }
+
+        if let Some(ref name) = settings.voice {
+            let voice = tts.voices().ok().and_then(|voices| voices.into_iter().find(|v| v.name() == *name));
+            if let Some(voice) = voice {
+                if let Err(e) = tts.set_voice(&voice) {
+                    warn!("Could not set speech voice: {}", e);
+                }
+            }
+        }
+    }
+
+    pub fn speak(&mut self, text: &str) {
+        let Some(tts) = self.tts.as_mut() else { return };
+        if let Err(e) = tts.speak(text, false) {
+            warn!("Could not speak: {}", e);
+        }
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(tts) = self.tts.as_mut() {
+            let _ = tts.stop();
+        }
+    }
+}
+
+/// Desktop builds without the `speech` feature have no backend
+#[cfg(not(any(feature = "speech", target_arch = "wasm32")))]
+impl Speaker {
+    pub fn available(&self) -> bool {
+        false
+    }
+
+    pub fn voices(&mut self) -> &[String] {
+        &self.voices
+    }
+
+    pub fn configure(&mut self, _settings: &SpeechSettings) {}
+
+    pub fn speak(&mut self, _text: &str) {}
+
+    pub fn stop(&mut self) {}
+}
+
+/// System to read newly recorded game log lines aloud
+pub fn speak_game_log(
+    log: Res<GameLog>,
+    settings: Res<SpeechSettings>,
+    mut speaker: ResMut<Speaker>,
+    mut spoken: Local<usize>,
+) {
+    if settings.is_changed() {
+        speaker.configure(&settings);
+        if !settings.enabled {
+            speaker.stop();
+        }
+    }
+
+    if !log.is_changed() {
+        return;
+    }
+    // A fresh log means a new game started
+    if log.entries.len() < *spoken {
+        *spoken = 0;
+    }
+    let new_entries = &log.entries[*spoken..];
+    *spoken = log.entries.len();
+
+    if !settings.enabled {
+        return;
+    }
+    for entry in new_entries.iter().filter(|entry| is_spoken(&entry.event)) {
+        speaker.speak(&entry.event.describe(&log.camel_names));
+    }
+}
+
+/// System to announce newly recorded game log lines to screen readers
+pub fn announce_game_log(log: Res<GameLog>, mut announcement: ResMut<Announcement>, mut announced: Local<usize>) {
+    if !log.is_changed() {
+        return;
+    }
+    // A fresh log means a new game started
+    if log.entries.len() < *announced {
+        *announced = 0;
+    }
+    let text = log.entries[*announced..]
+        .iter()
+        .filter(|entry| is_spoken(&entry.event))
+        .map(|entry| entry.event.describe(&log.camel_names))
+        .collect::<Vec<_>>()
+        .join(" ");
+    *announced = log.entries.len();
+
+    if text.is_empty() {
+        return;
+    }
+    #[cfg(target_arch = "wasm32")]
+    crate::network::js_bindings::announce(&text);
+    announcement.text = text;
+}
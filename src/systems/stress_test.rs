@@ -0,0 +1,183 @@
+// Hidden developer stress test
+// Starts an 8-player all-AI game and keeps every effect running at once (fireworks,
+// dice particle bursts, crown drops, camel animations) while logging frame times, to
+// profile the worst case on target devices. F9 on the main menu starts it, Escape leaves.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::game::ai::AiDifficulty;
+use crate::game::key_bindings::{KeyAction, KeyBindings, Shortcuts};
+use crate::game::state::{GameState, SuspendedGame};
+use crate::systems::animation::{
+    random_firework_color, spawn_crown, spawn_dice_particles, spawn_firework,
+};
+use crate::systems::setup::InitialSetupRolls;
+use crate::ui::hud::UiState;
+use crate::ui::player_setup::PlayerSetupConfig;
+
+/// Seconds between effect bursts
+const BURST_INTERVAL: f32 = 0.2;
+
+/// Seconds between crown drops (one crown per player)
+const CROWN_INTERVAL: f32 = 2.0;
+
+/// Seconds between frame time reports
+const REPORT_INTERVAL: f32 = 5.0;
+
+/// State of the developer stress test
+#[derive(Resource, Default)]
+pub struct StressTest {
+    pub active: bool,
+    elapsed: f32,
+    burst_timer: f32,
+    crown_timer: f32,
+    report_timer: f32,
+    crowns: Vec<Entity>,
+    /// Frame times (seconds) since the last report
+    frame_times: Vec<f32>,
+    /// Frame times (seconds) over the whole run
+    all_frame_times: Vec<f32>,
+}
+
+/// Frame time summary: (average ms, 95th percentile ms, worst ms)
+fn frame_stats(frame_times: &[f32]) -> Option<(f32, f32, f32)> {
+    if frame_times.is_empty() {
+        return None;
+    }
+    let mut sorted = frame_times.to_vec();
+    sorted.sort_by(f32::total_cmp);
+    let average = sorted.iter().sum::<f32>() / sorted.len() as f32;
+    let p95 = sorted[((sorted.len() - 1) as f32 * 0.95) as usize];
+    let worst = sorted[sorted.len() - 1];
+    Some((average * 1000.0, p95 * 1000.0, worst * 1000.0))
+}
+
+/// System to start the stress test from the main menu (F9 unless rebound)
+pub fn start_stress_test(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut config: ResMut<PlayerSetupConfig>,
+    mut stress_test: ResMut<StressTest>,
+    suspended: Res<SuspendedGame>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    // A saved game would be resumed instead of set up
+    if !key_bindings.just_pressed(&keys, KeyAction::StressTest) || suspended.active {
+        return;
+    }
+
+    // Maximum table: every seat taken by the most expensive AI
+    let mut stress_config = PlayerSetupConfig::default();
+    while stress_config.players.len() < PlayerSetupConfig::MAX_PLAYERS {
+        stress_config.add_player();
+    }
+    for i in 0..stress_config.players.len() {
+        stress_config.set_player_is_ai(i, true);
+        stress_config.players[i].ai_difficulty = AiDifficulty::Hard;
+    }
+    *config = stress_config;
+
+    *stress_test = StressTest {
+        active: true,
+        ..default()
+    };
+    info!("Stress test started: {} AI players", PlayerSetupConfig::MAX_PLAYERS);
+    next_state.set(GameState::Playing);
+}
+
+/// System to drive the stress test: skip setup clicks, spawn effects and log frame times
+pub fn stress_test_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    shortcuts: Shortcuts,
+    mut stress_test: ResMut<StressTest>,
+    setup: (ResMut<UiState>, Option<ResMut<InitialSetupRolls>>),
+    entities: Query<Entity>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let (mut ui_state, initial_rolls) = setup;
+    if !stress_test.active {
+        return;
+    }
+
+    if shortcuts.just_pressed(KeyAction::StopWatching) {
+        next_state.set(GameState::MainMenu);
+        return;
+    }
+
+    // Click through the setup rolls and the Start Game button
+    if let Some(mut rolls) = initial_rolls {
+        if rolls.waiting_for_click {
+            rolls.waiting_for_click = false;
+        }
+    }
+    if ui_state.camel_rolls_complete && !ui_state.initial_rolls_complete {
+        ui_state.initial_rolls_complete = true;
+    }
+
+    let dt = time.delta_secs();
+    stress_test.elapsed += dt;
+    stress_test.frame_times.push(dt);
+    stress_test.all_frame_times.push(dt);
+
+    let mut rng = rand::thread_rng();
+
+    stress_test.burst_timer += dt;
+    if stress_test.burst_timer >= BURST_INTERVAL {
+        stress_test.burst_timer = 0.0;
+        spawn_firework(&mut commands, rng.gen_range(-400.0..400.0), random_firework_color());
+        let pos = Vec3::new(rng.gen_range(-400.0..400.0), rng.gen_range(-250.0..250.0), 60.0);
+        spawn_dice_particles(&mut commands, pos, random_firework_color());
+    }
+
+    stress_test.crown_timer += dt;
+    if stress_test.crowns.is_empty() || stress_test.crown_timer >= CROWN_INTERVAL {
+        stress_test.crown_timer = 0.0;
+        for crown in stress_test.crowns.drain(..) {
+            commands.entity(crown).despawn();
+        }
+        for i in 0..PlayerSetupConfig::MAX_PLAYERS {
+            let x = -350.0 + i as f32 * 100.0;
+            let crown = spawn_crown(&mut commands, Vec3::new(x, 280.0, 60.0), Some(200.0));
+            commands.entity(crown).insert(DespawnOnExit(GameState::Playing));
+            stress_test.crowns.push(crown);
+        }
+    }
+
+    stress_test.report_timer += dt;
+    if stress_test.report_timer >= REPORT_INTERVAL {
+        stress_test.report_timer = 0.0;
+        if let Some((average, p95, worst)) = frame_stats(&stress_test.frame_times) {
+            info!(
+                "Stress test {:.0}s: {:.1} fps, avg {:.2} ms, p95 {:.2} ms, worst {:.2} ms, {} entities",
+                stress_test.elapsed,
+                1000.0 / average,
+                average,
+                p95,
+                worst,
+                entities.iter().count(),
+            );
+        }
+        stress_test.frame_times.clear();
+    }
+}
+
+/// System to end the stress test with a summary of the whole run
+pub fn end_stress_test(mut stress_test: ResMut<StressTest>) {
+    if !stress_test.active {
+        return;
+    }
+
+    if let Some((average, p95, worst)) = frame_stats(&stress_test.all_frame_times) {
+        info!(
+            "Stress test finished after {:.0}s: {:.1} fps, avg {:.2} ms, p95 {:.2} ms, worst {:.2} ms",
+            stress_test.elapsed,
+            1000.0 / average,
+            average,
+            p95,
+            worst,
+        );
+    }
+    *stress_test = StressTest::default();
+}
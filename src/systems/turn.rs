@@ -1,37 +1,24 @@
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 use bevy::input::touch::Touches;
 use crate::components::*;
 use crate::components::dice::DieRollResult;
 use crate::components::board::{SpectatorTileSprite, PyramidRollButton, PyramidShakeAnimation, PyramidHovered};
+use crate::game::actions::{apply, Applied, GameAction, GameTable, Rejected};
+use crate::game::core::race_order;
+use crate::game::desert_events::ActiveEvent;
+use crate::game::log::{GameEvent, GameLog, RuleCheck};
+use crate::game::relay::RelayRace;
+use crate::game::rng::GameRng;
 use crate::game::state::GameState;
+use crate::network::fairness::LegFairness;
+use crate::network::state::{NetworkState, OutgoingNetworkActions};
 use crate::systems::movement::MovementCompleteEvent;
+use crate::systems::camera_effects::{CameraEffects, FINISH_PUNCH, FINISH_TRAUMA};
+use crate::systems::leg_recap::LegRecap;
 use crate::systems::animation::{DiceRollAnimation, DiceSprite, PendingCamelMove, PendingCrazyCamelMove, MovementAnimation, spawn_crown};
 use crate::ui::hud::UiState;
-use crate::systems::setup::PYRAMID_SIZE;
-
-/// The current game turn state
-#[derive(Resource)]
-pub struct TurnState {
-    pub current_player: usize,
-    pub action_taken: bool,
-    pub leg_number: u32,
-    pub awaiting_action: bool,
-    pub leg_has_started: bool, // Set to true after first action in a leg
-    pub turn_delay_timer: f32, // Timer to delay before advancing turn (for animations/pacing)
-}
-
-impl Default for TurnState {
-    fn default() -> Self {
-        Self {
-            current_player: 0,
-            action_taken: false,
-            leg_number: 1,
-            awaiting_action: true,
-            leg_has_started: false,
-            turn_delay_timer: 0.0,
-        }
-    }
-}
+use crate::systems::setup::{spawn_color_letter, spawn_color_pattern, PYRAMID_SIZE};
 
 /// Delay constants for different action types (in seconds)
 const LEG_BET_DELAY: f32 = 0.8;
@@ -39,77 +26,106 @@ const RACE_BET_DELAY: f32 = 0.8;
 const SPECTATOR_TILE_DELAY: f32 = 1.0;
 const DICE_ROLL_DELAY: f32 = 1.5; // Longer to account for animation + movement
 
-/// Stores leg bets for each player
-#[derive(Resource, Default)]
-pub struct PlayerLegBetsStore {
-    pub bets: Vec<Vec<LegBetTile>>, // bets[player_id] = list of tiles
-}
-
-impl PlayerLegBetsStore {
-    pub fn new(player_count: usize) -> Self {
-        Self {
-            bets: vec![Vec::new(); player_count],
-        }
-    }
-
-    pub fn add_bet(&mut self, player_id: usize, tile: LegBetTile) {
-        if player_id < self.bets.len() {
-            self.bets[player_id].push(tile);
-        }
-    }
-
-    pub fn clear_all(&mut self) {
-        for bets in &mut self.bets {
-            bets.clear();
-        }
-    }
-}
-
-/// Stores pyramid token counts for each player
-#[derive(Resource, Default)]
-pub struct PlayerPyramidTokens {
-    pub counts: Vec<u8>, // counts[player_id] = number of pyramid tokens
+/// The resources that make up the table, for the systems that apply `GameAction`s to it
+#[derive(SystemParam)]
+pub struct TableResources<'w> {
+    pub players: ResMut<'w, Players>,
+    pub pyramid: ResMut<'w, Pyramid>,
+    pub leg_tiles: ResMut<'w, LegBettingTiles>,
+    pub race_bets: ResMut<'w, RaceBets>,
+    pub placed_tiles: ResMut<'w, PlacedSpectatorTiles>,
+    pub leg_bets: ResMut<'w, PlayerLegBetsStore>,
+    pub pyramid_tokens: ResMut<'w, PlayerPyramidTokens>,
+    pub sheikh: ResMut<'w, SheikhBettor>,
+    pub active_event: Res<'w, ActiveEvent>,
 }
 
-impl PlayerPyramidTokens {
-    pub fn new(player_count: usize) -> Self {
-        Self {
-            counts: vec![0; player_count],
-        }
-    }
-
-    pub fn add_token(&mut self, player_id: usize) {
-        if player_id < self.counts.len() {
-            self.counts[player_id] += 1;
-        }
-    }
-
-    pub fn clear_all(&mut self) {
-        for count in &mut self.counts {
-            *count = 0;
-        }
+impl TableResources<'_> {
+    /// Apply an action to the table (see `game::actions::apply`)
+    pub fn apply(&mut self, action: &GameAction) -> Result<Applied, Rejected> {
+        let mut table = GameTable {
+            players: &mut self.players,
+            pyramid: &mut self.pyramid,
+            leg_tiles: &mut self.leg_tiles,
+            race_bets: &mut self.race_bets,
+            placed_tiles: &mut self.placed_tiles,
+            leg_bets: &mut self.leg_bets,
+            pyramid_tokens: &mut self.pyramid_tokens,
+            sheikh: &mut self.sheikh,
+            tickets_per_roll: self.active_event.tickets_per_roll(),
+        };
+        apply(&mut table, action)
     }
 }
 
-/// Player action messages
+/// Player action messages. `from_host` marks actions replayed from the online host's log.
 #[derive(Message)]
 pub struct TakeLegBetAction {
     pub color: CamelColor,
+    pub from_host: bool,
 }
 
 #[derive(Message)]
 pub struct PlaceSpectatorTileAction {
     pub space_index: u8,
     pub is_oasis: bool,
+    pub from_host: bool,
 }
 
+/// Roll the pyramid. Online clients replay the host's roll with its `result`.
 #[derive(Message)]
-pub struct RollPyramidAction;
+pub struct RollPyramidAction {
+    pub result: Option<DieRollResult>,
+}
 
 #[derive(Message)]
 pub struct PlaceRaceBetAction {
     pub color: CamelColor,
     pub is_winner_bet: bool,
+    pub from_host: bool,
+}
+
+/// The four actions a player can take on their turn, for the HUD and the AI to send
+#[derive(SystemParam)]
+pub struct PlayerActions<'w> {
+    pub roll: MessageWriter<'w, RollPyramidAction>,
+    pub leg_bet: MessageWriter<'w, TakeLegBetAction>,
+    pub race_bet: MessageWriter<'w, PlaceRaceBetAction>,
+    pub spectator_tile: MessageWriter<'w, PlaceSpectatorTileAction>,
+}
+
+/// A player gives up and hands their seat to the AI
+#[derive(Message)]
+pub struct ConcedeAction {
+    pub player_index: usize,
+}
+
+/// The AI takes over a seat its human left (walked away, or dropped out of an online game).
+/// Unlike conceding, the seat keeps competing for the win.
+#[derive(Message)]
+pub struct ConvertToAiAction {
+    pub player_index: usize,
+}
+
+/// A player who dropped out of an online game came back: their seat is theirs again
+#[derive(Message)]
+pub struct ReturnSeatAction {
+    pub player_index: usize,
+}
+
+/// The room's turn timer ran out on a player, who gets the pyramid rolled for them
+#[derive(Message)]
+pub struct TurnTimedOutAction {
+    pub player_index: usize,
+}
+
+/// The actions taken on a seat rather than on a player's turn, for the action log to replay
+#[derive(SystemParam)]
+pub struct SeatActions<'w> {
+    pub concede: MessageWriter<'w, ConcedeAction>,
+    pub convert_to_ai: MessageWriter<'w, ConvertToAiAction>,
+    pub return_seat: MessageWriter<'w, ReturnSeatAction>,
+    pub timed_out: MessageWriter<'w, TurnTimedOutAction>,
 }
 
 /// Result of rolling the pyramid (regular camel)
@@ -127,27 +143,96 @@ pub struct CrazyCamelRollResult {
 }
 
 /// System to handle taking a leg bet
+/// Rule checks every action passes: it's the acting player's turn and their first action
+fn turn_checks(players: &Players) -> Vec<RuleCheck> {
+    let player = players.current_player();
+    vec![
+        RuleCheck::new(
+            "Turn owner",
+            format!(
+                "It was {}'s turn (seat {} of {})",
+                player.name,
+                players.current_player_index + 1,
+                players.player_count()
+            ),
+        ),
+        RuleCheck::new("One action per turn", "No action had been taken yet this turn"),
+    ]
+}
+
+/// Online clients don't apply their own actions: they send them to the host and apply
+/// them once they come back in the host's log. A replay ignores them: it only plays
+/// what was recorded. Returns true if the action was sent or ignored.
+fn send_to_host(
+    network_state: &NetworkState,
+    outgoing_actions: &mut OutgoingNetworkActions,
+    from_host: bool,
+    action: GameAction,
+) -> bool {
+    if network_state.follows_log() && !from_host {
+        if network_state.is_client() {
+            outgoing_actions.actions.push(action);
+        }
+        return true;
+    }
+    false
+}
+
+/// Online hosts log every action they apply, for the clients to replay. Local and
+/// hosted games also record them for the game's replay.
+fn log_for_clients(network_state: &NetworkState, outgoing_actions: &mut OutgoingNetworkActions, action: GameAction) {
+    if network_state.is_host() {
+        outgoing_actions.actions.push(action.clone());
+    }
+    if network_state.is_host() || network_state.is_local() {
+        outgoing_actions.recorded.push(action);
+    }
+}
+
 pub fn handle_leg_bet_action(
     mut events: MessageReader<TakeLegBetAction>,
-    mut leg_tiles: ResMut<LegBettingTiles>,
-    players: Res<Players>,
+    mut table: TableResources,
     mut turn_state: ResMut<TurnState>,
-    mut player_leg_bets: ResMut<PlayerLegBetsStore>,
     mut ui_state: ResMut<UiState>,
-    time: Res<Time>,
+    mut game_log: ResMut<GameLog>,
+    // The frame's clock rather than the fixed step's, as the HUD animates the card against it
+    time: Res<Time<Virtual>>,
+    network: (Res<NetworkState>, ResMut<OutgoingNetworkActions>),
 ) {
+    let (network_state, mut outgoing_actions) = network;
     for event in events.read() {
         if turn_state.action_taken {
             continue;
         }
+        let action = GameAction::TakeLegBet { color: event.color };
+        if send_to_host(&network_state, &mut outgoing_actions, event.from_host, action.clone()) {
+            continue;
+        }
 
-        // Get the tile value before taking it (for animation)
-        let tile_value = leg_tiles.top_tile(event.color).map(|t| t.value);
+        let tiles_left = table
+            .leg_tiles
+            .stacks
+            .get(crate::game::probability::color_index(event.color))
+            .map_or(0, |stack| stack.len());
 
-        if let Some(tile) = leg_tiles.take_tile(event.color) {
-            let player_id = players.current_player_index;
-            let player = players.current_player();
+        if let Ok(Applied::LegBetTaken { tile }) = table.apply(&action) {
+            let player = table.players.current_player();
             info!("Player {} took {:?} leg bet tile worth {}", player.name, tile.camel, tile.value);
+            let mut checks = turn_checks(&table.players);
+            checks.push(RuleCheck::new(
+                "Leg tile available",
+                format!(
+                    "{} had {} tile(s) left; the top one was worth ${}",
+                    game_log.camel_names.name(tile.camel),
+                    tiles_left,
+                    tile.value
+                ),
+            ));
+            game_log.record_action(turn_state.leg_number, GameEvent::LegBetTaken {
+                player: player.name.clone(),
+                color: tile.camel,
+                value: tile.value,
+            }, checks);
 
             // Trigger card flight animation
             // Get the card position from UiState (tracked during previous frame's render)
@@ -165,7 +250,7 @@ pub fn handle_leg_bet_action(
                 let end_pos = ui_state.player_bet_area_pos.unwrap_or_else(|| egui::pos2(60.0, 40.0));
                 ui_state.card_flight_animation = Some(CardFlightAnimation {
                     color: event.color,
-                    value: tile_value.unwrap_or(tile.value),
+                    value: tile.value,
                     start_pos,
                     end_pos,
                     start_time: time.elapsed_secs_f64(),
@@ -173,7 +258,7 @@ pub fn handle_leg_bet_action(
                 });
             }
 
-            player_leg_bets.add_bet(player_id, tile);
+            log_for_clients(&network_state, &mut outgoing_actions, action);
             turn_state.action_taken = true;
             turn_state.leg_has_started = true;
             turn_state.turn_delay_timer = LEG_BET_DELAY;
@@ -181,116 +266,207 @@ pub fn handle_leg_bet_action(
     }
 }
 
+/// Where the pyramid's dice are drawn from
+#[derive(SystemParam)]
+pub struct DiceSource<'w> {
+    game_rng: ResMut<'w, GameRng>,
+    leg_fairness: ResMut<'w, LegFairness>,
+}
+
+/// What a pyramid roll sets off on the table: the pyramid's shake, the die sprite and the
+/// result messages the HUD listens for
+#[derive(SystemParam)]
+pub struct RollEffects<'w, 's> {
+    commands: Commands<'w, 's>,
+    pyramid_button: Query<'w, 's, Entity, With<PyramidRollButton>>,
+    roll_result: MessageWriter<'w, PyramidRollResult>,
+    crazy_roll_result: MessageWriter<'w, CrazyCamelRollResult>,
+    camel_palette: Res<'w, CamelPalette>,
+}
+
 /// System to handle rolling the pyramid
 pub fn handle_pyramid_roll_action(
-    mut commands: Commands,
     mut events: MessageReader<RollPyramidAction>,
-    mut pyramid: ResMut<Pyramid>,
-    mut players: ResMut<Players>,
+    mut table: TableResources,
     mut turn_state: ResMut<TurnState>,
-    mut player_pyramid_tokens: ResMut<PlayerPyramidTokens>,
-    mut roll_result: MessageWriter<PyramidRollResult>,
-    mut crazy_roll_result: MessageWriter<CrazyCamelRollResult>,
-    pyramid_button: Query<Entity, With<PyramidRollButton>>,
+    mut effects: RollEffects,
+    mut game_log: ResMut<GameLog>,
+    mut dice: DiceSource,
+    network: (ResMut<NetworkState>, ResMut<OutgoingNetworkActions>),
 ) {
-    for _ in events.read() {
+    let (mut network_state, mut outgoing_actions) = network;
+    for event in events.read() {
         if turn_state.action_taken {
             continue;
         }
 
-        // Calculate tent index BEFORE rolling (number of dice already rolled)
-        let tent_index = pyramid.rolled_dice.len();
-
-        if let Some(die_result) = pyramid.roll_random_die() {
-            // Trigger pyramid shake animation (works for both human and AI rolls)
-            if let Ok(pyramid_entity) = pyramid_button.single() {
-                commands.entity(pyramid_entity).insert(PyramidShakeAnimation::new());
-            }
-            let player_id = players.current_player_index;
-
-            // Player earns 1 coin for rolling
-            players.current_player_mut().money += 1;
+        // Online clients never roll locally: they replay the host's rolls
+        let from_host = event.result.is_some();
+        if send_to_host(&network_state, &mut outgoing_actions, from_host, GameAction::RollPyramid { result: None }) {
+            continue;
+        }
 
-            // Track pyramid token (only for regular dice)
-            match &die_result {
-                DieRollResult::Regular { .. } => {
-                    player_pyramid_tokens.add_token(player_id);
+        // Calculate tent index BEFORE rolling (number of dice already rolled)
+        let tent_index = table.pyramid.rolled_dice.len();
+        let mut checks = turn_checks(&table.players);
+        checks.push(RuleCheck::new(
+            "Dice left in the pyramid",
+            format!("{} dice were still in the pyramid", table.pyramid.remaining_dice_count()),
+        ));
+
+        // Online hosts roll from the leg's committed seed so clients can verify the rolls;
+        // local games from the game's seed, so the same seed rolls the same dice
+        let committed_leg = dice.leg_fairness.leg;
+        let result = if from_host {
+            event.result.clone()
+        } else {
+            match dice.leg_fairness.rng_mut() {
+                Some(rng) if network_state.is_host() => {
+                    checks.push(RuleCheck::new(
+                        "Fair dice",
+                        format!("Rolled from the seed committed for leg {}", committed_leg),
+                    ));
+                    table.pyramid.draw_die_with(rng)
                 }
-                DieRollResult::Crazy { .. } => {
-                    // Crazy camel dice don't give pyramid tokens
+                _ => table.pyramid.draw_die_with(&mut **dice.game_rng),
+            }
+        };
+        // An empty pyramid has nothing to roll
+        let Some(die_result) = result else { continue };
+
+        let action = GameAction::RollPyramid { result: Some(die_result.clone()) };
+        let sheikh_tile = match table.apply(&action) {
+            Ok(Applied::Rolled { sheikh_tile }) => sheikh_tile,
+            _ => {
+                // The host rolled a die that isn't in the pyramid here: we're out of step,
+                // and only the host's state can tell what the roll should have been
+                if from_host && !network_state.resyncing {
+                    warn!("Host rolled {:?}, which isn't left in the pyramid here, resyncing", die_result);
+                    network_state.resyncing = true;
+                    outgoing_actions.actions.push(GameAction::RequestState);
                 }
+                continue;
             }
+        };
 
-            // Spawn animated dice sprite in center of board
-            let dice_pos = Vec3::new(0.0, 0.0, 100.0); // Center, high Z to be on top
-            let target_tent_pos = get_tent_world_position(tent_index);
+        // Trigger pyramid shake animation (works for both human and AI rolls)
+        if let Ok(pyramid_entity) = effects.pyramid_button.single() {
+            effects.commands.entity(pyramid_entity).insert(PyramidShakeAnimation::new());
+        }
 
-            let (dice_color, roll_value) = match &die_result {
-                DieRollResult::Regular { color, value } => {
-                    (color.to_bevy_color(), *value)
-                }
-                DieRollResult::Crazy { color, value } => {
-                    (color.to_bevy_color(), *value)
-                }
-            };
+        // Movement will be triggered when the dice animation finishes shaking
+        let extra_spaces = table.active_event.extra_move();
+        spawn_roll_dice(&mut effects.commands, &die_result, tent_index, extra_spaces, *effects.camel_palette);
+
+        log_for_clients(&network_state, &mut outgoing_actions, action);
+
+        // Send result events for UI updates (but NOT movement - that's triggered by animation)
+        let player_name = table.players.current_player().name.clone();
+        match die_result {
+            DieRollResult::Regular { color, value } => {
+                info!("Rolled {:?} - {}", color, value);
+                game_log.record_action(turn_state.leg_number, GameEvent::DieRolled {
+                    player: player_name,
+                    color,
+                    value,
+                }, checks);
+                effects.roll_result.write(PyramidRollResult { color, value });
+            }
+            DieRollResult::Crazy { color, value } => {
+                info!("Rolled crazy camel {:?} - {} (moving backwards!)", color, value);
+                game_log.record_action(turn_state.leg_number, GameEvent::CrazyDieRolled {
+                    player: player_name,
+                    color,
+                    value,
+                }, checks);
+                effects.crazy_roll_result.write(CrazyCamelRollResult { color, value });
+            }
+        }
 
-            // Spawn the dice sprite with animation and pending movement
-            // Movement will be triggered when the dice animation finishes shaking
-            // After display, dice moves to tent and stays there
-            let mut dice_entity = commands.spawn((
-                DiceSprite,
-                DiceRollAnimation::new(dice_pos, target_tent_pos),
-                Sprite {
-                    color: dice_color,
-                    custom_size: Some(Vec2::new(60.0, 60.0)),
-                    ..default()
-                },
-                Transform::from_translation(dice_pos),
-            ));
+        // 2-player variant: the Sheikh took the rolled camel's top leg tile
+        if let Some(tile) = sheikh_tile {
+            info!("{} took {:?} leg bet tile worth {}", SheikhBettor::NAME, tile.camel, tile.value);
+            let sheikh_check = RuleCheck::new(
+                "Neutral Sheikh variant",
+                "In 2-player games the Sheikh takes the rolled camel's top leg tile",
+            );
+            game_log.record_action(turn_state.leg_number, GameEvent::LegBetTaken {
+                player: SheikhBettor::NAME.to_string(),
+                color: tile.camel,
+                value: tile.value,
+            }, vec![sheikh_check]);
+        }
 
-            // Add the pending movement component based on die type
-            match &die_result {
-                DieRollResult::Regular { color, value } => {
-                    dice_entity.insert(PendingCamelMove { color: *color, spaces: *value });
-                }
-                DieRollResult::Crazy { color, value } => {
-                    dice_entity.insert(PendingCrazyCamelMove { color: *color, spaces: *value });
-                }
-            }
+        turn_state.action_taken = true;
+        turn_state.leg_has_started = true;
+        turn_state.turn_delay_timer = DICE_ROLL_DELAY;
+    }
+}
 
-            dice_entity.with_children(|parent| {
-                // Spawn the value text as a child
-                // Using a simple colored square for the pip representation
-                let pip_positions = get_pip_positions(roll_value);
-                for pip_pos in pip_positions {
-                    parent.spawn((
-                        Sprite {
-                            color: Color::WHITE,
-                            custom_size: Some(Vec2::new(10.0, 10.0)),
-                            ..default()
-                        },
-                        Transform::from_translation(Vec3::new(pip_pos.x, pip_pos.y, 1.0)),
-                    ));
-                }
-            });
+/// Spawn the animated dice sprite in the center of the board.
+/// After display, the dice moves to its tent and stays there. The camel movement is
+/// triggered when the shake animation finishes, adding `extra_spaces` to racing camel moves.
+fn spawn_roll_dice(
+    commands: &mut Commands,
+    die_result: &DieRollResult,
+    tent_index: usize,
+    extra_spaces: u8,
+    camel_palette: CamelPalette,
+) {
+    let dice_pos = Vec3::new(0.0, 0.0, 100.0); // Center, high Z to be on top
+    let target_tent_pos = get_tent_world_position(tent_index);
 
-            // Send result events for UI updates (but NOT movement - that's triggered by animation)
-            match die_result {
-                DieRollResult::Regular { color, value } => {
-                    info!("Rolled {:?} - {}", color, value);
-                    roll_result.write(PyramidRollResult { color, value });
-                }
-                DieRollResult::Crazy { color, value } => {
-                    info!("Rolled crazy camel {:?} - {} (moving backwards!)", color, value);
-                    crazy_roll_result.write(CrazyCamelRollResult { color, value });
-                }
-            }
+    let (dice_color, roll_value) = match die_result {
+        DieRollResult::Regular { color, value } => {
+            (color.to_bevy_color(camel_palette), *value)
+        }
+        DieRollResult::Crazy { color, value } => {
+            (color.to_bevy_color(), *value)
+        }
+    };
 
-            turn_state.action_taken = true;
-            turn_state.leg_has_started = true;
-            turn_state.turn_delay_timer = DICE_ROLL_DELAY;
+    let mut dice_entity = commands.spawn((
+        DiceSprite,
+        DiceRollAnimation::new(dice_pos, target_tent_pos),
+        Sprite {
+            color: dice_color,
+            custom_size: Some(Vec2::new(60.0, 60.0)),
+            ..default()
+        },
+        Transform::from_translation(dice_pos),
+    ));
+
+    // Add the pending movement component based on die type
+    match die_result {
+        DieRollResult::Regular { color, value } => {
+            dice_entity.insert(PendingCamelMove { color: *color, spaces: *value + extra_spaces });
+        }
+        DieRollResult::Crazy { color, value } => {
+            dice_entity.insert(PendingCrazyCamelMove { color: *color, spaces: *value });
         }
     }
+
+    dice_entity.with_children(|parent| {
+        // Racing camel dice carry the color's pattern and letter (clear of the pips)
+        if let (DieRollResult::Regular { color, .. }, true) = (die_result, camel_palette.markers) {
+            spawn_color_pattern(parent, *color, Vec2::ZERO, Vec2::splat(56.0), 0.5);
+            spawn_color_letter(parent, *color, Vec2::new(17.0, 17.0), 16.0, 1.2);
+        }
+
+        // Spawn the value text as a child
+        // Using a simple colored square for the pip representation
+        let pip_positions = get_pip_positions(roll_value);
+        for pip_pos in pip_positions {
+            parent.spawn((
+                Sprite {
+                    color: Color::WHITE,
+                    custom_size: Some(Vec2::new(10.0, 10.0)),
+                    ..default()
+                },
+                Transform::from_translation(Vec3::new(pip_pos.x, pip_pos.y, 1.0)),
+            ));
+        }
+    });
 }
 
 /// Constants for tent layout (must match setup.rs)
@@ -329,34 +505,49 @@ fn get_pip_positions(value: u8) -> Vec<Vec2> {
 /// System to handle race bets (overall winner/loser)
 pub fn handle_race_bet_action(
     mut events: MessageReader<PlaceRaceBetAction>,
-    mut race_bets: ResMut<RaceBets>,
-    mut players: ResMut<Players>,
+    mut table: TableResources,
     mut turn_state: ResMut<TurnState>,
+    mut game_log: ResMut<GameLog>,
+    network: (Res<NetworkState>, ResMut<OutgoingNetworkActions>),
 ) {
+    let (network_state, mut outgoing_actions) = network;
     for event in events.read() {
         if turn_state.action_taken {
             continue;
         }
+        let action = GameAction::PlaceRaceBet { color: event.color, is_winner_bet: event.is_winner_bet };
+        if send_to_host(&network_state, &mut outgoing_actions, event.from_host, action.clone()) {
+            continue;
+        }
 
-        let player = players.current_player_mut();
+        let mut checks = turn_checks(&table.players);
+        let cards_left = table.players.current_player().available_race_cards.len();
 
-        // Check if player still has this card
-        if !player.available_race_cards.contains(&event.color) {
+        // The player must still have this card
+        if table.apply(&action).is_err() {
             continue;
         }
-
-        // Remove the card from player's hand
-        player.available_race_cards.remove(&event.color);
-        let player_id = player.id;
-        let player_name = player.name.clone();
+        let player_name = table.players.current_player().name.clone();
+        checks.push(RuleCheck::new(
+            "Race card in hand",
+            format!(
+                "{} still held the {} race card ({} cards left)",
+                player_name,
+                game_log.camel_names.name(event.color),
+                cards_left
+            ),
+        ));
 
         if event.is_winner_bet {
-            race_bets.place_winner_bet(event.color, player_id);
             info!("Player {} bet on {:?} to win", player_name, event.color);
         } else {
-            race_bets.place_loser_bet(event.color, player_id);
             info!("Player {} bet on {:?} to lose", player_name, event.color);
         }
+        game_log.record_action(turn_state.leg_number, GameEvent::RaceBetPlaced {
+            player: player_name,
+            is_winner_bet: event.is_winner_bet,
+        }, checks);
+        log_for_clients(&network_state, &mut outgoing_actions, action);
 
         turn_state.action_taken = true;
         turn_state.leg_has_started = true;
@@ -364,60 +555,170 @@ pub fn handle_race_bet_action(
     }
 }
 
+/// System to hand conceded seats over to the AI (not a turn action, allowed any time)
+pub fn handle_concede_action(
+    mut events: MessageReader<ConcedeAction>,
+    mut table: TableResources,
+    turn_state: Res<TurnState>,
+    mut game_log: ResMut<GameLog>,
+    network: (Res<NetworkState>, ResMut<OutgoingNetworkActions>),
+) {
+    let (network_state, mut outgoing_actions) = network;
+    for event in events.read() {
+        let action = GameAction::Concede { player_index: event.player_index };
+        if table.apply(&action).is_err() {
+            continue;
+        }
+
+        let player = &table.players.players[event.player_index];
+        info!("Player {} conceded, AI takes over", player.name);
+        game_log.record(turn_state.leg_number, GameEvent::PlayerConceded {
+            player: player.name.clone(),
+        });
+        log_for_clients(&network_state, &mut outgoing_actions, action);
+    }
+}
+
+/// System to hand vacated seats over to the AI (not a turn action, allowed any time).
+/// Bets stay keyed by seat index and player id, so the AI picks up the seat's leg bet
+/// tiles, race bets, cards and money exactly where the human left them.
+pub fn handle_convert_to_ai_action(
+    mut events: MessageReader<ConvertToAiAction>,
+    mut table: TableResources,
+    turn_state: Res<TurnState>,
+    mut game_log: ResMut<GameLog>,
+    network: (Res<NetworkState>, ResMut<OutgoingNetworkActions>),
+) {
+    let (network_state, mut outgoing_actions) = network;
+    for event in events.read() {
+        let action = GameAction::ConvertToAi { player_index: event.player_index };
+        if table.apply(&action).is_err() {
+            continue;
+        }
+
+        let player = &table.players.players[event.player_index];
+        info!("AI takes over {}'s seat", player.name);
+        game_log.record(turn_state.leg_number, GameEvent::SeatTakenOver {
+            player: player.name.clone(),
+        });
+        log_for_clients(&network_state, &mut outgoing_actions, action);
+    }
+}
+
+/// System to give seats the AI took over back to their players (not a turn action,
+/// allowed any time). Conceded seats stay with the AI.
+pub fn handle_return_seat_action(
+    mut events: MessageReader<ReturnSeatAction>,
+    mut table: TableResources,
+    turn_state: Res<TurnState>,
+    mut game_log: ResMut<GameLog>,
+    network: (Res<NetworkState>, ResMut<OutgoingNetworkActions>),
+) {
+    let (network_state, mut outgoing_actions) = network;
+    for event in events.read() {
+        let action = GameAction::ReturnSeat { player_index: event.player_index };
+        if table.apply(&action).is_err() {
+            continue;
+        }
+
+        let player = &table.players.players[event.player_index];
+        info!("{} is back and takes their seat again", player.name);
+        game_log.record(turn_state.leg_number, GameEvent::SeatReturned {
+            player: player.name.clone(),
+        });
+        log_for_clients(&network_state, &mut outgoing_actions, action);
+    }
+}
+
+/// System to note a player running out of time (the roll made for them follows as a
+/// normal roll)
+pub fn handle_turn_timed_out_action(
+    mut events: MessageReader<TurnTimedOutAction>,
+    players: Res<Players>,
+    turn_state: Res<TurnState>,
+    mut game_log: ResMut<GameLog>,
+    network: (Res<NetworkState>, ResMut<OutgoingNetworkActions>),
+) {
+    let (network_state, mut outgoing_actions) = network;
+    for event in events.read() {
+        let Some(player) = players.players.get(event.player_index) else {
+            continue;
+        };
+        game_log.record(turn_state.leg_number, GameEvent::TurnTimedOut {
+            player: player.name.clone(),
+        });
+        log_for_clients(&network_state, &mut outgoing_actions, GameAction::TurnTimedOut { player_index: event.player_index });
+    }
+}
+
+/// The board a spectator tile is placed on: the camels that keep it off their spaces,
+/// and the layout its sprite is spawned by
+#[derive(SystemParam)]
+pub struct SpectatorTileBoard<'w, 's> {
+    commands: Commands<'w, 's>,
+    board: Res<'w, GameBoard>,
+    camels: Query<'w, 's, &'static BoardPosition, AnyCamel>,
+}
+
 /// System to handle spectator tile placement
 pub fn handle_spectator_tile_action(
     mut events: MessageReader<PlaceSpectatorTileAction>,
-    mut placed_tiles: ResMut<PlacedSpectatorTiles>,
-    mut players: ResMut<Players>,
+    mut table: TableResources,
     mut turn_state: ResMut<TurnState>,
-    camels: Query<&BoardPosition, With<Camel>>,
-    crazy_camels: Query<&BoardPosition, With<CrazyCamel>>,
-    mut commands: Commands,
-    board: Res<GameBoard>,
+    mut tile_board: SpectatorTileBoard,
+    mut game_log: ResMut<GameLog>,
+    network: (Res<NetworkState>, ResMut<OutgoingNetworkActions>),
 ) {
+    let (network_state, mut outgoing_actions) = network;
     for event in events.read() {
         if turn_state.action_taken {
             continue;
         }
-
-        let player = players.current_player_mut();
-
-        // Check if player has their spectator tile
-        if !player.has_spectator_tile {
+        let action = GameAction::PlaceSpectatorTile { space_index: event.space_index, is_oasis: event.is_oasis };
+        if send_to_host(&network_state, &mut outgoing_actions, event.from_host, action.clone()) {
             continue;
         }
 
-        // Check if space is valid (not space 0, no camels, no existing tiles)
-        if event.space_index == 0 {
+        // No spectator tiles during a sandstorm
+        if table.active_event.spectator_tiles_banned() {
             continue;
         }
 
+        let mut checks = turn_checks(&table.players);
+        if table.active_event.enabled {
+            checks.push(RuleCheck::new("No sandstorm", "Spectator tiles were allowed this leg"));
+        }
+
         // Check for camels on the space (including crazy camels)
-        let has_camel = camels.iter().any(|pos| pos.space_index == event.space_index);
-        let has_crazy_camel = crazy_camels.iter().any(|pos| pos.space_index == event.space_index);
-        if has_camel || has_crazy_camel {
+        if tile_board.camels.iter().any(|pos| pos.space_index == event.space_index) {
             continue;
         }
 
-        // Check for existing tile on the space
-        if placed_tiles.is_space_occupied(event.space_index) {
+        // The player must have their tile, and the space must be past the start with no
+        // tile on it. A tile already placed moves to the new space.
+        if table.apply(&action).is_err() {
             continue;
         }
-
-        // Remove any existing tile from this player
-        placed_tiles.remove_player_tile(player.id);
-
-        // Place the new tile
-        placed_tiles.place_tile(event.space_index, player.id, event.is_oasis);
-
-        // Mark player as having placed their tile (they can move it later)
-        player.has_spectator_tile = false;
+        let player = table.players.current_player();
+        let space = event.space_index + 1;
+        checks.extend([
+            RuleCheck::new("Tile in hand", format!("{} had a spectator tile to place", player.name)),
+            RuleCheck::new("Not the start space", format!("Space {} is past the first space", space)),
+            RuleCheck::new("Space free of camels", format!("No racing or crazy camel was on space {}", space)),
+            RuleCheck::new("Space free of tiles", format!("No other spectator tile was on space {}", space)),
+        ]);
 
         let tile_type = if event.is_oasis { "Oasis" } else { "Mirage" };
         info!("Player {} placed {} on space {}", player.name, tile_type, event.space_index + 1);
+        game_log.record_action(turn_state.leg_number, GameEvent::SpectatorTilePlaced {
+            player: player.name.clone(),
+            space_index: event.space_index,
+            is_oasis: event.is_oasis,
+        }, checks);
+        log_for_clients(&network_state, &mut outgoing_actions, action);
 
         // Spawn visual representation of the spectator tile with polished layers
-        let pos = board.get_position(event.space_index);
+        let pos = tile_board.board.get_position(event.space_index);
         let tile_size = Vec2::new(35.0, 18.0);
 
         let (main_color, border_color) = if event.is_oasis {
@@ -427,7 +728,7 @@ pub fn handle_spectator_tile_action(
         };
 
         // Parent entity with spectator tile component
-        commands.spawn((
+        tile_board.commands.spawn((
             DespawnOnExit(GameState::GameEnd),
             SpectatorTile {
                 owner_id: player.id,
@@ -491,6 +792,7 @@ pub fn advance_turn_system(
     mut players: ResMut<Players>,
     time: Res<Time>,
     ui_state: Res<crate::ui::hud::UiState>,
+    mut game_log: ResMut<GameLog>,
 ) {
     // Don't advance turns while leg scoring modal is showing
     if ui_state.show_leg_scoring {
@@ -509,6 +811,7 @@ pub fn advance_turn_system(
         turn_state.current_player = players.current_player_index;
         turn_state.action_taken = false;
         turn_state.awaiting_action = true;
+        game_log.turn += 1;
     }
 }
 
@@ -518,13 +821,27 @@ pub fn check_leg_end_system(
     turn_state: Res<TurnState>,
     time: Res<Time>,
     mut ui_state: ResMut<crate::ui::hud::UiState>,
+    mut active_event: ResMut<ActiveEvent>,
+    mut game_log: ResMut<GameLog>,
+    mut leg_recap: ResMut<LegRecap>,
 ) {
-    // Count down the leg scoring delay timer
+    // Desert Events variant: draw this leg's event once the previous leg has been scored
+    if active_event.enabled
+        && active_event.drawn_for_leg != turn_state.leg_number
+        && !ui_state.show_leg_scoring
+    {
+        let event = active_event.draw(turn_state.leg_number);
+        info!("Leg {} desert event: {}", turn_state.leg_number, event.name());
+        game_log.record(turn_state.leg_number, GameEvent::DesertEventDrawn { event });
+    }
+
+    // Count down the leg scoring delay timer, then play the recap.
+    // `leg_recap_system` opens the scoring modal once the recap is over.
     if ui_state.leg_scoring_delay > 0.0 {
         ui_state.leg_scoring_delay -= time.delta_secs();
         if ui_state.leg_scoring_delay <= 0.0 {
             ui_state.leg_scoring_delay = 0.0;
-            ui_state.show_leg_scoring = true;
+            leg_recap.start();
         }
         return;
     }
@@ -537,6 +854,7 @@ pub fn check_leg_end_system(
         && !turn_state.action_taken
         && turn_state.turn_delay_timer <= 0.0
         && !ui_state.show_leg_scoring
+        && leg_recap.is_idle()
     {
         info!("Leg {} complete! Showing scoring...", turn_state.leg_number);
         // Start the 800ms delay before showing the modal
@@ -544,43 +862,58 @@ pub fn check_leg_end_system(
     }
 }
 
+/// What greets the winning camel at the finish: the camera's shake and punch, and the
+/// winner's run past the line to its crown
+#[derive(SystemParam)]
+pub struct FinishCelebration<'w, 's> {
+    commands: Commands<'w, 's>,
+    board: Res<'w, GameBoard>,
+    camera_effects: ResMut<'w, CameraEffects>,
+}
+
 /// System to check if game has ended (camel crossed finish)
 pub fn check_game_end_system(
-    mut commands: Commands,
     mut events: MessageReader<MovementCompleteEvent>,
     mut ui_state: ResMut<crate::ui::hud::UiState>,
     camels: Query<(Entity, &Camel, &BoardPosition, &Transform)>,
-    board: Res<GameBoard>,
+    mut celebration: FinishCelebration,
+    mut game_log: ResMut<GameLog>,
+    turn_state: Res<TurnState>,
+    relay: Option<Res<RelayRace>>,
 ) {
     for event in events.read() {
+        // Relay Race: finishing an earlier track moves the race on instead of ending it
+        if relay.as_ref().is_some_and(|relay| relay.handing_off()) {
+            continue;
+        }
         if event.crossed_finish && ui_state.game_end_delay <= 0.0 {
             info!("A camel crossed the finish line! Game over!");
 
             // Find the winning camel (highest space index, then highest stack position)
-            let mut winner: Option<(Entity, u8, u8, Vec3)> = None;
-            for (entity, _camel, pos, transform) in camels.iter() {
-                match winner {
-                    None => winner = Some((entity, pos.space_index, pos.stack_position, transform.translation)),
-                    Some((_, best_space, best_stack, _)) => {
-                        if pos.space_index > best_space ||
-                           (pos.space_index == best_space && pos.stack_position > best_stack) {
-                            winner = Some((entity, pos.space_index, pos.stack_position, transform.translation));
-                        }
-                    }
+            let winner = race_order(
+                camels.iter().map(|(entity, _, pos, _)| (entity, pos.space_index, pos.stack_position)),
+            )
+            .first()
+            .copied();
+
+            if let Some(winner_entity) = winner {
+                let current_pos = camels.get(winner_entity).map_or(Vec3::ZERO, |(_, _, _, transform)| transform.translation);
+                if let Ok((_, camel, _, _)) = camels.get(winner_entity) {
+                    game_log.record(turn_state.leg_number, GameEvent::CamelFinished { color: camel.color });
                 }
-            }
+                celebration.camera_effects.add_trauma(FINISH_TRAUMA);
+                celebration.camera_effects.punch(FINISH_PUNCH);
 
-            if let Some((winner_entity, _space, _stack, current_pos)) = winner {
                 // Calculate winner position: just past the finish line
                 // Finish line is at space 15 (leftmost on top row)
                 // Winner goes 80 pixels further left (past the finish)
-                let finish_pos = board.get_position(15);
+                let finish_pos = celebration.board.get_position(15);
                 let winner_target_x = finish_pos.x - 80.0;
                 let winner_target_y = finish_pos.y; // Stay on top row Y
                 let winner_target = Vec3::new(winner_target_x, winner_target_y, current_pos.z);
 
                 // Move winner camel to victory position
-                commands.entity(winner_entity).insert(
+                celebration.commands.entity(winner_entity).insert(
                     MovementAnimation::new(current_pos, winner_target, 0.6)
                 );
 
@@ -592,7 +925,7 @@ pub fn check_game_end_system(
                 let head_top_y = winner_target_y + 16.0 + 5.0; // head height is 10, so +5 to top
                 // Shift crown 2px right so it doesn't extend as far to the left
                 let crown_pos = Vec3::new(head_center_x + 2.0, head_top_y + 8.0, 50.0);
-                spawn_crown(&mut commands, crown_pos, Some(150.0));
+                spawn_crown(&mut celebration.commands, crown_pos, Some(150.0));
 
                 info!("Crown spawned for winning camel!");
             }
@@ -618,6 +951,83 @@ pub fn game_end_delay_system(
     }
 }
 
+/// Board space tints while the current player is placing their spectator tile, laid
+/// over the painted sand (just short of opaque, so the space's highlight shows through)
+const VALID_PLACEMENT_COLOR: Color = Color::srgba(0.6, 0.85, 0.5, 0.85);
+const SELECTED_PLACEMENT_COLOR: Color = Color::srgba(0.45, 0.95, 0.4, 0.85);
+const INVALID_PLACEMENT_COLOR: Color = Color::srgba(0.72, 0.45, 0.4, 0.85);
+
+/// System to tint the board spaces green or red while a spectator tile is being placed,
+/// from the popup or the selected card, so the board shows where it can go
+pub fn tint_board_spaces_for_placement(
+    ui_state: Res<UiState>,
+    players: Option<Res<Players>>,
+    camels: Query<&BoardPosition, AnyCamel>,
+    placed_tiles: Option<Res<PlacedSpectatorTiles>>,
+    turn_state: Option<Res<TurnState>>,
+    mut spaces: Query<(&BoardSpace, &mut Sprite)>,
+) {
+    let Some(players) = players else { return };
+    let Some(placed_tiles) = placed_tiles else { return };
+    let Some(turn_state) = turn_state else { return };
+
+    let current = players.current_player();
+    let is_placing = (ui_state.show_spectator_tile || ui_state.spectator_tile_selected)
+        && current.has_spectator_tile
+        && !turn_state.action_taken
+        && !current.is_ai
+        && ui_state.initial_rolls_complete;
+
+    for (space, mut sprite) in spaces.iter_mut() {
+        let color = if !is_placing {
+            space.base_color
+        } else {
+            // Same rules as placing by tapping the board: not the first space, no camels,
+            // and no one else's tile
+            let has_camel = camels.iter().any(|pos| pos.space_index == space.index);
+            let has_other_tile = placed_tiles
+                .get_tile(space.index)
+                .is_some_and(|(owner, _)| owner != current.id);
+
+            if space.index == 0 || has_camel || has_other_tile {
+                INVALID_PLACEMENT_COLOR
+            } else if ui_state.spectator_tile_space == Some(space.index) {
+                SELECTED_PLACEMENT_COLOR
+            } else {
+                VALID_PLACEMENT_COLOR
+            }
+        };
+
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    }
+}
+
+/// What decides which spaces the current player's spectator tile can go on: the camels
+/// on the board and the tiles already placed
+#[derive(SystemParam)]
+pub struct TilePlacement<'w, 's> {
+    players: Option<Res<'w, Players>>,
+    placed_tiles: Option<Res<'w, PlacedSpectatorTiles>>,
+    camels: Query<'w, 's, &'static BoardPosition, AnyCamel>,
+}
+
+impl TilePlacement<'_, '_> {
+    /// Whether the current player can place their tile on `space`: past the first space,
+    /// with no camel (racing or crazy) and no one else's tile on it
+    fn allows(&self, space: u8) -> bool {
+        let (Some(players), Some(placed_tiles)) = (&self.players, &self.placed_tiles) else {
+            return false;
+        };
+        let current = players.current_player();
+        let has_camel = self.camels.iter().any(|pos| pos.space_index == space);
+        let has_other_tile = placed_tiles.tiles.iter()
+            .any(|(&s, &(owner, _))| s == space && owner != current.id);
+        space != 0 && !has_camel && !has_other_tile
+    }
+}
+
 /// System to update spectator tile sprite colors based on game state
 /// Each board space has a spectator tile sprite that can be:
 /// - Transparent (invisible): no tile selection active and no tile placed
@@ -625,16 +1035,13 @@ pub fn game_end_delay_system(
 /// - Fully opaque: tile has been placed on this space
 pub fn update_spectator_tile_sprites(
     ui_state: Res<UiState>,
-    players: Option<Res<Players>>,
-    camels: Query<&BoardPosition, With<Camel>>,
-    crazy_camels: Query<&BoardPosition, With<CrazyCamel>>,
-    placed_tiles: Option<Res<PlacedSpectatorTiles>>,
+    placement: TilePlacement,
     turn_state: Option<Res<TurnState>>,
     mut tile_sprites: Query<(&SpectatorTileSprite, &mut Sprite, &Children)>,
     mut text_query: Query<&mut TextColor>,
 ) {
-    let Some(players) = players else { return };
-    let Some(placed_tiles) = placed_tiles else { return };
+    let Some(players) = &placement.players else { return };
+    let Some(placed_tiles) = &placement.placed_tiles else { return };
     let Some(turn_state) = turn_state else { return };
 
     let current = players.current_player();
@@ -679,12 +1086,7 @@ pub fn update_spectator_tile_sprites(
         }
 
         // Check if this space is valid for placement (including crazy camels)
-        let has_camel = camels.iter().any(|pos| pos.space_index == space);
-        let has_crazy_camel = crazy_camels.iter().any(|pos| pos.space_index == space);
-        let has_other_tile = placed_tiles.tiles.iter()
-            .any(|(&s, &(owner, _))| s == space && owner != current.id);
-
-        if has_camel || has_crazy_camel || has_other_tile {
+        if !placement.allows(space) {
             // Invalid space - hide or show as invalid (red tint)
             sprite.color = Color::srgba(0.5, 0.2, 0.2, 0.3); // Semi-transparent red
             for child in children.iter() {
@@ -710,47 +1112,56 @@ pub fn update_spectator_tile_sprites(
     }
 }
 
+/// The mouse or a finger on the board, in the board's world coordinates
+#[derive(SystemParam)]
+pub struct BoardPointer<'w, 's> {
+    windows: Query<'w, 's, &'static Window>,
+    camera_query: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<crate::GameCamera>>,
+    mouse_input: Res<'w, ButtonInput<MouseButton>>,
+    touches: Res<'w, Touches>,
+}
+
+impl BoardPointer<'_, '_> {
+    /// Where on the board a click or tap landed this frame
+    fn just_pressed(&self) -> Option<Vec2> {
+        let screen_pos = if self.mouse_input.just_pressed(MouseButton::Left) {
+            self.windows.single().ok().and_then(|w| w.cursor_position())
+        } else {
+            self.touches.iter_just_pressed().next().map(|touch| touch.position())
+        };
+        self.to_world(screen_pos?)
+    }
+
+    /// Where on the board the mouse cursor is
+    fn hovered(&self) -> Option<Vec2> {
+        let screen_pos = self.windows.single().ok().and_then(|w| w.cursor_position());
+        self.to_world(screen_pos?)
+    }
+
+    fn to_world(&self, screen_pos: Vec2) -> Option<Vec2> {
+        let (camera, camera_transform) = self.camera_query.single().ok()?;
+        camera.viewport_to_world_2d(camera_transform, screen_pos).ok()
+    }
+}
+
 /// System to handle clicks/taps on spectator tile sprites for placement
 pub fn handle_spectator_tile_clicks(
-    windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    pointer: BoardPointer,
     tile_sprites: Query<(&GlobalTransform, &SpectatorTileSprite, &Sprite)>,
-    mouse_input: Res<ButtonInput<MouseButton>>,
-    touches: Res<Touches>,
     mut ui_state: ResMut<UiState>,
     mut action: MessageWriter<PlaceSpectatorTileAction>,
-    players: Option<Res<Players>>,
-    camels: Query<&BoardPosition, With<Camel>>,
-    crazy_camels: Query<&BoardPosition, With<CrazyCamel>>,
-    placed_tiles: Option<Res<PlacedSpectatorTiles>>,
+    placement: TilePlacement,
 ) {
     // Only process if tile is selected
     if !ui_state.spectator_tile_selected {
         return;
     }
 
-    let Some(players) = players else { return };
-    let Some(placed_tiles) = placed_tiles else { return };
-
-    // Get click/tap position
-    let click_pos = if mouse_input.just_pressed(MouseButton::Left) {
-        windows.single().ok().and_then(|w| w.cursor_position())
-    } else if let Some(touch) = touches.iter_just_pressed().next() {
-        Some(touch.position())
-    } else {
-        None
-    };
-
-    let Some(screen_pos) = click_pos else { return };
-    let Ok((camera, camera_transform)) = camera_query.single() else { return };
-
-    // Convert screen position to world position
-    let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, screen_pos) else { return };
+    let Some(world_pos) = pointer.just_pressed() else { return };
 
     // Check if click is on any tile sprite
     let tile_size = Vec2::new(35.0, 18.0);
     let half_size = tile_size * 0.5;
-    let current = players.current_player();
 
     for (transform, tile_sprite, sprite) in tile_sprites.iter() {
         // Skip invisible tiles (alpha near 0)
@@ -760,18 +1171,8 @@ pub fn handle_spectator_tile_clicks(
 
         let space = tile_sprite.space_index;
 
-        // Skip space 0 (can't place there)
-        if space == 0 {
-            continue;
-        }
-
         // Check if this is a valid space (including crazy camels)
-        let has_camel = camels.iter().any(|pos| pos.space_index == space);
-        let has_crazy_camel = crazy_camels.iter().any(|pos| pos.space_index == space);
-        let has_other_tile = placed_tiles.tiles.iter()
-            .any(|(&s, &(owner, _))| s == space && owner != current.id);
-
-        if has_camel || has_crazy_camel || has_other_tile {
+        if !placement.allows(space) {
             continue; // Can't place on invalid spaces
         }
 
@@ -786,6 +1187,7 @@ pub fn handle_spectator_tile_clicks(
             action.write(PlaceSpectatorTileAction {
                 space_index: space,
                 is_oasis: ui_state.spectator_tile_is_oasis,
+                from_host: false,
             });
 
             // Deselect the tile card
@@ -795,53 +1197,129 @@ pub fn handle_spectator_tile_clicks(
     }
 }
 
-/// System to handle clicks/taps on the pyramid roll button sprite
-pub fn handle_pyramid_click(
+/// System to open the stack popup for a board space when it's clicked or tapped.
+/// Clicking the same space again, or empty board, closes it.
+pub fn handle_board_space_clicks(
     windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
-    pyramid_query: Query<&GlobalTransform, With<PyramidRollButton>>,
-    start_button_query: Query<&GlobalTransform, With<crate::components::board::StartGameButton>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<crate::GameCamera>>,
+    spaces: Query<(&GlobalTransform, &BoardSpace, &Sprite)>,
     mouse_input: Res<ButtonInput<MouseButton>>,
     touches: Res<Touches>,
+    egui_settings: Query<&bevy_egui::EguiContextSettings>,
+    mut ui_state: ResMut<UiState>,
+) {
+    // Clicks during setup and tile placement mean something else
+    if !ui_state.initial_rolls_complete || ui_state.spectator_tile_selected {
+        return;
+    }
+
+    // Taps count when the finger lifts, so pinching and panning the board don't open popups
+    let click_pos = if mouse_input.just_pressed(MouseButton::Left) {
+        windows.single().ok().and_then(|w| w.cursor_position())
+    } else {
+        touches
+            .iter_just_released()
+            .find(|touch| touch.distance().length() <= crate::systems::camera_control::TAP_SLOP)
+            .map(|touch| touch.position())
+    };
+    let Some(screen_pos) = click_pos else { return };
+
+    // Ignore clicks on the panels around the board (window pixels vs egui points)
+    let egui_scale = egui_settings.iter().next().map_or(1.0, |settings| settings.scale_factor);
+    let on_board = ui_state.game_board_rect.is_some_and(|rect| {
+        rect.contains(bevy_egui::egui::pos2(screen_pos.x / egui_scale, screen_pos.y / egui_scale))
+    });
+    if !on_board {
+        return;
+    }
+
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, screen_pos) else { return };
+
+    let clicked = spaces.iter().find_map(|(transform, space, sprite)| {
+        let half_size = sprite.custom_size.unwrap_or(Vec2::new(70.0, 50.0)) * 0.5;
+        let offset = (world_pos - transform.translation().truncate()).abs();
+        (offset.x <= half_size.x && offset.y <= half_size.y).then_some(space.index)
+    });
+
+    ui_state.inspected_space = match clicked {
+        Some(space) if ui_state.inspected_space != Some(space) => Some(space),
+        _ => None,
+    };
+}
+
+/// What decides whether the player at this screen can roll the pyramid once the race
+/// is under way
+#[derive(SystemParam)]
+pub struct PyramidRollReadiness<'w, 's> {
+    players: Option<Res<'w, Players>>,
+    turn_state: Res<'w, TurnState>,
+    pyramid: Res<'w, Pyramid>,
+    shake_query: Query<'w, 's, (), With<PyramidShakeAnimation>>,
+}
+
+impl PyramidRollReadiness<'_, '_> {
+    /// Whether the pyramid can be rolled: no modal open and no roll still shaking, a
+    /// human's turn with their action still to take, and dice left in the pyramid
+    fn can_roll(&self, ui_state: &UiState) -> bool {
+        ui_state.initial_rolls_complete
+            && !ui_state.show_leg_scoring
+            && !ui_state.show_winner_betting
+            && !ui_state.show_loser_betting
+            && !self.is_shaking()
+            && self.players.as_ref().is_some_and(|p| !p.current_player().is_ai)
+            && !self.turn_state.action_taken
+            && !self.pyramid.all_dice_rolled()
+    }
+
+    fn is_shaking(&self) -> bool {
+        !self.shake_query.is_empty()
+    }
+}
+
+/// The setup rolls the pyramid is clicked through before the race, and the Start Game
+/// button that ends them
+#[derive(SystemParam)]
+pub struct SetupClicks<'w, 's> {
+    initial_rolls: Option<ResMut<'w, crate::systems::setup::InitialSetupRolls>>,
+    dice_query: Query<'w, 's, (), With<crate::systems::animation::DiceSprite>>,
+    start_button_query: Query<'w, 's, &'static GlobalTransform, With<crate::components::board::StartGameButton>>,
+}
+
+/// System to handle clicks/taps on the pyramid roll button sprite
+pub fn handle_pyramid_click(
+    pointer: BoardPointer,
+    pyramid_query: Query<&GlobalTransform, With<PyramidRollButton>>,
     mut ui_state: ResMut<UiState>,
     mut roll_action: MessageWriter<RollPyramidAction>,
-    players: Option<Res<Players>>,
-    turn_state: Res<TurnState>,
-    pyramid: Res<Pyramid>,
-    shake_query: Query<(), With<PyramidShakeAnimation>>,
-    mut initial_rolls: Option<ResMut<crate::systems::setup::InitialSetupRolls>>,
-    dice_query: Query<(), With<crate::systems::animation::DiceSprite>>,
-    network_state: Res<crate::network::state::NetworkState>,
+    readiness: PyramidRollReadiness,
+    mut setup: SetupClicks,
+    network: (Res<NetworkState>, ResMut<OutgoingNetworkActions>),
 ) {
+    let (network_state, mut outgoing_actions) = network;
+
     // === SETUP PHASE: Handle clicks during initial setup ===
     if !ui_state.initial_rolls_complete {
-        // Get click/tap position
-        let click_pos = if mouse_input.just_pressed(MouseButton::Left) {
-            windows.single().ok().and_then(|w| w.cursor_position())
-        } else if let Some(touch) = touches.iter_just_pressed().next() {
-            Some(touch.position())
-        } else {
-            None
-        };
-
-        let Some(screen_pos) = click_pos else { return };
-        let Ok((camera, camera_transform)) = camera_query.single() else { return };
+        // Online, only the host clicks through setup. The starting positions come from
+        // the shared game seed, so clients play the rolls out on their own and wait
+        // for the host to start the race. Replays do the same with the recorded seed.
+        if network_state.follows_log() {
+            if let Some(ref mut rolls) = setup.initial_rolls {
+                if rolls.waiting_for_click && setup.dice_query.is_empty() && !readiness.is_shaking() {
+                    rolls.waiting_for_click = false;
+                }
+            }
+            return;
+        }
 
-        // Convert screen position to world position
-        let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, screen_pos) else { return };
+        let Some(world_pos) = pointer.just_pressed() else { return };
 
         // If camel rolls are complete, check for Start Game button click
         if ui_state.camel_rolls_complete {
-            // In online mode, only the host can click Start Game
-            // (Non-host players already started the game from the lobby)
-            if network_state.is_online() && !network_state.is_host() {
-                return;
-            }
-
             // Check if click is on Start Game button (use a reasonable hit area)
             let button_half_size = Vec2::new(80.0, 20.0);
 
-            for transform in start_button_query.iter() {
+            for transform in setup.start_button_query.iter() {
                 let button_pos = transform.translation().truncate();
                 let min = button_pos - button_half_size;
                 let max = button_pos + button_half_size;
@@ -851,6 +1329,7 @@ pub fn handle_pyramid_click(
                 {
                     // Clicked on Start Game button - start the game!
                     ui_state.initial_rolls_complete = true;
+                    log_for_clients(&network_state, &mut outgoing_actions, GameAction::StartRace);
                     info!("Start Game clicked! Beginning gameplay.");
                     return;
                 }
@@ -859,20 +1338,14 @@ pub fn handle_pyramid_click(
         }
 
         // Camel rolls not complete - handle pyramid clicks for rolling
-        if let Some(ref mut rolls) = initial_rolls {
-            // In online mode, only the host can do init rolls
-            if network_state.is_online() && !network_state.is_host() {
-                // Non-host players can't do init rolls - they'll receive state from host
-                return;
-            }
-
+        if let Some(ref mut rolls) = setup.initial_rolls {
             // Don't allow click if dice is currently rolling
-            if !dice_query.is_empty() {
+            if !setup.dice_query.is_empty() {
                 return;
             }
 
             // Don't allow click if already shaking
-            if !shake_query.is_empty() {
+            if readiness.is_shaking() {
                 return;
             }
 
@@ -899,53 +1372,12 @@ pub fn handle_pyramid_click(
 
     // === NORMAL GAMEPLAY: Handle pyramid clicks after setup ===
 
-    // Don't process if showing leg scoring modal
-    if ui_state.show_leg_scoring {
-        return;
-    }
-
-    // Don't process if showing winner/loser betting modal
-    if ui_state.show_winner_betting || ui_state.show_loser_betting {
-        return;
-    }
-
-    // Don't process if already shaking
-    if !shake_query.is_empty() {
-        return;
-    }
-
-    let Some(players) = players else { return };
-    let current = players.current_player();
-
-    // Only allow human players to click
-    if current.is_ai {
-        return;
-    }
-
-    // Don't allow action if turn already taken
-    if turn_state.action_taken {
-        return;
-    }
-
-    // Check if all dice have been rolled (can't roll pyramid if leg is complete)
-    if pyramid.all_dice_rolled() {
+    // No rolling behind a modal, mid-shake, on the AI's turn or with the leg's dice gone
+    if !readiness.can_roll(&ui_state) {
         return;
     }
 
-    // Get click/tap position
-    let click_pos = if mouse_input.just_pressed(MouseButton::Left) {
-        windows.single().ok().and_then(|w| w.cursor_position())
-    } else if let Some(touch) = touches.iter_just_pressed().next() {
-        Some(touch.position())
-    } else {
-        None
-    };
-
-    let Some(screen_pos) = click_pos else { return };
-    let Ok((camera, camera_transform)) = camera_query.single() else { return };
-
-    // Convert screen position to world position
-    let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, screen_pos) else { return };
+    let Some(world_pos) = pointer.just_pressed() else { return };
 
     // Check if click is on pyramid
     let pyramid_size = Vec2::splat(PYRAMID_SIZE);
@@ -961,7 +1393,7 @@ pub fn handle_pyramid_click(
         {
             // Clicked on pyramid - trigger roll!
             // Shake animation is triggered in handle_pyramid_roll_action
-            roll_action.write(RollPyramidAction);
+            roll_action.write(RollPyramidAction { result: None });
             return;
         }
     }
@@ -969,20 +1401,15 @@ pub fn handle_pyramid_click(
 
 /// System to detect hover over the pyramid roll button
 pub fn handle_pyramid_hover(
-    windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    pointer: BoardPointer,
     pyramid_query: Query<(Entity, &GlobalTransform, Option<&PyramidHovered>), With<PyramidRollButton>>,
     mut commands: Commands,
     ui_state: Res<UiState>,
-    players: Option<Res<Players>>,
-    turn_state: Res<TurnState>,
-    pyramid: Res<Pyramid>,
-    shake_query: Query<(), With<PyramidShakeAnimation>>,
+    readiness: PyramidRollReadiness,
     network_state: Res<crate::network::state::NetworkState>,
 ) {
     // Get cursor position
-    let cursor_pos = windows.single().ok().and_then(|w| w.cursor_position());
-    let Some(screen_pos) = cursor_pos else {
+    let Some(world_pos) = pointer.hovered() else {
         // No cursor - remove hover from all pyramids
         for (entity, _, hovered) in pyramid_query.iter() {
             if hovered.is_some() {
@@ -992,9 +1419,6 @@ pub fn handle_pyramid_hover(
         return;
     };
 
-    let Ok((camera, camera_transform)) = camera_query.single() else { return };
-    let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, screen_pos) else { return };
-
     // Check if pyramid is interactive (can be clicked)
     // In online mode, non-host players should never see hover during setup phase
     let is_non_host_online = network_state.is_online() && !network_state.is_host();
@@ -1015,14 +1439,7 @@ pub fn handle_pyramid_hover(
         && !ui_state.camel_rolls_complete  // Still doing init rolls
         && (!network_state.is_online() || network_state.is_host());  // Local mode or host
 
-    let can_interact_in_gameplay = ui_state.initial_rolls_complete
-        && !ui_state.show_leg_scoring
-        && !ui_state.show_winner_betting
-        && !ui_state.show_loser_betting
-        && shake_query.is_empty()
-        && players.as_ref().map_or(false, |p| !p.current_player().is_ai)
-        && !turn_state.action_taken
-        && !pyramid.all_dice_rolled();
+    let can_interact_in_gameplay = readiness.can_roll(&ui_state);
 
     let is_interactive = can_interact_in_setup || can_interact_in_gameplay;
 
@@ -0,0 +1,72 @@
+// "Your turn" alert
+// Online players often switch to another tab while the others take their turns. When the
+// turn comes round to the local player, a chime plays and, in the browser, the tab title
+// flashes until they come back to it.
+
+use bevy::prelude::*;
+
+use crate::components::Players;
+use crate::game::settings::Settings;
+use crate::network::state::NetworkState;
+use crate::systems::sound_effects::{play_sound_effect, SoundEffect, SoundEffectAudio};
+
+/// What the tab title flashes to
+const TITLE_MESSAGE: &str = "Your turn! - Camel Up";
+
+/// Flash the browser tab title while the tab is in the background
+#[cfg(target_arch = "wasm32")]
+fn flash_title(message: &str) {
+    crate::network::js_bindings::flash_title(message);
+}
+
+#[cfg(target_arch = "wasm32")]
+fn stop_title_flash() {
+    crate::network::js_bindings::stop_title_flash();
+}
+
+/// Native windows have no tab to flash; the chime does the job
+#[cfg(not(target_arch = "wasm32"))]
+fn flash_title(_message: &str) {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn stop_title_flash() {}
+
+/// Whose turn it was last time we looked
+#[derive(Resource, Default)]
+pub struct TurnAlert {
+    last_player: Option<usize>,
+}
+
+/// System to alert the local player when the turn passes to them in an online game
+pub fn alert_local_turn(
+    mut commands: Commands,
+    players: Option<Res<Players>>,
+    network_state: Res<NetworkState>,
+    settings: Res<Settings>,
+    mut audio: Option<ResMut<Assets<SoundEffectAudio>>>,
+    mut alert: ResMut<TurnAlert>,
+) {
+    let Some(players) = players else { return };
+    if !network_state.is_online() {
+        return;
+    }
+    let current = Some(players.current_player_index);
+    if alert.last_player == current {
+        return;
+    }
+    alert.last_player = current;
+
+    if current == network_state.local_player_index {
+        play_sound_effect(&mut commands, audio.as_deref_mut(), &settings, SoundEffect::YourTurn);
+        flash_title(TITLE_MESSAGE);
+    } else {
+        stop_title_flash();
+    }
+}
+
+/// System to forget the last turn seen and stop any flashing when a game is left, so the
+/// next game alerts from its first turn
+pub fn reset_turn_alert(mut alert: ResMut<TurnAlert>) {
+    alert.last_player = None;
+    stop_title_flash();
+}
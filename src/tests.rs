@@ -0,0 +1,420 @@
+// Game tests
+// Local games played in a Bevy app without a window, through the real game plugins: each
+// test sends the same action messages the HUD and the AI send, lets the table resolve
+// them frame by frame, and checks the players, the pyramid and the camels afterwards.
+
+use std::collections::HashMap;
+use std::sync::Once;
+use std::time::Duration;
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::components::{
+    BoardPosition, Camel, CamelColor, CrazyCamel, DieRollResult, LegBettingTiles, PlayerLegBetsStore, PlayerPyramidTokens,
+    Players, Pyramid, RaceBets, TurnState,
+};
+use crate::game::core::{PYRAMID_TOKEN_PAYOUT, RACE_BET_PAYOUTS};
+use crate::game::snapshot::GameSnapshot;
+use crate::game::state::GameState;
+use crate::game::storage;
+use crate::network::NetworkPlugin;
+use crate::plugins::{AnimationPlugin, CameraPlugin, GameLogicPlugin};
+use crate::systems::leg::StartNextLegAction;
+use crate::systems::leg_recap::LegRecap;
+use crate::systems::setup::InitialSetupRolls;
+use crate::systems::turn::{PlaceRaceBetAction, RollPyramidAction, TakeLegBetAction};
+use crate::ui::hud::UiState;
+use crate::ui::player_setup::PlayerSetupConfig;
+
+/// Frames a test waits for the table to get somewhere before failing
+const MAX_FRAMES: usize = 2000;
+
+/// Time each frame moves the game on
+const FRAME_TIME: Duration = Duration::from_millis(50);
+
+/// A local game between four people at one screen, from a fixed seed, past the setup
+/// rolls and waiting on the first player
+fn new_game() -> App {
+    new_game_from_seed("1")
+}
+
+/// A local game like `new_game`'s, from `seed`
+fn new_game_from_seed(seed: &str) -> App {
+    // Settings, the autosave and the stats are kept in a scratch directory, not the home
+    // of whoever runs the tests
+    static SCRATCH_DIR: Once = Once::new();
+    SCRATCH_DIR.call_once(|| {
+        let dir = std::env::temp_dir().join("camel-up-tests");
+        let _ = std::fs::create_dir_all(&dir);
+        storage::set_dir(dir);
+    });
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), StatesPlugin, InputPlugin))
+        .init_asset::<Image>()
+        .init_asset::<Mesh>()
+        .init_asset::<ColorMaterial>()
+        .init_asset::<TextureAtlasLayout>()
+        .init_asset::<Font>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME_TIME))
+        .add_plugins((NetworkPlugin, GameLogicPlugin, AnimationPlugin, CameraPlugin));
+    app.finish();
+    app.cleanup();
+
+    let mut config = PlayerSetupConfig {
+        randomize_start_order: false,
+        seed: seed.to_string(),
+        ..default()
+    };
+    for player in &mut config.players {
+        player.is_ai = false;
+    }
+    app.insert_resource(config);
+    app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Playing);
+
+    // Click through the setup rolls and the Start Game button, as the HUD would
+    run_until(&mut app, "the setup rolls finish", |world| {
+        if let Some(mut rolls) = world.get_resource_mut::<InitialSetupRolls>() {
+            rolls.waiting_for_click = false;
+        }
+        let mut ui_state = world.resource_mut::<UiState>();
+        if ui_state.camel_rolls_complete {
+            ui_state.initial_rolls_complete = true;
+        }
+        ui_state.initial_rolls_complete
+    });
+    app
+}
+
+/// Update the app until `done`, failing the test if it takes more than `MAX_FRAMES`
+fn run_until(app: &mut App, what: &str, mut done: impl FnMut(&mut World) -> bool) {
+    for _ in 0..MAX_FRAMES {
+        app.update();
+        if done(app.world_mut()) {
+            return;
+        }
+    }
+    panic!("Waited {} frames for {}", MAX_FRAMES, what);
+}
+
+fn current_player(app: &App) -> usize {
+    app.world().resource::<Players>().current_player_index
+}
+
+/// Every racing camel's (space, stack position)
+fn camel_positions(app: &mut App) -> Vec<(CamelColor, u8, u8)> {
+    let mut camels = app.world_mut().query::<(&Camel, &BoardPosition)>();
+    let mut positions: Vec<_> = camels
+        .iter(app.world())
+        .map(|(camel, pos)| (camel.color, pos.space_index, pos.stack_position))
+        .collect();
+    positions.sort_by_key(|&(color, _, _)| color as usize);
+    positions
+}
+
+fn position_of(positions: &[(CamelColor, u8, u8)], color: CamelColor) -> (u8, u8) {
+    positions
+        .iter()
+        .find(|(camel, _, _)| *camel == color)
+        .map(|&(_, space, stack)| (space, stack))
+        .expect("every racing camel is on the board")
+}
+
+#[test]
+fn taking_a_leg_bet_hands_the_player_the_top_tile() {
+    let mut app = new_game();
+    let player = current_player(&app);
+
+    app.world_mut().write_message(TakeLegBetAction { color: CamelColor::Blue, from_host: false });
+    run_until(&mut app, "the turn to pass", |world| {
+        world.resource::<Players>().current_player_index != player
+    });
+
+    let world = app.world();
+    let bets = &world.resource::<PlayerLegBetsStore>().bets[player];
+    assert_eq!(bets.len(), 1);
+    assert_eq!((bets[0].camel, bets[0].value), (CamelColor::Blue, 5));
+    let top_tile = world.resource::<LegBettingTiles>().top_tile(CamelColor::Blue).map(|tile| tile.value);
+    assert_eq!(top_tile, Some(3));
+    assert_eq!(current_player(&app), (player + 1) % 4);
+}
+
+#[test]
+fn rolling_the_pyramid_moves_the_rolled_camel_and_everything_on_it() {
+    let mut app = new_game();
+    let player = current_player(&app);
+    let before = camel_positions(&mut app);
+    let rolled = CamelColor::Green;
+    let (start, start_stack) = position_of(&before, rolled);
+
+    let result = DieRollResult::Regular { color: rolled, value: 2 };
+    app.world_mut().write_message(RollPyramidAction { result: Some(result) });
+    run_until(&mut app, "the rolled camel to move", |world| {
+        let mut camels = world.query::<(&Camel, &BoardPosition)>();
+        camels
+            .iter(world)
+            .any(|(camel, pos)| camel.color == rolled && pos.space_index == start + 2)
+    });
+
+    // The riders keep their order on top of whatever was already there
+    let after = camel_positions(&mut app);
+    let mut riders: Vec<_> = before
+        .iter()
+        .filter(|&&(_, space, stack)| space == start && stack >= start_stack)
+        .collect();
+    riders.sort_by_key(|&&(_, _, stack)| stack);
+    let landed: Vec<u8> = riders
+        .iter()
+        .map(|&&(color, _, _)| {
+            let (space, stack) = position_of(&after, color);
+            assert_eq!(space, start + 2, "{:?} rode along", color);
+            stack
+        })
+        .collect();
+    assert!(landed.windows(2).all(|pair| pair[1] == pair[0] + 1), "stack order kept: {:?}", landed);
+    for &(color, space, stack) in &before {
+        if !riders.iter().any(|&&(rider, _, _)| rider == color) {
+            assert_eq!(position_of(&after, color), (space, stack), "{:?} stayed put", color);
+        }
+    }
+
+    let world = app.world();
+    assert!(!world.resource::<Pyramid>().has_die(rolled));
+    assert_eq!(world.resource::<PlayerPyramidTokens>().counts[player], 1);
+}
+
+#[test]
+fn a_player_gets_one_action_per_turn() {
+    let mut app = new_game();
+    let player = current_player(&app);
+    let player_id = app.world().resource::<Players>().current_player().id;
+
+    app.world_mut().write_message(PlaceRaceBetAction {
+        color: CamelColor::Red,
+        is_winner_bet: true,
+        from_host: false,
+    });
+    run_until(&mut app, "the race bet", |world| world.resource::<TurnState>().action_taken);
+
+    // A leg bet in the same turn is turned down
+    app.world_mut().write_message(TakeLegBetAction { color: CamelColor::Blue, from_host: false });
+    run_until(&mut app, "the turn to pass", |world| {
+        world.resource::<Players>().current_player_index != player
+    });
+
+    let world = app.world();
+    let race_bets = &world.resource::<RaceBets>().winner_bets;
+    assert_eq!(race_bets.len(), 1);
+    assert_eq!((race_bets[0].camel, race_bets[0].player_id), (CamelColor::Red, player_id));
+    let players = world.resource::<Players>();
+    assert!(!players.players[player].available_race_cards.contains(&CamelColor::Red));
+    assert!(world.resource::<PlayerLegBetsStore>().bets[player].is_empty());
+    assert_eq!(world.resource::<LegBettingTiles>().top_tile(CamelColor::Blue).map(|tile| tile.value), Some(5));
+}
+
+/// The snapshot as JSON, with each player's race cards sorted since they are kept in a set
+fn snapshot_value(snapshot: &GameSnapshot) -> serde_json::Value {
+    let mut value = serde_json::to_value(snapshot).unwrap();
+    for player in value["players"]["players"].as_array_mut().unwrap() {
+        let cards = player["available_race_cards"].as_array_mut().unwrap();
+        cards.sort_by_key(|card| card.to_string());
+    }
+    value
+}
+
+#[test]
+fn a_snapshot_puts_the_table_back_as_it_was() {
+    let mut app = new_game();
+    let player = current_player(&app);
+    let before = GameSnapshot::capture(app.world_mut()).expect("a game is set up");
+    let json = serde_json::to_string(&before).unwrap();
+
+    let result = DieRollResult::Regular { color: CamelColor::Green, value: 2 };
+    app.world_mut().write_message(RollPyramidAction { result: Some(result) });
+    run_until(&mut app, "the turn to pass", |world| {
+        world.resource::<Players>().current_player_index != player
+    });
+    let rolled = GameSnapshot::capture(app.world_mut()).expect("the game is still set up");
+    assert_ne!(snapshot_value(&rolled), snapshot_value(&before));
+
+    // Back from the saved JSON, as a save or an undo would
+    let saved: GameSnapshot = serde_json::from_str(&json).unwrap();
+    saved.restore(app.world_mut());
+    let after = GameSnapshot::capture(app.world_mut()).expect("the game is still set up");
+    assert_eq!(snapshot_value(&after), snapshot_value(&before));
+    assert_eq!(current_player(&app), player);
+}
+
+/// Games `whole_games_keep_the_books_and_the_stacks_straight` plays, one per seed
+const WHOLE_GAMES: u64 = 200;
+
+/// Frames a whole game may take before the test gives up on it
+const MAX_GAME_FRAMES: usize = 20_000;
+
+/// Time each frame of a whole game moves it on: longer frames than the single-action
+/// tests', so the animations between turns take a few frames each
+const WHOLE_GAME_FRAME_TIME: Duration = Duration::from_millis(250);
+
+fn money(app: &App) -> Vec<i32> {
+    app.world().resource::<Players>().players.iter().map(|player| player.money).collect()
+}
+
+/// Add a payout to a purse the way the rule book says: losses stop at $0
+fn pay(purse: &mut i32, payout: i32) {
+    *purse = (*purse + payout).max(0);
+}
+
+/// The racing camels from first to last: furthest along, then highest in the stack
+fn race_order(app: &mut App) -> Vec<CamelColor> {
+    let mut camels = camel_positions(app);
+    camels.sort_by_key(|&(_, space, stack)| std::cmp::Reverse((space, stack)));
+    camels.into_iter().map(|(color, _, _)| color).collect()
+}
+
+/// Every stack on the board, racing and crazy camels together, is numbered from the
+/// bottom up without gaps or doubles
+fn assert_stacks_in_bounds(app: &mut App, seed: u64) {
+    let mut pieces = app.world_mut().query_filtered::<&BoardPosition, Or<(With<Camel>, With<CrazyCamel>)>>();
+    let mut stacks: HashMap<u8, Vec<u8>> = HashMap::new();
+    for pos in pieces.iter(app.world()) {
+        stacks.entry(pos.space_index).or_default().push(pos.stack_position);
+    }
+    for (space, mut stack) in stacks {
+        stack.sort_unstable();
+        assert!(
+            stack.iter().copied().eq(0..stack.len() as u8),
+            "seed {}: the stack on space {} is numbered {:?}",
+            seed,
+            space,
+            stack
+        );
+    }
+}
+
+/// The current player can act: no action taken yet, dice left to roll, and no leg or
+/// race being wrapped up
+fn waiting_on_a_player(world: &World) -> bool {
+    let ui_state = world.resource::<UiState>();
+    !world.resource::<TurnState>().action_taken
+        && !world.resource::<Pyramid>().all_dice_rolled()
+        && !ui_state.show_leg_scoring
+        && ui_state.leg_scoring_delay <= 0.0
+        && ui_state.game_end_delay <= 0.0
+        && world.resource::<LegRecap>().is_idle()
+}
+
+/// Play the game from `seed` to the finish with scripted turns: mostly rolls of the
+/// pyramid, with leg bets and race bets on random camels in between. The players' money
+/// is kept by the rule book alongside the table and checked after every turn, every leg
+/// and the race, and the stacks after every turn.
+fn play_whole_game(seed: u64) {
+    let mut app = new_game_from_seed(&seed.to_string());
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(WHOLE_GAME_FRAME_TIME));
+    let mut script = StdRng::seed_from_u64(seed);
+    let mut books = money(&app);
+    let mut legs = 0;
+
+    for _ in 0..MAX_GAME_FRAMES {
+        app.update();
+
+        if *app.world().resource::<State<GameState>>().get() == GameState::GameEnd {
+            // The race bets are paid on the way in; the last leg's bets are left to the
+            // results screen
+            let order = race_order(&mut app);
+            let world = app.world();
+            let race_bets = world.resource::<RaceBets>();
+            let players = &world.resource::<Players>().players;
+            for (bets, result) in [(&race_bets.winner_bets, order.first()), (&race_bets.loser_bets, order.last())] {
+                let mut correct = 0;
+                for bet in bets {
+                    let payout = if Some(&bet.camel) == result {
+                        correct += 1;
+                        RACE_BET_PAYOUTS.get(correct - 1).copied().unwrap_or(1)
+                    } else {
+                        -1
+                    };
+                    let seat = players.iter().position(|player| player.id == bet.player_id).unwrap();
+                    pay(&mut books[seat], payout);
+                }
+            }
+            assert_eq!(money(&app), books, "seed {}: the race bets were paid", seed);
+            assert!(legs > 0, "seed {}: the race finished in the first leg", seed);
+            return;
+        }
+
+        if app.world().resource::<UiState>().show_leg_scoring {
+            // Leg tiles pay their value on the leader, $1 on the runner-up and cost $1
+            // on anything else
+            let order = race_order(&mut app);
+            for (purse, tiles) in books.iter_mut().zip(&app.world().resource::<PlayerLegBetsStore>().bets) {
+                for tile in tiles {
+                    let payout = if tile.camel == order[0] {
+                        tile.value as i32
+                    } else if tile.camel == order[1] {
+                        1
+                    } else {
+                        -1
+                    };
+                    pay(purse, payout);
+                }
+            }
+            app.world_mut().write_message(StartNextLegAction);
+            run_until(&mut app, "the next leg", |world| !world.resource::<UiState>().show_leg_scoring);
+            legs += 1;
+
+            let world = app.world();
+            assert_eq!(money(&app), books, "seed {}: leg {} was paid", seed, legs);
+            assert!(world.resource::<PlayerLegBetsStore>().bets.iter().all(Vec::is_empty));
+            assert!(!world.resource::<Pyramid>().all_dice_rolled());
+            continue;
+        }
+
+        if !waiting_on_a_player(app.world()) {
+            continue;
+        }
+        assert_stacks_in_bounds(&mut app, seed);
+        assert_eq!(money(&app), books, "seed {}: money after a turn", seed);
+
+        let player = current_player(&app);
+        let color = CamelColor::all()[script.gen_range(0..5)];
+        let world = app.world();
+        let tile_left = world.resource::<LegBettingTiles>().top_tile(color).is_some();
+        let card_left = world.resource::<Players>().players[player].available_race_cards.contains(&color);
+        let rolled = match script.gen_range(0..10) {
+            0 | 1 if tile_left => {
+                app.world_mut().write_message(TakeLegBetAction { color, from_host: false });
+                false
+            }
+            2 if card_left => {
+                let is_winner_bet = script.gen();
+                app.world_mut().write_message(PlaceRaceBetAction { color, is_winner_bet, from_host: false });
+                false
+            }
+            _ => {
+                app.world_mut().write_message(RollPyramidAction { result: None });
+                true
+            }
+        };
+        // A camel still on its way to the finish can end the race before the action is taken
+        run_until(&mut app, "the action to be taken", |world| {
+            world.resource::<TurnState>().action_taken
+                || *world.resource::<State<GameState>>().get() != GameState::Playing
+        });
+        if rolled && app.world().resource::<TurnState>().action_taken {
+            books[player] += PYRAMID_TOKEN_PAYOUT;
+        }
+    }
+    panic!("seed {}: the race didn't finish in {} frames", seed, MAX_GAME_FRAMES);
+}
+
+#[test]
+fn whole_games_keep_the_books_and_the_stacks_straight() {
+    for seed in 0..WHOLE_GAMES {
+        play_whole_game(seed);
+    }
+}
@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::systems::achievements::{Achievement, Achievements, TOAST_SECS};
+use crate::ui::theme::{desert_button, quality_shadow, DesertButtonStyle};
+
+const MODAL_BG: egui::Color32 = egui::Color32::from_rgb(30, 25, 20);
+const GOLD: egui::Color32 = egui::Color32::from_rgb(0xFF, 0xD7, 0x00);
+
+/// Whether the main menu's trophy gallery is open
+#[derive(Resource, Default)]
+pub struct TrophyGalleryState {
+    pub is_open: bool,
+}
+
+/// Toasts announcing achievements as they're unlocked, stacked under the top panel
+pub fn achievement_toast_ui(mut contexts: EguiContexts, achievements: Res<Achievements>, time: Res<Time>) {
+    if achievements.toasts.is_empty() {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let now = time.elapsed_secs_f64();
+
+    for (i, toast) in achievements.toasts.iter().enumerate() {
+        // Fade out over the last half second
+        let left = (TOAST_SECS - (now - toast.shown_at)) as f32;
+        let alpha = (left / 0.5).clamp(0.0, 1.0);
+        let alpha_u8 = (alpha * 255.0) as u8;
+
+        egui::Area::new(egui::Id::new(("achievement_toast", i)))
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 90.0 + i as f32 * 60.0))
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::new()
+                    .fill(egui::Color32::from_rgba_unmultiplied(40, 40, 50, (alpha * 240.0) as u8))
+                    .stroke(egui::Stroke::new(2.0, GOLD.gamma_multiply(alpha)))
+                    .corner_radius(egui::CornerRadius::same(8))
+                    .inner_margin(egui::Margin::symmetric(14, 8))
+                    .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
+                        offset: [0, 4],
+                        blur: 8,
+                        spread: 0,
+                        color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, (alpha * 100.0) as u8),
+                    }))
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new("🏆").size(22.0).color(GOLD.gamma_multiply(alpha)));
+                            ui.vertical(|ui| {
+                                ui.label(
+                                    egui::RichText::new(format!("{} unlocked {}", toast.player, toast.achievement.name()))
+                                        .size(15.0)
+                                        .strong()
+                                        .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, alpha_u8)),
+                                );
+                                ui.label(
+                                    egui::RichText::new(toast.achievement.description())
+                                        .size(12.0)
+                                        .color(egui::Color32::from_rgba_unmultiplied(200, 200, 200, alpha_u8)),
+                                );
+                            });
+                        });
+                    });
+            });
+    }
+    ctx.request_repaint();
+}
+
+/// Draw the trophy gallery: every achievement, with who unlocked it
+pub fn draw_trophy_gallery_ui(
+    ctx: &egui::Context,
+    gallery: &mut TrophyGalleryState,
+    achievements: &Achievements,
+    is_mobile: bool,
+) {
+    if !gallery.is_open {
+        return;
+    }
+
+    // Dark overlay behind modal
+    egui::Area::new(egui::Id::new("trophy_overlay"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .order(egui::Order::Middle)
+        .show(ctx, |ui| {
+            let screen_rect = ctx.input(|i| i.viewport_rect());
+            ui.painter().rect_filled(screen_rect, 0.0, egui::Color32::from_rgba_unmultiplied(0, 0, 0, 180));
+        });
+
+    egui::Area::new(egui::Id::new("trophy_panel"))
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(MODAL_BG)
+                .corner_radius(egui::CornerRadius::same(16))
+                .inner_margin(egui::Margin::same(if is_mobile { 16 } else { 24 }))
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
+                    offset: [0, 8],
+                    blur: 24,
+                    spread: 4,
+                    color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
+                }))
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.heading(egui::RichText::new("Trophies").size(28.0).color(egui::Color32::WHITE));
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{} of {} unlocked",
+                                achievements.unlocked.len(),
+                                Achievement::ALL.len()
+                            ))
+                            .size(14.0)
+                            .color(egui::Color32::LIGHT_GRAY),
+                        );
+                    });
+                    ui.add_space(12.0);
+
+                    let max_height = ctx.input(|i| i.viewport_rect()).height() * 0.6;
+                    egui::ScrollArea::vertical().max_height(max_height).show(ui, |ui| {
+                        for achievement in Achievement::ALL {
+                            draw_trophy(ui, achievement, achievements.unlocked.get(&achievement).map(|u| u.player.as_str()));
+                        }
+                    });
+
+                    ui.add_space(12.0);
+                    ui.vertical_centered(|ui| {
+                        if desert_button(ui, "Close", &DesertButtonStyle::medium()).clicked() {
+                            gallery.is_open = false;
+                        }
+                    });
+                });
+        });
+}
+
+/// One achievement: gold with its unlocker once unlocked, greyed out until then
+fn draw_trophy(ui: &mut egui::Ui, achievement: Achievement, unlocked_by: Option<&str>) {
+    let (icon_color, name_color) = match unlocked_by {
+        Some(_) => (GOLD, egui::Color32::WHITE),
+        None => (egui::Color32::from_gray(80), egui::Color32::GRAY),
+    };
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("🏆").size(22.0).color(icon_color));
+        ui.vertical(|ui| {
+            ui.label(egui::RichText::new(achievement.name()).size(15.0).strong().color(name_color));
+            let detail = match unlocked_by {
+                Some(player) => format!("{} (unlocked by {})", achievement.description(), player),
+                None => achievement.description().to_string(),
+            };
+            ui.label(egui::RichText::new(detail).size(12.0).color(egui::Color32::LIGHT_GRAY));
+        });
+    });
+    ui.add_space(4.0);
+}
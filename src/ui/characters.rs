@@ -1,4 +1,5 @@
 use bevy_egui::egui::{self, Color32, Pos2, Rect, Stroke};
+use serde::{Deserialize, Serialize};
 
 // ============================================================================
 // Color manipulation helpers
@@ -119,51 +120,133 @@ fn draw_rect_eyes(painter: &egui::Painter, center: Pos2, radius: f32, iris_color
 }
 
 /// Unique character identifiers for player avatars
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum CharacterId {
     #[default]
-    Scholar = 0,
-    Merchant = 1,
-    Princess = 2,
-    Jockey = 3,
-    Pharaoh = 4,
-    Nomad = 5,
-    DesertExplorer = 6,
-    FortuneTeller = 7,
-    SnakeCharmer = 8,
-    Sultan = 9,
-    Priestess = 10,
-    Archaeologist = 11,
-    Vizier = 12,
-    Guard = 13,
-    Dancer = 14,
-    Pirate = 15,
+    Scholar,
+    Merchant,
+    Princess,
+    Jockey,
+    Pharaoh,
+    Nomad,
+    DesertExplorer,
+    FortuneTeller,
+    SnakeCharmer,
+    Sultan,
+    Priestess,
+    Archaeologist,
+    Vizier,
+    Guard,
+    Dancer,
+    Pirate,
+    // Characters built from the avatar editor's parts (see `spec`)
+    CamelDriver,
+    SpiceTrader,
+    Queen,
+    Scout,
+    Smuggler,
+    Chieftain,
+    Astronomer,
+    Soldier,
+    Storyteller,
+    Weaver,
+    CaravanMaster,
+    TombRobber,
+    Healer,
+    Gladiator,
+    Poet,
+    Falconer,
+    /// A look the player built in the avatar editor
+    Custom(AvatarSpec),
 }
 
 impl CharacterId {
-    /// Get character from index (0-15), wrapping if needed
+    /// Every character that can be picked, in index order
+    const NAMED: [Self; 32] = [
+        Self::Scholar,
+        Self::Merchant,
+        Self::Princess,
+        Self::Jockey,
+        Self::Pharaoh,
+        Self::Nomad,
+        Self::DesertExplorer,
+        Self::FortuneTeller,
+        Self::SnakeCharmer,
+        Self::Sultan,
+        Self::Priestess,
+        Self::Archaeologist,
+        Self::Vizier,
+        Self::Guard,
+        Self::Dancer,
+        Self::Pirate,
+        Self::CamelDriver,
+        Self::SpiceTrader,
+        Self::Queen,
+        Self::Scout,
+        Self::Smuggler,
+        Self::Chieftain,
+        Self::Astronomer,
+        Self::Soldier,
+        Self::Storyteller,
+        Self::Weaver,
+        Self::CaravanMaster,
+        Self::TombRobber,
+        Self::Healer,
+        Self::Gladiator,
+        Self::Poet,
+        Self::Falconer,
+    ];
+
+    /// How many characters there are to pick from (custom avatars aside)
+    pub const COUNT: usize = Self::NAMED.len();
+
+    /// Get character from index. Indices past the picked characters are custom avatars,
+    /// so every index that fits in a byte (as sent to online rooms) is a different look.
     pub fn from_index(index: usize) -> Self {
-        match index % 16 {
-            0 => Self::Scholar,
-            1 => Self::Merchant,
-            2 => Self::Princess,
-            3 => Self::Jockey,
-            4 => Self::Pharaoh,
-            5 => Self::Nomad,
-            6 => Self::DesertExplorer,
-            7 => Self::FortuneTeller,
-            8 => Self::SnakeCharmer,
-            9 => Self::Sultan,
-            10 => Self::Priestess,
-            11 => Self::Archaeologist,
-            12 => Self::Vizier,
-            13 => Self::Guard,
-            14 => Self::Dancer,
-            _ => Self::Pirate,
+        Self::NAMED
+            .get(index)
+            .copied()
+            .unwrap_or_else(|| Self::Custom(AvatarSpec::from_code(index - Self::COUNT)))
+    }
+
+    /// Index of the character, the inverse of `from_index`
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Custom(spec) => Self::COUNT + spec.code(),
+            named => Self::NAMED.iter().position(|c| c == named).unwrap_or(0),
         }
     }
 
+    /// The parts a character is built from, for those not drawn by hand
+    pub fn spec(&self) -> Option<AvatarSpec> {
+        use Accessory as A;
+        use Headwear as H;
+        use SkinTone as S;
+        let (skin, headwear, accessory) = match self {
+            Self::CamelDriver => (S::Tan, H::Headscarf, A::Mustache),
+            Self::SpiceTrader => (S::Medium, H::Fez, A::Mustache),
+            Self::Queen => (S::Light, H::Crown, A::Earrings),
+            Self::Scout => (S::Dark, H::SafariHat, A::Glasses),
+            Self::Smuggler => (S::Medium, H::Hood, A::Eyepatch),
+            Self::Chieftain => (S::Dark, H::Turban, A::Beard),
+            Self::Astronomer => (S::Light, H::Hood, A::Glasses),
+            Self::Soldier => (S::Tan, H::Helmet, A::Mustache),
+            Self::Storyteller => (S::Dark, H::Fez, A::Beard),
+            Self::Weaver => (S::Medium, H::Headscarf, A::Earrings),
+            Self::CaravanMaster => (S::Light, H::Turban, A::Mustache),
+            Self::TombRobber => (S::Tan, H::Hood, A::Veil),
+            Self::Healer => (S::Dark, H::Headscarf, A::None),
+            Self::Gladiator => (S::Medium, H::Helmet, A::Beard),
+            Self::Poet => (S::Tan, H::None, A::Glasses),
+            Self::Falconer => (S::Light, H::SafariHat, A::Beard),
+            Self::Custom(spec) => return Some(*spec),
+            _ => return None,
+        };
+        Some(AvatarSpec { skin, headwear, accessory })
+    }
+
     /// Get display name for the character
+    #[allow(dead_code)]
     pub fn name(&self) -> &'static str {
         match self {
             Self::DesertExplorer => "Explorer",
@@ -182,6 +265,23 @@ impl CharacterId {
             Self::Guard => "Guard",
             Self::Dancer => "Dancer",
             Self::Pirate => "Pirate",
+            Self::CamelDriver => "Camel Driver",
+            Self::SpiceTrader => "Spice Trader",
+            Self::Queen => "Queen",
+            Self::Scout => "Scout",
+            Self::Smuggler => "Smuggler",
+            Self::Chieftain => "Chieftain",
+            Self::Astronomer => "Astronomer",
+            Self::Soldier => "Soldier",
+            Self::Storyteller => "Storyteller",
+            Self::Weaver => "Weaver",
+            Self::CaravanMaster => "Caravan Master",
+            Self::TombRobber => "Tomb Robber",
+            Self::Healer => "Healer",
+            Self::Gladiator => "Gladiator",
+            Self::Poet => "Poet",
+            Self::Falconer => "Falconer",
+            Self::Custom(_) => "Traveler",
         }
     }
 
@@ -243,6 +343,57 @@ impl CharacterId {
             Self::Pirate => &[
                 "Corsair", "Captain", "Salt", "Reef", "Tide", "Cutlass", "Wave", "Buccaneer",
             ],
+            Self::CamelDriver => &[
+                "Hump", "Trek", "Reins", "Saddle", "Caravan", "Stride", "Dromedary", "Trail",
+            ],
+            Self::SpiceTrader => &[
+                "Saffron", "Cumin", "Pepper", "Cinnamon", "Clove", "Anise", "Sumac", "Nutmeg",
+            ],
+            Self::Queen => &[
+                "Hatshepsut", "Tiye", "Ahmose", "Regina", "Majesty", "Sovereign", "Empress", "Nitocris",
+            ],
+            Self::Scout => &[
+                "Hawkeye", "Ranger", "Tracker", "Lookout", "Pathfinder", "Skye", "Scope", "Trail",
+            ],
+            Self::Smuggler => &[
+                "Shadow", "Sly", "Whisper", "Contraband", "Dodger", "Smoke", "Alley", "Nimble",
+            ],
+            Self::Chieftain => &[
+                "Khalid", "Faisal", "Tribe", "Elder", "Zayed", "Chief", "Mansur", "Hamid",
+            ],
+            Self::Astronomer => &[
+                "Stella", "Orion", "Sirius", "Nova", "Comet", "Vega", "Zenith", "Nebula",
+            ],
+            Self::Soldier => &[
+                "Spear", "Valor", "Legion", "Marshal", "Ramses", "Brave", "Sergeant", "Rank",
+            ],
+            Self::Storyteller => &[
+                "Scheherazade", "Fable", "Tale", "Legend", "Yarn", "Saga", "Aesop", "Narrator",
+            ],
+            Self::Weaver => &[
+                "Loom", "Thread", "Tapestry", "Kilim", "Spindle", "Weft", "Indigo", "Silk",
+            ],
+            Self::CaravanMaster => &[
+                "Ibn", "Convoy", "Rahal", "Waypoint", "Oasis", "Marco", "Leader", "Route",
+            ],
+            Self::TombRobber => &[
+                "Sneak", "Plunder", "Loot", "Dagger", "Raider", "Cipher", "Vault", "Jackal",
+            ],
+            Self::Healer => &[
+                "Imhotep", "Balm", "Myrrh", "Aloe", "Remedy", "Mercy", "Tonic", "Salve",
+            ],
+            Self::Gladiator => &[
+                "Maximus", "Titan", "Arena", "Brute", "Champion", "Spartan", "Hammer", "Iron",
+            ],
+            Self::Poet => &[
+                "Rumi", "Verse", "Sonnet", "Rhyme", "Hafiz", "Lyric", "Ode", "Quill",
+            ],
+            Self::Falconer => &[
+                "Talon", "Falcon", "Kestrel", "Swoop", "Feather", "Perch", "Sky", "Hunter",
+            ],
+            Self::Custom(_) => &[
+                "Traveler", "Wayfarer", "Pilgrim", "Rover", "Voyager", "Drifter", "Stranger", "Journey",
+            ],
         }
     }
 
@@ -255,6 +406,179 @@ impl CharacterId {
     }
 }
 
+// ============================================================================
+// Avatar editor parts
+// ============================================================================
+
+/// Skin tone of an avatar built from parts
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum SkinTone {
+    Light,
+    #[default]
+    Tan,
+    Medium,
+    Dark,
+}
+
+impl SkinTone {
+    pub const ALL: [Self; 4] = [Self::Light, Self::Tan, Self::Medium, Self::Dark];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Light => "Light",
+            Self::Tan => "Tan",
+            Self::Medium => "Medium",
+            Self::Dark => "Dark",
+        }
+    }
+
+    /// The next skin tone, for the editor's tap-to-cycle buttons
+    pub fn next(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % Self::ALL.len()]
+    }
+
+    fn color(&self) -> Color32 {
+        match self {
+            Self::Light => SKIN_LIGHT,
+            Self::Tan => SKIN_TAN,
+            Self::Medium => SKIN_MEDIUM,
+            Self::Dark => SKIN_DARK,
+        }
+    }
+}
+
+/// What an avatar built from parts wears on its head
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Headwear {
+    None,
+    #[default]
+    Turban,
+    Fez,
+    SafariHat,
+    Crown,
+    Headscarf,
+    Helmet,
+    Hood,
+}
+
+impl Headwear {
+    pub const ALL: [Self; 8] = [
+        Self::None,
+        Self::Turban,
+        Self::Fez,
+        Self::SafariHat,
+        Self::Crown,
+        Self::Headscarf,
+        Self::Helmet,
+        Self::Hood,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Turban => "Turban",
+            Self::Fez => "Fez",
+            Self::SafariHat => "Safari hat",
+            Self::Crown => "Crown",
+            Self::Headscarf => "Headscarf",
+            Self::Helmet => "Helmet",
+            Self::Hood => "Hood",
+        }
+    }
+
+    /// The next headwear, for the editor's tap-to-cycle buttons
+    pub fn next(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % Self::ALL.len()]
+    }
+
+    fn color(&self) -> Color32 {
+        match self {
+            Self::None => Color32::TRANSPARENT,
+            Self::Turban => Color32::from_rgb(240, 235, 220), // White linen
+            Self::Fez => Color32::from_rgb(170, 30, 40), // Deep red
+            Self::SafariHat => Color32::from_rgb(160, 130, 80), // Khaki
+            Self::Crown => Color32::from_rgb(255, 215, 0), // Gold
+            Self::Headscarf => Color32::from_rgb(60, 110, 170), // Indigo
+            Self::Helmet => Color32::from_rgb(150, 150, 160), // Iron
+            Self::Hood => Color32::from_rgb(90, 60, 110), // Dusk purple
+        }
+    }
+}
+
+/// What else an avatar built from parts has on its face
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Accessory {
+    #[default]
+    None,
+    Beard,
+    Mustache,
+    Glasses,
+    Earrings,
+    Eyepatch,
+    Veil,
+}
+
+impl Accessory {
+    pub const ALL: [Self; 7] = [
+        Self::None,
+        Self::Beard,
+        Self::Mustache,
+        Self::Glasses,
+        Self::Earrings,
+        Self::Eyepatch,
+        Self::Veil,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Beard => "Beard",
+            Self::Mustache => "Mustache",
+            Self::Glasses => "Glasses",
+            Self::Earrings => "Earrings",
+            Self::Eyepatch => "Eyepatch",
+            Self::Veil => "Veil",
+        }
+    }
+
+    /// The next accessory, for the editor's tap-to-cycle buttons
+    pub fn next(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % Self::ALL.len()]
+    }
+}
+
+/// An avatar built in the avatar editor from a skin tone, headwear and accessory. Kept in
+/// the player's settings, and sent to online rooms as its `CharacterId` index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct AvatarSpec {
+    pub skin: SkinTone,
+    pub headwear: Headwear,
+    pub accessory: Accessory,
+}
+
+impl AvatarSpec {
+    /// How many different avatars can be built
+    const COUNT: usize = SkinTone::ALL.len() * Headwear::ALL.len() * Accessory::ALL.len();
+
+    /// Number of this combination of parts (0 to `COUNT - 1`)
+    fn code(&self) -> usize {
+        (self.skin as usize * Headwear::ALL.len() + self.headwear as usize) * Accessory::ALL.len()
+            + self.accessory as usize
+    }
+
+    /// The combination of parts numbered `code`, wrapping past the last
+    fn from_code(code: usize) -> Self {
+        let code = code % Self::COUNT;
+        let accessory = Accessory::ALL[code % Accessory::ALL.len()];
+        let code = code / Accessory::ALL.len();
+        Self {
+            skin: SkinTone::ALL[code / Headwear::ALL.len()],
+            headwear: Headwear::ALL[code % Headwear::ALL.len()],
+            accessory,
+        }
+    }
+}
+
 /// Skin tone colors
 const SKIN_LIGHT: Color32 = Color32::from_rgb(255, 220, 185);
 const SKIN_TAN: Color32 = Color32::from_rgb(210, 170, 130);
@@ -308,6 +632,7 @@ pub fn draw_avatar_with_expression(
         CharacterId::Guard => draw_guard(painter, center, radius, happy),
         CharacterId::Dancer => draw_dancer(painter, center, radius, happy),
         CharacterId::Pirate => draw_pirate(painter, center, radius, happy),
+        character => draw_from_spec(painter, center, radius, character.spec().unwrap_or_default(), happy),
     }
 }
 
@@ -1891,6 +2216,230 @@ fn draw_pirate(painter: &egui::Painter, center: Pos2, radius: f32, happy: bool)
     }
 }
 
+/// Avatar built from parts (the editor's, and the characters made from them): drapes
+/// behind the face, the face, accessories, then headwear on top
+fn draw_from_spec(painter: &egui::Painter, center: Pos2, radius: f32, spec: AvatarSpec, happy: bool) {
+    let skin = spec.skin.color();
+    let hair_color = Color32::from_rgb(50, 35, 25);
+
+    // Headscarves and hoods hang down behind the face
+    if matches!(spec.headwear, Headwear::Headscarf | Headwear::Hood) {
+        let drape_rect = Rect::from_center_size(
+            Pos2::new(center.x, center.y + radius * 0.1),
+            egui::vec2(radius * 1.95, radius * 1.9),
+        );
+        draw_simple_layered_rect(painter, drape_rect, radius * 0.5, darken(spec.headwear.color(), 0.15));
+    }
+
+    // Face - layered rounded rectangle
+    let face_rect = Rect::from_center_size(
+        Pos2::new(center.x, center.y + radius * 0.1),
+        egui::vec2(radius * 1.5, radius * 1.6),
+    );
+    draw_layered_rect(painter, face_rect, radius * 0.35, skin);
+
+    // Beard over the chin, below the smile
+    if spec.accessory == Accessory::Beard {
+        let beard_rect = Rect::from_center_size(
+            Pos2::new(center.x, center.y + radius * 0.72),
+            egui::vec2(radius * 1.4, radius * 0.55),
+        );
+        draw_simple_layered_rect(painter, beard_rect, radius * 0.25, hair_color);
+    }
+
+    // Eyes
+    let eye_center = Pos2::new(center.x, center.y + radius * 0.1);
+    draw_rect_eyes(painter, eye_center, radius, Color32::from_rgb(70, 50, 30));
+
+    // Smile - big for happy, normal otherwise (a veil covers it)
+    let smile_center = Pos2::new(center.x, center.y + radius * 0.13);
+    if spec.accessory != Accessory::Veil {
+        if happy {
+            draw_big_smile(painter, smile_center, radius);
+        } else {
+            draw_smile(painter, smile_center, radius);
+        }
+    }
+
+    draw_accessory(painter, center, radius, spec, hair_color);
+    draw_headwear(painter, center, radius, spec.headwear);
+}
+
+/// Helper: Draw the accessories that sit in front of the face (beards go on with the face)
+fn draw_accessory(painter: &egui::Painter, center: Pos2, radius: f32, spec: AvatarSpec, hair_color: Color32) {
+    let eye_y = center.y + radius * 0.05;
+    let eye_offset = radius * 0.25;
+    let gold = Color32::from_rgb(255, 200, 50);
+
+    match spec.accessory {
+        Accessory::None | Accessory::Beard => {}
+        Accessory::Mustache => {
+            let mustache_rect = Rect::from_center_size(
+                Pos2::new(center.x, center.y + radius * 0.3),
+                egui::vec2(radius * 0.5, radius * 0.1),
+            );
+            painter.rect_filled(mustache_rect, radius * 0.04, hair_color);
+            // Drooping ends
+            for &x_mult in &[-1.0, 1.0] {
+                let end_rect = Rect::from_center_size(
+                    Pos2::new(center.x + radius * 0.25 * x_mult, center.y + radius * 0.36),
+                    egui::vec2(radius * 0.08, radius * 0.14),
+                );
+                painter.rect_filled(end_rect, radius * 0.03, hair_color);
+            }
+        }
+        Accessory::Glasses => {
+            let frame = Stroke::new(radius * 0.06, Color32::from_rgb(40, 30, 20));
+            for &x_mult in &[-1.0, 1.0] {
+                let lens_rect = Rect::from_center_size(
+                    Pos2::new(center.x + eye_offset * x_mult, eye_y),
+                    egui::vec2(radius * 0.36, radius * 0.3),
+                );
+                painter.rect_stroke(lens_rect, radius * 0.06, frame, egui::epaint::StrokeKind::Middle);
+            }
+            painter.line_segment(
+                [
+                    Pos2::new(center.x - eye_offset + radius * 0.18, eye_y),
+                    Pos2::new(center.x + eye_offset - radius * 0.18, eye_y),
+                ],
+                frame,
+            );
+        }
+        Accessory::Earrings => {
+            for &x_mult in &[-1.0, 1.0] {
+                let earring = Pos2::new(center.x + radius * 0.78 * x_mult, center.y + radius * 0.35);
+                painter.circle_filled(earring, radius * 0.09, gold);
+                painter.circle_stroke(earring, radius * 0.09, Stroke::new(1.0, darken(gold, 0.4)));
+            }
+        }
+        Accessory::Eyepatch => {
+            // Strap across the forehead, then the patch over the left eye
+            painter.line_segment(
+                [
+                    Pos2::new(center.x - radius * 0.7, eye_y - radius * 0.2),
+                    Pos2::new(center.x + radius * 0.7, eye_y - radius * 0.45),
+                ],
+                Stroke::new(radius * 0.06, Color32::from_rgb(30, 30, 30)),
+            );
+            let patch_rect = Rect::from_center_size(
+                Pos2::new(center.x - eye_offset, eye_y),
+                egui::vec2(radius * 0.32, radius * 0.28),
+            );
+            painter.rect_filled(patch_rect, radius * 0.08, Color32::from_rgb(20, 20, 20));
+        }
+        Accessory::Veil => {
+            // Cloth over the lower face, matching the headwear if there's any
+            let cloth = match spec.headwear {
+                Headwear::None => Color32::from_rgb(200, 180, 150),
+                headwear => headwear.color(),
+            };
+            let veil_rect = Rect::from_center_size(
+                Pos2::new(center.x, center.y + radius * 0.6),
+                egui::vec2(radius * 1.5, radius * 0.7),
+            );
+            draw_simple_layered_rect(painter, veil_rect, radius * 0.3, cloth);
+        }
+    }
+}
+
+/// Helper: Draw headwear on top of the face
+fn draw_headwear(painter: &egui::Painter, center: Pos2, radius: f32, headwear: Headwear) {
+    let color = headwear.color();
+    let dark = darken(color, 0.4);
+
+    match headwear {
+        Headwear::None => {}
+        Headwear::Turban => {
+            let turban_y = center.y - radius * 0.5;
+            let turban_rect = Rect::from_center_size(
+                Pos2::new(center.x, turban_y),
+                egui::vec2(radius * 1.6, radius * 0.7),
+            );
+            draw_layered_rect(painter, turban_rect, radius * 0.25, color);
+            let gem_rect = Rect::from_center_size(
+                Pos2::new(center.x, turban_y + radius * 0.15),
+                egui::vec2(radius * 0.2, radius * 0.2),
+            );
+            painter.rect_filled(gem_rect, radius * 0.05, Color32::from_rgb(50, 200, 100));
+        }
+        Headwear::Fez => {
+            let fez_rect = Rect::from_center_size(
+                Pos2::new(center.x, center.y - radius * 0.75),
+                egui::vec2(radius * 0.8, radius * 0.6),
+            );
+            draw_simple_layered_rect(painter, fez_rect, radius * 0.1, color);
+            // Tassel
+            let top = Pos2::new(center.x, fez_rect.top() + radius * 0.05);
+            let tassel_end = Pos2::new(center.x + radius * 0.45, center.y - radius * 0.55);
+            painter.line_segment([top, tassel_end], Stroke::new(radius * 0.05, Color32::BLACK));
+            painter.circle_filled(tassel_end, radius * 0.06, Color32::BLACK);
+        }
+        Headwear::SafariHat => {
+            let hat_top = center.y - radius * 0.3;
+            let brim_rect = Rect::from_center_size(
+                Pos2::new(center.x, hat_top - radius * 0.3),
+                egui::vec2(radius * 1.9, radius * 0.35),
+            );
+            draw_simple_layered_rect(painter, brim_rect, radius * 0.1, color);
+            let crown_rect = Rect::from_center_size(
+                Pos2::new(center.x, hat_top - radius * 0.6),
+                egui::vec2(radius * 1.0, radius * 0.4),
+            );
+            draw_simple_layered_rect(painter, crown_rect, radius * 0.15, color);
+            let band_rect = Rect::from_center_size(
+                Pos2::new(center.x, hat_top - radius * 0.45),
+                egui::vec2(radius * 1.0, radius * 0.1),
+            );
+            painter.rect_filled(band_rect, radius * 0.02, dark);
+        }
+        Headwear::Crown => {
+            let base_rect = Rect::from_center_size(
+                Pos2::new(center.x, center.y - radius * 0.7),
+                egui::vec2(radius * 1.2, radius * 0.3),
+            );
+            draw_simple_layered_rect(painter, base_rect, radius * 0.05, color);
+            for i in 0..3 {
+                let x = center.x + (i as f32 - 1.0) * radius * 0.4;
+                let points = vec![
+                    Pos2::new(x, base_rect.top() - radius * 0.4),
+                    Pos2::new(x - radius * 0.17, base_rect.top()),
+                    Pos2::new(x + radius * 0.17, base_rect.top()),
+                ];
+                painter.add(egui::Shape::convex_polygon(points, color, Stroke::new(1.0, dark)));
+            }
+            painter.circle_filled(base_rect.center(), radius * 0.08, Color32::from_rgb(220, 50, 50));
+        }
+        Headwear::Headscarf => {
+            // Band across the forehead; the rest hangs behind the face
+            let band_rect = Rect::from_center_size(
+                Pos2::new(center.x, center.y - radius * 0.6),
+                egui::vec2(radius * 1.7, radius * 0.5),
+            );
+            draw_layered_rect(painter, band_rect, radius * 0.2, color);
+        }
+        Headwear::Helmet => {
+            let dome_rect = Rect::from_center_size(
+                Pos2::new(center.x, center.y - radius * 0.6),
+                egui::vec2(radius * 1.7, radius * 0.7),
+            );
+            draw_layered_rect(painter, dome_rect, radius * 0.35, color);
+            // Nose guard between the eyes
+            let guard_rect = Rect::from_center_size(
+                Pos2::new(center.x, center.y - radius * 0.1),
+                egui::vec2(radius * 0.12, radius * 0.5),
+            );
+            painter.rect_filled(guard_rect, radius * 0.03, dark);
+        }
+        Headwear::Hood => {
+            let hood_rect = Rect::from_center_size(
+                Pos2::new(center.x, center.y - radius * 0.6),
+                egui::vec2(radius * 1.8, radius * 0.6),
+            );
+            draw_layered_rect(painter, hood_rect, radius * 0.4, color);
+        }
+    }
+}
+
 /// Draw a small crown on top of an avatar (for winner)
 pub fn draw_avatar_crown(painter: &egui::Painter, rect: Rect) {
     let center = rect.center();
@@ -0,0 +1,975 @@
+// Shared painters for cards, icons and avatars
+// Every painter takes the painter, where to draw, and a `Palette` (or the avatar
+// background color), so screens can restyle them without copying the drawing code.
+// Used by the HUD, scoring, rules, lobby and setup screens.
+
+use bevy_egui::egui;
+
+use crate::components::{CamelColor, CamelPalette};
+use crate::ui::characters::CharacterId;
+use crate::ui::theme::camel_color_to_egui;
+
+pub use crate::ui::characters::{draw_avatar, draw_avatar_crown, draw_avatar_with_expression};
+
+/// Colors a painter draws with
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Palette {
+    /// Main body color
+    pub fill: egui::Color32,
+    /// Secondary color: the shaded side, an inner panel, or a second detail
+    pub shade: egui::Color32,
+    /// Borders and text drawn over the fill
+    pub outline: egui::Color32,
+    /// Small details (gems, straps, labels on the fill)
+    pub accent: egui::Color32,
+    /// The camel these are the colors of, when its letter and pattern markers are drawn
+    pub camel: Option<CamelColor>,
+}
+
+impl Palette {
+    /// Gold pyramid tokens and coins
+    pub const GOLD: Palette = Palette {
+        fill: egui::Color32::from_rgb(0xD4, 0xA8, 0x4B),
+        shade: egui::Color32::from_rgb(0xA0, 0x7A, 0x30),
+        outline: egui::Color32::from_rgb(0x6B, 0x4A, 0x1A),
+        accent: egui::Color32::from_rgb(0x6B, 0x4A, 0x1A),
+        camel: None,
+    };
+
+    /// Cream card stock (fill), its inner panels (shade) and drop shadow (outline)
+    pub const PAPER: Palette = Palette {
+        fill: egui::Color32::from_rgb(245, 235, 215),
+        shade: egui::Color32::from_rgb(250, 245, 230),
+        outline: egui::Color32::from_rgb(60, 50, 40),
+        accent: egui::Color32::from_rgb(100, 70, 30),
+        camel: None,
+    };
+
+    /// Winner's crown: red outer gems, blue middle gem
+    pub const GOLD_CROWN: Palette = Palette {
+        fill: egui::Color32::from_rgb(255, 215, 0),
+        shade: egui::Color32::from_rgb(50, 100, 220),
+        outline: egui::Color32::from_rgb(200, 160, 0),
+        accent: egui::Color32::from_rgb(220, 50, 50),
+        camel: None,
+    };
+
+    /// Runner-up's crown: light blue outer gems, pearl middle gem
+    pub const SILVER_CROWN: Palette = Palette {
+        fill: egui::Color32::from_rgb(200, 200, 210),
+        shade: egui::Color32::from_rgb(180, 180, 200),
+        outline: egui::Color32::from_rgb(140, 140, 150),
+        accent: egui::Color32::from_rgb(100, 180, 220),
+        camel: None,
+    };
+
+    /// Muted grey-blue dunce cap with a dark chin strap
+    pub const DUNCE_CAP: Palette = Palette {
+        fill: egui::Color32::from_rgb(100, 100, 110),
+        shade: egui::Color32::from_rgb(100, 100, 110),
+        outline: egui::Color32::from_rgb(60, 60, 70),
+        accent: egui::Color32::from_rgb(70, 70, 70),
+        camel: None,
+    };
+
+    /// Neutral camel for the winner/loser icons
+    pub const GREY_CAMEL: Palette = Palette::outlined(
+        egui::Color32::from_rgb(140, 140, 140),
+        egui::Color32::from_rgb(80, 80, 80),
+    );
+
+    /// One color for everything
+    pub const fn solid(color: egui::Color32) -> Self {
+        Self { fill: color, shade: color, outline: color, accent: color, camel: None }
+    }
+
+    /// A fill with its own outline
+    pub const fn outlined(fill: egui::Color32, outline: egui::Color32) -> Self {
+        Self { fill, shade: fill, outline, accent: outline, camel: None }
+    }
+
+    /// A fill with darker shade and outline derived from it
+    pub fn shaded(fill: egui::Color32) -> Self {
+        let darken = |factor: f32| {
+            egui::Color32::from_rgb(
+                (fill.r() as f32 * factor) as u8,
+                (fill.g() as f32 * factor) as u8,
+                (fill.b() as f32 * factor) as u8,
+            )
+        };
+        Self { fill, shade: darken(0.6), outline: darken(0.5), accent: egui::Color32::WHITE, camel: None }
+    }
+
+    /// A camel's colors in the player's palette, with a readable label color for text on
+    /// the fill
+    pub fn camel(color: CamelColor, camel_palette: CamelPalette) -> Self {
+        let accent = if color == CamelColor::Yellow {
+            egui::Color32::BLACK
+        } else {
+            egui::Color32::WHITE
+        };
+        Self {
+            accent,
+            camel: Some(color).filter(|_| camel_palette.markers),
+            ..Self::shaded(camel_color_to_egui(color, camel_palette))
+        }
+    }
+
+    /// Every color brightened by `amount` (hover states)
+    pub fn lightened(&self, amount: u8) -> Self {
+        let lighten = |c: egui::Color32| {
+            egui::Color32::from_rgba_unmultiplied(
+                c.r().saturating_add(amount),
+                c.g().saturating_add(amount),
+                c.b().saturating_add(amount),
+                c.a(),
+            )
+        };
+        Self {
+            fill: lighten(self.fill),
+            shade: lighten(self.shade),
+            outline: lighten(self.outline),
+            accent: lighten(self.accent),
+            camel: self.camel,
+        }
+    }
+}
+
+/// Scale of a camel silhouette drawn in `rect` (1.0 = 30px)
+fn camel_scale(rect: egui::Rect) -> f32 {
+    (rect.width().min(rect.height()) / 30.0).min(1.0)
+}
+
+/// Where the head of a camel silhouette drawn in `rect` is, for hats and crowns
+fn camel_head_center(rect: egui::Rect) -> egui::Pos2 {
+    let scale = camel_scale(rect);
+    let body_center = rect.center() + egui::vec2(-2.0 * scale, 2.0 * scale);
+    let neck_center = body_center + egui::vec2(8.0 * scale, -4.0 * scale);
+    neck_center + egui::vec2(3.0 * scale, -5.0 * scale)
+}
+
+/// Helper function to draw a small camel silhouette for UI elements
+/// Draws a stylized side-view camel using 4 layers (shadow, border, main, highlight)
+/// to match the polished look of the board camels
+pub fn draw_camel_silhouette(painter: &egui::Painter, rect: egui::Rect, palette: &Palette) {
+    let color = palette.fill;
+    let border_color = palette.outline;
+    let center = rect.center();
+    let scale = camel_scale(rect);
+
+    // Body - main rectangle
+    let body_width = 16.0 * scale;
+    let body_height = 9.0 * scale;
+    let body_center = center + egui::vec2(-2.0 * scale, 2.0 * scale);
+    let body_rect = egui::Rect::from_center_size(body_center, egui::vec2(body_width, body_height));
+
+    // Hump - on top of body
+    let hump_width = 8.0 * scale;
+    let hump_height = 7.0 * scale;
+    let hump_center = body_center + egui::vec2(-scale, -6.0 * scale);
+    let hump_rect = egui::Rect::from_center_size(hump_center, egui::vec2(hump_width, hump_height));
+
+    // Neck - tall narrow rectangle
+    let neck_width = 4.0 * scale;
+    let neck_height = 9.0 * scale;
+    let neck_center = body_center + egui::vec2(8.0 * scale, -4.0 * scale);
+    let neck_rect = egui::Rect::from_center_size(neck_center, egui::vec2(neck_width, neck_height));
+
+    // Head - small rectangle
+    let head_width = 7.0 * scale;
+    let head_height = 5.0 * scale;
+    let head_center = neck_center + egui::vec2(3.0 * scale, -5.0 * scale);
+    let head_rect = egui::Rect::from_center_size(head_center, egui::vec2(head_width, head_height));
+
+    // Legs - four thin rectangles
+    let leg_width = 2.5 * scale;
+    let leg_height = 7.0 * scale;
+    let leg_positions = [
+        body_center + egui::vec2(-5.0 * scale, 7.0 * scale), // Back left
+        body_center + egui::vec2(-2.0 * scale, 7.0 * scale), // Back right
+        body_center + egui::vec2(4.0 * scale, 7.0 * scale),  // Front left
+        body_center + egui::vec2(7.0 * scale, 7.0 * scale),  // Front right
+    ];
+
+    // === Layer 1: SHADOW ===
+    let shadow_offset = egui::vec2(1.5 * scale, 1.5 * scale);
+    let shadow_color = egui::Color32::from_rgba_unmultiplied(0, 0, 0, 76); // ~0.3 alpha
+
+    painter.rect_filled(
+        body_rect.translate(shadow_offset),
+        1.0 * scale,
+        shadow_color,
+    );
+    painter.rect_filled(
+        hump_rect.translate(shadow_offset),
+        1.0 * scale,
+        shadow_color,
+    );
+    painter.rect_filled(
+        neck_rect.translate(shadow_offset),
+        0.5 * scale,
+        shadow_color,
+    );
+    painter.rect_filled(
+        head_rect.translate(shadow_offset),
+        1.0 * scale,
+        shadow_color,
+    );
+    for leg_pos in &leg_positions {
+        let leg_rect = egui::Rect::from_center_size(*leg_pos, egui::vec2(leg_width, leg_height));
+        painter.rect_filled(leg_rect.translate(shadow_offset), 0.5 * scale, shadow_color);
+    }
+
+    // === Layer 2: BORDER ===
+    let border_expand = 1.5 * scale;
+    painter.rect_filled(body_rect.expand(border_expand), 1.0 * scale, border_color);
+    painter.rect_filled(hump_rect.expand(border_expand), 1.0 * scale, border_color);
+    painter.rect_filled(neck_rect.expand(border_expand), 0.5 * scale, border_color);
+    painter.rect_filled(head_rect.expand(border_expand), 1.0 * scale, border_color);
+    for leg_pos in &leg_positions {
+        let leg_rect = egui::Rect::from_center_size(*leg_pos, egui::vec2(leg_width, leg_height));
+        painter.rect_filled(
+            leg_rect.expand(border_expand * 0.5),
+            0.5 * scale,
+            border_color,
+        );
+    }
+
+    // === Layer 3: MAIN COLOR ===
+    painter.rect_filled(body_rect, 1.0 * scale, color);
+    painter.rect_filled(hump_rect, 1.0 * scale, color);
+    painter.rect_filled(neck_rect, 0.5 * scale, color);
+    painter.rect_filled(head_rect, 1.0 * scale, color);
+    for leg_pos in &leg_positions {
+        let leg_rect = egui::Rect::from_center_size(*leg_pos, egui::vec2(leg_width, leg_height));
+        painter.rect_filled(leg_rect, 0.5 * scale, color);
+    }
+
+    // === Layer 4: HIGHLIGHT ===
+    let highlight_color = egui::Color32::from_rgba_unmultiplied(255, 255, 255, 102); // ~0.4 alpha
+
+    // Highlight strip on hump
+    let hump_highlight_rect = egui::Rect::from_center_size(
+        hump_center + egui::vec2(0.0, -2.0 * scale),
+        egui::vec2((hump_width - 2.0 * scale).max(2.0), 2.0 * scale),
+    );
+    painter.rect_filled(hump_highlight_rect, 0.5 * scale, highlight_color);
+
+    // Highlight strip on head
+    let head_highlight_rect = egui::Rect::from_center_size(
+        head_center + egui::vec2(0.0, -1.5 * scale),
+        egui::vec2((head_width - 2.0 * scale).max(2.0), 1.5 * scale),
+    );
+    painter.rect_filled(head_highlight_rect, 0.5 * scale, highlight_color);
+
+    // === Eye ===
+    let eye_pos = head_center + egui::vec2(1.5 * scale, -0.5 * scale);
+    painter.circle_filled(eye_pos, 1.0 * scale, egui::Color32::from_rgb(30, 30, 30));
+
+    // === Color marker on the body ===
+    if let Some(camel) = palette.camel {
+        draw_color_pattern(painter, body_rect, camel);
+        draw_color_letter(painter, body_center, 10.0 * scale, camel);
+    }
+}
+
+/// Hatch `rect` with a camel color's pattern (for players with the color markers on)
+pub fn draw_color_pattern(painter: &egui::Painter, rect: egui::Rect, color: CamelColor) {
+    let ink = egui::Color32::from_rgba_unmultiplied(0, 0, 0, 90);
+    let painter = painter.with_clip_rect(rect.intersect(painter.clip_rect()));
+
+    for mark in color.pattern().marks() {
+        // Marks are laid out y up, egui draws y down
+        let center = rect.center() + egui::vec2(mark.center.x * rect.width(), -mark.center.y * rect.height());
+        let size = egui::vec2(mark.size.x * rect.width(), mark.size.y * rect.height());
+        if mark.diamond {
+            let half = size * std::f32::consts::FRAC_1_SQRT_2;
+            let points = vec![
+                center + egui::vec2(0.0, -half.y),
+                center + egui::vec2(half.x, 0.0),
+                center + egui::vec2(0.0, half.y),
+                center + egui::vec2(-half.x, 0.0),
+            ];
+            painter.add(egui::Shape::convex_polygon(points, ink, egui::Stroke::NONE));
+        } else {
+            painter.rect_filled(egui::Rect::from_center_size(center, size), 0.0, ink);
+        }
+    }
+}
+
+/// A camel color's letter in a dark badge `size` across (for players with the color
+/// markers on)
+pub fn draw_color_letter(painter: &egui::Painter, center: egui::Pos2, size: f32, color: CamelColor) {
+    painter.circle_filled(center, size * 0.5, egui::Color32::from_rgba_unmultiplied(0, 0, 0, 170));
+    painter.text(
+        center,
+        egui::Align2::CENTER_CENTER,
+        color.letter(),
+        egui::FontId::proportional(size * 0.75),
+        egui::Color32::WHITE,
+    );
+}
+
+/// Draws a camel with a gold crown on its head (winner icon)
+pub fn draw_camel_with_crown(painter: &egui::Painter, rect: egui::Rect, palette: &Palette) {
+    draw_camel_silhouette(painter, rect, palette);
+    draw_crown_overlay(painter, rect, &Palette::GOLD_CROWN);
+}
+
+/// Draws a camel with a dunce cap on its head (loser icon)
+pub fn draw_camel_with_dunce_cap(painter: &egui::Painter, rect: egui::Rect, palette: &Palette) {
+    draw_camel_silhouette(painter, rect, palette);
+    draw_dunce_cap_overlay(painter, rect, &Palette::DUNCE_CAP);
+}
+
+/// Draws just a crown overlay on top of a camel silhouette
+/// The rect should be the same rect used for draw_camel_silhouette.
+/// The outer gems use the palette's accent and the middle gem its shade.
+pub fn draw_crown_overlay(painter: &egui::Painter, rect: egui::Rect, palette: &Palette) {
+    let head_center = camel_head_center(rect);
+    let scale = camel_scale(rect);
+
+    // Crown position - on top of head
+    let crown_center = head_center + egui::vec2(0.0, -5.0 * scale);
+    let crown_width = 8.0 * scale;
+    let crown_height = 4.0 * scale;
+
+    // Crown base (rectangle)
+    let base_rect = egui::Rect::from_center_size(
+        crown_center + egui::vec2(0.0, 1.5 * scale),
+        egui::vec2(crown_width, crown_height * 0.5),
+    );
+    painter.rect_filled(base_rect, 1.0 * scale, palette.fill);
+
+    // Crown points (3 triangles)
+    let point_height = 4.0 * scale;
+    let point_width = 2.5 * scale;
+    let point_y = crown_center.y - 1.0 * scale;
+
+    for i in 0..3 {
+        let x_offset = (i as f32 - 1.0) * 2.5 * scale;
+        let point_center = egui::pos2(crown_center.x + x_offset, point_y);
+
+        // Triangle for crown point
+        let points = vec![
+            egui::pos2(point_center.x, point_center.y - point_height), // Top
+            egui::pos2(point_center.x - point_width / 2.0, point_center.y), // Bottom left
+            egui::pos2(point_center.x + point_width / 2.0, point_center.y), // Bottom right
+        ];
+        painter.add(egui::Shape::convex_polygon(
+            points,
+            palette.fill,
+            egui::Stroke::new(0.5 * scale, palette.outline),
+        ));
+    }
+
+    // Small gems on crown points
+    let gem_colors = [palette.accent, palette.shade, palette.accent];
+    for (i, gem_color) in gem_colors.into_iter().enumerate() {
+        let x_offset = (i as f32 - 1.0) * 2.5 * scale;
+        let gem_pos = egui::pos2(crown_center.x + x_offset, point_y - 2.0 * scale);
+        painter.circle_filled(gem_pos, 1.0 * scale, gem_color);
+    }
+}
+
+/// Draws just a dunce cap overlay on top of a camel silhouette
+/// The rect should be the same rect used for draw_camel_silhouette.
+/// The chin strap uses the palette's accent.
+pub fn draw_dunce_cap_overlay(painter: &egui::Painter, rect: egui::Rect, palette: &Palette) {
+    let head_center = camel_head_center(rect);
+    let scale = camel_scale(rect);
+
+    // Dunce cap position - on top of head
+    let cap_base = head_center + egui::vec2(0.0, -3.0 * scale);
+    let cap_height = 10.0 * scale;
+    let cap_width = 6.0 * scale;
+
+    let cap_color = palette.fill;
+    let cap_outline = palette.outline;
+
+    // Dunce cap triangle
+    let points = vec![
+        egui::pos2(cap_base.x, cap_base.y - cap_height), // Top point
+        egui::pos2(cap_base.x - cap_width / 2.0, cap_base.y), // Bottom left
+        egui::pos2(cap_base.x + cap_width / 2.0, cap_base.y), // Bottom right
+    ];
+    painter.add(egui::Shape::convex_polygon(
+        points,
+        cap_color,
+        egui::Stroke::new(0.8 * scale, cap_outline),
+    ));
+
+    // Chin strap
+    let strap_color = palette.accent;
+    let chin_left = head_center + egui::vec2(-3.0 * scale, 2.0 * scale);
+    let chin_right = head_center + egui::vec2(3.0 * scale, 2.0 * scale);
+    painter.line_segment(
+        [chin_left, chin_right],
+        egui::Stroke::new(0.5 * scale, strap_color),
+    );
+}
+
+/// Indicates what type of race bet was placed for displaying on unavailable cards
+#[derive(Clone, Copy)]
+pub enum PlacedBetType {
+    Winner,
+    Loser,
+}
+
+/// Helper function to draw a mini leg bet card (camel silhouette on top, value on bottom)
+/// The palette is the camel's (see `Palette::camel`)
+pub fn draw_mini_leg_bet_card(painter: &egui::Painter, rect: egui::Rect, palette: &Palette, value: u8) {
+    let paper = Palette::PAPER;
+
+    // Card border/shadow
+    painter.rect_filled(rect.expand(1.0), 3.0, paper.outline);
+    painter.rect_filled(rect, 2.0, paper.fill);
+
+    // Split into top (camel) and bottom (value)
+    let top_half =
+        egui::Rect::from_min_max(rect.min, egui::pos2(rect.max.x, rect.center().y + 2.0));
+    let bottom_half =
+        egui::Rect::from_min_max(egui::pos2(rect.min.x, rect.center().y + 2.0), rect.max);
+
+    // Top half - cream for camel
+    painter.rect_filled(top_half.shrink(1.0), 1.0, paper.shade);
+
+    // Draw camel silhouette
+    let camel_rect = egui::Rect::from_min_size(
+        top_half.min + egui::vec2(2.0, 2.0),
+        egui::vec2(top_half.width() - 4.0, top_half.height() - 4.0),
+    );
+    draw_camel_silhouette(painter, camel_rect, palette);
+
+    // Bottom half - cream background with gold coin
+    painter.rect_filled(bottom_half.shrink(1.0), 1.0, paper.shade);
+
+    // Draw gold coin with value
+    let coin_center = bottom_half.center();
+    let coin_radius = (bottom_half.height() * 0.38).min(bottom_half.width() * 0.38);
+
+    // Gold colors (matching pyramid token)
+    let gold_light = Palette::GOLD.fill;
+    let gold_dark = Palette::GOLD.shade;
+    let gold_outline = Palette::GOLD.outline;
+
+    // Outer shadow/depth
+    painter.circle_filled(
+        coin_center + egui::vec2(1.0, 1.0),
+        coin_radius,
+        gold_outline,
+    );
+    // Main coin body
+    painter.circle_filled(coin_center, coin_radius, gold_light);
+    // Inner shadow ring for depth
+    painter.circle_stroke(
+        coin_center,
+        coin_radius * 0.85,
+        egui::Stroke::new(1.0, gold_dark),
+    );
+    // Outer edge
+    painter.circle_stroke(
+        coin_center,
+        coin_radius,
+        egui::Stroke::new(1.5, gold_outline),
+    );
+
+    // Value text on coin
+    painter.text(
+        coin_center,
+        egui::Align2::CENTER_CENTER,
+        format!("{}", value),
+        egui::FontId::proportional(coin_radius * 0.9),
+        gold_outline,
+    );
+}
+
+/// Helper function to draw a tiny leg bet indicator for player's bet collection
+/// Smaller than draw_mini_leg_bet_card, designed for overlapping display
+/// The palette is the camel's (see `Palette::camel`)
+pub fn draw_mini_leg_bet_indicator(painter: &egui::Painter, rect: egui::Rect, palette: &Palette, value: u8) {
+    // Card border/shadow
+    painter.rect_filled(rect.expand(0.5), 1.5, Palette::PAPER.outline);
+    painter.rect_filled(rect, 1.0, Palette::PAPER.fill);
+
+    // Top half - camel color block
+    let top_half = egui::Rect::from_min_max(rect.min, egui::pos2(rect.max.x, rect.center().y));
+    painter.rect_filled(top_half.shrink(0.5), 0.5, palette.fill);
+    if let Some(camel) = palette.camel {
+        draw_color_pattern(painter, top_half.shrink(0.5), camel);
+        draw_color_letter(painter, top_half.center(), top_half.height(), camel);
+    }
+
+    // Bottom half - value
+    let bottom_center = egui::pos2(rect.center().x, rect.max.y - rect.height() * 0.25);
+    painter.text(
+        bottom_center,
+        egui::Align2::CENTER_CENTER,
+        format!("{}", value),
+        egui::FontId::proportional(rect.height() * 0.35),
+        Palette::GOLD.outline,
+    );
+}
+
+/// Helper function to draw a small pyramid token icon (usually with `Palette::GOLD`)
+pub fn draw_pyramid_token_icon(painter: &egui::Painter, center: egui::Pos2, palette: &Palette, size: f32) {
+    let pyramid_height = size * 0.8;
+    let pyramid_width = size * 0.7;
+
+    let apex = egui::pos2(center.x, center.y - pyramid_height / 2.0);
+    let base_left = egui::pos2(
+        center.x - pyramid_width / 2.0,
+        center.y + pyramid_height / 2.0,
+    );
+    let base_right = egui::pos2(
+        center.x + pyramid_width / 2.0,
+        center.y + pyramid_height / 2.0,
+    );
+    let mid_base = egui::pos2(center.x, center.y + pyramid_height / 2.0);
+
+    let pyramid_color = palette.fill;
+    let shadow_color = palette.shade;
+    let outline_color = palette.outline;
+
+    // Draw shadow/depth on left side
+    painter.add(egui::Shape::convex_polygon(
+        vec![apex, base_left, mid_base],
+        shadow_color,
+        egui::Stroke::NONE,
+    ));
+
+    // Draw lit side on right
+    painter.add(egui::Shape::convex_polygon(
+        vec![apex, mid_base, base_right],
+        pyramid_color,
+        egui::Stroke::NONE,
+    ));
+
+    // Draw outline
+    painter.add(egui::Shape::closed_line(
+        vec![apex, base_left, base_right],
+        egui::Stroke::new(1.0, outline_color),
+    ));
+
+    // Draw "$1" on the token
+    painter.text(
+        egui::pos2(center.x, center.y + 2.0),
+        egui::Align2::CENTER_CENTER,
+        "$1",
+        egui::FontId::proportional(size * 0.28),
+        outline_color,
+    );
+}
+
+/// Draw an interactive pyramid button with optional flip animation
+/// flip_progress: 0.0 = not animating, 0.01-1.0 = flip in progress
+pub fn draw_pyramid_button(
+    ui: &mut egui::Ui,
+    size: egui::Vec2,
+    palette: &Palette,
+    flip_progress: f32,
+) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
+    let painter = ui.painter();
+
+    // Hover effect - brighten colors
+    let colors = if response.hovered() { palette.lightened(0x10) } else { *palette };
+    let (light, dark, outline_color) = (colors.fill, colors.shade, palette.outline);
+
+    // Calculate rotation angle for shake (oscillates with decay)
+    let rotation_angle = if flip_progress > 0.0 {
+        let shake_intensity = 0.15; // radians max rotation (~8.5 degrees)
+        let shake_frequency = 4.0; // number of full oscillations
+        let decay = 1.0 - flip_progress; // decreases over time
+        (flip_progress * shake_frequency * std::f32::consts::TAU).sin() * shake_intensity * decay
+    } else {
+        0.0
+    };
+
+    // Helper to rotate a point around a center
+    let rotate_point = |point: egui::Pos2, center: egui::Pos2, angle: f32| -> egui::Pos2 {
+        let cos_a = angle.cos();
+        let sin_a = angle.sin();
+        let dx = point.x - center.x;
+        let dy = point.y - center.y;
+        egui::pos2(
+            center.x + dx * cos_a - dy * sin_a,
+            center.y + dx * sin_a + dy * cos_a,
+        )
+    };
+
+    // Define pyramid points (unrotated)
+    let center = rect.center();
+    let apex_base = egui::pos2(rect.center().x, rect.top() + 4.0);
+    let base_left_base = egui::pos2(rect.left() + 4.0, rect.bottom() - 4.0);
+    let base_right_base = egui::pos2(rect.right() - 4.0, rect.bottom() - 4.0);
+    let mid_base_base = egui::pos2(rect.center().x, rect.bottom() - 4.0);
+
+    // Apply rotation
+    let apex = rotate_point(apex_base, center, rotation_angle);
+    let base_left = rotate_point(base_left_base, center, rotation_angle);
+    let base_right = rotate_point(base_right_base, center, rotation_angle);
+    let mid_base = rotate_point(mid_base_base, center, rotation_angle);
+
+    // Left (shadow) side
+    painter.add(egui::Shape::convex_polygon(
+        vec![apex, base_left, mid_base],
+        dark,
+        egui::Stroke::NONE,
+    ));
+
+    // Right (lit) side
+    painter.add(egui::Shape::convex_polygon(
+        vec![apex, mid_base, base_right],
+        light,
+        egui::Stroke::NONE,
+    ));
+
+    // Outline
+    painter.add(egui::Shape::closed_line(
+        vec![apex, base_left, base_right],
+        egui::Stroke::new(1.5, outline_color),
+    ));
+
+    // Draw "Roll" text and "+$1" (rotate with pyramid)
+    let roll_pos_base = egui::pos2(center.x, center.y - 4.0);
+    let cost_pos_base = egui::pos2(center.x, center.y + size.y * 0.2);
+    let roll_pos = rotate_point(roll_pos_base, center, rotation_angle);
+    let cost_pos = rotate_point(cost_pos_base, center, rotation_angle);
+
+    painter.text(
+        roll_pos,
+        egui::Align2::CENTER_CENTER,
+        "Roll",
+        egui::FontId::proportional(size.y * 0.18),
+        outline_color,
+    );
+    painter.text(
+        cost_pos,
+        egui::Align2::CENTER_CENTER,
+        "+$1",
+        egui::FontId::proportional(size.y * 0.14),
+        outline_color,
+    );
+
+    response
+}
+
+/// Helper function to draw a spectator tile card with player avatar on top and +1/-1 on bottom
+/// flip_progress: 0.0 = front fully visible, 1.0 = back fully visible
+/// Uses clip-rect approach for unified flip effect - all elements clipped identically
+/// The palette is the player's; its fill is the avatar background.
+pub fn draw_spectator_tile_card(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    palette: &Palette,
+    character_id: CharacterId,
+    is_oasis: bool,
+    flip_progress: f32,
+) {
+    // Calculate horizontal scale for flip effect (simulates 3D rotation)
+    let (show_front, scale_x) = if flip_progress <= 0.5 {
+        // First half: showing front, shrinking
+        let t = flip_progress * 2.0; // 0.0 to 1.0
+        (true, 1.0 - t)
+    } else {
+        // Second half: showing back, growing
+        let t = (flip_progress - 0.5) * 2.0; // 0.0 to 1.0
+        (false, t)
+    };
+
+    // If scale is too small, don't draw (card is edge-on)
+    if scale_x < 0.02 {
+        return;
+    }
+
+    // Determine which side to show based on is_oasis and show_front
+    let showing_oasis = if show_front { is_oasis } else { !is_oasis };
+
+    // Create clip rect - a narrowing horizontal strip centered on the card
+    // This creates a uniform flip effect where all elements are clipped identically
+    let center_x = rect.center().x;
+    let visible_half_width = rect.width() * scale_x / 2.0;
+    let clip_rect = egui::Rect::from_x_y_ranges(
+        center_x - visible_half_width..=center_x + visible_half_width,
+        rect.top()..=rect.bottom(),
+    );
+
+    // Create a clipped painter - all drawing will be clipped to the narrowing strip
+    let clipped_painter = painter.with_clip_rect(clip_rect);
+
+    // Draw full card content at normal size - clipping creates the flip effect
+    draw_spectator_tile_content(&clipped_painter, rect, palette, character_id, showing_oasis);
+}
+
+/// Helper function to draw spectator tile card content at full size (used with clipping for flip effect)
+fn draw_spectator_tile_content(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    palette: &Palette,
+    character_id: CharacterId,
+    showing_oasis: bool,
+) {
+    let paper = Palette::PAPER;
+
+    // Colors for each side
+    let (bg_color, _value_color, value_text) = if showing_oasis {
+        // Oasis (+1): Green/lush colors
+        (
+            egui::Color32::from_rgb(80, 160, 80), // Green
+            egui::Color32::from_rgb(50, 120, 50), // Dark green
+            "+1",
+        )
+    } else {
+        // Mirage (-1): Sandy/orange colors
+        (
+            egui::Color32::from_rgb(200, 150, 80), // Sandy
+            egui::Color32::from_rgb(160, 100, 40), // Dark sandy
+            "-1",
+        )
+    };
+
+    // Card shadow
+    painter.rect_filled(rect.expand(2.0), 5.0, paper.outline);
+
+    // Card background
+    painter.rect_filled(rect, 4.0, paper.fill);
+
+    // Split into top (avatar) and bottom (value)
+    let top_half =
+        egui::Rect::from_min_max(rect.min, egui::pos2(rect.max.x, rect.center().y + 4.0));
+    let bottom_half =
+        egui::Rect::from_min_max(egui::pos2(rect.min.x, rect.center().y + 4.0), rect.max);
+
+    // Top half - cream background with avatar
+    painter.rect_filled(top_half.shrink(2.0), 2.0, paper.shade);
+
+    // Draw player avatar in top portion at full size
+    let avatar_size = (top_half.height() - 8.0).min(top_half.width() - 8.0);
+    let avatar_rect =
+        egui::Rect::from_center_size(top_half.center(), egui::vec2(avatar_size, avatar_size));
+    draw_avatar(painter, avatar_rect, character_id, Some(palette.fill));
+
+    // Bottom half - colored band with value
+    painter.rect_filled(bottom_half.shrink2(egui::vec2(2.0, 0.0)), 2.0, bg_color);
+
+    // Draw +1 or -1 text (slightly left of center to make room for coin)
+    let text_offset = -rect.width() * 0.12;
+    painter.text(
+        bottom_half.center() + egui::vec2(text_offset, 0.0),
+        egui::Align2::CENTER_CENTER,
+        value_text,
+        egui::FontId::proportional(16.0),
+        egui::Color32::WHITE,
+    );
+
+    // Draw gold coin with "1" in top-right corner of bottom half (shows $1 reward for landing)
+    let coin_radius = 7.0;
+    let coin_center = egui::pos2(
+        bottom_half.right() - coin_radius - 4.0,
+        bottom_half.top() + coin_radius + 2.0,
+    );
+
+    // Gold coin colors
+    let coin_gold = Palette::GOLD.fill;
+    let coin_dark = Palette::GOLD.shade;
+
+    // Coin circle with darker border
+    painter.circle_filled(coin_center, coin_radius, coin_gold);
+    painter.circle_stroke(coin_center, coin_radius, egui::Stroke::new(1.0, coin_dark));
+
+    // "1" text on coin
+    painter.text(
+        coin_center,
+        egui::Align2::CENTER_CENTER,
+        "1",
+        egui::FontId::proportional(9.0),
+        coin_dark,
+    );
+}
+
+/// Draw a flip/sync icon (two curved arrows) for the spectator tile flip button
+pub fn draw_flip_icon(painter: &egui::Painter, center: egui::Pos2, palette: &Palette, size: f32) {
+    let color = palette.fill;
+    let r = size * 0.38; // Radius of the circular path
+    let stroke = egui::Stroke::new(size * 0.12, color);
+    let arrow_size = size * 0.15;
+
+    // Draw using line segments to approximate arcs
+    // Top arc (right half of circle, pointing right)
+    let segments = 8;
+    for i in 0..segments {
+        let angle1 =
+            std::f32::consts::PI * 0.15 + (i as f32 / segments as f32) * std::f32::consts::PI * 0.7;
+        let angle2 = std::f32::consts::PI * 0.15
+            + ((i + 1) as f32 / segments as f32) * std::f32::consts::PI * 0.7;
+        let p1 = center + egui::vec2(r * angle1.cos(), -r * angle1.sin());
+        let p2 = center + egui::vec2(r * angle2.cos(), -r * angle2.sin());
+        painter.line_segment([p1, p2], stroke);
+    }
+
+    // Bottom arc (left half of circle, pointing left)
+    for i in 0..segments {
+        let angle1 =
+            std::f32::consts::PI * 1.15 + (i as f32 / segments as f32) * std::f32::consts::PI * 0.7;
+        let angle2 = std::f32::consts::PI * 1.15
+            + ((i + 1) as f32 / segments as f32) * std::f32::consts::PI * 0.7;
+        let p1 = center + egui::vec2(r * angle1.cos(), -r * angle1.sin());
+        let p2 = center + egui::vec2(r * angle2.cos(), -r * angle2.sin());
+        painter.line_segment([p1, p2], stroke);
+    }
+
+    // Arrow head on top arc (pointing right/down)
+    let top_arrow_pos = center + egui::vec2(r * 0.85, -r * 0.5);
+    painter.line_segment(
+        [
+            top_arrow_pos,
+            top_arrow_pos + egui::vec2(-arrow_size, -arrow_size * 0.5),
+        ],
+        stroke,
+    );
+    painter.line_segment(
+        [
+            top_arrow_pos,
+            top_arrow_pos + egui::vec2(-arrow_size * 0.3, arrow_size),
+        ],
+        stroke,
+    );
+
+    // Arrow head on bottom arc (pointing left/up)
+    let bottom_arrow_pos = center + egui::vec2(-r * 0.85, r * 0.5);
+    painter.line_segment(
+        [
+            bottom_arrow_pos,
+            bottom_arrow_pos + egui::vec2(arrow_size, arrow_size * 0.5),
+        ],
+        stroke,
+    );
+    painter.line_segment(
+        [
+            bottom_arrow_pos,
+            bottom_arrow_pos + egui::vec2(arrow_size * 0.3, -arrow_size),
+        ],
+        stroke,
+    );
+
+    // Small center circle (eye/viewer symbol)
+    painter.circle_filled(center, size * 0.12, color);
+}
+
+/// Helper function to draw a race bet card (player avatar on camel color background)
+/// The palette is the camel's (see `Palette::camel`)
+pub fn draw_race_bet_card(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    palette: &Palette,
+    camel_name: &str,
+    character_id: CharacterId,
+    player_color: egui::Color32,
+    hovered: bool,
+) {
+    let color = palette.fill;
+    let border_color = palette.outline;
+
+    // Card shadow
+    let shadow_rect = rect.translate(egui::vec2(2.0, 2.0));
+    painter.rect_filled(
+        shadow_rect,
+        6.0,
+        egui::Color32::from_rgba_unmultiplied(0, 0, 0, 60),
+    );
+
+    // Card border
+    painter.rect_filled(rect.expand(2.0), 6.0, border_color);
+
+    // Card background with camel color
+    painter.rect_filled(rect, 5.0, color);
+    if let Some(camel) = palette.camel {
+        draw_color_pattern(painter, rect.shrink(3.0), camel);
+        draw_color_letter(painter, rect.min + egui::vec2(9.0, 9.0), 14.0, camel);
+    }
+
+    // Avatar in the center-top area
+    let avatar_size = rect.width() * 0.65;
+    let avatar_rect = egui::Rect::from_center_size(
+        egui::pos2(rect.center().x, rect.center().y - rect.height() * 0.08),
+        egui::vec2(avatar_size, avatar_size),
+    );
+    draw_avatar(painter, avatar_rect, character_id, Some(player_color));
+
+    // Camel name at the bottom
+    let text_color = palette.accent;
+    painter.text(
+        egui::pos2(rect.center().x, rect.max.y - 10.0),
+        egui::Align2::CENTER_CENTER,
+        camel_name,
+        egui::FontId::proportional(10.0),
+        text_color,
+    );
+
+    // Hover glow effect
+    if hovered {
+        painter.rect_stroke(
+            rect.expand(3.0),
+            6.0,
+            egui::Stroke::new(3.0, egui::Color32::GOLD),
+            egui::epaint::StrokeKind::Outside,
+        );
+    }
+}
+
+/// Helper function to draw an unavailable/used race bet card
+/// Shows a camel with crown (winner bet) or dunce cap (loser bet) instead of an X
+pub fn draw_race_bet_card_unavailable(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    palette: &Palette,
+    camel_name: &str,
+    placed_bet: PlacedBetType,
+) {
+    let color = palette.fill;
+    let faded_color = egui::Color32::from_rgba_unmultiplied(
+        color.r(),
+        color.g(),
+        color.b(),
+        100, // Slightly more visible than before since we're showing content
+    );
+    let border_color = egui::Color32::from_rgba_unmultiplied(
+        (color.r() as f32 * 0.5) as u8,
+        (color.g() as f32 * 0.5) as u8,
+        (color.b() as f32 * 0.5) as u8,
+        120,
+    );
+
+    // Faded card border
+    painter.rect_filled(rect.expand(2.0), 6.0, border_color);
+
+    // Faded card background
+    painter.rect_filled(rect, 5.0, faded_color);
+
+    // Draw camel silhouette with crown or dunce cap based on bet type
+    let icon_rect = egui::Rect::from_center_size(
+        rect.center() + egui::vec2(0.0, -5.0), // Shift up slightly to make room for label
+        egui::vec2(rect.width() * 0.7, rect.height() * 0.55),
+    );
+
+    // Use the camel's actual color (not grey) so player can see which color they bet on
+    draw_camel_silhouette(painter, icon_rect, &Palette { camel: palette.camel, ..Palette::outlined(color, palette.shade) });
+
+    // Draw the appropriate accessory based on bet type
+    match placed_bet {
+        PlacedBetType::Winner => draw_crown_overlay(painter, icon_rect, &Palette::GOLD_CROWN),
+        PlacedBetType::Loser => draw_dunce_cap_overlay(painter, icon_rect, &Palette::DUNCE_CAP),
+    }
+
+    // Camel name at the bottom
+    let text_color = egui::Color32::from_rgba_unmultiplied(80, 80, 80, 200);
+    painter.text(
+        egui::pos2(rect.center().x, rect.max.y - 10.0),
+        egui::Align2::CENTER_CENTER,
+        camel_name,
+        egui::FontId::proportional(10.0),
+        text_color,
+    );
+}
+
@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use crate::game::log::GameLog;
+use crate::ui::hud::UiState;
+use crate::ui::theme::{desert_button, DesertButtonStyle};
+
+/// Plain-text game log window
+/// Mirrors every logged event as a sentence in a read-only, selectable text box so it
+/// works with screen readers and the narrative can be copied out of the game.
+pub fn game_log_window_ui(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<UiState>,
+    game_log: Res<GameLog>,
+) {
+    if !ui_state.show_game_log {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let is_mobile = !ui_state.use_side_panels;
+    let mut open = true;
+    let log_text = game_log.to_text();
+
+    egui::Window::new("Game Log")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(!is_mobile)
+        .default_size(if is_mobile { egui::vec2(320.0, 260.0) } else { egui::vec2(480.0, 320.0) })
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            // &str is an immutable text buffer: selectable but not editable
+            let mut text = log_text.as_str();
+            egui::ScrollArea::vertical()
+                .stick_to_bottom(true)
+                .max_height(if is_mobile { 220.0 } else { 280.0 })
+                .show(ui, |ui| {
+                    // Once focused, the arrow/page keys move through the log
+                    ui.add(
+                        egui::TextEdit::multiline(&mut text)
+                            .font(egui::TextStyle::Monospace)
+                            .desired_width(f32::INFINITY)
+                            .lock_focus(false),
+                    );
+                });
+
+            ui.add_space(6.0);
+            rule_inspector(ui, &game_log, is_mobile);
+
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                if desert_button(ui, "Copy All", &DesertButtonStyle::small()).clicked() {
+                    ui.ctx().copy_text(log_text.clone());
+                }
+                ui.label(
+                    egui::RichText::new(format!("{} events", game_log.entries.len()))
+                        .size(12.0)
+                        .color(egui::Color32::GRAY),
+                );
+            });
+        });
+
+    if !open {
+        ui_state.show_game_log = false;
+    }
+}
+
+/// Most recent entries the turn history panel shows
+const TURN_HISTORY_ROWS: usize = 100;
+
+/// Turn history panel: a tab on the right edge that slides out a scrolling list of
+/// short lines, one per event, newest at the bottom
+pub fn turn_history_panel_ui(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<UiState>,
+    game_log: Res<GameLog>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let is_mobile = !ui_state.use_side_panels;
+
+    egui::Area::new(egui::Id::new("turn_history_panel"))
+        .anchor(egui::Align2::RIGHT_CENTER, egui::vec2(0.0, 0.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            ui.horizontal_centered(|ui| {
+                let tab = if ui_state.show_turn_history { "▶" } else { "◀ History" };
+                let tab_button = egui::Button::new(egui::RichText::new(tab).size(12.0).color(egui::Color32::WHITE))
+                    .fill(egui::Color32::from_rgba_unmultiplied(30, 25, 20, 220))
+                    .corner_radius(egui::CornerRadius { nw: 6, sw: 6, ne: 0, se: 0 });
+                if ui.add(tab_button).on_hover_text("Turn history").clicked() {
+                    ui_state.show_turn_history = !ui_state.show_turn_history;
+                }
+
+                if !ui_state.show_turn_history {
+                    return;
+                }
+                egui::Frame::new()
+                    .fill(egui::Color32::from_rgba_unmultiplied(30, 25, 20, 235))
+                    .inner_margin(egui::Margin::same(8))
+                    .show(ui, |ui| {
+                        let width = if is_mobile { 220.0 } else { 280.0 };
+                        ui.set_width(width);
+                        ui.label(egui::RichText::new("Turn History").size(14.0).strong().color(egui::Color32::WHITE));
+                        ui.separator();
+                        egui::ScrollArea::vertical()
+                            .stick_to_bottom(true)
+                            .max_height(if is_mobile { 240.0 } else { 360.0 })
+                            .show(ui, |ui| {
+                                let lines: Vec<String> = game_log
+                                    .entries
+                                    .iter()
+                                    .filter_map(|entry| entry.summary(&game_log.camel_names))
+                                    .collect();
+                                if lines.is_empty() {
+                                    ui.label(egui::RichText::new("Nothing has happened yet.").size(12.0).color(egui::Color32::GRAY));
+                                }
+                                for line in &lines[lines.len().saturating_sub(TURN_HISTORY_ROWS)..] {
+                                    ui.label(egui::RichText::new(line).size(12.0).color(egui::Color32::LIGHT_GRAY));
+                                }
+                            });
+                    });
+            });
+        });
+}
+
+/// "Why was this allowed?" inspector: the rule checks each action passed,
+/// for settling disputes about whether a rule was applied correctly
+fn rule_inspector(ui: &mut egui::Ui, game_log: &GameLog, is_mobile: bool) {
+    egui::CollapsingHeader::new("Why was this allowed?")
+        .id_salt("rule_inspector")
+        .show(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_salt("rule_inspector_scroll")
+                .max_height(if is_mobile { 140.0 } else { 180.0 })
+                .show(ui, |ui| {
+                    let actions = game_log
+                        .entries
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, entry)| !entry.checks.is_empty())
+                        .rev();
+                    for (index, entry) in actions {
+                        let title = format!(
+                            "Leg {}: {}",
+                            entry.leg,
+                            entry.event.describe(&game_log.camel_names)
+                        );
+                        egui::CollapsingHeader::new(title)
+                            .id_salt(("rule_checks", index))
+                            .show(ui, |ui| {
+                                for check in &entry.checks {
+                                    ui.label(
+                                        egui::RichText::new(format!("✔ {}: {}", check.rule, check.detail))
+                                            .size(12.0),
+                                    );
+                                }
+                            });
+                    }
+                });
+        });
+}
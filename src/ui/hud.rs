@@ -1,994 +1,67 @@
 use crate::components::dice::PyramidDie;
 use crate::components::{
-    BoardPosition, Camel, CamelColor, CrazyCamel, CrazyCamelColor, LegBettingTiles,
-    PlacedSpectatorTiles, Players, Pyramid, RaceBets, SpectatorTile, TRACK_LENGTH,
+    BoardPosition, Camel, CamelColor, CamelNames, CamelPalette, CrazyCamel, CrazyCamelColor, GameBoard,
+    LegBettingTiles, LegBetTile, PlacedSpectatorTiles, PlayerLegBetsStore, PlayerPyramidTokens, Players, Pyramid,
+    RaceBets, SheikhBettor, TurnState, TRACK_LENGTH,
 };
-use crate::game::state::GameState;
+use crate::game::desert_events::ActiveEvent;
+use crate::game::log::GameLog;
+use crate::game::relay::RelayRace;
+use crate::game::rules::{ActiveReminders, Reminder};
+use crate::game::core::{leg_tile_payout, PYRAMID_TOKEN_PAYOUT};
+use crate::game::settings::Settings;
+use crate::game::simulation::SimulationResult;
+use crate::network::emotes::{Emotes, ShownEmote, EMOTE_SECS};
+use crate::network::fairness::LegFairness;
+use crate::network::messages::Emote;
+use crate::network::state::NetworkState;
+use crate::systems::animation::MoneyDeltaPopup;
+use crate::systems::autoplay::AutoplayConfig;
+use crate::systems::leg::StartNextLegAction;
 use crate::systems::movement::{get_leading_camel, get_second_place_camel};
 use crate::systems::setup::PendingInitialMove;
 use crate::systems::turn::{
-    CrazyCamelRollResult, PlaceRaceBetAction, PlaceSpectatorTileAction, PlayerLegBetsStore,
-    PlayerPyramidTokens, PyramidRollResult, RollPyramidAction, TakeLegBetAction, TurnState,
+    CrazyCamelRollResult, PlaceRaceBetAction, PlaceSpectatorTileAction, PlayerActions, PyramidRollResult,
+    RollPyramidAction, TakeLegBetAction,
 };
-use crate::ui::characters::{draw_avatar, CharacterId};
-use crate::ui::player_setup::is_iphone;
+use crate::ui::draw::{
+    draw_avatar, draw_camel_silhouette, draw_camel_with_crown, draw_camel_with_dunce_cap, draw_color_letter,
+    draw_crown_overlay, draw_dunce_cap_overlay, draw_flip_icon, draw_mini_leg_bet_card, draw_mini_leg_bet_indicator,
+    draw_pyramid_button, draw_pyramid_token_icon, draw_race_bet_card, draw_race_bet_card_unavailable,
+    draw_spectator_tile_card, Palette, PlacedBetType,
+};
+use crate::ui::odds::RaceOdds;
+use crate::ui::player_setup::{is_iphone, PlayerSetupConfig};
 use crate::ui::rules::{draw_rules_ui, RulesState};
 use crate::ui::theme::{
     camel_color_to_egui, crazy_camel_color_to_egui, desert_button, desktop, draw_overlapping_stack,
-    draw_spaced_row, layout, mobile, DesertButtonStyle, PLAYER_COLORS,
+    draw_spaced_row, mobile, panel_fill, panel_stroke, quality_shadow, text_color, DesertButtonStyle,
+    GOLD_LIGHT, player_color_to_egui,
 };
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::window::{MonitorSelection, WindowMode};
 use bevy_egui::{egui, EguiContexts};
 
-/// Helper function to draw a small camel silhouette for UI elements
-/// Draws a stylized side-view camel using 4 layers (shadow, border, main, highlight)
-/// to match the polished look of the board camels
-pub fn draw_camel_silhouette(
-    painter: &egui::Painter,
-    rect: egui::Rect,
-    color: egui::Color32,
-    border_color: egui::Color32,
-) {
-    let center = rect.center();
-    let scale = (rect.width().min(rect.height()) / 30.0).min(1.0);
-
-    // Body - main rectangle
-    let body_width = 16.0 * scale;
-    let body_height = 9.0 * scale;
-    let body_center = center + egui::vec2(-2.0 * scale, 2.0 * scale);
-    let body_rect = egui::Rect::from_center_size(body_center, egui::vec2(body_width, body_height));
-
-    // Hump - on top of body
-    let hump_width = 8.0 * scale;
-    let hump_height = 7.0 * scale;
-    let hump_center = body_center + egui::vec2(-1.0 * scale, -6.0 * scale);
-    let hump_rect = egui::Rect::from_center_size(hump_center, egui::vec2(hump_width, hump_height));
-
-    // Neck - tall narrow rectangle
-    let neck_width = 4.0 * scale;
-    let neck_height = 9.0 * scale;
-    let neck_center = body_center + egui::vec2(8.0 * scale, -4.0 * scale);
-    let neck_rect = egui::Rect::from_center_size(neck_center, egui::vec2(neck_width, neck_height));
-
-    // Head - small rectangle
-    let head_width = 7.0 * scale;
-    let head_height = 5.0 * scale;
-    let head_center = neck_center + egui::vec2(3.0 * scale, -5.0 * scale);
-    let head_rect = egui::Rect::from_center_size(head_center, egui::vec2(head_width, head_height));
-
-    // Legs - four thin rectangles
-    let leg_width = 2.5 * scale;
-    let leg_height = 7.0 * scale;
-    let leg_positions = [
-        body_center + egui::vec2(-5.0 * scale, 7.0 * scale), // Back left
-        body_center + egui::vec2(-2.0 * scale, 7.0 * scale), // Back right
-        body_center + egui::vec2(4.0 * scale, 7.0 * scale),  // Front left
-        body_center + egui::vec2(7.0 * scale, 7.0 * scale),  // Front right
-    ];
-
-    // === Layer 1: SHADOW ===
-    let shadow_offset = egui::vec2(1.5 * scale, 1.5 * scale);
-    let shadow_color = egui::Color32::from_rgba_unmultiplied(0, 0, 0, 76); // ~0.3 alpha
-
-    painter.rect_filled(
-        body_rect.translate(shadow_offset),
-        1.0 * scale,
-        shadow_color,
-    );
-    painter.rect_filled(
-        hump_rect.translate(shadow_offset),
-        1.0 * scale,
-        shadow_color,
-    );
-    painter.rect_filled(
-        neck_rect.translate(shadow_offset),
-        0.5 * scale,
-        shadow_color,
-    );
-    painter.rect_filled(
-        head_rect.translate(shadow_offset),
-        1.0 * scale,
-        shadow_color,
-    );
-    for leg_pos in &leg_positions {
-        let leg_rect = egui::Rect::from_center_size(*leg_pos, egui::vec2(leg_width, leg_height));
-        painter.rect_filled(leg_rect.translate(shadow_offset), 0.5 * scale, shadow_color);
-    }
-
-    // === Layer 2: BORDER ===
-    let border_expand = 1.5 * scale;
-    painter.rect_filled(body_rect.expand(border_expand), 1.0 * scale, border_color);
-    painter.rect_filled(hump_rect.expand(border_expand), 1.0 * scale, border_color);
-    painter.rect_filled(neck_rect.expand(border_expand), 0.5 * scale, border_color);
-    painter.rect_filled(head_rect.expand(border_expand), 1.0 * scale, border_color);
-    for leg_pos in &leg_positions {
-        let leg_rect = egui::Rect::from_center_size(*leg_pos, egui::vec2(leg_width, leg_height));
-        painter.rect_filled(
-            leg_rect.expand(border_expand * 0.5),
-            0.5 * scale,
-            border_color,
-        );
-    }
-
-    // === Layer 3: MAIN COLOR ===
-    painter.rect_filled(body_rect, 1.0 * scale, color);
-    painter.rect_filled(hump_rect, 1.0 * scale, color);
-    painter.rect_filled(neck_rect, 0.5 * scale, color);
-    painter.rect_filled(head_rect, 1.0 * scale, color);
-    for leg_pos in &leg_positions {
-        let leg_rect = egui::Rect::from_center_size(*leg_pos, egui::vec2(leg_width, leg_height));
-        painter.rect_filled(leg_rect, 0.5 * scale, color);
-    }
-
-    // === Layer 4: HIGHLIGHT ===
-    let highlight_color = egui::Color32::from_rgba_unmultiplied(255, 255, 255, 102); // ~0.4 alpha
-
-    // Highlight strip on hump
-    let hump_highlight_rect = egui::Rect::from_center_size(
-        hump_center + egui::vec2(0.0, -2.0 * scale),
-        egui::vec2((hump_width - 2.0 * scale).max(2.0), 2.0 * scale),
-    );
-    painter.rect_filled(hump_highlight_rect, 0.5 * scale, highlight_color);
-
-    // Highlight strip on head
-    let head_highlight_rect = egui::Rect::from_center_size(
-        head_center + egui::vec2(0.0, -1.5 * scale),
-        egui::vec2((head_width - 2.0 * scale).max(2.0), 1.5 * scale),
-    );
-    painter.rect_filled(head_highlight_rect, 0.5 * scale, highlight_color);
-
-    // === Eye ===
-    let eye_pos = head_center + egui::vec2(1.5 * scale, -0.5 * scale);
-    painter.circle_filled(eye_pos, 1.0 * scale, egui::Color32::from_rgb(30, 30, 30));
-}
-
-/// Draws a grey camel with a gold crown on its head (winner icon)
-pub fn draw_camel_with_crown(painter: &egui::Painter, rect: egui::Rect) {
-    let camel_color = egui::Color32::from_rgb(140, 140, 140); // Grey camel
-    let border_color = egui::Color32::from_rgb(80, 80, 80);
-
-    // Draw the camel first
-    draw_camel_silhouette(painter, rect, camel_color, border_color);
-
-    // Calculate crown position based on rect (on head)
-    let center = rect.center();
-    let scale = (rect.width().min(rect.height()) / 30.0).min(1.0);
-    let body_center = center + egui::vec2(-2.0 * scale, 2.0 * scale);
-    let neck_center = body_center + egui::vec2(8.0 * scale, -4.0 * scale);
-    let head_center = neck_center + egui::vec2(3.0 * scale, -5.0 * scale);
-
-    // Crown position - on top of head
-    let crown_center = head_center + egui::vec2(-1.0 * scale, -5.0 * scale);
-    let crown_width = 8.0 * scale;
-    let crown_height = 4.0 * scale;
-
-    // Crown colors
-    let gold = egui::Color32::from_rgb(255, 215, 0);
-    let gold_dark = egui::Color32::from_rgb(200, 160, 0);
-
-    // Crown base (rectangle)
-    let base_rect = egui::Rect::from_center_size(
-        crown_center + egui::vec2(0.0, 1.5 * scale),
-        egui::vec2(crown_width, crown_height * 0.5),
-    );
-    painter.rect_filled(base_rect, 1.0 * scale, gold);
-
-    // Crown points (3 triangles)
-    let point_height = 4.0 * scale;
-    let point_width = 2.5 * scale;
-    let point_y = crown_center.y - 1.0 * scale;
-
-    for i in 0..3 {
-        let x_offset = (i as f32 - 1.0) * 2.5 * scale;
-        let point_center = egui::pos2(crown_center.x + x_offset, point_y);
-
-        // Triangle for crown point
-        let points = vec![
-            egui::pos2(point_center.x, point_center.y - point_height), // Top
-            egui::pos2(point_center.x - point_width / 2.0, point_center.y), // Bottom left
-            egui::pos2(point_center.x + point_width / 2.0, point_center.y), // Bottom right
-        ];
-        painter.add(egui::Shape::convex_polygon(
-            points,
-            gold,
-            egui::Stroke::new(0.5 * scale, gold_dark),
-        ));
-    }
-
-    // Small gems on crown points
-    let gem_colors = [
-        egui::Color32::from_rgb(220, 50, 50),  // Red
-        egui::Color32::from_rgb(50, 100, 220), // Blue
-        egui::Color32::from_rgb(220, 50, 50),  // Red
-    ];
-    for i in 0..3 {
-        let x_offset = (i as f32 - 1.0) * 2.5 * scale;
-        let gem_pos = egui::pos2(crown_center.x + x_offset, point_y - 2.0 * scale);
-        painter.circle_filled(gem_pos, 1.0 * scale, gem_colors[i]);
-    }
-}
-
-/// Draws a grey camel with a dunce cap on its head (loser icon)
-pub fn draw_camel_with_dunce_cap(painter: &egui::Painter, rect: egui::Rect) {
-    let camel_color = egui::Color32::from_rgb(140, 140, 140); // Grey camel
-    let border_color = egui::Color32::from_rgb(80, 80, 80);
-
-    // Draw the camel first
-    draw_camel_silhouette(painter, rect, camel_color, border_color);
-
-    // Draw dunce cap overlay
-    draw_dunce_cap_overlay(painter, rect);
-}
-
-/// Draws just a crown overlay on top of a camel silhouette
-/// The rect should be the same rect used for draw_camel_silhouette
-pub fn draw_crown_overlay(painter: &egui::Painter, rect: egui::Rect) {
-    // Calculate crown position based on rect (on head, right side of camel)
-    let center = rect.center();
-    let scale = (rect.width().min(rect.height()) / 30.0).min(1.0);
-    let body_center = center + egui::vec2(-2.0 * scale, 2.0 * scale);
-    let neck_center = body_center + egui::vec2(8.0 * scale, -4.0 * scale);
-    let head_center = neck_center + egui::vec2(3.0 * scale, -5.0 * scale);
-
-    // Crown position - on top of head
-    let crown_center = head_center + egui::vec2(0.0, -5.0 * scale);
-    let crown_width = 8.0 * scale;
-    let crown_height = 4.0 * scale;
-
-    // Crown colors
-    let gold = egui::Color32::from_rgb(255, 215, 0);
-    let gold_dark = egui::Color32::from_rgb(200, 160, 0);
-
-    // Crown base (rectangle)
-    let base_rect = egui::Rect::from_center_size(
-        crown_center + egui::vec2(0.0, 1.5 * scale),
-        egui::vec2(crown_width, crown_height * 0.5),
-    );
-    painter.rect_filled(base_rect, 1.0 * scale, gold);
-
-    // Crown points (3 triangles)
-    let point_height = 4.0 * scale;
-    let point_width = 2.5 * scale;
-    let point_y = crown_center.y - 1.0 * scale;
-
-    for i in 0..3 {
-        let x_offset = (i as f32 - 1.0) * 2.5 * scale;
-        let point_center = egui::pos2(crown_center.x + x_offset, point_y);
-
-        // Triangle for crown point
-        let points = vec![
-            egui::pos2(point_center.x, point_center.y - point_height), // Top
-            egui::pos2(point_center.x - point_width / 2.0, point_center.y), // Bottom left
-            egui::pos2(point_center.x + point_width / 2.0, point_center.y), // Bottom right
-        ];
-        painter.add(egui::Shape::convex_polygon(
-            points,
-            gold,
-            egui::Stroke::new(0.5 * scale, gold_dark),
-        ));
-    }
-
-    // Small gems on crown points
-    let gem_colors = [
-        egui::Color32::from_rgb(220, 50, 50),  // Red
-        egui::Color32::from_rgb(50, 100, 220), // Blue
-        egui::Color32::from_rgb(220, 50, 50),  // Red
-    ];
-    for i in 0..3 {
-        let x_offset = (i as f32 - 1.0) * 2.5 * scale;
-        let gem_pos = egui::pos2(crown_center.x + x_offset, point_y - 2.0 * scale);
-        painter.circle_filled(gem_pos, 1.0 * scale, gem_colors[i]);
-    }
-}
-
-/// Draws a silver crown overlay on top of a camel silhouette (2nd place)
-/// The rect should be the same rect used for draw_camel_silhouette
-pub fn draw_silver_crown_overlay(painter: &egui::Painter, rect: egui::Rect) {
-    // Calculate crown position based on rect (on head, right side of camel)
-    let center = rect.center();
-    let scale = (rect.width().min(rect.height()) / 30.0).min(1.0);
-    let body_center = center + egui::vec2(-2.0 * scale, 2.0 * scale);
-    let neck_center = body_center + egui::vec2(8.0 * scale, -4.0 * scale);
-    let head_center = neck_center + egui::vec2(3.0 * scale, -5.0 * scale);
-
-    // Crown position - on top of head
-    let crown_center = head_center + egui::vec2(0.0, -5.0 * scale);
-    let crown_width = 8.0 * scale;
-    let crown_height = 4.0 * scale;
-
-    // Silver crown colors
-    let silver = egui::Color32::from_rgb(200, 200, 210);
-    let silver_dark = egui::Color32::from_rgb(140, 140, 150);
-
-    // Crown base (rectangle)
-    let base_rect = egui::Rect::from_center_size(
-        crown_center + egui::vec2(0.0, 1.5 * scale),
-        egui::vec2(crown_width, crown_height * 0.5),
-    );
-    painter.rect_filled(base_rect, 1.0 * scale, silver);
-
-    // Crown points (3 triangles)
-    let point_height = 4.0 * scale;
-    let point_width = 2.5 * scale;
-    let point_y = crown_center.y - 1.0 * scale;
-
-    for i in 0..3 {
-        let x_offset = (i as f32 - 1.0) * 2.5 * scale;
-        let point_center = egui::pos2(crown_center.x + x_offset, point_y);
-
-        // Triangle for crown point
-        let points = vec![
-            egui::pos2(point_center.x, point_center.y - point_height), // Top
-            egui::pos2(point_center.x - point_width / 2.0, point_center.y), // Bottom left
-            egui::pos2(point_center.x + point_width / 2.0, point_center.y), // Bottom right
-        ];
-        painter.add(egui::Shape::convex_polygon(
-            points,
-            silver,
-            egui::Stroke::new(0.5 * scale, silver_dark),
-        ));
-    }
-
-    // Small gems on crown points - light blue and pearl for silver
-    let gem_colors = [
-        egui::Color32::from_rgb(100, 180, 220), // Light blue
-        egui::Color32::from_rgb(180, 180, 200), // Pearl
-        egui::Color32::from_rgb(100, 180, 220), // Light blue
-    ];
-    for i in 0..3 {
-        let x_offset = (i as f32 - 1.0) * 2.5 * scale;
-        let gem_pos = egui::pos2(crown_center.x + x_offset, point_y - 2.0 * scale);
-        painter.circle_filled(gem_pos, 1.0 * scale, gem_colors[i]);
-    }
-}
-
-/// Draws just a dunce cap overlay on top of a camel silhouette
-/// The rect should be the same rect used for draw_camel_silhouette
-pub fn draw_dunce_cap_overlay(painter: &egui::Painter, rect: egui::Rect) {
-    // Calculate cap position based on rect (on head)
-    let center = rect.center();
-    let scale = (rect.width().min(rect.height()) / 30.0).min(1.0);
-    let body_center = center + egui::vec2(-2.0 * scale, 2.0 * scale);
-    let neck_center = body_center + egui::vec2(8.0 * scale, -4.0 * scale);
-    let head_center = neck_center + egui::vec2(3.0 * scale, -5.0 * scale);
-
-    // Dunce cap position - on top of head
-    let cap_base = head_center + egui::vec2(0.0, -3.0 * scale);
-    let cap_height = 10.0 * scale;
-    let cap_width = 6.0 * scale;
-
-    // Cap colors - muted/subdued
-    let cap_color = egui::Color32::from_rgb(100, 100, 110); // Muted grey-blue
-    let cap_outline = egui::Color32::from_rgb(60, 60, 70);
-
-    // Dunce cap triangle
-    let points = vec![
-        egui::pos2(cap_base.x, cap_base.y - cap_height), // Top point
-        egui::pos2(cap_base.x - cap_width / 2.0, cap_base.y), // Bottom left
-        egui::pos2(cap_base.x + cap_width / 2.0, cap_base.y), // Bottom right
-    ];
-    painter.add(egui::Shape::convex_polygon(
-        points,
-        cap_color,
-        egui::Stroke::new(0.8 * scale, cap_outline),
-    ));
-
-    // Chin strap
-    let strap_color = egui::Color32::from_rgb(70, 70, 70);
-    let chin_left = head_center + egui::vec2(-3.0 * scale, 2.0 * scale);
-    let chin_right = head_center + egui::vec2(3.0 * scale, 2.0 * scale);
-    painter.line_segment(
-        [chin_left, chin_right],
-        egui::Stroke::new(0.5 * scale, strap_color),
-    );
-}
-
-/// Indicates what type of race bet was placed for displaying on unavailable cards
-#[derive(Clone, Copy)]
-enum PlacedBetType {
-    Winner,
-    Loser,
-}
-
-/// Helper function to draw a mini leg bet card (camel silhouette on top, value on bottom)
-pub fn draw_mini_leg_bet_card(
-    painter: &egui::Painter,
-    rect: egui::Rect,
-    camel_color: CamelColor,
-    value: u8,
-) {
-    let color = camel_color_to_egui(camel_color);
-    let border_color = egui::Color32::from_rgb(
-        (color.r() as f32 * 0.5) as u8,
-        (color.g() as f32 * 0.5) as u8,
-        (color.b() as f32 * 0.5) as u8,
-    );
-
-    // Card border/shadow
-    painter.rect_filled(rect.expand(1.0), 3.0, egui::Color32::from_rgb(60, 50, 40));
-    painter.rect_filled(rect, 2.0, egui::Color32::from_rgb(245, 235, 215));
-
-    // Split into top (camel) and bottom (value)
-    let top_half =
-        egui::Rect::from_min_max(rect.min, egui::pos2(rect.max.x, rect.center().y + 2.0));
-    let bottom_half =
-        egui::Rect::from_min_max(egui::pos2(rect.min.x, rect.center().y + 2.0), rect.max);
-
-    // Top half - cream for camel
-    painter.rect_filled(
-        top_half.shrink(1.0),
-        1.0,
-        egui::Color32::from_rgb(250, 245, 230),
-    );
-
-    // Draw camel silhouette
-    let camel_rect = egui::Rect::from_min_size(
-        top_half.min + egui::vec2(2.0, 2.0),
-        egui::vec2(top_half.width() - 4.0, top_half.height() - 4.0),
-    );
-    draw_camel_silhouette(painter, camel_rect, color, border_color);
-
-    // Bottom half - cream background with gold coin
-    painter.rect_filled(
-        bottom_half.shrink(1.0),
-        1.0,
-        egui::Color32::from_rgb(250, 245, 230),
-    );
-
-    // Draw gold coin with value
-    let coin_center = bottom_half.center();
-    let coin_radius = (bottom_half.height() * 0.38).min(bottom_half.width() * 0.38);
-
-    // Gold colors (matching pyramid token)
-    let gold_light = egui::Color32::from_rgb(0xD4, 0xA8, 0x4B);
-    let gold_dark = egui::Color32::from_rgb(0xA0, 0x7A, 0x30);
-    let gold_outline = egui::Color32::from_rgb(0x6B, 0x4A, 0x1A);
-
-    // Outer shadow/depth
-    painter.circle_filled(
-        coin_center + egui::vec2(1.0, 1.0),
-        coin_radius,
-        gold_outline,
-    );
-    // Main coin body
-    painter.circle_filled(coin_center, coin_radius, gold_light);
-    // Inner shadow ring for depth
-    painter.circle_stroke(
-        coin_center,
-        coin_radius * 0.85,
-        egui::Stroke::new(1.0, gold_dark),
-    );
-    // Outer edge
-    painter.circle_stroke(
-        coin_center,
-        coin_radius,
-        egui::Stroke::new(1.5, gold_outline),
-    );
-
-    // Value text on coin
-    painter.text(
-        coin_center,
-        egui::Align2::CENTER_CENTER,
-        format!("{}", value),
-        egui::FontId::proportional(coin_radius * 0.9),
-        gold_outline,
-    );
-}
-
-/// Helper function to draw a tiny leg bet indicator for player's bet collection
-/// Smaller than draw_mini_leg_bet_card, designed for overlapping display
-pub fn draw_mini_leg_bet_indicator(
-    painter: &egui::Painter,
-    rect: egui::Rect,
-    camel_color: CamelColor,
-    value: u8,
-) {
-    let color = camel_color_to_egui(camel_color);
-
-    // Card border/shadow
-    painter.rect_filled(rect.expand(0.5), 1.5, egui::Color32::from_rgb(50, 40, 35));
-    painter.rect_filled(rect, 1.0, egui::Color32::from_rgb(245, 235, 215));
-
-    // Top half - camel color block
-    let top_half = egui::Rect::from_min_max(rect.min, egui::pos2(rect.max.x, rect.center().y));
-    painter.rect_filled(top_half.shrink(0.5), 0.5, color);
-
-    // Bottom half - value
-    let bottom_center = egui::pos2(rect.center().x, rect.max.y - rect.height() * 0.25);
-    painter.text(
-        bottom_center,
-        egui::Align2::CENTER_CENTER,
-        format!("{}", value),
-        egui::FontId::proportional(rect.height() * 0.35),
-        egui::Color32::from_rgb(100, 70, 30),
-    );
-}
-
-/// Helper function to draw a small pyramid token icon
-/// Returns the rect used for the icon
-pub fn draw_pyramid_token_icon(painter: &egui::Painter, center: egui::Pos2, size: f32) {
-    let pyramid_height = size * 0.8;
-    let pyramid_width = size * 0.7;
-
-    let apex = egui::pos2(center.x, center.y - pyramid_height / 2.0);
-    let base_left = egui::pos2(
-        center.x - pyramid_width / 2.0,
-        center.y + pyramid_height / 2.0,
-    );
-    let base_right = egui::pos2(
-        center.x + pyramid_width / 2.0,
-        center.y + pyramid_height / 2.0,
-    );
-    let mid_base = egui::pos2(center.x, center.y + pyramid_height / 2.0);
-
-    // Gold/sand colored pyramid token
-    let pyramid_color = egui::Color32::from_rgb(0xD4, 0xA8, 0x4B);
-    let shadow_color = egui::Color32::from_rgb(0xA0, 0x7A, 0x30);
-    let outline_color = egui::Color32::from_rgb(0x6B, 0x4A, 0x1A);
-
-    // Draw shadow/depth on left side
-    painter.add(egui::Shape::convex_polygon(
-        vec![apex, base_left, mid_base],
-        shadow_color,
-        egui::Stroke::NONE,
-    ));
-
-    // Draw lit side on right
-    painter.add(egui::Shape::convex_polygon(
-        vec![apex, mid_base, base_right],
-        pyramid_color,
-        egui::Stroke::NONE,
-    ));
-
-    // Draw outline
-    painter.add(egui::Shape::closed_line(
-        vec![apex, base_left, base_right],
-        egui::Stroke::new(1.0, outline_color),
-    ));
-
-    // Draw "$1" on the token
-    painter.text(
-        egui::pos2(center.x, center.y + 2.0),
-        egui::Align2::CENTER_CENTER,
-        "$1",
-        egui::FontId::proportional(size * 0.28),
-        outline_color,
-    );
-}
-
-/// Draw an interactive pyramid button with optional flip animation
-/// flip_progress: 0.0 = not animating, 0.01-1.0 = flip in progress
-pub fn draw_pyramid_button(
-    ui: &mut egui::Ui,
-    size: egui::Vec2,
-    flip_progress: f32,
-) -> egui::Response {
-    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click());
-    let painter = ui.painter();
-
-    // Pyramid colors
-    let pyramid_light = egui::Color32::from_rgb(0xD4, 0xA8, 0x4B);
-    let pyramid_dark = egui::Color32::from_rgb(0xA0, 0x7A, 0x30);
-    let outline_color = egui::Color32::from_rgb(0x6B, 0x4A, 0x1A);
-
-    // Hover effect - brighten colors
-    let (light, dark) = if response.hovered() {
-        (
-            egui::Color32::from_rgb(0xE4, 0xB8, 0x5B),
-            egui::Color32::from_rgb(0xB0, 0x8A, 0x40),
-        )
-    } else {
-        (pyramid_light, pyramid_dark)
-    };
-
-    // Calculate rotation angle for shake (oscillates with decay)
-    let rotation_angle = if flip_progress > 0.0 {
-        let shake_intensity = 0.15; // radians max rotation (~8.5 degrees)
-        let shake_frequency = 4.0; // number of full oscillations
-        let decay = 1.0 - flip_progress; // decreases over time
-        (flip_progress * shake_frequency * std::f32::consts::TAU).sin() * shake_intensity * decay
-    } else {
-        0.0
-    };
-
-    // Helper to rotate a point around a center
-    let rotate_point = |point: egui::Pos2, center: egui::Pos2, angle: f32| -> egui::Pos2 {
-        let cos_a = angle.cos();
-        let sin_a = angle.sin();
-        let dx = point.x - center.x;
-        let dy = point.y - center.y;
-        egui::pos2(
-            center.x + dx * cos_a - dy * sin_a,
-            center.y + dx * sin_a + dy * cos_a,
-        )
-    };
-
-    // Define pyramid points (unrotated)
-    let center = rect.center();
-    let apex_base = egui::pos2(rect.center().x, rect.top() + 4.0);
-    let base_left_base = egui::pos2(rect.left() + 4.0, rect.bottom() - 4.0);
-    let base_right_base = egui::pos2(rect.right() - 4.0, rect.bottom() - 4.0);
-    let mid_base_base = egui::pos2(rect.center().x, rect.bottom() - 4.0);
-
-    // Apply rotation
-    let apex = rotate_point(apex_base, center, rotation_angle);
-    let base_left = rotate_point(base_left_base, center, rotation_angle);
-    let base_right = rotate_point(base_right_base, center, rotation_angle);
-    let mid_base = rotate_point(mid_base_base, center, rotation_angle);
-
-    // Left (shadow) side
-    painter.add(egui::Shape::convex_polygon(
-        vec![apex, base_left, mid_base],
-        dark,
-        egui::Stroke::NONE,
-    ));
-
-    // Right (lit) side
-    painter.add(egui::Shape::convex_polygon(
-        vec![apex, mid_base, base_right],
-        light,
-        egui::Stroke::NONE,
-    ));
-
-    // Outline
-    painter.add(egui::Shape::closed_line(
-        vec![apex, base_left, base_right],
-        egui::Stroke::new(1.5, outline_color),
-    ));
-
-    // Draw "Roll" text and "+$1" (rotate with pyramid)
-    let roll_pos_base = egui::pos2(center.x, center.y - 4.0);
-    let cost_pos_base = egui::pos2(center.x, center.y + size.y * 0.2);
-    let roll_pos = rotate_point(roll_pos_base, center, rotation_angle);
-    let cost_pos = rotate_point(cost_pos_base, center, rotation_angle);
-
-    painter.text(
-        roll_pos,
-        egui::Align2::CENTER_CENTER,
-        "Roll",
-        egui::FontId::proportional(size.y * 0.18),
-        outline_color,
-    );
-    painter.text(
-        cost_pos,
-        egui::Align2::CENTER_CENTER,
-        "+$1",
-        egui::FontId::proportional(size.y * 0.14),
-        outline_color,
-    );
-
-    response
-}
-
-/// Helper function to draw a spectator tile card with player avatar on top and +1/-1 on bottom
-/// flip_progress: 0.0 = front fully visible, 1.0 = back fully visible
-/// Uses clip-rect approach for unified flip effect - all elements clipped identically
-pub fn draw_spectator_tile_card(
-    painter: &egui::Painter,
-    rect: egui::Rect,
-    character_id: CharacterId,
-    player_color: egui::Color32,
-    is_oasis: bool,
-    flip_progress: f32,
-) {
-    // Calculate horizontal scale for flip effect (simulates 3D rotation)
-    let (show_front, scale_x) = if flip_progress <= 0.5 {
-        // First half: showing front, shrinking
-        let t = flip_progress * 2.0; // 0.0 to 1.0
-        (true, 1.0 - t)
-    } else {
-        // Second half: showing back, growing
-        let t = (flip_progress - 0.5) * 2.0; // 0.0 to 1.0
-        (false, t)
-    };
-
-    // If scale is too small, don't draw (card is edge-on)
-    if scale_x < 0.02 {
-        return;
-    }
-
-    // Determine which side to show based on is_oasis and show_front
-    let showing_oasis = if show_front { is_oasis } else { !is_oasis };
-
-    // Create clip rect - a narrowing horizontal strip centered on the card
-    // This creates a uniform flip effect where all elements are clipped identically
-    let center_x = rect.center().x;
-    let visible_half_width = rect.width() * scale_x / 2.0;
-    let clip_rect = egui::Rect::from_x_y_ranges(
-        center_x - visible_half_width..=center_x + visible_half_width,
-        rect.top()..=rect.bottom(),
-    );
-
-    // Create a clipped painter - all drawing will be clipped to the narrowing strip
-    let clipped_painter = painter.with_clip_rect(clip_rect);
-
-    // Draw full card content at normal size - clipping creates the flip effect
-    draw_spectator_tile_content(
-        &clipped_painter,
-        rect,
-        character_id,
-        player_color,
-        showing_oasis,
-    );
-}
-
-/// Helper function to draw spectator tile card content at full size (used with clipping for flip effect)
-fn draw_spectator_tile_content(
-    painter: &egui::Painter,
-    rect: egui::Rect,
-    character_id: CharacterId,
-    player_color: egui::Color32,
-    showing_oasis: bool,
-) {
-    // Colors for each side
-    let (bg_color, _value_color, value_text) = if showing_oasis {
-        // Oasis (+1): Green/lush colors
-        (
-            egui::Color32::from_rgb(80, 160, 80), // Green
-            egui::Color32::from_rgb(50, 120, 50), // Dark green
-            "+1",
-        )
-    } else {
-        // Mirage (-1): Sandy/orange colors
-        (
-            egui::Color32::from_rgb(200, 150, 80), // Sandy
-            egui::Color32::from_rgb(160, 100, 40), // Dark sandy
-            "-1",
-        )
-    };
-
-    // Card shadow
-    painter.rect_filled(rect.expand(2.0), 5.0, egui::Color32::from_rgb(40, 30, 20));
-
-    // Card background
-    painter.rect_filled(rect, 4.0, egui::Color32::from_rgb(245, 235, 215));
-
-    // Split into top (avatar) and bottom (value)
-    let top_half =
-        egui::Rect::from_min_max(rect.min, egui::pos2(rect.max.x, rect.center().y + 4.0));
-    let bottom_half =
-        egui::Rect::from_min_max(egui::pos2(rect.min.x, rect.center().y + 4.0), rect.max);
-
-    // Top half - cream background with avatar
-    painter.rect_filled(
-        top_half.shrink(2.0),
-        2.0,
-        egui::Color32::from_rgb(250, 245, 230),
-    );
-
-    // Draw player avatar in top portion at full size
-    let avatar_size = (top_half.height() - 8.0).min(top_half.width() - 8.0);
-    let avatar_rect =
-        egui::Rect::from_center_size(top_half.center(), egui::vec2(avatar_size, avatar_size));
-    draw_avatar(painter, avatar_rect, character_id, Some(player_color));
-
-    // Bottom half - colored band with value
-    painter.rect_filled(bottom_half.shrink2(egui::vec2(2.0, 0.0)), 2.0, bg_color);
-
-    // Draw +1 or -1 text (slightly left of center to make room for coin)
-    let text_offset = -rect.width() * 0.12;
-    painter.text(
-        bottom_half.center() + egui::vec2(text_offset, 0.0),
-        egui::Align2::CENTER_CENTER,
-        value_text,
-        egui::FontId::proportional(16.0),
-        egui::Color32::WHITE,
-    );
-
-    // Draw gold coin with "1" in top-right corner of bottom half (shows $1 reward for landing)
-    let coin_radius = 7.0;
-    let coin_center = egui::pos2(
-        bottom_half.right() - coin_radius - 4.0,
-        bottom_half.top() + coin_radius + 2.0,
-    );
-
-    // Gold coin colors
-    let coin_gold = egui::Color32::from_rgb(0xD4, 0xA8, 0x4B);
-    let coin_dark = egui::Color32::from_rgb(0xA0, 0x7A, 0x30);
-
-    // Coin circle with darker border
-    painter.circle_filled(coin_center, coin_radius, coin_gold);
-    painter.circle_stroke(coin_center, coin_radius, egui::Stroke::new(1.0, coin_dark));
-
-    // "1" text on coin
-    painter.text(
-        coin_center,
-        egui::Align2::CENTER_CENTER,
-        "1",
-        egui::FontId::proportional(9.0),
-        coin_dark,
-    );
-}
-
-/// Draw a flip/sync icon (two curved arrows) for the spectator tile flip button
-fn draw_flip_icon(painter: &egui::Painter, center: egui::Pos2, size: f32, color: egui::Color32) {
-    let r = size * 0.38; // Radius of the circular path
-    let stroke = egui::Stroke::new(size * 0.12, color);
-    let arrow_size = size * 0.15;
-
-    // Draw using line segments to approximate arcs
-    // Top arc (right half of circle, pointing right)
-    let segments = 8;
-    for i in 0..segments {
-        let angle1 =
-            std::f32::consts::PI * 0.15 + (i as f32 / segments as f32) * std::f32::consts::PI * 0.7;
-        let angle2 = std::f32::consts::PI * 0.15
-            + ((i + 1) as f32 / segments as f32) * std::f32::consts::PI * 0.7;
-        let p1 = center + egui::vec2(r * angle1.cos(), -r * angle1.sin());
-        let p2 = center + egui::vec2(r * angle2.cos(), -r * angle2.sin());
-        painter.line_segment([p1, p2], stroke);
-    }
-
-    // Bottom arc (left half of circle, pointing left)
-    for i in 0..segments {
-        let angle1 =
-            std::f32::consts::PI * 1.15 + (i as f32 / segments as f32) * std::f32::consts::PI * 0.7;
-        let angle2 = std::f32::consts::PI * 1.15
-            + ((i + 1) as f32 / segments as f32) * std::f32::consts::PI * 0.7;
-        let p1 = center + egui::vec2(r * angle1.cos(), -r * angle1.sin());
-        let p2 = center + egui::vec2(r * angle2.cos(), -r * angle2.sin());
-        painter.line_segment([p1, p2], stroke);
-    }
-
-    // Arrow head on top arc (pointing right/down)
-    let top_arrow_pos = center + egui::vec2(r * 0.85, -r * 0.5);
-    painter.line_segment(
-        [
-            top_arrow_pos,
-            top_arrow_pos + egui::vec2(-arrow_size, -arrow_size * 0.5),
-        ],
-        stroke,
-    );
-    painter.line_segment(
-        [
-            top_arrow_pos,
-            top_arrow_pos + egui::vec2(-arrow_size * 0.3, arrow_size),
-        ],
-        stroke,
-    );
-
-    // Arrow head on bottom arc (pointing left/up)
-    let bottom_arrow_pos = center + egui::vec2(-r * 0.85, r * 0.5);
-    painter.line_segment(
-        [
-            bottom_arrow_pos,
-            bottom_arrow_pos + egui::vec2(arrow_size, arrow_size * 0.5),
-        ],
-        stroke,
-    );
-    painter.line_segment(
-        [
-            bottom_arrow_pos,
-            bottom_arrow_pos + egui::vec2(arrow_size * 0.3, -arrow_size),
-        ],
-        stroke,
-    );
-
-    // Small center circle (eye/viewer symbol)
-    painter.circle_filled(center, size * 0.12, color);
-}
-
-/// Helper function to draw a race bet card (player avatar on camel color background)
-fn draw_race_bet_card(
-    painter: &egui::Painter,
-    rect: egui::Rect,
-    camel_color: CamelColor,
-    character_id: CharacterId,
-    player_color: egui::Color32,
-    hovered: bool,
-) {
-    let color = camel_color_to_egui(camel_color);
-    let border_color = egui::Color32::from_rgb(
-        (color.r() as f32 * 0.5) as u8,
-        (color.g() as f32 * 0.5) as u8,
-        (color.b() as f32 * 0.5) as u8,
-    );
-
-    // Card shadow
-    let shadow_rect = rect.translate(egui::vec2(2.0, 2.0));
-    painter.rect_filled(
-        shadow_rect,
-        6.0,
-        egui::Color32::from_rgba_unmultiplied(0, 0, 0, 60),
-    );
-
-    // Card border
-    painter.rect_filled(rect.expand(2.0), 6.0, border_color);
-
-    // Card background with camel color
-    painter.rect_filled(rect, 5.0, color);
-
-    // Avatar in the center-top area
-    let avatar_size = rect.width() * 0.65;
-    let avatar_rect = egui::Rect::from_center_size(
-        egui::pos2(rect.center().x, rect.center().y - rect.height() * 0.08),
-        egui::vec2(avatar_size, avatar_size),
-    );
-    draw_avatar(painter, avatar_rect, character_id, Some(player_color));
-
-    // Camel name at the bottom
-    let text_color = if camel_color == CamelColor::Yellow {
-        egui::Color32::BLACK
-    } else {
-        egui::Color32::WHITE
-    };
-    painter.text(
-        egui::pos2(rect.center().x, rect.max.y - 10.0),
-        egui::Align2::CENTER_CENTER,
-        format!("{:?}", camel_color),
-        egui::FontId::proportional(10.0),
-        text_color,
+/// Paint a small amber hint chip centered on `anchor`
+fn draw_reminder_chip(painter: &egui::Painter, anchor: egui::Pos2, text: &str, font_size: f32) {
+    let galley = painter.layout_no_wrap(
+        text.to_string(),
+        egui::FontId::proportional(font_size),
+        REMINDER_TEXT,
     );
-
-    // Hover glow effect
-    if hovered {
-        painter.rect_stroke(
-            rect.expand(3.0),
-            6.0,
-            egui::Stroke::new(3.0, egui::Color32::GOLD),
-            egui::epaint::StrokeKind::Outside,
-        );
-    }
+    let chip_rect = egui::Rect::from_center_size(anchor, galley.size() + egui::vec2(8.0, 4.0));
+    painter.rect_filled(chip_rect, chip_rect.height() / 2.0, REMINDER_FILL);
+    painter.galley(chip_rect.center() - galley.size() / 2.0, galley, REMINDER_TEXT);
 }
 
-/// Helper function to draw an unavailable/used race bet card
-/// Shows a camel with crown (winner bet) or dunce cap (loser bet) instead of an X
-fn draw_race_bet_card_unavailable(
-    painter: &egui::Painter,
-    rect: egui::Rect,
-    camel_color: CamelColor,
-    placed_bet: PlacedBetType,
-) {
-    let color = camel_color_to_egui(camel_color);
-    let faded_color = egui::Color32::from_rgba_unmultiplied(
-        color.r(),
-        color.g(),
-        color.b(),
-        100, // Slightly more visible than before since we're showing content
-    );
-    let border_color = egui::Color32::from_rgba_unmultiplied(
-        (color.r() as f32 * 0.5) as u8,
-        (color.g() as f32 * 0.5) as u8,
-        (color.b() as f32 * 0.5) as u8,
-        120,
-    );
-
-    // Faded card border
-    painter.rect_filled(rect.expand(2.0), 6.0, border_color);
-
-    // Faded card background
-    painter.rect_filled(rect, 5.0, faded_color);
-
-    // Draw camel silhouette with crown or dunce cap based on bet type
-    let icon_rect = egui::Rect::from_center_size(
-        rect.center() + egui::vec2(0.0, -5.0), // Shift up slightly to make room for label
-        egui::vec2(rect.width() * 0.7, rect.height() * 0.55),
-    );
-
-    // Use the camel's actual color (not grey) so player can see which color they bet on
-    let camel_border = egui::Color32::from_rgb(
-        (color.r() as f32 * 0.6) as u8,
-        (color.g() as f32 * 0.6) as u8,
-        (color.b() as f32 * 0.6) as u8,
-    );
-    draw_camel_silhouette(painter, icon_rect, color, camel_border);
-
-    // Draw the appropriate accessory based on bet type
-    match placed_bet {
-        PlacedBetType::Winner => draw_crown_overlay(painter, icon_rect),
-        PlacedBetType::Loser => draw_dunce_cap_overlay(painter, icon_rect),
-    }
+const REMINDER_FILL: egui::Color32 = egui::Color32::from_rgba_premultiplied(230, 180, 80, 230);
+const REMINDER_TEXT: egui::Color32 = egui::Color32::from_rgb(60, 40, 10);
 
-    // Camel name at the bottom
-    let text_color = egui::Color32::from_rgba_unmultiplied(80, 80, 80, 200);
-    painter.text(
-        egui::pos2(rect.center().x, rect.max.y - 10.0),
-        egui::Align2::CENTER_CENTER,
-        format!("{:?}", camel_color),
-        egui::FontId::proportional(10.0),
-        text_color,
-    );
+/// Expected value of a bet for hover text, e.g. "EV: +$1.8"
+fn format_expected_value(value: f32) -> String {
+    let sign = if value < 0.0 { "-" } else { "+" };
+    format!("EV: {}${:.1}", sign, value.abs())
 }
 
 /// Represents the last die roll result (regular or crazy camel)
@@ -1052,11 +125,20 @@ pub struct UiState {
     pub leg_scoring_delay: f32, // Delay timer before showing leg scoring modal (800ms)
     pub game_end_delay: f32,   // Delay timer before transitioning to GameEnd state (800ms)
     pub show_rules: bool,      // Show game rules modal
+    pub show_game_log: bool,   // Show plain-text game log window
+    pub show_turn_history: bool, // Turn history tab on the right edge is expanded
+    pub show_odds: bool,       // Show exact leg odds panel
+    pub show_quit_dialog: bool, // Show leave game confirmation
+    pub inspected_space: Option<u8>, // Board space whose stack popup is open
+    pub preview_camel: Option<CamelColor>, // Camel hovered in the positions panel (movement preview)
+    pub expanded_player: Option<usize>, // Player whose detail card is open in the players list
     pub camel_rolls_complete: bool,   // Whether camel setup rolls have finished (waiting for Start Game)
     pub initial_rolls_complete: bool, // Whether initial setup rolls have finished
     pub exit_fullscreen_requested: bool, // Request to exit fullscreen mode
     pub enter_fullscreen_requested: bool, // Request to enter fullscreen mode
     pub use_side_panels: bool, // Layout mode: true = side panels (landscape), false = top/bottom (portrait)
+    pub action_sheet_expanded: bool, // Portrait action sheet swiped up to show race bets, tile and standings
+    pub action_sheet_drag: f32, // How far the action sheet's handle has been dragged so far (down is positive)
     pub game_board_rect: Option<egui::Rect>, // Measured game board area from CentralPanel
     #[allow(dead_code)]
     pub mobile_tab: MobileTab, // Current tab in mobile view (deprecated)
@@ -1065,6 +147,7 @@ pub struct UiState {
     pub card_flight_animation: Option<CardFlightAnimation>, // Animation for leg bet card flying to player
     pub leg_bet_card_positions: [Option<egui::Pos2>; 5], // Screen positions of leg bet card stacks (indexed by CamelColor)
     pub player_bet_area_pos: Option<egui::Pos2>, // Screen position where player's bets are displayed
+    pub player_avatar_positions: [Option<egui::Pos2>; PlayerSetupConfig::MAX_PLAYERS], // Screen centers of player avatars (money popups)
     pub show_debug_overlay: bool,                // Show debug overlay with window dimensions
 }
 
@@ -1112,11 +195,20 @@ impl Default for UiState {
             leg_scoring_delay: 0.0,
             game_end_delay: 0.0,
             show_rules: false,
+            show_game_log: false,
+            show_turn_history: false,
+            show_odds: false,
+            show_quit_dialog: false,
+            inspected_space: None,
+            preview_camel: None,
+            expanded_player: None,
             camel_rolls_complete: false,
             initial_rolls_complete: false,
             exit_fullscreen_requested: false,
             enter_fullscreen_requested: false,
             use_side_panels: true, // Default to side panels (landscape)
+            action_sheet_expanded: false,
+            action_sheet_drag: 0.0,
             game_board_rect: None,
             mobile_tab: MobileTab::default(),
             die_roll_animation: None,
@@ -1124,40 +216,60 @@ impl Default for UiState {
             card_flight_animation: None,
             leg_bet_card_positions: [None; 5],
             player_bet_area_pos: None,
+            player_avatar_positions: [None; PlayerSetupConfig::MAX_PLAYERS],
             show_debug_overlay: false,
         }
     }
 }
 
+/// The game as the HUD shows it: the table and the camels on the board
+#[derive(SystemParam)]
+pub struct HudTable<'w, 's> {
+    players: Option<Res<'w, Players>>,
+    pyramid: Option<Res<'w, Pyramid>>,
+    leg_tiles: Option<Res<'w, LegBettingTiles>>,
+    turn_state: Option<Res<'w, TurnState>>,
+    placed_tiles: Option<Res<'w, PlacedSpectatorTiles>>,
+    player_leg_bets: Option<Res<'w, PlayerLegBetsStore>>,
+    player_pyramid_tokens: Option<Res<'w, PlayerPyramidTokens>>,
+    race_bets: Option<Res<'w, RaceBets>>,
+    camels: Query<'w, 's, (&'static Camel, &'static BoardPosition), Without<PendingInitialMove>>,
+    crazy_camels: Query<'w, 's, (&'static CrazyCamel, &'static BoardPosition), Without<PendingInitialMove>>,
+}
+
+/// What the HUD shows alongside the table: names, odds, reminders, the log and the
+/// player's settings
+#[derive(SystemParam)]
+pub struct HudInfo<'w> {
+    camel_names: Res<'w, CamelNames>,
+    race_odds: Res<'w, RaceOdds>,
+    config: Res<'w, PlayerSetupConfig>,
+    reminders: Res<'w, ActiveReminders>,
+    network_state: Res<'w, NetworkState>,
+    game_log: Res<'w, GameLog>,
+    settings: Res<'w, Settings>,
+}
+
+/// The HUD's own state between frames, and the window it can switch to fullscreen
+#[derive(SystemParam)]
+pub struct HudScreen<'w, 's> {
+    ui_state: ResMut<'w, UiState>,
+    rules_state: ResMut<'w, RulesState>,
+    camel_animations: Res<'w, CamelPositionAnimations>,
+    emotes: ResMut<'w, Emotes>,
+    initial_rolls: Option<ResMut<'w, crate::systems::setup::InitialSetupRolls>>,
+    windows: Query<'w, 's, &'static mut Window>,
+    time: Res<'w, Time>,
+}
+
 pub fn game_hud_ui(
     mut contexts: EguiContexts,
-    game_resources: (
-        Option<Res<Players>>,
-        Option<Res<Pyramid>>,
-        Option<Res<LegBettingTiles>>,
-        Option<Res<TurnState>>,
-        Option<Res<PlacedSpectatorTiles>>,
-        Option<Res<PlayerLegBetsStore>>,
-        Option<Res<PlayerPyramidTokens>>,
-        Option<Res<RaceBets>>,
-    ),
-    mut ui_state: ResMut<UiState>,
-    mut rules_state: ResMut<RulesState>,
-    camel_animations: Res<CamelPositionAnimations>,
-    actions: (
-        MessageWriter<RollPyramidAction>,
-        MessageWriter<TakeLegBetAction>,
-        MessageWriter<PlaceRaceBetAction>,
-        MessageWriter<PlaceSpectatorTileAction>,
-    ),
-    camels: Query<(&Camel, &BoardPosition), Without<PendingInitialMove>>,
-    crazy_camels: Query<(&CrazyCamel, &BoardPosition), Without<PendingInitialMove>>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut windows: Query<&mut Window>,
-    time: Res<Time>,
-    mut initial_rolls: Option<ResMut<crate::systems::setup::InitialSetupRolls>>,
+    table: HudTable,
+    screen: HudScreen,
+    actions: PlayerActions,
+    info: HudInfo,
 ) {
-    let (
+    let HudTable {
         players,
         pyramid,
         leg_tiles,
@@ -1166,9 +278,18 @@ pub fn game_hud_ui(
         player_leg_bets,
         player_pyramid_tokens,
         race_bets,
-    ) = game_resources;
-    let (mut roll_action, mut leg_bet_action, mut race_bet_action, mut spectator_tile_action) =
-        actions;
+        camels,
+        crazy_camels,
+    } = table;
+    let HudScreen { mut ui_state, mut rules_state, camel_animations, mut emotes, mut initial_rolls, mut windows, time } =
+        screen;
+    let PlayerActions {
+        roll: mut roll_action,
+        leg_bet: mut leg_bet_action,
+        race_bet: mut race_bet_action,
+        spectator_tile: mut spectator_tile_action,
+    } = actions;
+    let HudInfo { camel_names, race_odds, config, reminders, network_state, game_log, settings } = info;
     let Some(players) = players else { return };
     let Some(pyramid) = pyramid else { return };
     let Some(leg_tiles) = leg_tiles else { return };
@@ -1185,6 +306,10 @@ pub fn game_hud_ui(
     let Some(race_bets) = race_bets else { return };
     let Ok(ctx) = contexts.ctx_mut() else { return };
 
+    // Odds behind the expected value hints on bets (off for purists)
+    let bet_odds = race_odds.odds().filter(|_| config.show_bet_values);
+    let camel_palette = settings.camel_palette();
+
     // Draw rules UI if triggered from HUD
     if ui_state.show_rules {
         rules_state.is_open = true;
@@ -1195,6 +320,7 @@ pub fn game_hud_ui(
         &mut rules_state,
         !ui_state.use_side_panels,
         time.delta_secs(),
+        camel_palette,
     );
 
     // Debug overlay - show window dimensions in top left (only in debug builds)
@@ -1221,8 +347,8 @@ pub fn game_hud_ui(
     }
 
     // Shared current player color (used in multiple places)
-    let current_player_color =
-        PLAYER_COLORS[players.current_player().color_index % PLAYER_COLORS.len()];
+    let current = players.current_player();
+    let current_player_color = player_color_to_egui(current.color_index, current.custom_color);
 
     // Top bar - Game info (responsive based on layout mode)
     egui::TopBottomPanel::top("game_info").show(ctx, |ui| {
@@ -1256,7 +382,7 @@ pub fn game_hud_ui(
                     );
                     match die {
                         PyramidDie::Regular(regular) => {
-                            let color = camel_color_to_egui(regular.color);
+                            let color = camel_color_to_egui(regular.color, camel_palette);
                             ui.painter().rect_filled(die_rect, rounding, color);
                             ui.painter().rect_stroke(
                                 die_rect,
@@ -1308,7 +434,7 @@ pub fn game_hud_ui(
                         let center = rect.center() + egui::vec2(shake_offset, drop_offset);
                         let die_rect = egui::Rect::from_center_size(center, egui::vec2(size, size));
                         let base_color = match anim.die_color {
-                            Some(color) => camel_color_to_egui(color),
+                            Some(color) => camel_color_to_egui(color, camel_palette),
                             None => egui::Color32::from_rgb(100, 100, 100), // Crazy die
                         };
                         let color = egui::Color32::from_rgba_unmultiplied(
@@ -1340,7 +466,7 @@ pub fn game_hud_ui(
                     ui.separator();
                     match last_roll {
                         LastRoll::Regular(color, value) => {
-                            ui.label(format!("Last roll: {:?} moved {} spaces", color, value));
+                            ui.label(format!("Last roll: {} moved {} spaces", camel_names.name(*color), value));
                         }
                         LastRoll::Crazy(color, value) => {
                             ui.label(format!(
@@ -1360,7 +486,19 @@ pub fn game_hud_ui(
                 if !ui_state.use_side_panels {
                     // Portrait: smaller buttons
                     if desert_button(ui, "Menu", &small_style).clicked() {
-                        next_state.set(GameState::MainMenu);
+                        ui_state.show_quit_dialog = true;
+                    }
+                    if desert_button(ui, "Log", &compact_style)
+                        .on_hover_text("Game Log")
+                        .clicked()
+                    {
+                        ui_state.show_game_log = !ui_state.show_game_log;
+                    }
+                    if desert_button(ui, "%", &compact_style)
+                        .on_hover_text("Leg Odds")
+                        .clicked()
+                    {
+                        ui_state.show_odds = !ui_state.show_odds;
                     }
                     if desert_button(ui, "?", &compact_style)
                         .on_hover_text("How to Play")
@@ -1370,7 +508,19 @@ pub fn game_hud_ui(
                     }
                 } else {
                     if desert_button(ui, "Back to Menu", &small_style).clicked() {
-                        next_state.set(GameState::MainMenu);
+                        ui_state.show_quit_dialog = true;
+                    }
+                    if desert_button(ui, "Log", &compact_style)
+                        .on_hover_text("Game Log")
+                        .clicked()
+                    {
+                        ui_state.show_game_log = !ui_state.show_game_log;
+                    }
+                    if desert_button(ui, "%", &compact_style)
+                        .on_hover_text("Leg Odds")
+                        .clicked()
+                    {
+                        ui_state.show_odds = !ui_state.show_odds;
                     }
                     if desert_button(ui, "?", &compact_style)
                         .on_hover_text("How to Play")
@@ -1428,7 +578,7 @@ pub fn game_hud_ui(
             &pyramid,
             &leg_tiles,
             &turn_state,
-            &*placed_tiles,
+            &placed_tiles,
             &player_leg_bets,
             &player_pyramid_tokens,
             &race_bets,
@@ -1441,6 +591,15 @@ pub fn game_hud_ui(
             &camels,
             current_player_color,
             &mut initial_rolls,
+            &camel_names,
+            bet_odds,
+            &reminders,
+            &network_state,
+            &mut emotes,
+            &game_log,
+            time.elapsed_secs_f64(),
+            settings.left_handed,
+            camel_palette,
         );
     } else {
         // Portrait layout - top/bottom panels
@@ -1450,7 +609,7 @@ pub fn game_hud_ui(
             &pyramid,
             &leg_tiles,
             &turn_state,
-            &*placed_tiles,
+            &placed_tiles,
             &player_leg_bets,
             &player_pyramid_tokens,
             &race_bets,
@@ -1463,6 +622,12 @@ pub fn game_hud_ui(
             &camels,
             current_player_color,
             &mut initial_rolls,
+            &camel_names,
+            bet_odds,
+            &reminders,
+            &network_state,
+            settings.left_handed,
+            camel_palette,
         );
     }
 
@@ -1476,23 +641,31 @@ pub fn game_hud_ui(
     // Shared popup windows (race betting, spectator tile placement, dice result)
     render_popup_windows(
         ctx,
-        &*players,
-        &*placed_tiles,
-        &*race_bets,
-        &mut *ui_state,
+        &players,
+        &placed_tiles,
+        &race_bets,
+        &mut ui_state,
         &mut race_bet_action,
         &mut spectator_tile_action,
         &camels,
         &crazy_camels,
         current_player_color,
+        &camel_names,
+        bet_odds,
+        camel_palette,
     );
 
     // Card flight animation overlay (drawn on top of everything)
-    render_card_flight_animation(ctx, &mut *ui_state, time.elapsed_secs_f64());
+    render_card_flight_animation(ctx, &mut ui_state, time.elapsed_secs_f64(), camel_palette);
 }
 
 /// Render the flying card animation overlay
-fn render_card_flight_animation(ctx: &egui::Context, ui_state: &mut UiState, current_time: f64) {
+fn render_card_flight_animation(
+    ctx: &egui::Context,
+    ui_state: &mut UiState,
+    current_time: f64,
+    camel_palette: CamelPalette,
+) {
     if let Some(ref mut anim) = ui_state.card_flight_animation {
         let elapsed = (current_time - anim.start_time) as f32;
 
@@ -1515,7 +688,7 @@ fn render_card_flight_animation(ctx: &egui::Context, ui_state: &mut UiState, cur
         anim.phase = phase;
 
         // Get the color for the card
-        let color = camel_color_to_egui(anim.color);
+        let color = camel_color_to_egui(anim.color, camel_palette);
         let card_width = 36.0;
         let card_height = 48.0;
 
@@ -1536,7 +709,7 @@ fn render_card_flight_animation(ctx: &egui::Context, ui_state: &mut UiState, cur
                         );
                         let rect =
                             egui::Rect::from_center_size(pos, egui::vec2(card_width, card_height));
-                        draw_mini_leg_bet_card(painter, rect, anim.color, anim.value);
+                        draw_mini_leg_bet_card(painter, rect, &Palette::camel(anim.color, camel_palette), anim.value);
                     }
                     CardFlightPhase::DisappearingUnder => {
                         // Shrink and fade at end position
@@ -1589,10 +762,16 @@ fn render_card_flight_animation(ctx: &egui::Context, ui_state: &mut UiState, cur
     }
 }
 
-/// Render mobile UI with restructured layout:
-/// - Top panel: Player info + Camel standings
-/// - Dice tents: Separate panel above HUD
-/// - Bottom HUD: Actions only (no scroll)
+/// Height of the action sheet's grab handle
+const SHEET_HANDLE_HEIGHT: f32 = 16.0;
+
+/// How far the action sheet's handle has to be swiped to expand or collapse it
+const SHEET_SWIPE_DISTANCE: f32 = 20.0;
+
+/// Render mobile UI for portrait screens, where side panels would crush the board:
+/// - Top strip: one compact row of players, scrolling sideways when they don't fit
+/// - Bottom action sheet: leg bets always; swipe it up (or tap its handle) for the race
+///   bets, the spectator tile and the camel standings
 #[allow(clippy::too_many_arguments)]
 fn render_mobile_ui(
     ctx: &egui::Context,
@@ -1613,6 +792,12 @@ fn render_mobile_ui(
     _camels: &Query<(&Camel, &BoardPosition), Without<PendingInitialMove>>,
     current_player_color: egui::Color32,
     _initial_rolls: &mut Option<ResMut<crate::systems::setup::InitialSetupRolls>>,
+    camel_names: &CamelNames,
+    bet_odds: Option<&SimulationResult>,
+    reminders: &ActiveReminders,
+    network_state: &NetworkState,
+    left_handed: bool,
+    camel_palette: CamelPalette,
 ) {
     let current = players.current_player();
     let can_act = !turn_state.action_taken
@@ -1620,208 +805,125 @@ fn render_mobile_ui(
         && ui_state.initial_rolls_complete
         && !ui_state.show_leg_scoring;
 
-    // === TOP PANEL: Player Info + Camel Standings ===
+    // === TOP STRIP: one compact row of players ===
     let panel_response = egui::TopBottomPanel::top("mobile_info_panel")
         .frame(egui::Frame::new().fill(ctx.style().visuals.panel_fill))
         .show(ctx, |ui| {
-            // Constants for consistent styling
-            const CARD_MARGIN: f32 = 2.0;
-            const CARD_ROUNDING: f32 = 2.0;
+            const CHIP_MARGIN: f32 = 2.0;
+            const CHIP_ROUNDING: f32 = 2.0;
             const AVATAR_SIZE: f32 = 22.0;
 
             ui.add_space(2.0);
 
-            // --- Players Section ---
-            let player_count = players.players.len();
-            let available_width = ui.available_width();
-            let per_row = layout::players_per_row(player_count, available_width);
-
-            // Calculate exact card width: (Total - (Spaces between cards)) / Count
-            // We use outer_margin on frames, so we account for that in the width calculation
-            let card_width = (available_width / per_row as f32).floor();
-
-            // 1. Draw Player Card Closure
-            let draw_player_card = |ui: &mut egui::Ui,
-                                        i: usize,
-                                        player: &crate::components::PlayerData|
-             -> Option<egui::Pos2> {
-                let is_current = i == players.current_player_index;
-                let player_color = PLAYER_COLORS[player.color_index % PLAYER_COLORS.len()];
-
-                let bg_color = if is_current {
-                    egui::Color32::from_rgb(40, 60, 40)
-                } else {
-                    egui::Color32::from_rgb(35, 35, 40)
-                };
+            egui::ScrollArea::horizontal()
+                .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysHidden)
+                .show(ui, |ui| {
+                    ui.horizontal_top(|ui| {
+                        // Spacing comes from the chips' outer margin
+                        ui.spacing_mut().item_spacing.x = 0.0;
 
-                // Use outer_margin for spacing between cards (replaces ui.add_space)
-                let frame = egui::Frame::new()
-                    .fill(bg_color)
-                    .corner_radius(CARD_ROUNDING)
-                    .inner_margin(3.0)
-                    .outer_margin(CARD_MARGIN);
+                        for (i, player) in players.players.iter().enumerate() {
+                            let is_current = i == players.current_player_index;
+                            let player_color = player_color_to_egui(player.color_index, player.custom_color);
 
-                let mut current_player_pos = None;
+                            let bg_color = if is_current {
+                                egui::Color32::from_rgb(40, 60, 40)
+                            } else {
+                                egui::Color32::from_rgb(35, 35, 40)
+                            };
 
-                frame.show(ui, |ui| {
-                    // Force width to ensure uniform cards
-                    // We subtract outer_margin * 2 because the allocated rect includes the outer margin
-                    ui.set_width(card_width - (CARD_MARGIN * 2.0));
+                            let mut avatar_pos = egui::Pos2::ZERO;
+
+                            egui::Frame::new()
+                                .fill(panel_fill(bg_color))
+                                // No border normally; in high contrast the current player's is green
+                                .stroke(panel_stroke(egui::Stroke::new(
+                                    0.0,
+                                    if is_current { egui::Color32::LIGHT_GREEN } else { egui::Color32::TRANSPARENT },
+                                )))
+                                .corner_radius(CHIP_ROUNDING)
+                                .inner_margin(3.0)
+                                .outer_margin(CHIP_MARGIN)
+                                .show(ui, |ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.spacing_mut().item_spacing.x = 3.0;
 
-                    ui.horizontal(|ui| {
-                        // Avatar
-                        let (rect, _) = ui.allocate_exact_size(
-                            egui::vec2(AVATAR_SIZE, AVATAR_SIZE),
-                            egui::Sense::hover(),
-                        );
-                        draw_avatar(ui.painter(), rect, player.character_id, Some(player_color));
-
-                        if is_current {
-                            current_player_pos = Some(rect.center());
-                        }
-
-                        ui.add_space(4.0);
-
-                        // Money
-                        ui.label(egui::RichText::new(format!("${}", player.money)).size(10.0));
-
-                        // Leg Bets
-                        if let Some(bets) = player_leg_bets.bets.get(i) {
-                            if !bets.is_empty() {
-                                draw_overlapping_stack(
-                                    ui,
-                                    bets,
-                                    mobile::MINI_LEG_BET_WIDTH,
-                                    mobile::MINI_LEG_BET_HEIGHT,
-                                    mobile::MINI_LEG_BET_OVERLAP,
-                                    |painter, rect, bet| {
-                                        draw_mini_leg_bet_indicator(
-                                            painter, rect, bet.camel, bet.value,
+                                        // Avatar
+                                        let (rect, _) = ui.allocate_exact_size(
+                                            egui::vec2(AVATAR_SIZE, AVATAR_SIZE),
+                                            egui::Sense::hover(),
                                         );
-                                    },
-                                );
-                            }
-                        }
-
-                        // Pyramid Tokens
-                        if let Some(&count) = player_pyramid_tokens.counts.get(i) {
-                            if count > 0 {
-                                draw_spaced_row(
-                                    ui,
-                                    count as usize,
-                                    mobile::PYRAMID_TOKEN_SIZE,
-                                    mobile::PYRAMID_TOKEN_SPACING,
-                                    |painter, center, _| {
-                                        draw_pyramid_token_icon(
-                                            painter,
-                                            center,
-                                            mobile::PYRAMID_TOKEN_SIZE,
-                                        );
-                                    },
-                                );
-                            }
-                        }
-                    });
-                });
-
-                current_player_pos
-            };
-
-            // 2. Render Rows
-            for row_start in (0..player_count).step_by(per_row) {
-                // FIX: Use horizontal_top to prevent "staircase" bug where successive elements drop down
-                ui.horizontal_top(|ui| {
-                    // Reset item spacing because we are handling it via Frame outer_margin
-                    ui.spacing_mut().item_spacing.x = 0.0;
-
-                    let row_end = (row_start + per_row).min(player_count);
-                    for i in row_start..row_end {
-                        if let Some(pos) = draw_player_card(ui, i, &players.players[i]) {
-                            ui_state.player_bet_area_pos = Some(pos);
-                        }
-                    }
-                });
-            }
-
-            ui.separator();
-            ui.add_space(2.0);
-
-            // --- Camel Standings Section ---
-            let sorted_camels = get_sorted_camels(camel_animations);
-            let camel_count = sorted_camels.len();
-
-            if camel_count > 0 {
-                let camel_w = 32.0;
-                let camel_h = 24.0;
-
-                ui.add_space(4.0);
-
-                // Use vertical_centered to handle the centering logic automatically
-                ui.vertical_centered(|ui| {
-                    ui.horizontal(|ui| {
-                        // "Current Standings" Label
-                        let label_text = egui::RichText::new("Current\nStandings")
-                            .size(10.0)
-                            .color(egui::Color32::from_rgb(120, 120, 120));
-                        ui.label(label_text);
-
-                        ui.add_space(8.0); // Spacing between label and camels
-
-                        // Draw Camels (Right-to-Left logic: 1st place is last in this loop)
-                        // Assuming standard left-to-right drawing: Last Place -> ... -> 1st Place
-
-                        let last_place_rank = camel_count - 1;
-
-                        for (i, (color, x_offset, _, width_scale)) in
-                            sorted_camels.iter().rev().enumerate()
-                        {
-                            let rank = camel_count - 1 - i; // 0 = 1st place
-                            let camel_egui_color = camel_color_to_egui(*color);
-                            let border_color = egui::Color32::from_rgb(
-                                (camel_egui_color.r() as f32 * 0.5) as u8,
-                                (camel_egui_color.g() as f32 * 0.5) as u8,
-                                (camel_egui_color.b() as f32 * 0.5) as u8,
-                            );
-
-                            // Animate width during scale-in
-                            let animated_width = camel_w * width_scale;
-
-                            let (rect, _) = ui.allocate_exact_size(
-                                egui::vec2(animated_width, camel_h),
-                                egui::Sense::hover(),
-                            );
-
-                            // Draw Camel with animated width
-                            let animated_rect = egui::Rect::from_center_size(
-                                egui::pos2(rect.center().x + *x_offset * 0.5, rect.center().y),
-                                egui::vec2((camel_w - 4.0) * width_scale, camel_h),
-                            );
-
-                            // Only draw if width is visible
-                            if *width_scale > 0.01 {
-                                draw_camel_silhouette(
-                                    ui.painter(),
-                                    animated_rect,
-                                    camel_egui_color,
-                                    border_color,
-                                );
+                                        draw_avatar(ui.painter(), rect, player.character_id, Some(player_color));
+                                        if network_state.is_absent(i) {
+                                            draw_disconnected_badge(ui.painter(), rect);
+                                        }
+                                        avatar_pos = rect.center();
+
+                                        // Money
+                                        ui.label(egui::RichText::new(format!("${}", player.money)).size(10.0));
+
+                                        // Leg Bets
+                                        if let Some(bets) = player_leg_bets.bets.get(i) {
+                                            if !bets.is_empty() {
+                                                draw_overlapping_stack(
+                                                    ui,
+                                                    bets,
+                                                    mobile::MINI_LEG_BET_WIDTH,
+                                                    mobile::MINI_LEG_BET_HEIGHT,
+                                                    mobile::MINI_LEG_BET_OVERLAP,
+                                                    |painter, rect, bet| {
+                                                        draw_mini_leg_bet_indicator(
+                                                            painter,
+                                                            rect,
+                                                            &Palette::camel(bet.camel, camel_palette),
+                                                            bet.value,
+                                                        );
+                                                    },
+                                                );
+                                            }
+                                        }
 
-                                // Draw Hats (only when fully scaled in)
-                                if *width_scale > 0.95 {
-                                    match rank {
-                                        0 => draw_crown_overlay(ui.painter(), animated_rect),
-                                        1 => draw_silver_crown_overlay(ui.painter(), animated_rect),
-                                        r if r == last_place_rank => {
-                                            draw_dunce_cap_overlay(ui.painter(), animated_rect)
+                                        // Pyramid Tokens, as a count so the chip stays narrow
+                                        if let Some(&count) = player_pyramid_tokens.counts.get(i) {
+                                            if count > 0 {
+                                                let (rect, _) = ui.allocate_exact_size(
+                                                    egui::vec2(mobile::PYRAMID_TOKEN_SIZE, mobile::PYRAMID_TOKEN_SIZE),
+                                                    egui::Sense::hover(),
+                                                );
+                                                draw_pyramid_token_icon(
+                                                    ui.painter(),
+                                                    rect.center(),
+                                                    &Palette::GOLD,
+                                                    mobile::PYRAMID_TOKEN_SIZE,
+                                                );
+                                                if count > 1 {
+                                                    ui.label(egui::RichText::new(format!("x{}", count)).size(9.0));
+                                                }
+                                            }
                                         }
-                                        _ => {}
-                                    }
+                                    });
+                                });
+
+                            if is_current {
+                                ui_state.player_bet_area_pos = Some(avatar_pos);
+
+                                // Scroll the current player into view when the turn passes to them
+                                let shown_id = egui::Id::new("mobile_strip_current_player");
+                                if ui.data(|data| data.get_temp::<usize>(shown_id)) != Some(i) {
+                                    ui.data_mut(|data| data.insert_temp(shown_id, i));
+                                    ui.scroll_to_rect(
+                                        egui::Rect::from_center_size(avatar_pos, egui::Vec2::splat(AVATAR_SIZE)),
+                                        None,
+                                    );
                                 }
                             }
+                            if let Some(slot) = ui_state.player_avatar_positions.get_mut(i) {
+                                *slot = Some(avatar_pos);
+                            }
                         }
                     });
                 });
-            }
+
             ui.add_space(2.0);
         });
 
@@ -1834,275 +936,539 @@ fn render_mobile_ui(
             .order(egui::Order::Foreground)
             .interactable(false)
             .show(ctx, |ui| {
-                render_dice_toast(ui, ui_state);
+                render_dice_toast(ui, ui_state, camel_names, camel_palette);
             });
     }
 
-    // === BOTTOM PLAYER PANEL: Player-owned items (WITH background) ===
-    egui::TopBottomPanel::bottom("mobile_player_hud").show(ctx, |ui| {
-        ui.add_space(4.0);
+    // === BOTTOM ACTION SHEET: leg bets, and the rest when swiped up ===
+    egui::TopBottomPanel::bottom("mobile_action_sheet")
+        .frame(
+            egui::Frame::new()
+                .fill(panel_fill(ctx.style().visuals.panel_fill))
+                .stroke(panel_stroke(egui::Stroke::NONE))
+                .corner_radius(egui::CornerRadius { nw: 12, ne: 12, sw: 0, se: 0 })
+                .inner_margin(egui::Margin::symmetric(4, 0)),
+        )
+        .show(ctx, |ui| {
+            // Grab handle: swipe up to expand, down to collapse, or tap to toggle
+            let (handle_rect, handle) = ui.allocate_exact_size(
+                egui::vec2(ui.available_width(), SHEET_HANDLE_HEIGHT),
+                egui::Sense::click_and_drag(),
+            );
+            ui.painter().rect_filled(
+                egui::Rect::from_center_size(handle_rect.center(), egui::vec2(36.0, 4.0)),
+                2.0,
+                if handle.hovered() { egui::Color32::LIGHT_GRAY } else { egui::Color32::GRAY },
+            );
+            if handle.dragged() {
+                ui_state.action_sheet_drag += handle.drag_delta().y;
+            }
+            if handle.drag_stopped() {
+                if ui_state.action_sheet_drag < -SHEET_SWIPE_DISTANCE {
+                    ui_state.action_sheet_expanded = true;
+                } else if ui_state.action_sheet_drag > SHEET_SWIPE_DISTANCE {
+                    ui_state.action_sheet_expanded = false;
+                }
+                ui_state.action_sheet_drag = 0.0;
+            } else if handle.clicked() {
+                ui_state.action_sheet_expanded = !ui_state.action_sheet_expanded;
+            }
 
-        if can_act {
-            let card_width = 45.0;
-            let card_height = 55.0;
-            let flip_btn_width = 26.0;
-            let btn_size = card_height;
-            let icon_size = 24.0;
+            // No button needed - player taps pyramid to set up camels
+            if ui_state.initial_rolls_complete {
+                // Show leg bet cards only (pyramid is now a Bevy sprite on the game board)
+                let card_width = 36.0;
+                let card_height = 48.0;
+                let total_width = (card_width * 5.0) + (2.0 * 4.0);
+                let start_x = (ui.available_width() - total_width) / 2.0;
 
-            // Calculate total width for centering
-            let total_width = btn_size + 2.0 + btn_size + 4.0 + card_width + flip_btn_width;
-            let start_x = (ui.available_width() - total_width) / 2.0;
+                ui.allocate_ui_with_layout(
+                    egui::vec2(ui.available_width(), card_height),
+                    sheet_row_layout(left_handed),
+                    |ui| {
+                        ui.add_space(start_x.max(0.0));
+
+                        // Leg bet cards (always visible, track positions)
+                        for (i, color) in CamelColor::all().iter().enumerate() {
+                            let color = *color;
+                            if let Some(tile) = leg_tiles.top_tile(color) {
+                                let sense = if can_act {
+                                    egui::Sense::click()
+                                } else {
+                                    egui::Sense::hover()
+                                };
+                                let (rect, response) =
+                                    ui.allocate_exact_size(egui::vec2(card_width, card_height), sense);
+                                draw_mini_leg_bet_card(ui.painter(), rect, &Palette::camel(color, camel_palette), tile.value);
 
-            ui.horizontal(|ui| {
-                ui.add_space(start_x.max(0.0));
+                                // Track card position for flight animation
+                                ui_state.leg_bet_card_positions[i] = Some(rect.center());
 
-                // Winner bet button - square with icon inside
-                let (winner_rect, winner_response) =
-                    ui.allocate_exact_size(egui::vec2(btn_size, btn_size), egui::Sense::click());
-                let winner_bg = if winner_response.hovered() {
-                    egui::Color32::from_rgb(80, 140, 80)
-                } else {
-                    egui::Color32::from_rgb(60, 120, 60)
-                };
-                ui.painter().rect_filled(winner_rect, 4.0, winner_bg);
-                ui.painter().rect_stroke(
-                    winner_rect,
-                    4.0,
-                    egui::Stroke::new(1.0, egui::Color32::from_rgb(40, 80, 40)),
-                    egui::epaint::StrokeKind::Outside,
-                );
-                let icon_rect = egui::Rect::from_center_size(
-                    winner_rect.center() + egui::vec2(0.0, -8.0),
-                    egui::vec2(icon_size, icon_size),
-                );
-                draw_camel_with_crown(ui.painter(), icon_rect);
-                ui.painter().text(
-                    winner_rect.center() + egui::vec2(0.0, 16.0),
-                    egui::Align2::CENTER_CENTER,
-                    "Bet Winner",
-                    egui::FontId::proportional(8.0),
-                    egui::Color32::WHITE,
-                );
-                if winner_response.clicked() {
-                    ui_state.show_winner_betting = true;
-                }
+                                if can_act {
+                                    if response.clicked() {
+                                        leg_bet_action.write(TakeLegBetAction { color, from_host: false });
+                                    }
 
-                ui.add_space(2.0);
+                                    if response.hovered() {
+                                        ui.painter().rect_stroke(
+                                            rect.expand(2.0),
+                                            3.0,
+                                            egui::Stroke::new(2.0, egui::Color32::GOLD),
+                                            egui::epaint::StrokeKind::Outside,
+                                        );
+                                    }
+                                }
 
-                // Loser bet button - square with icon inside
-                let (loser_rect, loser_response) =
-                    ui.allocate_exact_size(egui::vec2(btn_size, btn_size), egui::Sense::click());
-                let loser_bg = if loser_response.hovered() {
-                    egui::Color32::from_rgb(140, 80, 80)
-                } else {
-                    egui::Color32::from_rgb(120, 60, 60)
-                };
-                ui.painter().rect_filled(loser_rect, 4.0, loser_bg);
-                ui.painter().rect_stroke(
-                    loser_rect,
-                    4.0,
-                    egui::Stroke::new(1.0, egui::Color32::from_rgb(80, 40, 40)),
-                    egui::epaint::StrokeKind::Outside,
-                );
-                let icon_rect = egui::Rect::from_center_size(
-                    loser_rect.center() + egui::vec2(0.0, -8.0),
-                    egui::vec2(icon_size, icon_size),
-                );
-                draw_camel_with_dunce_cap(ui.painter(), icon_rect);
-                ui.painter().text(
-                    loser_rect.center() + egui::vec2(0.0, 16.0),
-                    egui::Align2::CENTER_CENTER,
-                    "Bet Loser",
-                    egui::FontId::proportional(8.0),
-                    egui::Color32::WHITE,
+                                if let Some(odds) = bet_odds {
+                                    response.on_hover_text(format_expected_value(odds.leg_bet_value(color, tile.value)));
+                                }
+                            } else {
+                                let (rect, _) = ui.allocate_exact_size(
+                                    egui::vec2(card_width, card_height),
+                                    egui::Sense::hover(),
+                                );
+                                let camel_color = camel_color_to_egui(color, camel_palette);
+                                let faded = egui::Color32::from_rgba_unmultiplied(
+                                    camel_color.r(),
+                                    camel_color.g(),
+                                    camel_color.b(),
+                                    40,
+                                );
+                                ui.painter().rect_filled(rect, 3.0, faded);
+                                ui.painter().text(
+                                    rect.center(),
+                                    egui::Align2::CENTER_CENTER,
+                                    "X",
+                                    egui::FontId::proportional(12.0),
+                                    egui::Color32::GRAY,
+                                );
+                                ui_state.leg_bet_card_positions[i] = None;
+                            }
+                            ui.add_space(2.0);
+                        }
+                    },
                 );
-                if loser_response.clicked() {
-                    ui_state.show_loser_betting = true;
-                }
-
-                ui.add_space(4.0);
 
-                // Spectator tile card
-                if current.has_spectator_tile {
-                    let (card_rect, card_response) = ui.allocate_exact_size(
-                        egui::vec2(card_width, card_height),
-                        egui::Sense::click(),
-                    );
+                // Status message overlay
+                if current.is_ai && !turn_state.action_taken {
+                    ui.horizontal(|ui| {
+                        ui.add_space((ui.available_width() - 80.0) / 2.0);
+                        ui.label(
+                            egui::RichText::new("AI thinking...")
+                                .italics()
+                                .color(text_color(egui::Color32::GRAY)),
+                        );
+                    });
+                } else if turn_state.action_taken {
+                    ui.horizontal(|ui| {
+                        ui.add_space((ui.available_width() - 60.0) / 2.0);
+                        ui.label(egui::RichText::new("Done").color(egui::Color32::YELLOW));
+                    });
+                }
+            }
 
-                    draw_spectator_tile_card(
-                        ui.painter(),
-                        card_rect,
-                        current.character_id,
-                        current_player_color,
-                        ui_state.spectator_tile_is_oasis,
-                        ui_state.spectator_tile_flip_anim,
-                    );
+            if ui_state.action_sheet_expanded {
+                ui.separator();
 
-                    if ui_state.spectator_tile_selected {
+                if can_act {
+                    let card_width = 45.0;
+                    let card_height = 55.0;
+                    let flip_btn_width = 26.0;
+                    let btn_size = card_height;
+                    let icon_size = 24.0;
+
+                    // Calculate total width for centering
+                    let total_width = btn_size + 2.0 + btn_size + 4.0 + card_width + flip_btn_width;
+                    let start_x = (ui.available_width() - total_width) / 2.0;
+
+                    ui.allocate_ui_with_layout(egui::vec2(ui.available_width(), btn_size), sheet_row_layout(left_handed), |ui| {
+                        ui.add_space(start_x.max(0.0));
+
+                        // Winner bet button - square with icon inside
+                        let (winner_rect, winner_response) =
+                            ui.allocate_exact_size(egui::vec2(btn_size, btn_size), egui::Sense::click());
+                        let winner_bg = if winner_response.hovered() {
+                            egui::Color32::from_rgb(80, 140, 80)
+                        } else {
+                            egui::Color32::from_rgb(60, 120, 60)
+                        };
+                        ui.painter().rect_filled(winner_rect, 4.0, winner_bg);
                         ui.painter().rect_stroke(
-                            card_rect.expand(2.0),
+                            winner_rect,
                             4.0,
-                            egui::Stroke::new(3.0, egui::Color32::GOLD),
+                            egui::Stroke::new(1.0, egui::Color32::from_rgb(40, 80, 40)),
                             egui::epaint::StrokeKind::Outside,
                         );
-                    } else if card_response.hovered() {
+                        let icon_rect = egui::Rect::from_center_size(
+                            winner_rect.center() + egui::vec2(0.0, -8.0),
+                            egui::vec2(icon_size, icon_size),
+                        );
+                        draw_camel_with_crown(ui.painter(), icon_rect, &Palette::GREY_CAMEL);
+                        ui.painter().text(
+                            winner_rect.center() + egui::vec2(0.0, 16.0),
+                            egui::Align2::CENTER_CENTER,
+                            "Bet Winner",
+                            egui::FontId::proportional(8.0),
+                            egui::Color32::WHITE,
+                        );
+                        if winner_response.clicked() {
+                            ui_state.show_winner_betting = true;
+                        }
+
+                        ui.add_space(2.0);
+
+                        // Loser bet button - square with icon inside
+                        let (loser_rect, loser_response) =
+                            ui.allocate_exact_size(egui::vec2(btn_size, btn_size), egui::Sense::click());
+                        let loser_bg = if loser_response.hovered() {
+                            egui::Color32::from_rgb(140, 80, 80)
+                        } else {
+                            egui::Color32::from_rgb(120, 60, 60)
+                        };
+                        ui.painter().rect_filled(loser_rect, 4.0, loser_bg);
                         ui.painter().rect_stroke(
-                            card_rect.expand(1.0),
-                            3.0,
-                            egui::Stroke::new(
-                                2.0,
-                                egui::Color32::from_rgba_unmultiplied(255, 215, 0, 128),
-                            ),
+                            loser_rect,
+                            4.0,
+                            egui::Stroke::new(1.0, egui::Color32::from_rgb(80, 40, 40)),
                             egui::epaint::StrokeKind::Outside,
                         );
-                    }
+                        let icon_rect = egui::Rect::from_center_size(
+                            loser_rect.center() + egui::vec2(0.0, -8.0),
+                            egui::vec2(icon_size, icon_size),
+                        );
+                        draw_camel_with_dunce_cap(ui.painter(), icon_rect, &Palette::GREY_CAMEL);
+                        ui.painter().text(
+                            loser_rect.center() + egui::vec2(0.0, 16.0),
+                            egui::Align2::CENTER_CENTER,
+                            "Bet Loser",
+                            egui::FontId::proportional(8.0),
+                            egui::Color32::WHITE,
+                        );
+                        if loser_response.clicked() {
+                            ui_state.show_loser_betting = true;
+                        }
 
-                    if card_response.clicked() {
-                        ui_state.spectator_tile_selected = !ui_state.spectator_tile_selected;
-                    }
+                        ui.add_space(4.0);
 
-                    // Flip button
-                    let (flip_rect, flip_response) = ui.allocate_exact_size(
-                        egui::vec2(flip_btn_width, card_height),
-                        egui::Sense::click(),
-                    );
-                    let flip_bg = if flip_response.hovered() {
-                        egui::Color32::from_rgb(70, 70, 80)
-                    } else {
-                        egui::Color32::from_rgb(50, 50, 60)
-                    };
-                    ui.painter().rect_filled(flip_rect, 3.0, flip_bg);
-                    draw_flip_icon(
-                        ui.painter(),
-                        flip_rect.center(),
-                        flip_btn_width.min(card_height) * 0.7,
-                        egui::Color32::from_rgb(200, 200, 210),
-                    );
-                    if flip_response.clicked() && ui_state.spectator_tile_flip_anim == 0.0 {
-                        ui_state.spectator_tile_flip_anim = 0.01;
-                    }
-                } else {
-                    let (card_rect, _) = ui.allocate_exact_size(
-                        egui::vec2(card_width + flip_btn_width + 4.0, card_height),
-                        egui::Sense::hover(),
-                    );
-                    ui.painter().rect_filled(
-                        card_rect,
-                        4.0,
-                        egui::Color32::from_rgba_unmultiplied(80, 80, 80, 100),
-                    );
-                    ui.painter().text(
-                        card_rect.center(),
-                        egui::Align2::CENTER_CENTER,
-                        "No Tile",
-                        egui::FontId::proportional(9.0),
-                        egui::Color32::GRAY,
-                    );
-                }
-            });
-        } else {
-            // Placeholder to maintain panel height when can_act is false
-            // This prevents camera zoom changes when action_taken becomes true
-            let card_height = 55.0;
-            ui.allocate_space(egui::vec2(1.0, card_height));
-        }
+                        // Spectator tile card
+                        if current.has_spectator_tile {
+                            let (card_rect, card_response) = ui.allocate_exact_size(
+                                egui::vec2(card_width, card_height),
+                                egui::Sense::click(),
+                            );
 
-        ui.add_space(4.0);
-    });
+                            draw_spectator_tile_card(
+                                ui.painter(),
+                                card_rect,
+                                &Palette::shaded(current_player_color),
+                                current.character_id,
+                                ui_state.spectator_tile_is_oasis,
+                                ui_state.spectator_tile_flip_anim,
+                            );
 
-    // === SHARED ACTIONS PANEL: Roll + Leg Bets (NO background - part of central area) ===
-    egui::TopBottomPanel::bottom("mobile_shared_actions")
-        .frame(egui::Frame::NONE)
-        .show(ctx, |ui| {
-            ui.add_space(4.0);
+                            if reminders.has(Reminder::SpectatorTileLastChance) {
+                                draw_reminder_chip(
+                                    ui.painter(),
+                                    card_rect.center_bottom(),
+                                    Reminder::SpectatorTileLastChance.short_message(),
+                                    7.0,
+                                );
+                            }
 
-            // No button needed - player taps pyramid to set up camels
-            if ui_state.initial_rolls_complete {
-                // Show leg bet cards only (pyramid is now a Bevy sprite on the game board)
-                let card_width = 36.0;
-                let card_height = 48.0;
-                let total_width = (card_width * 5.0) + (2.0 * 4.0);
-                let start_x = (ui.available_width() - total_width) / 2.0;
+                            if ui_state.spectator_tile_selected {
+                                ui.painter().rect_stroke(
+                                    card_rect.expand(2.0),
+                                    4.0,
+                                    egui::Stroke::new(3.0, egui::Color32::GOLD),
+                                    egui::epaint::StrokeKind::Outside,
+                                );
+                            } else if card_response.hovered() {
+                                ui.painter().rect_stroke(
+                                    card_rect.expand(1.0),
+                                    3.0,
+                                    egui::Stroke::new(
+                                        2.0,
+                                        egui::Color32::from_rgba_unmultiplied(255, 215, 0, 128),
+                                    ),
+                                    egui::epaint::StrokeKind::Outside,
+                                );
+                            }
 
-                ui.horizontal(|ui| {
-                    ui.add_space(start_x.max(0.0));
-
-                    // Leg bet cards (always visible, track positions)
-                    for (i, color) in CamelColor::all().iter().enumerate() {
-                        let color = *color;
-                        if let Some(tile) = leg_tiles.top_tile(color) {
-                            let sense = if can_act {
-                                egui::Sense::click()
+                            if card_response.clicked() {
+                                ui_state.spectator_tile_selected = !ui_state.spectator_tile_selected;
+                            }
+
+                            // Flip button
+                            let (flip_rect, flip_response) = ui.allocate_exact_size(
+                                egui::vec2(flip_btn_width, card_height),
+                                egui::Sense::click(),
+                            );
+                            let flip_bg = if flip_response.hovered() {
+                                egui::Color32::from_rgb(70, 70, 80)
                             } else {
-                                egui::Sense::hover()
+                                egui::Color32::from_rgb(50, 50, 60)
                             };
-                            let (rect, response) =
-                                ui.allocate_exact_size(egui::vec2(card_width, card_height), sense);
-                            draw_mini_leg_bet_card(ui.painter(), rect, color, tile.value);
-
-                            // Track card position for flight animation
-                            ui_state.leg_bet_card_positions[i] = Some(rect.center());
-
-                            if can_act {
-                                if response.clicked() {
-                                    leg_bet_action.write(TakeLegBetAction { color });
-                                }
-
-                                if response.hovered() {
-                                    ui.painter().rect_stroke(
-                                        rect.expand(2.0),
-                                        3.0,
-                                        egui::Stroke::new(2.0, egui::Color32::GOLD),
-                                        egui::epaint::StrokeKind::Outside,
-                                    );
-                                }
+                            ui.painter().rect_filled(flip_rect, 3.0, flip_bg);
+                            draw_flip_icon(
+                                ui.painter(),
+                                flip_rect.center(),
+                                &Palette::solid(egui::Color32::from_rgb(200, 200, 210)),
+                                flip_btn_width.min(card_height) * 0.7,
+                            );
+                            if flip_response.clicked() && ui_state.spectator_tile_flip_anim == 0.0 {
+                                ui_state.spectator_tile_flip_anim = 0.01;
                             }
                         } else {
-                            let (rect, _) = ui.allocate_exact_size(
-                                egui::vec2(card_width, card_height),
+                            let (card_rect, _) = ui.allocate_exact_size(
+                                egui::vec2(card_width + flip_btn_width + 4.0, card_height),
                                 egui::Sense::hover(),
                             );
-                            let camel_color = camel_color_to_egui(color);
-                            let faded = egui::Color32::from_rgba_unmultiplied(
-                                camel_color.r(),
-                                camel_color.g(),
-                                camel_color.b(),
-                                40,
+                            ui.painter().rect_filled(
+                                card_rect,
+                                4.0,
+                                egui::Color32::from_rgba_unmultiplied(80, 80, 80, 100),
                             );
-                            ui.painter().rect_filled(rect, 3.0, faded);
                             ui.painter().text(
-                                rect.center(),
+                                card_rect.center(),
                                 egui::Align2::CENTER_CENTER,
-                                "X",
-                                egui::FontId::proportional(12.0),
+                                "No Tile",
+                                egui::FontId::proportional(9.0),
                                 egui::Color32::GRAY,
                             );
-                            ui_state.leg_bet_card_positions[i] = None;
                         }
-                        ui.add_space(2.0);
-                    }
-                });
+                    });
+                } else {
+                    // Placeholder to maintain panel height when can_act is false
+                    // This prevents camera zoom changes when action_taken becomes true
+                    let card_height = 55.0;
+                    ui.allocate_space(egui::vec2(1.0, card_height));
+                }
 
-                // Status message overlay
-                if current.is_ai && !turn_state.action_taken {
+                ui.separator();
+
+                // --- Camel Standings Section ---
+                let sorted_camels = get_sorted_camels(camel_animations);
+                let camel_count = sorted_camels.len();
+
+                if camel_count > 0 {
+                    let camel_w = 32.0;
+                    let camel_h = 24.0;
+
+                    ui.add_space(4.0);
+
+                    // Use vertical_centered to handle the centering logic automatically
+                    ui.vertical_centered(|ui| {
+                        ui.horizontal(|ui| {
+                            // "Current Standings" Label
+                            let label_text = egui::RichText::new("Current\nStandings")
+                                .size(10.0)
+                                .color(egui::Color32::from_rgb(120, 120, 120));
+                            ui.label(label_text);
+
+                            ui.add_space(8.0); // Spacing between label and camels
+
+                            // Draw Camels (Right-to-Left logic: 1st place is last in this loop)
+                            // Assuming standard left-to-right drawing: Last Place -> ... -> 1st Place
+
+                            let last_place_rank = camel_count - 1;
+
+                            for (i, (color, x_offset, _, width_scale)) in
+                                sorted_camels.iter().rev().enumerate()
+                            {
+                                let rank = camel_count - 1 - i; // 0 = 1st place
+                                // Animate width during scale-in
+                                let animated_width = camel_w * width_scale;
+
+                                let (rect, _) = ui.allocate_exact_size(
+                                    egui::vec2(animated_width, camel_h),
+                                    egui::Sense::hover(),
+                                );
+
+                                // Draw Camel with animated width
+                                let animated_rect = egui::Rect::from_center_size(
+                                    egui::pos2(rect.center().x + *x_offset * 0.5, rect.center().y),
+                                    egui::vec2((camel_w - 4.0) * width_scale, camel_h),
+                                );
+
+                                // Only draw if width is visible
+                                if *width_scale > 0.01 {
+                                    draw_camel_silhouette(
+                                        ui.painter(),
+                                        animated_rect,
+                                        &Palette::camel(*color, camel_palette),
+                                    );
+
+                                    // Draw Hats (only when fully scaled in)
+                                    if *width_scale > 0.95 {
+                                        match rank {
+                                            0 => draw_crown_overlay(ui.painter(), animated_rect, &Palette::GOLD_CROWN),
+                                            1 => draw_crown_overlay(ui.painter(), animated_rect, &Palette::SILVER_CROWN),
+                                            r if r == last_place_rank => {
+                                                draw_dunce_cap_overlay(ui.painter(), animated_rect, &Palette::DUNCE_CAP)
+                                            }
+                                            _ => {}
+                                        }
+                                    }
+                                }
+                            }
+                        });
+                    });
+                }
+            }
+
+            ui.add_space(4.0);
+        });
+}
+
+/// Layout for a row of the mobile action sheet. Left-handed, rows are laid out from the
+/// right, so the first leg bet card, the spectator tile and its flip button sit under the
+/// left thumb.
+fn sheet_row_layout(left_handed: bool) -> egui::Layout {
+    if left_handed {
+        egui::Layout::right_to_left(egui::Align::Center)
+    } else {
+        egui::Layout::left_to_right(egui::Align::Center)
+    }
+}
+
+/// Detail card under an expanded player in the players list: each leg bet and what it
+/// pays, race cards played, where their spectator tile is, and their money over the game
+fn draw_player_detail(
+    ui: &mut egui::Ui,
+    player: &crate::components::PlayerData,
+    leg_bets: &[LegBetTile],
+    placed_tiles: &PlacedSpectatorTiles,
+    game_log: &GameLog,
+    camel_names: &CamelNames,
+    camel_palette: CamelPalette,
+) {
+    ui.separator();
+    egui::Grid::new(("player_detail", player.id))
+        .num_columns(2)
+        .spacing(egui::vec2(8.0, 2.0))
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new("Leg bets").small().color(text_color(egui::Color32::GRAY)));
+            ui.vertical(|ui| {
+                if leg_bets.is_empty() {
+                    ui.label(egui::RichText::new("None this leg").small());
+                }
+                for bet in leg_bets {
                     ui.horizontal(|ui| {
-                        ui.add_space((ui.available_width() - 80.0) / 2.0);
+                        let (rect, _) = ui.allocate_exact_size(egui::vec2(10.0, 10.0), egui::Sense::hover());
+                        ui.painter().rect_filled(rect, 2.0, camel_color_to_egui(bet.camel, camel_palette));
                         ui.label(
-                            egui::RichText::new("AI thinking...")
-                                .italics()
-                                .color(egui::Color32::GRAY),
+                            egui::RichText::new(format!(
+                                "{}: +${} if 1st, +$1 if 2nd, -$1 otherwise",
+                                camel_names.name(bet.camel),
+                                bet.value
+                            ))
+                            .small(),
                         );
                     });
-                } else if turn_state.action_taken {
-                    ui.horizontal(|ui| {
-                        ui.add_space((ui.available_width() - 60.0) / 2.0);
-                        ui.label(egui::RichText::new("Done").color(egui::Color32::YELLOW));
-                    });
+                }
+            });
+            ui.end_row();
+
+            let played = CamelColor::all().len() - player.available_race_cards.len();
+            ui.label(egui::RichText::new("Race cards").small().color(text_color(egui::Color32::GRAY)));
+            ui.label(egui::RichText::new(format!("{} of {} played", played, CamelColor::all().len())).small());
+            ui.end_row();
+
+            let tile = placed_tiles.tiles.iter().find(|(_, (owner, _))| *owner == player.id);
+            let tile_status = match tile {
+                Some((space, (_, true))) => format!("Oasis (+1) on space {}", space + 1),
+                Some((space, (_, false))) => format!("Mirage (-1) on space {}", space + 1),
+                None if player.has_spectator_tile => "In hand".to_string(),
+                None => "Used".to_string(),
+            };
+            ui.label(egui::RichText::new("Spectator tile").small().color(text_color(egui::Color32::GRAY)));
+            ui.label(egui::RichText::new(tile_status).small());
+            ui.end_row();
+
+            ui.label(egui::RichText::new("Money").small().color(text_color(egui::Color32::GRAY)));
+            draw_money_sparkline(ui, &game_log.money_history(&player.name, player.money));
+            ui.end_row();
+        });
+}
+
+/// A small line chart of a player's money over the game, with the lowest and highest amounts
+fn draw_money_sparkline(ui: &mut egui::Ui, history: &[i32]) {
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(120.0, 24.0), egui::Sense::hover());
+    let (Some(&low), Some(&high)) = (history.iter().min(), history.iter().max()) else { return };
+    let range = (high - low).max(1) as f32;
+    let steps = (history.len() - 1).max(1) as f32;
+    let points: Vec<egui::Pos2> = history
+        .iter()
+        .enumerate()
+        .map(|(i, &money)| {
+            egui::pos2(
+                rect.left() + rect.width() * i as f32 / steps,
+                rect.bottom() - rect.height() * (money - low) as f32 / range,
+            )
+        })
+        .collect();
+
+    ui.painter().rect_filled(rect, 2.0, egui::Color32::from_rgba_unmultiplied(0, 0, 0, 60));
+    if let [only] = points.as_slice() {
+        ui.painter().circle_filled(*only, 2.0, egui::Color32::GOLD);
+    } else {
+        ui.painter().add(egui::Shape::line(points.clone(), egui::Stroke::new(1.5, egui::Color32::GOLD)));
+        if let Some(last) = points.last() {
+            ui.painter().circle_filled(*last, 2.5, egui::Color32::GOLD);
+        }
+    }
+    response.on_hover_text(format!(
+        "From ${} to ${} (lowest ${}, highest ${})",
+        history[0],
+        history[history.len() - 1],
+        low,
+        high
+    ));
+}
+
+/// One die per camel, plus the crazy camels' gray die: dice still in the pyramid in
+/// color with the chance they come out this leg, rolled ones greyed out
+fn draw_pyramid_dice(ui: &mut egui::Ui, pyramid: &Pyramid, camel_palette: CamelPalette) {
+    const DIE_SIZE: f32 = 18.0;
+
+    let chance = pyramid.chance_to_roll();
+    let dice = CamelColor::all()
+        .into_iter()
+        .map(|color| (Some(color), camel_color_to_egui(color, camel_palette), pyramid.has_die(color)))
+        .chain(std::iter::once((None, egui::Color32::from_rgb(128, 128, 128), pyramid.has_crazy_die())));
+
+    for (camel, color, in_pyramid) in dice {
+        ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 1.0;
+            let (rect, response) = ui.allocate_exact_size(egui::vec2(DIE_SIZE, DIE_SIZE), egui::Sense::hover());
+            let fill = if in_pyramid { color } else { egui::Color32::from_rgba_unmultiplied(70, 70, 70, 120) };
+            ui.painter().rect_filled(rect, 3.0, fill);
+            ui.painter().rect_stroke(
+                rect,
+                3.0,
+                egui::Stroke::new(1.0, if in_pyramid { egui::Color32::BLACK } else { egui::Color32::DARK_GRAY }),
+                egui::epaint::StrokeKind::Inside,
+            );
+            if in_pyramid && camel_palette.markers {
+                if let Some(camel) = camel {
+                    draw_color_letter(ui.painter(), rect.center(), DIE_SIZE * 0.7, camel);
                 }
             }
 
-            ui.add_space(4.0);
+            let name = camel.map_or("The crazy camels'".to_string(), |camel| format!("{:?}", camel));
+            if in_pyramid {
+                ui.label(egui::RichText::new(format!("{:.0}%", chance * 100.0)).size(10.0));
+                response.on_hover_text(format!(
+                    "{} die is still in the pyramid: {:.0}% chance it's rolled this leg",
+                    name,
+                    chance * 100.0
+                ));
+            } else {
+                ui.label(egui::RichText::new("-").size(10.0).color(text_color(egui::Color32::DARK_GRAY)));
+                response.on_hover_text(format!("{} die has been rolled this leg", name));
+            }
         });
+    }
 }
 
 /// Get sorted camels from animation state in current race ranking order
@@ -2133,7 +1499,7 @@ fn render_desktop_ui(
     pyramid: &Pyramid,
     leg_tiles: &LegBettingTiles,
     turn_state: &TurnState,
-    _placed_tiles: &PlacedSpectatorTiles,
+    placed_tiles: &PlacedSpectatorTiles,
     player_leg_bets: &PlayerLegBetsStore,
     player_pyramid_tokens: &PlayerPyramidTokens,
     race_bets: &RaceBets,
@@ -2146,7 +1512,23 @@ fn render_desktop_ui(
     camels: &Query<(&Camel, &BoardPosition), Without<PendingInitialMove>>,
     current_player_color: egui::Color32,
     _initial_rolls: &mut Option<ResMut<crate::systems::setup::InitialSetupRolls>>,
+    camel_names: &CamelNames,
+    bet_odds: Option<&SimulationResult>,
+    reminders: &ActiveReminders,
+    network_state: &NetworkState,
+    emotes: &mut Emotes,
+    game_log: &GameLog,
+    now: f64,
+    left_handed: bool,
+    camel_palette: CamelPalette,
 ) {
+    // Left-handed layout swaps the two side panels
+    let (actions_side, players_side) = if left_handed {
+        (egui::panel::Side::Right, egui::panel::Side::Left)
+    } else {
+        (egui::panel::Side::Left, egui::panel::Side::Right)
+    };
+
     // Bottom panel - Pyramid tokens display (Dice tents are now Bevy sprites)
     egui::TopBottomPanel::bottom("dice_info").show(ctx, |ui| {
         ui.add_space(8.0);
@@ -2159,6 +1541,9 @@ fn render_desktop_ui(
             ui.add_space(10.0);
             ui.label(format!("({} remaining in pyramid)", remaining));
 
+            ui.add_space(10.0);
+            draw_pyramid_dice(ui, pyramid, camel_palette);
+
             ui.add_space(30.0);
             ui.separator();
             ui.add_space(10.0);
@@ -2246,13 +1631,13 @@ fn render_desktop_ui(
             ui.label(
                 egui::RichText::new(count_text)
                     .size(12.0)
-                    .color(egui::Color32::GRAY),
+                    .color(text_color(egui::Color32::GRAY)),
             );
         });
     });
 
-    // Left panel - Current player info and actions
-    egui::SidePanel::left("player_panel").min_width(220.0).show(ctx, |ui| {
+    // Left panel (right when left-handed) - Current player info and actions
+    egui::SidePanel::new(actions_side, "player_panel").min_width(220.0).show(ctx, |ui| {
         ui.heading("Current Turn");
         ui.separator();
 
@@ -2267,7 +1652,7 @@ fn render_desktop_ui(
 
         ui.label(format!("Money: ${}", current.money));
         if current.is_ai {
-            ui.label(egui::RichText::new("(AI Player - thinking...)").italics().color(egui::Color32::GRAY));
+            ui.label(egui::RichText::new("(AI Player - thinking...)").italics().color(text_color(egui::Color32::GRAY)));
         }
 
         ui.add_space(20.0);
@@ -2286,10 +1671,10 @@ fn render_desktop_ui(
         ui.add_enabled_ui(can_act, |ui| {
             // Roll Pyramid button - pyramid shape with flip animation
             let pyramid_size = egui::vec2(75.0, 75.0);
-            let pyramid_response = draw_pyramid_button(ui, pyramid_size, ui_state.pyramid_flip_anim);
+            let pyramid_response = draw_pyramid_button(ui, pyramid_size, &Palette::GOLD, ui_state.pyramid_flip_anim);
             if pyramid_response.clicked() && ui_state.pyramid_flip_anim == 0.0 {
                 ui_state.pyramid_flip_anim = 0.01;  // Start flip animation
-                roll_action.write(RollPyramidAction);
+                roll_action.write(RollPyramidAction { result: None });
             }
             pyramid_response.on_hover_text("Roll a random die from the pyramid.\nYou earn $1.");
 
@@ -2301,12 +1686,7 @@ fn render_desktop_ui(
                 for (i, color) in CamelColor::all().iter().enumerate() {
                     let color = *color;
                     if let Some(tile) = leg_tiles.top_tile(color) {
-                        let camel_color = camel_color_to_egui(color);
-                        let border_color = egui::Color32::from_rgb(
-                            (camel_color.r() as f32 * 0.5) as u8,
-                            (camel_color.g() as f32 * 0.5) as u8,
-                            (camel_color.b() as f32 * 0.5) as u8,
-                        );
+                        let camel_color = camel_color_to_egui(color, camel_palette);
                         let tile_size = egui::vec2(42.0, 58.0);
 
                         // Create a clickable tile
@@ -2338,7 +1718,7 @@ fn render_desktop_ui(
                             top_half.min + egui::vec2(4.0, 4.0),
                             egui::vec2(top_half.width() - 8.0, top_half.height() - 8.0)
                         );
-                        draw_camel_silhouette(ui.painter(), camel_rect, camel_color, border_color);
+                        draw_camel_silhouette(ui.painter(), camel_rect, &Palette::camel(color, camel_palette));
 
                         // Bottom half - colored band with value
                         ui.painter().rect_filled(bottom_half.shrink2(egui::vec2(2.0, 0.0)), 2.0, camel_color);
@@ -2361,7 +1741,7 @@ fn render_desktop_ui(
 
                         // Handle click
                         if response.clicked() {
-                            leg_bet_action.write(TakeLegBetAction { color });
+                            leg_bet_action.write(TakeLegBetAction { color, from_host: false });
                         }
 
                         // Hover effect - gold glow border
@@ -2369,12 +1749,16 @@ fn render_desktop_ui(
                             ui.painter().rect_stroke(rect.expand(1.0), 5.0, egui::Stroke::new(3.0, egui::Color32::GOLD), egui::epaint::StrokeKind::Outside);
                         }
 
-                        response.on_hover_text(format!("{:?} - ${}\nEarn ${} if 1st, $1 if 2nd, -$1 otherwise", color, tile.value, tile.value));
+                        let mut hover = format!("{} - ${}\nEarn ${} if 1st, $1 if 2nd, -$1 otherwise", camel_names.name(color), tile.value, tile.value);
+                        if let Some(odds) = bet_odds {
+                            hover.push_str(&format!("\n{}", format_expected_value(odds.leg_bet_value(color, tile.value))));
+                        }
+                        response.on_hover_text(hover);
                     } else {
                         // No tile available - show empty/faded slot
                         ui_state.leg_bet_card_positions[i] = None;
 
-                        let camel_color = camel_color_to_egui(color);
+                        let camel_color = camel_color_to_egui(color, camel_palette);
                         let faded = egui::Color32::from_rgba_unmultiplied(
                             camel_color.r(), camel_color.g(), camel_color.b(), 40
                         );
@@ -2400,7 +1784,7 @@ fn render_desktop_ui(
                             (camel_color.b() as f32 * 0.5) as u8,
                             60
                         );
-                        draw_camel_silhouette(ui.painter(), camel_rect, faded, faded_border);
+                        draw_camel_silhouette(ui.painter(), camel_rect, &Palette::outlined(faded, faded_border));
 
                         // Draw X to indicate no tiles left
                         ui.painter().line_segment(
@@ -2412,7 +1796,7 @@ fn render_desktop_ui(
                             egui::Stroke::new(2.0, egui::Color32::from_rgba_unmultiplied(100, 100, 100, 150))
                         );
 
-                        response.on_hover_text(format!("{:?} - No tiles left", color));
+                        response.on_hover_text(format!("{} - No tiles left", camel_names.name(color)));
                     }
                 }
             });
@@ -2431,8 +1815,8 @@ fn render_desktop_ui(
                     draw_spectator_tile_card(
                         ui.painter(),
                         card_rect,
+                        &Palette::shaded(current_player_color),
                         current.character_id,
-                        current_player_color,
                         ui_state.spectator_tile_is_oasis,
                         ui_state.spectator_tile_flip_anim,
                     );
@@ -2471,13 +1855,28 @@ fn render_desktop_ui(
                         egui::Color32::from_rgb(50, 50, 60)
                     };
                     ui.painter().rect_filled(flip_rect, 3.0, flip_bg);
-                    draw_flip_icon(ui.painter(), flip_rect.center(), flip_size * 0.75, egui::Color32::from_rgb(200, 200, 210));
+                    draw_flip_icon(ui.painter(), flip_rect.center(), &Palette::solid(egui::Color32::from_rgb(200, 200, 210)), flip_size * 0.75);
                     if flip_response.clicked() && ui_state.spectator_tile_flip_anim == 0.0 {
                         // Start flip animation (will animate from 0 to 1 in update system)
                         ui_state.spectator_tile_flip_anim = 0.001; // Signal to start animation
                     }
                     flip_response.on_hover_text("Flip tile to show other side");
                 });
+
+                if reminders.has(Reminder::SpectatorTileLastChance) {
+                    egui::Frame::new()
+                        .fill(panel_fill(REMINDER_FILL))
+                        .stroke(panel_stroke(egui::Stroke::NONE))
+                        .corner_radius(egui::CornerRadius::same(8))
+                        .inner_margin(egui::Margin::symmetric(6, 2))
+                        .show(ui, |ui| {
+                            ui.label(
+                                egui::RichText::new(Reminder::SpectatorTileLastChance.message())
+                                    .size(11.0)
+                                    .color(REMINDER_TEXT),
+                            );
+                        });
+                }
             }
 
             ui.add_space(8.0);
@@ -2505,7 +1904,7 @@ fn render_desktop_ui(
                     winner_rect.center() + egui::vec2(0.0, -10.0),
                     egui::vec2(icon_size, icon_size)
                 );
-                draw_camel_with_crown(ui.painter(), icon_rect);
+                draw_camel_with_crown(ui.painter(), icon_rect, &Palette::GREY_CAMEL);
                 // Draw text below icon
                 ui.painter().text(
                     winner_rect.center() + egui::vec2(0.0, 22.0),
@@ -2539,7 +1938,7 @@ fn render_desktop_ui(
                     loser_rect.center() + egui::vec2(0.0, -10.0),
                     egui::vec2(icon_size, icon_size)
                 );
-                draw_camel_with_dunce_cap(ui.painter(), icon_rect);
+                draw_camel_with_dunce_cap(ui.painter(), icon_rect, &Palette::GREY_CAMEL);
                 // Draw text below icon
                 ui.painter().text(
                     loser_rect.center() + egui::vec2(0.0, 22.0),
@@ -2562,32 +1961,47 @@ fn render_desktop_ui(
         }
     });
 
-    // Right panel - All players and camel positions
-    egui::SidePanel::right("players_list")
+    // Right panel (left when left-handed) - All players and camel positions
+    egui::SidePanel::new(players_side, "players_list")
         .min_width(280.0)
         .show(ctx, |ui| {
+            let panel_clip = ui.clip_rect();
             egui::ScrollArea::vertical().show(ui, |ui| {
                 ui.heading("Players");
                 ui.separator();
 
+                // Quick reactions for the rest of the table (online games)
+                if network_state.is_online() && network_state.local_player_index.is_some() {
+                    ui.horizontal(|ui| {
+                        let cooling_down = emotes.cooling_down(now);
+                        for emote in Emote::ALL {
+                            let button = egui::Button::new(egui::RichText::new(emote.symbol()).size(18.0));
+                            if ui.add_enabled(!cooling_down, button).clicked() {
+                                emotes.send(emote, now);
+                            }
+                        }
+                    });
+                    ui.separator();
+                }
+
                 for (i, player) in players.players.iter().enumerate() {
                     let is_current = i == players.current_player_index;
-                    let player_color = PLAYER_COLORS[player.color_index % PLAYER_COLORS.len()];
+                    let player_color = player_color_to_egui(player.color_index, player.custom_color);
 
                     // Player header with frame for current player
                     let frame = if is_current {
                         egui::Frame::group(ui.style())
-                            .stroke(egui::Stroke::new(2.0, egui::Color32::LIGHT_GREEN))
+                            .stroke(panel_stroke(egui::Stroke::new(2.0, egui::Color32::LIGHT_GREEN)))
                             .inner_margin(4.0)
                     } else {
                         egui::Frame::group(ui.style()).inner_margin(4.0)
                     };
 
                     frame.show(ui, |ui| {
-                        ui.horizontal(|ui| {
+                        let header = ui.horizontal(|ui| {
                             // Character avatar with colored border
                             let avatar_size = 40.0;
-                            let (rect, _) = ui.allocate_exact_size(
+                            let (rect, response) = ui.allocate_exact_size(
                                 egui::vec2(avatar_size, avatar_size),
                                 egui::Sense::hover(),
                             );
@@ -2597,11 +2011,22 @@ fn render_desktop_ui(
                                 player.character_id,
                                 Some(player_color),
                             );
+                            let is_absent = network_state.is_absent(i);
+                            if is_absent {
+                                draw_disconnected_badge(ui.painter(), rect);
+                                response.on_hover_text("Disconnected: the AI takes over if they don't come back soon");
+                            }
+                            if let Some(shown) = emotes.latest(i) {
+                                draw_emote_bubble(ui.ctx(), panel_clip, rect, shown, now);
+                            }
 
                             // Track current player's position for leg bet card animation
                             if is_current {
                                 ui_state.player_bet_area_pos = Some(rect.center());
                             }
+                            if let Some(slot) = ui_state.player_avatar_positions.get_mut(i) {
+                                *slot = Some(rect.center());
+                            }
 
                             ui.add_space(8.0);
 
@@ -2618,8 +2043,13 @@ fn render_desktop_ui(
                                 };
                                 ui.label(text);
 
-                                // Money and AI status on second line
-                                let ai_tag = if player.is_ai { " (AI)" } else { "" };
+                                // Money and AI (or connection) status on second line
+                                let ai_tag = match (player.is_ai, is_absent) {
+                                    (true, true) => " (AI, disconnected)",
+                                    (false, true) => " (disconnected)",
+                                    (true, false) => " (AI)",
+                                    (false, false) => "",
+                                };
                                 ui.label(
                                     egui::RichText::new(format!("${}{}", player.money, ai_tag))
                                         .size(12.0),
@@ -2627,6 +2057,16 @@ fn render_desktop_ui(
                             });
                         });
 
+                        // Click the header to expand the player's detail card
+                        let header = header
+                            .response
+                            .interact(egui::Sense::click())
+                            .on_hover_cursor(egui::CursorIcon::PointingHand);
+                        if header.clicked() {
+                            ui_state.expanded_player =
+                                if ui_state.expanded_player == Some(i) { None } else { Some(i) };
+                        }
+
                         // Show player's leg bets as overlapping mini cards
                         if i < player_leg_bets.bets.len() && !player_leg_bets.bets[i].is_empty() {
                             ui.horizontal(|ui| {
@@ -2642,7 +2082,10 @@ fn render_desktop_ui(
                                     desktop::MINI_LEG_BET_OVERLAP,
                                     |painter, rect, bet| {
                                         draw_mini_leg_bet_indicator(
-                                            painter, rect, bet.camel, bet.value,
+                                            painter,
+                                            rect,
+                                            &Palette::camel(bet.camel, camel_palette),
+                                            bet.value,
                                         );
                                     },
                                 );
@@ -2665,6 +2108,7 @@ fn render_desktop_ui(
                                         draw_pyramid_token_icon(
                                             painter,
                                             center,
+                                            &Palette::GOLD,
                                             desktop::PYRAMID_TOKEN_SIZE,
                                         );
                                     },
@@ -2701,7 +2145,7 @@ fn render_desktop_ui(
                                         .color(egui::Color32::LIGHT_GREEN),
                                 );
                                 for bet in winner_bets {
-                                    let camel_color = camel_color_to_egui(bet.camel);
+                                    let camel_color = camel_color_to_egui(bet.camel, camel_palette);
                                     let (rect, _) = ui.allocate_exact_size(
                                         egui::vec2(10.0, 10.0),
                                         egui::Sense::hover(),
@@ -2720,7 +2164,7 @@ fn render_desktop_ui(
                                         .color(egui::Color32::from_rgb(255, 100, 100)),
                                 );
                                 for bet in loser_bets {
-                                    let camel_color = camel_color_to_egui(bet.camel);
+                                    let camel_color = camel_color_to_egui(bet.camel, camel_palette);
                                     let (rect, _) = ui.allocate_exact_size(
                                         egui::vec2(10.0, 10.0),
                                         egui::Sense::hover(),
@@ -2729,6 +2173,19 @@ fn render_desktop_ui(
                                 }
                             });
                         }
+
+                        if ui_state.expanded_player == Some(i) {
+                            let leg_bets = player_leg_bets.bets.get(i).map_or(&[][..], |bets| bets.as_slice());
+                            draw_player_detail(
+                                ui,
+                                player,
+                                leg_bets,
+                                placed_tiles,
+                                game_log,
+                                camel_names,
+                                camel_palette,
+                            );
+                        }
                     });
 
                     ui.add_space(4.0);
@@ -2748,6 +2205,9 @@ fn render_desktop_ui(
                 // Silhouette size for camel icons
                 let silhouette_size = egui::vec2(50.0, 36.0);
 
+                // Hovering a row previews where that camel could land
+                ui_state.preview_camel = None;
+
                 for (rank, (color, space, _stack)) in camel_positions.iter().enumerate() {
                     // Get animation offset for this camel
                     let y_offset = camel_animations
@@ -2757,7 +2217,7 @@ fn render_desktop_ui(
                         .map(|a| a.current_y_offset)
                         .unwrap_or(0.0);
 
-                    ui.horizontal(|ui| {
+                    let row = ui.horizontal(|ui| {
                         // Rank indicator on the left with fixed width
                         let rank_text = match rank {
                             0 => "1st",
@@ -2788,7 +2248,7 @@ fn render_desktop_ui(
                         );
 
                         // Draw progress bar fill with camel color
-                        let camel_bar_color = camel_color_to_egui(*color);
+                        let camel_bar_color = camel_color_to_egui(*color, camel_palette);
                         let fill_width = bar_width * progress;
                         let fill_rect = egui::Rect::from_min_size(
                             bar_rect.min,
@@ -2807,13 +2267,6 @@ fn render_desktop_ui(
                         // Add flexible spacer to push camel to the right
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                             // Draw camel silhouette on the right with animation offset
-                            let camel_egui_color = camel_color_to_egui(*color);
-                            let border_color = egui::Color32::from_rgb(
-                                (camel_egui_color.r() as f32 * 0.5) as u8,
-                                (camel_egui_color.g() as f32 * 0.5) as u8,
-                                (camel_egui_color.b() as f32 * 0.5) as u8,
-                            );
-
                             let (rect, _) =
                                 ui.allocate_exact_size(silhouette_size, egui::Sense::hover());
                             // Apply animation offset to the silhouette position
@@ -2821,11 +2274,13 @@ fn render_desktop_ui(
                             draw_camel_silhouette(
                                 ui.painter(),
                                 animated_rect,
-                                camel_egui_color,
-                                border_color,
+                                &Palette::camel(*color, camel_palette),
                             );
                         });
                     });
+                    if row.response.contains_pointer() {
+                        ui_state.preview_camel = Some(*color);
+                    }
 
                     // Add spacing between rows
                     if rank < 4 {
@@ -2836,7 +2291,7 @@ fn render_desktop_ui(
         });
 
     // Dice roll toast notification - floating below the right panel
-    render_dice_toast_floating(ctx, ui_state);
+    render_dice_toast_floating(ctx, ui_state, camel_names, camel_palette);
 }
 
 /// Render shared popup windows (race betting, spectator tile placement, dice result)
@@ -2852,6 +2307,9 @@ fn render_popup_windows(
     camels: &Query<(&Camel, &BoardPosition), Without<PendingInitialMove>>,
     crazy_camels: &Query<(&CrazyCamel, &BoardPosition), Without<PendingInitialMove>>,
     current_player_color: egui::Color32,
+    camel_names: &CamelNames,
+    bet_odds: Option<&SimulationResult>,
+    camel_palette: CamelPalette,
 ) {
     // Winner betting popup window
     if ui_state.show_winner_betting {
@@ -2861,7 +2319,7 @@ fn render_popup_windows(
             .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
             .show(ctx, |ui| {
                 let current = players.current_player();
-                let player_color = PLAYER_COLORS[current.color_index % PLAYER_COLORS.len()];
+                let player_color = player_color_to_egui(current.color_index, current.custom_color);
                 let character_id = current.character_id;
 
                 ui.horizontal(|ui| {
@@ -2895,7 +2353,7 @@ fn render_popup_windows(
                         ui.label(
                             egui::RichText::new("Choose a camel you think will WIN:")
                                 .size(12.0)
-                                .color(egui::Color32::GRAY),
+                                .color(text_color(egui::Color32::GRAY)),
                         );
                         ui.add_space(8.0);
 
@@ -2910,7 +2368,8 @@ fn render_popup_windows(
                                     draw_race_bet_card(
                                         ui.painter(),
                                         rect,
-                                        color,
+                                        &Palette::camel(color, camel_palette),
+                                        &camel_names.name(color),
                                         character_id,
                                         player_color,
                                         response.hovered(),
@@ -2920,11 +2379,17 @@ fn render_popup_windows(
                                         race_bet_action.write(PlaceRaceBetAction {
                                             color,
                                             is_winner_bet: true,
+                                            from_host: false,
                                         });
                                         ui_state.show_winner_betting = false;
                                     }
 
-                                    response.on_hover_text(format!("Bet on {:?} to WIN", color));
+                                    let mut hover = format!("Bet on {} to WIN", camel_names.name(color));
+                                    if let Some(odds) = bet_odds {
+                                        let value = odds.race_bet_value(color, true, race_bets.winner_bets.len());
+                                        hover.push_str(&format!("\n{}", format_expected_value(value)));
+                                    }
+                                    response.on_hover_text(hover);
                                 } else {
                                     // Determine if this card was used for winner or loser bet
                                     let bet_type = if race_bets
@@ -2939,11 +2404,12 @@ fn render_popup_windows(
                                     draw_race_bet_card_unavailable(
                                         ui.painter(),
                                         rect,
-                                        color,
+                                        &Palette::camel(color, camel_palette),
+                                        &camel_names.name(color),
                                         bet_type,
                                     );
                                     response
-                                        .on_hover_text(format!("{:?} card already used", color));
+                                        .on_hover_text(format!("{} card already used", camel_names.name(color)));
                                 }
                             }
                         });
@@ -2968,7 +2434,7 @@ fn render_popup_windows(
             .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
             .show(ctx, |ui| {
                 let current = players.current_player();
-                let player_color = PLAYER_COLORS[current.color_index % PLAYER_COLORS.len()];
+                let player_color = player_color_to_egui(current.color_index, current.custom_color);
                 let character_id = current.character_id;
 
                 ui.horizontal(|ui| {
@@ -3002,7 +2468,7 @@ fn render_popup_windows(
                         ui.label(
                             egui::RichText::new("Choose a camel you think will LOSE:")
                                 .size(12.0)
-                                .color(egui::Color32::GRAY),
+                                .color(text_color(egui::Color32::GRAY)),
                         );
                         ui.add_space(8.0);
 
@@ -3017,7 +2483,8 @@ fn render_popup_windows(
                                     draw_race_bet_card(
                                         ui.painter(),
                                         rect,
-                                        color,
+                                        &Palette::camel(color, camel_palette),
+                                        &camel_names.name(color),
                                         character_id,
                                         player_color,
                                         response.hovered(),
@@ -3027,11 +2494,17 @@ fn render_popup_windows(
                                         race_bet_action.write(PlaceRaceBetAction {
                                             color,
                                             is_winner_bet: false,
+                                            from_host: false,
                                         });
                                         ui_state.show_loser_betting = false;
                                     }
 
-                                    response.on_hover_text(format!("Bet on {:?} to LOSE", color));
+                                    let mut hover = format!("Bet on {} to LOSE", camel_names.name(color));
+                                    if let Some(odds) = bet_odds {
+                                        let value = odds.race_bet_value(color, false, race_bets.loser_bets.len());
+                                        hover.push_str(&format!("\n{}", format_expected_value(value)));
+                                    }
+                                    response.on_hover_text(hover);
                                 } else {
                                     // Determine if this card was used for winner or loser bet
                                     let bet_type = if race_bets
@@ -3046,11 +2519,12 @@ fn render_popup_windows(
                                     draw_race_bet_card_unavailable(
                                         ui.painter(),
                                         rect,
-                                        color,
+                                        &Palette::camel(color, camel_palette),
+                                        &camel_names.name(color),
                                         bet_type,
                                     );
                                     response
-                                        .on_hover_text(format!("{:?} card already used", color));
+                                        .on_hover_text(format!("{} card already used", camel_names.name(color)));
                                 }
                             }
                         });
@@ -3093,8 +2567,8 @@ fn render_popup_windows(
                     draw_spectator_tile_card(
                         ui.painter(),
                         preview_rect,
+                        &Palette::shaded(current_player_color),
                         players.current_player().character_id,
-                        current_player_color,
                         ui_state.spectator_tile_is_oasis,
                         0.0,
                     );
@@ -3112,7 +2586,7 @@ fn render_popup_windows(
                         "(Cannot place on space 1, spaces with camels, or other tiles)",
                     )
                     .small()
-                    .color(egui::Color32::GRAY),
+                    .color(text_color(egui::Color32::GRAY)),
                 );
                 ui.add_space(6.0);
 
@@ -3168,6 +2642,7 @@ fn render_popup_windows(
                             spectator_tile_action.write(PlaceSpectatorTileAction {
                                 space_index: selected_space,
                                 is_oasis: ui_state.spectator_tile_is_oasis,
+                                from_host: false,
                             });
                             ui_state.show_spectator_tile = false;
                             ui_state.spectator_tile_space = None;
@@ -3185,7 +2660,12 @@ fn render_popup_windows(
 }
 
 /// Render dice roll toast notification as a floating element that slides down from top panels
-fn render_dice_toast_floating(ctx: &egui::Context, ui_state: &UiState) {
+fn render_dice_toast_floating(
+    ctx: &egui::Context,
+    ui_state: &UiState,
+    camel_names: &CamelNames,
+    camel_palette: CamelPalette,
+) {
     // Only show after delay completes (waits for dice shake animation)
     if ui_state.dice_popup_timer <= 0.0 || ui_state.dice_popup_delay > 0.0 {
         return;
@@ -3223,9 +2703,9 @@ fn render_dice_toast_floating(ctx: &egui::Context, ui_state: &UiState) {
 
     let (color_name, value, camel_color, is_crazy) = match last_roll {
         LastRoll::Regular(color, value) => (
-            format!("{:?}", color),
+            camel_names.name(*color),
             *value,
-            camel_color_to_egui(*color),
+            camel_color_to_egui(*color, camel_palette),
             false,
         ),
         LastRoll::Crazy(color, value) => (
@@ -3260,16 +2740,16 @@ fn render_dice_toast_floating(ctx: &egui::Context, ui_state: &UiState) {
         .order(egui::Order::Foreground)
         .show(ctx, |ui| {
             let toast_frame = egui::Frame::new()
-                .fill(bg_color)
-                .stroke(egui::Stroke::new(2.0, border_color))
+                .fill(panel_fill(bg_color))
+                .stroke(panel_stroke(egui::Stroke::new(2.0, border_color)))
                 .corner_radius(egui::CornerRadius::same(8))
                 .inner_margin(egui::Margin::symmetric(12, 8))
-                .shadow(egui::epaint::Shadow {
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                     offset: [0, 4],
                     blur: 8,
                     spread: 0,
                     color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, (alpha * 100.0) as u8),
-                });
+                }));
 
             toast_frame.show(ui, |ui| {
                 ui.horizontal(|ui| {
@@ -3288,7 +2768,7 @@ fn render_dice_toast_floating(ctx: &egui::Context, ui_state: &UiState) {
                         camel_color.b(),
                         alpha_u8,
                     );
-                    draw_camel_silhouette(ui.painter(), rect, color_with_alpha, border);
+                    draw_camel_silhouette(ui.painter(), rect, &Palette::outlined(color_with_alpha, border));
 
                     ui.add_space(4.0);
 
@@ -3319,7 +2799,12 @@ fn render_dice_toast_floating(ctx: &egui::Context, ui_state: &UiState) {
     ctx.request_repaint();
 }
 
-fn render_dice_toast(ui: &mut egui::Ui, ui_state: &UiState) {
+fn render_dice_toast(
+    ui: &mut egui::Ui,
+    ui_state: &UiState,
+    camel_names: &CamelNames,
+    camel_palette: CamelPalette,
+) {
     // Safety check
     let Some(ref last_roll) = ui_state.last_roll else {
         return;
@@ -3349,9 +2834,9 @@ fn render_dice_toast(ui: &mut egui::Ui, ui_state: &UiState) {
 
     let (color_name, value, camel_color, is_crazy) = match last_roll {
         LastRoll::Regular(color, value) => (
-            format!("{:?}", color),
+            camel_names.name(*color),
             *value,
-            camel_color_to_egui(*color),
+            camel_color_to_egui(*color, camel_palette),
             false,
         ),
         LastRoll::Crazy(color, value) => (
@@ -3379,16 +2864,16 @@ fn render_dice_toast(ui: &mut egui::Ui, ui_state: &UiState) {
     ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
         ui.add_space(8.0 + y_offset);
 
-        let shadow = egui::Shadow {
+        let shadow = quality_shadow(ui.ctx(), egui::Shadow {
             offset: [2, 2],
             blur: 10,
             spread: 0,
             color: egui::Color32::from_black_alpha(96),
-        };
+        });
 
         let toast_frame = egui::Frame::new()
-            .fill(bg_color)
-            .stroke(egui::Stroke::new(2.0, border_color))
+            .fill(panel_fill(bg_color))
+            .stroke(panel_stroke(egui::Stroke::new(2.0, border_color)))
             .corner_radius(egui::CornerRadius::same(6))
             .inner_margin(egui::Margin::symmetric(12, 8))
             .shadow(shadow); // Use the manually created shadow
@@ -3408,7 +2893,7 @@ fn render_dice_toast(ui: &mut egui::Ui, ui_state: &UiState) {
                     alpha_u8,
                 );
 
-                draw_camel_silhouette(ui.painter(), rect, fill_color, fill_color);
+                draw_camel_silhouette(ui.painter(), rect, &Palette::outlined(fill_color, fill_color));
 
                 ui.label(
                     egui::RichText::new(&color_name)
@@ -3465,6 +2950,392 @@ pub fn update_ui_on_crazy_roll(
     }
 }
 
+/// Draw a labeled probability bar filled in the camel's color
+fn draw_probability_bar(ui: &mut egui::Ui, label: &str, probability: f32, color: egui::Color32) {
+    ui.label(egui::RichText::new(label).size(11.0).color(text_color(egui::Color32::GRAY)));
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(80.0, 10.0), egui::Sense::hover());
+    ui.painter().rect_filled(rect, 3.0, egui::Color32::from_rgb(0x3A, 0x32, 0x2A));
+    let mut filled = rect;
+    filled.set_width(rect.width() * probability.clamp(0.0, 1.0));
+    ui.painter().rect_filled(filled, 3.0, color);
+    ui.label(egui::RichText::new(format!("{:>3.0}%", probability * 100.0)).size(11.0));
+}
+
+/// Collapsible panel with each camel's chance to win the current leg and the race,
+/// refreshed after every roll
+pub fn probability_panel_ui(
+    mut contexts: EguiContexts,
+    ui_state: Res<UiState>,
+    race_odds: Res<RaceOdds>,
+    camel_names: Res<CamelNames>,
+    camel_palette: Res<CamelPalette>,
+) {
+    if !ui_state.initial_rolls_complete || ui_state.show_leg_scoring {
+        return;
+    }
+    let Some(odds) = race_odds.odds() else { return };
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let is_mobile = !ui_state.use_side_panels;
+    egui::Window::new("Win Chances")
+        .collapsible(true)
+        .default_open(!is_mobile)
+        .resizable(false)
+        .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -90.0))
+        .show(ctx, |ui| {
+            let mut colors = CamelColor::all().to_vec();
+            colors.sort_by(|a, b| odds.race_winner(*b).total_cmp(&odds.race_winner(*a)));
+            for color in colors {
+                let camel_color = camel_color_to_egui(color, *camel_palette);
+                ui.label(
+                    egui::RichText::new(camel_names.name(color))
+                        .size(12.0)
+                        .strong()
+                        .color(camel_color),
+                );
+                ui.horizontal(|ui| {
+                    draw_probability_bar(ui, "Leg", odds.leg_first(color), camel_color);
+                    ui.add_space(6.0);
+                    draw_probability_bar(ui, "Race", odds.race_winner(color), camel_color);
+                });
+            }
+        });
+}
+
+/// Banner showing the active Desert Events rule change at the top of the board
+pub fn desert_event_banner_ui(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<UiState>,
+    active_event: Option<Res<ActiveEvent>>,
+) {
+    let Some(active_event) = active_event else { return };
+    let Some(event) = active_event.event else { return };
+    if !active_event.enabled || ui_state.show_leg_scoring {
+        return;
+    }
+
+    // Drop any spectator tile placement in progress while tiles are banned
+    if active_event.spectator_tiles_banned() {
+        ui_state.show_spectator_tile = false;
+        ui_state.spectator_tile_selected = false;
+        ui_state.spectator_tile_space = None;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let board_top = ui_state.game_board_rect.map(|rect| rect.top()).unwrap_or(0.0);
+
+    egui::Area::new(egui::Id::new("desert_event_banner"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, board_top + 6.0))
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0x5A, 0x4D, 0x40, 220)))
+                .stroke(panel_stroke(egui::Stroke::new(1.5, GOLD_LIGHT)))
+                .corner_radius(egui::CornerRadius::same(6))
+                .inner_margin(egui::Margin::symmetric(10, 4))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(event.name())
+                                .size(14.0)
+                                .strong()
+                                .color(GOLD_LIGHT),
+                        );
+                        ui.label(
+                            egui::RichText::new(event.description())
+                                .size(12.0)
+                                .color(egui::Color32::WHITE),
+                        );
+                    });
+                });
+        });
+}
+
+/// Relay Race: which track the camels are on, and how the last one finished
+pub fn relay_track_banner_ui(
+    mut contexts: EguiContexts,
+    ui_state: Res<UiState>,
+    relay: Option<Res<RelayRace>>,
+    board: Option<Res<GameBoard>>,
+    camel_names: Res<CamelNames>,
+    camel_palette: Res<CamelPalette>,
+) {
+    let (Some(relay), Some(board)) = (relay, board) else { return };
+    if !relay.enabled || ui_state.show_leg_scoring {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let Some(rect) = ui_state.game_board_rect else { return };
+
+    egui::Area::new(egui::Id::new("relay_track_banner"))
+        .fixed_pos(egui::pos2(rect.left() + 6.0, rect.top() + 6.0))
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0x5A, 0x4D, 0x40, 220)))
+                .stroke(panel_stroke(egui::Stroke::new(1.5, GOLD_LIGHT)))
+                .corner_radius(egui::CornerRadius::same(6))
+                .inner_margin(egui::Margin::symmetric(8, 4))
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "Track {} of {}",
+                            board.active_track + 1,
+                            board.track_count()
+                        ))
+                        .size(13.0)
+                        .strong()
+                        .color(GOLD_LIGHT),
+                    );
+                    if relay.handing_off() {
+                        ui.label(
+                            egui::RichText::new("Camels line up for the next track...")
+                                .size(11.0)
+                                .color(egui::Color32::WHITE),
+                        );
+                    }
+                    // Previous track's finish, which seeded the current start
+                    if let Some(order) = relay.finish_orders.last() {
+                        ui.horizontal(|ui| {
+                            ui.spacing_mut().item_spacing.x = 3.0;
+                            ui.label(egui::RichText::new("Seeded:").size(11.0).color(egui::Color32::WHITE));
+                            for color in order {
+                                ui.label(
+                                    egui::RichText::new(camel_names.name(*color))
+                                        .size(11.0)
+                                        .strong()
+                                        .color(camel_color_to_egui(*color, *camel_palette)),
+                                );
+                            }
+                        });
+                    }
+                });
+        });
+}
+
+/// Banner explaining a failed in-game sync while playing online
+pub fn network_error_banner_ui(
+    mut contexts: EguiContexts,
+    mut network_state: ResMut<NetworkState>,
+) {
+    let Some(failure) = network_state.connection_error.as_ref() else { return };
+    if failure.operation.is_room_setup() {
+        return;
+    }
+    let message = failure.error.user_message();
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let mut dismissed = false;
+    egui::Area::new(egui::Id::new("network_error_banner"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -12.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(120, 30, 20, 230)))
+                .stroke(panel_stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 100, 100))))
+                .corner_radius(egui::CornerRadius::same(6))
+                .inner_margin(egui::Margin::symmetric(10, 6))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(message).size(13.0).color(egui::Color32::WHITE));
+                        if desert_button(ui, "OK", &DesertButtonStyle::small()).clicked() {
+                            dismissed = true;
+                        }
+                    });
+                });
+        });
+
+    if dismissed {
+        network_state.connection_error = None;
+    }
+}
+
+/// Banner shown while moves wait for the connection to come back, so nobody thinks
+/// they were lost
+pub fn write_retry_banner_ui(
+    mut contexts: EguiContexts,
+    network_state: Res<NetworkState>,
+    time: Res<Time>,
+) {
+    let queue = &network_state.write_queue;
+    if !network_state.is_online() || !queue.is_stalled(time.elapsed_secs_f64()) {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let waiting = queue.len();
+    let message = if waiting == 1 {
+        "Connection lost - retrying (1 move waiting)".to_string()
+    } else {
+        format!("Connection lost - retrying ({} moves waiting)", waiting)
+    };
+    egui::Area::new(egui::Id::new("write_retry_banner"))
+        .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -52.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(40, 40, 50, 240)))
+                .stroke(panel_stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 200, 80))))
+                .corner_radius(egui::CornerRadius::same(6))
+                .inner_margin(egui::Margin::symmetric(10, 6))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(egui::RichText::new(message).size(13.0).color(egui::Color32::WHITE));
+                    });
+                });
+        });
+}
+
+/// Toast shown while an online client waits for the host's state after falling out of step
+pub fn resync_toast_ui(
+    mut contexts: EguiContexts,
+    network_state: Res<NetworkState>,
+) {
+    if !network_state.resyncing {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    egui::Area::new(egui::Id::new("resync_toast"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 48.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(40, 40, 50, 240)))
+                .stroke(panel_stroke(egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 200, 80))))
+                .corner_radius(egui::CornerRadius::same(8))
+                .inner_margin(egui::Margin::symmetric(12, 8))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(
+                            egui::RichText::new("Out of step with the host, resyncing...")
+                                .size(14.0)
+                                .color(egui::Color32::WHITE),
+                        );
+                    });
+                });
+        });
+}
+
+/// Dim a player's avatar and mark it with a red dot while they're gone from the room
+fn draw_disconnected_badge(painter: &egui::Painter, rect: egui::Rect) {
+    painter.rect_filled(rect, 4.0, egui::Color32::from_rgba_unmultiplied(0, 0, 0, 140));
+    let radius = (rect.width() * 0.18).max(4.0);
+    let center = egui::pos2(rect.right() - radius * 0.6, rect.top() + radius * 0.6);
+    painter.circle(center, radius, egui::Color32::from_rgb(220, 60, 50), egui::Stroke::new(1.5, egui::Color32::WHITE));
+}
+
+/// Float a player's emote up from the top of their avatar, fading out at the end
+fn draw_emote_bubble(ctx: &egui::Context, clip: egui::Rect, avatar: egui::Rect, shown: &ShownEmote, now: f64) {
+    let age = ((now - shown.shown_at) / EMOTE_SECS).clamp(0.0, 1.0) as f32;
+    let alpha = if age < 0.7 { 1.0 } else { 1.0 - (age - 0.7) / 0.3 };
+    let size = 30.0;
+    let center = egui::pos2(avatar.center().x, avatar.top() - size * 0.3 - age * 12.0);
+    let bubble = egui::Rect::from_center_size(center, egui::vec2(size, size));
+
+    let painter = ctx
+        .layer_painter(egui::LayerId::new(egui::Order::Foreground, egui::Id::new(("emote_bubble", shown.seat))))
+        .with_clip_rect(clip);
+    let fill = egui::Color32::WHITE.gamma_multiply(alpha);
+    painter.add(egui::Shape::convex_polygon(
+        vec![
+            egui::pos2(center.x - 5.0, bubble.bottom() - 1.0),
+            egui::pos2(center.x + 5.0, bubble.bottom() - 1.0),
+            egui::pos2(center.x, bubble.bottom() + 6.0),
+        ],
+        fill,
+        egui::Stroke::NONE,
+    ));
+    painter.rect_filled(bubble, 8.0, fill);
+    painter.text(
+        center,
+        egui::Align2::CENTER_CENTER,
+        shown.emote.symbol(),
+        egui::FontId::proportional(18.0),
+        egui::Color32::from_rgb(70, 45, 20).gamma_multiply(alpha),
+    );
+}
+
+/// Time left for the current turn, when the room plays with a turn timer
+pub fn turn_timer_ui(
+    mut contexts: EguiContexts,
+    network_state: Res<NetworkState>,
+) {
+    let Some(left) = network_state.turn_time_left else { return };
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let seconds = left.ceil() as u32;
+    let color = if seconds <= 10 {
+        egui::Color32::from_rgb(255, 110, 90)
+    } else {
+        egui::Color32::WHITE
+    };
+    egui::Area::new(egui::Id::new("turn_timer"))
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 48.0))
+        .order(egui::Order::Foreground)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(40, 40, 50, 220)))
+                .stroke(panel_stroke(egui::Stroke::NONE))
+                .corner_radius(egui::CornerRadius::same(8))
+                .inner_margin(egui::Margin::symmetric(12, 6))
+                .show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new(format!("Turn time: {}s", seconds))
+                            .size(14.0)
+                            .color(color),
+                    );
+                });
+        });
+}
+
+/// Floating money changes: each "+$1" / "-$1" rises from the player's avatar and fades
+pub fn money_delta_popups_ui(
+    mut contexts: EguiContexts,
+    ui_state: Res<UiState>,
+    popups: Query<(Entity, &MoneyDeltaPopup)>,
+) {
+    if popups.is_empty() {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    for (entity, popup) in popups.iter() {
+        let Some(Some(avatar)) = ui_state.player_avatar_positions.get(popup.player_index) else { continue };
+        let progress = popup.progress();
+
+        // Ease out as it rises; hold full opacity for the first half, then fade
+        let rise = 36.0 * (1.0 - (1.0 - progress).powi(2));
+        let alpha = (1.0 - ((progress - 0.5) * 2.0).max(0.0)) * 255.0;
+        let (text, rgb) = if popup.delta >= 0 {
+            (format!("+${}", popup.delta), (120, 230, 120))
+        } else {
+            (format!("-${}", -popup.delta), (255, 110, 110))
+        };
+
+        egui::Area::new(egui::Id::new(("money_delta_popup", entity)))
+            .order(egui::Order::Tooltip)
+            .interactable(false)
+            .pivot(egui::Align2::CENTER_BOTTOM)
+            .fixed_pos(*avatar - egui::vec2(0.0, 14.0 + rise))
+            .show(ctx, |ui| {
+                ui.label(
+                    egui::RichText::new(text)
+                        .size(16.0)
+                        .strong()
+                        .color(egui::Color32::from_rgba_unmultiplied(rgb.0, rgb.1, rgb.2, alpha as u8))
+                        .background_color(egui::Color32::from_rgba_unmultiplied(0, 0, 0, (alpha * 0.5) as u8)),
+                );
+            });
+    }
+}
+
 /// System to update dice popup timer
 pub fn update_dice_popup_timer(time: Res<Time>, mut ui_state: ResMut<UiState>) {
     // Count down delay first (waits for dice shake animation)
@@ -3637,9 +3508,9 @@ pub fn update_camel_position_animations(
                         anim.current_y_offset += direction * step;
 
                         // Clamp to target if we overshoot
-                        if direction > 0.0 && anim.current_y_offset > anim.target_y_offset {
-                            anim.current_y_offset = anim.target_y_offset;
-                        } else if direction < 0.0 && anim.current_y_offset < anim.target_y_offset {
+                        if (direction > 0.0 && anim.current_y_offset > anim.target_y_offset)
+                            || (direction < 0.0 && anim.current_y_offset < anim.target_y_offset)
+                        {
                             anim.current_y_offset = anim.target_y_offset;
                         }
                     } else {
@@ -3665,9 +3536,9 @@ pub fn update_camel_position_animations(
             anim.current_podium_y += direction * step;
 
             // Clamp to target if we overshoot
-            if direction > 0.0 && anim.current_podium_y > anim.target_podium_y {
-                anim.current_podium_y = anim.target_podium_y;
-            } else if direction < 0.0 && anim.current_podium_y < anim.target_podium_y {
+            if (direction > 0.0 && anim.current_podium_y > anim.target_podium_y)
+                || (direction < 0.0 && anim.current_podium_y < anim.target_podium_y)
+            {
                 anim.current_podium_y = anim.target_podium_y;
             }
         } else {
@@ -3676,38 +3547,46 @@ pub fn update_camel_position_animations(
     }
 }
 
+/// The bets and tokens a leg is scored on, shown in the scoring modal
+#[derive(SystemParam)]
+pub struct LegScores<'w> {
+    players: Option<Res<'w, Players>>,
+    player_leg_bets: Option<Res<'w, PlayerLegBetsStore>>,
+    player_pyramid_tokens: Option<Res<'w, PlayerPyramidTokens>>,
+    sheikh: Res<'w, SheikhBettor>,
+}
+
+/// A player's row in the scoring modal: (name, leg_bet_total, bet_details, pyramid_tokens)
+pub type LegScoreChange = (String, i32, Vec<(CamelColor, u8, i32)>, u8);
+
 /// System to show leg scoring modal popup
 pub fn leg_scoring_modal_ui(
     mut contexts: EguiContexts,
-    mut ui_state: ResMut<UiState>,
-    mut players: Option<ResMut<Players>>,
-    mut pyramid: Option<ResMut<Pyramid>>,
-    mut leg_tiles: Option<ResMut<LegBettingTiles>>,
-    mut player_leg_bets: Option<ResMut<PlayerLegBetsStore>>,
-    mut player_pyramid_tokens: Option<ResMut<PlayerPyramidTokens>>,
-    mut turn_state: Option<ResMut<TurnState>>,
-    mut placed_tiles: Option<ResMut<PlacedSpectatorTiles>>,
+    ui_state: Res<UiState>,
+    scores: LegScores,
     camels: Query<(&Camel, &BoardPosition), Without<PendingInitialMove>>,
-    spectator_tile_entities: Query<Entity, With<SpectatorTile>>,
-    dice_sprite_entities: Query<Entity, With<crate::systems::animation::DiceSprite>>,
-    mut commands: Commands,
+    game_log: Res<GameLog>,
+    (leg_fairness, autoplay, camel_palette): (Res<LegFairness>, Res<AutoplayConfig>, Res<CamelPalette>),
+    mut next_leg: MessageWriter<StartNextLegAction>,
 ) {
+    let LegScores { players, player_leg_bets, player_pyramid_tokens, sheikh } = scores;
     if !ui_state.show_leg_scoring {
         return;
     }
 
-    let Some(ref mut players) = players else {
+    let Some(ref players) = players else {
         return;
     };
     let Ok(ctx) = contexts.ctx_mut() else { return };
+    let camel_names = game_log.camel_names.clone();
+    let camel_palette = *camel_palette;
 
     // Calculate scores for display
     let first_place = get_leading_camel(&camels);
     let second_place = get_second_place_camel(&camels);
 
     // Calculate score changes for each player
-    // Structure: (name, leg_bet_total, bet_details, pyramid_tokens)
-    let mut score_changes: Vec<(String, i32, Vec<(CamelColor, u8, i32)>, u8)> = Vec::new();
+    let mut score_changes: Vec<LegScoreChange> = Vec::new();
 
     if let Some(ref player_leg_bets) = player_leg_bets {
         for (player_idx, player) in players.players.iter().enumerate() {
@@ -3716,13 +3595,7 @@ pub fn leg_scoring_modal_ui(
 
             if player_idx < player_leg_bets.bets.len() {
                 for tile in &player_leg_bets.bets[player_idx] {
-                    let change = if Some(tile.camel) == first_place {
-                        tile.value as i32
-                    } else if Some(tile.camel) == second_place {
-                        1
-                    } else {
-                        -1
-                    };
+                    let change = leg_tile_payout(tile, first_place, second_place);
                     leg_bet_total += change;
                     bet_details.push((tile.camel, tile.value, change));
                 }
@@ -3748,6 +3621,18 @@ pub fn leg_scoring_modal_ui(
         }
     }
 
+    // The neutral Sheikh's tiles are scored like a player's but it isn't in the standings
+    let score_leg_tile = |tile: &crate::components::LegBetTile| leg_tile_payout(tile, first_place, second_place);
+    if sheikh.enabled && !sheikh.leg_bets.is_empty() {
+        let details: Vec<(CamelColor, u8, i32)> = sheikh
+            .leg_bets
+            .iter()
+            .map(|tile| (tile.camel, tile.value, score_leg_tile(tile)))
+            .collect();
+        let total = details.iter().map(|(_, _, change)| change).sum();
+        score_changes.push((format!("{} (neutral)", SheikhBettor::NAME), total, details, 0));
+    }
+
     // Get current standings for display (INCLUDING leg earnings from this leg)
     let mut sorted_players: Vec<_> = players
         .players
@@ -3758,7 +3643,7 @@ pub fn leg_scoring_modal_ui(
             let leg_earnings = score_changes
                 .get(idx)
                 .map(|(_, leg_bet_total, _, pyramid_tokens)| {
-                    *leg_bet_total + (*pyramid_tokens as i32)
+                    *leg_bet_total + *pyramid_tokens as i32 * PYRAMID_TOKEN_PAYOUT
                 })
                 .unwrap_or(0);
             // Show updated money (current + leg earnings)
@@ -3766,7 +3651,7 @@ pub fn leg_scoring_modal_ui(
             (p.name.clone(), updated_money)
         })
         .collect();
-    sorted_players.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted_players.sort_by_key(|p| std::cmp::Reverse(p.1));
 
     let mut should_continue = false;
 
@@ -3775,37 +3660,53 @@ pub fn leg_scoring_modal_ui(
         .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
         .show(ctx, |ui| {
             egui::Frame::new()
-                .fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 200))
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 200)))
+                .stroke(panel_stroke(egui::Stroke::NONE))
                 .corner_radius(egui::CornerRadius::same(16))
                 .inner_margin(egui::Margin::same(32))
-                .shadow(egui::epaint::Shadow {
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                     offset: [0, 8],
                     blur: 24,
                     spread: 4,
                     color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
-                })
+                }))
                 .show(ui, |ui| {
                     ui.vertical_centered(|ui| {
                         ui.heading(egui::RichText::new("Leg Complete!").size(32.0).strong());
+
+                        // Online games: result of checking the rolls against the host's committed seed
+                        match leg_fairness.verified {
+                            Some(true) => {
+                                ui.label(
+                                    egui::RichText::new("✔ Dice verified fair")
+                                        .size(12.0)
+                                        .color(egui::Color32::from_rgb(80, 180, 80)),
+                                )
+                                .on_hover_text("The revealed seed matches the host's commitment and reproduces every roll");
+                            }
+                            Some(false) => {
+                                ui.label(
+                                    egui::RichText::new("⚠ Dice could not be verified")
+                                        .size(12.0)
+                                        .color(egui::Color32::from_rgb(220, 80, 60)),
+                                )
+                                .on_hover_text("The revealed seed does not match the rolls of this leg");
+                            }
+                            None => {}
+                        }
                         ui.add_space(20.0);
 
                         // Show first and second place with camel silhouettes
                         if let Some(first) = first_place {
                             ui.horizontal(|ui| {
                                 ui.label(egui::RichText::new("1st Place:").size(16.0));
-                                let color = camel_color_to_egui(first);
-                                let border_color = egui::Color32::from_rgb(
-                                    (color.r() as f32 * 0.5) as u8,
-                                    (color.g() as f32 * 0.5) as u8,
-                                    (color.b() as f32 * 0.5) as u8,
-                                );
                                 let (rect, _) = ui.allocate_exact_size(
                                     egui::vec2(40.0, 30.0),
                                     egui::Sense::hover(),
                                 );
-                                draw_camel_silhouette(ui.painter(), rect, color, border_color);
+                                draw_camel_silhouette(ui.painter(), rect, &Palette::camel(first, camel_palette));
                                 ui.label(
-                                    egui::RichText::new(format!("{:?}", first))
+                                    egui::RichText::new(camel_names.name(first))
                                         .size(16.0)
                                         .strong(),
                                 );
@@ -3814,19 +3715,13 @@ pub fn leg_scoring_modal_ui(
                         if let Some(second) = second_place {
                             ui.horizontal(|ui| {
                                 ui.label(egui::RichText::new("2nd Place:").size(16.0));
-                                let color = camel_color_to_egui(second);
-                                let border_color = egui::Color32::from_rgb(
-                                    (color.r() as f32 * 0.5) as u8,
-                                    (color.g() as f32 * 0.5) as u8,
-                                    (color.b() as f32 * 0.5) as u8,
-                                );
                                 let (rect, _) = ui.allocate_exact_size(
                                     egui::vec2(40.0, 30.0),
                                     egui::Sense::hover(),
                                 );
-                                draw_camel_silhouette(ui.painter(), rect, color, border_color);
+                                draw_camel_silhouette(ui.painter(), rect, &Palette::camel(second, camel_palette));
                                 ui.label(
-                                    egui::RichText::new(format!("{:?}", second))
+                                    egui::RichText::new(camel_names.name(second))
                                         .size(16.0)
                                         .strong(),
                                 );
@@ -3867,7 +3762,7 @@ pub fn leg_scoring_modal_ui(
                                             draw_mini_leg_bet_card(
                                                 ui.painter(),
                                                 rect,
-                                                *camel,
+                                                &Palette::camel(*camel, camel_palette),
                                                 *value,
                                             );
 
@@ -3911,6 +3806,7 @@ pub fn leg_scoring_modal_ui(
                                                 draw_pyramid_token_icon(
                                                     ui.painter(),
                                                     token_center,
+                                                    &Palette::GOLD,
                                                     token_size,
                                                 );
                                             }
@@ -3925,7 +3821,7 @@ pub fn leg_scoring_modal_ui(
                                         }
 
                                         // Total - wraps only if needed
-                                        let total = *leg_bet_total + (*pyramid_tokens as i32);
+                                        let total = *leg_bet_total + *pyramid_tokens as i32 * PYRAMID_TOKEN_PAYOUT;
                                         if total != 0 {
                                             ui.add_space(8.0);
                                             let (text, color) = if total > 0 {
@@ -3966,7 +3862,7 @@ pub fn leg_scoring_modal_ui(
                                             draw_mini_leg_bet_card(
                                                 ui.painter(),
                                                 rect,
-                                                *camel,
+                                                &Palette::camel(*camel, camel_palette),
                                                 *value,
                                             );
 
@@ -3974,7 +3870,7 @@ pub fn leg_scoring_modal_ui(
                                             let change_text = if *change > 0 {
                                                 format!("+${}", change)
                                             } else {
-                                                format!("-$1")
+                                                "-$1".to_string()
                                             };
                                             let change_color = if *change > 0 {
                                                 egui::Color32::LIGHT_GREEN
@@ -4017,6 +3913,7 @@ pub fn leg_scoring_modal_ui(
                                                 draw_pyramid_token_icon(
                                                     ui.painter(),
                                                     token_center,
+                                                    &Palette::GOLD,
                                                     token_size,
                                                 );
                                             }
@@ -4033,7 +3930,7 @@ pub fn leg_scoring_modal_ui(
 
                                         // Show total for this leg
                                         let total_leg_earnings =
-                                            *leg_bet_total + (*pyramid_tokens as i32);
+                                            *leg_bet_total + *pyramid_tokens as i32 * PYRAMID_TOKEN_PAYOUT;
                                         if total_leg_earnings != 0 {
                                             ui.add_space(12.0);
                                             let total_text = if total_leg_earnings > 0 {
@@ -4098,65 +3995,8 @@ pub fn leg_scoring_modal_ui(
                 });
         });
 
-    if should_continue {
-        // Apply leg bet scores
-        if let Some(ref player_leg_bets) = player_leg_bets {
-            for (player_idx, player) in players.players.iter_mut().enumerate() {
-                if player_idx < player_leg_bets.bets.len() {
-                    for tile in &player_leg_bets.bets[player_idx] {
-                        if Some(tile.camel) == first_place {
-                            player.money += tile.value as i32;
-                        } else if Some(tile.camel) == second_place {
-                            player.money += 1;
-                        } else {
-                            player.money = (player.money - 1).max(0);
-                        }
-                    }
-                }
-            }
-        }
-
-        // Reset for new leg
-        if let Some(ref mut pyramid) = pyramid {
-            pyramid.reset();
-        }
-        if let Some(ref mut leg_tiles) = leg_tiles {
-            leg_tiles.reset();
-        }
-        if let Some(ref mut player_leg_bets) = player_leg_bets {
-            player_leg_bets.clear_all();
-        }
-        if let Some(ref mut player_pyramid_tokens) = player_pyramid_tokens {
-            player_pyramid_tokens.clear_all();
-        }
-        if let Some(ref mut turn_state) = turn_state {
-            turn_state.leg_number += 1;
-            turn_state.action_taken = false;
-            turn_state.awaiting_action = true;
-            turn_state.leg_has_started = false;
-            turn_state.turn_delay_timer = 0.0;
-        }
-
-        // Clear placed spectator tiles and return them to players
-        if let Some(ref mut placed_tiles) = placed_tiles {
-            placed_tiles.clear();
-        }
-
-        // Return spectator tiles to all players
-        for player in players.players.iter_mut() {
-            player.has_spectator_tile = true;
-        }
-
-        // Despawn visual spectator tile entities
-        for entity in spectator_tile_entities.iter() {
-            commands.entity(entity).despawn();
-        }
-
-        // Despawn dice sprites in tents from previous leg
-        for entity in dice_sprite_entities.iter() {
-            commands.entity(entity).despawn();
-        }
-
-        ui_state.show_leg_scoring = false;
+    // Paying out and clearing the table is left to the game (see `start_next_leg_system`)
+    if should_continue || autoplay.leg_scoring_ready() {
+        next_leg.write(StartNextLegAction);
     }
 }
@@ -0,0 +1,88 @@
+// Room invite link and its QR code
+// Shown in the waiting room so people at the same table can join by scanning
+// instead of typing the room code, or copied and sent to friends. The link opens
+// the game and joins the room straight away.
+
+use bevy_egui::egui;
+use qrcode::QrCode;
+
+/// Modules of light border around the code (scanners need at least 4)
+const QUIET_ZONE: usize = 4;
+
+/// Link that opens the game and joins this room
+#[cfg(target_arch = "wasm32")]
+pub fn invite_link(room_code: &str) -> Option<String> {
+    Some(crate::network::js_bindings::get_invite_link(room_code))
+}
+
+/// Native builds aren't served from a URL, so there's nothing to link to
+#[cfg(not(target_arch = "wasm32"))]
+pub fn invite_link(_room_code: &str) -> Option<String> {
+    None
+}
+
+/// Copy the room code or invite link (the browser's clipboard on the web)
+#[cfg(target_arch = "wasm32")]
+pub fn copy_to_clipboard(_ctx: &egui::Context, text: &str) {
+    crate::network::js_bindings::copy_to_clipboard(text);
+}
+
+/// Copy the room code or invite link (egui hands it to the system clipboard)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn copy_to_clipboard(ctx: &egui::Context, text: &str) {
+    ctx.copy_text(text.to_string());
+}
+
+/// Whether there's a share sheet to send the invite with
+#[cfg(target_arch = "wasm32")]
+pub fn can_share() -> bool {
+    crate::network::js_bindings::can_share()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn can_share() -> bool {
+    false
+}
+
+/// Send the invite through the browser's share sheet
+#[cfg(target_arch = "wasm32")]
+pub fn share_invite(room_code: &str, link: &str) {
+    crate::network::js_bindings::share_invite(room_code, link);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn share_invite(_room_code: &str, _link: &str) {}
+
+/// Encode text as a QR code image, one pixel per module
+pub fn qr_code_image(text: &str) -> Option<egui::ColorImage> {
+    let code = QrCode::new(text.as_bytes()).ok()?;
+    let width = code.width();
+    let size = width + QUIET_ZONE * 2;
+    let colors = code.to_colors();
+
+    let mut pixels = vec![egui::Color32::WHITE; size * size];
+    for (i, color) in colors.into_iter().enumerate() {
+        let (x, y) = (i % width + QUIET_ZONE, i / width + QUIET_ZONE);
+        pixels[y * size + x] = color.select(egui::Color32::BLACK, egui::Color32::WHITE);
+    }
+    Some(egui::ColorImage::new([size, size], pixels))
+}
+
+/// QR code texture for an invite link, generated once per link
+#[derive(Default)]
+pub struct InviteQr {
+    link: String,
+    texture: Option<egui::TextureHandle>,
+}
+
+impl InviteQr {
+    pub fn texture(&mut self, ctx: &egui::Context, link: &str) -> Option<&egui::TextureHandle> {
+        if self.link != link {
+            self.link = link.to_string();
+            // Nearest filtering keeps the modules sharp when scaled up
+            self.texture = qr_code_image(link)
+                .map(|image| ctx.load_texture("invite_qr", image, egui::TextureOptions::NEAREST));
+        }
+        self.texture.as_ref()
+    }
+}
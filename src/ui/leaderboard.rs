@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::game::leaderboard::Leaderboard;
+use crate::ui::theme::{desert_button, quality_shadow, DesertButtonStyle};
+
+const MODAL_BG: egui::Color32 = egui::Color32::from_rgb(30, 25, 20);
+const HEADER_COLOR: egui::Color32 = egui::Color32::from_rgb(0xD4, 0xA8, 0x4B);
+const GOLD: egui::Color32 = egui::Color32::from_rgb(0xFF, 0xD7, 0x00);
+
+/// Whether the main menu's leaderboard is open
+#[derive(Resource, Default)]
+pub struct LeaderboardPageState {
+    pub is_open: bool,
+}
+
+/// Draw the leaderboard: the best final scores made on this device
+pub fn draw_leaderboard_ui(
+    ctx: &egui::Context,
+    page: &mut LeaderboardPageState,
+    leaderboard: &Leaderboard,
+    is_mobile: bool,
+) {
+    if !page.is_open {
+        return;
+    }
+
+    // Dark overlay behind modal
+    egui::Area::new(egui::Id::new("leaderboard_overlay"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .order(egui::Order::Middle)
+        .show(ctx, |ui| {
+            let screen_rect = ctx.input(|i| i.viewport_rect());
+            ui.painter().rect_filled(screen_rect, 0.0, egui::Color32::from_rgba_unmultiplied(0, 0, 0, 180));
+        });
+
+    egui::Area::new(egui::Id::new("leaderboard_panel"))
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(MODAL_BG)
+                .corner_radius(egui::CornerRadius::same(16))
+                .inner_margin(egui::Margin::same(if is_mobile { 16 } else { 24 }))
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
+                    offset: [0, 8],
+                    blur: 24,
+                    spread: 4,
+                    color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
+                }))
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.heading(egui::RichText::new("Leaderboard").size(28.0).color(egui::Color32::WHITE));
+                    });
+                    ui.add_space(12.0);
+
+                    if leaderboard.entries.is_empty() {
+                        ui.label(
+                            egui::RichText::new("Finish a game to get on the leaderboard.")
+                                .size(15.0)
+                                .color(egui::Color32::LIGHT_GRAY),
+                        );
+                    } else {
+                        draw_leaderboard_table(ui, leaderboard);
+                    }
+
+                    ui.add_space(12.0);
+                    ui.vertical_centered(|ui| {
+                        if desert_button(ui, "Close", &DesertButtonStyle::medium()).clicked() {
+                            page.is_open = false;
+                        }
+                    });
+                });
+        });
+}
+
+/// One row per result, best first, with the top score in gold
+fn draw_leaderboard_table(ui: &mut egui::Ui, leaderboard: &Leaderboard) {
+    egui::Grid::new("leaderboard_grid").num_columns(5).spacing([16.0, 6.0]).striped(true).show(ui, |ui| {
+        for header in ["#", "Player", "Money", "Players", "Date"] {
+            ui.label(egui::RichText::new(header).size(13.0).strong().color(HEADER_COLOR));
+        }
+        ui.end_row();
+
+        for (rank, entry) in leaderboard.entries.iter().enumerate() {
+            let color = if rank == 0 { GOLD } else { egui::Color32::WHITE };
+            let cells = [
+                (rank + 1).to_string(),
+                entry.name.clone(),
+                format!("${}", entry.money),
+                entry.player_count.to_string(),
+                entry.date.clone(),
+            ];
+            for cell in cells {
+                ui.label(egui::RichText::new(cell).size(14.0).color(color));
+            }
+            ui.end_row();
+        }
+    });
+}
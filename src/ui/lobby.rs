@@ -1,17 +1,27 @@
 //! Lobby and Waiting Room UI for online multiplayer
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 
 use crate::game::state::GameState;
-use crate::network::state::{NetworkState, RoomPlayers};
-
-#[cfg(target_arch = "wasm32")]
-use crate::network::state::{NetworkMode, OnlinePlayerInfo};
-use crate::network::room::generate_room_code;
+use crate::network::backend::{Backend, NetworkBackend};
+use crate::network::error::{NetworkError, NetworkFailure, NetworkOperation, NetworkResults};
+use crate::network::fairness;
+use crate::network::messages::{
+    SerializableGameSetup, SerializableGameState, SerializableRoomSettings, PROTOCOL_VERSION,
+};
+use crate::network::state::{NetworkState, OnlinePlayerInfo, ReceivedGameState, RoomPlayers};
+use crate::network::room::{self, generate_room_code};
+use crate::network::session::{self, SavedSession};
 use crate::ui::hud::UiState;
-use crate::ui::theme::{desert_button, DesertButtonStyle, STONE_DARK, PLAYER_COLORS};
-use crate::ui::characters::{draw_avatar, CharacterId};
+use crate::ui::theme::{
+    desert_button, desert_button_enabled, find_player_color_clash, panel_fill, panel_stroke, player_color_picker,
+    player_color_to_egui, player_colors_clash, DesertButtonStyle, STONE_DARK, PLAYER_COLORS,
+};
+use crate::ui::characters::CharacterId;
+use crate::ui::draw::draw_avatar;
+use crate::ui::invite::{can_share, copy_to_clipboard, invite_link, share_invite, InviteQr};
 use crate::ui::player_setup::PlayerSetupConfig;
 
 #[cfg(target_arch = "wasm32")]
@@ -26,12 +36,30 @@ pub struct LobbyState {
     pub player_name: String,
     pub selected_character: CharacterId,
     pub selected_color: usize,
+    /// Color picked with the color picker in the waiting room, instead of `selected_color`
+    pub selected_custom_color: Option<[u8; 3]>,
     pub error_message: Option<String>,
     pub is_loading: bool,
     pub is_ready: bool,
     pub firebase_initialized: bool,
     pub firebase_user_id: Option<String>,
     pub appearance_initialized: bool,  // Track if we've set up unique appearance in waiting room
+    /// Whether the waiting room has subscribed to the room's player and metadata updates
+    pub players_subscribed: bool,
+    /// QR code of the room's invite link, shown in the waiting room
+    pub invite_qr: InviteQr,
+    /// Whether the Rejoin screen has asked for our seat back yet
+    pub rejoin_sent: bool,
+    /// Whether the host has shared its room settings with the room yet
+    pub room_settings_shared: bool,
+    /// Join the room on the Join screen as soon as we're signed in (opened from an invite link)
+    pub invite_auto_join: bool,
+    /// When the invite link was last copied, to say so for a moment
+    pub invite_copied_at: Option<f64>,
+    /// When the room code was last copied, to say so for a moment
+    pub code_copied_at: Option<f64>,
+    /// Whether the invite QR code is shown large, for scanning from further away
+    pub qr_enlarged: bool,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
@@ -40,6 +68,7 @@ pub enum LobbyScreen {
     Main,       // Choose create or join
     Create,     // Creating a room
     Join,       // Entering room code
+    Rejoin,     // Getting back into a game after the page reloaded
 }
 
 /// Colors for the desert theme (from main_menu.rs)
@@ -75,50 +104,56 @@ fn draw_desert_background(painter: &egui::Painter, rect: egui::Rect) {
     ));
 }
 
+/// The connection to the backend the lobby screens work through
+#[derive(SystemParam)]
+pub struct LobbyConnection<'w> {
+    network_state: ResMut<'w, NetworkState>,
+    results: Res<'w, NetworkResults>,
+    backend: Res<'w, Backend>,
+}
+
 /// Lobby UI system - create or join a room
+#[allow(unused_variables)]
 pub fn lobby_ui(
     mut contexts: EguiContexts,
     mut next_state: ResMut<NextState<GameState>>,
     mut lobby_state: ResMut<LobbyState>,
-    mut network_state: ResMut<NetworkState>,
+    connection: LobbyConnection,
     ui_state: Res<UiState>,
+    mut config: ResMut<PlayerSetupConfig>,
+    mut received_state: ResMut<ReceivedGameState>,
 ) {
+    let LobbyConnection { mut network_state, results, backend } = connection;
     let Ok(ctx) = contexts.ctx_mut() else { return };
     let is_mobile = !ui_state.use_side_panels;
 
-    // Initialize Firebase on first frame (WASM only)
-    #[cfg(target_arch = "wasm32")]
+    // Connect to the backend on first frame
     if !lobby_state.firebase_initialized {
         lobby_state.firebase_initialized = true;
         lobby_state.is_loading = true;
 
-        // Initialize Firebase and sign in
-        js_bindings::async_ops::init_and_authenticate(|result| {
-            // This callback runs asynchronously - we'll poll for completion
-        });
+        // Sign in; the outcome is reported through NetworkResults
+        backend.authenticate(results.clone());
     }
 
-    #[cfg(target_arch = "wasm32")]
-    {
-        // Check if authentication completed
-        if lobby_state.is_loading && lobby_state.firebase_user_id.is_none() {
-            if let Some(uid) = js_bindings::get_current_user_id() {
-                lobby_state.firebase_user_id = Some(uid.clone());
-                lobby_state.is_loading = false;
-                network_state.local_player_id = Some(uid);
-            } else if let Some(err) = js_bindings::get_firebase_error() {
-                lobby_state.error_message = Some(format!("Firebase error: {}", err));
-                lobby_state.is_loading = false;
-            }
+    // Check if authentication completed
+    if lobby_state.is_loading && lobby_state.firebase_user_id.is_none() {
+        if let Some(uid) = backend.local_player_id() {
+            lobby_state.firebase_user_id = Some(uid.clone());
+            lobby_state.is_loading = false;
+            network_state.local_player_id = Some(uid);
         }
     }
 
-    // For non-WASM, just mark as ready (multiplayer won't work but UI will render)
-    #[cfg(not(target_arch = "wasm32"))]
-    {
-        if !lobby_state.firebase_initialized {
-            lobby_state.firebase_initialized = true;
-            lobby_state.error_message = Some("Multiplayer only available in browser".to_string());
+    // Show the latest failed operation (sign in, or a room we were sent back from)
+    if let Some(failure) = network_state.connection_error.take() {
+        lobby_state.error_message = Some(failure.error.user_message());
+        lobby_state.is_loading = false;
+        // The game we were rejoining is gone for good
+        if lobby_state.screen == LobbyScreen::Rejoin {
+            room::abandon_room(&**backend, &mut network_state);
+            session::clear_session();
+            lobby_state.screen = LobbyScreen::Main;
         }
     }
 
@@ -183,6 +218,8 @@ pub fn lobby_ui(
                                 &mut lobby_state,
                                 &mut network_state,
                                 &mut next_state,
+                                &**backend,
+                                &results,
                                 is_mobile,
                             );
                         }
@@ -192,9 +229,22 @@ pub fn lobby_ui(
                                 &mut lobby_state,
                                 &mut network_state,
                                 &mut next_state,
+                                &**backend,
+                                &results,
                                 is_mobile,
                             );
                         }
+                        LobbyScreen::Rejoin => {
+                            draw_rejoin_room_screen(
+                                ui,
+                                &mut lobby_state,
+                                &mut network_state,
+                                &mut next_state,
+                                &**backend,
+                                &results,
+                                (&mut config, &mut received_state),
+                            );
+                        }
                     }
                 });
             });
@@ -248,6 +298,8 @@ fn draw_create_room_screen(
     lobby_state: &mut LobbyState,
     network_state: &mut NetworkState,
     next_state: &mut NextState<GameState>,
+    backend: &dyn NetworkBackend,
+    results: &NetworkResults,
     is_mobile: bool,
 ) {
     ui.label(
@@ -282,38 +334,19 @@ fn draw_create_room_screen(
     let can_create = !lobby_state.is_loading;
 
     if desert_button(ui, "Create & Wait for Players", &button_style).clicked() && can_create {
-        #[cfg(target_arch = "wasm32")]
-        {
-            lobby_state.is_loading = true;
-            let room_code = lobby_state.room_code_input.clone();
-            // Generate a random thematic name for online players
-            let player_name = lobby_state.selected_character.random_name();
-            lobby_state.player_name = player_name.clone();
-            let character_id = lobby_state.selected_character as u8;
-            let color_index = lobby_state.selected_color;
-
-            js_bindings::async_ops::create_room_async(
-                room_code.clone(),
-                player_name,
-                character_id,
-                color_index,
-                move |result| {
-                    // Result will be checked by polling
-                },
-            );
-
-            // Set network state
-            network_state.mode = NetworkMode::OnlineHost;
-            network_state.room_code = Some(room_code);
-            network_state.is_connected = true;
-
-            // Go to waiting room
-            next_state.set(GameState::WaitingRoom);
-        }
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            lobby_state.error_message = Some("Multiplayer only available in browser".to_string());
-        }
+        lobby_state.is_loading = true;
+        let room_code = lobby_state.room_code_input.clone();
+        // Generate a random thematic name for online players
+        let player_name = lobby_state.selected_character.random_name();
+        lobby_state.player_name = player_name.clone();
+        let character_id = lobby_state.selected_character.index() as u8;
+        let color_index = lobby_state.selected_color;
+        lobby_state.selected_custom_color = None;
+
+        room::host_room(backend, network_state, room_code, &player_name, character_id, color_index, results);
+
+        // Go to waiting room
+        next_state.set(GameState::WaitingRoom);
     }
 
     ui.add_space(15.0);
@@ -332,6 +365,8 @@ fn draw_join_room_screen(
     lobby_state: &mut LobbyState,
     network_state: &mut NetworkState,
     next_state: &mut NextState<GameState>,
+    backend: &dyn NetworkBackend,
+    results: &NetworkResults,
     is_mobile: bool,
 ) {
     ui.label(
@@ -368,39 +403,24 @@ fn draw_join_room_screen(
     let can_join = lobby_state.room_code_input.len() == 4
         && !lobby_state.is_loading;
 
-    if desert_button(ui, "Join Room", &button_style).clicked() && can_join {
-        #[cfg(target_arch = "wasm32")]
-        {
-            lobby_state.is_loading = true;
-            let room_code = lobby_state.room_code_input.clone();
-            // Generate a random thematic name for online players
-            let player_name = lobby_state.selected_character.random_name();
-            lobby_state.player_name = player_name.clone();
-            let character_id = lobby_state.selected_character as u8;
-            let color_index = lobby_state.selected_color;
-
-            js_bindings::async_ops::join_room_async(
-                room_code.clone(),
-                player_name,
-                character_id,
-                color_index,
-                move |result| {
-                    // Result will be checked by polling
-                },
-            );
-
-            // Set network state
-            network_state.mode = NetworkMode::OnlineClient;
-            network_state.room_code = Some(room_code);
-            network_state.is_connected = true;
+    // An invite link joins by itself once we're signed in
+    let auto_join = lobby_state.invite_auto_join && lobby_state.firebase_user_id.is_some();
 
-            // Go to waiting room
-            next_state.set(GameState::WaitingRoom);
-        }
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            lobby_state.error_message = Some("Multiplayer only available in browser".to_string());
-        }
+    if (desert_button(ui, "Join Room", &button_style).clicked() || auto_join) && can_join {
+        lobby_state.invite_auto_join = false;
+        lobby_state.is_loading = true;
+        let room_code = lobby_state.room_code_input.clone();
+        // Generate a random thematic name for online players
+        let player_name = lobby_state.selected_character.random_name();
+        lobby_state.player_name = player_name.clone();
+        let character_id = lobby_state.selected_character.index() as u8;
+        let color_index = lobby_state.selected_color;
+        lobby_state.selected_custom_color = None;
+
+        room::join_room(backend, network_state, room_code, &player_name, character_id, color_index, results);
+
+        // Go to waiting room
+        next_state.set(GameState::WaitingRoom);
     }
 
     ui.add_space(15.0);
@@ -410,6 +430,76 @@ fn draw_join_room_screen(
     if desert_button(ui, "Back", &back_style).clicked() {
         lobby_state.screen = LobbyScreen::Main;
         lobby_state.error_message = None;
+        lobby_state.invite_auto_join = false;
+    }
+}
+
+/// Take our seat back in the game we were playing before the page reloaded, then
+/// restore it from the host's latest state and its action log. The game is set up
+/// again in `restore`: the setup config, and the host's state once it arrives.
+fn draw_rejoin_room_screen(
+    ui: &mut egui::Ui,
+    lobby_state: &mut LobbyState,
+    network_state: &mut NetworkState,
+    next_state: &mut NextState<GameState>,
+    backend: &dyn NetworkBackend,
+    results: &NetworkResults,
+    restore: (&mut PlayerSetupConfig, &mut ReceivedGameState),
+) {
+    let (config, received_state) = restore;
+    if !lobby_state.rejoin_sent {
+        lobby_state.rejoin_sent = true;
+        match session::saved_session() {
+            Some(saved) => {
+                let token = saved.rejoin_token.as_deref();
+                room::rejoin_room(backend, network_state, saved.room_code, &saved.player_id, token, results);
+            }
+            None => {
+                session::clear_session();
+                lobby_state.error_message = Some("That game can't be rejoined from here.".to_string());
+                lobby_state.screen = LobbyScreen::Main;
+                return;
+            }
+        }
+    }
+
+    ui.label(
+        egui::RichText::new(format!("Rejoining room {}...", lobby_state.room_code_input))
+            .size(22.0)
+            .color(egui::Color32::WHITE),
+    );
+    ui.add_space(10.0);
+    ui.spinner();
+
+    // Wait for the host's setup and latest state, then set up the same game
+    if let Some(state_json) = backend.poll_game_state() {
+        received_state.state_json = Some(state_json);
+        received_state.needs_processing = true;
+    }
+    let setup = backend
+        .game_setup()
+        .and_then(|json| serde_json::from_str::<SerializableGameSetup>(&json).ok());
+    let state = received_state
+        .state_json
+        .as_ref()
+        .and_then(|json| serde_json::from_str::<SerializableGameState>(json).ok());
+    if let (Some(setup), Some(state)) = (setup, state) {
+        // Backends that know players by their connection hand the seat's id back
+        if let Some(player_id) = backend.local_player_id() {
+            network_state.local_player_id = Some(player_id);
+        }
+        apply_game_setup(&setup, network_state, config);
+        setup_players_from_state(&state, network_state, config);
+        // Replay waits for the host's state, which then picks up from the log
+        network_state.resyncing = true;
+        next_state.set(GameState::Playing);
+    }
+
+    ui.add_space(25.0);
+    if desert_button(ui, "Back", &DesertButtonStyle::small()).clicked() {
+        // Keep the saved game, to try again later
+        room::abandon_room(backend, network_state);
+        lobby_state.screen = LobbyScreen::Main;
     }
 }
 
@@ -419,86 +509,142 @@ pub fn waiting_room_ui(
     mut contexts: EguiContexts,
     mut next_state: ResMut<NextState<GameState>>,
     mut lobby_state: ResMut<LobbyState>,
-    mut network_state: ResMut<NetworkState>,
+    connection: LobbyConnection,
     mut room_players: ResMut<RoomPlayers>,
     mut config: ResMut<PlayerSetupConfig>,
     ui_state: Res<UiState>,
 ) {
+    let LobbyConnection { mut network_state, results, backend } = connection;
     let Ok(ctx) = contexts.ctx_mut() else { return };
     let is_mobile = !ui_state.use_side_panels;
     let is_host = network_state.is_host();
 
-    // Subscribe to player updates (WASM only)
-    #[cfg(target_arch = "wasm32")]
+    // Creating, joining or starting failed: go back to the lobby, which shows the error
+    if network_state
+        .connection_error
+        .as_ref()
+        .is_some_and(|failure| failure.operation.is_room_setup())
     {
-        static mut SUBSCRIBED: bool = false;
-        unsafe {
-            if !SUBSCRIBED {
-                if let Some(ref room_code) = network_state.room_code {
-                    js_bindings::subscribe_to_players(room_code);
-                    js_bindings::subscribe_to_metadata(room_code);
-                    SUBSCRIBED = true;
-                }
-            }
+        room::abandon_room(&**backend, &mut network_state);
+        next_state.set(GameState::Lobby);
+        return;
+    }
+
+    // Subscribe to player updates
+    if !lobby_state.players_subscribed {
+        if let Some(ref room_code) = network_state.room_code {
+            backend.subscribe_to_room(room_code);
+            lobby_state.players_subscribed = true;
         }
+    }
 
-        // Poll for player updates
-        if let Some(players_json) = js_bindings::poll_players() {
-            if let Ok(players) = serde_json::from_str::<Vec<OnlinePlayerInfo>>(&players_json) {
-                room_players.players = players;
-            }
+    // Poll for player updates
+    if let Some(players_json) = backend.poll_players() {
+        if let Ok(players) = serde_json::from_str::<Vec<OnlinePlayerInfo>>(&players_json) {
+            room_players.players = players;
         }
+    }
 
-        // Auto-select unique character/color when first entering waiting room
-        if !lobby_state.appearance_initialized && !room_players.players.is_empty() {
-            lobby_state.appearance_initialized = true;
-
-            // Find our current player info
-            let my_id = network_state.local_player_id.as_ref();
-
-            // Collect taken characters and colors from OTHER players
-            let taken_characters: std::collections::HashSet<u8> = room_players.players.iter()
-                .filter(|p| Some(&p.id) != my_id)
-                .map(|p| p.character_id)
-                .collect();
-            let taken_colors: std::collections::HashSet<usize> = room_players.players.iter()
-                .filter(|p| Some(&p.id) != my_id)
-                .map(|p| p.color_index)
-                .collect();
-
-            // Find first available character (0-15)
-            let available_char = (0u8..16).find(|c| !taken_characters.contains(c)).unwrap_or(0);
-            // Find first available color (0-7)
-            let available_color = (0usize..8).find(|c| !taken_colors.contains(c)).unwrap_or(0);
-
-            // Check if we need to update (if different from what we joined with)
-            let needs_update = lobby_state.selected_character as u8 != available_char
-                || lobby_state.selected_color != available_color;
-
-            if needs_update {
-                lobby_state.selected_character = CharacterId::from_index(available_char as usize);
-                lobby_state.selected_color = available_color;
-
-                // Update Firebase
-                if let Some(ref room_code) = network_state.room_code {
-                    js_bindings::async_ops::update_appearance_async(
-                        room_code.clone(),
-                        available_char,
-                        available_color,
-                        None,
-                    );
-                }
+    // The host took us out of the room: back to the lobby, which says why
+    if !is_host && backend.was_kicked() {
+        network_state.connection_error = Some(NetworkFailure {
+            operation: NetworkOperation::JoinRoom,
+            error: NetworkError::Kicked,
+        });
+        room::abandon_room(&**backend, &mut network_state);
+        room_players.players.clear();
+        lobby_state.appearance_initialized = false;
+        lobby_state.players_subscribed = false;
+        lobby_state.room_settings_shared = false;
+        next_state.set(GameState::Lobby);
+        return;
+    }
+
+    // The host shares its table once; from then on everyone plays by the room's settings
+    let room_settings = backend
+        .room_settings()
+        .and_then(|json| serde_json::from_str::<SerializableRoomSettings>(&json).ok());
+    if let Some(ref settings) = room_settings {
+        apply_room_settings(settings, &mut config);
+    } else if is_host && !lobby_state.room_settings_shared {
+        if let Some(ref room_code) = network_state.room_code {
+            let settings = room_settings_from_config(&config);
+            backend.set_room_settings(room_code, serde_json::to_string(&settings).unwrap_or_default());
+            lobby_state.room_settings_shared = true;
+        }
+    }
+
+    // Auto-select unique character/color when first entering waiting room
+    if !lobby_state.appearance_initialized && !room_players.players.is_empty() {
+        lobby_state.appearance_initialized = true;
+
+        // Find our current player info
+        let my_id = network_state.local_player_id.as_ref();
+
+        // Collect taken characters and colors from OTHER players
+        let taken_characters: std::collections::HashSet<u8> = room_players.players.iter()
+            .filter(|p| Some(&p.id) != my_id)
+            .map(|p| p.character_id)
+            .collect();
+        let taken_colors: Vec<egui::Color32> = room_players.players.iter()
+            .filter(|p| Some(&p.id) != my_id)
+            .map(|p| player_color_to_egui(p.color_index, p.custom_color))
+            .collect();
+
+        // Keep the avatar from our settings (the first seat on the main menu) if nobody has
+        // it, otherwise take the first character nobody has
+        let preferred_char = config.players.iter().find(|p| !p.is_ai).map_or(0, |p| p.character_id.index() as u8);
+        let available_char = std::iter::once(preferred_char)
+            .chain(0..CharacterId::COUNT as u8)
+            .find(|c| !taken_characters.contains(c))
+            .unwrap_or(0);
+        // Find first palette color that doesn't clash with anyone's
+        let available_color = (0..PLAYER_COLORS.len())
+            .find(|&c| !taken_colors.iter().any(|&taken| player_colors_clash(taken, PLAYER_COLORS[c])))
+            .unwrap_or(0);
+
+        // Check if we need to update (if different from what we joined with)
+        let needs_update = lobby_state.selected_character.index() as u8 != available_char
+            || lobby_state.selected_color != available_color;
+
+        if needs_update {
+            lobby_state.selected_character = CharacterId::from_index(available_char as usize);
+            lobby_state.selected_color = available_color;
+
+            // Tell the room
+            if let Some(ref room_code) = network_state.room_code {
+                backend.update_appearance(room_code, available_char, available_color, None, None);
             }
         }
+    }
 
-        // Check if game has started (for clients)
-        if !is_host && js_bindings::has_game_started() {
-            // Subscribe to game state updates
+    // Check if game has started (for clients), and play the game the host set up
+    let host_setup = backend
+        .game_setup()
+        .and_then(|json| serde_json::from_str::<SerializableGameSetup>(&json).ok());
+    if !is_host && backend.has_game_started() {
+        if host_setup.as_ref().is_some_and(|setup| setup.protocol != PROTOCOL_VERSION) {
+            // The host's game would play out differently on this build: back to the lobby
+            network_state.connection_error = Some(NetworkFailure {
+                operation: NetworkOperation::JoinRoom,
+                error: NetworkError::VersionMismatch,
+            });
+        } else if let Some(setup) = host_setup {
+            // Subscribe to the host's action log
             if let Some(ref room_code) = network_state.room_code {
-                js_bindings::subscribe_to_game_state(room_code);
+                backend.subscribe_to_game_state(room_code);
             }
             // Set up local players based on room players
-            setup_players_from_room(&room_players, &network_state, &mut config);
+            apply_game_setup(&setup, &mut network_state, &mut config);
+            setup_players_from_room(&room_players, &mut network_state, &mut config, backend.randomize_order(), setup.ai_seats);
+            // Remember the game, so reloading the page can get back to it
+            if let (Some(room_code), Some(player_id)) = (&network_state.room_code, &network_state.local_player_id) {
+                session::save_session(&SavedSession {
+                    room_code: room_code.clone(),
+                    player_id: player_id.clone(),
+                    rejoin_token: backend.rejoin_token(),
+                });
+            }
             next_state.set(GameState::Playing);
         }
     }
@@ -530,6 +676,67 @@ pub fn waiting_room_ui(
                                 .color(egui::Color32::from_rgb(255, 215, 0))
                                 .monospace(),
                         );
+
+                        ui.add_space(4.0);
+                        let now = ui.input(|i| i.time);
+                        let copied = lobby_state.code_copied_at.is_some_and(|at| now - at < 2.0);
+                        let label = if copied { "Code Copied!" } else { "Copy Code" };
+                        if desert_button(ui, label, &DesertButtonStyle::small()).clicked() {
+                            copy_to_clipboard(ui.ctx(), code);
+                            lobby_state.code_copied_at = Some(now);
+                        }
+                        if copied {
+                            ui.ctx().request_repaint_after(std::time::Duration::from_millis(250));
+                        }
+                    }
+
+                    // Invite QR code for people at the same table
+                    let invite = network_state.room_code.as_deref().and_then(invite_link);
+                    if let Some(link) = invite {
+                        if let Some(texture_id) = lobby_state.invite_qr.texture(ui.ctx(), &link).map(|t| t.id()) {
+                            // Tap to enlarge, for phones scanning from across the table
+                            let size = match (lobby_state.qr_enlarged, is_mobile) {
+                                (true, _) => ui.available_width().min(320.0),
+                                (false, true) => 120.0,
+                                (false, false) => 150.0,
+                            };
+                            ui.add_space(8.0);
+                            let response = ui.add(
+                                egui::Image::new((texture_id, egui::vec2(size, size))).sense(egui::Sense::click()),
+                            );
+                            if response.clicked() {
+                                lobby_state.qr_enlarged = !lobby_state.qr_enlarged;
+                            }
+                            let hint = if lobby_state.qr_enlarged { "Scan to join (tap to shrink)" } else { "Scan to join (tap to enlarge)" };
+                            ui.label(
+                                egui::RichText::new(hint)
+                                    .size(12.0)
+                                    .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 180)),
+                            );
+                        }
+
+                        // Or send the link to friends who aren't at the table
+                        ui.add_space(6.0);
+                        let now = ui.input(|i| i.time);
+                        let copied = lobby_state.invite_copied_at.is_some_and(|at| now - at < 2.0);
+                        let label = if copied { "Link Copied!" } else { "Copy Invite Link" };
+                        if desert_button(ui, label, &DesertButtonStyle::small()).clicked() {
+                            copy_to_clipboard(ui.ctx(), &link);
+                            lobby_state.invite_copied_at = Some(now);
+                        }
+                        if copied {
+                            ui.ctx().request_repaint_after(std::time::Duration::from_millis(250));
+                        }
+
+                        // Phones can send it straight to a chat
+                        if can_share() {
+                            ui.add_space(4.0);
+                            if desert_button(ui, "Share", &DesertButtonStyle::small()).clicked() {
+                                if let Some(ref code) = network_state.room_code {
+                                    share_invite(code, &link);
+                                }
+                            }
+                        }
                     }
 
                     ui.add_space(10.0);
@@ -559,7 +766,6 @@ pub fn waiting_room_ui(
 
                     ui.add_space(10.0);
 
-                    #[cfg(target_arch = "wasm32")]
                     {
                         // Collect taken characters and colors from OTHER players (for cycling)
                         let my_id = network_state.local_player_id.as_ref();
@@ -567,13 +773,14 @@ pub fn waiting_room_ui(
                             .filter(|p| Some(&p.id) != my_id)
                             .map(|p| p.character_id)
                             .collect();
-                        let taken_colors: std::collections::HashSet<usize> = room_players.players.iter()
+                        let taken_colors: Vec<egui::Color32> = room_players.players.iter()
                             .filter(|p| Some(&p.id) != my_id)
-                            .map(|p| p.color_index)
+                            .map(|p| player_color_to_egui(p.color_index, p.custom_color))
                             .collect();
 
                         egui::Frame::new()
-                            .fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 100))
+                            .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 100)))
+                            .stroke(panel_stroke(egui::Stroke::NONE))
                             .inner_margin(15.0)
                             .corner_radius(8.0)
                             .show(ui, |ui| {
@@ -585,7 +792,7 @@ pub fn waiting_room_ui(
                                 } else {
                                     for player in &room_players.players {
                                         let is_local_player = Some(&player.id) == my_id;
-                                        let player_color = PLAYER_COLORS[player.color_index % PLAYER_COLORS.len()];
+                                        let player_color = player_color_to_egui(player.color_index, player.custom_color);
 
                                         // Use fixed-height row with centered vertical alignment (like start screen)
                                         let row_height = 44.0;
@@ -619,28 +826,43 @@ pub fn waiting_room_ui(
                                                         // Cycle both character AND color on click (local player only)
                                                         if is_local_player && response.clicked() {
                                                             // Find next available character
-                                                            let current_char = lobby_state.selected_character as u8;
-                                                            let next_char = ((current_char + 1)..16).chain(0..current_char)
+                                                            let current_char = lobby_state.selected_character.index();
+                                                            let next_char = (1..=CharacterId::COUNT)
+                                                                .map(|offset| ((current_char + offset) % CharacterId::COUNT) as u8)
                                                                 .find(|c| !taken_characters.contains(c))
-                                                                .unwrap_or(current_char);
+                                                                .unwrap_or(current_char as u8);
 
-                                                            // Find next available color
+                                                            // Find next palette color that doesn't clash with anyone's
                                                             let current_color = lobby_state.selected_color;
-                                                            let next_color = ((current_color + 1)..8).chain(0..current_color)
-                                                                .find(|c| !taken_colors.contains(c))
+                                                            let next_color = (1..=PLAYER_COLORS.len())
+                                                                .map(|offset| (current_color + offset) % PLAYER_COLORS.len())
+                                                                .find(|&c| !taken_colors.iter().any(|&taken| player_colors_clash(taken, PLAYER_COLORS[c])))
                                                                 .unwrap_or(current_color);
 
                                                             lobby_state.selected_character = CharacterId::from_index(next_char as usize);
                                                             lobby_state.selected_color = next_color;
+                                                            lobby_state.selected_custom_color = None;
 
-                                                            // Update Firebase
+                                                            // Tell the room
                                                             if let Some(ref room_code) = network_state.room_code {
-                                                                js_bindings::async_ops::update_appearance_async(
-                                                                    room_code.clone(),
-                                                                    next_char,
-                                                                    next_color,
-                                                                    None,
-                                                                );
+                                                                backend.update_appearance(room_code, next_char, next_color, None, None);
+                                                            }
+                                                        }
+
+                                                        // Any color at all, from the picker (local player only)
+                                                        if is_local_player {
+                                                            ui.add_space(4.0);
+                                                            let color_index = lobby_state.selected_color;
+                                                            if player_color_picker(ui, color_index, &mut lobby_state.selected_custom_color) {
+                                                                if let Some(ref room_code) = network_state.room_code {
+                                                                    backend.update_appearance(
+                                                                        room_code,
+                                                                        lobby_state.selected_character.index() as u8,
+                                                                        lobby_state.selected_color,
+                                                                        lobby_state.selected_custom_color,
+                                                                        None,
+                                                                    );
+                                                                }
                                                             }
                                                         }
 
@@ -659,13 +881,14 @@ pub fn waiting_room_ui(
                                                                 ui.add(text_edit)
                                                             }).inner;
 
-                                                            // Update Firebase when name changes
+                                                            // Tell the room when the name changes
                                                             if response.changed() {
                                                                 if let Some(ref room_code) = network_state.room_code {
-                                                                    js_bindings::async_ops::update_appearance_async(
-                                                                        room_code.clone(),
-                                                                        lobby_state.selected_character as u8,
+                                                                    backend.update_appearance(
+                                                                        room_code,
+                                                                        lobby_state.selected_character.index() as u8,
                                                                         lobby_state.selected_color,
+                                                                        lobby_state.selected_custom_color,
                                                                         Some(lobby_state.player_name.clone()),
                                                                     );
                                                                 }
@@ -681,13 +904,35 @@ pub fn waiting_room_ui(
 
                                                         ui.add_space(8.0);
 
-                                                        // Host indicator
+                                                        // Host indicator, or whether the player is ready
                                                         if player.is_host {
                                                             ui.label(
                                                                 egui::RichText::new("(Host)")
                                                                     .size(12.0)
                                                                     .color(egui::Color32::from_rgb(255, 215, 0)),
                                                             );
+                                                        } else if player.is_ready {
+                                                            ui.label(
+                                                                egui::RichText::new("Ready")
+                                                                    .size(12.0)
+                                                                    .color(egui::Color32::from_rgb(100, 255, 100)),
+                                                            );
+                                                        } else {
+                                                            ui.label(
+                                                                egui::RichText::new("Not ready")
+                                                                    .size(12.0)
+                                                                    .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 120)),
+                                                            );
+                                                        }
+
+                                                        // Host can remove anyone else from the room
+                                                        if is_host && !player.is_host {
+                                                            ui.add_space(8.0);
+                                                            if desert_button(ui, "Kick", &DesertButtonStyle::small()).clicked() {
+                                                                if let Some(ref room_code) = network_state.room_code {
+                                                                    backend.kick_player(room_code, &player.id);
+                                                                }
+                                                            }
                                                         }
 
                                                         ui.add_space(10.0);
@@ -697,103 +942,208 @@ pub fn waiting_room_ui(
                                         );
                                         ui.add_space(2.0);
                                     }
+
+                                    // The AI players the host's game will seat, as everyone will see them
+                                    let settings = room_settings.clone().unwrap_or_default();
+                                    let ai_seats = ai_seats_for(&settings, room_players.players.len());
+                                    let taken: Vec<_> = room_players
+                                        .players
+                                        .iter()
+                                        .map(|p| {
+                                            let color = player_color_to_egui(p.color_index, p.custom_color);
+                                            (CharacterId::from_index(p.character_id as usize), color)
+                                        })
+                                        .collect();
+                                    for (character, color_index) in ai_seat_looks(&taken, ai_seats) {
+                                        ui.allocate_ui(egui::vec2(ui.available_width(), 44.0), |ui| {
+                                            ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                                                ui.add_space(10.0);
+                                                let (rect, _) = ui.allocate_exact_size(egui::vec2(40.0, 40.0), egui::Sense::hover());
+                                                draw_avatar(
+                                                    ui.painter(),
+                                                    rect,
+                                                    character,
+                                                    Some(PLAYER_COLORS[color_index]),
+                                                );
+                                                ui.add_space(10.0);
+                                                ui.label(egui::RichText::new(character.name()).size(14.0).color(egui::Color32::WHITE));
+                                                ui.add_space(8.0);
+                                                ui.label(
+                                                    egui::RichText::new("(AI)")
+                                                        .size(12.0)
+                                                        .color(egui::Color32::from_rgb(150, 200, 255)),
+                                                );
+                                            });
+                                        });
+                                        ui.add_space(2.0);
+                                    }
                                 }
                             });
                     }
 
-                    #[cfg(not(target_arch = "wasm32"))]
-                    {
-                        egui::Frame::new()
-                            .fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 100))
-                            .inner_margin(15.0)
-                            .corner_radius(8.0)
-                            .show(ui, |ui| {
-                                ui.label(
-                                    egui::RichText::new("Online multiplayer not available in native builds")
-                                        .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 150)),
-                                );
-                            });
+                    // Two players the same color can't be told apart in the game
+                    let room_colors: Vec<egui::Color32> = room_players
+                        .players
+                        .iter()
+                        .map(|p| player_color_to_egui(p.color_index, p.custom_color))
+                        .collect();
+                    let color_clash = find_player_color_clash(&room_colors);
+                    if let Some((a, b)) = color_clash {
+                        ui.add_space(5.0);
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{} and {} have colors too alike to tell apart",
+                                room_players.players[a].name, room_players.players[b].name
+                            ))
+                            .size(12.0)
+                            .color(egui::Color32::from_rgb(255, 150, 100)),
+                        );
+                    }
+
+                    // Ready toggle for everyone but the host, who starts the game when all are ready
+                    let local_player = room_players
+                        .players
+                        .iter()
+                        .find(|p| Some(&p.id) == network_state.local_player_id.as_ref());
+                    if let Some(local_player) = local_player.filter(|p| !p.is_host) {
+                        let is_ready = local_player.is_ready;
+                        ui.add_space(12.0);
+                        let label = if is_ready { "Not Ready Yet" } else { "I'm Ready" };
+                        if desert_button(ui, label, &DesertButtonStyle::medium()).clicked() {
+                            if let Some(ref room_code) = network_state.room_code {
+                                backend.set_ready(room_code, !is_ready);
+                                lobby_state.is_ready = !is_ready;
+                            }
+                        }
                     }
 
                     ui.add_space(20.0);
 
                     // Randomize order toggle - visible to all, editable by host only
-                    #[cfg(target_arch = "wasm32")]
                     {
-                        let randomize_order = js_bindings::get_randomize_order();
-
-                        ui.horizontal(|ui| {
-                            // Draw checkbox manually to show correct state
-                            let (rect, response) = ui.allocate_exact_size(
-                                egui::vec2(20.0, 20.0),
-                                if is_host { egui::Sense::click() } else { egui::Sense::hover() },
-                            );
+                        let randomize_order = backend.randomize_order();
+                        if setting_checkbox(ui, "Randomize play order", randomize_order, is_host) {
+                            if let Some(ref room_code) = network_state.room_code {
+                                backend.set_randomize_order(room_code, !randomize_order);
+                            }
+                        }
+                    }
 
-                            // Draw checkbox background
-                            ui.painter().rect_filled(
-                                rect,
-                                3.0,
-                                if is_host { STONE_DARK } else { egui::Color32::from_rgba_unmultiplied(60, 60, 60, 200) },
-                            );
-                            ui.painter().rect_stroke(
-                                rect,
-                                3.0,
-                                egui::Stroke::new(1.0, egui::Color32::from_rgba_unmultiplied(255, 255, 255, 100)),
-                                egui::epaint::StrokeKind::Outside,
-                            );
+                    ui.add_space(12.0);
 
-                            // Draw checkmark if enabled
-                            if randomize_order {
-                                let check_color = egui::Color32::from_rgb(100, 255, 100);
-                                let center = rect.center();
-                                let size = rect.width() * 0.3;
-                                ui.painter().line_segment(
-                                    [
-                                        egui::pos2(center.x - size, center.y),
-                                        egui::pos2(center.x - size * 0.3, center.y + size * 0.7),
-                                    ],
-                                    egui::Stroke::new(2.5, check_color),
-                                );
-                                ui.painter().line_segment(
-                                    [
-                                        egui::pos2(center.x - size * 0.3, center.y + size * 0.7),
-                                        egui::pos2(center.x + size, center.y - size * 0.5),
-                                    ],
-                                    egui::Stroke::new(2.5, check_color),
+                    // Room settings - the table everyone will play at, set by the host
+                    if let Some(settings) = room_settings.clone() {
+                        let mut edited = settings.clone();
+                        egui::Frame::new()
+                            .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 100)))
+                            .stroke(panel_stroke(egui::Stroke::NONE))
+                            .inner_margin(12.0)
+                            .corner_radius(8.0)
+                            .show(ui, |ui| {
+                                ui.label(
+                                    egui::RichText::new("Room Settings")
+                                        .size(16.0)
+                                        .color(egui::Color32::WHITE),
                                 );
-                            }
-
-                            // Handle click (host only)
-                            if is_host && response.clicked() {
-                                if let Some(ref room_code) = network_state.room_code {
-                                    js_bindings::async_ops::set_randomize_order_async(
-                                        room_code.clone(),
-                                        !randomize_order,
+                                ui.add_space(6.0);
+
+                                // Seats, never fewer than the players already here
+                                ui.horizontal(|ui| {
+                                    let min_seats = room_players.players.len().max(PlayerSetupConfig::MIN_PLAYERS) as u8;
+                                    if is_host && edited.max_players > min_seats
+                                        && desert_button(ui, "-", &DesertButtonStyle::small()).clicked()
+                                    {
+                                        edited.max_players -= 1;
+                                    }
+                                    ui.label(
+                                        egui::RichText::new(format!("Max players: {}", settings.max_players))
+                                            .size(14.0)
+                                            .color(egui::Color32::WHITE),
                                     );
+                                    if is_host && usize::from(edited.max_players) < PlayerSetupConfig::MAX_PLAYERS
+                                        && desert_button(ui, "+", &DesertButtonStyle::small()).clicked()
+                                    {
+                                        edited.max_players += 1;
+                                    }
+                                });
+
+                                if setting_checkbox(ui, "Fill empty seats with AI", settings.fill_with_ai, is_host) {
+                                    edited.fill_with_ai = !settings.fill_with_ai;
                                 }
-                            }
 
-                            ui.add_space(8.0);
+                                ui.horizontal(|ui| {
+                                    let timer_label = match settings.turn_timer_secs {
+                                        0 => "Turn timer: Off".to_string(),
+                                        secs => format!("Turn timer: {}s", secs),
+                                    };
+                                    if is_host {
+                                        if desert_button(ui, &timer_label, &DesertButtonStyle::small()).clicked() {
+                                            edited.turn_timer_secs = next_choice(&TURN_TIMER_CHOICES, settings.turn_timer_secs);
+                                        }
+                                    } else {
+                                        ui.label(
+                                            egui::RichText::new(timer_label)
+                                                .size(14.0)
+                                                .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 150)),
+                                        );
+                                    }
+                                });
+
+                                ui.horizontal(|ui| {
+                                    let grace_label = format!("AI takes over after: {}s away", settings.takeover_grace_secs);
+                                    if is_host {
+                                        if desert_button(ui, &grace_label, &DesertButtonStyle::small()).clicked() {
+                                            edited.takeover_grace_secs =
+                                                next_choice(&TAKEOVER_GRACE_CHOICES, settings.takeover_grace_secs);
+                                        }
+                                    } else {
+                                        ui.label(
+                                            egui::RichText::new(grace_label)
+                                                .size(14.0)
+                                                .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 150)),
+                                        );
+                                    }
+                                });
 
-                            let label_color = if is_host {
-                                egui::Color32::WHITE
-                            } else {
-                                egui::Color32::from_rgba_unmultiplied(255, 255, 255, 150)
-                            };
-                            ui.label(
-                                egui::RichText::new("Randomize play order")
-                                    .size(14.0)
-                                    .color(label_color),
-                            );
+                                ui.add_space(4.0);
+                                if setting_checkbox(ui, "Sheikh variant", settings.sheikh_variant, is_host) {
+                                    edited.sheikh_variant = !settings.sheikh_variant;
+                                }
+                                if setting_checkbox(ui, "Desert events", settings.desert_events, is_host) {
+                                    edited.desert_events = !settings.desert_events;
+                                }
+                                if setting_checkbox(ui, "Relay race", settings.relay_race, is_host) {
+                                    edited.relay_race = !settings.relay_race;
+                                }
+                            });
 
-                            if !is_host {
-                                ui.label(
-                                    egui::RichText::new("(host only)")
-                                        .size(11.0)
-                                        .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 100)),
-                                );
+                        if edited != settings {
+                            if let Some(ref room_code) = network_state.room_code {
+                                let settings_json = serde_json::to_string(&edited).unwrap_or_default();
+                                backend.set_room_settings(room_code, settings_json);
+                            }
+                        }
+                    }
+
+                    ui.add_space(10.0);
+                    // Room lock - the host closes the room once everyone expected is in
+                    let locked = backend.is_locked();
+                    if is_host {
+                        ui.add_space(10.0);
+                        let label = if locked { "Unlock Room" } else { "Lock Room" };
+                        if desert_button(ui, label, &DesertButtonStyle::medium()).clicked() {
+                            if let Some(ref room_code) = network_state.room_code {
+                                backend.set_locked(room_code, !locked);
                             }
-                        });
+                        }
+                    }
+                    if locked {
+                        ui.add_space(5.0);
+                        ui.label(
+                            egui::RichText::new("Room locked - no new players can join")
+                                .size(12.0)
+                                .color(egui::Color32::from_rgb(255, 200, 100)),
+                        );
                     }
 
                     ui.add_space(15.0);
@@ -801,31 +1151,39 @@ pub fn waiting_room_ui(
                     // Host controls
                     if is_host {
                         let button_style = DesertButtonStyle::large();
-                        let can_start = room_players.players.len() >= 2;
-
-                        if desert_button(ui, "Start Game", &button_style).clicked() && can_start {
-                            #[cfg(target_arch = "wasm32")]
-                            {
-                                if let Some(ref room_code) = network_state.room_code {
-                                    // Subscribe to actions from clients
-                                    js_bindings::subscribe_to_actions(room_code);
-
-                                    js_bindings::async_ops::start_game_async(
-                                        room_code.clone(),
-                                        |_| {},
-                                    );
-                                }
-
-                                // Set up local players based on room players
-                                setup_players_from_room(&room_players, &network_state, &mut config);
+                        let settings = room_settings.clone().unwrap_or_default();
+                        let ai_seats = ai_seats_for(&settings, room_players.players.len());
+                        let enough_players = room_players.players.len() + usize::from(ai_seats) >= PlayerSetupConfig::MIN_PLAYERS;
+                        let all_ready = room_players.players.iter().all(|p| p.is_host || p.is_ready);
+                        let can_start = enough_players && all_ready && color_clash.is_none();
+
+                        if desert_button_enabled(ui, "Start Game", &button_style, can_start).clicked() && can_start {
+                            let setup = new_game_setup(&config, &settings, ai_seats);
+                            apply_game_setup(&setup, &mut network_state, &mut config);
+                            if let Some(ref room_code) = network_state.room_code {
+                                // Subscribe to actions from clients
+                                backend.subscribe_to_actions(room_code);
+                                let setup_json = serde_json::to_string(&setup).unwrap_or_default();
+                                backend.start_game(room_code, setup_json, results.clone());
                             }
+
+                            // Set up local players based on room players
+                            let randomize_order = backend.randomize_order();
+                            setup_players_from_room(&room_players, &mut network_state, &mut config, randomize_order, setup.ai_seats);
                             next_state.set(GameState::Playing);
                         }
 
                         if !can_start {
+                            let reason = if !enough_players {
+                                "Need at least 2 players to start (or fill empty seats with AI)"
+                            } else if !all_ready {
+                                "Waiting for everyone to be ready"
+                            } else {
+                                "Waiting for players to pick colors that don't clash"
+                            };
                             ui.add_space(5.0);
                             ui.label(
-                                egui::RichText::new("Need at least 2 players to start")
+                                egui::RichText::new(reason)
                                     .size(12.0)
                                     .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 150)),
                             );
@@ -837,20 +1195,10 @@ pub fn waiting_room_ui(
                     // Leave room button
                     let back_style = DesertButtonStyle::small();
                     if desert_button(ui, "Leave Room", &back_style).clicked() {
-                        #[cfg(target_arch = "wasm32")]
-                        {
-                            if let Some(ref room_code) = network_state.room_code {
-                                js_bindings::async_ops::leave_room_async(room_code.clone());
-                                js_bindings::unsubscribe_all();
-                            }
-                            // Reset subscribed flag
-                            unsafe {
-                                static mut SUBSCRIBED: bool = false;
-                                SUBSCRIBED = false;
-                            }
-                        }
+                        room::leave_room(&**backend, &mut network_state);
                         lobby_state.appearance_initialized = false;
-                        network_state.reset();
+                        lobby_state.players_subscribed = false;
+                        lobby_state.room_settings_shared = false;
                         next_state.set(GameState::Lobby);
                     }
                 });
@@ -858,47 +1206,269 @@ pub fn waiting_room_ui(
         });
 }
 
+/// Settle the game everyone in the room will play: the host's variants and camel
+/// names, and a fresh seed for everything left to chance
+fn new_game_setup(config: &PlayerSetupConfig, settings: &SerializableRoomSettings, ai_seats: u8) -> SerializableGameSetup {
+    SerializableGameSetup {
+        seed: fairness::encode_seed(rand::random()),
+        sheikh_variant: config.sheikh_variant,
+        desert_events: config.desert_events,
+        relay_race: config.relay_race,
+        camel_names: config.camel_names.to_vec(),
+        ai_seats,
+        turn_timer_secs: settings.turn_timer_secs,
+        takeover_grace_secs: settings.takeover_grace_secs,
+        protocol: PROTOCOL_VERSION,
+    }
+}
+
+/// Room settings matching the host's local game setup, shared when the room opens
+fn room_settings_from_config(config: &PlayerSetupConfig) -> SerializableRoomSettings {
+    SerializableRoomSettings {
+        sheikh_variant: config.sheikh_variant,
+        desert_events: config.desert_events,
+        relay_race: config.relay_race,
+        ..Default::default()
+    }
+}
+
+/// Set up the game the way the room's settings say
+fn apply_room_settings(settings: &SerializableRoomSettings, config: &mut PlayerSetupConfig) {
+    config.sheikh_variant = settings.sheikh_variant;
+    config.desert_events = settings.desert_events;
+    config.relay_race = settings.relay_race;
+}
+
+/// AI players to seat alongside the room's players
+fn ai_seats_for(settings: &SerializableRoomSettings, room_players: usize) -> u8 {
+    if settings.fill_with_ai {
+        usize::from(settings.max_players).saturating_sub(room_players) as u8
+    } else {
+        0
+    }
+}
+
+/// Turn timer choices the host cycles through, in seconds (0 for none)
+const TURN_TIMER_CHOICES: [u16; 5] = [0, 30, 60, 90, 120];
+
+/// How long a player may be gone before the AI takes their seat, in seconds
+const TAKEOVER_GRACE_CHOICES: [u16; 4] = [15, 30, 60, 120];
+
+fn next_choice(choices: &[u16], current: u16) -> u16 {
+    let index = choices.iter().position(|&secs| secs == current).unwrap_or(0);
+    choices[(index + 1) % choices.len()]
+}
+
+/// Play with the host's game setup
+fn apply_game_setup(setup: &SerializableGameSetup, network_state: &mut NetworkState, config: &mut PlayerSetupConfig) {
+    network_state.game_seed = fairness::decode_seed(&setup.seed);
+    network_state.turn_timer_secs = setup.turn_timer_secs;
+    network_state.takeover_grace_secs = setup.takeover_grace_secs;
+    config.sheikh_variant = setup.sheikh_variant;
+    config.desert_events = setup.desert_events;
+    config.relay_race = setup.relay_race;
+    for (name, host_name) in config.camel_names.iter_mut().zip(&setup.camel_names) {
+        name.clone_from(host_name);
+    }
+}
+
+/// Room setting checkbox, visible to all and editable by the host only. Returns
+/// whether the host clicked it.
+fn setting_checkbox(ui: &mut egui::Ui, label: &str, checked: bool, is_host: bool) -> bool {
+    ui.horizontal(|ui| {
+        // Draw checkbox manually to show correct state
+        let (rect, response) = ui.allocate_exact_size(
+            egui::vec2(20.0, 20.0),
+            if is_host { egui::Sense::click() } else { egui::Sense::hover() },
+        );
+
+        // Draw checkbox background
+        ui.painter().rect_filled(
+            rect,
+            3.0,
+            if is_host { STONE_DARK } else { egui::Color32::from_rgba_unmultiplied(60, 60, 60, 200) },
+        );
+        ui.painter().rect_stroke(
+            rect,
+            3.0,
+            egui::Stroke::new(1.0, egui::Color32::from_rgba_unmultiplied(255, 255, 255, 100)),
+            egui::epaint::StrokeKind::Outside,
+        );
+
+        // Draw checkmark if enabled
+        if checked {
+            let check_color = egui::Color32::from_rgb(100, 255, 100);
+            let center = rect.center();
+            let size = rect.width() * 0.3;
+            ui.painter().line_segment(
+                [
+                    egui::pos2(center.x - size, center.y),
+                    egui::pos2(center.x - size * 0.3, center.y + size * 0.7),
+                ],
+                egui::Stroke::new(2.5, check_color),
+            );
+            ui.painter().line_segment(
+                [
+                    egui::pos2(center.x - size * 0.3, center.y + size * 0.7),
+                    egui::pos2(center.x + size, center.y - size * 0.5),
+                ],
+                egui::Stroke::new(2.5, check_color),
+            );
+        }
+
+        ui.add_space(8.0);
+
+        let label_color = if is_host {
+            egui::Color32::WHITE
+        } else {
+            egui::Color32::from_rgba_unmultiplied(255, 255, 255, 150)
+        };
+        ui.label(
+            egui::RichText::new(label)
+                .size(14.0)
+                .color(label_color),
+        );
+
+        if !is_host {
+            ui.label(
+                egui::RichText::new("(host only)")
+                    .size(11.0)
+                    .color(egui::Color32::from_rgba_unmultiplied(255, 255, 255, 100)),
+            );
+        }
+
+        // Handle click (host only)
+        is_host && response.clicked()
+    })
+    .inner
+}
+
 /// Set up the player configuration from room players
-#[allow(unused_variables, dead_code)]
 fn setup_players_from_room(
     room_players: &RoomPlayers,
-    network_state: &NetworkState,
+    network_state: &mut NetworkState,
     config: &mut PlayerSetupConfig,
+    randomize_order: bool,
+    ai_seats: u8,
 ) {
-    use rand::seq::SliceRandom;
-
     config.players.clear();
+    // The room already settled the turn order; seats must line up with `seat_player_ids`
+    config.randomize_start_order = false;
 
     // Get the list of players and sort so host is always first
     let mut players: Vec<_> = room_players.players.iter().collect();
-    players.sort_by(|a, b| b.is_host.cmp(&a.is_host)); // Host (true) comes before non-host (false)
-
-    // Optionally randomize order based on Firebase setting (but keep host first)
-    #[cfg(target_arch = "wasm32")]
-    {
-        if js_bindings::get_randomize_order() {
-            let mut rng = rand::thread_rng();
-            // Only shuffle non-host players (skip first player which is host)
-            if players.len() > 1 {
-                players[1..].shuffle(&mut rng);
-            }
+    players.sort_by_key(|p| std::cmp::Reverse(p.is_host)); // Host (true) comes before non-host (false)
+
+    // Optionally randomize order based on the room setting (but keep host first).
+    // Everyone lists the room's players in the same order, so the shared seed gives
+    // everyone the same shuffle.
+    if randomize_order {
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(network_state.game_seed.unwrap_or_default());
+        // Only shuffle non-host players (skip first player which is host)
+        if players.len() > 1 {
+            players[1..].shuffle(&mut rng);
         }
     }
 
-    for player in players {
-        let _is_local = Some(&player.id) == network_state.local_player_id.as_ref();
+    network_state.seat_player_ids = players.iter().map(|p| p.id.clone()).collect();
+    network_state.local_player_index = network_state
+        .local_player_id
+        .as_ref()
+        .and_then(|id| network_state.seat_of(id));
 
+    for player in players {
         config.players.push(crate::ui::player_setup::PlayerConfig {
             name: player.name.clone(),
             is_ai: false, // Online players are never AI
             character_id: CharacterId::from_index(player.character_id as usize),
             color_index: player.color_index,
+            custom_color: player.custom_color,
+            name_edited: true,
+            ai_difficulty: Default::default(),
+        });
+    }
+
+    // AI players in the seats nobody joined for, looking the same for everyone
+    let taken: Vec<_> = config.players.iter().map(|p| (p.character_id, p.color())).collect();
+    for (character, color_index) in ai_seat_looks(&taken, ai_seats) {
+        network_state.seat_player_ids.push(String::new());
+        config.players.push(crate::ui::player_setup::PlayerConfig {
+            name: character.name().to_string(),
+            is_ai: true,
+            character_id: character,
+            color_index,
+            custom_color: None,
             name_edited: true,
+            ai_difficulty: Default::default(),
         });
+    }
+}
 
-        // Track which player index is the local player
-        // (Currently unused but will be needed for turn management)
+/// Character and color of each AI seat: the first ones nobody (not even an earlier AI
+/// seat) has taken, skipping colors that clash with a picked one
+fn ai_seat_looks(taken: &[(CharacterId, egui::Color32)], ai_seats: u8) -> Vec<(CharacterId, usize)> {
+    let mut looks = taken.to_vec();
+    let mut seats = Vec::new();
+    for _ in 0..ai_seats {
+        let character = (0..CharacterId::COUNT)
+            .map(CharacterId::from_index)
+            .find(|c| !looks.iter().any(|(taken, _)| taken == c))
+            .unwrap_or_default();
+        let color_index = (0..PLAYER_COLORS.len())
+            .find(|&c| !looks.iter().any(|&(_, taken)| player_colors_clash(taken, PLAYER_COLORS[c])))
+            .unwrap_or(0);
+        looks.push((character, PLAYER_COLORS[color_index]));
+        seats.push((character, color_index));
     }
+    seats
+}
+
+/// Set up the player configuration from the seats in the host's game state, for
+/// rejoining a game whose room may have changed since it started
+fn setup_players_from_state(
+    state: &SerializableGameState,
+    network_state: &mut NetworkState,
+    config: &mut PlayerSetupConfig,
+) {
+    config.players.clear();
+    config.randomize_start_order = false;
+
+    network_state.seat_player_ids = state.players.iter().map(|p| p.network_id.clone()).collect();
+    network_state.local_player_index = network_state
+        .local_player_id
+        .as_ref()
+        .and_then(|id| network_state.seat_of(id));
+
+    for player in &state.players {
+        config.players.push(crate::ui::player_setup::PlayerConfig {
+            name: player.name.clone(),
+            // Seats the AI took over come back with the host's state
+            is_ai: false,
+            character_id: CharacterId::from_index(player.character_id as usize),
+            color_index: player.color_index,
+            custom_color: player.custom_color,
+            name_edited: true,
+            ai_difficulty: Default::default(),
+        });
+    }
+}
+
+/// System to join the room when the page was opened from an invite link: sign in,
+/// join and go to the waiting room without stopping at the Join screen
+#[cfg(target_arch = "wasm32")]
+pub fn open_invite_link(
+    mut lobby_state: ResMut<LobbyState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(code) = js_bindings::take_invite_room_code() else { return };
+    info!("Opened from an invite link for room {}", code);
+    lobby_state.screen = LobbyScreen::Join;
+    lobby_state.room_code_input = code.to_uppercase();
+    lobby_state.invite_auto_join = true;
+    next_state.set(GameState::Lobby);
 }
 
 /// Cleanup when leaving lobby/waiting room states
@@ -910,4 +1480,6 @@ pub fn cleanup_lobby(
     lobby_state.error_message = None;
     lobby_state.is_loading = false;
     lobby_state.appearance_initialized = false;
+    lobby_state.rejoin_sent = false;
+    lobby_state.room_settings_shared = false;
 }
@@ -1,14 +1,31 @@
-use crate::components::camel::{CamelColor, CrazyCamelColor};
-use crate::game::ai::{AiConfig, AiDifficulty};
-use crate::game::state::GameState;
-use crate::ui::characters::{draw_avatar, CharacterId};
+use crate::components::camel::{CamelColor, CamelPalette, CrazyCamelColor};
+use crate::game::autosave::Autosave;
+use crate::game::key_bindings::{KeyAction, KeyBindings};
+use crate::game::replay::ReplayViewer;
+use crate::game::settings::{Settings, MAX_ANIMATION_SPEED, MAX_UI_SCALE, MIN_ANIMATION_SPEED, MIN_UI_SCALE};
+use crate::game::leaderboard::Leaderboard;
+use crate::game::stats::PlayerStats;
+use crate::systems::achievements::Achievements;
+use crate::game::state::{GameState, SuspendedGame};
+use crate::network::session::saved_session;
+use crate::network::state::{NetworkState, ReceivedGameState};
+use crate::systems::autoplay::AutoplayConfig;
+use crate::systems::speech::{Speaker, SpeechSettings, MAX_SPEECH_RATE, MIN_SPEECH_RATE};
+use crate::ui::characters::{AvatarSpec, CharacterId};
+use crate::ui::draw::draw_avatar;
 use crate::ui::hud::UiState;
+use crate::ui::lobby::{LobbyScreen, LobbyState};
 use crate::ui::player_setup::PlayerSetupConfig;
 use crate::ui::rules::{draw_rules_ui, RulesState};
+use crate::ui::stats::{draw_stats_ui, StatsPageState};
+use crate::ui::leaderboard::{draw_leaderboard_ui, LeaderboardPageState};
+use crate::ui::achievements::{draw_trophy_gallery_ui, TrophyGalleryState};
 use crate::ui::theme::{
-    desert_button, desert_button_enabled, desert_combobox, desert_toggle, DesertButtonStyle,
+    camel_color_to_egui, desert_button, desert_button_enabled, desert_toggle, DesertButtonStyle,
+    find_player_color_clash, player_color_picker, player_colors_clash, GOLD_DARK,
     PLAYER_COLORS, STONE_DARK,
 };
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 use rand::seq::SliceRandom;
@@ -36,9 +53,9 @@ enum AnyCamel {
 }
 
 impl AnyCamel {
-    fn to_egui_color(&self) -> egui::Color32 {
+    fn to_egui_color(self, camel_palette: CamelPalette) -> egui::Color32 {
         match self {
-            AnyCamel::Regular(color) => bevy_to_egui_color(color.to_bevy_color()),
+            AnyCamel::Regular(color) => bevy_to_egui_color(color.to_bevy_color(camel_palette)),
             AnyCamel::Crazy(color) => bevy_to_egui_color(color.to_bevy_color()),
         }
     }
@@ -48,28 +65,95 @@ impl AnyCamel {
     }
 }
 
+/// The ways out of the main menu into a game: a new local game, a suspended or
+/// autosaved one, autoplay, the online lobby or a replay
+#[derive(SystemParam)]
+pub struct MenuGameStarts<'w> {
+    next_state: ResMut<'w, NextState<GameState>>,
+    config: ResMut<'w, PlayerSetupConfig>,
+    suspended: ResMut<'w, SuspendedGame>,
+    autosave: ResMut<'w, Autosave>,
+    autoplay: ResMut<'w, AutoplayConfig>,
+    lobby_state: ResMut<'w, LobbyState>,
+    received_state: ResMut<'w, ReceivedGameState>,
+    network_state: ResMut<'w, NetworkState>,
+    replays: ResMut<'w, ReplayViewer>,
+}
+
+/// The player's preferences the menu's Settings edit
+#[derive(SystemParam)]
+pub struct MenuPreferences<'w> {
+    settings: ResMut<'w, Settings>,
+    speech: ResMut<'w, SpeechSettings>,
+    speaker: ResMut<'w, Speaker>,
+    key_bindings: ResMut<'w, KeyBindings>,
+    game_config: Res<'w, crate::GameConfig>,
+}
+
+/// The pages the menu opens over itself: the rules, stats, leaderboard and trophies
+#[derive(SystemParam)]
+pub struct MenuPages<'w> {
+    rules_state: ResMut<'w, RulesState>,
+    stats_page: ResMut<'w, StatsPageState>,
+    player_stats: Res<'w, PlayerStats>,
+    trophy_gallery: ResMut<'w, TrophyGalleryState>,
+    achievements: Res<'w, Achievements>,
+    leaderboard_page: ResMut<'w, LeaderboardPageState>,
+    leaderboard: Res<'w, Leaderboard>,
+}
+
 pub fn main_menu_ui(
     mut contexts: EguiContexts,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut config: ResMut<PlayerSetupConfig>,
-    mut ai_config: ResMut<AiConfig>,
     ui_state: Res<UiState>,
     time: Res<Time>,
-    mut rules_state: ResMut<RulesState>,
+    game_starts: MenuGameStarts,
+    preferences: MenuPreferences,
+    pages: MenuPages,
+    #[cfg_attr(target_arch = "wasm32", allow(unused_mut, unused_variables))]
+    mut quit_requested: MessageWriter<crate::QuitRequested>,
 ) {
+    let MenuGameStarts {
+        mut next_state,
+        mut config,
+        mut suspended,
+        mut autosave,
+        mut autoplay,
+        mut lobby_state,
+        mut received_state,
+        mut network_state,
+        mut replays,
+    } = game_starts;
+    let MenuPreferences { mut settings, mut speech, mut speaker, mut key_bindings, game_config } = preferences;
+    let MenuPages {
+        mut rules_state,
+        mut stats_page,
+        player_stats,
+        mut trophy_gallery,
+        achievements,
+        mut leaderboard_page,
+        leaderboard,
+    } = pages;
     let Ok(ctx) = contexts.ctx_mut() else { return };
     let is_mobile = !ui_state.use_side_panels;
     let elapsed = time.elapsed_secs();
+    let camel_palette = settings.camel_palette();
 
     // Draw rules UI if open (on top of everything)
-    draw_rules_ui(ctx, &mut rules_state, is_mobile, time.delta_secs());
+    draw_rules_ui(ctx, &mut rules_state, is_mobile, time.delta_secs(), camel_palette);
+    draw_stats_ui(ctx, &mut stats_page, &player_stats, is_mobile);
+    draw_leaderboard_ui(ctx, &mut leaderboard_page, &leaderboard, is_mobile);
+    draw_trophy_gallery_ui(ctx, &mut trophy_gallery, &achievements, is_mobile);
 
     egui::CentralPanel::default()
         .frame(egui::Frame::NONE)
         .show(ctx, |ui| {
             // Draw pyramid background behind everything
             let rect = ui.available_rect_before_wrap();
-            draw_pyramid_background(ui.painter(), rect, elapsed);
+            draw_pyramid_background(ui.painter(), rect, elapsed, camel_palette);
+
+            // Players whose colors are too alike, which keeps the game from starting
+            let colors: Vec<egui::Color32> = config.players.iter().map(|p| p.color()).collect();
+            let color_clash = find_player_color_clash(&colors);
 
             egui::ScrollArea::vertical().show(ui, |ui| {
                 ui.vertical_centered(|ui| {
@@ -151,7 +235,7 @@ pub fn main_menu_ui(
                             .max_height(220.0)
                             .show(ui, |ui| {
                                 for i in 0..config.players.len() {
-                                    let player_color = PLAYER_COLORS[config.players[i].color_index % PLAYER_COLORS.len()];
+                                    let player_color = config.players[i].color();
                                     let character_id = config.players[i].character_id;
 
                                     // Calculate used characters (for cycling)
@@ -189,10 +273,10 @@ pub fn main_menu_ui(
                                                     // Cycle on click (cycles both avatar and color)
                                                     if response.clicked() {
                                                         // Cycle character
-                                                        let current_idx = character_id as usize;
-                                                        for offset in 1..=16 {
+                                                        let current_idx = character_id.index();
+                                                        for offset in 1..=CharacterId::COUNT {
                                                             let next = CharacterId::from_index(
-                                                                (current_idx + offset) % 16,
+                                                                (current_idx + offset) % CharacterId::COUNT,
                                                             );
                                                             if !used.contains(&next) {
                                                                 config.players[i].character_id = next;
@@ -204,25 +288,32 @@ pub fn main_menu_ui(
                                                             }
                                                         }
 
-                                                        // Cycle color
-                                                        let used_colors: HashSet<usize> = config
+                                                        // Cycle color through the palette, skipping any that
+                                                        // clash with another player's
+                                                        let other_colors: Vec<egui::Color32> = config
                                                             .players
                                                             .iter()
                                                             .enumerate()
                                                             .filter(|(idx, _)| *idx != i)
-                                                            .map(|(_, p)| p.color_index)
+                                                            .map(|(_, p)| p.color())
                                                             .collect();
 
                                                         let current_color = config.players[i].color_index;
-                                                        for offset in 1..=8 {
-                                                            let next_color = (current_color + offset) % 8;
-                                                            if !used_colors.contains(&next_color) {
+                                                        for offset in 1..=PLAYER_COLORS.len() {
+                                                            let next_color = (current_color + offset) % PLAYER_COLORS.len();
+                                                            if !other_colors.iter().any(|&c| player_colors_clash(c, PLAYER_COLORS[next_color])) {
                                                                 config.players[i].color_index = next_color;
+                                                                config.players[i].custom_color = None;
                                                                 break;
                                                             }
                                                         }
                                                     }
 
+                                                    // Any color at all, from the picker
+                                                    ui.add_space(4.0);
+                                                    let player = &mut config.players[i];
+                                                    player_color_picker(ui, player.color_index, &mut player.custom_color);
+
                                                     ui.add_space(10.0);
 
                                                     // Calculate remaining width for flexible name input
@@ -271,6 +362,22 @@ pub fn main_menu_ui(
                                                         config.set_player_is_ai(i, true);
                                                     }
 
+                                                    // AI difficulty (tap to cycle)
+                                                    if config.players[i].is_ai {
+                                                        ui.add_space(6.0);
+                                                        let difficulty = config.players[i].ai_difficulty;
+                                                        if desert_button(
+                                                            ui,
+                                                            difficulty.label(),
+                                                            &DesertButtonStyle::compact(),
+                                                        )
+                                                        .on_hover_text("AI difficulty")
+                                                        .clicked()
+                                                        {
+                                                            config.players[i].ai_difficulty = difficulty.next();
+                                                        }
+                                                    }
+
                                                     ui.add_space(10.0);
                                                 },
                                             );
@@ -280,6 +387,21 @@ pub fn main_menu_ui(
                                 }
                             });
 
+                        // Two players the same color can't be told apart in the game
+                        if let Some((a, b)) = color_clash {
+                            ui.add_space(4.0);
+                            ui.horizontal(|ui| {
+                                ui.add_space(10.0);
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "{} and {} have colors too alike to tell apart",
+                                        config.players[a].name, config.players[b].name
+                                    ))
+                                    .color(egui::Color32::from_rgb(255, 150, 100)),
+                                );
+                            });
+                        }
+
                         ui.add_space(10.0);
                         // Custom themed separator
                         let separator_rect = ui.available_rect_before_wrap();
@@ -297,34 +419,41 @@ pub fn main_menu_ui(
                         );
                         ui.add_space(5.0);
 
-                        // AI Difficulty selector (only show if there are AI players)
-                        let has_ai_players = config.players.iter().any(|p| p.is_ai);
-                        if has_ai_players {
+                        // Neutral Sheikh variant toggle (2-player games only)
+                        if config.players.len() == 2 {
                             ui.horizontal(|ui| {
                                 ui.add_space(10.0);
-                                ui.label(
-                                    egui::RichText::new("AI Difficulty:")
+                                ui.checkbox(
+                                    &mut config.sheikh_variant,
+                                    egui::RichText::new("Neutral Sheikh takes rolled camel's tile")
                                         .color(egui::Color32::WHITE),
                                 );
-                                ui.add_space(8.0);
-
-                                let options = [
-                                    AiDifficulty::Random,
-                                    AiDifficulty::Basic,
-                                    AiDifficulty::Smart,
-                                ];
-                                let labels = ["Random", "Basic", "Smart"];
-                                desert_combobox(
-                                    ui,
-                                    "ai_difficulty",
-                                    &mut ai_config.difficulty,
-                                    &options,
-                                    &labels,
-                                );
                             });
                             ui.add_space(5.0);
                         }
 
+                        // Desert Events variant toggle
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.checkbox(
+                                &mut config.desert_events,
+                                egui::RichText::new("Desert Events (random rule each leg)")
+                                    .color(egui::Color32::WHITE),
+                            );
+                        });
+                        ui.add_space(5.0);
+
+                        // Relay Race variant toggle (experimental)
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.checkbox(
+                                &mut config.relay_race,
+                                egui::RichText::new("Relay Race (experimental: two tracks)")
+                                    .color(egui::Color32::WHITE),
+                            );
+                        });
+                        ui.add_space(5.0);
+
                         // Randomize start order toggle
                         ui.horizontal(|ui| {
                             ui.add_space(10.0);
@@ -334,6 +463,104 @@ pub fn main_menu_ui(
                                     .color(egui::Color32::WHITE),
                             );
                         });
+                        ui.add_space(5.0);
+
+                        // Expected value hints on bets (purists can turn them off)
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.checkbox(
+                                &mut config.show_bet_values,
+                                egui::RichText::new("Show expected value of bets")
+                                    .color(egui::Color32::WHITE),
+                            );
+                        });
+                        ui.add_space(5.0);
+
+                        // Seed to replay a game exactly (a number, or any word)
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.label(egui::RichText::new("Game seed").color(egui::Color32::WHITE));
+                            ui.scope(|ui| {
+                                ui.visuals_mut().extreme_bg_color = STONE_DARK;
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut config.seed)
+                                        .hint_text("random")
+                                        .char_limit(24)
+                                        .desired_width(140.0)
+                                        .text_color(egui::Color32::WHITE),
+                                );
+                            });
+                        });
+                        ui.add_space(5.0);
+
+                        // Cosmetic camel names (rules still use the colors)
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.collapsing(
+                                egui::RichText::new("Camel names").color(egui::Color32::WHITE),
+                                |ui| {
+                                    for (i, color) in CamelColor::all().into_iter().enumerate() {
+                                        ui.horizontal(|ui| {
+                                            ui.label(
+                                                egui::RichText::new(format!("{:?}", color))
+                                                    .color(camel_color_to_egui(color, camel_palette))
+                                                    .strong(),
+                                            );
+                                            ui.scope(|ui| {
+                                                ui.visuals_mut().extreme_bg_color = STONE_DARK;
+                                                ui.add(
+                                                    egui::TextEdit::singleline(&mut config.camel_names[i])
+                                                        .hint_text(format!("{:?}", color))
+                                                        .char_limit(16)
+                                                        .desired_width(140.0)
+                                                        .text_color(egui::Color32::WHITE),
+                                                );
+                                            });
+                                        });
+                                    }
+                                },
+                            );
+                        });
+                        ui.add_space(5.0);
+
+                        // Spoken commentary for players who can't easily read the panels
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.collapsing(
+                                egui::RichText::new("Spoken commentary").color(egui::Color32::WHITE),
+                                |ui| draw_speech_settings(ui, &mut speech, &mut speaker),
+                            );
+                        });
+                        ui.add_space(5.0);
+
+                        // Preferences kept between runs
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.collapsing(
+                                egui::RichText::new("Settings").color(egui::Color32::WHITE),
+                                |ui| draw_preferences(ui, &mut settings, &mut config),
+                            );
+                        });
+                        ui.add_space(5.0);
+
+                        // Master, music and sound effect volumes, and mute
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.collapsing(
+                                egui::RichText::new("Sound").color(egui::Color32::WHITE),
+                                |ui| draw_sound_settings(ui, &mut settings),
+                            );
+                        });
+                        ui.add_space(5.0);
+
+                        // Keyboard shortcuts, rebound by clicking one and pressing a key
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+                            ui.collapsing(
+                                egui::RichText::new("Key bindings").color(egui::Color32::WHITE),
+                                |ui| draw_key_bindings(ui, &mut key_bindings, game_config.developer_tools),
+                            );
+                        });
 
                         ui.add_space(10.0);
                     });
@@ -351,16 +578,81 @@ pub fn main_menu_ui(
                         DesertButtonStyle::large()
                     };
 
-                    if desert_button(ui, "Start Game", &start_style).clicked() {
-                        next_state.set(GameState::Playing);
-                    }
+                    let medium_style = DesertButtonStyle::medium();
+                    if suspended.active {
+                        // A saved game has to be resumed or abandoned before starting another
+                        if desert_button(ui, "Resume Game", &start_style).clicked() {
+                            suspended.active = false;
+                            next_state.set(GameState::Playing);
+                        }
 
-                    ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+                        ui.add_space(if is_mobile { 10.0 } else { 15.0 });
 
-                    // Play Online button
-                    let medium_style = DesertButtonStyle::medium();
-                    if desert_button(ui, "Play Online", &medium_style).clicked() {
-                        next_state.set(GameState::Lobby);
+                        if desert_button(ui, "Abandon Game", &medium_style).clicked() {
+                            // Re-entering the menu cleans up the abandoned game
+                            suspended.active = false;
+                            autosave.clear();
+                            next_state.set(GameState::MainMenu);
+                        }
+                    } else {
+                        if desert_button_enabled(ui, "Start Game", &start_style, color_clash.is_none()).clicked() {
+                            // The new game takes the save's place
+                            autosave.clear();
+                            next_state.set(GameState::Playing);
+                        }
+
+                        ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+
+                        // The local game that was going when the app last closed
+                        if autosave.saved.is_some() {
+                            if desert_button(ui, "Continue Last Game", &medium_style).clicked()
+                                && autosave.restore(&mut config, &mut received_state)
+                            {
+                                next_state.set(GameState::Playing);
+                            }
+
+                            ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+                        }
+
+                        // The online game we were in when the page reloaded or the app closed
+                        if let Some(saved) = saved_session() {
+                            let label = format!("Rejoin room {}", saved.room_code);
+                            if desert_button(ui, &label, &medium_style).clicked() {
+                                lobby_state.screen = LobbyScreen::Rejoin;
+                                lobby_state.room_code_input = saved.room_code;
+                                next_state.set(GameState::Lobby);
+                            }
+
+                            ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+                        }
+
+                        // Play Online button
+                        if desert_button(ui, "Play Online", &medium_style).clicked() {
+                            next_state.set(GameState::Lobby);
+                        }
+
+                        ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+
+                        // Every seat played by the AI, hands-free (Escape to stop, unless rebound)
+                        let watch = desert_button(ui, "Watch AI Game", &medium_style).on_hover_text(format!(
+                            "All seats go to the AI and the game plays itself. Press {} to stop.",
+                            key_bindings.key_name(KeyAction::StopWatching)
+                        ));
+                        if watch.clicked() {
+                            autoplay.start(&mut config);
+                            next_state.set(GameState::Playing);
+                        }
+
+                        // The last finished game, played back move by move
+                        if replays.last.is_some() {
+                            ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+
+                            let replay = desert_button(ui, "Watch Replay", &medium_style)
+                                .on_hover_text("Watch your last finished game again, with pause, step and speed controls.");
+                            if replay.clicked() && replays.start(&mut config, &mut network_state) {
+                                next_state.set(GameState::Playing);
+                            }
+                        }
                     }
 
                     ui.add_space(if is_mobile { 10.0 } else { 15.0 });
@@ -372,10 +664,30 @@ pub fn main_menu_ui(
 
                     ui.add_space(if is_mobile { 10.0 } else { 15.0 });
 
+                    // Results of everyone who has played on this device
+                    if desert_button(ui, "Stats", &medium_style).clicked() {
+                        stats_page.is_open = true;
+                    }
+
+                    ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+
+                    // Best final scores made on this device
+                    if desert_button(ui, "Leaderboard", &medium_style).clicked() {
+                        leaderboard_page.is_open = true;
+                    }
+
+                    ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+
+                    if desert_button(ui, "Trophies", &medium_style).clicked() {
+                        trophy_gallery.is_open = true;
+                    }
+
+                    ui.add_space(if is_mobile { 10.0 } else { 15.0 });
+
                     // Quit button (hide on mobile/web - users close the browser tab)
                     #[cfg(not(target_arch = "wasm32"))]
                     if desert_button(ui, "Quit", &DesertButtonStyle::small()).clicked() {
-                        std::process::exit(0);
+                        quit_requested.write(crate::QuitRequested);
                     }
                 });
             });
@@ -389,17 +701,251 @@ pub fn main_menu_ui(
 /// phase_offset: offset for walking cycle (different camels walk at different phases)
 /// color: the camel's main color
 /// direction: 1.0 for left-to-right, -1.0 for right-to-left
-fn draw_walking_camel(
-    painter: &egui::Painter,
-    x: f32,
-    y: f32,
+/// Controls for reading the game log aloud: on/off, rate, voice and a test line
+fn draw_speech_settings(ui: &mut egui::Ui, speech: &mut ResMut<SpeechSettings>, speaker: &mut Speaker) {
+    // Edit a copy so the settings only register as changed when the player changes them
+    let mut settings = (**speech).clone();
+    let available = speaker.available();
+
+    let toggle = ui.add_enabled(
+        available,
+        egui::Checkbox::new(
+            &mut settings.enabled,
+            egui::RichText::new("Read the game aloud").color(egui::Color32::WHITE),
+        ),
+    );
+    if !available {
+        toggle.on_disabled_hover_text("Text-to-speech isn't available on this device");
+    }
+
+    ui.add_enabled_ui(available && settings.enabled, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Rate").color(egui::Color32::WHITE));
+            ui.add(
+                egui::Slider::new(&mut settings.rate, MIN_SPEECH_RATE..=MAX_SPEECH_RATE)
+                    .step_by(0.1)
+                    .suffix("x"),
+            );
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Volume").color(egui::Color32::WHITE));
+            ui.add(egui::Slider::new(&mut settings.volume, 0.0..=1.0).custom_formatter(|v, _| format!("{:.0}%", v * 100.0)));
+        });
+
+        let voices = speaker.voices().to_vec();
+        if !voices.is_empty() {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new("Voice").color(egui::Color32::WHITE));
+                egui::ComboBox::from_id_salt("speech_voice")
+                    .selected_text(settings.voice.as_deref().unwrap_or("Default"))
+                    .width(180.0)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut settings.voice, None, "Default");
+                        for voice in voices {
+                            ui.selectable_value(&mut settings.voice, Some(voice.clone()), voice);
+                        }
+                    });
+            });
+        }
+
+        if ui.button("Test voice").clicked() {
+            speaker.configure(&settings);
+            speaker.speak("The camels are lined up and ready to race.");
+        }
+    });
+
+    speech.set_if_neq(settings);
+}
+
+/// Volume sliders for everything, the music and the sound effects, and a mute toggle
+fn draw_sound_settings(ui: &mut egui::Ui, settings: &mut ResMut<Settings>) {
+    // Edit a copy so the settings only register as changed when the player changes them
+    let mut edited = (**settings).clone();
+
+    ui.checkbox(&mut edited.muted, egui::RichText::new("Mute").color(egui::Color32::WHITE));
+
+    ui.add_enabled_ui(!edited.muted, |ui| {
+        egui::Grid::new("sound_volumes").num_columns(2).spacing([12.0, 4.0]).show(ui, |ui| {
+            for (label, volume) in [
+                ("Master", &mut edited.master_volume),
+                ("Music", &mut edited.music_volume),
+                ("Sound effects", &mut edited.sfx_volume),
+            ] {
+                ui.label(egui::RichText::new(label).color(egui::Color32::WHITE));
+                ui.add(egui::Slider::new(volume, 0.0..=1.0).custom_formatter(|v, _| format!("{:.0}%", v * 100.0)));
+                ui.end_row();
+            }
+        });
+    });
+
+    settings.set_if_neq(edited);
+}
+
+/// Preferences kept between runs: animation speed, colorblind palette and markers, UI scale,
+/// large text, the dyslexia-friendly font, high contrast, the left-handed layout, and the
+/// name and avatar the first seat starts with
+fn draw_preferences(ui: &mut egui::Ui, settings: &mut ResMut<Settings>, config: &mut PlayerSetupConfig) {
+    // Edit a copy so the settings only register as changed when the player changes them
+    let mut edited = (**settings).clone();
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("Animation speed").color(egui::Color32::WHITE));
+        ui.add(
+            egui::Slider::new(&mut edited.animation_speed, MIN_ANIMATION_SPEED..=MAX_ANIMATION_SPEED)
+                .step_by(0.25)
+                .suffix("x"),
+        )
+        .on_hover_text("Local games only: online games always play at normal speed");
+    });
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("UI scale").color(egui::Color32::WHITE));
+        ui.add(egui::Slider::new(&mut edited.ui_scale, MIN_UI_SCALE..=MAX_UI_SCALE).step_by(0.05).suffix("x"));
+    });
+
+    ui.checkbox(&mut edited.large_text, egui::RichText::new("Large text").color(egui::Color32::WHITE));
+    ui.checkbox(&mut edited.dyslexia_font, egui::RichText::new("Dyslexia-friendly font").color(egui::Color32::WHITE))
+        .on_hover_text("A plain, wide font with letters that are hard to mix up");
+    ui.checkbox(&mut edited.high_contrast, egui::RichText::new("High contrast").color(egui::Color32::WHITE))
+        .on_hover_text("Darker panels, thicker borders and brighter text, for bright sunlight or low vision");
+    ui.checkbox(&mut edited.left_handed, egui::RichText::new("Left-handed layout").color(egui::Color32::WHITE))
+        .on_hover_text("Actions on the right and players on the left, with the phone action sheet mirrored");
+
+    ui.checkbox(
+        &mut edited.colorblind,
+        egui::RichText::new("Colorblind-friendly camel colors").color(egui::Color32::WHITE),
+    );
+    ui.checkbox(
+        &mut edited.color_markers,
+        egui::RichText::new("Letters and patterns on camels, dice and bet tiles").color(egui::Color32::WHITE),
+    );
+
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("Your name").color(egui::Color32::WHITE));
+        let mut name = edited.player_name.clone().unwrap_or_default();
+        ui.scope(|ui| {
+            ui.visuals_mut().extreme_bg_color = STONE_DARK;
+            ui.add(
+                egui::TextEdit::singleline(&mut name)
+                    .hint_text("Player 1")
+                    .char_limit(16)
+                    .desired_width(120.0)
+                    .text_color(egui::Color32::WHITE),
+            );
+        });
+        edited.player_name = (!name.trim().is_empty()).then_some(name);
+
+        // Avatar (tap to cycle; tapping a built one goes back to the characters)
+        let character = CharacterId::from_index(edited.player_character.unwrap_or(0) as usize);
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::click());
+        draw_avatar(ui.painter(), rect, edited.player_avatar.map_or(character, CharacterId::Custom), None);
+        if response.on_hover_text("Your avatar (tap to change)").clicked() {
+            if edited.player_avatar.is_some() {
+                edited.player_avatar = None;
+            } else {
+                edited.player_character = Some(((character.index() + 1) % CharacterId::COUNT) as u8);
+            }
+        }
+    });
+
+    ui.collapsing(egui::RichText::new("Avatar editor").color(egui::Color32::WHITE), |ui| {
+        draw_avatar_editor(ui, &mut edited.player_avatar);
+    });
+
+    let seat_changed = edited.player_name != settings.player_name
+        || edited.player_character != settings.player_character
+        || edited.player_avatar != settings.player_avatar;
+    if settings.set_if_neq(edited) && seat_changed {
+        settings.apply_to_first_seat(config);
+    }
+}
+
+/// Build an avatar from a skin tone, headwear and accessory, each tapped to cycle, with a
+/// preview beside them. Editing starts from the default look if there's no avatar yet.
+fn draw_avatar_editor(ui: &mut egui::Ui, avatar: &mut Option<AvatarSpec>) {
+    let mut spec = avatar.unwrap_or_default();
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(56.0, 56.0), egui::Sense::hover());
+        draw_avatar(ui.painter(), rect, CharacterId::Custom(spec), None);
+        ui.add_space(8.0);
+
+        egui::Grid::new("avatar_editor_grid").num_columns(2).spacing([12.0, 4.0]).show(ui, |ui| {
+            let style = DesertButtonStyle::compact();
+            ui.label(egui::RichText::new("Skin").color(egui::Color32::WHITE));
+            if desert_button(ui, spec.skin.label(), &style).clicked() {
+                spec.skin = spec.skin.next();
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label(egui::RichText::new("Headwear").color(egui::Color32::WHITE));
+            if desert_button(ui, spec.headwear.label(), &style).clicked() {
+                spec.headwear = spec.headwear.next();
+                changed = true;
+            }
+            ui.end_row();
+
+            ui.label(egui::RichText::new("Accessory").color(egui::Color32::WHITE));
+            if desert_button(ui, spec.accessory.label(), &style).clicked() {
+                spec.accessory = spec.accessory.next();
+                changed = true;
+            }
+            ui.end_row();
+        });
+    });
+
+    if avatar.is_none() && ui.button("Use this avatar").clicked() {
+        changed = true;
+    }
+    if changed {
+        *avatar = Some(spec);
+    }
+}
+
+/// One row per shortcut this build listens for. Clicking a key waits for the next key
+/// pressed (see `capture_key_binding`); clicking it again cancels.
+fn draw_key_bindings(ui: &mut egui::Ui, key_bindings: &mut ResMut<KeyBindings>, developer_tools: bool) {
+    egui::Grid::new("key_bindings_grid").num_columns(2).spacing([12.0, 4.0]).show(ui, |ui| {
+        for action in KeyAction::ALL.into_iter().filter(|a| a.is_available(developer_tools)) {
+            ui.label(egui::RichText::new(action.label()).color(egui::Color32::WHITE));
+
+            let capturing = key_bindings.capturing == Some(action);
+            let text = if capturing { "Press a key..." } else { key_bindings.key_name(action) };
+            let button = egui::Button::new(egui::RichText::new(text).color(egui::Color32::WHITE))
+                .fill(if capturing { GOLD_DARK } else { STONE_DARK })
+                .min_size(egui::vec2(110.0, 0.0));
+            if ui.add(button).clicked() {
+                key_bindings.capturing = if capturing { None } else { Some(action) };
+            }
+            ui.end_row();
+        }
+    });
+
+    if ui.button("Reset to defaults").clicked() {
+        **key_bindings = KeyBindings::default();
+    }
+}
+
+/// One camel of the menu's caravan
+#[derive(Clone, Copy)]
+struct WalkingCamel {
+    pos: egui::Pos2,
     scale: f32,
-    time: f32,
+    /// Offset into the walk cycle, so the caravan doesn't step in unison
     phase_offset: f32,
+    /// Fades farther camels
     alpha: u8,
     color: egui::Color32,
+    /// 1.0 walking right, -1.0 walking left
     direction: f32,
-) {
+}
+
+fn draw_walking_camel(painter: &egui::Painter, camel: WalkingCamel, time: f32) {
+    let WalkingCamel { pos: egui::Pos2 { x, y }, scale, phase_offset, alpha, color, direction } = camel;
+
     // Camel dimensions (scaled)
     let body_w = 32.0 * scale;
     let body_h = 14.0 * scale;
@@ -556,7 +1102,7 @@ fn draw_walking_camel(
 }
 
 /// Draw the pyramid scene as a background decoration
-fn draw_pyramid_background(painter: &egui::Painter, rect: egui::Rect, time: f32) {
+fn draw_pyramid_background(painter: &egui::Painter, rect: egui::Rect, time: f32, camel_palette: CamelPalette) {
     // Draw sky background
     painter.rect_filled(rect, 0.0, SKY_BLUE);
 
@@ -698,7 +1244,7 @@ fn draw_pyramid_background(painter: &egui::Painter, rect: egui::Rect, time: f32)
 
     for (i, (phase, scale, y_off, speed_mult)) in camel_configs.iter().enumerate() {
         let camel = selected_camels[i];
-        let camel_color = camel.to_egui_color();
+        let camel_color = camel.to_egui_color(camel_palette);
         let is_crazy = camel.is_crazy();
 
         // Crazy camels go right to left, regular camels go left to right
@@ -720,16 +1266,14 @@ fn draw_pyramid_background(painter: &egui::Painter, rect: egui::Rect, time: f32)
         // Use alpha for distance fading (smaller scale = farther away = more faded)
         let alpha = ((300.0 * scale) as u8).max(100);
 
-        draw_walking_camel(
-            painter,
-            camel_x,
-            camel_y,
-            *scale,
-            time,
-            *phase,
+        let walking_camel = WalkingCamel {
+            pos: egui::pos2(camel_x, camel_y),
+            scale: *scale,
+            phase_offset: *phase,
             alpha,
-            camel_color,
+            color: camel_color,
             direction,
-        );
+        };
+        draw_walking_camel(painter, walking_camel, time);
     }
 }
@@ -6,6 +6,20 @@ pub mod pyramid;
 pub mod scoring;
 pub mod player_setup;
 pub mod characters;
+pub mod draw;
 pub mod rules;
 pub mod theme;
 pub mod lobby;
+pub mod invite;
+pub mod game_log;
+pub mod odds;
+pub mod quit_dialog;
+pub mod space_inspector;
+pub mod turn_banner;
+pub mod stats;
+pub mod leaderboard;
+pub mod achievements;
+#[cfg(feature = "state_overlay")]
+pub mod state_overlay;
+#[cfg(feature = "network_overlay")]
+pub mod network_overlay;
@@ -0,0 +1,99 @@
+// Developer overlay showing what the network layer is doing
+// Build with `--features network_overlay` and press F3 in any screen. Shows the
+// network mode, the room and our seat, the latest message each way with its size,
+// and how many actions are queued, so sync bugs can be watched as they happen.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::game::key_bindings::{KeyAction, KeyBindings};
+use crate::network::state::{NetworkState, PendingNetworkActions};
+
+/// Whether the overlay is showing (F3 toggles)
+#[derive(Resource, Default)]
+pub struct NetworkOverlay {
+    pub visible: bool,
+}
+
+const LABEL_COLOR: egui::Color32 = egui::Color32::from_rgb(0x90, 0x90, 0x90);
+const VALUE_COLOR: egui::Color32 = egui::Color32::from_rgb(0xFF, 0xC8, 0x3C);
+
+/// A message's time as seconds ago, or a dash if there hasn't been one
+fn ago(at: Option<f64>, now: f64) -> String {
+    at.map_or("-".to_string(), |at| format!("{:.1}s ago", now - at))
+}
+
+/// Bytes as B or KB, whichever reads better
+fn size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+/// System to toggle the overlay with its key (F3 unless rebound)
+pub fn toggle_network_overlay(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut overlay: ResMut<NetworkOverlay>,
+) {
+    if key_bindings.just_pressed(&keys, KeyAction::NetworkOverlay) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// System to draw the network overlay
+pub fn network_overlay_ui(
+    mut contexts: EguiContexts,
+    overlay: Res<NetworkOverlay>,
+    network_state: Res<NetworkState>,
+    pending_actions: Res<PendingNetworkActions>,
+    time: Res<Time>,
+) {
+    if !overlay.visible {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let now = time.elapsed_secs_f64();
+    let traffic = &network_state.traffic;
+
+    let rows = [
+        ("Mode", format!("{:?}", network_state.mode)),
+        ("Room", network_state.room_code.clone().unwrap_or_else(|| "-".to_string())),
+        ("Local seat", network_state.local_player_index.map_or("-".to_string(), |i| i.to_string())),
+        ("Connected", network_state.is_connected.to_string()),
+        ("Last sent", format!("{} ({})", ago(traffic.last_sent_at, now), size(traffic.last_sent_bytes))),
+        ("Last received", format!("{} ({})", ago(traffic.last_received_at, now), size(traffic.last_received_bytes))),
+        ("Total sent", size(traffic.total_sent_bytes)),
+        ("Total received", size(traffic.total_received_bytes)),
+        ("Pending actions", pending_actions.actions.len().to_string()),
+        ("Queued writes", network_state.write_queue.len().to_string()),
+        ("Actions logged", network_state.actions_logged.to_string()),
+        ("Awaiting replay", network_state.pending_replay.len().to_string()),
+        ("Resyncing", network_state.resyncing.to_string()),
+    ];
+
+    egui::Window::new("Network")
+        .collapsible(true)
+        .resizable(false)
+        .default_width(260.0)
+        .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+        .show(ctx, |ui| {
+            egui::Grid::new("network_overlay_grid").num_columns(2).spacing([12.0, 2.0]).show(ui, |ui| {
+                for (label, value) in rows {
+                    ui.label(egui::RichText::new(label).size(11.0).color(LABEL_COLOR));
+                    ui.label(egui::RichText::new(value).size(12.0).color(VALUE_COLOR).monospace());
+                    ui.end_row();
+                }
+            });
+            if let Some(ref failure) = network_state.connection_error {
+                ui.separator();
+                ui.label(
+                    egui::RichText::new(format!("{:?}: {:?}", failure.operation, failure.error))
+                        .size(11.0)
+                        .color(egui::Color32::from_rgb(255, 100, 100)),
+                );
+            }
+        });
+}
@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
+use bevy_egui::{egui, EguiContexts};
+use crate::components::{
+    BoardPosition, Camel, CamelColor, CamelNames, CamelPalette, CrazyCamel, PlacedSpectatorTiles, Pyramid,
+};
+use crate::game::probability::RaceBoard;
+use crate::game::simulation::{simulate, SimulationResult, DEFAULT_SAMPLES};
+use crate::ui::hud::UiState;
+use crate::ui::theme::camel_color_to_egui;
+
+/// Leg and race odds for the current board, shown by the odds panels.
+/// Leg odds are exact, race odds are sampled. Calculated in the background and
+/// redone only when the board changes.
+#[derive(Resource, Default)]
+pub struct RaceOdds {
+    task: Option<Task<SimulationResult>>,
+    odds: Option<SimulationResult>,
+    board: Option<RaceBoard>,
+}
+
+impl RaceOdds {
+    pub fn odds(&self) -> Option<&SimulationResult> {
+        self.odds.as_ref()
+    }
+
+    /// Whether newer odds are still being calculated
+    pub fn is_updating(&self) -> bool {
+        self.task.is_some()
+    }
+}
+
+/// System to recalculate the odds after every change to the board
+pub fn update_race_odds(
+    mut race_odds: ResMut<RaceOdds>,
+    ui_state: Res<UiState>,
+    camels: Query<(&Camel, &BoardPosition)>,
+    crazy_camels: Query<(&CrazyCamel, &BoardPosition)>,
+    pyramid: Res<Pyramid>,
+    placed_tiles: Res<PlacedSpectatorTiles>,
+) {
+    // Camels are still being placed during the setup rolls
+    if !ui_state.initial_rolls_complete {
+        return;
+    }
+
+    let board = RaceBoard::from_world(&camels, &crazy_camels, &pyramid, &placed_tiles);
+    // Only shown, never played on, so the odds needn't come from the game's rng
+    if race_odds.board.as_ref() != Some(&board) {
+        let task_board = board.clone();
+        race_odds.task = Some(
+            AsyncComputeTaskPool::get()
+                .spawn(async move { simulate(&task_board, DEFAULT_SAMPLES, &mut rand::thread_rng()) }),
+        );
+        race_odds.board = Some(board);
+    }
+    if let Some(task) = race_odds.task.as_mut() {
+        if let Some(odds) = block_on(future::poll_once(task)) {
+            race_odds.odds = Some(odds);
+            race_odds.task = None;
+        }
+    }
+}
+
+/// Player-facing odds panel: chance each camel finishes the leg first or second
+pub fn leg_odds_panel_ui(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<UiState>,
+    race_odds: Res<RaceOdds>,
+    camel_names: Res<CamelNames>,
+    camel_palette: Res<CamelPalette>,
+) {
+    if !ui_state.show_odds {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let is_mobile = !ui_state.use_side_panels;
+    let calculating = race_odds.is_updating();
+    let mut open = true;
+
+    egui::Window::new("Leg Odds")
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .default_width(if is_mobile { 260.0 } else { 300.0 })
+        .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-10.0, 60.0))
+        .show(ctx, |ui| {
+            let Some(odds) = race_odds.odds() else {
+                ui.label("Calculating...");
+                return;
+            };
+
+            egui::Grid::new("leg_odds_grid")
+                .num_columns(3)
+                .spacing(egui::vec2(16.0, 4.0))
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new("Camel").strong());
+                    ui.label(egui::RichText::new("1st").strong());
+                    ui.label(egui::RichText::new("2nd").strong());
+                    ui.end_row();
+
+                    let mut colors = CamelColor::all().to_vec();
+                    colors.sort_by(|a, b| odds.leg_first(*b).total_cmp(&odds.leg_first(*a)));
+                    for color in colors {
+                        ui.label(
+                            egui::RichText::new(camel_names.name(color))
+                                .color(camel_color_to_egui(color, *camel_palette))
+                                .strong(),
+                        );
+                        ui.label(format!("{:.1}%", odds.leg_first(color) * 100.0));
+                        ui.label(format!("{:.1}%", odds.leg_second(color) * 100.0));
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(if calculating {
+                    "Updating..."
+                } else {
+                    "Exact odds over every remaining roll"
+                })
+                .size(11.0)
+                .color(egui::Color32::GRAY),
+            );
+        });
+
+    if !open {
+        ui_state.show_odds = false;
+    }
+}
@@ -1,7 +1,13 @@
 use bevy::prelude::*;
 use rand::seq::SliceRandom;
-use std::collections::HashSet;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use crate::components::{CamelColor, CamelNames, SeatConfig};
+use crate::game::ai::AiDifficulty;
+use crate::game::rng::parse_seed;
 use crate::ui::characters::CharacterId;
+use bevy_egui::egui;
+use crate::ui::theme::{player_color_to_egui, player_colors_clash, PLAYER_COLORS};
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
@@ -42,33 +48,45 @@ pub fn is_iphone() -> bool {
 }
 
 /// Configuration for a single player during setup
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct PlayerConfig {
     pub name: String,
     pub is_ai: bool,
     pub character_id: CharacterId,
     pub color_index: usize,
+    /// Color picked with the color picker, used instead of the `color_index` slot
+    pub custom_color: Option<[u8; 3]>,
     /// Tracks if the user manually edited the name (prevents auto-name updates)
     pub name_edited: bool,
+    /// How strongly this player plays when controlled by the AI
+    pub ai_difficulty: AiDifficulty,
 }
 
-impl Default for PlayerConfig {
-    fn default() -> Self {
-        Self {
-            name: String::new(),
-            is_ai: false,
-            character_id: CharacterId::default(),
-            color_index: 0,
-            name_edited: false,
-        }
+impl PlayerConfig {
+    /// The player's color, picked or from the palette
+    pub fn color(&self) -> egui::Color32 {
+        player_color_to_egui(self.color_index, self.custom_color)
     }
 }
 
+
 /// Resource to hold player configuration state during setup
 #[derive(Resource)]
 pub struct PlayerSetupConfig {
     pub players: Vec<PlayerConfig>,
     pub randomize_start_order: bool,
+    /// Add the neutral Sheikh bettor in 2-player games
+    pub sheikh_variant: bool,
+    /// Draw a random rule-changing event at the start of each leg
+    pub desert_events: bool,
+    /// Experimental: race two tracks in a row, track one's finish seeding track two
+    pub relay_race: bool,
+    /// Custom camel names, indexed like `CamelColor::all()` (blank = use the color)
+    pub camel_names: [String; 5],
+    /// Show the expected value of bets when hovering them
+    pub show_bet_values: bool,
+    /// Seed for a local game, to replay one exactly (blank = random)
+    pub seed: String,
 }
 
 impl Default for PlayerSetupConfig {
@@ -86,31 +104,45 @@ impl Default for PlayerSetupConfig {
                     is_ai: false,
                     character_id: CharacterId::from_index(0), // Scholar
                     color_index: 0, // Red
+                    custom_color: None,
                     name_edited: false,
+                    ai_difficulty: AiDifficulty::default(),
                 },
                 PlayerConfig {
                     name: CharacterId::from_index(1).random_name(),
                     is_ai: true,
                     character_id: CharacterId::from_index(1), // Merchant
                     color_index: 1, // Blue
+                    custom_color: None,
                     name_edited: false,
+                    ai_difficulty: AiDifficulty::default(),
                 },
                 PlayerConfig {
                     name: CharacterId::from_index(2).random_name(),
                     is_ai: true,
                     character_id: CharacterId::from_index(2), // Princess
                     color_index: 2, // Green
+                    custom_color: None,
                     name_edited: false,
+                    ai_difficulty: AiDifficulty::default(),
                 },
                 PlayerConfig {
                     name: CharacterId::from_index(3).random_name(),
                     is_ai: true,
                     character_id: CharacterId::from_index(3), // Jockey
                     color_index: 3, // Yellow
+                    custom_color: None,
                     name_edited: false,
+                    ai_difficulty: AiDifficulty::default(),
                 },
             ],
             randomize_start_order: false,
+            sheikh_variant: true,
+            desert_events: false,
+            relay_race: false,
+            show_bet_values: true,
+            camel_names: Default::default(),
+            seed: String::new(),
         }
     }
 }
@@ -123,15 +155,14 @@ impl PlayerSetupConfig {
         if self.players.len() < Self::MAX_PLAYERS {
             // Find an unused character
             let used: HashSet<CharacterId> = self.players.iter().map(|p| p.character_id).collect();
-            let available = (0..16)
+            let available = (0..CharacterId::COUNT)
                 .map(CharacterId::from_index)
                 .find(|c| !used.contains(c))
                 .unwrap_or(CharacterId::default());
 
-            // Find an unused color index
-            let used_colors: HashSet<usize> = self.players.iter().map(|p| p.color_index).collect();
-            let available_color = (0..8)
-                .find(|c| !used_colors.contains(c))
+            // Find a palette color that doesn't clash with anyone's, picked colors included
+            let available_color = (0..PLAYER_COLORS.len())
+                .find(|&c| !self.players.iter().any(|p| player_colors_clash(p.color(), PLAYER_COLORS[c])))
                 .unwrap_or(0);
 
             // New players default to AI with avatar-based name
@@ -140,7 +171,9 @@ impl PlayerSetupConfig {
                 is_ai: true,
                 character_id: available,
                 color_index: available_color,
+                custom_color: None,
                 name_edited: false,
+                ai_difficulty: AiDifficulty::default(),
             });
         }
     }
@@ -156,19 +189,20 @@ impl PlayerSetupConfig {
         let mut rng = rand::thread_rng();
 
         // Create shuffled character IDs
-        let mut character_ids: Vec<CharacterId> = (0..16)
+        let mut character_ids: Vec<CharacterId> = (0..CharacterId::COUNT)
             .map(CharacterId::from_index)
             .collect();
         character_ids.shuffle(&mut rng);
 
         // Create shuffled color indices
-        let mut color_indices: Vec<usize> = (0..8).collect();
+        let mut color_indices: Vec<usize> = (0..PLAYER_COLORS.len()).collect();
         color_indices.shuffle(&mut rng);
 
         // Assign to each player
         for (i, player) in self.players.iter_mut().enumerate() {
             player.character_id = character_ids[i % character_ids.len()];
             player.color_index = color_indices[i % color_indices.len()];
+            player.custom_color = None;
 
             // Update name based on new character (if not manually edited)
             if !player.name_edited {
@@ -201,19 +235,48 @@ impl PlayerSetupConfig {
         }
     }
 
-    /// Convert to the format expected by Players::new()
-    /// If randomize_start_order is true, shuffles the player order
-    pub fn to_player_configs(&self) -> Vec<(String, bool, CharacterId, usize)> {
-        let mut configs: Vec<(String, bool, CharacterId, usize)> = self.players
-            .iter()
-            .map(|p| (p.name.clone(), p.is_ai, p.character_id, p.color_index))
-            .collect();
+    /// Display names for the camels of this game
+    pub fn camel_names(&self) -> CamelNames {
+        CamelNames::new(CamelColor::all().into_iter().zip(self.camel_names.iter().cloned()))
+    }
+
+    /// Seed typed in for the game, if any
+    pub fn chosen_seed(&self) -> Option<u64> {
+        parse_seed(&self.seed)
+    }
+
+    /// Whether the neutral Sheikh joins this game (2-player games only)
+    pub fn uses_sheikh(&self) -> bool {
+        self.sheikh_variant && self.players.len() == 2
+    }
+
+    /// Players in turn order
+    /// If randomize_start_order is true, shuffles the player order with the game's rng
+    pub fn ordered_players(&self, rng: &mut impl Rng) -> Vec<PlayerConfig> {
+        let mut players = self.players.clone();
 
         if self.randomize_start_order {
-            let mut rng = rand::thread_rng();
-            configs.shuffle(&mut rng);
+            players.shuffle(rng);
         }
 
-        configs
+        players
+    }
+
+    /// Convert players in turn order to the format expected by Players::new()
+    pub fn to_player_configs(players: &[PlayerConfig]) -> Vec<SeatConfig> {
+        players
+            .iter()
+            .map(|p| (p.name.clone(), p.is_ai, p.character_id, p.color_index, p.custom_color))
+            .collect()
+    }
+
+    /// AI difficulty of each AI player, keyed by player id (turn order index)
+    pub fn ai_difficulties(players: &[PlayerConfig]) -> HashMap<u8, AiDifficulty> {
+        players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_ai)
+            .map(|(i, p)| (i as u8, p.ai_difficulty))
+            .collect()
     }
 }
@@ -0,0 +1,173 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use crate::components::Players;
+use crate::game::state::{GameState, SuspendedGame};
+use crate::network::state::{NetworkState, OutgoingNetworkActions};
+use crate::game::actions::GameAction;
+use crate::systems::turn::{ConcedeAction, ConvertToAiAction};
+use crate::ui::hud::UiState;
+use crate::ui::theme::{desert_button, desert_button_enabled, DesertButtonStyle};
+
+/// The seat the local player would concede, if any
+/// Online this is our own seat. On a shared device it's the human whose turn it is,
+/// or the only human at the table.
+fn local_concede_seat(players: &Players, network_state: &NetworkState) -> Option<usize> {
+    let seat = if network_state.is_online() {
+        network_state.local_player_index
+    } else if !players.current_player().is_ai {
+        Some(players.current_player_index)
+    } else {
+        let mut humans = players.players.iter().enumerate().filter(|(_, p)| !p.is_ai);
+        match (humans.next(), humans.next()) {
+            (Some((i, _)), None) => Some(i),
+            _ => None,
+        }
+    };
+    seat.filter(|&i| players.players.get(i).is_some_and(|p| !p.conceded))
+}
+
+/// Where a seat given up from the dialog goes: conceded or handed to the AI here, or
+/// sent to the host by an online client
+#[derive(SystemParam)]
+pub struct SeatHandover<'w> {
+    outgoing_actions: ResMut<'w, OutgoingNetworkActions>,
+    concede_actions: MessageWriter<'w, ConcedeAction>,
+    convert_actions: MessageWriter<'w, ConvertToAiAction>,
+}
+
+/// Confirmation shown by "Back to Menu": save & quit, concede, hand a seat to the AI,
+/// or keep playing
+pub fn quit_dialog_ui(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<UiState>,
+    players: Res<Players>,
+    network_state: Res<NetworkState>,
+    handover: SeatHandover,
+    mut suspended: ResMut<SuspendedGame>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let SeatHandover { mut outgoing_actions, mut concede_actions, mut convert_actions } = handover;
+    if !ui_state.show_quit_dialog {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let is_mobile = !ui_state.use_side_panels;
+    let concede_seat = local_concede_seat(&players, &network_state);
+    let style = if is_mobile { DesertButtonStyle::small() } else { DesertButtonStyle::default() };
+    let mut close = false;
+
+    egui::Window::new("Leave Game?")
+        .collapsible(false)
+        .resizable(false)
+        .default_width(if is_mobile { 300.0 } else { 380.0 })
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                if network_state.is_online() {
+                    ui.label("Online games can't be saved. Concede to hand your seat to the AI.");
+                    if !network_state.is_host() {
+                        let warning = "Closing the game without conceding also hands your seat to the AI \
+                                       once you've been gone a while.";
+                        ui.label(
+                            egui::RichText::new(warning)
+                                .size(12.0)
+                                .color(egui::Color32::from_rgb(255, 190, 90)),
+                        );
+                    }
+                    if network_state.is_host() {
+                        ui.label(
+                            egui::RichText::new("You're hosting: the game keeps running here for the others.")
+                                .size(12.0)
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                } else {
+                    ui.label("Save the game to resume it from the main menu, or concede and let the AI finish your race.");
+                }
+                ui.add_space(10.0);
+
+                if desert_button_enabled(ui, "Save & Quit", &style, !network_state.is_online()).clicked() {
+                    info!("Game suspended");
+                    suspended.active = true;
+                    next_state.set(GameState::MainMenu);
+                    close = true;
+                }
+                ui.add_space(6.0);
+
+                let concede_label = if network_state.is_client() { "Concede & Leave" } else { "Concede" };
+                let concede = desert_button_enabled(ui, concede_label, &style, concede_seat.is_some());
+                let concede = if concede_seat.is_none() {
+                    concede.on_disabled_hover_text("Wait for your turn to concede")
+                } else {
+                    concede
+                };
+                if concede.clicked() {
+                    if let Some(player_index) = concede_seat {
+                        if network_state.is_client() {
+                            // The host applies it; we're done with this game
+                            outgoing_actions.actions.push(GameAction::Concede { player_index });
+                            crate::network::session::clear_session();
+                            next_state.set(GameState::MainMenu);
+                        } else {
+                            concede_actions.write(ConcedeAction { player_index });
+                        }
+                    }
+                    close = true;
+                }
+                ui.add_space(6.0);
+
+                // Someone left the table: let the AI play their seat out without conceding it.
+                // Clients can't change seats; the host decides for the room.
+                let vacated: Vec<(usize, String)> = players
+                    .players
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| !p.is_ai)
+                    .map(|(i, p)| (i, p.name.clone()))
+                    .collect();
+                if !network_state.is_client() && !vacated.is_empty() {
+                    ui.collapsing("Hand a seat to the AI", |ui| {
+                        for (player_index, name) in vacated {
+                            ui.horizontal(|ui| {
+                                ui.label(name);
+                                if desert_button(ui, "Convert to AI", &DesertButtonStyle::small()).clicked() {
+                                    convert_actions.write(ConvertToAiAction { player_index });
+                                }
+                            });
+                        }
+                    });
+                    ui.add_space(6.0);
+                }
+
+                if desert_button(ui, "Cancel", &style).clicked() {
+                    close = true;
+                }
+            });
+        });
+
+    if close {
+        ui_state.show_quit_dialog = false;
+    }
+}
+
+/// Ask the browser to confirm before the tab is closed or reloaded
+#[cfg(target_arch = "wasm32")]
+fn set_leave_guard(enabled: bool) {
+    crate::network::js_bindings::set_leave_guard(enabled);
+}
+
+/// Closing a native window goes through the game's own Quit
+#[cfg(not(target_arch = "wasm32"))]
+fn set_leave_guard(_enabled: bool) {}
+
+/// System to guard the browser tab while a game is being played
+pub fn arm_leave_guard() {
+    set_leave_guard(true);
+}
+
+/// System to let the browser tab close freely again once the game is over or left
+pub fn disarm_leave_guard() {
+    set_leave_guard(false);
+}
@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 use bevy_egui::egui;
-use crate::components::CamelColor;
-use crate::ui::hud::{draw_camel_silhouette, draw_mini_leg_bet_card, draw_pyramid_token_icon};
-use crate::ui::theme::{desert_button, gold_tab, DesertButtonStyle, camel_color_to_egui};
+use crate::components::{CamelColor, CamelPalette};
+use crate::ui::draw::{draw_camel_silhouette, draw_mini_leg_bet_card, draw_pyramid_token_icon, Palette};
+use crate::ui::theme::{desert_button, gold_tab, quality_shadow, DesertButtonStyle, camel_color_to_egui};
 
 // Desert theme colors
 const SAND_COLOR: egui::Color32 = egui::Color32::from_rgb(0xED, 0xC9, 0x9A);
@@ -61,6 +61,7 @@ pub fn draw_rules_ui(
     rules_state: &mut RulesState,
     is_mobile: bool,
     time_delta: f32,
+    camel_palette: CamelPalette,
 ) {
     if !rules_state.is_open {
         return;
@@ -97,12 +98,12 @@ pub fn draw_rules_ui(
                 .fill(MODAL_BG)
                 .corner_radius(egui::CornerRadius::same(16))
                 .inner_margin(egui::Margin::same(if is_mobile { 16 } else { 24 }))
-                .shadow(egui::epaint::Shadow {
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                     offset: [0, 8],
                     blur: 24,
                     spread: 4,
                     color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
-                })
+                }))
                 .show(ui, |ui| {
                     ui.set_min_size(panel_size);
                     ui.set_max_size(panel_size);
@@ -113,7 +114,7 @@ pub fn draw_rules_ui(
                     });
                     ui.add_space(12.0);
 
-                    draw_mobile_layout(ui, rules_state);
+                    draw_mobile_layout(ui, rules_state, camel_palette);
 
                     ui.add_space(12.0);
 
@@ -127,7 +128,7 @@ pub fn draw_rules_ui(
         });
 }
 
-fn draw_mobile_layout(ui: &mut egui::Ui, rules_state: &mut RulesState) {
+fn draw_mobile_layout(ui: &mut egui::Ui, rules_state: &mut RulesState, camel_palette: CamelPalette) {
     // Horizontal tab bar at top using gold_tab theme
     ui.horizontal_wrapped(|ui| {
         for section in RulesSection::all() {
@@ -147,15 +148,15 @@ fn draw_mobile_layout(ui: &mut egui::Ui, rules_state: &mut RulesState) {
     egui::ScrollArea::vertical()
         .max_height(350.0)
         .show(ui, |ui| {
-            draw_section_content(ui, rules_state, true);
+            draw_section_content(ui, rules_state, true, camel_palette);
         });
 }
 
-fn draw_section_content(ui: &mut egui::Ui, rules_state: &mut RulesState, is_mobile: bool) {
+fn draw_section_content(ui: &mut egui::Ui, rules_state: &mut RulesState, is_mobile: bool, camel_palette: CamelPalette) {
     match rules_state.current_section {
-        RulesSection::Overview => draw_overview_section(ui, is_mobile),
-        RulesSection::CamelMovement => draw_movement_section(ui, rules_state, is_mobile),
-        RulesSection::Betting => draw_betting_section(ui),
+        RulesSection::Overview => draw_overview_section(ui, is_mobile, camel_palette),
+        RulesSection::CamelMovement => draw_movement_section(ui, rules_state, is_mobile, camel_palette),
+        RulesSection::Betting => draw_betting_section(ui, camel_palette),
         RulesSection::DesertTiles => draw_desert_tiles_section(ui),
         RulesSection::Scoring => draw_scoring_section(ui),
     }
@@ -165,7 +166,7 @@ fn draw_section_content(ui: &mut egui::Ui, rules_state: &mut RulesState, is_mobi
 // Overview Section
 // ============================================================================
 
-fn draw_overview_section(ui: &mut egui::Ui, is_mobile: bool) {
+fn draw_overview_section(ui: &mut egui::Ui, is_mobile: bool, camel_palette: CamelPalette) {
     ui.heading(egui::RichText::new("Welcome to Camel Up!").size(20.0).color(egui::Color32::WHITE));
     ui.add_space(12.0);
 
@@ -201,10 +202,10 @@ fn draw_overview_section(ui: &mut egui::Ui, is_mobile: bool) {
         });
 
         // Leg Bet action
-        draw_action_card(ui, icon_size, "Leg Bet", camel_color_to_egui(CamelColor::Blue), |painter, rect| {
+        draw_action_card(ui, icon_size, "Leg Bet", camel_color_to_egui(CamelColor::Blue, camel_palette), |painter, rect| {
             let card_rect = egui::Rect::from_center_size(rect.center(), egui::vec2(28.0, 36.0));
             painter.rect_filled(card_rect, 3.0, egui::Color32::from_rgb(245, 235, 215));
-            draw_camel_silhouette(painter, card_rect.shrink(4.0), camel_color_to_egui(CamelColor::Blue), egui::Color32::DARK_GRAY);
+            draw_camel_silhouette(painter, card_rect.shrink(4.0), &Palette::outlined(camel_color_to_egui(CamelColor::Blue, camel_palette), egui::Color32::DARK_GRAY));
         });
 
         // Desert Tile action
@@ -247,9 +248,11 @@ fn draw_overview_section(ui: &mut egui::Ui, is_mobile: bool) {
     let highlight_red = egui::Color32::from_rgb(255, 100, 100);
 
     // Use LayoutJob for mixed formatting in a single label
-    let mut job = egui::text::LayoutJob::default();
-    job.wrap = egui::text::TextWrapping {
-        max_width: ui.available_width(),
+    let mut job = egui::text::LayoutJob {
+        wrap: egui::text::TextWrapping {
+            max_width: ui.available_width(),
+            ..Default::default()
+        },
         ..Default::default()
     };
 
@@ -309,7 +312,7 @@ fn draw_action_card(
 // Camel Movement Section
 // ============================================================================
 
-fn draw_movement_section(ui: &mut egui::Ui, rules_state: &mut RulesState, is_mobile: bool) {
+fn draw_movement_section(ui: &mut egui::Ui, rules_state: &mut RulesState, is_mobile: bool, camel_palette: CamelPalette) {
     ui.heading(egui::RichText::new("Camel Movement").size(20.0).color(egui::Color32::WHITE));
     ui.add_space(12.0);
 
@@ -324,7 +327,7 @@ fn draw_movement_section(ui: &mut egui::Ui, rules_state: &mut RulesState, is_mob
         });
         ui.add_space(8.0);
 
-        draw_stacking_demo(ui, rules_state.demo_elapsed, is_mobile);
+        draw_stacking_demo(ui, rules_state.demo_elapsed, is_mobile, camel_palette);
 
         ui.add_space(8.0);
         ui.vertical_centered(|ui| {
@@ -349,7 +352,7 @@ fn draw_movement_section(ui: &mut egui::Ui, rules_state: &mut RulesState, is_mob
     // Draw dice
     ui.horizontal(|ui| {
         for color in CamelColor::all() {
-            let egui_color = camel_color_to_egui(color);
+            let egui_color = camel_color_to_egui(color, camel_palette);
             let (rect, _) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::hover());
             ui.painter().rect_filled(rect, 4.0, egui_color);
             ui.painter().rect_stroke(rect, 4.0, egui::Stroke::new(1.0, egui::Color32::WHITE), egui::epaint::StrokeKind::Outside);
@@ -400,10 +403,10 @@ fn draw_movement_section(ui: &mut egui::Ui, rules_state: &mut RulesState, is_mob
         .size(14.0).color(egui::Color32::LIGHT_GRAY));
 }
 
-fn draw_stacking_demo(ui: &mut egui::Ui, elapsed: f32, is_mobile: bool) {
+fn draw_stacking_demo(ui: &mut egui::Ui, elapsed: f32, is_mobile: bool, camel_palette: CamelPalette) {
     // Make width responsive - use available width clamped to reasonable bounds
     let demo_width = if is_mobile {
-        ui.available_width().min(400.0).max(260.0)
+        ui.available_width().clamp(260.0, 400.0)
     } else {
         400.0
     };
@@ -453,13 +456,7 @@ fn draw_stacking_demo(ui: &mut egui::Ui, elapsed: f32, is_mobile: bool) {
     // Helper to draw a camel at position
     let draw_camel = |painter: &egui::Painter, x: f32, y: f32, color: CamelColor| {
         let camel_rect = egui::Rect::from_center_size(egui::pos2(x, y), camel_size);
-        let egui_color = camel_color_to_egui(color);
-        let border = egui::Color32::from_rgb(
-            (egui_color.r() as f32 * 0.5) as u8,
-            (egui_color.g() as f32 * 0.5) as u8,
-            (egui_color.b() as f32 * 0.5) as u8,
-        );
-        draw_camel_silhouette(painter, camel_rect, egui_color, border);
+        draw_camel_silhouette(painter, camel_rect, &Palette::camel(color, camel_palette));
     };
 
     let base_y = track_y - (14.0 * scale);
@@ -552,7 +549,7 @@ fn ease_out_cubic(t: f32) -> f32 {
 // Betting Section
 // ============================================================================
 
-fn draw_betting_section(ui: &mut egui::Ui) {
+fn draw_betting_section(ui: &mut egui::Ui, camel_palette: CamelPalette) {
     ui.heading(egui::RichText::new("Betting").size(20.0).color(egui::Color32::WHITE));
     ui.add_space(12.0);
 
@@ -570,7 +567,7 @@ fn draw_betting_section(ui: &mut egui::Ui) {
     ui.horizontal(|ui| {
         for (color, value) in [(CamelColor::Blue, 5), (CamelColor::Green, 3), (CamelColor::Red, 2)] {
             let (rect, _) = ui.allocate_exact_size(egui::vec2(40.0, 55.0), egui::Sense::hover());
-            draw_mini_leg_bet_card(ui.painter(), rect, color, value);
+            draw_mini_leg_bet_card(ui.painter(), rect, &Palette::camel(color, camel_palette), value);
             ui.add_space(4.0);
         }
         ui.label(egui::RichText::new("← Tile values: 5, 3, 2").size(12.0).color(egui::Color32::GRAY));
@@ -601,7 +598,7 @@ fn draw_betting_section(ui: &mut egui::Ui) {
 
     ui.horizontal(|ui| {
         let (rect, _) = ui.allocate_exact_size(egui::vec2(24.0, 24.0), egui::Sense::hover());
-        draw_pyramid_token_icon(ui.painter(), rect.center(), 24.0);
+        draw_pyramid_token_icon(ui.painter(), rect.center(), &Palette::GOLD, 24.0);
         ui.add_space(8.0);
         ui.label(egui::RichText::new("Earn $1 every time you roll the pyramid die!")
             .size(14.0).color(egui::Color32::LIGHT_GRAY));
@@ -758,7 +755,7 @@ fn draw_scoring_section(ui: &mut egui::Ui) {
 
     ui.horizontal(|ui| {
         let (rect, _) = ui.allocate_exact_size(egui::vec2(20.0, 20.0), egui::Sense::hover());
-        draw_pyramid_token_icon(ui.painter(), rect.center(), 20.0);
+        draw_pyramid_token_icon(ui.painter(), rect.center(), &Palette::GOLD, 20.0);
         ui.label(egui::RichText::new(" Pyramid token: +$1 each").size(14.0).color(egui::Color32::LIGHT_GRAY));
     });
     ui.horizontal(|ui| {
@@ -1,14 +1,39 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
-use rand::Rng;
-use crate::components::{Players, CamelColor, Camel, BoardPosition, RaceBets};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use crate::components::{
+    Players, CamelColor, CamelNames, CamelPalette, Camel, BoardPosition, RaceBets, PlayerLegBetsStore,
+    PlayerPyramidTokens,
+};
+use crate::game::leaderboard::Leaderboard;
+use crate::game::log::{GameLog, MoneyTimeline};
+use crate::game::probability::{move_camel, stack_rankings, TrackStacks};
+use crate::game::rng::GameRng;
+use crate::game::core::{apply_payout, leg_tile_payout, PYRAMID_TOKEN_PAYOUT};
 use crate::game::state::GameState;
+use crate::game::stats::plays_here;
+use crate::game::storage;
+use crate::network::backend::Backend;
+use crate::network::room;
+use crate::network::state::NetworkState;
+use crate::systems::autoplay::AutoplayConfig;
 use crate::systems::movement::{get_leading_camel, get_second_place_camel, get_last_place_camel};
-use crate::systems::turn::{PlayerLegBetsStore, PlayerPyramidTokens};
 use crate::systems::animation::{spawn_firework, random_firework_color};
-use crate::ui::characters::{draw_avatar, draw_avatar_with_expression, draw_avatar_crown};
-use crate::ui::hud::{draw_camel_silhouette, draw_crown_overlay, draw_dunce_cap_overlay, draw_mini_leg_bet_card};
-use crate::ui::theme::{camel_color_to_egui, desert_button, DesertButtonStyle, PLAYER_COLORS};
+use crate::ui::draw::{
+    draw_avatar, draw_avatar_crown, draw_avatar_with_expression, draw_camel_silhouette, draw_crown_overlay,
+    draw_dunce_cap_overlay, draw_mini_leg_bet_card, Palette,
+};
+use crate::ui::hud::LegScoreChange;
+use crate::ui::theme::{
+    camel_color_to_egui, desert_button, panel_fill, panel_stroke, quality_shadow, text_color, DesertButtonStyle,
+    player_color_to_egui,
+};
+
+/// File the Export Log button hands the player
+const LOG_FILE_NAME: &str = "camel-up-game-log.json";
 
 /// Easing function for smooth panel animations
 fn ease_out_cubic(t: f32) -> f32 {
@@ -23,9 +48,19 @@ pub enum GameEndPhase {
     StandingsPreBets,     // Show standings before long-term bets
     RevealingWinnerBets,  // Animate winner bets one by one
     RevealingLoserBets,   // Animate loser bets one by one
+    Tiebreaker,           // Resolve tied final scores
     FinalResults,         // Show final winner
 }
 
+/// Who can start a rematch in the same room from the final results
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RematchRole {
+    #[default]
+    None,   // Local game: Play Again sets up a new one
+    Host,   // Online host: can take everyone back to the waiting room
+    Guest,  // Online guest: waits for the host
+}
+
 /// Data for a pending bet reveal
 #[derive(Clone, Debug)]
 pub struct PendingBetReveal {
@@ -37,6 +72,18 @@ pub struct PendingBetReveal {
     pub payout: i32,          // Positive for correct, -1 for wrong
 }
 
+/// How a group of players tied on money was separated
+#[derive(Clone, Debug)]
+pub struct TiebreakGroup {
+    pub money: i32,
+    /// (player_id, correct race bet cards) for each tied player
+    pub correct_cards: Vec<(u8, usize)>,
+    /// Sudden-death leg: (player_id, camel) in finishing order, empty if cards decided it
+    pub sudden_death: Vec<(u8, CamelColor)>,
+    /// Player ids of the group in their final order
+    pub order: Vec<u8>,
+}
+
 /// State for the game end sequence
 #[derive(Resource, Default)]
 pub struct GameEndState {
@@ -58,6 +105,20 @@ pub struct GameEndState {
     pub panel_animation_progress: f32,
     // Animated money values for smooth progress bar updates (indexed by player_id)
     pub animated_player_money: Vec<f32>,
+    // Tied groups and how they were broken
+    pub tiebreaks: Vec<TiebreakGroup>,
+    // Drawn from the game's rng as the race ends, for any sudden-death legs
+    pub sudden_death_seed: u64,
+    // Set by the Quit button; game_end_ui passes it on as a QuitRequested message
+    pub quit_requested: bool,
+    // Whether this player can start a rematch, or waits for one
+    pub rematch: RematchRole,
+    // Set by the Rematch button; game_end_ui takes the room back to its waiting room
+    pub rematch_requested: bool,
+    // Set by the Export Log button; game_end_ui hands the player the game log as JSON
+    pub export_requested: bool,
+    // Whether the final scores have been put on the leaderboard
+    pub leaderboard_recorded: bool,
 }
 
 impl GameEndState {
@@ -78,8 +139,38 @@ impl GameEndState {
             scores_before_long_term: Vec::new(),
             panel_animation_progress: 0.0,
             animated_player_money: Vec::new(),
+            tiebreaks: Vec::new(),
+            sudden_death_seed: 0,
+            quit_requested: false,
+            rematch: RematchRole::None,
+            rematch_requested: false,
+            export_requested: false,
+            leaderboard_recorded: false,
         }
     }
+
+    /// Id of the player the final results put first: most money, ties broken as the
+    /// tiebreaker decided, and anyone who conceded last
+    pub fn winner(&self, players: &Players) -> Option<u8> {
+        players
+            .players
+            .iter()
+            .min_by(|a, b| {
+                a.conceded
+                    .cmp(&b.conceded)
+                    .then(b.money.cmp(&a.money))
+                    .then(self.tiebreak_rank(a.id).cmp(&self.tiebreak_rank(b.id)))
+            })
+            .map(|p| p.id)
+    }
+
+    /// Position of a player within their tiebreak group (0 if not tied)
+    pub fn tiebreak_rank(&self, player_id: u8) -> usize {
+        self.tiebreaks
+            .iter()
+            .find_map(|group| group.order.iter().position(|&id| id == player_id))
+            .unwrap_or(0)
+    }
 }
 
 /// State for firework celebration on game end
@@ -97,6 +188,7 @@ pub fn setup_game_end_state(
     players: Res<Players>,
     race_bets: Res<RaceBets>,
     camels: Query<(&Camel, &BoardPosition)>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let winner = get_leading_camel(&camels);
     let loser = get_last_place_camel(&camels);
@@ -106,6 +198,7 @@ pub fn setup_game_end_state(
     let mut state = GameEndState::new();
     state.winning_camel = winner;
     state.losing_camel = loser;
+    state.sudden_death_seed = game_rng.gen();
 
     // Store player scores before long-term bets
     state.scores_before_long_term = players.players.iter()
@@ -124,13 +217,13 @@ pub fn setup_game_end_state(
     let winner_payouts = [8, 5, 3, 2, 1];
     let mut correct_winner_count = 0;
 
-    for (_idx, bet) in race_bets.winner_bets.iter().enumerate() {
+    for bet in race_bets.winner_bets.iter() {
         let player = players.players.iter().find(|p| p.id == bet.player_id);
         if let Some(player) = player {
             let is_correct = Some(bet.camel) == winner;
             let payout = if is_correct {
                 let p = if correct_winner_count < winner_payouts.len() {
-                    winner_payouts[correct_winner_count] as i32
+                    winner_payouts[correct_winner_count]
                 } else {
                     1
                 };
@@ -155,13 +248,13 @@ pub fn setup_game_end_state(
     let loser_payouts = [8, 5, 3, 2, 1];
     let mut correct_loser_count = 0;
 
-    for (_idx, bet) in race_bets.loser_bets.iter().enumerate() {
+    for bet in race_bets.loser_bets.iter() {
         let player = players.players.iter().find(|p| p.id == bet.player_id);
         if let Some(player) = player {
             let is_correct = Some(bet.camel) == loser;
             let payout = if is_correct {
                 let p = if correct_loser_count < loser_payouts.len() {
-                    loser_payouts[correct_loser_count] as i32
+                    loser_payouts[correct_loser_count]
                 } else {
                     1
                 };
@@ -185,26 +278,176 @@ pub fn setup_game_end_state(
     commands.insert_resource(state);
 }
 
+/// Move on from bet reveals: go through the tiebreaker if any players are tied on money
+fn enter_final_results(state: &mut GameEndState, players: &Players) {
+    state.tiebreaks = resolve_ties(state, players);
+    state.phase = if state.tiebreaks.is_empty() {
+        GameEndPhase::FinalResults
+    } else {
+        GameEndPhase::Tiebreaker
+    };
+    state.reveal_timer = 0.0;
+    state.current_payout_applied = false;
+}
+
+/// Break ties on final money: most correct race bet cards first, then a
+/// one-leg sudden-death race where each tied player rides one camel. With more tied
+/// players than camels, some share a camel, and its riders draw lots for their order.
+fn resolve_ties(state: &GameEndState, players: &Players) -> Vec<TiebreakGroup> {
+    let correct_cards = |player_id: u8| {
+        state.winner_bets_to_reveal.iter()
+            .chain(state.loser_bets_to_reveal.iter())
+            .filter(|bet| bet.player_id == player_id && bet.is_correct)
+            .count()
+    };
+
+    // Conceded players rank last regardless, so they never need a tiebreak
+    let mut by_money: Vec<(i32, Vec<u8>)> = Vec::new();
+    for player in players.players.iter().filter(|p| !p.conceded) {
+        match by_money.iter_mut().find(|(money, _)| *money == player.money) {
+            Some((_, ids)) => ids.push(player.id),
+            None => by_money.push((player.money, vec![player.id])),
+        }
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(state.sudden_death_seed);
+    let mut groups = Vec::new();
+    for (money, ids) in by_money.into_iter().filter(|(_, ids)| ids.len() > 1) {
+        let cards: Vec<(u8, usize)> = ids.iter().map(|&id| (id, correct_cards(id))).collect();
+
+        let mut order: Vec<u8> = ids.clone();
+        order.sort_by(|a, b| {
+            let cards_a = cards.iter().find(|(id, _)| id == a).map_or(0, |(_, c)| *c);
+            let cards_b = cards.iter().find(|(id, _)| id == b).map_or(0, |(_, c)| *c);
+            cards_b.cmp(&cards_a)
+        });
+
+        // Players still level on cards race each other for one leg
+        let mut sudden_death = Vec::new();
+        let has_card_tie = cards.iter().any(|(id, count)| {
+            cards.iter().any(|(other, other_count)| other != id && other_count == count)
+        });
+        if has_card_tie {
+            let camel_order = run_sudden_death_leg(&mut rng);
+            let camels = CamelColor::all();
+            let mut seating = ids.clone();
+            if seating.len() > camels.len() {
+                seating.shuffle(&mut rng);
+            }
+            // The first rider of each camel drew the first lot, and so on
+            let riders: Vec<(u8, CamelColor)> = seating.iter().enumerate()
+                .map(|(seat, &id)| (id, camels[seat % camels.len()]))
+                .collect();
+            let camel_rank = |id: u8| {
+                riders.iter().enumerate().find(|(_, (rider, _))| *rider == id)
+                    .and_then(|(seat, (_, camel))| {
+                        let place = camel_order.iter().position(|c| c == camel)?;
+                        Some((place, seat / camels.len()))
+                    })
+                    .unwrap_or((usize::MAX, usize::MAX))
+            };
+            order.sort_by(|a, b| {
+                let cards_a = cards.iter().find(|(id, _)| id == a).map_or(0, |(_, c)| *c);
+                let cards_b = cards.iter().find(|(id, _)| id == b).map_or(0, |(_, c)| *c);
+                cards_b.cmp(&cards_a).then(camel_rank(*a).cmp(&camel_rank(*b)))
+            });
+            sudden_death = riders.clone();
+            sudden_death.sort_by_key(|(id, _)| camel_rank(*id));
+        }
+
+        info!("Tiebreak at ${}: final order {:?}", money, order);
+        groups.push(TiebreakGroup { money, correct_cards: cards, sudden_death, order });
+    }
+
+    // Highest money first so the winner's tiebreak is shown at the top
+    groups.sort_by_key(|group| std::cmp::Reverse(group.money));
+    groups
+}
+
+/// Race all five camels from the start line for one leg and return them in finishing order
+fn run_sudden_death_leg(rng: &mut impl Rng) -> Vec<CamelColor> {
+    let mut colors: Vec<CamelColor> = CamelColor::all().into();
+    colors.shuffle(rng);
+
+    // Everyone starts stacked on the first space in a random order
+    let mut stacks: TrackStacks = vec![colors.clone()];
+    colors.shuffle(rng);
+    for color in colors {
+        move_camel(&mut stacks, color, rng.gen_range(1..=3));
+    }
+    stack_rankings(&stacks)
+}
+
+/// The game as it finished, for the results screens to show: the camels where they
+/// ended, the last leg's bets and tokens, and the game's log
+#[derive(SystemParam)]
+pub struct FinishedGame<'w, 's> {
+    player_leg_bets: Option<Res<'w, PlayerLegBetsStore>>,
+    player_pyramid_tokens: Option<Res<'w, PlayerPyramidTokens>>,
+    camels: Query<'w, 's, (&'static Camel, &'static BoardPosition)>,
+    camel_names: Res<'w, CamelNames>,
+    camel_palette: Res<'w, CamelPalette>,
+    game_log: Res<'w, GameLog>,
+    money_timeline: Res<'w, MoneyTimeline>,
+}
+
+/// The results screens' own state, and the fireworks they set off
+#[derive(SystemParam)]
+pub struct GameEndScreen<'w, 's> {
+    commands: Commands<'w, 's>,
+    game_end_state: Option<ResMut<'w, GameEndState>>,
+    celebration_state: Option<ResMut<'w, CelebrationState>>,
+    ui_state: Res<'w, crate::ui::hud::UiState>,
+    time: Res<'w, Time>,
+}
+
+/// Where the results screens lead: back to the menu or out of the game, the next
+/// autoplay game or an online rematch, and the leaderboard the game is recorded on
+#[derive(SystemParam)]
+pub struct GameEndExits<'w> {
+    next_state: ResMut<'w, NextState<GameState>>,
+    autoplay: ResMut<'w, AutoplayConfig>,
+    quit_requested: MessageWriter<'w, crate::QuitRequested>,
+    network_state: ResMut<'w, NetworkState>,
+    backend: Res<'w, Backend>,
+    leaderboard: ResMut<'w, Leaderboard>,
+}
+
 pub fn game_end_ui(
-    mut commands: Commands,
     mut contexts: EguiContexts,
     mut players: Option<ResMut<Players>>,
-    mut game_end_state: Option<ResMut<GameEndState>>,
-    mut celebration_state: Option<ResMut<CelebrationState>>,
-    player_leg_bets: Option<Res<PlayerLegBetsStore>>,
-    player_pyramid_tokens: Option<Res<PlayerPyramidTokens>>,
-    camels: Query<(&Camel, &BoardPosition)>,
-    time: Res<Time>,
-    mut next_state: ResMut<NextState<GameState>>,
-    ui_state: Res<crate::ui::hud::UiState>,
+    screen: GameEndScreen,
+    finished: FinishedGame,
+    exits: GameEndExits,
 ) {
+    let GameEndScreen { mut commands, mut game_end_state, mut celebration_state, ui_state, time } = screen;
+    let GameEndExits { mut next_state, mut autoplay, mut quit_requested, mut network_state, backend, mut leaderboard } =
+        exits;
     let Some(ref mut players) = players else { return };
     let Some(ref mut state) = game_end_state else { return };
     let Ok(ctx) = contexts.ctx_mut() else { return };
 
+    state.rematch = if network_state.is_host() {
+        RematchRole::Host
+    } else if network_state.is_client() {
+        RematchRole::Guest
+    } else {
+        RematchRole::None
+    };
+
+    // The host started a rematch: follow them back to the waiting room
+    if network_state.is_client() && !backend.has_game_started() {
+        room::return_to_room(&**backend, &mut network_state);
+        next_state.set(GameState::WaitingRoom);
+        return;
+    }
+
     // Update reveal timer
     state.reveal_timer += time.delta_secs();
 
+    // Autoplay clicks through each screen once it has been up for a moment
+    let auto_click = autoplay.should_click(state.reveal_timer);
+
     // Handle firework celebration during FinalResults phase
     if state.phase == GameEndPhase::FinalResults {
         if let Some(ref mut celebration) = celebration_state {
@@ -242,22 +485,67 @@ pub fn game_end_ui(
     }
 
     let is_mobile = !ui_state.use_side_panels;
+    let camel_palette = *finished.camel_palette;
 
     match state.phase {
         GameEndPhase::LegComplete => {
-            draw_final_leg_complete_phase(ctx, players, &player_leg_bets, &player_pyramid_tokens, &camels, state, is_mobile);
+            draw_final_leg_complete_phase(ctx, players, &finished, state, is_mobile, auto_click);
         }
         GameEndPhase::StandingsPreBets => {
-            draw_standings_pre_bets_phase(ctx, players, state);
+            draw_standings_pre_bets_phase(ctx, players, state, auto_click);
         }
         GameEndPhase::RevealingWinnerBets => {
-            draw_winner_bets_reveal_phase(ctx, players, state, is_mobile, time.delta_secs());
+            draw_winner_bets_reveal_phase(ctx, players, &finished, state, is_mobile, time.delta_secs(), auto_click);
         }
         GameEndPhase::RevealingLoserBets => {
-            draw_loser_bets_reveal_phase(ctx, players, state, is_mobile, time.delta_secs());
+            draw_loser_bets_reveal_phase(ctx, players, &finished, state, is_mobile, time.delta_secs(), auto_click);
+        }
+        GameEndPhase::Tiebreaker => {
+            draw_tiebreaker_phase(ctx, players, state, camel_palette, auto_click);
         }
         GameEndPhase::FinalResults => {
-            draw_final_results_phase(ctx, players, state, &mut next_state, is_mobile, time.delta_secs());
+            // Put the final scores of the people playing here on the leaderboard, once.
+            // Games nobody here played (the AI by itself, or a replay) stay off it.
+            if !std::mem::replace(&mut state.leaderboard_recorded, true)
+                && !autoplay.active
+                && !network_state.is_replay()
+            {
+                let player_count = players.players.len();
+                for (seat, player) in players.players.iter().enumerate() {
+                    if !player.conceded && plays_here(&network_state, seat, player) {
+                        leaderboard.record(&player.name, player.money, player_count);
+                    }
+                }
+                leaderboard.save();
+            }
+            draw_final_results_phase(
+                ctx,
+                players,
+                state,
+                &mut next_state,
+                is_mobile,
+                time.delta_secs(),
+                &finished.money_timeline,
+            );
+            if std::mem::take(&mut state.quit_requested) {
+                quit_requested.write(crate::QuitRequested);
+            }
+            if std::mem::take(&mut state.export_requested) {
+                storage::export(LOG_FILE_NAME, &finished.game_log.to_json(players));
+            }
+            if std::mem::take(&mut state.rematch_requested) {
+                if let Some(ref room_code) = network_state.room_code {
+                    backend.reset_room(room_code);
+                }
+                room::return_to_room(&**backend, &mut network_state);
+                next_state.set(GameState::WaitingRoom);
+            }
+
+            // Autoplay: on to the next game
+            if autoplay.final_results_ready(state.reveal_timer) {
+                autoplay.start_next_game();
+                next_state.set(GameState::MainMenu);
+            }
         }
     }
 }
@@ -266,17 +554,18 @@ pub fn game_end_ui(
 fn draw_final_leg_complete_phase(
     ctx: &egui::Context,
     players: &mut ResMut<Players>,
-    player_leg_bets: &Option<Res<PlayerLegBetsStore>>,
-    player_pyramid_tokens: &Option<Res<PlayerPyramidTokens>>,
-    camels: &Query<(&Camel, &BoardPosition)>,
+    finished: &FinishedGame,
     state: &mut GameEndState,
     is_mobile: bool,
+    auto_click: bool,
 ) {
+    let FinishedGame { player_leg_bets, player_pyramid_tokens, camels, camel_names, .. } = finished;
+    let camel_palette = *finished.camel_palette;
     let first_place = get_leading_camel(camels);
     let second_place = get_second_place_camel(camels);
 
     // Calculate score changes (without applying yet if not done)
-    let mut score_changes: Vec<(String, i32, Vec<(CamelColor, u8, i32)>, u8)> = Vec::new();
+    let mut score_changes: Vec<LegScoreChange> = Vec::new();
 
     if let Some(ref player_leg_bets) = player_leg_bets {
         for (player_idx, player) in players.players.iter().enumerate() {
@@ -285,13 +574,7 @@ fn draw_final_leg_complete_phase(
 
             if player_idx < player_leg_bets.bets.len() {
                 for tile in &player_leg_bets.bets[player_idx] {
-                    let change = if Some(tile.camel) == first_place {
-                        tile.value as i32
-                    } else if Some(tile.camel) == second_place {
-                        1
-                    } else {
-                        -1
-                    };
+                    let change = leg_tile_payout(tile, first_place, second_place);
                     leg_bet_total += change;
                     bet_details.push((tile.camel, tile.value, change));
                 }
@@ -317,13 +600,7 @@ fn draw_final_leg_complete_phase(
             for (player_idx, player) in players.players.iter_mut().enumerate() {
                 if player_idx < player_leg_bets.bets.len() {
                     for tile in &player_leg_bets.bets[player_idx] {
-                        if Some(tile.camel) == first_place {
-                            player.money += tile.value as i32;
-                        } else if Some(tile.camel) == second_place {
-                            player.money += 1;
-                        } else {
-                            player.money = (player.money - 1).max(0);
-                        }
+                        apply_payout(&mut player.money, leg_tile_payout(tile, first_place, second_place));
                     }
                 }
             }
@@ -339,7 +616,7 @@ fn draw_final_leg_complete_phase(
     let mut sorted_players: Vec<_> = players.players.iter()
         .map(|p| (p.name.clone(), p.money))
         .collect();
-    sorted_players.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted_players.sort_by_key(|p| std::cmp::Reverse(p.1));
 
     let mut should_continue = false;
 
@@ -347,15 +624,16 @@ fn draw_final_leg_complete_phase(
         .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
         .show(ctx, |ui| {
             egui::Frame::new()
-                .fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 220))
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 220)))
+                .stroke(panel_stroke(egui::Stroke::NONE))
                 .corner_radius(egui::CornerRadius::same(16))
                 .inner_margin(egui::Margin::same(32))
-                .shadow(egui::epaint::Shadow {
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                     offset: [0, 8],
                     blur: 24,
                     spread: 4,
                     color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
-                })
+                }))
                 .show(ui, |ui| {
                     ui.vertical_centered(|ui| {
                         ui.heading(egui::RichText::new("Final Leg Complete!").size(32.0).strong());
@@ -365,29 +643,17 @@ fn draw_final_leg_complete_phase(
                         if let Some(first) = first_place {
                             ui.horizontal(|ui| {
                                 ui.label(egui::RichText::new("1st Place:").size(16.0));
-                                let color = camel_color_to_egui(first);
-                                let border_color = egui::Color32::from_rgb(
-                                    (color.r() as f32 * 0.5) as u8,
-                                    (color.g() as f32 * 0.5) as u8,
-                                    (color.b() as f32 * 0.5) as u8,
-                                );
                                 let (rect, _) = ui.allocate_exact_size(egui::vec2(40.0, 30.0), egui::Sense::hover());
-                                draw_camel_silhouette(ui.painter(), rect, color, border_color);
-                                ui.label(egui::RichText::new(format!("{:?}", first)).size(16.0).strong());
+                                draw_camel_silhouette(ui.painter(), rect, &Palette::camel(first, camel_palette));
+                                ui.label(egui::RichText::new(camel_names.name(first)).size(16.0).strong());
                             });
                         }
                         if let Some(second) = second_place {
                             ui.horizontal(|ui| {
                                 ui.label(egui::RichText::new("2nd Place:").size(16.0));
-                                let color = camel_color_to_egui(second);
-                                let border_color = egui::Color32::from_rgb(
-                                    (color.r() as f32 * 0.5) as u8,
-                                    (color.g() as f32 * 0.5) as u8,
-                                    (color.b() as f32 * 0.5) as u8,
-                                );
                                 let (rect, _) = ui.allocate_exact_size(egui::vec2(40.0, 30.0), egui::Sense::hover());
-                                draw_camel_silhouette(ui.painter(), rect, color, border_color);
-                                ui.label(egui::RichText::new(format!("{:?}", second)).size(16.0).strong());
+                                draw_camel_silhouette(ui.painter(), rect, &Palette::camel(second, camel_palette));
+                                ui.label(egui::RichText::new(camel_names.name(second)).size(16.0).strong());
                             });
                         }
 
@@ -415,12 +681,12 @@ fn draw_final_leg_complete_phase(
                                     for (camel, value, change) in details {
                                         let card_size = egui::vec2(28.0, 38.0);
                                         let (rect, _) = ui.allocate_exact_size(card_size, egui::Sense::hover());
-                                        draw_mini_leg_bet_card(ui.painter(), rect, *camel, *value);
+                                        draw_mini_leg_bet_card(ui.painter(), rect, &Palette::camel(*camel, camel_palette), *value);
 
                                         let change_text = if *change > 0 {
                                             format!("+${}", change)
                                         } else {
-                                            format!("-$1")
+                                            "-$1".to_string()
                                         };
                                         let change_color = if *change > 0 {
                                             egui::Color32::LIGHT_GREEN
@@ -438,7 +704,7 @@ fn draw_final_leg_complete_phase(
                                         draw_pyramid_token_icon(ui, *pyramid_tokens);
                                     }
 
-                                    let total = *leg_bet_total + (*pyramid_tokens as i32);
+                                    let total = *leg_bet_total + *pyramid_tokens as i32 * PYRAMID_TOKEN_PAYOUT;
                                     if total != 0 {
                                         ui.add_space(12.0);
                                         let total_text = if total > 0 {
@@ -467,7 +733,7 @@ fn draw_final_leg_complete_phase(
                 });
         });
 
-    if should_continue {
+    if should_continue || auto_click {
         state.phase = GameEndPhase::StandingsPreBets;
         state.reveal_timer = 0.0;
     }
@@ -478,29 +744,32 @@ fn draw_standings_pre_bets_phase(
     ctx: &egui::Context,
     players: &ResMut<Players>,
     state: &mut GameEndState,
+    auto_click: bool,
 ) {
     let mut sorted_players: Vec<_> = players.players.iter().enumerate().collect();
-    sorted_players.sort_by(|a, b| b.1.money.cmp(&a.1.money));
+    sorted_players.sort_by_key(|p| std::cmp::Reverse(p.1.money));
 
     let has_winner_bets = !state.winner_bets_to_reveal.is_empty();
     let has_loser_bets = !state.loser_bets_to_reveal.is_empty();
     let has_any_bets = has_winner_bets || has_loser_bets;
 
     let mut should_continue = false;
+    let mut skip_to_results = false;
 
     egui::Area::new(egui::Id::new("game_end_standings_pre"))
         .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
         .show(ctx, |ui| {
             egui::Frame::new()
-                .fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230))
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230)))
+                .stroke(panel_stroke(egui::Stroke::NONE))
                 .corner_radius(egui::CornerRadius::same(16))
                 .inner_margin(egui::Margin::same(40))
-                .shadow(egui::epaint::Shadow {
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                     offset: [0, 8],
                     blur: 24,
                     spread: 4,
                     color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
-                })
+                }))
                 .show(ui, |ui| {
                     ui.vertical_centered(|ui| {
                         ui.horizontal(|ui| {
@@ -508,12 +777,12 @@ fn draw_standings_pre_bets_phase(
                             ui.heading(egui::RichText::new("Current Standings").size(36.0).strong());
                         });
                         ui.add_space(10.0);
-                        ui.label(egui::RichText::new("Before Long-Term Bet Results").size(16.0).color(egui::Color32::GRAY));
+                        ui.label(egui::RichText::new("Before Long-Term Bet Results").size(16.0).color(text_color(egui::Color32::GRAY)));
                         ui.add_space(30.0);
 
                         // Draw standings with avatars
                         for (rank, (_player_idx, player)) in sorted_players.iter().enumerate() {
-                            let player_color = PLAYER_COLORS[player.color_index % PLAYER_COLORS.len()];
+                            let player_color = player_color_to_egui(player.color_index, player.custom_color);
                             let rank_text = match rank {
                                 0 => "1st",
                                 1 => "2nd",
@@ -558,34 +827,42 @@ fn draw_standings_pre_bets_phase(
                             }
                         } else {
                             if desert_button(ui, "Final Results", &DesertButtonStyle::medium()).clicked() {
-                                state.phase = GameEndPhase::FinalResults;
+                                skip_to_results = true;
                             }
                         }
                     });
                 });
         });
 
-    if should_continue {
+    if should_continue || (auto_click && has_any_bets) {
         if has_winner_bets {
             state.phase = GameEndPhase::RevealingWinnerBets;
         } else if has_loser_bets {
             state.phase = GameEndPhase::RevealingLoserBets;
         } else {
-            state.phase = GameEndPhase::FinalResults;
+            enter_final_results(state, players);
         }
         state.current_reveal_index = 0;
         state.reveal_timer = 0.0;
     }
+
+    if skip_to_results || (auto_click && !has_any_bets) {
+        enter_final_results(state, players);
+    }
 }
 
 /// Draw the winner bets reveal phase with animation
 fn draw_winner_bets_reveal_phase(
     ctx: &egui::Context,
     players: &mut ResMut<Players>,
+    finished: &FinishedGame,
     state: &mut GameEndState,
     is_mobile: bool,
     delta: f32,
+    auto_click: bool,
 ) {
+    let camel_names = &finished.camel_names;
+    let camel_palette = *finished.camel_palette;
     let mut should_advance = false;
     let mut should_next_card = false;
     let current_idx = state.current_reveal_index;
@@ -624,21 +901,22 @@ fn draw_winner_bets_reveal_phase(
 
     // Sort players by current money
     let mut sorted_players: Vec<_> = players.players.iter().enumerate().collect();
-    sorted_players.sort_by(|a, b| b.1.money.cmp(&a.1.money));
+    sorted_players.sort_by_key(|p| std::cmp::Reverse(p.1.money));
 
     egui::Area::new(egui::Id::new("game_end_winner_reveal"))
         .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
         .show(ctx, |ui| {
             egui::Frame::new()
-                .fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230))
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230)))
+                .stroke(panel_stroke(egui::Stroke::NONE))
                 .corner_radius(egui::CornerRadius::same(16))
                 .inner_margin(egui::Margin::same(40))
-                .shadow(egui::epaint::Shadow {
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                     offset: [0, 8],
                     blur: 24,
                     spread: 4,
                     color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
-                })
+                }))
                 .show(ui, |ui| {
                     ui.vertical_centered(|ui| {
                         ui.heading(egui::RichText::new("Winner Bets").size(32.0).strong().color(egui::Color32::GOLD));
@@ -648,16 +926,10 @@ fn draw_winner_bets_reveal_phase(
                             ui.add_space(10.0);
                             ui.horizontal(|ui| {
                                 ui.label(egui::RichText::new("Race Winner:").size(14.0));
-                                let color = camel_color_to_egui(winner);
-                                let border_color = egui::Color32::from_rgb(
-                                    (color.r() as f32 * 0.5) as u8,
-                                    (color.g() as f32 * 0.5) as u8,
-                                    (color.b() as f32 * 0.5) as u8,
-                                );
                                 let (rect, _) = ui.allocate_exact_size(egui::vec2(30.0, 22.0), egui::Sense::hover());
-                                draw_camel_silhouette(ui.painter(), rect, color, border_color);
-                                draw_crown_overlay(ui.painter(), rect);  // Winner wears a crown
-                                ui.label(egui::RichText::new(format!("{:?}", winner)).size(14.0).strong());
+                                draw_camel_silhouette(ui.painter(), rect, &Palette::camel(winner, camel_palette));
+                                draw_crown_overlay(ui.painter(), rect, &Palette::GOLD_CROWN);  // Winner wears a crown
+                                ui.label(egui::RichText::new(camel_names.name(winner)).size(14.0).strong());
                             });
                         }
 
@@ -667,7 +939,13 @@ fn draw_winner_bets_reveal_phase(
                         if current_idx < total_bets {
                             let bet = &state.winner_bets_to_reveal[current_idx];
                             let grey_hold_ratio = state.grey_hold_duration / state.reveal_animation_duration;
-                            draw_bet_reveal_card(ui, bet, state.reveal_timer / state.reveal_animation_duration, grey_hold_ratio);
+                            draw_bet_reveal_card(
+                                ui,
+                                bet,
+                                state.reveal_timer / state.reveal_animation_duration,
+                                grey_hold_ratio,
+                                camel_palette,
+                            );
                         }
 
                         ui.add_space(20.0);
@@ -690,7 +968,7 @@ fn draw_winner_bets_reveal_phase(
                         let bar_max_width = 150.0;
 
                         for (rank, (_player_idx, player)) in sorted_players.iter().enumerate() {
-                            let player_color = PLAYER_COLORS[player.color_index % PLAYER_COLORS.len()];
+                            let player_color = player_color_to_egui(player.color_index, player.custom_color);
                             ui.horizontal(|ui| {
                                 let rank_text = format!("{}.", rank + 1);
                                 ui.label(egui::RichText::new(&rank_text).size(14.0).monospace());
@@ -735,7 +1013,7 @@ fn draw_winner_bets_reveal_phase(
                         ui.add_space(16.0);
 
                         // Progress indicator
-                        ui.label(egui::RichText::new(format!("Bet {}/{}", (current_idx + 1).min(total_bets), total_bets)).size(12.0).color(egui::Color32::GRAY));
+                        ui.label(egui::RichText::new(format!("Bet {}/{}", (current_idx + 1).min(total_bets), total_bets)).size(12.0).color(text_color(egui::Color32::GRAY)));
 
                         // Next button - always rendered to prevent layout shift, but invisible when not ready
                         ui.add_space(10.0);
@@ -754,7 +1032,8 @@ fn draw_winner_bets_reveal_phase(
                                 // Make button invisible but still occupy space
                                 ui.set_invisible();
                             }
-                            if desert_button(ui, btn_text, &DesertButtonStyle::default()).clicked() && btn_visible {
+                            let clicked = desert_button(ui, btn_text, &DesertButtonStyle::default()).clicked();
+                            if (clicked || auto_click) && btn_visible {
                                 if current_idx + 1 < total_bets {
                                     should_next_card = true;
                                 } else {
@@ -777,7 +1056,7 @@ fn draw_winner_bets_reveal_phase(
             state.phase = GameEndPhase::RevealingLoserBets;
             state.current_reveal_index = 0;
         } else {
-            state.phase = GameEndPhase::FinalResults;
+            enter_final_results(state, players);
         }
         state.reveal_timer = 0.0;
         state.current_payout_applied = false;
@@ -788,10 +1067,14 @@ fn draw_winner_bets_reveal_phase(
 fn draw_loser_bets_reveal_phase(
     ctx: &egui::Context,
     players: &mut ResMut<Players>,
+    finished: &FinishedGame,
     state: &mut GameEndState,
     is_mobile: bool,
     delta: f32,
+    auto_click: bool,
 ) {
+    let camel_names = &finished.camel_names;
+    let camel_palette = *finished.camel_palette;
     let mut should_advance = false;
     let mut should_next_card = false;
     let current_idx = state.current_reveal_index;
@@ -830,21 +1113,22 @@ fn draw_loser_bets_reveal_phase(
 
     // Sort players by current money
     let mut sorted_players: Vec<_> = players.players.iter().enumerate().collect();
-    sorted_players.sort_by(|a, b| b.1.money.cmp(&a.1.money));
+    sorted_players.sort_by_key(|p| std::cmp::Reverse(p.1.money));
 
     egui::Area::new(egui::Id::new("game_end_loser_reveal"))
         .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
         .show(ctx, |ui| {
             egui::Frame::new()
-                .fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230))
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230)))
+                .stroke(panel_stroke(egui::Stroke::NONE))
                 .corner_radius(egui::CornerRadius::same(16))
                 .inner_margin(egui::Margin::same(40))
-                .shadow(egui::epaint::Shadow {
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                     offset: [0, 8],
                     blur: 24,
                     spread: 4,
                     color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
-                })
+                }))
                 .show(ui, |ui| {
                     ui.vertical_centered(|ui| {
                         ui.heading(egui::RichText::new("Loser Bets").size(32.0).strong().color(egui::Color32::from_rgb(200, 100, 100)));
@@ -854,16 +1138,10 @@ fn draw_loser_bets_reveal_phase(
                             ui.add_space(10.0);
                             ui.horizontal(|ui| {
                                 ui.label(egui::RichText::new("Race Loser:").size(14.0));
-                                let color = camel_color_to_egui(loser);
-                                let border_color = egui::Color32::from_rgb(
-                                    (color.r() as f32 * 0.5) as u8,
-                                    (color.g() as f32 * 0.5) as u8,
-                                    (color.b() as f32 * 0.5) as u8,
-                                );
                                 let (rect, _) = ui.allocate_exact_size(egui::vec2(30.0, 22.0), egui::Sense::hover());
-                                draw_camel_silhouette(ui.painter(), rect, color, border_color);
-                                draw_dunce_cap_overlay(ui.painter(), rect);  // Loser wears a dunce cap
-                                ui.label(egui::RichText::new(format!("{:?}", loser)).size(14.0).strong());
+                                draw_camel_silhouette(ui.painter(), rect, &Palette::camel(loser, camel_palette));
+                                draw_dunce_cap_overlay(ui.painter(), rect, &Palette::DUNCE_CAP);  // Loser wears a dunce cap
+                                ui.label(egui::RichText::new(camel_names.name(loser)).size(14.0).strong());
                             });
                         }
 
@@ -873,7 +1151,13 @@ fn draw_loser_bets_reveal_phase(
                         if current_idx < total_bets {
                             let bet = &state.loser_bets_to_reveal[current_idx];
                             let grey_hold_ratio = state.grey_hold_duration / state.reveal_animation_duration;
-                            draw_bet_reveal_card(ui, bet, state.reveal_timer / state.reveal_animation_duration, grey_hold_ratio);
+                            draw_bet_reveal_card(
+                                ui,
+                                bet,
+                                state.reveal_timer / state.reveal_animation_duration,
+                                grey_hold_ratio,
+                                camel_palette,
+                            );
                         }
 
                         ui.add_space(20.0);
@@ -896,7 +1180,7 @@ fn draw_loser_bets_reveal_phase(
                         let bar_max_width = 150.0;
 
                         for (rank, (_player_idx, player)) in sorted_players.iter().enumerate() {
-                            let player_color = PLAYER_COLORS[player.color_index % PLAYER_COLORS.len()];
+                            let player_color = player_color_to_egui(player.color_index, player.custom_color);
                             ui.horizontal(|ui| {
                                 let rank_text = format!("{}.", rank + 1);
                                 ui.label(egui::RichText::new(&rank_text).size(14.0).monospace());
@@ -941,7 +1225,7 @@ fn draw_loser_bets_reveal_phase(
                         ui.add_space(16.0);
 
                         // Progress indicator
-                        ui.label(egui::RichText::new(format!("Bet {}/{}", (current_idx + 1).min(total_bets), total_bets)).size(12.0).color(egui::Color32::GRAY));
+                        ui.label(egui::RichText::new(format!("Bet {}/{}", (current_idx + 1).min(total_bets), total_bets)).size(12.0).color(text_color(egui::Color32::GRAY)));
 
                         // Next button - always rendered to prevent layout shift, but invisible when not ready
                         ui.add_space(10.0);
@@ -958,7 +1242,8 @@ fn draw_loser_bets_reveal_phase(
                                 // Make button invisible but still occupy space
                                 ui.set_invisible();
                             }
-                            if desert_button(ui, btn_text, &DesertButtonStyle::default()).clicked() && btn_visible {
+                            let clicked = desert_button(ui, btn_text, &DesertButtonStyle::default()).clicked();
+                            if (clicked || auto_click) && btn_visible {
                                 if current_idx + 1 < total_bets {
                                     should_next_card = true;
                                 } else {
@@ -977,9 +1262,85 @@ fn draw_loser_bets_reveal_phase(
     }
 
     if should_advance {
+        enter_final_results(state, players);
+    }
+}
+
+/// Draw the tiebreaker phase explaining how tied players were separated
+fn draw_tiebreaker_phase(
+    ctx: &egui::Context,
+    players: &Players,
+    state: &mut GameEndState,
+    camel_palette: CamelPalette,
+    auto_click: bool,
+) {
+    let mut should_continue = false;
+    let name_of = |id: u8| {
+        players.players.iter().find(|p| p.id == id).map(|p| p.name.clone()).unwrap_or_default()
+    };
+
+    egui::Area::new(egui::Id::new("game_end_tiebreaker"))
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230)))
+                .stroke(panel_stroke(egui::Stroke::NONE))
+                .corner_radius(egui::CornerRadius::same(16))
+                .inner_margin(egui::Margin::same(32))
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
+                    offset: [0, 8],
+                    blur: 24,
+                    spread: 4,
+                    color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
+                }))
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.heading(egui::RichText::new("Tiebreaker!").size(28.0).strong().color(egui::Color32::GOLD));
+                        ui.add_space(16.0);
+
+                        for group in &state.tiebreaks {
+                            let names: Vec<String> = group.order.iter().map(|&id| name_of(id)).collect();
+                            ui.label(egui::RichText::new(format!("Tied at ${}: {}", group.money, names.join(", "))).size(16.0).strong());
+                            ui.add_space(6.0);
+
+                            ui.label(egui::RichText::new("Correct race bet cards").size(13.0).color(text_color(egui::Color32::GRAY)));
+                            for (id, count) in &group.correct_cards {
+                                ui.label(egui::RichText::new(format!("{}: {}", name_of(*id), count)).size(14.0));
+                            }
+
+                            if !group.sudden_death.is_empty() {
+                                ui.add_space(8.0);
+                                ui.label(egui::RichText::new("Still level - sudden-death leg!").size(13.0).color(text_color(egui::Color32::GRAY)));
+                                for (place, (id, camel)) in group.sudden_death.iter().enumerate() {
+                                    let shared = group.sudden_death.iter().filter(|(_, c)| c == camel).count() > 1;
+                                    ui.horizontal(|ui| {
+                                        ui.label(egui::RichText::new(format!("{}.", place + 1)).size(14.0).monospace());
+                                        let (rect, _) = ui.allocate_exact_size(egui::vec2(32.0, 24.0), egui::Sense::hover());
+                                        draw_camel_silhouette(ui.painter(), rect, &Palette::camel(*camel, camel_palette));
+                                        ui.label(egui::RichText::new(name_of(*id)).size(14.0));
+                                        if shared {
+                                            ui.label(egui::RichText::new("(shared camel, drew lots)").size(12.0).color(text_color(egui::Color32::GRAY)));
+                                        }
+                                    });
+                                }
+                            }
+
+                            if let Some(&first) = group.order.first() {
+                                ui.add_space(6.0);
+                                ui.label(egui::RichText::new(format!("{} takes the higher place", name_of(first))).size(14.0).color(egui::Color32::LIGHT_GREEN));
+                            }
+                            ui.add_space(16.0);
+                        }
+
+                        if desert_button(ui, "Final Results", &DesertButtonStyle::medium()).clicked() {
+                            should_continue = true;
+                        }
+                    });
+                });
+        });
+
+    if should_continue || auto_click {
         state.phase = GameEndPhase::FinalResults;
-        state.reveal_timer = 0.0;
-        state.current_payout_applied = false;
     }
 }
 
@@ -988,11 +1349,12 @@ fn draw_loser_bets_reveal_phase(
 /// Bottom panel: Winner announcement and action buttons
 fn draw_final_results_mobile_panels(
     ctx: &egui::Context,
-    _players: &ResMut<Players>,
+    players: &ResMut<Players>,
     sorted_players: &[(usize, &crate::components::player::PlayerData)],
     state: &mut GameEndState,
     next_state: &mut ResMut<NextState<GameState>>,
     time_delta: f32,
+    money_timeline: &MoneyTimeline,
 ) {
     // Animate panel progress (0 to 1 over 0.3 seconds)
     state.panel_animation_progress = (state.panel_animation_progress + time_delta / 0.3).min(1.0);
@@ -1017,7 +1379,7 @@ fn draw_final_results_mobile_panels(
 
             // Player rankings (compact horizontal layout for mobile)
             for (rank, (_player_idx, player)) in sorted_players.iter().enumerate() {
-                let player_color = PLAYER_COLORS[player.color_index % PLAYER_COLORS.len()];
+                let player_color = player_color_to_egui(player.color_index, player.custom_color);
                 let is_winner = rank == 0;
 
                 let rank_text = match rank {
@@ -1056,6 +1418,9 @@ fn draw_final_results_mobile_panels(
                         egui::RichText::new(&player.name).size(13.0)
                     };
                     ui.label(name_text);
+                    if player.conceded {
+                        ui.label(egui::RichText::new("(conceded)").size(11.0).color(text_color(egui::Color32::GRAY)));
+                    }
 
                     // Money (right-aligned)
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -1068,6 +1433,9 @@ fn draw_final_results_mobile_panels(
                 });
                 ui.add_space(2.0);
             }
+
+            ui.add_space(6.0);
+            draw_money_chart(ui, money_timeline, players, egui::vec2(ui.available_width(), 90.0));
         });
 
     // Bottom panel: slides up from bottom - Winner + Buttons
@@ -1079,7 +1447,7 @@ fn draw_final_results_mobile_panels(
             ui.vertical_centered(|ui| {
                 // Winner announcement
                 if let Some((_winner_idx, winner)) = winner {
-                    let winner_color = PLAYER_COLORS[winner.color_index % PLAYER_COLORS.len()];
+                    let winner_color = player_color_to_egui(winner.color_index, winner.custom_color);
 
                     ui.horizontal(|ui| {
                         // Winner avatar
@@ -1115,10 +1483,17 @@ fn draw_final_results_mobile_panels(
 
                     ui.add_space(12.0);
 
+                    draw_rematch_button(ui, state, &DesertButtonStyle::default(), 12.0);
+
+                    if desert_button(ui, "Export Log", &DesertButtonStyle::default()).clicked() {
+                        state.export_requested = true;
+                    }
+                    ui.add_space(12.0);
+
                     #[cfg(not(target_arch = "wasm32"))]
                     {
                         if desert_button(ui, "Quit", &DesertButtonStyle::default()).clicked() {
-                            std::process::exit(0);
+                            state.quit_requested = true;
                         }
                     }
                 });
@@ -1134,14 +1509,20 @@ fn draw_final_results_phase(
     next_state: &mut ResMut<NextState<GameState>>,
     is_mobile: bool,
     time_delta: f32,
+    money_timeline: &MoneyTimeline,
 ) {
-    // Sort players by money
+    // Sort players by money, using the tiebreak order for equal scores.
+    // Conceded players rank below everyone who finished the race.
     let mut sorted_players: Vec<_> = players.players.iter().enumerate().collect();
-    sorted_players.sort_by(|a, b| b.1.money.cmp(&a.1.money));
+    sorted_players.sort_by(|a, b| {
+        a.1.conceded.cmp(&b.1.conceded)
+            .then(b.1.money.cmp(&a.1.money))
+            .then(state.tiebreak_rank(a.1.id).cmp(&state.tiebreak_rank(b.1.id)))
+    });
 
     // Use sliding panels on mobile, modal on desktop
     if is_mobile {
-        draw_final_results_mobile_panels(ctx, players, &sorted_players, state, next_state, time_delta);
+        draw_final_results_mobile_panels(ctx, players, &sorted_players, state, next_state, time_delta, money_timeline);
         return;
     }
 
@@ -1158,15 +1539,16 @@ fn draw_final_results_phase(
         .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
         .show(ctx, |ui| {
             egui::Frame::new()
-                .fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230))
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(0, 0, 0, 230)))
+                .stroke(panel_stroke(egui::Stroke::NONE))
                 .corner_radius(egui::CornerRadius::same(12))
                 .inner_margin(egui::Margin::same(margin as i8))
-                .shadow(egui::epaint::Shadow {
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                     offset: [0, 4],
                     blur: 16,
                     spread: 2,
                     color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 120),
-                })
+                }))
                 .show(ui, |ui| {
                     ui.vertical_centered(|ui| {
                         // Title - Final Standings at top
@@ -1175,7 +1557,7 @@ fn draw_final_results_phase(
 
                         // Final standings with avatars
                         for (rank, (_player_idx, player)) in sorted_players.iter().enumerate() {
-                            let player_color = PLAYER_COLORS[player.color_index % PLAYER_COLORS.len()];
+                            let player_color = player_color_to_egui(player.color_index, player.custom_color);
                             let is_winner = rank == 0;
 
                             let rank_text = match rank {
@@ -1211,8 +1593,14 @@ fn draw_final_results_phase(
                                 ui.label(name_text);
 
                                 if !is_mobile {
-                                    let ai_tag = if player.is_ai { " (AI)" } else { "" };
-                                    ui.label(egui::RichText::new(ai_tag).size(12.0).color(egui::Color32::GRAY));
+                                    let ai_tag = if player.conceded {
+                                        " (conceded)"
+                                    } else if player.is_ai {
+                                        " (AI)"
+                                    } else {
+                                        ""
+                                    };
+                                    ui.label(egui::RichText::new(ai_tag).size(12.0).color(text_color(egui::Color32::GRAY)));
                                 }
 
                                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -1223,13 +1611,16 @@ fn draw_final_results_phase(
                             ui.add_space(if is_mobile { 3.0 } else { 5.0 });
                         }
 
+                        ui.add_space(15.0);
+                        draw_money_chart(ui, money_timeline, players, egui::vec2(420.0, 140.0));
+
                         ui.add_space(if is_mobile { 15.0 } else { 25.0 });
                         ui.separator();
                         ui.add_space(if is_mobile { 15.0 } else { 20.0 });
 
                         // Winner announcement at bottom
                         if let Some(winner) = winner {
-                            let winner_color = PLAYER_COLORS[winner.color_index % PLAYER_COLORS.len()];
+                            let winner_color = player_color_to_egui(winner.color_index, winner.custom_color);
 
                             ui.horizontal(|ui| {
                                 // Winner avatar - with happy expression!
@@ -1262,10 +1653,17 @@ fn draw_final_results_phase(
 
                             ui.add_space(if is_mobile { 15.0 } else { 20.0 });
 
+                            draw_rematch_button(ui, state, &style, if is_mobile { 15.0 } else { 20.0 });
+
+                            if desert_button(ui, "Export Log", &style).on_hover_text("Save every turn, roll and payout as JSON").clicked() {
+                                state.export_requested = true;
+                            }
+                            ui.add_space(if is_mobile { 15.0 } else { 20.0 });
+
                             #[cfg(not(target_arch = "wasm32"))]
                             {
                                 if desert_button(ui, "Quit", &DesertButtonStyle::default()).clicked() {
-                                    std::process::exit(0);
+                                    state.quit_requested = true;
                                 }
                             }
                         });
@@ -1274,10 +1672,111 @@ fn draw_final_results_phase(
         });
 }
 
+/// Line chart of each player's money at the start and after every scoring, in their
+/// colors. Gold rings mark where someone new took the lead.
+fn draw_money_chart(ui: &mut egui::Ui, timeline: &MoneyTimeline, players: &Players, size: egui::Vec2) {
+    let samples = &timeline.samples;
+    if samples.len() < 2 {
+        return;
+    }
+
+    ui.label(egui::RichText::new("Money over the game").size(13.0).color(text_color(egui::Color32::GRAY)));
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 4.0, egui::Color32::from_rgba_unmultiplied(255, 255, 255, 12));
+
+    // Room for the money scale on the left and the sample labels underneath
+    let plot = egui::Rect::from_min_max(rect.min + egui::vec2(28.0, 6.0), rect.max - egui::vec2(8.0, 16.0));
+    let high = samples.iter().flat_map(|s| s.money.iter().copied()).max().unwrap_or(0).max(1);
+    let low = samples.iter().flat_map(|s| s.money.iter().copied()).min().unwrap_or(0).min(0);
+    let x_of = |i: usize| plot.left() + plot.width() * i as f32 / (samples.len() - 1) as f32;
+    let y_of = |money: i32| plot.bottom() - plot.height() * (money - low) as f32 / (high - low) as f32;
+
+    let scale_font = egui::FontId::proportional(10.0);
+    let scale_color = text_color(egui::Color32::GRAY);
+    for money in [low, high] {
+        painter.text(egui::pos2(plot.left() - 4.0, y_of(money)), egui::Align2::RIGHT_CENTER, format!("${}", money), scale_font.clone(), scale_color);
+    }
+    painter.line_segment([plot.left_bottom(), plot.right_bottom()], egui::Stroke::new(1.0, scale_color));
+
+    // Label every sample if they fit, otherwise just the first and last
+    let fits = plot.width() / samples.len() as f32 >= 36.0;
+    for (i, sample) in samples.iter().enumerate() {
+        if fits || i == 0 || i == samples.len() - 1 {
+            painter.text(egui::pos2(x_of(i), plot.bottom() + 2.0), egui::Align2::CENTER_TOP, &sample.label, scale_font.clone(), scale_color);
+        }
+    }
+
+    for (seat, player) in players.players.iter().enumerate() {
+        let points: Vec<egui::Pos2> = samples
+            .iter()
+            .enumerate()
+            .filter_map(|(i, sample)| sample.money.get(seat).map(|&money| egui::pos2(x_of(i), y_of(money))))
+            .collect();
+        let color = player_color_to_egui(player.color_index, player.custom_color);
+        painter.add(egui::Shape::line(points, egui::Stroke::new(2.0, color)));
+    }
+
+    // A ring wherever the lead changed hands
+    let leaders = timeline.leaders();
+    let mut last_leader = None;
+    for (i, leader) in leaders.iter().enumerate() {
+        let Some(seat) = *leader else { continue };
+        if last_leader.is_some_and(|last| last != seat) {
+            let money = samples[i].money[seat];
+            painter.circle_stroke(egui::pos2(x_of(i), y_of(money)), 5.0, egui::Stroke::new(1.5, egui::Color32::GOLD));
+        }
+        last_leader = Some(seat);
+    }
+
+    // Everyone's money at the sample under the pointer
+    if let Some(pointer) = response.hover_pos() {
+        let step = plot.width() / (samples.len() - 1) as f32;
+        let i = (((pointer.x - plot.left()) / step).round().max(0.0) as usize).min(samples.len() - 1);
+        painter.line_segment(
+            [egui::pos2(x_of(i), plot.top()), egui::pos2(x_of(i), plot.bottom())],
+            egui::Stroke::new(1.0, egui::Color32::from_rgba_unmultiplied(255, 255, 255, 60)),
+        );
+        let lines: Vec<String> = players
+            .players
+            .iter()
+            .zip(&samples[i].money)
+            .map(|(player, money)| format!("{}: ${}", player.name, money))
+            .collect();
+        response.on_hover_text(format!("{}\n{}", samples[i].label, lines.join("\n")));
+    }
+}
+
+/// Online games: the host's Rematch button, or a note that guests are waiting for it
+fn draw_rematch_button(ui: &mut egui::Ui, state: &mut GameEndState, style: &DesertButtonStyle, spacing: f32) {
+    match state.rematch {
+        RematchRole::None => return,
+        RematchRole::Host => {
+            if desert_button(ui, "Rematch", style).clicked() {
+                state.rematch_requested = true;
+            }
+        }
+        RematchRole::Guest => {
+            ui.label(
+                egui::RichText::new("Waiting for the host to start a rematch...")
+                    .size(13.0)
+                    .color(text_color(egui::Color32::LIGHT_GRAY)),
+            );
+        }
+    }
+    ui.add_space(spacing);
+}
+
 /// Draw a bet reveal card with flip animation
 /// Phase 1 (0 to grey_hold_ratio): Grey neutral card at full size
 /// Phase 2 (grey_hold_ratio to 1.0): Flip animation (grey shrinks, color grows)
-fn draw_bet_reveal_card(ui: &mut egui::Ui, bet: &PendingBetReveal, progress: f32, grey_hold_ratio: f32) {
+fn draw_bet_reveal_card(
+    ui: &mut egui::Ui,
+    bet: &PendingBetReveal,
+    progress: f32,
+    grey_hold_ratio: f32,
+    camel_palette: CamelPalette,
+) {
     let card_width = 70.0;   // Match race bet card picker dimensions
     let card_height = 90.0;
 
@@ -1311,7 +1810,7 @@ fn draw_bet_reveal_card(ui: &mut egui::Ui, bet: &PendingBetReveal, progress: f32
 
         if is_front {
             // Revealed card - camel color with player avatar
-            draw_revealed_bet_card(ui.painter(), display_rect, bet, scale_x);
+            draw_revealed_bet_card(ui.painter(), display_rect, bet, scale_x, camel_palette);
         } else {
             // Grey neutral card back
             draw_card_back(ui.painter(), display_rect, scale_x);
@@ -1367,8 +1866,14 @@ fn draw_card_back(painter: &egui::Painter, rect: egui::Rect, scale_x: f32) {
 }
 
 /// Draw the revealed bet card with camel color and player avatar
-fn draw_revealed_bet_card(painter: &egui::Painter, rect: egui::Rect, bet: &PendingBetReveal, scale_x: f32) {
-    let camel_color = camel_color_to_egui(bet.camel);
+fn draw_revealed_bet_card(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    bet: &PendingBetReveal,
+    scale_x: f32,
+    camel_palette: CamelPalette,
+) {
+    let camel_color = camel_color_to_egui(bet.camel, camel_palette);
 
     // Card background with camel color
     painter.rect_filled(rect, 8.0, camel_color);
@@ -1409,7 +1914,7 @@ fn draw_revealed_bet_card(painter: &egui::Painter, rect: egui::Rect, bet: &Pendi
 
 /// Helper to draw pyramid token icons - one icon per token collected
 fn draw_pyramid_token_icon(ui: &mut egui::Ui, count: u8) {
-    use crate::ui::hud::draw_pyramid_token_icon as draw_single_token;
+    use crate::ui::draw::draw_pyramid_token_icon as draw_single_token;
 
     let token_size = 20.0;
     let token_spacing = 16.0; // Slight overlap for stacked look
@@ -1427,10 +1932,60 @@ fn draw_pyramid_token_icon(ui: &mut egui::Ui, count: u8) {
             tokens_rect.left() + token_size / 2.0 + (t as f32 * token_spacing),
             tokens_rect.center().y
         );
-        draw_single_token(ui.painter(), token_center, token_size);
+        draw_single_token(ui.painter(), token_center, &Palette::GOLD, token_size);
     }
 
     // Show total value
     ui.label(egui::RichText::new(format!("+${}", count))
         .size(12.0).color(egui::Color32::GOLD));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::characters::CharacterId;
+    use crate::ui::player_setup::PlayerSetupConfig;
+
+    /// `count` players who all finished on the same money, with no race bets between them
+    fn level_players(count: usize) -> Players {
+        Players::new(
+            (0..count)
+                .map(|seat| (format!("Player {}", seat + 1), false, CharacterId::from_index(seat), seat, None))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn tied_players_sharing_a_camel_draw_lots_for_their_place() {
+        let camels = CamelColor::all().len();
+        for count in 2..=PlayerSetupConfig::MAX_PLAYERS {
+            let mut first_seat_won = false;
+            let mut first_seat_lost = false;
+            for seed in 0..40 {
+                let state = GameEndState { sudden_death_seed: seed, ..default() };
+                let groups = resolve_ties(&state, &level_players(count));
+                assert_eq!(groups.len(), 1);
+                let group = &groups[0];
+
+                // The sudden-death leg decides the whole order
+                let finishers: Vec<u8> = group.sudden_death.iter().map(|(id, _)| *id).collect();
+                assert_eq!(finishers, group.order);
+
+                // Camels are only shared once there are more players than camels
+                for camel in CamelColor::all() {
+                    let riders: Vec<u8> =
+                        group.sudden_death.iter().filter(|(_, c)| *c == camel).map(|(id, _)| *id).collect();
+                    assert!(riders.len() <= count.div_ceil(camels));
+                    if riders.len() > 1 {
+                        first_seat_won |= riders[0] < riders[1];
+                        first_seat_lost |= riders[0] > riders[1];
+                    }
+                }
+            }
+            // Sitting earlier at the table is no help: the lots go both ways
+            if count > camels {
+                assert!(first_seat_won && first_seat_lost, "{} players", count);
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+// Board space inspector
+// Clicking or tapping a board space opens a small popup above it listing the stack
+// from top to bottom, whose spectator tile sits there, and how far it is to the
+// finish. Tall stacks of four or five camels are hard to read on the board itself.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContextSettings, EguiContexts};
+
+use crate::components::{
+    BoardPosition, Camel, CamelColor, CamelNames, CamelPalette, CrazyCamel, CrazyCamelColor, GameBoard,
+    PlacedSpectatorTiles, Players, TRACK_LENGTH,
+};
+use crate::ui::draw::{draw_camel_silhouette, Palette};
+use crate::ui::hud::UiState;
+use crate::ui::theme::crazy_camel_color_to_egui;
+
+/// A camel in the inspected stack
+enum StackedCamel {
+    Racing(CamelColor),
+    Crazy(CrazyCamelColor),
+}
+
+/// The game camera and egui's scale, to find where on screen a board position is
+#[derive(SystemParam)]
+pub struct BoardToScreen<'w, 's> {
+    camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<crate::GameCamera>>,
+    egui_settings: Query<'w, 's, &'static EguiContextSettings>,
+}
+
+impl BoardToScreen<'_, '_> {
+    /// Where `world_pos` is on screen, in egui points
+    fn egui_pos(&self, world_pos: Vec2) -> Option<egui::Pos2> {
+        let (camera, camera_transform) = self.camera.single().ok()?;
+        let screen_pos = camera.world_to_viewport(camera_transform, world_pos.extend(0.0)).ok()?;
+        let egui_scale = self.egui_settings.iter().next().map_or(1.0, |settings| settings.scale_factor);
+        Some(egui::pos2(screen_pos.x / egui_scale, screen_pos.y / egui_scale))
+    }
+}
+
+/// Popup listing what's on the space the player clicked
+pub fn space_inspector_ui(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<UiState>,
+    camels: Query<(&Camel, &BoardPosition)>,
+    crazy_camels: Query<(&CrazyCamel, &BoardPosition)>,
+    (board, players, placed_tiles): (Res<GameBoard>, Res<Players>, Res<PlacedSpectatorTiles>),
+    (camel_names, camel_palette): (Res<CamelNames>, Res<CamelPalette>),
+    screen: BoardToScreen,
+) {
+    let Some(space) = ui_state.inspected_space else { return };
+
+    // Anchor the popup just above the space
+    let above_space = board.get_position(space) + Vec2::new(0.0, 30.0);
+    let Some(anchor) = screen.egui_pos(above_space) else { return };
+
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    // Top of the stack first
+    let mut stack: Vec<(u8, StackedCamel)> = camels
+        .iter()
+        .filter(|(_, pos)| pos.space_index == space)
+        .map(|(camel, pos)| (pos.stack_position, StackedCamel::Racing(camel.color)))
+        .chain(
+            crazy_camels
+                .iter()
+                .filter(|(_, pos)| pos.space_index == space)
+                .map(|(camel, pos)| (pos.stack_position, StackedCamel::Crazy(camel.color))),
+        )
+        .collect();
+    stack.sort_by_key(|(stack_position, _)| std::cmp::Reverse(*stack_position));
+
+    let mut close = false;
+
+    egui::Area::new(egui::Id::new("space_inspector"))
+        .order(egui::Order::Foreground)
+        .pivot(egui::Align2::CENTER_BOTTOM)
+        .fixed_pos(anchor)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_min_width(150.0);
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(format!("Space {}", space + 1)).strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("×").clicked() {
+                            close = true;
+                        }
+                    });
+                });
+
+                if stack.is_empty() {
+                    ui.label(egui::RichText::new("No camels").color(egui::Color32::GRAY));
+                } else {
+                    ui.label(egui::RichText::new("Top to bottom:").small().color(egui::Color32::GRAY));
+                    for (_, camel) in &stack {
+                        ui.horizontal(|ui| {
+                            let (rect, _) = ui.allocate_exact_size(egui::vec2(24.0, 16.0), egui::Sense::hover());
+                            match camel {
+                                StackedCamel::Racing(color) => {
+                                    draw_camel_silhouette(ui.painter(), rect, &Palette::camel(*color, *camel_palette));
+                                    ui.label(camel_names.name(*color));
+                                }
+                                StackedCamel::Crazy(color) => {
+                                    draw_camel_silhouette(
+                                        ui.painter(),
+                                        rect,
+                                        &Palette::shaded(crazy_camel_color_to_egui(*color)),
+                                    );
+                                    ui.label(format!("{:?} crazy camel", color));
+                                }
+                            }
+                        });
+                    }
+                }
+
+                if let Some((owner_id, is_oasis)) = placed_tiles.get_tile(space) {
+                    let owner = players
+                        .players
+                        .iter()
+                        .find(|p| p.id == owner_id)
+                        .map_or("Someone", |p| p.name.as_str());
+                    ui.label(format!(
+                        "{}'s {} tile ({})",
+                        owner,
+                        if is_oasis { "oasis" } else { "mirage" },
+                        if is_oasis { "+1" } else { "-1" }
+                    ));
+                }
+
+                let to_finish = TRACK_LENGTH - space;
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} space{} to the {}",
+                        to_finish,
+                        if to_finish == 1 { "" } else { "s" },
+                        if board.on_last_track() { "finish" } else { "end of this track" }
+                    ))
+                    .small()
+                    .color(egui::Color32::GRAY),
+                );
+            });
+        });
+
+    if close {
+        ui_state.inspected_space = None;
+    }
+}
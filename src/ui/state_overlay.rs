@@ -0,0 +1,228 @@
+// Developer overlay showing the app's state machines as a live diagram
+// Build with `--features state_overlay` and press F10 in any screen. Shows the
+// GameState flow, the turn phase, the network mode and which animations are still
+// pending, so it's easy to see why the game is (or isn't) waiting on something.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::components::PyramidShakeAnimation;
+use crate::game::key_bindings::{KeyAction, KeyBindings};
+use crate::game::state::{GameState, SuspendedGame};
+use crate::network::state::{NetworkMode, NetworkState};
+use crate::systems::animation::{DiceRollAnimation, Moving};
+use crate::systems::setup::PendingInitialMove;
+use crate::components::TurnState;
+use crate::ui::hud::UiState;
+
+/// Whether the overlay is showing (F10 toggles)
+#[derive(Resource, Default)]
+pub struct StateOverlay {
+    pub visible: bool,
+}
+
+/// Where the current turn is, derived from `TurnState` and `UiState`
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TurnPhase {
+    Setup,
+    AwaitingAction,
+    Resolving,
+    LegScoring,
+}
+
+impl TurnPhase {
+    const ALL: [TurnPhase; 4] = [
+        TurnPhase::Setup,
+        TurnPhase::AwaitingAction,
+        TurnPhase::Resolving,
+        TurnPhase::LegScoring,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            TurnPhase::Setup => "Setup rolls",
+            TurnPhase::AwaitingAction => "Awaiting action",
+            TurnPhase::Resolving => "Resolving",
+            TurnPhase::LegScoring => "Leg scoring",
+        }
+    }
+
+    fn current(ui_state: &UiState, turn_state: &TurnState) -> Self {
+        if !ui_state.initial_rolls_complete {
+            TurnPhase::Setup
+        } else if ui_state.show_leg_scoring || ui_state.leg_scoring_delay > 0.0 {
+            TurnPhase::LegScoring
+        } else if turn_state.action_taken {
+            TurnPhase::Resolving
+        } else {
+            TurnPhase::AwaitingAction
+        }
+    }
+}
+
+const GAME_STATES: [GameState; 5] = [
+    GameState::MainMenu,
+    GameState::Lobby,
+    GameState::WaitingRoom,
+    GameState::Playing,
+    GameState::GameEnd,
+];
+
+const NETWORK_MODES: [NetworkMode; 4] = [
+    NetworkMode::Local,
+    NetworkMode::OnlineHost,
+    NetworkMode::OnlineClient,
+    NetworkMode::Replay,
+];
+
+const ACTIVE_COLOR: egui::Color32 = egui::Color32::from_rgb(0xFF, 0xC8, 0x3C);
+const IDLE_COLOR: egui::Color32 = egui::Color32::from_rgb(0x70, 0x70, 0x70);
+
+/// Draw one row of the diagram: every state as a box, joined by arrows, current one lit
+fn draw_state_row(ui: &mut egui::Ui, title: &str, labels: &[String], current: Option<usize>) {
+    ui.label(egui::RichText::new(title).size(11.0).color(egui::Color32::GRAY));
+    ui.horizontal_wrapped(|ui| {
+        for (i, label) in labels.iter().enumerate() {
+            if i > 0 {
+                ui.label(egui::RichText::new("→").color(IDLE_COLOR));
+            }
+            let active = current == Some(i);
+            let (fill, text) = if active {
+                (ACTIVE_COLOR, egui::Color32::BLACK)
+            } else {
+                (egui::Color32::from_rgb(0x30, 0x30, 0x30), IDLE_COLOR)
+            };
+            egui::Frame::new()
+                .fill(fill)
+                .corner_radius(egui::CornerRadius::same(4))
+                .inner_margin(egui::Margin::symmetric(6, 2))
+                .show(ui, |ui| {
+                    let label = egui::RichText::new(label).size(12.0).color(text);
+                    ui.label(if active { label.strong() } else { label });
+                });
+        }
+    });
+    ui.add_space(4.0);
+}
+
+/// One pending-animation flag: lit while the animation is running
+fn draw_flag(ui: &mut egui::Ui, label: &str, pending: bool, detail: String) {
+    ui.horizontal(|ui| {
+        let color = if pending { ACTIVE_COLOR } else { IDLE_COLOR };
+        ui.label(egui::RichText::new(if pending { "●" } else { "○" }).color(color));
+        ui.label(egui::RichText::new(label).size(12.0).color(color));
+        if pending && !detail.is_empty() {
+            ui.label(egui::RichText::new(detail).size(11.0).color(egui::Color32::GRAY));
+        }
+    });
+}
+
+/// System to toggle the overlay with its key (F10 unless rebound)
+pub fn toggle_state_overlay(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    mut overlay: ResMut<StateOverlay>,
+) {
+    if key_bindings.just_pressed(&keys, KeyAction::StateOverlay) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// The state machines the overlay shows
+#[derive(SystemParam)]
+pub struct StateMachines<'w> {
+    game_state: Res<'w, State<GameState>>,
+    suspended: Res<'w, SuspendedGame>,
+    network_state: Res<'w, NetworkState>,
+    ui_state: Res<'w, UiState>,
+    turn_state: Option<Res<'w, TurnState>>,
+}
+
+/// The animations a turn waits on
+#[derive(SystemParam)]
+pub struct PendingAnimations<'w, 's> {
+    movements: Query<'w, 's, (), Moving>,
+    dice_rolls: Query<'w, 's, (), With<DiceRollAnimation>>,
+    pyramid_shakes: Query<'w, 's, (), With<PyramidShakeAnimation>>,
+    initial_moves: Query<'w, 's, (), With<PendingInitialMove>>,
+}
+
+/// System to draw the state machine overlay
+pub fn state_overlay_ui(
+    mut contexts: EguiContexts,
+    overlay: Res<StateOverlay>,
+    machines: StateMachines,
+    animations: PendingAnimations,
+) {
+    let StateMachines { game_state, suspended, network_state, ui_state, turn_state } = machines;
+    let PendingAnimations { movements, dice_rolls, pyramid_shakes, initial_moves } = animations;
+    if !overlay.visible {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    egui::Window::new("State Machines")
+        .collapsible(true)
+        .resizable(false)
+        .default_width(420.0)
+        .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(10.0, -10.0))
+        .show(ctx, |ui| {
+            let labels: Vec<String> = GAME_STATES.iter().map(|s| format!("{:?}", s)).collect();
+            let current = GAME_STATES.iter().position(|s| s == game_state.get());
+            draw_state_row(ui, "GameState", &labels, current);
+            if suspended.active {
+                ui.label(egui::RichText::new("A saved game is waiting to be resumed").size(11.0).color(ACTIVE_COLOR));
+            }
+
+            let labels: Vec<String> = TurnPhase::ALL.iter().map(|p| p.label().to_string()).collect();
+            match turn_state.as_deref() {
+                Some(turn_state) if *game_state.get() == GameState::Playing => {
+                    let phase = TurnPhase::current(&ui_state, turn_state);
+                    draw_state_row(ui, "Turn phase", &labels, TurnPhase::ALL.iter().position(|p| *p == phase));
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "Leg {} · player {} · leg started: {} · turn delay {:.2}s",
+                            turn_state.leg_number,
+                            turn_state.current_player + 1,
+                            turn_state.leg_has_started,
+                            turn_state.turn_delay_timer,
+                        ))
+                        .size(11.0)
+                        .color(egui::Color32::GRAY),
+                    );
+                    ui.add_space(4.0);
+                }
+                _ => draw_state_row(ui, "Turn phase", &labels, None),
+            }
+
+            let labels: Vec<String> = NETWORK_MODES.iter().map(|m| format!("{:?}", m)).collect();
+            let current = NETWORK_MODES.iter().position(|m| *m == network_state.mode);
+            draw_state_row(ui, "Network mode", &labels, current);
+            if let Some(ref room_code) = network_state.room_code {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Room {} · connected: {} · state v{}",
+                        room_code, network_state.is_connected, network_state.game_state_version
+                    ))
+                    .size(11.0)
+                    .color(egui::Color32::GRAY),
+                );
+            }
+
+            ui.separator();
+            ui.label(egui::RichText::new("Pending animations").size(11.0).color(egui::Color32::GRAY));
+            let moving = movements.iter().count();
+            draw_flag(ui, "Camel movement", moving > 0, format!("{} moving", moving));
+            let placing = initial_moves.iter().count();
+            draw_flag(ui, "Setup placement", placing > 0, format!("{} waiting", placing));
+            draw_flag(ui, "Dice roll", !dice_rolls.is_empty(), String::new());
+            draw_flag(ui, "Pyramid shake", !pyramid_shakes.is_empty(), String::new());
+            draw_flag(ui, "Die selection", ui_state.die_roll_animation.is_some(), String::new());
+            draw_flag(ui, "Pyramid flip", ui_state.pyramid_flip_anim > 0.0, format!("{:.0}%", ui_state.pyramid_flip_anim * 100.0));
+            draw_flag(ui, "Card flight", ui_state.card_flight_animation.is_some(), String::new());
+            draw_flag(ui, "Dice popup", ui_state.dice_popup_timer > 0.0, format!("{:.2}s", ui_state.dice_popup_timer));
+            draw_flag(ui, "Leg scoring delay", ui_state.leg_scoring_delay > 0.0, format!("{:.2}s", ui_state.leg_scoring_delay));
+            draw_flag(ui, "Game end delay", ui_state.game_end_delay > 0.0, format!("{:.2}s", ui_state.game_end_delay));
+        });
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::game::stats::PlayerStats;
+use crate::ui::theme::{desert_button, quality_shadow, DesertButtonStyle};
+
+const MODAL_BG: egui::Color32 = egui::Color32::from_rgb(30, 25, 20);
+const HEADER_COLOR: egui::Color32 = egui::Color32::from_rgb(0xD4, 0xA8, 0x4B);
+
+/// Whether the main menu's Stats page is open
+#[derive(Resource, Default)]
+pub struct StatsPageState {
+    pub is_open: bool,
+}
+
+/// Draw the Stats page: every profile's tallies across the games played on this device
+pub fn draw_stats_ui(ctx: &egui::Context, page: &mut StatsPageState, stats: &PlayerStats, is_mobile: bool) {
+    if !page.is_open {
+        return;
+    }
+
+    // Dark overlay behind modal
+    egui::Area::new(egui::Id::new("stats_overlay"))
+        .fixed_pos(egui::pos2(0.0, 0.0))
+        .order(egui::Order::Middle)
+        .show(ctx, |ui| {
+            let screen_rect = ctx.input(|i| i.viewport_rect());
+            ui.painter().rect_filled(screen_rect, 0.0, egui::Color32::from_rgba_unmultiplied(0, 0, 0, 180));
+        });
+
+    egui::Area::new(egui::Id::new("stats_panel"))
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(MODAL_BG)
+                .corner_radius(egui::CornerRadius::same(16))
+                .inner_margin(egui::Margin::same(if is_mobile { 16 } else { 24 }))
+                .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
+                    offset: [0, 8],
+                    blur: 24,
+                    spread: 4,
+                    color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150),
+                }))
+                .show(ui, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.heading(egui::RichText::new("Stats").size(28.0).color(egui::Color32::WHITE));
+                    });
+                    ui.add_space(12.0);
+
+                    if stats.profiles.is_empty() {
+                        ui.label(
+                            egui::RichText::new("Finish a game to start keeping stats.")
+                                .size(15.0)
+                                .color(egui::Color32::LIGHT_GRAY),
+                        );
+                    } else {
+                        let max_height = ctx.input(|i| i.viewport_rect()).height() * 0.6;
+                        egui::ScrollArea::both().max_height(max_height).show(ui, |ui| {
+                            draw_stats_table(ui, stats);
+                        });
+                    }
+
+                    ui.add_space(12.0);
+                    ui.vertical_centered(|ui| {
+                        if desert_button(ui, "Close", &DesertButtonStyle::medium()).clicked() {
+                            page.is_open = false;
+                        }
+                    });
+                });
+        });
+}
+
+/// One row per profile, most wins first
+fn draw_stats_table(ui: &mut egui::Ui, stats: &PlayerStats) {
+    let mut profiles: Vec<_> = stats.profiles.iter().collect();
+    profiles.sort_by(|a, b| b.1.wins.cmp(&a.1.wins).then(b.1.games_played.cmp(&a.1.games_played)));
+
+    egui::Grid::new("player_stats_grid").num_columns(6).spacing([16.0, 6.0]).striped(true).show(ui, |ui| {
+        for header in ["Player", "Games", "Wins", "Avg. money", "Favorite action", "Best leg"] {
+            ui.label(egui::RichText::new(header).size(13.0).strong().color(HEADER_COLOR));
+        }
+        ui.end_row();
+
+        for (name, profile) in profiles {
+            let cells = [
+                name.clone(),
+                profile.games_played.to_string(),
+                profile.wins.to_string(),
+                format!("${:.1}", profile.average_money()),
+                profile.actions.favorite().unwrap_or("-").to_string(),
+                format!("${}", profile.best_leg),
+            ];
+            for cell in cells {
+                ui.label(egui::RichText::new(cell).size(14.0).color(egui::Color32::WHITE));
+            }
+            ui.end_row();
+        }
+    });
+}
@@ -1,8 +1,10 @@
 //! Desert/Pyramid themed UI widgets
 //! Custom-drawn widgets that replace standard egui styling
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use bevy_egui::{egui, EguiContexts};
-use crate::components::{CamelColor, CrazyCamelColor};
+use crate::components::{CamelColor, CamelPalette, CrazyCamelColor};
 
 // ============================================================================
 // Color Palette - Desert Theme
@@ -20,8 +22,51 @@ pub const PLAYER_COLORS: [egui::Color32; 8] = [
     egui::Color32::from_rgb(200, 100, 150), // Pink
 ];
 
-/// Convert CamelColor to egui Color32 for UI display
-pub fn camel_color_to_egui(color: CamelColor) -> egui::Color32 {
+/// How far apart two player colors have to be (RGB distance) to tell them apart
+const PLAYER_COLOR_MIN_DISTANCE: i32 = 60;
+
+/// A player's color: the one they picked with the color picker, or else their
+/// `PLAYER_COLORS` slot
+pub fn player_color_to_egui(color_index: usize, custom_color: Option<[u8; 3]>) -> egui::Color32 {
+    match custom_color {
+        Some([r, g, b]) => egui::Color32::from_rgb(r, g, b),
+        None => PLAYER_COLORS[color_index % PLAYER_COLORS.len()],
+    }
+}
+
+/// Whether two player colors are too close to tell apart on the board and in the panels
+pub fn player_colors_clash(a: egui::Color32, b: egui::Color32) -> bool {
+    let channel = |x: u8, y: u8| (x as i32 - y as i32).pow(2);
+    channel(a.r(), b.r()) + channel(a.g(), b.g()) + channel(a.b(), b.b()) < PLAYER_COLOR_MIN_DISTANCE.pow(2)
+}
+
+/// The first pair of colors (by position in `colors`) that clash, if any
+pub fn find_player_color_clash(colors: &[egui::Color32]) -> Option<(usize, usize)> {
+    (0..colors.len()).find_map(|i| {
+        (i + 1..colors.len())
+            .find(|&j| player_colors_clash(colors[i], colors[j]))
+            .map(|j| (i, j))
+    })
+}
+
+/// Small swatch button that opens a color picker for a player's color; returns
+/// true when the color was changed
+pub fn player_color_picker(ui: &mut egui::Ui, color_index: usize, custom_color: &mut Option<[u8; 3]>) -> bool {
+    let mut color = player_color_to_egui(color_index, *custom_color);
+    let changed = egui::color_picker::color_edit_button_srgba(ui, &mut color, egui::color_picker::Alpha::Opaque)
+        .on_hover_text("Pick a color")
+        .changed();
+    if changed {
+        *custom_color = Some([color.r(), color.g(), color.b()]);
+    }
+    changed
+}
+
+/// Convert CamelColor to egui Color32 for UI display, in the player's palette
+pub fn camel_color_to_egui(color: CamelColor, palette: CamelPalette) -> egui::Color32 {
+    if let Some([r, g, b]) = color.colorblind_rgb(palette) {
+        return egui::Color32::from_rgb(r, g, b);
+    }
     match color {
         CamelColor::Blue => egui::Color32::from_rgb(50, 100, 230),
         CamelColor::Green => egui::Color32::from_rgb(50, 200, 80),
@@ -75,6 +120,107 @@ pub const SKY_BLUE: egui::Color32 = egui::Color32::from_rgb(0x87, 0xCE, 0xEB);
 #[allow(dead_code)]
 pub const TERRACOTTA: egui::Color32 = egui::Color32::from_rgb(0xC4, 0x5C, 0x3B);
 
+// ============================================================================
+// High Contrast Theme
+// ============================================================================
+
+/// Whether the UI is drawn in high contrast (a player setting)
+static HIGH_CONTRAST: AtomicBool = AtomicBool::new(false);
+
+/// Draw the UI in high contrast: near-black backgrounds, thicker borders and brighter
+/// text, for bright sunlight on a phone or for low vision
+pub fn use_high_contrast(on: bool) {
+    HIGH_CONTRAST.store(on, Ordering::Relaxed);
+}
+
+/// Whether the high contrast theme is in use
+pub fn high_contrast() -> bool {
+    HIGH_CONTRAST.load(Ordering::Relaxed)
+}
+
+/// Halfway to white and fully opaque
+fn brighten(color: egui::Color32) -> egui::Color32 {
+    let [r, g, b, _] = color.to_srgba_unmultiplied();
+    let lift = |c: u8| c + (255 - c) / 2;
+    egui::Color32::from_rgb(lift(r), lift(g), lift(b))
+}
+
+/// Background of a panel, card or toast: a near-black shade of it, fully opaque, in high contrast
+pub fn panel_fill(fill: egui::Color32) -> egui::Color32 {
+    if !high_contrast() {
+        return fill;
+    }
+    let [r, g, b, _] = fill.to_srgba_unmultiplied();
+    egui::Color32::from_rgb(r / 6, g / 6, b / 6)
+}
+
+/// Border of a panel, card or toast: at least twice as thick and brighter in high
+/// contrast. Borderless panels get a white one.
+pub fn panel_stroke(stroke: egui::Stroke) -> egui::Stroke {
+    if !high_contrast() {
+        return stroke;
+    }
+    let color = if stroke.color.a() == 0 { egui::Color32::WHITE } else { brighten(stroke.color) };
+    egui::Stroke::new((stroke.width * 2.0).max(2.0), color)
+}
+
+/// Text color, brighter in high contrast so grays read close to white
+pub fn text_color(color: egui::Color32) -> egui::Color32 {
+    if high_contrast() { brighten(color) } else { color }
+}
+
+/// System to switch egui's own widgets and panels to the high contrast theme and back.
+/// Runs every frame but only restyles when the setting changes, like `configure_fonts`.
+pub fn apply_contrast_theme(
+    mut contexts: EguiContexts,
+    settings: bevy::prelude::Res<crate::game::settings::Settings>,
+    mut applied: bevy::prelude::Local<Option<bool>>,
+) {
+    if *applied == Some(settings.high_contrast) {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    use_high_contrast(settings.high_contrast);
+
+    // Only the colors and strokes are touched, so the low quality tier's missing shadows stay missing
+    let dark = egui::Visuals::dark();
+    ctx.style_mut(|style| {
+        let visuals = &mut style.visuals;
+        if settings.high_contrast {
+            visuals.panel_fill = egui::Color32::BLACK;
+            visuals.window_fill = egui::Color32::BLACK;
+            visuals.extreme_bg_color = egui::Color32::BLACK;
+            visuals.faint_bg_color = egui::Color32::from_gray(24);
+            visuals.window_stroke = egui::Stroke::new(2.0, egui::Color32::WHITE);
+            visuals.selection.stroke = egui::Stroke::new(2.0, egui::Color32::YELLOW);
+
+            let widgets = &mut visuals.widgets;
+            for widget in [
+                &mut widgets.noninteractive,
+                &mut widgets.inactive,
+                &mut widgets.hovered,
+                &mut widgets.active,
+                &mut widgets.open,
+            ] {
+                widget.bg_stroke = egui::Stroke::new((widget.bg_stroke.width * 2.0).max(1.5), egui::Color32::WHITE);
+                widget.fg_stroke = egui::Stroke::new(widget.fg_stroke.width.max(1.5), egui::Color32::WHITE);
+            }
+            widgets.inactive.bg_fill = egui::Color32::from_gray(20);
+            widgets.inactive.weak_bg_fill = egui::Color32::from_gray(20);
+            widgets.hovered.bg_stroke.color = egui::Color32::YELLOW;
+        } else {
+            visuals.panel_fill = dark.panel_fill;
+            visuals.window_fill = dark.window_fill;
+            visuals.extreme_bg_color = dark.extreme_bg_color;
+            visuals.faint_bg_color = dark.faint_bg_color;
+            visuals.window_stroke = dark.window_stroke;
+            visuals.selection = dark.selection;
+            visuals.widgets = dark.widgets;
+        }
+    });
+    *applied = Some(settings.high_contrast);
+}
+
 // ============================================================================
 // Desert Button - Stone tablet style
 // ============================================================================
@@ -206,7 +352,7 @@ fn desert_button_impl(
         );
 
         // Draw main button body
-        painter.rect_filled(rect, style.corner_radius, base_color);
+        painter.rect_filled(rect, style.corner_radius, panel_fill(base_color));
 
         // Draw bevel edges for 3D effect
         let bevel_width = 2.0;
@@ -270,7 +416,7 @@ fn desert_button_impl(
         painter.rect_stroke(
             rect,
             style.corner_radius,
-            egui::Stroke::new(1.5, border_color),
+            panel_stroke(egui::Stroke::new(1.5, border_color)),
             egui::epaint::StrokeKind::Outside,
         );
 
@@ -315,7 +461,7 @@ fn desert_button_impl(
             egui::Align2::CENTER_CENTER,
             text,
             egui::FontId::proportional(style.font_size),
-            text_color,
+            self::text_color(text_color),
         );
     }
 
@@ -616,11 +762,12 @@ pub fn gold_tab(ui: &mut egui::Ui, text: &str, selected: bool) -> egui::Response
             );
         }
 
-        painter.rect_filled(rect, rounding, bg_color);
+        // The selected tab stays gold in high contrast, so it still stands out
+        painter.rect_filled(rect, rounding, if selected { bg_color } else { panel_fill(bg_color) });
         painter.rect_stroke(
             rect,
             rounding,
-            egui::Stroke::new(1.5, border_color),
+            panel_stroke(egui::Stroke::new(1.5, border_color)),
             egui::epaint::StrokeKind::Outside,
         );
 
@@ -630,7 +777,7 @@ pub fn gold_tab(ui: &mut egui::Ui, text: &str, selected: bool) -> egui::Response
             egui::Align2::CENTER_CENTER,
             text,
             egui::FontId::proportional(13.0),
-            text_color,
+            if selected { text_color } else { self::text_color(text_color) },
         );
     }
 
@@ -894,9 +1041,7 @@ pub fn desert_combobox<T: PartialEq + Clone>(
         );
 
         // Background
-        let bg_color = if is_open {
-            STONE_LIGHT
-        } else if is_hovered {
+        let bg_color = if is_open || is_hovered {
             STONE_LIGHT
         } else {
             STONE
@@ -963,12 +1108,12 @@ pub fn desert_combobox<T: PartialEq + Clone>(
                     .fill(egui::Color32::from_rgb(50, 45, 40))
                     .stroke(egui::Stroke::new(1.5, GOLD_OUTLINE))
                     .corner_radius(4.0)
-                    .shadow(egui::epaint::Shadow {
+                    .shadow(quality_shadow(ui.ctx(), egui::epaint::Shadow {
                         offset: [2, 4],
                         blur: 8,
                         spread: 2,
                         color: egui::Color32::from_rgba_unmultiplied(0, 0, 0, 100),
-                    })
+                    }))
                     .show(ui, |ui| {
                         ui.set_min_width(button_width - 4.0);
                         for (i, option) in options.iter().enumerate() {
@@ -1036,8 +1181,6 @@ pub mod desktop {
 /// Mobile layout sizes
 #[allow(dead_code)]
 pub mod mobile {
-    /// Minimum card width for player cards in grid
-    pub const MIN_PLAYER_CARD_WIDTH: f32 = 80.0;
     /// Avatar size in compact cards
     pub const AVATAR_SIZE: f32 = 22.0;
     /// Mini leg bet card dimensions (smaller for mobile)
@@ -1052,22 +1195,6 @@ pub mod mobile {
     pub const CAMEL_DISPLAY_HEIGHT: f32 = 24.0;
 }
 
-/// Shared layout helpers
-pub mod layout {
-    /// Calculate optimal players per row based on available width
-    pub fn players_per_row(player_count: usize, available_width: f32) -> usize {
-        let max_fit = (available_width / super::mobile::MIN_PLAYER_CARD_WIDTH).floor() as usize;
-        match player_count {
-            2 => 2,
-            3 => 3.min(max_fit),
-            4 => 2, // Always 2x2 grid for 4 players
-            5 | 6 => if max_fit >= 3 { 3 } else { 2 },
-            7 | 8 => if max_fit >= 4 { 4 } else if max_fit >= 3 { 3 } else { 2 },
-            _ => max_fit.max(2).min(4),
-        }
-    }
-}
-
 // ============================================================================
 // Overlapping Stack Widget
 // ============================================================================
@@ -1144,17 +1271,67 @@ where
 // Font Configuration
 // ============================================================================
 
-/// Resource to track if fonts have been configured
-#[derive(bevy::prelude::Resource, Default)]
-pub struct FontsConfigured(pub bool);
+/// Drop shadow for a frame, skipped on low-end devices (see `QualityTier`)
+pub fn quality_shadow(ctx: &egui::Context, shadow: egui::Shadow) -> egui::Shadow {
+    let low_quality = ctx.data(|data| {
+        data.get_temp::<bool>(egui::Id::new(crate::systems::quality::LOW_QUALITY_EGUI_ID))
+    });
+    if low_quality.unwrap_or(false) {
+        egui::Shadow::NONE
+    } else {
+        shadow
+    }
+}
 
-/// Configure egui to use the Aleo font as the default proportional font.
-/// This system runs every frame but only configures fonts once.
-pub fn configure_fonts(mut contexts: EguiContexts, mut configured: bevy::prelude::ResMut<FontsConfigured>) {
-    if configured.0 {
+/// How much bigger all text is drawn with the large text setting
+const LARGE_TEXT_SCALE: f32 = 1.25;
+
+/// Resource to track if fonts have been configured, and with which
+/// (large text, dyslexia-friendly font) settings
+#[derive(bevy::prelude::Resource, Default)]
+pub struct FontsConfigured(pub Option<(bool, bool)>);
+
+/// The dyslexia-friendly font (DejaVu Sans), loaded the first time the setting is
+/// turned on
+const DYSLEXIA_FONT_PATH: &str = "fonts/DejaVuSans.ttf";
+
+/// Configure egui to use the Aleo font as the default proportional font, or DejaVu Sans
+/// with the dyslexia-friendly font setting.
+/// This system runs every frame but only configures fonts once, and again when the
+/// large text or font setting changes.
+pub fn configure_fonts(
+    mut contexts: EguiContexts,
+    mut configured: bevy::prelude::ResMut<FontsConfigured>,
+    settings: bevy::prelude::Res<crate::game::settings::Settings>,
+    asset_server: bevy::prelude::Res<bevy::prelude::AssetServer>,
+    font_assets: bevy::prelude::Res<bevy::prelude::Assets<bevy::prelude::Font>>,
+    mut dyslexia_font: bevy::prelude::Local<Option<bevy::prelude::Handle<bevy::prelude::Font>>>,
+) {
+    let wanted = (settings.large_text, settings.dyslexia_font);
+    if configured.0 == Some(wanted) {
         return;
     }
 
+    // Dyslexia-friendly font: a plain sans-serif with wide, open letters and no serifs
+    // to crowd them. Most players never turn it on, so it's
+    // only loaded once someone does.
+    let dyslexia_font_data = if settings.dyslexia_font {
+        let handle = dyslexia_font.get_or_insert_with(|| asset_server.load(DYSLEXIA_FONT_PATH));
+        match font_assets.get(&*handle) {
+            Some(font) => Some(font.data.clone()),
+            None if !asset_server.load_state(handle.id()).is_failed() => {
+                // Keep the fonts we have until it has loaded
+                return;
+            }
+            None => {
+                bevy::log::warn!("Couldn't load {}, keeping the default font", DYSLEXIA_FONT_PATH);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
     let Ok(ctx) = contexts.ctx_mut() else { return };
 
     let mut fonts = egui::FontDefinitions::default();
@@ -1166,14 +1343,41 @@ pub fn configure_fonts(mut contexts: EguiContexts, mut configured: bevy::prelude
             "../../assets/fonts/Aleo-Variable.ttf"
         ))),
     );
+    if let Some(data) = &dyslexia_font_data {
+        fonts.font_data.insert(
+            "DejaVuSans".to_owned(),
+            std::sync::Arc::new(egui::FontData::from_owned(data.to_vec())),
+        );
+    }
 
-    // Set Aleo as the primary proportional font
+    // Set Aleo, or the dyslexia-friendly font, as the primary proportional font
+    let primary = if dyslexia_font_data.is_some() { "DejaVuSans" } else { "Aleo" };
     fonts
         .families
         .get_mut(&egui::FontFamily::Proportional)
         .unwrap()
-        .insert(0, "Aleo".to_owned());
+        .insert(0, primary.to_owned());
+
+    // Large text: every font's glyphs drawn bigger, so explicitly sized text grows too
+    if settings.large_text {
+        for data in fonts.font_data.values_mut() {
+            let mut scaled = (**data).clone();
+            scaled.tweak.scale *= LARGE_TEXT_SCALE;
+            *data = std::sync::Arc::new(scaled);
+        }
+    }
 
     ctx.set_fonts(fonts);
-    configured.0 = true;
+    configured.0 = Some(wanted);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_dyslexia_font_ships_with_the_assets() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("assets").join(DYSLEXIA_FONT_PATH);
+        assert!(path.is_file(), "{} is missing", path.display());
+    }
 }
@@ -0,0 +1,100 @@
+// Current player banner
+// A card in the middle of the board, inside the track, showing whose turn it is: their
+// avatar and name in their color. On phones the players panel can be collapsed, so this
+// keeps the turn readable from the board itself. The banner entity marks where it goes
+// in the world; the card is drawn over it with egui, so it follows the camera.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContextSettings, EguiContexts};
+
+use crate::components::Players;
+use crate::game::state::GameState;
+use crate::systems::leg_recap::LegRecap;
+use crate::ui::draw::draw_avatar;
+use crate::ui::hud::UiState;
+use crate::ui::theme::{panel_fill, panel_stroke, player_color_to_egui};
+
+/// How long the border pulses when the turn passes to someone (seconds)
+const PULSE_DURATION: f64 = 1.0;
+
+/// Where the banner sits: the middle of the board, inside the track
+#[derive(Component, Default)]
+pub struct TurnBanner {
+    /// Whose turn the banner shows, and when it passed to them (egui time)
+    player: Option<usize>,
+    changed_at: f64,
+}
+
+/// System to place the banner when a game starts or resumes
+pub fn spawn_turn_banner(mut commands: Commands) {
+    commands.spawn((
+        DespawnOnExit(GameState::Playing),
+        TurnBanner::default(),
+        Transform::from_xyz(0.0, 0.0, 0.0),
+    ));
+}
+
+/// Banner with the current player's avatar and name, kept over its spot on the board
+pub fn turn_banner_ui(
+    mut contexts: EguiContexts,
+    ui_state: Res<UiState>,
+    leg_recap: Res<LegRecap>,
+    players: Option<Res<Players>>,
+    mut banners: Query<(&mut TurnBanner, &GlobalTransform)>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<crate::GameCamera>>,
+    egui_settings: Query<&EguiContextSettings>,
+) {
+    // Not before the camels are set up, nor over the leg recap and scoring
+    if !ui_state.initial_rolls_complete || ui_state.show_leg_scoring || !leg_recap.is_idle() {
+        return;
+    }
+    let Some(players) = players else { return };
+    let Ok((mut banner, transform)) = banners.single_mut() else { return };
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Ok(screen_pos) = camera.world_to_viewport(camera_transform, transform.translation()) else { return };
+    let egui_scale = egui_settings.iter().next().map_or(1.0, |settings| settings.scale_factor);
+    let anchor = egui::pos2(screen_pos.x / egui_scale, screen_pos.y / egui_scale);
+
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let now = ctx.input(|i| i.time);
+    if banner.player != Some(players.current_player_index) {
+        banner.player = Some(players.current_player_index);
+        banner.changed_at = now;
+    }
+    // 0.0 as the turn passes, 1.0 once the pulse is over
+    let settled = ((now - banner.changed_at) / PULSE_DURATION).min(1.0) as f32;
+
+    let player = players.current_player();
+    let color = player_color_to_egui(player.color_index, player.custom_color);
+
+    egui::Area::new(egui::Id::new("turn_banner"))
+        .order(egui::Order::Background)
+        .pivot(egui::Align2::CENTER_CENTER)
+        .fixed_pos(anchor)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::new()
+                .fill(panel_fill(egui::Color32::from_rgba_unmultiplied(30, 20, 10, 200)))
+                .stroke(panel_stroke(egui::Stroke::new(2.0 + 3.0 * (1.0 - settled), color)))
+                .corner_radius(10.0)
+                .inner_margin(egui::Margin::symmetric(10, 6))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        let (rect, _) = ui.allocate_exact_size(egui::vec2(36.0, 36.0), egui::Sense::hover());
+                        draw_avatar(ui.painter(), rect, player.character_id, Some(color));
+                        ui.add_space(4.0);
+                        ui.label(
+                            egui::RichText::new(format!("{}'s turn", player.name))
+                                .size(18.0)
+                                .strong()
+                                .color(color),
+                        );
+                    });
+                });
+        });
+
+    if settled < 1.0 {
+        ctx.request_repaint();
+    }
+}
@@ -8,11 +8,13 @@ import {
     set,
     push,
     onValue,
+    onChildAdded,
     get,
     update,
     remove,
     serverTimestamp,
-    off
+    off,
+    onDisconnect
 } from 'https://www.gstatic.com/firebasejs/10.7.1/firebase-database.js';
 import {
     getAuth,
@@ -32,13 +34,27 @@ const tabSessionId = crypto.randomUUID();
 // Store active listeners for cleanup
 const activeListeners = new Map();
 
+// How often we tell the room we're still here, so others can tell a live tab from a frozen one
+const HEARTBEAT_MS = 5000;
+let heartbeatTimer = null;
+
+// Difference between the database's clock and ours, to compare heartbeats with
+let serverTimeOffset = 0;
+
 // Queues for receiving data from Firebase (polled by Rust)
 window.firebaseGameStateQueue = [];
 window.firebaseActionsQueue = [];
+window.firebaseHostActionsQueue = [];
 window.firebasePlayersQueue = [];
+window.firebaseEmotesQueue = [];
 window.firebaseAuthReady = false;
 window.firebaseError = null;
 
+// Error text including the Firebase error code (e.g. "PERMISSION_DENIED: ...") so the game can classify it
+function describeError(error) {
+    return error.code ? `${error.code}: ${error.message}` : error.message;
+}
+
 // Initialize Firebase with the provided config
 window.initializeFirebase = function() {
     const config = {
@@ -55,6 +71,9 @@ window.initializeFirebase = function() {
         app = initializeApp(config);
         db = getDatabase(app);
         auth = getAuth(app);
+        onValue(ref(db, '.info/serverTimeOffset'), (snapshot) => {
+            serverTimeOffset = snapshot.val() || 0;
+        });
         console.log('Firebase initialized successfully');
         return true;
     } catch (error) {
@@ -95,10 +114,41 @@ window.isAuthenticated = function() {
     return currentUserId !== null;
 };
 
+// Mark our seat as disconnected if this tab goes away, so the host can hand it to the AI,
+// and keep a heartbeat going for tabs that freeze without closing
+async function flagDisconnect(roomCode) {
+    const connectedRef = ref(db, `rooms/${roomCode}/players/${currentUserId}/is_connected`);
+    await onDisconnect(connectedRef).set(false);
+    startHeartbeat(roomCode);
+}
+
+function startHeartbeat(roomCode) {
+    stopHeartbeat();
+    const lastSeenRef = ref(db, `rooms/${roomCode}/players/${currentUserId}/last_seen`);
+    const beat = () => set(lastSeenRef, serverTimestamp()).catch((error) => {
+        console.error('Heartbeat error:', error);
+    });
+    beat();
+    heartbeatTimer = setInterval(beat, HEARTBEAT_MS);
+}
+
+function stopHeartbeat() {
+    if (heartbeatTimer !== null) {
+        clearInterval(heartbeatTimer);
+        heartbeatTimer = null;
+    }
+}
+
+// The database's clock (ms since the epoch), which heartbeats are stamped with
+window.getServerTime = function() {
+    return Date.now() + serverTimeOffset;
+};
+
 // Create a new room
-window.createRoom = async function(roomCode, hostName, characterId, colorIndex) {
+window.createRoom = async function(roomCode, hostName, characterId, colorIndex, protocolVersion) {
     if (!currentUserId) {
         console.error('Not authenticated');
+        window.firebaseError = 'Not authenticated';
         return false;
     }
 
@@ -112,7 +162,8 @@ window.createRoom = async function(roomCode, hostName, characterId, colorIndex)
                 created_at: now,
                 game_started: false,
                 max_players: 8,
-                randomize_order: false
+                randomize_order: false,
+                protocol_version: protocolVersion
             },
             players: {
                 [currentUserId]: {
@@ -126,12 +177,13 @@ window.createRoom = async function(roomCode, hostName, characterId, colorIndex)
                 }
             }
         });
+        await flagDisconnect(roomCode);
 
         console.log('Room created:', roomCode);
         return true;
     } catch (error) {
         console.error('Create room error:', error);
-        window.firebaseError = error.message;
+        window.firebaseError = describeError(error);
         return false;
     }
 };
@@ -149,9 +201,10 @@ window.roomExists = async function(roomCode) {
 };
 
 // Join an existing room
-window.joinRoom = async function(roomCode, playerName, characterId, colorIndex) {
+window.joinRoom = async function(roomCode, playerName, characterId, colorIndex, protocolVersion) {
     if (!currentUserId) {
         console.error('Not authenticated');
+        window.firebaseError = 'Not authenticated';
         return false;
     }
 
@@ -166,10 +219,28 @@ window.joinRoom = async function(roomCode, playerName, characterId, colorIndex)
         }
 
         const metadata = snapshot.val();
+        if (!sameProtocol(metadata, protocolVersion)) {
+            return false;
+        }
         if (metadata.game_started) {
             window.firebaseError = 'Game already started';
             return false;
         }
+        if (metadata.kicked?.[currentUserId]) {
+            window.firebaseError = 'Removed from room by the host';
+            return false;
+        }
+        if (metadata.locked) {
+            window.firebaseError = 'Room is locked';
+            return false;
+        }
+
+        const maxPlayers = Math.min(metadata.settings?.max_players ?? 8, 8);
+        const playersSnapshot = await get(ref(db, `rooms/${roomCode}/players`));
+        if (playersSnapshot.exists() && Object.keys(playersSnapshot.val()).length >= maxPlayers) {
+            window.firebaseError = 'Room is full';
+            return false;
+        }
 
         // Add player to room
         const playerRef = ref(db, `rooms/${roomCode}/players/${currentUserId}`);
@@ -182,12 +253,65 @@ window.joinRoom = async function(roomCode, playerName, characterId, colorIndex)
             is_host: false,
             joined_at: Date.now()
         });
+        await flagDisconnect(roomCode);
 
         console.log('Joined room:', roomCode);
         return true;
     } catch (error) {
         console.error('Join room error:', error);
-        window.firebaseError = error.message;
+        window.firebaseError = describeError(error);
+        return false;
+    }
+};
+
+// Whether a room's host plays the same protocol version as us; sets the error if not.
+// Rooms from builds before versioning have none, and count as version 0.
+function sameProtocol(metadata, protocolVersion) {
+    const roomVersion = metadata.protocol_version ?? 0;
+    if (roomVersion !== protocolVersion) {
+        window.firebaseError = `Version mismatch: the room plays version ${roomVersion}, you have ${protocolVersion}`;
+        return false;
+    }
+    return true;
+}
+
+// Take our seat again in a started game, after a page reload
+window.rejoinRoom = async function(roomCode, protocolVersion) {
+    if (!currentUserId) {
+        console.error('Not authenticated');
+        window.firebaseError = 'Not authenticated';
+        return false;
+    }
+
+    try {
+        const snapshot = await get(ref(db, `rooms/${roomCode}/metadata`));
+        if (!snapshot.exists()) {
+            window.firebaseError = 'Room not found';
+            return false;
+        }
+        if (!snapshot.val().game_started) {
+            window.firebaseError = 'That game is over';
+            return false;
+        }
+        if (!sameProtocol(snapshot.val(), protocolVersion)) {
+            return false;
+        }
+
+        const playerRef = ref(db, `rooms/${roomCode}/players/${currentUserId}`);
+        const playerSnapshot = await get(playerRef);
+        if (!playerSnapshot.exists()) {
+            window.firebaseError = 'You no longer have a seat in that game';
+            return false;
+        }
+
+        await update(playerRef, { is_connected: true });
+        await flagDisconnect(roomCode);
+
+        console.log('Rejoined room:', roomCode);
+        return true;
+    } catch (error) {
+        console.error('Rejoin room error:', error);
+        window.firebaseError = describeError(error);
         return false;
     }
 };
@@ -195,6 +319,7 @@ window.joinRoom = async function(roomCode, playerName, characterId, colorIndex)
 // Leave a room
 window.leaveRoom = async function(roomCode) {
     if (!currentUserId) return;
+    stopHeartbeat();
 
     try {
         const playerRef = ref(db, `rooms/${roomCode}/players/${currentUserId}`);
@@ -220,14 +345,16 @@ window.setPlayerReady = async function(roomCode, isReady) {
 };
 
 // Update player appearance (character, color, and optionally name)
-window.updatePlayerAppearance = async function(roomCode, characterId, colorIndex, name = null) {
+window.updatePlayerAppearance = async function(roomCode, characterId, colorIndex, customColor = null, name = null) {
     if (!currentUserId) return false;
 
     try {
         const playerRef = ref(db, `rooms/${roomCode}/players/${currentUserId}`);
         const updateData = {
             character_id: characterId,
-            color_index: colorIndex
+            color_index: colorIndex,
+            // [r, g, b] picked with the color picker, or null to use the palette slot
+            custom_color: customColor ? Array.from(customColor) : null
         };
         if (name !== null) {
             updateData.name = name;
@@ -241,17 +368,51 @@ window.updatePlayerAppearance = async function(roomCode, characterId, colorIndex
     }
 };
 
-// Start the game (host only)
-window.startGame = async function(roomCode) {
+// Start the game with the host's setup (host only)
+window.startGame = async function(roomCode, setupJson) {
     if (!currentUserId) return false;
 
     try {
-        const metadataRef = ref(db, `rooms/${roomCode}/metadata/game_started`);
-        await set(metadataRef, true);
+        const metadataRef = ref(db, `rooms/${roomCode}/metadata`);
+        await update(metadataRef, { game_started: true, setup: JSON.parse(setupJson) });
         console.log('Game started');
         return true;
     } catch (error) {
         console.error('Start game error:', error);
+        window.firebaseError = describeError(error);
+        return false;
+    }
+};
+
+// Take a finished game's room back to its waiting room, keeping its players (host only).
+// Clears the game and everyone's ready flag, and lets go of players who have left.
+window.resetRoom = async function(roomCode) {
+    if (!currentUserId) return false;
+
+    try {
+        const updates = {
+            'metadata/game_started': false,
+            'metadata/setup': null,
+            game_state: null,
+            log: null,
+            actions: null,
+            emotes: null
+        };
+        const playersSnapshot = await get(ref(db, `rooms/${roomCode}/players`));
+        playersSnapshot.forEach((child) => {
+            const player = child.val();
+            if (player.is_connected === false) {
+                updates[`players/${child.key}`] = null;
+            } else if (!player.is_host) {
+                updates[`players/${child.key}/is_ready`] = false;
+            }
+        });
+        await update(ref(db, `rooms/${roomCode}`), updates);
+        console.log('Room reset for a rematch');
+        return true;
+    } catch (error) {
+        console.error('Reset room error:', error);
+        window.firebaseError = describeError(error);
         return false;
     }
 };
@@ -265,6 +426,7 @@ window.writeGameState = async function(roomCode, stateJson) {
         return true;
     } catch (error) {
         console.error('Write game state error:', error);
+        window.firebaseError = describeError(error);
         return false;
     }
 };
@@ -284,6 +446,20 @@ window.submitAction = async function(roomCode, actionJson) {
         return true;
     } catch (error) {
         console.error('Submit action error:', error);
+        window.firebaseError = describeError(error);
+        return false;
+    }
+};
+
+// Add an applied action to the room's action log (host only)
+window.broadcastAction = async function(roomCode, actionJson) {
+    try {
+        const logRef = ref(db, `rooms/${roomCode}/log`);
+        await push(logRef, JSON.parse(actionJson));
+        return true;
+    } catch (error) {
+        console.error('Log action error:', error);
+        window.firebaseError = describeError(error);
         return false;
     }
 };
@@ -300,8 +476,24 @@ window.markActionProcessed = async function(roomCode, actionId) {
     }
 };
 
-// Subscribe to game state changes
+// Subscribe to the host's action log and game state changes
 window.subscribeToGameState = function(roomCode) {
+    const logRef = ref(db, `rooms/${roomCode}/log`);
+
+    if (activeListeners.has('log')) {
+        off(activeListeners.get('log'));
+    }
+
+    // Delivers the whole log once, then each new entry as it is added
+    onChildAdded(logRef, (child) => {
+        window.firebaseHostActionsQueue.push(child.val());
+    }, (error) => {
+        console.error('Action log subscription error:', error);
+        window.firebaseError = error.message;
+    });
+
+    activeListeners.set('log', logRef);
+
     const stateRef = ref(db, `rooms/${roomCode}/game_state`);
 
     // Remove existing listener if any
@@ -330,21 +522,14 @@ window.subscribeToActions = function(roomCode) {
         off(activeListeners.get('actions'));
     }
 
-    const unsubscribe = onValue(actionsRef, (snapshot) => {
-        if (snapshot.exists()) {
-            const actions = [];
-            snapshot.forEach((child) => {
-                const action = child.val();
-                if (!action.processed) {
-                    actions.push({
-                        id: child.key,
-                        ...action
-                    });
-                }
-            });
-            if (actions.length > 0) {
-                window.firebaseActionsQueue.push(JSON.stringify(actions));
-            }
+    // Each action is delivered once, as it is added
+    const unsubscribe = onChildAdded(actionsRef, (child) => {
+        const action = child.val();
+        if (!action.processed) {
+            window.firebaseActionsQueue.push(JSON.stringify([{
+                id: child.key,
+                ...action
+            }]));
         }
     }, (error) => {
         console.error('Actions subscription error:', error);
@@ -401,6 +586,57 @@ window.subscribeToMetadata = function(roomCode) {
     activeListeners.set('metadata', metadataRef);
 };
 
+// Emotes older than this when they arrive (the room's backlog, on subscribing) aren't shown
+const EMOTE_MAX_AGE_MS = 5000;
+
+// Subscribe to the other players' emotes
+window.subscribeToEmotes = function(roomCode) {
+    const emotesRef = ref(db, `rooms/${roomCode}/emotes`);
+
+    if (activeListeners.has('emotes')) {
+        off(activeListeners.get('emotes'));
+    }
+
+    onChildAdded(emotesRef, (child) => {
+        const emote = child.val();
+        if (emote.player_id === currentUserId || window.getServerTime() - emote.sent_at > EMOTE_MAX_AGE_MS) {
+            return;
+        }
+        window.firebaseEmotesQueue.push({ player_id: emote.player_id, emote: emote.emote });
+    }, (error) => {
+        console.error('Emotes subscription error:', error);
+    });
+
+    activeListeners.set('emotes', emotesRef);
+};
+
+// Show everyone else in the room an emote
+window.sendEmote = async function(roomCode, emoteJson) {
+    if (!currentUserId) return false;
+
+    try {
+        await push(ref(db, `rooms/${roomCode}/emotes`), {
+            player_id: currentUserId,
+            emote: JSON.parse(emoteJson),
+            sent_at: window.getServerTime()
+        });
+        return true;
+    } catch (error) {
+        console.error('Send emote error:', error);
+        return false;
+    }
+};
+
+// Poll for the other players' emotes (called from Rust), all at once
+window.pollEmotes = function() {
+    if (window.firebaseEmotesQueue.length > 0) {
+        const emotes = window.firebaseEmotesQueue;
+        window.firebaseEmotesQueue = [];
+        return JSON.stringify(emotes);
+    }
+    return null;
+};
+
 // Poll for game state updates (called from Rust)
 window.pollGameState = function() {
     if (window.firebaseGameStateQueue.length > 0) {
@@ -417,6 +653,16 @@ window.pollActions = function() {
     return null;
 };
 
+// Poll for the host's logged actions (called from Rust), all at once
+window.pollHostActions = function() {
+    if (window.firebaseHostActionsQueue.length > 0) {
+        const actions = window.firebaseHostActionsQueue;
+        window.firebaseHostActionsQueue = [];
+        return JSON.stringify(actions);
+    }
+    return null;
+};
+
 // Poll for player updates (called from Rust)
 window.pollPlayers = function() {
     if (window.firebasePlayersQueue.length > 0) {
@@ -425,6 +671,201 @@ window.pollPlayers = function() {
     return null;
 };
 
+// Logical CPU cores, used to pick the rendering quality tier (0 if unknown)
+window.getHardwareConcurrency = function() {
+    return navigator.hardwareConcurrency || 0;
+};
+
+// Link that opens the game and joins a room (shared as a QR code or copied)
+window.getInviteLink = function(roomCode) {
+    return `${window.location.origin}${window.location.pathname}?room=${encodeURIComponent(roomCode)}`;
+};
+
+// Copy text to the clipboard
+window.copyToClipboard = function(text) {
+    if (!navigator.clipboard) {
+        console.warn('Clipboard not available');
+        return;
+    }
+    navigator.clipboard.writeText(text).catch((error) => console.error('Copy error:', error));
+};
+
+// Whether the browser has a share sheet (mostly mobile browsers)
+window.canShare = function() {
+    return typeof navigator.share === 'function';
+};
+
+// Open the share sheet with an invite to a room
+window.shareInvite = function(roomCode, link) {
+    if (typeof navigator.share !== 'function') return;
+    navigator.share({
+        title: 'Camel Up',
+        text: `Join my Camel Up game! Room code: ${roomCode}`,
+        url: link,
+    }).catch((error) => {
+        // Closing the share sheet counts as an error too
+        if (error.name !== 'AbortError') console.error('Share error:', error);
+    });
+};
+
+// Ask before the tab is closed or reloaded while a game is in progress
+let leaveGuardEnabled = false;
+window.addEventListener('beforeunload', (event) => {
+    if (!leaveGuardEnabled) return;
+    event.preventDefault();
+    // Older browsers only show the prompt when returnValue is set
+    event.returnValue = '';
+});
+
+window.setLeaveGuard = function(enabled) {
+    leaveGuardEnabled = enabled;
+};
+
+// Room code from an invite link, if the page was opened with one. Removed from the
+// address bar so a reload doesn't try to join again.
+window.takeInviteRoomCode = function() {
+    const params = new URLSearchParams(window.location.search);
+    const code = params.get('room');
+    if (!code) return null;
+    window.history.replaceState(null, '', window.location.pathname);
+    return code;
+};
+
+// The online game we're in, kept across page reloads so we can rejoin it
+const SESSION_KEY = 'camelUpSession';
+
+window.saveSession = function(sessionJson) {
+    try {
+        window.localStorage.setItem(SESSION_KEY, sessionJson);
+    } catch (error) {
+        console.error('Could not save session:', error);
+    }
+};
+
+window.loadSession = function() {
+    try {
+        return window.localStorage.getItem(SESSION_KEY);
+    } catch (error) {
+        return null;
+    }
+};
+
+window.clearSession = function() {
+    try {
+        window.localStorage.removeItem(SESSION_KEY);
+    } catch (error) {
+        console.error('Could not clear session:', error);
+    }
+};
+
+// Documents kept between visits, like the autosaved local game and the last replay
+window.storeItem = function(key, json) {
+    try {
+        window.localStorage.setItem(key, json);
+    } catch (error) {
+        console.error('Could not store', key, error);
+    }
+};
+
+window.loadItem = function(key) {
+    try {
+        return window.localStorage.getItem(key);
+    } catch (error) {
+        return null;
+    }
+};
+
+window.removeItem = function(key) {
+    try {
+        window.localStorage.removeItem(key);
+    } catch (error) {
+        console.error('Could not remove', key, error);
+    }
+};
+
+// Hand the player a file, like an exported game log, as a download
+window.downloadFile = function(fileName, contents) {
+    const url = URL.createObjectURL(new Blob([contents], { type: 'application/json' }));
+    const link = document.createElement('a');
+    link.href = url;
+    link.download = fileName;
+    document.body.appendChild(link);
+    link.click();
+    link.remove();
+    URL.revokeObjectURL(url);
+};
+
+// Spoken commentary (Web Speech API)
+window.isSpeechSupported = function() {
+    return 'speechSynthesis' in window;
+};
+
+// Voice names, one per line (empty until the browser has loaded its voices)
+window.getSpeechVoices = function() {
+    if (!('speechSynthesis' in window)) return '';
+    return window.speechSynthesis.getVoices().map(v => v.name).join('\n');
+};
+
+// Queue a line to be spoken; an empty voice name uses the browser default
+window.speakText = function(text, rate, volume, voiceName) {
+    if (!('speechSynthesis' in window)) return;
+    const utterance = new SpeechSynthesisUtterance(text);
+    utterance.rate = rate;
+    utterance.volume = volume;
+    if (voiceName) {
+        const voice = window.speechSynthesis.getVoices().find(v => v.name === voiceName);
+        if (voice) utterance.voice = voice;
+    }
+    window.speechSynthesis.speak(utterance);
+};
+
+window.stopSpeech = function() {
+    if ('speechSynthesis' in window) window.speechSynthesis.cancel();
+};
+
+// Screen reader announcements: put the text in the hidden live region. Clearing it
+// first and filling it on the next frame makes repeats of the same text get read too.
+window.announce = function(text) {
+    let region = document.getElementById('announcer');
+    if (!region) {
+        region = document.createElement('div');
+        region.id = 'announcer';
+        region.setAttribute('role', 'status');
+        region.setAttribute('aria-live', 'polite');
+        region.setAttribute('aria-atomic', 'true');
+        region.style.cssText = 'position:absolute;width:1px;height:1px;overflow:hidden;clip:rect(0,0,0,0);';
+        document.body.appendChild(region);
+    }
+    region.textContent = '';
+    requestAnimationFrame(() => { region.textContent = text; });
+};
+
+// "Your turn" alert: while the tab is in the background, swap its title back and forth
+// with the message until the player comes back to it
+let titleFlash = null;
+
+window.flashTitle = function(message) {
+    if (!document.hidden || titleFlash) return;
+    const original = document.title;
+    let showingMessage = false;
+    const interval = setInterval(() => {
+        showingMessage = !showingMessage;
+        document.title = showingMessage ? message : original;
+    }, 1000);
+    titleFlash = { interval, original };
+};
+
+window.stopTitleFlash = function() {
+    if (!titleFlash) return;
+    clearInterval(titleFlash.interval);
+    document.title = titleFlash.original;
+    titleFlash = null;
+};
+
+document.addEventListener('visibilitychange', () => {
+    if (!document.hidden) window.stopTitleFlash();
+});
+
 // Get last Firebase error
 window.getFirebaseError = function() {
     const error = window.firebaseError;
@@ -437,6 +878,12 @@ window.hasGameStarted = function() {
     return window.firebaseMetadata?.game_started ?? false;
 };
 
+// Setup the host started the game with (null until it has)
+window.getGameSetup = function() {
+    const setup = window.firebaseMetadata?.setup;
+    return setup ? JSON.stringify(setup) : null;
+};
+
 // Get randomize order setting
 window.getRandomizeOrder = function() {
     return window.firebaseMetadata?.randomize_order ?? false;
@@ -455,15 +902,84 @@ window.setRandomizeOrder = async function(roomCode, randomize) {
     }
 };
 
+// Table the host set up in the waiting room (null until shared)
+window.getRoomSettings = function() {
+    const settings = window.firebaseMetadata?.settings;
+    return settings ? JSON.stringify(settings) : null;
+};
+
+// Share the room's table settings (host only)
+window.setRoomSettings = async function(roomCode, settingsJson) {
+    try {
+        await set(ref(db, `rooms/${roomCode}/metadata/settings`), JSON.parse(settingsJson));
+        return true;
+    } catch (error) {
+        console.error('Set room settings error:', error);
+        return false;
+    }
+};
+
+// Whether the host has closed the room to new players
+window.isRoomLocked = function() {
+    return window.firebaseMetadata?.locked ?? false;
+};
+
+// Lock or unlock the room (host only)
+window.setRoomLocked = async function(roomCode, locked) {
+    try {
+        await set(ref(db, `rooms/${roomCode}/metadata/locked`), locked);
+        return true;
+    } catch (error) {
+        console.error('Set room locked error:', error);
+        return false;
+    }
+};
+
+// Remove a player from the room and keep them out (host only)
+window.kickPlayer = async function(roomCode, playerId) {
+    try {
+        await set(ref(db, `rooms/${roomCode}/metadata/kicked/${playerId}`), true);
+        await remove(ref(db, `rooms/${roomCode}/players/${playerId}`));
+        console.log('Kicked player:', playerId);
+        return true;
+    } catch (error) {
+        console.error('Kick player error:', error);
+        return false;
+    }
+};
+
+// Whether the host removed us from the room we're in
+window.wasKicked = function() {
+    return !!(currentUserId && window.firebaseMetadata?.kicked?.[currentUserId]);
+};
+
 // Unsubscribe from all listeners
 window.unsubscribeAll = function() {
     for (const [key, refValue] of activeListeners) {
         off(refValue);
     }
     activeListeners.clear();
+    stopHeartbeat();
     window.firebaseGameStateQueue = [];
     window.firebaseActionsQueue = [];
+    window.firebaseHostActionsQueue = [];
     window.firebasePlayersQueue = [];
+    window.firebaseEmotesQueue = [];
+    window.firebaseMetadata = null;
+};
+
+// Stop listening to the game, but stay in the room (back in its waiting room)
+window.unsubscribeFromGame = function() {
+    for (const key of ['log', 'game_state', 'actions']) {
+        if (activeListeners.has(key)) {
+            off(activeListeners.get(key));
+            activeListeners.delete(key);
+        }
+    }
+    window.firebaseGameStateQueue = [];
+    window.firebaseActionsQueue = [];
+    window.firebaseHostActionsQueue = [];
+    window.firebaseEmotesQueue = [];
 };
 
 // Delete a room (host cleanup)